@@ -11,9 +11,13 @@ let package = Package(
     .package(url: "https://github.com/danielsaidi/EmojiKit", from: "3.0.0"),
   ],
   targets: [
+    .target(
+      name: "IgniteroIPC"
+    ),
     .target(
       name: "IgniteroCore",
       dependencies: [
+        "IgniteroIPC",
         .product(name: "GRDB", package: "GRDB.swift"),
         .product(name: "KeyboardShortcuts", package: "KeyboardShortcuts"),
         .product(name: "Fuse", package: "fuse-swift"),
@@ -25,12 +29,17 @@ let package = Package(
     ),
     .executableTarget(
       name: "IgniteroLauncher",
-      dependencies: ["IgniteroCore"]
+      dependencies: ["IgniteroCore", "IgniteroIPC"]
+    ),
+    .executableTarget(
+      name: "ignitero",
+      dependencies: ["IgniteroIPC"]
     ),
     .testTarget(
       name: "IgniteroCoreTests",
       dependencies: [
         "IgniteroCore",
+        "IgniteroIPC",
         .product(name: "GRDB", package: "GRDB.swift"),
         .product(name: "KeyboardShortcuts", package: "KeyboardShortcuts"),
         .product(name: "Fuse", package: "fuse-swift"),
@@ -1,7 +1,7 @@
 import AppKit
 import Foundation
+import IgniteroIPC
 import SwiftUI
-import os
 
 /// アプリケーション全体のコンポーネントを統合し、ライフサイクルを管理するコーディネーター。
 ///
@@ -12,8 +12,7 @@ import os
 @Observable
 public final class AppCoordinator {
 
-  private static let logger = Logger(
-    subsystem: "com.ignitero.launcher", category: "AppCoordinator")
+  private static let logger = AppLogger(category: "AppCoordinator")
 
   // MARK: - コアサービス
 
@@ -38,9 +37,60 @@ public final class AppCoordinator {
   /// アプリ/ディレクトリ/コマンド起動サービス
   public let launchService: any Launching
 
+  /// 実行中のプロセスの取得・終了
+  public let processManager: any ProcessControlling
+
+  /// 直前のアプリへの貼り付け
+  public let pasteService: any Pasting
+
+  /// 項目をゴミ箱に入れ、元に戻すサービス
+  private let trashService: any Trashing
+
+  /// クリップボードへの書き込み
+  private let clipboard: any ClipboardWriting
+
+  /// ディスプレイの倍率の取得
+  private let displayInfoProvider: any DisplayInfoProviding
+
+  /// アクセシビリティ権限の確認・要求
+  private let accessibilityPermission: any AccessibilityPermissionProviding
+
+  /// アクセシビリティ権限の変化の監視
+  public let accessibilityMonitor: AccessibilityPermissionMonitor
+
+  /// ゴミ箱に入れた項目を元に戻せる時間
+  static let trashUndoInterval: Duration = .seconds(10)
+
+  /// キャッシュ済みアプリの存在確認に使うファイルシステム
+  private let fileSystem: any FileSystemProvider
+
+  /// 消音・音量・ダークモードなどのシステム操作を実行するサービス
+  private let systemControlService: any SystemControlling
+
+  /// 直前のアプリのウィンドウを移動・リサイズするサービス
+  private let windowArranger: any WindowArranging
+
+  /// Dock のアイコンの表示を切り替えるサービス
+  private let activationPolicy: any ActivationPolicyControlling
+
+  /// 反映済みの Dock のアイコンの表示（Info.plist の `LSUIElement` によりメニューバーのみで起動する）
+  private var isDockIconVisible = false
+
+  /// 検索対象のシステム設定のパネルを返すプロバイダー
+  private let systemSettingsProvider: any SystemSettingsPaneProviding
+
+  /// 読み込み済みのシステム設定のパネル（初回のキャッシュ読込時に 1 度だけ探索する）
+  private var systemSettingsPanes: [SystemSettingsPane]?
+
   /// アイコンキャッシュ管理
   public let iconCacheManager: IconCacheManager
 
+  /// アプリのアイコンを表示時に変換するワーカープール
+  private let iconConversionQueue: IconConversionQueue
+
+  /// 起動時にアイコンを先に変換しておく、よく使うアプリの数
+  static let iconPrefetchCount = 24
+
   /// アプリケーションスキャナー
   public let appScanner: any AppScannerProtocol
 
@@ -64,6 +114,54 @@ public final class AppCoordinator {
   /// アップデートチェッカー
   public let updateChecker: UpdateChecker
 
+  /// アップデートのダウンロード・インストール
+  public let updateInstaller: any UpdateInstalling
+
+  /// アップデートで置き換える現在のアプリ（テスト時に差し替える）
+  var installedAppURL = Bundle.main.bundleURL
+
+  /// インストール後にアプリを再起動する処理（テスト時に差し替える）
+  var relaunchHandler: @MainActor (URL) -> Void = AppCoordinator.relaunch
+
+  /// 直近のアップデートチェックで見つかった新バージョン
+  private var availableUpdate: UpdateCheckResult?
+
+  /// ダウンロード・検証済みのアップデート（バージョンと展開した .app）
+  private var stagedUpdate: (version: String, app: URL)?
+
+  /// `ignitero` CLI 用ソケットのパス（nil なら待ち受けない）
+  private let ipcSocketPath: String?
+
+  /// `ignitero` CLI からのリクエストを受け付けるサーバー
+  private var ipcServer: IPCServer?
+
+  /// 設定ファイルの外部での編集を監視するか
+  private let watchesSettingsFile: Bool
+
+  /// 設定ファイルの外部での編集の監視
+  private var settingsFileWatcher: SettingsFileWatcher?
+
+  /// キャッシュ DB に記録した検索語と選んだ結果（選択履歴から押し出された古い選択も含む）
+  private var queryHistory: [SelectionHistoryEntry] = []
+
+  /// キャッシュ DB に記憶したディレクトリごとのエディタ（記憶を使わない設定でも保持する）
+  private var directoryEditors: [String: EditorType] = [:]
+
+  /// キャッシュ DB から読み込んだ SSH ホスト・tmux セッション
+  private var terminalTargets: [TerminalTarget] = []
+
+  /// `file_openers` のアプリの指定ごとに解決したアプリの場所（見つからないアプリは nil）
+  private var fileOpenerApplicationURLs: [String: URL?] = [:]
+
+  /// `get_query_suggestions` で返す候補の最大件数
+  static let querySuggestionLimit = 10
+
+  /// `get_icon` でサイズを省略したときのポイントサイズ（検索結果のアイコンと同じ）
+  static let defaultIconPointSize = 32
+
+  /// 起動処理の完了前に開かれた `ignitero://` URL（起動完了後に処理する）
+  private var pendingDeepLinks: [URL] = []
+
   // MARK: - UIコンポーネント
 
   /// ランチャービューモデル
@@ -87,6 +185,20 @@ public final class AppCoordinator {
   /// 起動処理がすべて完了し、操作可能な状態かどうか
   public private(set) var isReady: Bool = false
 
+  /// メニューバーアイコンに表示する状態。
+  ///
+  /// 起動処理・キャッシュ更新中は更新中、通知済みのアップデートがあればアップデートありを返す
+  /// （アップデートより更新中を優先する）。
+  public var menuBarIconState: MenuBarIconState {
+    if !isReady || cacheBootstrap.isScanning {
+      return .refreshing
+    }
+    if let version = launcherViewModel.updateBannerVersion {
+      return .updateAvailable(version)
+    }
+    return .idle
+  }
+
   // MARK: - 初期化
 
   /// AppCoordinator を初期化し、全コンポーネントを接続する。
@@ -100,6 +212,22 @@ public final class AppCoordinator {
   ///   - directoryScanner: ディレクトリスキャナー（テスト時に差し替え可能）
   ///   - selectionHistory: 選択履歴（テスト時に差し替え可能）
   ///   - urlSession: HTTP セッション（テスト時に差し替え可能）
+  ///   - loginItemService: ログイン時起動の登録サービス（テスト時に差し替え可能）
+  ///   - processManager: プロセスの取得・終了（テスト時に差し替え可能）
+  ///   - iconCacheManager: アイコンキャッシュ（テスト時に差し替え可能）
+  ///   - pasteService: 直前のアプリへの貼り付け（テスト時に差し替え可能）
+  ///   - clipboard: クリップボードへの書き込み（テスト時に差し替え可能）
+  ///   - updateInstaller: アップデートのインストール（テスト時に差し替え可能）
+  ///   - fileSystem: キャッシュ済みアプリの存在確認（テスト時に差し替え可能）
+  ///   - systemSettingsProvider: 検索対象のシステム設定のパネルを返すプロバイダー
+  ///   - systemControlService: システム操作を実行するサービス
+  ///   - windowArranger: 直前のアプリのウィンドウを移動・リサイズするサービス
+  ///   - activationPolicy: Dock のアイコンの表示の切り替え（テスト時に差し替え可能）
+  ///   - displayInfoProvider: ディスプレイの倍率の取得（テスト時に差し替え可能）
+  ///   - accessibilityPermission: アクセシビリティ権限の確認・要求（テスト時に差し替え可能）
+  ///   - terminalTargetProvider: 検索対象の SSH ホスト・tmux セッションを返すプロバイダー
+  ///   - ipcSocketPath: `ignitero` CLI 用ソケットのパス（nil なら待ち受けない）
+  ///   - watchesSettingsFile: 設定ファイルの外部での編集を監視して反映するか
   public init(
     settingsManager: SettingsManager? = nil,
     cacheDatabase: (any CacheDatabaseProtocol)? = nil,
@@ -109,6 +237,23 @@ public final class AppCoordinator {
     directoryScanner: (any DirectoryScannerProtocol)? = nil,
     selectionHistory: SelectionHistory? = nil,
     urlSession: (any URLSessionProtocol)? = nil,
+    loginItemService: (any LoginItemControlling)? = nil,
+    processManager: (any ProcessControlling)? = nil,
+    iconCacheManager: IconCacheManager? = nil,
+    pasteService: (any Pasting)? = nil,
+    clipboard: (any ClipboardWriting)? = nil,
+    trashService: (any Trashing)? = nil,
+    updateInstaller: (any UpdateInstalling)? = nil,
+    fileSystem: (any FileSystemProvider)? = nil,
+    systemSettingsProvider: (any SystemSettingsPaneProviding)? = nil,
+    systemControlService: (any SystemControlling)? = nil,
+    windowArranger: (any WindowArranging)? = nil,
+    activationPolicy: (any ActivationPolicyControlling)? = nil,
+    displayInfoProvider: (any DisplayInfoProviding)? = nil,
+    accessibilityPermission: (any AccessibilityPermissionProviding)? = nil,
+    terminalTargetProvider: (any TerminalTargetProviding)? = nil,
+    ipcSocketPath: String? = IPCSocket.defaultPath,
+    watchesSettingsFile: Bool = true,
     shortcutDebounceInterval: Duration = .milliseconds(300)
   ) {
     // コアサービスを初期化する
@@ -132,11 +277,26 @@ public final class AppCoordinator {
       }
     }
 
-    self.searchService = SearchService()
+    let processes = processManager ?? ProcessManager.shared
+    self.processManager = processes
+    self.searchService = SearchService(processes: processes)
     self.calculatorEngine = CalculatorEngine()
     self.imeController = imeController ?? IMEController()
     self.launchService = launchService ?? LaunchService()
-    self.iconCacheManager = IconCacheManager()
+    self.iconCacheManager = iconCacheManager ?? IconCacheManager()
+    self.iconConversionQueue = IconConversionQueue(iconCacheManager: self.iconCacheManager)
+    self.pasteService = pasteService ?? PasteService()
+    self.clipboard = clipboard ?? ClipboardService()
+    self.trashService = trashService ?? TrashService()
+    self.fileSystem = fileSystem ?? DefaultFileSystemProvider()
+    self.systemSettingsProvider = systemSettingsProvider ?? SystemSettingsProvider()
+    self.systemControlService = systemControlService ?? SystemControlService()
+    self.windowArranger = windowArranger ?? WindowLayoutService()
+    self.activationPolicy = activationPolicy ?? ActivationPolicyService()
+    self.displayInfoProvider = displayInfoProvider ?? DisplayInfoProvider()
+    let permission = accessibilityPermission ?? AccessibilityPermissionService()
+    self.accessibilityPermission = permission
+    self.accessibilityMonitor = AccessibilityPermissionMonitor(provider: permission)
 
     let scanner = appScanner ?? AppScanner(iconCacheManager: self.iconCacheManager)
     self.appScanner = scanner
@@ -171,14 +331,20 @@ public final class AppCoordinator {
       settingsManager: settings,
       cacheDatabase: self.cacheDatabase,
       appScanner: scanner,
-      directoryScanner: dirScanner
+      directoryScanner: dirScanner,
+      iconCacheManager: self.iconCacheManager,
+      terminalTargetProvider: terminalTargetProvider
     )
 
-    // アップデートチェッカーを初期化する
+    // アップデートチェッカーを初期化する（HTTPS_PROXY / HTTP_PROXY が指定されていれば経由する）
+    let updateSession = urlSession ?? ProxyConfiguration.makeSession()
     self.updateChecker = UpdateChecker(
-      session: urlSession ?? URLSession.shared,
+      session: updateSession,
       settingsManager: settings
     )
+    self.updateInstaller = updateInstaller ?? UpdateInstaller(session: updateSession)
+    self.ipcSocketPath = ipcSocketPath
+    self.watchesSettingsFile = watchesSettingsFile
 
     // ViewModel 群を初期化する
     let launcherVM = LauncherViewModel(
@@ -187,7 +353,10 @@ public final class AppCoordinator {
     )
     self.launcherViewModel = launcherVM
 
-    let settingsVM = SettingsViewModel(settingsManager: settings)
+    let settingsVM = SettingsViewModel(
+      settingsManager: settings,
+      loginItemService: loginItemService ?? LoginItemService()
+    )
     // インストール済みエディタ/ターミナルを設定
     settingsVM.installedEditors = self.launchService.availableEditors().filter { $0.installed }
     settingsVM.installedTerminals = self.launchService.availableTerminals().filter { $0.installed }
@@ -206,6 +375,8 @@ public final class AppCoordinator {
     // - reloadOnly: コマンド/エディタ/ターミナル変更 → ViewModel 再読込のみ
     // - cacheInvalidated: ディレクトリ/除外アプリ変更 → キャッシュ再構築
     // - updateScheduleChanged: 自動更新設定変更 → タイマー再起動 + 再読込
+    // - hotkeysChanged: 追加ホットキー変更 → Carbon ホットキーの再登録
+    // - directoryRefreshRequested: 単一ディレクトリの再スキャン → 該当範囲のみ更新
     settingsViewModel.onSettingsChanged = { [weak self] change in
       guard let self else { return }
       switch change {
@@ -216,6 +387,12 @@ public final class AppCoordinator {
       case .updateScheduleChanged:
         self.reloadDataFromSettings()
         self.cacheBootstrap.startAutoUpdate()
+      case .hotkeysChanged:
+        self.globalShortcut.updateBindings(self.settingsManager.settings.hotkeyBindings)
+      case .releaseChannelChanged:
+        Task { await self.checkForUpdates() }
+      case .directoryRefreshRequested(let path):
+        Task { await self.refreshDirectory(path: path) }
       }
     }
 
@@ -230,16 +407,119 @@ public final class AppCoordinator {
       self?.persistDismissedUpdateVersion(version)
     }
 
+    launcherVM.onInstallUpdate = { [weak self] in
+      guard let self else { return }
+      Task { await self.installUpdate() }
+    }
+
+    launcherVM.onConfirmTrash = { [weak self] result in
+      self?.moveToTrash(result, confirmed: true)
+    }
+    launcherVM.onUndoTrash = { [weak self] in
+      guard let self else { return }
+      Task { await self.undoMoveToTrash() }
+    }
+    launcherVM.onMoveToTrash = { [weak self] result in
+      self?.moveToTrash(result)
+    }
+    launcherVM.onCopyResult = { [weak self] result, format in
+      self?.copyResult(result, as: format)
+    }
+    launcherVM.onEnsureIcon = { [weak self] appPath in
+      guard let self else { return }
+      Task { await self.ensureIcon(forAppPath: appPath) }
+    }
+
     // メニューバーからのキャッシュ再構築要求は AppCoordinator の再構築フローに委譲する
     // （これによりスキャン結果が DB に保存され、ビューモデルへ再反映される）
     menuBarActions.onRebuildCache = { [weak self] in
       await self?.rebuildCacheAndReload()
     }
+    menuBarActions.onResetCache = { [weak self] in
+      guard let self else { return }
+      do {
+        try await self.resetCache()
+      } catch {
+        self.reportError(error, action: "Reset cache")
+      }
+    }
+    menuBarActions.cacheBootstrap = cacheBootstrap
+    menuBarActions.recentItemsProvider = { [weak self] limit in
+      self?.recentItems(limit: limit) ?? []
+    }
+    menuBarActions.onOpenRecentItem = { [weak self] result in
+      guard let self else { return }
+      Task {
+        let report = await self.launchItems([result])
+        if let error = report.error {
+          self.reportError(error, action: "Open recent item")
+        }
+      }
+    }
+
+    // 設定画面からのアクセシビリティ権限の要求と、権限の変化の反映
+    settingsViewModel.isAccessibilityTrusted = accessibilityMonitor.isTrusted
+    settingsViewModel.onRequestAccessibilityPermission = { [weak self] in
+      self?.requestAccessibilityPermission()
+    }
+    settingsViewModel.onOpenAccessibilitySettings = { [weak self] in
+      self?.accessibilityPermission.openSystemSettings()
+    }
+    accessibilityMonitor.onChange = { [weak self] trusted in
+      self?.settingsViewModel.isAccessibilityTrusted = trusted
+    }
+
+    // 設定画面の「診断」タブからの自己診断の要求
+    settingsViewModel.onRunDiagnostics = { [weak self] in
+      await self?.runDiagnostics()
+    }
+
+    // 設定画面からのアイコンキャッシュ削除の要求
+    settingsViewModel.onClearIconCache = { [weak self] in
+      await self?.clearIconCache()
+    }
+
+    // 設定画面・メニューバーからのキャッシュのリセットの要求
+    settingsViewModel.onResetCache = { [weak self] in
+      try await self?.resetCache()
+    }
+
+    // 設定画面からの設定のバックアップの復元の要求
+    settingsViewModel.onRestoreSettingsBackup = { [weak self] generation in
+      try await self?.restoreSettingsBackup(generation: generation)
+    }
+
+    // 設定画面からのディレクトリごとのエディタの変更・削除の要求
+    settingsViewModel.onSetDirectoryEditor = { [weak self] editor, path in
+      try self?.setDirectoryEditor(editor, forPath: path)
+    }
+
+    // 設定画面・メニューバーからのプロファイル切り替えの要求
+    settingsViewModel.onSwitchProfile = { [weak self] name in
+      try await self?.switchProfile(to: name)
+    }
+    settingsViewModel.onProfileDeleted = { [weak self] name in
+      try self?.removeProfileCache(named: name)
+    }
+    menuBarActions.onSwitchProfile = { [weak self] name in
+      guard let self else { return }
+      Task {
+        do {
+          try await self.switchProfile(to: name)
+        } catch {
+          self.reportError(error, action: "Switch profile")
+        }
+      }
+    }
 
     wm.onShowLauncher = { [weak self] in
       guard let self else { return }
       self.launcherViewModel.clearSearch()
-      self.windowManager.resizeForResults(count: 0)
+      // 最近のプロジェクトがあれば空クエリのまま即座に表示する
+      if !self.launcherViewModel.recentProjects.isEmpty {
+        self.launcherViewModel.updateSearch()
+      }
+      self.windowManager.resizeForResults(count: self.launcherViewModel.searchResults.count)
       self.launcherViewModel.focusTrigger += 1
     }
 
@@ -248,12 +528,27 @@ public final class AppCoordinator {
       self?.launcherViewModel.clearSearch()
     }
 
+    // 表示時に英数入力へ切り替えた入力ソースを、設定に応じて閉じたときに元へ戻す
+    wm.onHideLauncher = { [weak self] in
+      guard let self else { return }
+      if self.globalShortcut.activeIMEBehavior.restoresOnHide {
+        self.imeController.restoreInputSource()
+      } else {
+        self.imeController.discardSavedInputSource()
+      }
+    }
+
     // キーイベントモニターのハンドラ設定
     wm.onKeyEvent = { [weak self] event -> Bool in
       guard let self else { return false }
       return self.handleLauncherKeyEvent(event)
     }
 
+    // 追加ホットキー（モード別）の発火をモード起動へ委譲する
+    globalShortcut.onModeHotkey = { [weak self] mode, imeBehavior in
+      self?.activate(mode: mode, imeBehavior: imeBehavior)
+    }
+
     // ピッカーを全て閉じるコールバック（ショートカットでトグル時に使用）
     wm.onCloseAllPickers = { [weak self] in
       guard let self else { return }
@@ -261,6 +556,13 @@ public final class AppCoordinator {
       self.terminalPickerPanel.dismiss()
       self.emojiPickerPanel.dismissPanel()
     }
+
+    // ユーザーが移動したランチャーの位置を設定へ永続化する
+    wm.onPositionChanged = { [weak self] position in
+      self?.persistWindowPosition(position)
+    }
+    applyWindowPositionSettings()
+    applyAppearanceSettings()
   }
 
   // MARK: - ライフサイクル
@@ -282,6 +584,24 @@ public final class AppCoordinator {
     } catch {
       Self.logger.error("Failed to load settings: \(error.localizedDescription)")
     }
+    settingsViewModel.logFile.level = settingsManager.settings.logLevel
+    applyActivationPolicy()
+    Task { await resolveFileOpenerApplications() }
+
+    // 使用中のプロファイルのキャッシュを読み書きする
+    do {
+      try cacheDatabase.selectPartition(
+        SettingsProfile.cachePartitionName(for: settingsManager.settings.activeProfile))
+    } catch {
+      Self.logger.error("Failed to open profile cache: \(error.localizedDescription)")
+    }
+
+    // ログイン時起動の設定とシステムの登録状態を同期する
+    do {
+      try settingsViewModel.syncLaunchAtLogin()
+    } catch {
+      Self.logger.error("Failed to sync launch at login: \(error.localizedDescription)")
+    }
 
     // 2. 選択履歴を読み込む
     do {
@@ -291,7 +611,8 @@ public final class AppCoordinator {
       Self.logger.error("Failed to load selection history: \(error.localizedDescription)")
     }
 
-    // 3. グローバルショートカットを設定する
+    // 3. グローバルショートカットを設定する（追加バインディングも同時に登録）
+    globalShortcut.updateBindings(settingsManager.settings.hotkeyBindings)
     globalShortcut.setup()
     Self.logger.info("Global shortcut registered")
 
@@ -310,12 +631,30 @@ public final class AppCoordinator {
     // 6. アップデートを確認する
     await checkForUpdates()
 
-    // 7. 必要なら自動更新タイマーを開始する
+    // 7. 必要なら自動更新タイマーを開始する（スリープから復帰したら予定を決め直す）
     cacheBootstrap.startAutoUpdate()
+    cacheBootstrap.startObservingWake()
+
+    // 8. ignitero CLI からのリクエストの待ち受けを開始する
+    startIPCServer()
+
+    // 9. 設定ファイルの手動編集・同期による変更の監視を開始する
+    startSettingsFileWatcher()
+
+    // 10. アクセシビリティ権限の変化の監視を開始する（未許可なら設定画面で許可を案内する）
+    accessibilityMonitor.start()
+    if !accessibilityMonitor.isTrusted {
+      Self.logger.warning("Accessibility permission is not granted")
+    }
 
     // 起動完了
     isReady = true
     Self.logger.info("App coordinator started")
+
+    // 起動中に受け取った ignitero:// URL を処理する
+    let deepLinks = pendingDeepLinks
+    pendingDeepLinks = []
+    deepLinks.forEach(handleDeepLink)
   }
 
   /// アプリケーション終了時のクリーンアップを実行する。
@@ -327,6 +666,16 @@ public final class AppCoordinator {
 
     // 自動更新を停止する
     cacheBootstrap.stopAutoUpdate()
+    cacheBootstrap.stopObservingWake()
+
+    // CLI の待ち受けを停止する
+    ipcServer?.stop()
+
+    // 設定ファイルの監視を停止する
+    settingsFileWatcher?.stop()
+
+    // アクセシビリティ権限の監視を停止する
+    accessibilityMonitor.stop()
 
     // 選択履歴を保存する
     do {
@@ -351,18 +700,28 @@ public final class AppCoordinator {
   ///
   /// 結果の種別に応じてアプリ起動、ディレクトリオープン、コマンド実行を行い、
   /// app/directory/command の選択履歴を記録してランチャーを非表示にする。
-  /// Web検索・Emoji・カラーピッカーは一過性アクションのため履歴に記録しない。
+  /// Web検索・システム設定・システム操作・ウィンドウ操作・Emoji・記号・カラーピッカーは
+  /// 一過性アクションのため履歴に記録しない。
   /// - Parameter result: 実行する検索結果
   public func executeResult(_ result: SearchResult) {
+    // 前回の操作のエラーは新しい操作を始めた時点で消す
+    launcherViewModel.dismissError()
+
     // 即時アクション（Web検索・Emoji・カラーピッカー）はアプリがアクティブなうちに同期実行し、
     // 履歴には記録せず早期 return する。
     // これらは path が空または一過性の URL で、検索結果（appsByPath/dirsByPath/commandsByIdentifier）
     // へ復元できないため履歴ブースト・最近使った項目に出ない。記録すると keyword ごとに別エントリが
     // 際限なく蓄積し、最大 50 件の履歴枠を圧迫して正規のアプリ/ディレクトリ履歴を押し出してしまう。
     switch result.kind {
-    case .webSearch:
+    case .webSearch, .systemSettings:
       if let url = URL(string: result.path) {
-        NSWorkspace.shared.open(url)
+        Task {
+          do {
+            try await launchService.openURL(url)
+          } catch {
+            reportError(error, action: "Open URL")
+          }
+        }
       }
       dismissLauncher()
       return
@@ -370,6 +729,42 @@ public final class AppCoordinator {
       dismissLauncher()
       showEmojiPicker()
       return
+    case .character:
+      clipboard.copy(result.path)
+      dismissLauncher()
+      return
+    case .process:
+      terminateProcess(result, force: false)
+      return
+    case .systemControl:
+      dismissLauncher()
+      if let control = SystemControl(identifier: result.path) {
+        Task {
+          do {
+            try await systemControlService.perform(control)
+          } catch {
+            reportError(error, action: "System control")
+          }
+        }
+      }
+      return
+    case .windowLayout:
+      // 操作対象は直前のアプリなので、そのアプリを前面に戻してからウィンドウを動かす
+      let target = previousApplication?.processIdentifier
+      dismissLauncher(restoringFocus: true)
+      guard let action = WindowLayoutAction(identifier: result.path) else { return }
+      guard let target else {
+        reportError(WindowLayoutError.noFocusedWindow, action: "Arrange window")
+        return
+      }
+      Task {
+        do {
+          try await windowArranger.arrange(action, processIdentifier: target)
+        } catch {
+          reportError(error, action: "Arrange window")
+        }
+      }
+      return
     case .colorPicker:
       dismissLauncher()
       Task {
@@ -381,50 +776,258 @@ public final class AppCoordinator {
       break
     }
 
-    // 選択履歴を記録する（履歴で復元可能な app/directory/command のみ）。
-    // 検索時の比較（applyHistoryBoost）と同じ正規化を施して保存する。
-    // 生クエリのまま保存すると "Xcode" と正規化済みクエリ "xcode" が一致せず履歴ブーストが効かない。
-    selectionHistory.record(
-      keyword: SearchQueryNormalizer.normalize(launcherViewModel.searchQuery),
-      path: result.path
-    )
+    if result.kind == .command, let directory = launcherViewModel.commandTargetDirectory {
+      runCommand(result, in: directory)
+      return
+    }
 
-    // ViewModel 側の履歴も即時更新する
-    launcherViewModel.history = selectionHistory.allEntries
+    recordSelection(result)
 
     // 非同期アクション（アプリ、ディレクトリ、コマンド）を実行する
     Task {
       do {
-        switch result.kind {
-        case .app:
-          try await launchService.launchApp(at: result.path)
-        case .directory:
-          let editorType =
-            result.editor.flatMap { EditorType(rawValue: $0) }
-            ?? settingsManager.settings.defaultEditor
-          Self.logger.info(
-            "Open directory: result.editor=\(result.editor ?? "nil", privacy: .public), defaultEditor=\(self.settingsManager.settings.defaultEditor.rawValue, privacy: .public), resolved=\(editorType.rawValue, privacy: .public)"
-          )
-          try await launchService.openDirectory(result.path, editor: editorType)
-        case .command:
-          if let command = result.command {
-            let terminal = settingsManager.settings.defaultTerminal
-            try await launchService.executeCommand(
-              command,
-              workingDirectory: result.workingDirectory,
-              terminal: terminal
-            )
-          }
-        case .webSearch, .emoji, .colorPicker:
-          break
-        }
+        try await launch(result)
+      } catch {
+        reportError(error, action: "Execute result")
+      }
+    }
+
+    // 検索状態をクリアしてランチャーを閉じる（閉じない設定では続けて起動できるよう残す）
+    dismissLauncherAfterLaunch()
+  }
+
+  /// Xcode のプロジェクトがあるディレクトリを Xcode で開く（設定したエディタは変えない）。
+  func openInXcode(_ result: SearchResult) {
+    recordSelection(result)
+    Task {
+      do {
+        try await launchService.openDirectory(result.path, editor: .xcode)
+        recordDirectoryOpen(result.path, editor: .xcode)
       } catch {
-        Self.logger.error("Failed to execute result: \(error.localizedDescription)")
+        reportError(error, action: "Open in Xcode")
       }
     }
+    dismissLauncherAfterLaunch()
+  }
+
+  /// 「ここでコマンドを実行」で選んだコマンドを、既定のターミナルでディレクトリを開いて実行する。
+  ///
+  /// 入力したコマンドそのもの（path が空）は選択履歴に記録しない。
+  /// - Parameters:
+  ///   - result: カスタムコマンドまたは入力したコマンド
+  ///   - directory: コマンドを実行するディレクトリ
+  private func runCommand(_ result: SearchResult, in directory: String) {
+    guard let command = result.command else { return }
+    if !result.path.isEmpty {
+      recordSelection(result)
+    }
+    let terminal = settingsManager.settings.defaultTerminal
+    Task {
+      do {
+        try await launchService.executeCommand(
+          command, workingDirectory: directory, terminal: terminal)
+      } catch {
+        reportError(error, action: "Run command in directory")
+      }
+    }
+    dismissLauncherAfterLaunch()
+  }
 
-    // 検索状態をクリアしてランチャーを閉じる
+  /// 検索結果のパスやコマンドを、ランチャーを開く前に使っていたアプリへ貼り付ける。
+  ///
+  /// ランチャーを閉じてから元のアプリを前面に戻し、⌘V を送る。
+  /// 貼り付けできない種別（Emoji ピッカー・カラーピッカー・プロセス）は何もしない。
+  /// - Parameter result: 貼り付ける検索結果
+  public func pasteResult(_ result: SearchResult) {
+    guard let text = PasteService.text(for: result) else { return }
+    launcherViewModel.dismissError()
+    let target = previousApplication?.processIdentifier
     dismissLauncher()
+    Task {
+      do {
+        try await pasteService.paste(text, into: target)
+      } catch {
+        reportError(error, action: "Paste result")
+      }
+    }
+  }
+
+  /// 複数選択した結果をまとめて起動し、ランチャーを閉じる。
+  ///
+  /// 失敗した項目があればまとめてエラーバナーに表示する。
+  /// 閉じると検索語が消えるため、選択履歴の検索語は閉じる前に取り出しておく。
+  public func executeMarkedResults() {
+    launcherViewModel.dismissError()
+    let results = launcherViewModel.markedResults
+    let query = launcherViewModel.searchQuery
+    dismissLauncherAfterLaunch()
+    Task {
+      let report = await launchItems(results, query: query)
+      if let error = report.error {
+        reportError(error, action: "Launch \(report.failures.count) of \(results.count) items")
+      }
+    }
+  }
+
+  /// 複数の検索結果を順に起動する。
+  ///
+  /// 途中で失敗しても残りの起動を続け、項目ごとの失敗を返す。
+  /// app/directory/command 以外の結果は対象外として無視する。
+  /// - Parameters:
+  ///   - results: 起動する検索結果
+  ///   - query: 選択履歴に記録する検索語（nil の場合は今の検索語）
+  /// - Returns: 起動できた項目と失敗した項目
+  @discardableResult
+  public func launchItems(
+    _ results: [SearchResult], query: String? = nil
+  ) async -> BatchLaunchReport {
+    var launchedPaths: [String] = []
+    var failures: [BatchLaunchFailure] = []
+    for result in results where [.app, .directory, .command].contains(result.kind) {
+      recordSelection(result, query: query)
+      do {
+        try await launch(result)
+        launchedPaths.append(result.path)
+      } catch {
+        Self.logger.warning(
+          "Batch launch failed: \(result.path): \(error.localizedDescription)")
+        failures.append(
+          BatchLaunchFailure(name: result.name, path: result.path, error: LauncherError(error)))
+      }
+    }
+    Self.logger.info(
+      "Batch launch: launched=\(launchedPaths.count), failed=\(failures.count)")
+    return BatchLaunchReport(launchedPaths: launchedPaths, failures: failures)
+  }
+
+  /// バンドルが存在しないアプリをキャッシュから削除し、残りのアプリを返す。
+  ///
+  /// アンインストール済みのアプリが次のスキャンまで検索結果に残り、起動に失敗するのを防ぐ。
+  /// 存在確認はアプリ数分の stat のみのため、バックグラウンドでまとめて行う。
+  private func pruningMissingApps(_ apps: [AppItem]) async -> [AppItem] {
+    let fileSystem = fileSystem
+    let missing = await Task.detached(priority: .utility) {
+      Set(apps.lazy.map(\.path).filter { !fileSystem.fileExists(atPath: $0) })
+    }.value
+    guard !missing.isEmpty else { return apps }
+    do {
+      try cacheDatabase.deleteApps(paths: Array(missing))
+      Self.logger.info("Pruned \(missing.count) missing apps from cache")
+    } catch {
+      Self.logger.error("Failed to prune missing apps: \(error.localizedDescription)")
+    }
+    return apps.filter { !missing.contains($0.path) }
+  }
+
+  /// 起動時に見つからなかったアプリをキャッシュと検索対象から除き、検索し直す。
+  private func removeMissingApps(_ paths: [String]) async {
+    Self.logger.warning("App no longer exists, removing from cache: \(paths)")
+    do {
+      try cacheDatabase.deleteApps(paths: paths)
+    } catch {
+      Self.logger.error("Failed to remove missing apps: \(error.localizedDescription)")
+    }
+    let removed = Set(paths)
+    launcherViewModel.apps.removeAll { removed.contains($0.path) }
+    launcherViewModel.updateSearch()
+    windowManager.resizeForResults(count: launcherViewModel.searchResults.count)
+  }
+
+  /// 最近起動した項目を新しい順に返す（メニューバーの「最近使った項目」用）。
+  ///
+  /// 同じ項目を別のキーワードで選んだ履歴は 1 件にまとめ、インデックスにない項目は除く。
+  /// - Parameter limit: 最大件数
+  public func recentItems(limit: Int) -> [SearchResult] {
+    let index = launcherViewModel.searchIndex
+    var seen = Set<String>()
+    var results: [SearchResult] = []
+    for entry in selectionHistory.allEntries.sorted(by: { $0.lastUsed > $1.lastUsed }) {
+      guard results.count < limit else { break }
+      guard seen.insert(entry.selectedPath).inserted,
+        let result = index.indexedResult(for: entry.selectedPath)
+      else { continue }
+      results.append(result)
+    }
+    return results
+  }
+
+  /// 選択履歴を記録する（履歴で復元可能な app/directory/command のみ）。
+  ///
+  /// 検索時の比較（applyHistoryBoost）と同じ正規化を施して保存する。
+  /// 生クエリのまま保存すると "Xcode" と正規化済みクエリ "xcode" が一致せず履歴ブーストが効かない。
+  /// - Parameter query: 記録する検索語（nil の場合は今の検索語）
+  private func recordSelection(_ result: SearchResult, query: String? = nil) {
+    let keyword = SearchQueryNormalizer.normalize(query ?? launcherViewModel.searchQuery)
+    selectionHistory.record(keyword: keyword, path: result.path)
+    recordQuery(keyword, selectedPath: result.path)
+
+    // ViewModel 側の履歴も即時更新する
+    launcherViewModel.history = mergedHistory()
+  }
+
+  /// 検索語と選んだ結果をキャッシュ DB の検索履歴に記録する（空の検索語は記録しない）。
+  private func recordQuery(_ query: String, selectedPath: String) {
+    guard !query.isEmpty else { return }
+    do {
+      try cacheDatabase.recordQuery(query, selectedPath: selectedPath)
+    } catch {
+      Self.logger.error("Failed to record query: \(error.localizedDescription)")
+      return
+    }
+    if let index = queryHistory.firstIndex(where: {
+      $0.keyword == query && $0.selectedPath == selectedPath
+    }) {
+      queryHistory[index].count += 1
+      queryHistory[index].lastUsed = Date()
+    } else {
+      queryHistory.insert(SelectionHistoryEntry(keyword: query, selectedPath: selectedPath), at: 0)
+    }
+  }
+
+  /// 選択履歴に、キャッシュ DB の検索履歴だけにある組み合わせを加えた履歴を返す。
+  ///
+  /// 選択履歴は件数の上限で古い選択から消えるため、検索履歴で補って
+  /// よく選ぶ結果を短い検索語でも上位に出す。同じ検索語と結果の組み合わせは
+  /// 二重に数えないよう選択履歴の記録を優先する。
+  private func mergedHistory() -> [SelectionHistoryEntry] {
+    let entries = selectionHistory.allEntries
+    let recorded = Set(
+      entries.map { "\(SearchQueryNormalizer.normalize($0.keyword))\n\($0.selectedPath)" })
+    return entries
+      + queryHistory.filter { !recorded.contains("\($0.keyword)\n\($0.selectedPath)") }
+  }
+
+  /// アプリ起動・ディレクトリオープン・コマンド実行のいずれかを行う。
+  private func launch(_ result: SearchResult) async throws {
+    switch result.kind {
+    case .app:
+      guard fileSystem.fileExists(atPath: result.path) else {
+        // アンインストール済みのアプリはキャッシュから除き、表示中の結果を検索し直す
+        await removeMissingApps([result.path])
+        throw LauncherError.notFound(result.path)
+      }
+      try await launchService.launchApp(at: result.path)
+    case .directory:
+      let editorType = directoryEditor(
+        for: result.path, configured: result.editor, hasXcodeProject: result.xcodeProject != nil)
+      Self.logger.info(
+        "Open directory: result.editor=\(result.editor ?? "nil"), remembered=\(self.directoryEditors[result.path]?.rawValue ?? "nil"), defaultEditor=\(self.settingsManager.settings.defaultEditor.rawValue), resolved=\(editorType.rawValue)"
+      )
+      try await launchService.openDirectory(result.path, editor: editorType)
+      recordDirectoryOpen(result.path, editor: editorType)
+    case .command:
+      if let command = result.command {
+        let terminal = settingsManager.settings.defaultTerminal
+        try await launchService.executeCommand(
+          command,
+          workingDirectory: result.workingDirectory,
+          terminal: terminal
+        )
+      }
+    case .webSearch, .emoji, .colorPicker, .character, .process, .systemSettings,
+      .systemControl, .windowLayout:
+      break
+    }
   }
 
   /// ランチャーパネルのキーダウンイベントを処理する。
@@ -446,7 +1049,12 @@ public final class AppCoordinator {
       HapticService.selectionChanged()
       return true
     case 53:  // Escape
-      dismissLauncher()
+      // コマンドを選ぶモード中は通常の検索に戻る
+      if launcherViewModel.endRunCommand() {
+        windowManager.resizeForResults(count: launcherViewModel.searchResults.count)
+        return true
+      }
+      dismissLauncher(restoringFocus: true)
       return true
     case 123:  // Left arrow
       Self.logger.debug(
@@ -470,6 +1078,65 @@ public final class AppCoordinator {
       }
       Self.logger.debug("Right arrow: no action (no directory selected?)")
       return false
+    case 4 where event.modifierFlags.contains([.command, .shift]):  // Cmd+Shift+H
+      guard let result = launcherViewModel.confirmSelection(),
+        [.app, .directory, .command].contains(result.kind)
+      else { return false }
+      hideItem(path: result.path)
+      return true
+    case 51 where event.modifierFlags.contains(.command):  // Cmd+Delete
+      // 検索語がある間は検索フィールドの行頭まで削除に任せる。キー操作では設定に関わらず
+      // 確認を表示し、確認のボタンを押したときだけ入れる
+      guard launcherViewModel.searchQuery.isEmpty,
+        let result = launcherViewModel.confirmSelection(), result.kind == .directory
+      else { return false }
+      launcherViewModel.requestTrashConfirmation(for: result)
+      return true
+    case 6 where event.modifierFlags.contains(.command):  // Cmd+Z
+      // 元に戻せる項目がなければ検索フィールドの取り消しに任せる
+      guard launcherViewModel.trashUndo != nil else { return false }
+      Task { await undoMoveToTrash() }
+      return true
+    case 8 where event.modifierFlags.contains(.command):  // Cmd+C
+      // 修飾キーで形式を選ぶ。Cmd+C だけなら検索フィールドのコピーに任せる
+      let format: ClipboardCopyFormat
+      if event.modifierFlags.contains(.shift) {
+        format = .path
+      } else if event.modifierFlags.contains(.option) {
+        format = .fileURL
+      } else if event.modifierFlags.contains(.control) {
+        format = .name
+      } else {
+        return false
+      }
+      guard let result = launcherViewModel.confirmSelection(), format.text(for: result) != nil
+      else { return false }
+      copyResult(result, as: format)
+      return true
+    case 36 where event.modifierFlags.contains(.option):  // Option+Enter
+      guard let result = launcherViewModel.confirmSelection() else { return false }
+      pasteResult(result)
+      return true
+    case 36 where event.modifierFlags.contains(.command):  // Cmd+Enter
+      guard let result = launcherViewModel.confirmSelection() else { return false }
+      if result.kind == .process {
+        terminateProcess(result, force: true)
+        return true
+      }
+      guard result.kind == .directory, result.xcodeProject != nil else { return false }
+      openInXcode(result)
+      return true
+    case 48:  // Tab
+      guard launcherViewModel.toggleMark() else { return false }
+      HapticService.selectionChanged()
+      return true
+    case 2 where event.modifierFlags.contains(.command):  // Cmd+D
+      guard let result = launcherViewModel.confirmSelection(),
+        result.kind == .app || result.kind == .directory
+      else { return false }
+      toggleFavorite(path: result.path)
+      HapticService.selectionChanged()
+      return true
     default:
       return false
     }
@@ -479,11 +1146,13 @@ public final class AppCoordinator {
   private func handleSpecialKeyAction(_ action: SpecialKeyAction) {
     switch action {
     case .dismiss:
-      dismissLauncher()
+      dismissLauncher(restoringFocus: true)
     case .execute:
       if let result = launcherViewModel.confirmSelection() {
         executeResult(result)
       }
+    case .executeMarked:
+      executeMarkedResults()
     case .copyCalculator:
       launcherViewModel.copyCalculatorResult()
     case .openInTerminal:
@@ -493,47 +1162,217 @@ public final class AppCoordinator {
     case .showEditorPicker:
       if let result = launcherViewModel.confirmSelection() {
         let currentEditor = result.editor.flatMap { EditorType(rawValue: $0) }
-        showEditorPicker(for: result.path, currentEditor: currentEditor)
+        showEditorPicker(
+          for: result.path, currentEditor: currentEditor, projectType: result.projectType)
       }
     case .showTerminalPicker:
       if let result = launcherViewModel.confirmSelection() {
         showTerminalPicker(for: result.path)
       }
+    case .runCommandHere:
+      if let result = launcherViewModel.confirmSelection() {
+        launcherViewModel.beginRunCommand(in: result.path)
+        windowManager.resizeForResults(count: launcherViewModel.searchResults.count)
+      }
     }
   }
 
-  /// ランチャーを非表示にし、検索状態をクリアする。
-  public func dismissLauncher() {
-    launcherViewModel.clearSearch()
-    windowManager.resizeForResults(count: 0)
-    windowManager.hideLauncher()
-  }
-
-  /// カラーピッカーを表示し、選択色の HEX をクリップボードにコピーする。
-  private func showColorPicker() {
-    NSColorSampler().show { selectedColor in
-      guard let selectedColor else { return }
-      guard let color = selectedColor.usingColorSpace(.sRGB) else { return }
-      let r = Int(color.redComponent * 255)
-      let g = Int(color.greenComponent * 255)
-      let b = Int(color.blueComponent * 255)
-      let hex = String(format: "#%02X%02X%02X", r, g, b)
-      Task { @MainActor in
-        NSPasteboard.general.clearContents()
-        NSPasteboard.general.setString(hex, forType: .string)
+  /// 追加ホットキーに割り当てられたモードでランチャーを起動する。
+  ///
+  /// 検索対象を絞り込むモードは同じモードで表示中なら閉じる（トグル動作）。
+  /// - Parameters:
+  ///   - mode: 起動するモード
+  ///   - imeBehavior: 入力ソースの扱い（nil は設定の `window_behavior.ime_behavior` に従う）
+  public func activate(mode: LauncherMode, imeBehavior: IMEBehavior? = nil) {
+    if let scope = mode.searchScope {
+      if windowManager.isLauncherVisible, launcherViewModel.scopeOverride == scope {
+        dismissLauncher()
+        return
+      }
+      if !windowManager.isLauncherVisible || windowManager.isPickerVisible {
+        windowManager.toggleLauncher()
       }
+      // showLauncher 時の clearSearch 後に絞り込み対象を設定する
+      launcherViewModel.scopeOverride = scope
+      launcherViewModel.updateSearch()
+      windowManager.resizeForResults(count: launcherViewModel.searchResults.count)
+      globalShortcut.applyIMEBehavior(imeBehavior)
+      return
     }
-  }
 
-  /// Emoji ピッカーを表示し、選択された絵文字をクリップボードにコピーする。
-  private func showEmojiPicker() {
+    switch mode {
+    case .launcher:
+      windowManager.toggleLauncher()
+      if windowManager.isLauncherVisible {
+        globalShortcut.applyIMEBehavior(imeBehavior)
+      }
+    case .emoji:
+      if windowManager.isLauncherVisible {
+        dismissLauncher()
+      }
+      showEmojiPicker()
+    case .colorPicker:
+      if windowManager.isLauncherVisible {
+        dismissLauncher()
+      }
+      showColorPicker()
+    case .apps, .directories, .commands:
+      break
+    }
+  }
+
+  /// 選択されたプロセスを終了させ、ランチャーを閉じる。
+  ///
+  /// - Parameters:
+  ///   - result: プロセスの検索結果
+  ///   - force: `true` の場合は SIGKILL で強制終了する
+  func terminateProcess(_ result: SearchResult, force: Bool) {
+    guard let process = result.process else { return }
+    do {
+      try processManager.terminate(pid: process.pid, force: force)
+      Self.logger.info(
+        "Terminated process \(process.pid) (\(process.name)), force=\(force)")
+    } catch {
+      reportError(error, action: "Terminate process")
+    }
+    dismissLauncher()
+  }
+
+  /// ディレクトリをゴミ箱に入れ、検索対象とキャッシュから除く。
+  ///
+  /// `confirm_move_to_trash` が有効な場合は確認を表示し、確認のボタンを押したときに入れる
+  /// （同じ操作を繰り返しても確定しない）。入れた後は `trashUndoInterval` の間だけ元に戻せる。
+  /// - Parameters:
+  ///   - result: ゴミ箱に入れる結果（ディレクトリ以外は何もしない）
+  ///   - confirmed: 確認のボタンが押されたか（確認の設定に関わらず入れる）
+  public func moveToTrash(_ result: SearchResult, confirmed: Bool = false) {
+    guard result.kind == .directory else { return }
+    if settingsManager.settings.confirmsMoveToTrash, !confirmed {
+      launcherViewModel.requestTrashConfirmation(for: result)
+      return
+    }
+    launcherViewModel.cancelTrashConfirmation()
+
+    let item: TrashedItem
+    do {
+      item = try trashService.moveToTrash(path: result.path)
+    } catch {
+      reportError(error, action: "Move to trash")
+      return
+    }
+    Self.logger.info("Moved to trash: \(result.path)")
+    removeTrashedResult(result)
+
+    launcherViewModel.showTrashUndo(item)
+    let interval = Self.trashUndoInterval
+    Task { [weak self] in
+      try? await Task.sleep(for: interval)
+      self?.launcherViewModel.dismissTrashUndo(item)
+    }
+  }
+
+  /// 直前にゴミ箱に入れた項目を元の場所へ戻し、キャッシュを更新して検索対象に戻す。
+  public func undoMoveToTrash() async {
+    guard let item = launcherViewModel.trashUndo else { return }
+    launcherViewModel.dismissTrashUndo()
+    do {
+      try trashService.restore(item)
+    } catch {
+      reportError(error, action: "Restore from trash")
+      return
+    }
+    Self.logger.info("Restored from trash: \(item.originalPath)")
+    await rebuildCacheAndReload()
+  }
+
+  /// 結果のパス・`file://` URL・名前をクリップボードにコピーし、ランチャーを閉じる。
+  ///
+  /// クリップボードへはランチャーを閉じる前に書き込むため、閉じた直後に貼り付けられる。
+  /// - Parameters:
+  ///   - result: コピーする結果
+  ///   - format: コピーする形式（この形式でコピーできない結果では何もしない）
+  public func copyResult(_ result: SearchResult, as format: ClipboardCopyFormat) {
+    guard let text = format.text(for: result) else { return }
+    clipboard.copy(text)
+    Self.logger.info("Copied \(format.rawValue) of \(result.name) to clipboard")
+    HapticService.confirmed()
+    dismissLauncher()
+  }
+
+  /// ゴミ箱に入れたディレクトリ（配下も）を検索対象とキャッシュから除く。
+  private func removeTrashedResult(_ result: SearchResult) {
+    let path = result.path
+    do {
+      try cacheDatabase.deleteDirectories(paths: [path])
+    } catch {
+      Self.logger.error("Failed to remove trashed item from cache: \(error.localizedDescription)")
+    }
+    launcherViewModel.updateDataSources {
+      launcherViewModel.apps.removeAll { $0.path == path }
+      launcherViewModel.directories.removeAll { $0.path == path || $0.path.hasPrefix(path + "/") }
+    }
+    launcherViewModel.updateSearch()
+    windowManager.resizeForResults(count: launcherViewModel.searchResults.count)
+  }
+
+  /// 操作の失敗をエラーコード付きでログに記録し、ランチャーのエラーバナーに表示する。
+  ///
+  /// 失敗はランチャーを閉じた後に起きることが多いため、次に開いたときに表示される。
+  /// - Parameters:
+  ///   - error: サービスが投げたエラー
+  ///   - action: ログに記録する操作名
+  func reportError(_ error: any Error, action: String) {
+    let launcherError = LauncherError(error)
+    Self.logger.error(
+      "\(action) failed [\(launcherError.code)]: \(error.localizedDescription)"
+    )
+    launcherViewModel.showError(launcherError)
+  }
+
+  /// ランチャーを非表示にし、検索状態をクリアする。
+  ///
+  /// - Parameter restoringFocus: ランチャーを開く前のアプリを前面に戻すか（Escape で閉じた場合など）
+  public func dismissLauncher(restoringFocus: Bool = false) {
+    launcherViewModel.clearSearch()
+    windowManager.resizeForResults(count: 0)
+    windowManager.hideLauncher(restoringFocus: restoringFocus)
+  }
+
+  /// 項目の起動後に、設定で有効な場合だけランチャーを閉じる。
+  private func dismissLauncherAfterLaunch() {
+    guard settingsManager.settings.windowBehavior.hideAfterLaunch else { return }
+    dismissLauncher()
+  }
+
+  /// ランチャーを開く前に最前面だったアプリ（貼り付け先・フォーカスの復元先）
+  public var previousApplication: NSRunningApplication? {
+    windowManager.previousApplication
+  }
+
+  /// カラーピッカーを表示し、選択色の HEX をクリップボードにコピーする。
+  private func showColorPicker() {
+    let clipboard = self.clipboard
+    NSColorSampler().show { selectedColor in
+      guard let selectedColor else { return }
+      guard let color = selectedColor.usingColorSpace(.sRGB) else { return }
+      let r = Int(color.redComponent * 255)
+      let g = Int(color.greenComponent * 255)
+      let b = Int(color.blueComponent * 255)
+      let hex = String(format: "#%02X%02X%02X", r, g, b)
+      Task { @MainActor [clipboard] in
+        clipboard.copy(hex)
+      }
+    }
+  }
+
+  /// Emoji ピッカーを表示し、選択された絵文字をクリップボードにコピーする。
+  private func showEmojiPicker() {
     // 他ピッカー（Editor/Terminal）と同様に isPickerVisible を立てる。
     // これを怠ると emoji 表示中の Option+Space で toggleLauncher が onCloseAllPickers を
     // 呼ばず、emoji パネルが残ったままランチャーが二重表示される。
     windowManager.showPicker()
-    emojiPickerPanel.show { emoji in
-      NSPasteboard.general.clearContents()
-      NSPasteboard.general.setString(emoji, forType: .string)
+    emojiPickerPanel.show { [clipboard] emoji in
+      clipboard.copy(emoji)
       HapticService.confirmed()
     }
     emojiPickerPanel.onDismiss = { [weak self] in
@@ -553,7 +1392,7 @@ public final class AppCoordinator {
       do {
         try await launchService.openInTerminal(path, terminal: terminal)
       } catch {
-        Self.logger.error("Failed to open in terminal: \(error.localizedDescription)")
+        reportError(error, action: "Open in terminal")
       }
     }
     dismissLauncher()
@@ -564,16 +1403,26 @@ public final class AppCoordinator {
   /// Tauri と同様のフロー: ランチャーを隠す → ピッカーを最前面に表示。
   /// - Parameters:
   ///   - directoryPath: 開くディレクトリのパス
-  ///   - currentEditor: 初期選択するエディタ（ディレクトリに紐づくエディタ）
-  public func showEditorPicker(for directoryPath: String, currentEditor: EditorType? = nil) {
+  ///   - currentEditor: 登録ディレクトリに設定したエディタ
+  public func showEditorPicker(
+    for directoryPath: String, currentEditor: EditorType? = nil, projectType: ProjectType? = nil
+  ) {
     let editors = launchService.availableEditors().filter { $0.installed }
     let frame = launcherPanel.frame
 
-    // 既定選択: ディレクトリに紐づくエディタ → 設定の既定エディタの順でフォールバックする
+    // 既定選択: 記憶したエディタ → プロジェクトの種類に適したエディタ（インストール済みの場合）
+    // → ディレクトリに紐づくエディタ → 設定の既定エディタの順でフォールバックする
+    let remembered =
+      settingsManager.settings.remembersDirectoryEditor ? directoryEditors[directoryPath] : nil
+    let preferred = projectType?.preferredEditor.flatMap { preferred in
+      editors.contains { $0.id == preferred } ? preferred : nil
+    }
+    let initialEditor =
+      remembered ?? preferred
+      ?? directoryEditor(for: directoryPath, configured: currentEditor?.rawValue)
     let defaultIndex =
-      currentEditor.flatMap { editor in
-        editors.firstIndex { $0.id == editor }
-      } ?? editors.firstIndex { $0.id == settingsManager.settings.defaultEditor }
+      editors.firstIndex { $0.id == initialEditor }
+      ?? editors.firstIndex { $0.id == settingsManager.settings.defaultEditor }
 
     // ピッカーを先に表示し、キーウィンドウを維持する
     windowManager.showPicker()
@@ -590,8 +1439,12 @@ public final class AppCoordinator {
       Task {
         do {
           try await self.launchService.openDirectory(directoryPath, editor: editor)
+          self.recordDirectoryOpen(directoryPath, editor: editor)
+          self.rememberDirectoryEditor(
+            editor, forPath: directoryPath,
+            fallback: currentEditor ?? self.settingsManager.settings.defaultEditor)
         } catch {
-          Self.logger.error("Failed to open in editor: \(error.localizedDescription)")
+          self.reportError(error, action: "Open in editor")
         }
       }
       // エディタ確定時はランチャーの検索もクリアする
@@ -632,8 +1485,7 @@ public final class AppCoordinator {
         do {
           try await self.launchService.openInTerminal(directoryPath, terminal: terminal)
         } catch {
-          Self.logger.error(
-            "Failed to open in terminal picker: \(error.localizedDescription)")
+          self.reportError(error, action: "Open in terminal picker")
         }
       }
       self.windowManager.hidePicker()
@@ -650,14 +1502,208 @@ public final class AppCoordinator {
   ///
   /// 設定画面でディレクトリやコマンドが変更された際に呼び出す。
   public func reloadDataFromSettings() {
-    launcherViewModel.commands = settingsManager.settings.customCommands
+    launcherViewModel.updateDataSources {
+      applySettingsDataSources()
+    }
     launcherViewModel.defaultEditorRawValue = settingsManager.settings.defaultEditor.rawValue
+    applyDirectoryEditors()
     let terminalType = settingsManager.settings.defaultTerminal
     launcherViewModel.defaultTerminalName = LaunchService.displayName(for: terminalType)
+    launcherViewModel.language = settingsManager.settings.language.resolved()
+    applyWindowPositionSettings()
+    applyAppearanceSettings()
+    applyActivationPolicy()
+    Task { await resolveFileOpenerApplications() }
+
+    // 全文検索の切り替え時はディレクトリの読み込み方が変わるためキャッシュから読み直す
+    if (fullTextDirectorySearcher() != nil) != (launcherViewModel.directorySearcher != nil) {
+      Task { await loadCacheDataIntoViewModel() }
+    }
+    // 最近のプロジェクトの件数設定を反映する（0 件にした場合は表示を消す）
+    Task { await refreshRecentProjects() }
+  }
+
+  /// `file_openers` のアプリの場所をバックグラウンドで探し直し、開くときに使えるようにしておく。
+  ///
+  /// 設定の保存（再読込）のたびに呼ばれ、開くたびにファイルシステムを探さずに済むようにする。
+  func resolveFileOpenerApplications() async {
+    let applications = Set(
+      settingsManager.settings.fileOpeners.filter(\.isValid).map(\.application))
+    let resolved = await Task.detached(priority: .utility) {
+      Dictionary(
+        uniqueKeysWithValues: applications.map { ($0, LaunchService.applicationURL(for: $0)) })
+    }.value
+    fileOpenerApplicationURLs = resolved
+  }
+
+  /// ファイルを開くアプリの場所（解決前のアプリはその場でバックグラウンドで探す）。
+  private func fileOpenerApplicationURL(for application: String) async -> URL? {
+    if let cached = fileOpenerApplicationURLs[application] {
+      return cached
+    }
+    let url = await Task.detached(priority: .userInitiated) {
+      LaunchService.applicationURL(for: application)
+    }.value
+    fileOpenerApplicationURLs[application] = url
+    return url
+  }
+
+  /// 外部で編集された設定ファイルを読み直し、変わった項目を反映する。
+  ///
+  /// 読めない JSON（編集の途中など）の場合は現在の設定のまま、次の保存を待つ。
+  public func reloadSettingsFromDisk() async {
+    let previous: Settings
+    do {
+      guard let reloaded = try settingsManager.reloadFromDisk() else { return }
+      previous = reloaded
+    } catch {
+      Self.logger.warning("Ignored unreadable settings file: \(error.localizedDescription)")
+      return
+    }
+    Self.logger.info("Settings file changed externally; reloading")
+    await applyReplacedSettings(from: previous)
+  }
+
+  /// 設定ファイルのバックアップから設定を復元し、変わった項目を反映する。
+  ///
+  /// - Parameter generation: 復元する世代（1 が最新）
+  /// - Throws: バックアップがない・読めない場合、または保存に失敗した場合
+  public func restoreSettingsBackup(generation: Int = 1) async throws {
+    let previous = try settingsManager.restoreBackup(generation: generation)
+    Self.logger.info("Restored settings backup #\(generation)")
+    await applyReplacedSettings(from: previous)
+  }
+
+  /// 設定全体が置き換わった後に、変わった項目を反映する。
+  ///
+  /// ホットキーの再登録と自動更新タイマーの再起動は常に行い、登録ディレクトリ・除外アプリ・
+  /// プロファイルが変わった場合はキャッシュを再構築する。反映後に
+  /// `SettingsManager.didChangeNotification` を投稿する。
+  private func applyReplacedSettings(from previous: Settings) async {
+    let settings = settingsManager.settings
+
+    settingsViewModel.logFile.level = settings.logLevel
+    if settings.activeProfile != previous.activeProfile {
+      do {
+        try cacheDatabase.selectPartition(
+          SettingsProfile.cachePartitionName(for: settings.activeProfile))
+      } catch {
+        Self.logger.error("Failed to open profile cache: \(error.localizedDescription)")
+      }
+    }
+    if settings.launchAtLogin != previous.launchAtLogin {
+      do {
+        try settingsViewModel.setLaunchAtLogin(settings.launchAtLogin)
+      } catch {
+        Self.logger.error("Failed to update launch at login: \(error.localizedDescription)")
+      }
+    }
+    globalShortcut.updateBindings(settings.hotkeyBindings)
+    cacheBootstrap.startAutoUpdate()
+    reloadDataFromSettings()
+    NotificationCenter.default.post(
+      name: SettingsManager.didChangeNotification, object: settingsManager)
+
+    if settings.registeredDirectories != previous.registeredDirectories
+      || settings.excludedApps != previous.excludedApps
+      || settings.activeProfile != previous.activeProfile
+    {
+      await rebuildCacheAndReload()
+    }
+    if settings.update.releaseChannel != previous.update.releaseChannel {
+      await checkForUpdates()
+    }
   }
 
   // MARK: - 非公開ヘルパー
 
+  /// 設定ファイルの監視を開始する。
+  private func startSettingsFileWatcher() {
+    guard watchesSettingsFile, settingsFileWatcher == nil else { return }
+    let watcher = SettingsFileWatcher(fileURL: settingsManager.fileURL) { [weak self] in
+      guard let self else { return }
+      Task { await self.reloadSettingsFromDisk() }
+    }
+    watcher.start()
+    settingsFileWatcher = watcher
+  }
+
+  /// システム設定のパネルを返す（初回のみバンドルを探索し、以降は読み込み済みの一覧を使う）。
+  private func loadSystemSettingsPanes() async -> [SystemSettingsPane] {
+    if let systemSettingsPanes { return systemSettingsPanes }
+    let panes = await systemSettingsProvider.panes()
+    systemSettingsPanes = panes
+    return panes
+  }
+
+  /// 設定で全文検索が有効な場合に、ディレクトリの検索に使うキャッシュ DB を返す。
+  private func fullTextDirectorySearcher() -> (any DirectoryFullTextSearching)? {
+    guard settingsManager.settings.fullTextSearchEnabled else { return nil }
+    return cacheDatabase as? any DirectoryFullTextSearching
+  }
+
+  /// 設定由来の検索対象（コマンド・別名・除外・クイックリンク・ローマ字検索）と結果の件数を反映する。
+  private func applySettingsDataSources() {
+    launcherViewModel.commands = settingsManager.settings.customCommands
+    launcherViewModel.searchPrefixes = settingsManager.settings.searchPrefixes
+    launcherViewModel.resultSettings = settingsManager.settings.results
+    launcherViewModel.itemAliases = settingsManager.settings.itemAliases
+    launcherViewModel.hiddenPaths = Set(settingsManager.settings.excludedItems)
+    launcherViewModel.quickLinks = settingsManager.settings.quickLinks
+    launcherViewModel.terminalTargets =
+      settingsManager.settings.cacheUpdate.indexTerminalTargets ? terminalTargets : []
+    applyTransliterationSettings()
+  }
+
+  /// ローマ字検索の設定を検索インデックスへ反映する（変更時のみ再構築する）。
+  private func applyTransliterationSettings() {
+    let enabled = settingsManager.settings.transliterationEnabled
+    guard enabled != !launcherViewModel.transliterators.isEmpty else { return }
+    launcherViewModel.transliterators = enabled ? [ICUTransliterator()] : []
+  }
+
+  /// 表示位置・閉じ方・入力ソースの設定と保存済み位置を WindowManager などへ反映する。
+  private func applyWindowPositionSettings() {
+    let settings = settingsManager.settings
+    windowManager.placement = settings.windowPlacement
+    windowManager.savedPosition = settings.windowPosition
+    windowManager.restoresFocusOnHide = settings.restoreFocusOnHide
+    windowManager.hidesOnFocusLoss = settings.windowBehavior.hideOnFocusLoss
+    windowManager.focusLossGracePeriod = settings.windowBehavior.clampedFocusLossGracePeriod
+    globalShortcut.imeBehavior = settings.windowBehavior.imeBehavior
+  }
+
+  /// アプリがアクティブになったとき（⌘Tab での切り替えや Dock のアイコンのクリック）に、
+  /// Dock にアイコンを表示する設定でウィンドウが何も表示されていなければランチャーを表示する。
+  ///
+  /// - Parameter hasVisibleWindows: 設定ウィンドウなど、ランチャー以外のウィンドウが表示中か
+  public func handleDidBecomeActive(hasVisibleWindows: Bool) {
+    guard settingsManager.settings.windowBehavior.showDockIcon, !hasVisibleWindows,
+      !windowManager.isLauncherVisible, !windowManager.isPickerVisible
+    else { return }
+    windowManager.showLauncher()
+  }
+
+  /// Dock のアイコンの表示の設定を反映する（変わった場合のみ切り替える）。
+  private func applyActivationPolicy() {
+    let visible = settingsManager.settings.windowBehavior.showDockIcon
+    guard visible != isDockIconVisible else { return }
+    isDockIconVisible = visible
+    activationPolicy.setDockIconVisible(visible)
+  }
+
+  /// 外観の設定をランチャーのビュー・ウィンドウへ反映する。
+  private func applyAppearanceSettings() {
+    let appearance = settingsManager.settings.appearance
+    launcherViewModel.appearance = appearance
+    launcherPanel.appearance = appearance.mode.nsAppearance
+    windowManager.applyAppearance(
+      width: CGFloat(appearance.clampedWindowWidth),
+      rowHeight: CGFloat(appearance.density.rowHeight),
+      resultCount: launcherViewModel.searchResults.count
+    )
+  }
+
   /// ランチャーパネルに LauncherView を設定する。
   private func setupLauncherView() {
     let view = LauncherView(
@@ -665,6 +1711,9 @@ public final class AppCoordinator {
       onExecute: { [weak self] result in
         self?.executeResult(result)
       },
+      onExecuteMarked: { [weak self] in
+        self?.executeMarkedResults()
+      },
       onDismiss: { [weak self] in
         self?.dismissLauncher()
       },
@@ -693,11 +1742,12 @@ public final class AppCoordinator {
 
     launcherPanel.setContentView(view)
 
-    // 初期サイズを設定する
+    // 初期サイズを設定する（読み込んだ設定の外観を反映する）
+    applyAppearanceSettings()
     let frame = NSRect(
       x: launcherPanel.frame.origin.x,
       y: launcherPanel.frame.origin.y,
-      width: WindowManager.width,
+      width: windowManager.windowWidth,
       height: WindowManager.minHeight
     )
     launcherPanel.setFrame(frame, display: true)
@@ -717,25 +1767,213 @@ public final class AppCoordinator {
     await cacheBootstrap.rebuildCache()
   }
 
+  /// 設定プロファイルを切り替え、プロファイルのキャッシュ・コマンド・追加ホットキーを反映する。
+  ///
+  /// キャッシュはプロファイルごとの DB に分かれており、初めて使うプロファイルではスキャンする。
+  /// - Parameter name: 切り替え先のプロファイル名
+  /// - Throws: キャッシュの更新中、またはプロファイルがない場合は `SettingsProfileError`
+  public func switchProfile(to name: String) async throws {
+    guard !cacheBootstrap.isScanning else { throw SettingsProfileError.cacheBusy }
+    let previous = settingsManager.settings.activeProfile
+    guard previous != name else { return }
+    guard settingsManager.profileNames.contains(name) else {
+      throw SettingsProfileError.notFound(name)
+    }
+    try cacheDatabase.selectPartition(SettingsProfile.cachePartitionName(for: name))
+    do {
+      try settingsManager.switchProfile(to: name, launcherShortcut: globalShortcut.launcherShortcut)
+    } catch {
+      try? cacheDatabase.selectPartition(SettingsProfile.cachePartitionName(for: previous))
+      throw error
+    }
+    Self.logger.info("Switched profile: \(previous ?? "none") -> \(name)")
+
+    globalShortcut.applyLauncherShortcut(
+      settingsManager.settings.profiles.first { $0.name == name }?.launcherShortcut)
+    globalShortcut.updateBindings(settingsManager.settings.hotkeyBindings)
+    reloadDataFromSettings()
+    if (try? cacheDatabase.isEmpty()) ?? true {
+      await rebuildCacheAndReload()
+    } else {
+      await loadCacheDataIntoViewModel()
+    }
+  }
+
+  /// 削除したプロファイルのキャッシュ DB を削除する（`default` のプロファイルは
+  /// プロファイルを使わない場合と同じ DB のため残す）。
+  func removeProfileCache(named name: String) throws {
+    guard let partition = SettingsProfile.cachePartitionName(for: name) else { return }
+    try cacheDatabase.removePartition(partition)
+  }
+
+  /// アイコンキャッシュをすべて削除し、キャッシュを再構築してアイコンを作り直す。
+  public func clearIconCache() async {
+    guard !cacheBootstrap.isScanning else {
+      Self.logger.info("Cache rebuild in progress; skipping icon cache clear")
+      return
+    }
+    let iconCacheManager = iconCacheManager
+    do {
+      try await Task.detached { try iconCacheManager.removeAll() }.value
+      Self.logger.info("Icon cache cleared")
+    } catch {
+      reportError(error, action: "Clear icon cache")
+    }
+    await rebuildCacheAndReload()
+    settingsViewModel.iconCacheUsage = await currentIconCacheUsage()
+  }
+
+  /// キャッシュの DB とアイコンキャッシュを削除し、空の DB からスキャンし直す。
+  ///
+  /// 再構築でも直らない状態になったときの最後の手段。お気に入りと履歴も消える。
+  /// - Throws: キャッシュの更新中、または DB を作り直せない場合は `LauncherError.cache`
+  public func resetCache() async throws {
+    guard !cacheBootstrap.isScanning else {
+      throw LauncherError.cache("Cannot reset the cache while it is being rebuilt")
+    }
+    do {
+      try cacheDatabase.resetStorage()
+    } catch {
+      throw LauncherError.cache(error.localizedDescription)
+    }
+    // アイコンはスキャン後の表示で作り直されるため、削除の失敗は記録だけにする
+    let iconCacheManager = iconCacheManager
+    do {
+      try await Task.detached { try iconCacheManager.removeAll() }.value
+    } catch {
+      Self.logger.error("Failed to clear icon cache during reset: \(error.localizedDescription)")
+    }
+    launcherViewModel.convertedIconPaths = [:]
+    Self.logger.info("Cache reset; rebuilding from scratch")
+    await rebuildCacheAndReload()
+    settingsViewModel.iconCacheUsage = await currentIconCacheUsage()
+  }
+
+  /// アクセシビリティ権限を求めるシステムのダイアログを表示する。
+  ///
+  /// 許可済みならダイアログは出さない。許可されると監視が変化を検知して設定画面に反映する。
+  /// - Returns: 現在の状態（ダイアログで許可する前の状態）
+  @discardableResult
+  public func requestAccessibilityPermission() -> Bool {
+    let trusted = accessibilityPermission.requestAccess()
+    Self.logger.info("Requested accessibility permission (trusted: \(trusted))")
+    accessibilityMonitor.check()
+    return trusted
+  }
+
+  /// アイコンキャッシュの使用量をバックグラウンドで集計する。
+  private func currentIconCacheUsage() async -> IconCacheUsage {
+    let iconCacheManager = iconCacheManager
+    return await Task.detached { iconCacheManager.usage() }.value
+  }
+
+  /// 自己診断を実行する。
+  ///
+  /// アクセシビリティ権限・ホットキーの登録・キャッシュ DB の整合性・設定ファイル・
+  /// アイコンキャッシュの使用量・ディレクトリのスキャン状況を検査し、結果をログにも記録する。
+  public func runDiagnostics() async -> DiagnosticReport {
+    let integrity: Result<[String], any Error>
+    do {
+      integrity = .success(try await cacheDatabase.integrityProblems())
+    } catch {
+      integrity = .failure(error)
+    }
+    let iconUsage = await currentIconCacheUsage()
+    settingsViewModel.iconCacheUsage = iconUsage
+
+    let report = DiagnosticReport(checks: [
+      Diagnostics.accessibility(isTrusted: accessibilityMonitor.check()),
+      Diagnostics.hotkeys(
+        isToggleRegistered: globalShortcut.isToggleHotKeyRegistered,
+        failedBindings: globalShortcut.failedBindings),
+      Diagnostics.cacheIntegrity(integrity),
+      Diagnostics.settingsFile(settingsManager.fileStatus()),
+      Diagnostics.iconCache(iconUsage),
+      Diagnostics.scanHealth(cacheBootstrap.scanStatuses),
+    ])
+    Self.logger.info("\(report.summary)")
+    return report
+  }
+
+  /// 登録ディレクトリ 1 件だけを再スキャンし、ビューモデルへ反映する。
+  ///
+  /// /Applications を含む全体の再スキャンを避けるため、設定画面用の全アプリ一覧は
+  /// 既存のものを引き継ぐ。
+  /// - Parameter path: 再スキャンする登録ディレクトリのパス
+  public func refreshDirectory(path: String) async {
+    guard !cacheBootstrap.isScanning else {
+      Self.logger.info("Cache rebuild already in progress; skipping directory refresh")
+      return
+    }
+    launcherViewModel.isScanning = true
+    defer { launcherViewModel.isScanning = false }
+    let refreshed = await cacheBootstrap.refreshDirectory(path: path)
+    settingsViewModel.scanStatuses = cacheBootstrap.scanStatuses
+    guard refreshed else { return }
+    await loadCacheDataIntoViewModel(scannedAllApps: settingsViewModel.allApps)
+  }
+
   /// キャッシュデータをビューモデルに読み込む。
   ///
   /// - Parameter scannedAllApps: スキャン直後に呼ばれる場合の全アプリ一覧
   ///   （除外フィルタ前）。nil の場合（起動時のスキャンスキップ時）は
   ///   設定画面用にバックグラウンドでスキャンする。
   private func loadCacheDataIntoViewModel(scannedAllApps: [AppItem]? = nil) async {
-    var cacheLoadSucceeded = true
+    var cachedItems: (apps: [AppItem], directories: [DirectoryItem])?
     do {
-      let apps = try await cacheDatabase.loadApps()
-      let directories = try await cacheDatabase.loadDirectories()
-      launcherViewModel.apps = apps
-      launcherViewModel.directories = directories
+      cachedItems = (
+        try await cacheDatabase.loadApps(), try await cacheDatabase.loadDirectories()
+      )
     } catch {
-      cacheLoadSucceeded = false
-      Self.logger.error("Failed to load cache data: \(error.localizedDescription)")
+      reportError(error, action: "Load cache data")
     }
+    if let apps = cachedItems?.apps {
+      cachedItems?.apps = await pruningMissingApps(apps)
+    }
+    let cacheLoadSucceeded = cachedItems != nil
+    let panes = await loadSystemSettingsPanes()
+    let systemControls = await systemControlService.availableControls()
 
-    // 設定からコマンドを読み込む
-    launcherViewModel.commands = settingsManager.settings.customCommands
+    do {
+      terminalTargets = try await cacheDatabase.loadTerminalTargets()
+    } catch {
+      Self.logger.error("Failed to load terminal targets: \(error.localizedDescription)")
+    }
+
+    var favoritePaths: Set<String>?
+    do {
+      favoritePaths = try await cacheDatabase.favoritePaths()
+    } catch {
+      Self.logger.error("Failed to load favorites: \(error.localizedDescription)")
+    }
+
+    // 全文検索時は、空クエリで表示するお気に入り・履歴のディレクトリだけをメモリに残す
+    let directorySearcher = fullTextDirectorySearcher()
+    var directories = cachedItems?.directories
+    if directorySearcher != nil, let loaded = directories {
+      let pinnedPaths = (favoritePaths ?? launcherViewModel.favoritePaths)
+        .union(selectionHistory.allEntries.map(\.selectedPath))
+      directories = loaded.filter { pinnedPaths.contains($0.path) }
+    }
+
+    // キャッシュと設定の検索対象をまとめて差し替える
+    // インデックスはバックグラウンドで構築し、構築中も既存のインデックスで検索できる
+    await launcherViewModel.updateDataSourcesInBackground {
+      if let cachedItems, let directories {
+        launcherViewModel.apps = cachedItems.apps
+        launcherViewModel.directories = directories
+      }
+      if let favoritePaths {
+        launcherViewModel.favoritePaths = favoritePaths
+      }
+      launcherViewModel.directorySearcher = directorySearcher
+      launcherViewModel.systemSettingsPanes = panes
+      launcherViewModel.systemControls = systemControls
+      launcherViewModel.windowLayoutActions = WindowLayoutAction.allCases
+      applySettingsDataSources()
+    }
+    settingsViewModel.scanStatuses = cacheBootstrap.scanStatuses
+    settingsViewModel.iconCacheUsage = await currentIconCacheUsage()
 
     // エディタアイコンパスを読み込む
     let editors = launchService.availableEditors()
@@ -748,9 +1986,19 @@ public final class AppCoordinator {
     launcherViewModel.editorIconPaths = iconPaths
     launcherViewModel.defaultEditorRawValue = settingsManager.settings.defaultEditor.rawValue
 
+    // ディレクトリごとに記憶したエディタを読み込む
+    do {
+      directoryEditors = try await cacheDatabase.preferredEditors()
+        .compactMapValues { EditorType(rawValue: $0) }
+    } catch {
+      Self.logger.error("Failed to load directory editors: \(error.localizedDescription)")
+    }
+    applyDirectoryEditors()
+
     // 既定ターミナルの表示名を読み込む
     let terminalType = settingsManager.settings.defaultTerminal
     launcherViewModel.defaultTerminalName = LaunchService.displayName(for: terminalType)
+    launcherViewModel.language = settingsManager.settings.language.resolved()
 
     // 設定画面の除外アプリ一覧向けの全アプリ一覧（除外フィルタなし）
     if let scannedAllApps {
@@ -770,24 +2018,297 @@ public final class AppCoordinator {
     if cacheLoadSucceeded {
       var validPaths = Set<String>()
       for app in launcherViewModel.apps { validPaths.insert(app.path) }
-      for dir in launcherViewModel.directories { validPaths.insert(dir.path) }
+      for dir in cachedItems?.directories ?? [] { validPaths.insert(dir.path) }
       for command in launcherViewModel.commands { validPaths.insert(command.historyIdentifier) }
+      for target in terminalTargets { validPaths.insert(target.identifier) }
       for app in settingsViewModel.allApps { validPaths.insert(app.path) }
       selectionHistory.purgeInvalidPaths(validPaths)
     }
 
     // 履歴を読み込む
-    launcherViewModel.history = selectionHistory.allEntries
+    do {
+      queryHistory = try await cacheDatabase.queryHistory(limit: CacheDatabase.queryHistoryLimit)
+    } catch {
+      Self.logger.error("Failed to load query history: \(error.localizedDescription)")
+    }
+    launcherViewModel.history = mergedHistory()
+
+    // 最近のプロジェクトを読み込む
+    await refreshRecentProjects()
+
+    // よく使うアプリのアイコンは表示される前に変換しておく
+    Task { [weak self] in await self?.prefetchIcons() }
+  }
+
+  /// アプリのアイコンを変換済みにし、128px の PNG のパスを返す（変換できない場合は nil）。
+  ///
+  /// 変換したパスはキャッシュに記録し、次にキャッシュを読み込むまではビューモデルの
+  /// `convertedIconPaths` で表示する。検索対象にないパスは Finder と同じアイコンを変換する。
+  /// - Parameter path: アプリのパス
+  @discardableResult
+  public func ensureIcon(forAppPath path: String) async -> String? {
+    if let iconPath = launcherViewModel.convertedIconPaths[path] {
+      return iconPath
+    }
+    let app = launcherViewModel.apps.first { $0.path == path }
+    if let iconPath = app?.iconPath {
+      return iconPath
+    }
+    guard
+      let iconPath = await iconConversionQueue.ensureIcon(
+        appPath: path, sourcePath: app?.iconSourcePath)
+    else { return nil }
+    storeConvertedIcons([path: iconPath])
+    return iconPath
+  }
+
+  /// よく使う順に上位のアプリ（`iconPrefetchCount` 件）のうち、未変換のアイコンを変換する。
+  func prefetchIcons() async {
+    let frecency = SearchService.frecencyScores(history: launcherViewModel.history)
+    let targets = launcherViewModel.apps
+      .filter { $0.iconPath == nil && $0.iconSourcePath != nil && frecency[$0.path] != nil }
+      .sorted { frecency[$0.path, default: 0] > frecency[$1.path, default: 0] }
+      .prefix(Self.iconPrefetchCount)
+      .filter { launcherViewModel.convertedIconPaths[$0.path] == nil }
+    guard !targets.isEmpty else { return }
+    let iconPaths = await iconConversionQueue.prefetch(targets)
+    storeConvertedIcons(iconPaths)
+    Self.logger.info("Prefetched \(iconPaths.count) of \(targets.count) app icons")
+  }
+
+  /// 変換したアイコンのパスをビューモデルとキャッシュに反映する。
+  private func storeConvertedIcons(_ iconPaths: [String: String]) {
+    guard !iconPaths.isEmpty else { return }
+    launcherViewModel.convertedIconPaths.merge(iconPaths) { _, new in new }
+    do {
+      try cacheDatabase.updateAppIconPaths(iconPaths)
+    } catch {
+      Self.logger.error("Failed to save converted icon paths: \(error.localizedDescription)")
+    }
+  }
+
+  /// 項目を検索結果から非表示にし、表示中の結果を更新する。
+  ///
+  /// - Parameter path: アプリ・ディレクトリのパス、またはカスタムコマンドの識別子
+  public func hideItem(path: String) {
+    do {
+      try settingsViewModel.excludeItem(path: path)
+    } catch {
+      reportError(error, action: "Hide item")
+      return
+    }
+    if windowManager.isLauncherVisible {
+      launcherViewModel.updateSearch()
+      windowManager.resizeForResults(count: launcherViewModel.searchResults.count)
+    }
+  }
+
+  /// アプリまたはディレクトリのお気に入りを切り替え、検索結果へ反映する。
+  ///
+  /// - Parameter path: 対象のパス
+  /// - Returns: 切り替え後にお気に入りかどうか（保存に失敗した場合は nil）
+  @discardableResult
+  public func toggleFavorite(path: String) -> Bool? {
+    let isFavorite: Bool
+    do {
+      isFavorite = try cacheDatabase.toggleFavorite(path: path)
+    } catch {
+      reportError(error, action: "Toggle favorite")
+      return nil
+    }
+    if isFavorite {
+      launcherViewModel.favoritePaths.insert(path)
+    } else {
+      launcherViewModel.favoritePaths.remove(path)
+    }
+    if windowManager.isLauncherVisible {
+      launcherViewModel.updateSearch()
+    }
+    return isFavorite
+  }
+
+  /// 最近開いたディレクトリを新しい順に取得する。
+  ///
+  /// - Parameter limit: 最大件数
+  /// - Returns: 最近のプロジェクト（取得に失敗した場合は空配列）
+  public func recentProjects(limit: Int) async -> [RecentProject] {
+    do {
+      return try await cacheDatabase.recentProjects(limit: limit)
+    } catch {
+      Self.logger.error("Failed to load recent projects: \(error.localizedDescription)")
+      return []
+    }
+  }
+
+  /// 設定の表示件数で最近のプロジェクトを読み込み、ランチャーへ反映する。
+  func refreshRecentProjects() async {
+    let limit = settingsManager.settings.recentProjectsLimit
+    launcherViewModel.recentProjects = limit > 0 ? await recentProjects(limit: limit) : []
+  }
+
+  /// ディレクトリを開いたことをキャッシュ DB に記録し、最近のプロジェクトを更新する。
+  private func recordDirectoryOpen(_ path: String, editor: EditorType) {
+    do {
+      try cacheDatabase.recordDirectoryOpen(path: path, editor: editor.rawValue)
+    } catch {
+      Self.logger.error("Failed to record directory open: \(error.localizedDescription)")
+      return
+    }
+    Task {
+      await refreshRecentProjects()
+    }
+  }
+
+  /// ディレクトリを開くエディタを決める。
+  ///
+  /// 記憶したエディタ（設定で有効な場合）→ Xcode（Xcode のプロジェクトがあり、設定で
+  /// 有効な場合）→ 登録ディレクトリに設定したエディタ → デフォルトエディタの順に使う。
+  /// - Parameters:
+  ///   - path: ディレクトリのパス
+  ///   - configured: 登録ディレクトリに設定したエディタの rawValue
+  ///   - hasXcodeProject: 直下に Xcode のワークスペース・プロジェクトがあるか
+  func directoryEditor(
+    for path: String, configured: String?, hasXcodeProject: Bool = false
+  ) -> EditorType {
+    let settings = settingsManager.settings
+    let remembered = settings.remembersDirectoryEditor ? directoryEditors[path] : nil
+    let xcode: EditorType? =
+      hasXcodeProject && settings.opensXcodeProjectsInXcode ? .xcode : nil
+    return remembered ?? xcode ?? configured.flatMap { EditorType(rawValue: $0) }
+      ?? settings.defaultEditor
+  }
+
+  /// ディレクトリに記憶したエディタを変更し、ランチャーと設定画面へ反映する。
+  ///
+  /// - Parameters:
+  ///   - editor: 次から開くエディタ（nil で記憶を消す）
+  ///   - path: ディレクトリのパス
+  /// - Throws: キャッシュ DB への保存に失敗した場合
+  public func setDirectoryEditor(_ editor: EditorType?, forPath path: String) throws {
+    try cacheDatabase.setPreferredEditor(editor?.rawValue, forPath: path)
+    directoryEditors[path] = editor
+    applyDirectoryEditors()
+  }
+
+  /// エディタピッカーで選んだエディタを記憶する。
+  ///
+  /// 記憶がなくても使われるエディタ（`fallback`）を選んだ場合は記憶を消す。
+  private func rememberDirectoryEditor(
+    _ editor: EditorType, forPath path: String, fallback: EditorType
+  ) {
+    guard settingsManager.settings.remembersDirectoryEditor else { return }
+    let remembered = editor == fallback ? nil : editor
+    guard directoryEditors[path] != remembered else { return }
+    do {
+      try setDirectoryEditor(remembered, forPath: path)
+    } catch {
+      Self.logger.error("Failed to remember directory editor: \(error.localizedDescription)")
+    }
+  }
+
+  /// 記憶したエディタをランチャーの表示と設定画面へ反映する。
+  private func applyDirectoryEditors() {
+    settingsViewModel.directoryEditors = directoryEditors
+    launcherViewModel.opensXcodeProjectsInXcode =
+      settingsManager.settings.opensXcodeProjectsInXcode
+    launcherViewModel.directoryEditors =
+      settingsManager.settings.remembersDirectoryEditor
+      ? directoryEditors.mapValues(\.rawValue) : [:]
   }
 
   /// アップデートチェックを実行する。
-  private func checkForUpdates() async {
+  ///
+  /// 自動ダウンロードが有効なら、通知と同時にダウンロード・検証まで済ませておく。
+  func checkForUpdates() async {
     let result = await updateChecker.checkForUpdate(currentVersion: Ignitero.version)
-    if let result {
-      launcherViewModel.showUpdateBanner(version: result.latestVersion)
+    guard let result else {
+      // チャンネルを戻した場合などに、通知済みのバージョンを取り下げる
+      availableUpdate = nil
+      launcherViewModel.updateBannerVersion = nil
+      launcherViewModel.isUpdateInstallable = false
+      return
+    }
+    availableUpdate = result
+    launcherViewModel.isUpdateInstallable = canInstall(result)
+    launcherViewModel.showUpdateBanner(version: result.latestVersion)
+    if settingsManager.settings.update.installMode == .autoDownload, canInstall(result) {
+      _ = await stageUpdate(result)
+    }
+  }
+
+  /// 見つかったアップデートをインストールしてアプリを再起動する。
+  ///
+  /// アプリ内でインストールできない場合（アーカイブが添付されていないリリースや、
+  /// `.app` 以外から実行している場合）はリリースページを開く。
+  public func installUpdate() async {
+    guard let update = availableUpdate, !launcherViewModel.updateInstallState.isInProgress
+    else { return }
+    guard canInstall(update) else {
+      if let url = URL(string: update.downloadURL) {
+        do {
+          try await launchService.openURL(url)
+        } catch {
+          reportError(error, action: "Open release page")
+        }
+      }
+      return
+    }
+
+    guard let app = await stageUpdate(update) else { return }
+    launcherViewModel.updateInstallState = .installing
+    do {
+      try await updateInstaller.install(stagedApp: app, replacing: installedAppURL)
+    } catch {
+      Self.logger.error("Update install failed: \(error.localizedDescription)")
+      launcherViewModel.updateInstallState = .failed(LauncherError(error).detail)
+      stagedUpdate = nil
+      return
+    }
+    Self.logger.notice("Installed update \(update.latestVersion), relaunching")
+    relaunchHandler(installedAppURL)
+  }
+
+  /// アップデートをダウンロード・検証する（検証済みならそのまま返す）。
+  ///
+  /// - Returns: 展開した .app。失敗した場合は nil（進行状況に理由を表示する）
+  private func stageUpdate(_ update: UpdateCheckResult) async -> URL? {
+    if let stagedUpdate, stagedUpdate.version == update.latestVersion {
+      return stagedUpdate.app
+    }
+    do {
+      let app = try await updateInstaller.stage(update) { [weak self] state in
+        Task { @MainActor in self?.launcherViewModel.updateInstallState = state }
+      }
+      stagedUpdate = (update.latestVersion, app)
+      launcherViewModel.updateInstallState = .readyToInstall
+      return app
+    } catch {
+      Self.logger.error("Update download failed: \(error.localizedDescription)")
+      launcherViewModel.updateInstallState = .failed(LauncherError(error).detail)
+      return nil
     }
   }
 
+  /// アプリ内でインストールできるか（署名付きのアーカイブがあり、公開鍵を埋め込んだ .app として実行している）
+  private func canInstall(_ update: UpdateCheckResult) -> Bool {
+    update.isInstallable && updateInstaller.canVerifySignatures
+      && installedAppURL.pathExtension == "app"
+  }
+
+  /// 少し待ってから新しいアプリを開き、現在のアプリを終了する。
+  private static func relaunch(_ appURL: URL) {
+    let process = Process()
+    process.executableURL = URL(fileURLWithPath: "/bin/sh")
+    process.arguments = ["-c", "sleep 1; /usr/bin/open \"$0\"", appURL.path]
+    do {
+      try process.run()
+    } catch {
+      logger.error("Failed to schedule relaunch: \(error.localizedDescription)")
+      return
+    }
+    NSApp.terminate(nil)
+  }
+
   /// 非表示にされたアップデートバージョンを設定へ永続化する。
   private func persistDismissedUpdateVersion(_ version: String) {
     var cache = settingsManager.settings.updateCache ?? UpdateCache()
@@ -801,6 +2322,351 @@ public final class AppCoordinator {
     }
   }
 
+  /// ランチャーの位置を設定へ保存する（記憶が無効な場合は保存しない）。
+  private func persistWindowPosition(_ position: WindowPosition) {
+    guard settingsManager.settings.windowPlacement == .rememberedPosition else { return }
+    settingsManager.settings.windowPosition = position
+    do {
+      try settingsManager.save()
+    } catch {
+      Self.logger.error("Failed to persist window position: \(error.localizedDescription)")
+    }
+  }
+
+  // MARK: - CLI・URL スキーム
+
+  /// `ignitero` CLI 用のソケットで待ち受けを開始する。
+  private func startIPCServer() {
+    guard let ipcSocketPath, ipcServer == nil else { return }
+    let server = IPCServer(socketPath: ipcSocketPath) { [weak self] request in
+      guard let self else { return .failure("Ignitero Launcher is shutting down") }
+      return await self.handleIPCRequest(request)
+    }
+    do {
+      try server.start()
+      ipcServer = server
+    } catch {
+      Self.logger.error("Failed to start IPC server: \(error.localizedDescription)")
+    }
+  }
+
+  /// `ignitero` CLI からのリクエストを処理する。
+  ///
+  /// - `show`: ランチャーを表示する
+  /// - `refresh`: キャッシュを再構築する
+  /// - `search`: ランチャーと同じインデックス・履歴・設定で検索し、結果を返す
+  /// - `get_default_items`: 検索語が空のときにランチャーが表示する既定の項目を返す
+  /// - `open`: アプリは起動、ディレクトリは既定のエディタ、それ以外は既定のアプリで開く
+  /// - `list_profiles`: 設定プロファイルの一覧と使用中のプロファイルを返す
+  /// - `switch_profile`: 設定プロファイルを切り替える
+  /// - `restore_settings_backup`: 設定ファイルのバックアップから復元する（引数: 世代、既定は 1）
+  /// - `get_query_suggestions`: 入力中の検索語に前方一致する過去の検索語を返す
+  /// - Parameter request: CLI からのリクエスト
+  /// - Returns: CLI へ返すレスポンス
+  public func handleIPCRequest(_ request: IPCRequest) async -> IPCResponse {
+    switch request.command {
+    case .show:
+      menuBarActions.showWindow()
+      return .success
+    case .refresh:
+      await rebuildCacheAndReload()
+      return .success
+    case .search:
+      guard let query = request.argument, !query.isEmpty else {
+        return .failure("Missing search query")
+      }
+      let results = searchService.search(
+        query: query,
+        index: launcherViewModel.searchIndex,
+        history: launcherViewModel.history,
+        prefixes: launcherViewModel.searchPrefixes,
+        resultSettings: launcherViewModel.resultSettings
+      )
+      // 見出しごとにまとめる設定では、結果は検索で見出しの順に並べ替え済み
+      let sections =
+        launcherViewModel.resultSettings.groupResults
+        ? SearchResultSection.sections(
+          of: results, order: launcherViewModel.resultSettings.groupOrder
+        ).map {
+          IPCSearchSection(
+            group: $0.group.rawValue, title: $0.group.title, count: $0.results.count)
+        } : nil
+      return IPCResponse(ok: true, results: results.map(Self.ipcSearchItem), sections: sections)
+    case .getDefaultItems:
+      // お気に入り・最近のプロジェクト・時間帯の提案・よく使う項目（ランチャーの空の検索語と同じ）
+      let results = searchService.defaultItems(
+        index: launcherViewModel.searchIndex,
+        history: launcherViewModel.history,
+        recentProjects: launcherViewModel.recentProjects,
+        resultSettings: launcherViewModel.resultSettings
+      )
+      return IPCResponse(ok: true, results: results.map(Self.ipcSearchItem))
+    case .open:
+      guard let path = request.argument, !path.isEmpty else { return .failure("Missing path") }
+      var isDirectory: ObjCBool = false
+      guard FileManager.default.fileExists(atPath: path, isDirectory: &isDirectory) else {
+        return .failure("No such file or directory: \(path)")
+      }
+      do {
+        if path.hasSuffix(".app") {
+          try await launchService.launchApp(at: path)
+        } else if isDirectory.boolValue {
+          let editor = directoryEditor(for: path, configured: nil)
+          try await launchService.openDirectory(path, editor: editor)
+          recordDirectoryOpen(path, editor: editor)
+        } else {
+          // 拡張子に割り当てたアプリがあればそれで開く（なければ既定のアプリ）
+          let opener = FileOpener.opener(
+            forPath: path, in: settingsManager.settings.fileOpeners)
+          var applicationURL: URL?
+          if let opener {
+            applicationURL = await fileOpenerApplicationURL(for: opener.application)
+            if applicationURL == nil {
+              Self.logger.warning(
+                "Application not found: \(opener.application); using default handler")
+            }
+          }
+          try await launchService.openFile(path, applicationURL: applicationURL)
+        }
+        return .success
+      } catch {
+        Self.logger.warning("IPC open failed: \(path): \(error.localizedDescription)")
+        return .failure(error.localizedDescription)
+      }
+    case .listProfiles:
+      return IPCResponse(
+        ok: true, profiles: settingsManager.profileNames,
+        activeProfile: settingsManager.settings.activeProfile)
+    case .switchProfile:
+      guard let name = request.argument, !name.isEmpty else {
+        return .failure("Missing profile name")
+      }
+      do {
+        try await switchProfile(to: name)
+        return .success
+      } catch {
+        return .failure(error.localizedDescription)
+      }
+    case .restoreSettingsBackup:
+      guard let generation = Int(request.argument ?? "1"), generation > 0 else {
+        return .failure("Invalid backup number: \(request.argument ?? "")")
+      }
+      do {
+        try await restoreSettingsBackup(generation: generation)
+        return .success
+      } catch {
+        return .failure(error.localizedDescription)
+      }
+    case .copyToClipboard:
+      guard let text = request.argument, !text.isEmpty else {
+        return .failure("Missing text to copy")
+      }
+      clipboard.copy(text)
+      return .success
+    case .getIcon:
+      guard let path = request.argument, !path.isEmpty else { return .failure("Missing path") }
+      guard FileManager.default.fileExists(atPath: path) else {
+        return .failure("No such file or directory: \(path)")
+      }
+      let pointSize = request.size ?? Self.defaultIconPointSize
+      guard pointSize > 0 else { return .failure("Invalid icon size: \(pointSize)") }
+      // 未変換のアイコンはその場で変換する
+      guard let largePath = await ensureIcon(forAppPath: path) else {
+        return .failure("Failed to load icon: \(path)")
+      }
+      let scale = displayInfoProvider.activeScale()
+      return IPCResponse(
+        ok: true,
+        iconPath: IconCacheManager.iconPath(largePath, pointSize: Double(pointSize), scale: scale))
+    case .ensureIcon:
+      guard let path = request.argument, !path.isEmpty else { return .failure("Missing path") }
+      guard FileManager.default.fileExists(atPath: path) else {
+        return .failure("No such file or directory: \(path)")
+      }
+      guard let iconPath = await ensureIcon(forAppPath: path) else {
+        return .failure("Failed to load icon: \(path)")
+      }
+      return IPCResponse(ok: true, iconPath: iconPath)
+    case .displayInfo:
+      let displays = displayInfoProvider.displays().map {
+        IPCDisplay(
+          name: $0.name, scale: $0.scale, width: $0.width, height: $0.height, active: $0.isActive)
+      }
+      return IPCResponse(ok: true, displays: displays)
+    case .searchDebug:
+      guard settingsManager.settings.searchDebugEnabled else {
+        return .failure("search_debug is disabled (enable search_debug_enabled in settings)")
+      }
+      guard let query = request.argument, !query.isEmpty else {
+        return .failure("Missing search query")
+      }
+      let results = searchService.debugSearch(
+        query: query,
+        index: launcherViewModel.searchIndex,
+        history: launcherViewModel.history,
+        prefixes: launcherViewModel.searchPrefixes,
+        tieBreak: launcherViewModel.resultSettings.tieBreak
+      )
+      return IPCResponse(
+        ok: true,
+        debugResults: results.map {
+          IPCSearchDebugItem(
+            name: $0.name, path: $0.path, kind: String(describing: $0.kind),
+            fuzzyScore: $0.breakdown.fuzzy, categoryAdjustment: $0.breakdown.categoryAdjustment,
+            historyBoost: $0.breakdown.historyBoost, aliasScore: $0.breakdown.aliasScore,
+            finalScore: $0.score, favorite: $0.isFavorite)
+        })
+    case .getPermissionStatus:
+      return IPCResponse(
+        ok: true, permissions: IPCPermissionStatus(accessibility: accessibilityMonitor.check()))
+    case .requestAccessibilityPermission:
+      let trusted = requestAccessibilityPermission()
+      return IPCResponse(ok: true, permissions: IPCPermissionStatus(accessibility: trusted))
+    case .getQuerySuggestions:
+      let prefix = SearchQueryNormalizer.normalize(request.argument ?? "")
+      do {
+        let suggestions = try await cacheDatabase.querySuggestions(
+          prefix: prefix, limit: Self.querySuggestionLimit)
+        return IPCResponse(ok: true, suggestions: suggestions)
+      } catch {
+        return .failure(error.localizedDescription)
+      }
+    case .setActivationPolicy:
+      let visible: Bool
+      switch request.argument?.lowercased() {
+      case "regular": visible = true
+      case "accessory": visible = false
+      default:
+        return .failure(
+          "Unknown activation policy: \(request.argument ?? "") (use regular or accessory)")
+      }
+      var behavior = settingsManager.settings.windowBehavior
+      behavior.showDockIcon = visible
+      do {
+        try settingsViewModel.setWindowBehavior(behavior)
+        applyActivationPolicy()
+        return .success
+      } catch {
+        return .failure(error.localizedDescription)
+      }
+    case .pauseIndexing:
+      cacheBootstrap.pauseIndexing()
+      return .success
+    case .resumeIndexing:
+      cacheBootstrap.resumeIndexing()
+      return .success
+    case .resetCache:
+      do {
+        try await resetCache()
+        return .success
+      } catch {
+        return .failure(error.localizedDescription)
+      }
+    case .exportCache:
+      guard let path = request.argument, !path.isEmpty else {
+        return .failure("Missing export path")
+      }
+      let format: CacheExportFormat
+      if let name = request.format {
+        guard let parsed = CacheExportFormat(rawValue: name.lowercased()) else {
+          return .failure("Unknown export format: \(name) (use json or csv)")
+        }
+        format = parsed
+      } else {
+        format = .inferred(fromPath: path)
+      }
+      do {
+        let count = try await exportCache(to: path, format: format)
+        return IPCResponse(ok: true, exportedCount: count)
+      } catch {
+        return .failure(error.localizedDescription)
+      }
+    }
+  }
+
+  /// `search` / `get_default_items` で CLI へ返す検索結果 1 件
+  private static func ipcSearchItem(_ result: SearchResult) -> IPCSearchItem {
+    IPCSearchItem(
+      name: result.name, path: result.path, kind: String(describing: result.kind),
+      score: result.score, matchedIndices: result.matchedIndices,
+      group: SearchResultGroup(kind: result.kind).rawValue)
+  }
+
+  /// 使用中のプロファイルのキャッシュ（除外フィルタ適用前の全項目）をファイルに書き出す。
+  ///
+  /// - Returns: 書き出した項目の数
+  @discardableResult
+  public func exportCache(to path: String, format: CacheExportFormat) async throws -> Int {
+    let apps = try await cacheDatabase.loadApps()
+    let directories = try await cacheDatabase.loadDirectories()
+    let terminalTargets = try await cacheDatabase.loadTerminalTargets()
+    let count = try await Task.detached(priority: .utility) {
+      try CacheExporter.write(
+        apps: apps, directories: directories, terminalTargets: terminalTargets, format: format,
+        to: path)
+    }.value
+    Self.logger.info("Exported \(count) cached items to \(path) as \(format.rawValue)")
+    return count
+  }
+
+  /// `ignitero://` URL を処理する。
+  ///
+  /// 起動処理の完了前（検索対象の読み込み前）に受け取った URL は、起動完了後に処理する。
+  /// - Parameter url: 開かれた URL
+  public func handleDeepLink(_ url: URL) {
+    guard isReady else {
+      pendingDeepLinks.append(url)
+      return
+    }
+    guard let link = DeepLink(url: url) else {
+      Self.logger.warning("Unsupported deep link: \(url.absoluteString)")
+      return
+    }
+    Self.logger.info("Deep link: \(url.absoluteString)")
+    switch link {
+    case .show:
+      showLauncher(query: "")
+    case .search(let query):
+      showLauncher(query: query)
+    case .open(let path):
+      openIndexedItem(path: path)
+    }
+  }
+
+  /// ランチャーを表示し、検索語を入力した状態にする。
+  private func showLauncher(query: String) {
+    if !windowManager.isLauncherVisible {
+      windowManager.showLauncher()
+    }
+    // showLauncher 時の clearSearch 後に検索語を設定する
+    launcherViewModel.searchQuery = query
+    launcherViewModel.updateSearch()
+    windowManager.resizeForResults(count: launcherViewModel.searchResults.count)
+  }
+
+  /// 検索対象に含まれるアプリ・ディレクトリを開く。
+  ///
+  /// 外部から任意のファイルやスクリプトを開かせないよう、検索対象にないパスはエラーにする。
+  private func openIndexedItem(path: String) {
+    let path = URL(fileURLWithPath: path).standardizedFileURL.path
+    let result: SearchResult
+    if let app = launcherViewModel.apps.first(where: { $0.path == path }) {
+      result = SearchResult(appItem: app, score: 0)
+    } else if let directory = launcherViewModel.directories.first(where: { $0.path == path }) {
+      result = SearchResult(directoryItem: directory, score: 0)
+    } else {
+      reportError(LauncherError.notFound(path), action: "Open deep link")
+      return
+    }
+    Task {
+      do {
+        try await launch(result)
+      } catch {
+        reportError(error, action: "Open deep link")
+      }
+    }
+  }
+
   // MARK: - 既定パス
 
   /// デフォルトのデータベースファイルパスを返す。
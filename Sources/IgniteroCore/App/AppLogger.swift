@@ -0,0 +1,287 @@
+import Foundation
+import Synchronization
+import os
+
+// MARK: - LogLevel
+
+/// ログの出力レベル（設定したレベル未満のログはファイルに書き込まない）
+public enum LogLevel: String, Codable, CaseIterable, Sendable, Comparable {
+  case debug
+  case info
+  case warning
+  case error
+
+  /// 設定画面の表示名
+  public var displayName: String {
+    switch self {
+    case .debug: "デバッグ"
+    case .info: "情報"
+    case .warning: "警告"
+    case .error: "エラー"
+    }
+  }
+
+  /// ログファイルに書き込むラベル
+  var label: String {
+    rawValue.uppercased()
+  }
+
+  private var order: Int {
+    switch self {
+    case .debug: 0
+    case .info: 1
+    case .warning: 2
+    case .error: 3
+    }
+  }
+
+  public static func < (lhs: LogLevel, rhs: LogLevel) -> Bool {
+    lhs.order < rhs.order
+  }
+}
+
+// MARK: - LogFile
+
+/// `~/Library/Logs/ignitero-launcher/` に書き込むローテーション付きのログファイル。
+///
+/// 現在のファイルが上限サイズを超えると `ignitero.1.log`, `ignitero.2.log` … へ順に繰り下げ、
+/// 古いものから削除する。不具合報告に添付できるよう、直近のログを行単位で読み出せる。
+/// 書き込みは呼び出し元を待たせないよう、直列のバックグラウンドキューで順に行う。
+public final class LogFile: Sendable {
+
+  /// アプリ全体で共有するログファイル
+  public static let shared = LogFile(
+    directory: FileManager.default.homeDirectoryForCurrentUser
+      .appendingPathComponent("Library/Logs/ignitero-launcher"))
+
+  /// 1 ファイルの上限サイズ（バイト）
+  static let defaultMaxFileSize = 1_000_000
+  /// 残しておくローテーション済みファイルの数
+  static let defaultMaxArchivedFiles = 3
+
+  /// ログを書き込むディレクトリ
+  public let directory: URL
+  private let maxFileSize: Int
+  private let maxArchivedFiles: Int
+  private let state: Mutex<LogLevel>
+  /// 書き込み・ローテーション・読み出しを直列化するキュー
+  private let queue = DispatchQueue(label: "com.owayo.ignitero.launcher.log", qos: .utility)
+
+  /// 現在書き込み中のログファイル
+  public var fileURL: URL {
+    directory.appendingPathComponent("ignitero.log")
+  }
+
+  /// 書き込む最低レベル
+  public var level: LogLevel {
+    get { state.withLock { $0 } }
+    set { state.withLock { $0 = newValue } }
+  }
+
+  /// LogFile を初期化する。
+  ///
+  /// - Parameters:
+  ///   - directory: ログを書き込むディレクトリ（存在しない場合は書き込み時に作成する）
+  ///   - level: 書き込む最低レベル
+  ///   - maxFileSize: 1 ファイルの上限サイズ（バイト）
+  ///   - maxArchivedFiles: 残しておくローテーション済みファイルの数
+  public init(
+    directory: URL,
+    level: LogLevel = .info,
+    maxFileSize: Int = LogFile.defaultMaxFileSize,
+    maxArchivedFiles: Int = LogFile.defaultMaxArchivedFiles
+  ) {
+    self.directory = directory
+    self.state = Mutex(level)
+    self.maxFileSize = maxFileSize
+    self.maxArchivedFiles = maxArchivedFiles
+  }
+
+  /// 設定したレベル以上であればログを 1 行書き込む（書き込みの完了は待たない）。
+  ///
+  /// - Parameters:
+  ///   - level: ログのレベル
+  ///   - category: 出力元のカテゴリ
+  ///   - message: 本文（改行は空白に置き換える）
+  public func write(_ level: LogLevel, category: String, message: String) {
+    guard level >= self.level else { return }
+    let timestamp = Date().formatted(.iso8601)
+    let body = message.replacingOccurrences(of: "\n", with: " ")
+    let line = "\(timestamp) [\(level.label)] [\(category)] \(body)\n"
+
+    queue.async { [self] in
+      let fileManager = FileManager.default
+      do {
+        try fileManager.createDirectory(at: directory, withIntermediateDirectories: true)
+        rotateIfNeeded(adding: line.utf8.count)
+        if !fileManager.fileExists(atPath: fileURL.path) {
+          fileManager.createFile(atPath: fileURL.path, contents: nil)
+        }
+        let handle = try FileHandle(forWritingTo: fileURL)
+        defer { try? handle.close() }
+        try handle.seekToEnd()
+        try handle.write(contentsOf: Data(line.utf8))
+      } catch {
+        // ログの書き込み失敗はアプリの動作に影響させない
+      }
+    }
+  }
+
+  /// 直近のログを古い順に返す（ローテーション済みのファイルもさかのぼって読む）。
+  ///
+  /// 呼び出し前に依頼した書き込みが終わるのを待ってから読む。
+  /// - Parameter limit: 最大行数
+  public func recentLines(limit: Int = 500) -> [String] {
+    guard limit > 0 else { return [] }
+    return queue.sync {
+      var lines: [String] = []
+      for url in [fileURL] + (1...max(maxArchivedFiles, 1)).map(archiveURL) {
+        guard lines.count < limit,
+          let content = try? String(contentsOf: url, encoding: .utf8)
+        else { continue }
+        let fileLines = content.split(separator: "\n").map(String.init)
+        lines = fileLines.suffix(limit - lines.count) + lines
+      }
+      return lines
+    }
+  }
+
+  /// ローテーション済みのファイルの URL（n が大きいほど古い）
+  private func archiveURL(_ n: Int) -> URL {
+    directory.appendingPathComponent("ignitero.\(n).log")
+  }
+
+  /// 書き込むと上限サイズを超える場合に、ファイルを 1 つずつ繰り下げる。
+  private func rotateIfNeeded(adding byteCount: Int) {
+    let fileManager = FileManager.default
+    let size = (try? fileManager.attributesOfItem(atPath: fileURL.path)[.size] as? Int) ?? 0
+    guard size > 0, size + byteCount > maxFileSize else { return }
+
+    try? fileManager.removeItem(at: archiveURL(maxArchivedFiles))
+    if maxArchivedFiles > 1 {
+      for n in stride(from: maxArchivedFiles - 1, through: 1, by: -1) {
+        try? fileManager.moveItem(at: archiveURL(n), to: archiveURL(n + 1))
+      }
+    }
+    if maxArchivedFiles > 0 {
+      try? fileManager.moveItem(at: fileURL, to: archiveURL(1))
+    } else {
+      try? fileManager.removeItem(at: fileURL)
+    }
+  }
+}
+
+// MARK: - LogMessage
+
+/// 補間した値を統合ログに公開するか
+public enum LogPrivacy: Sendable {
+  case `public`
+  case `private`
+}
+
+/// ログの本文。
+///
+/// `os.Logger` と同じく、補間した値は既定で統合ログでは `<private>` に伏せる（整数と真偽値は
+/// 公開）。パスや検索語などの利用者のデータを含まない値は `privacy: .public` で公開する。
+/// ログファイルは利用者が不具合報告に添付するためのもので、伏せずに書き込む。
+public struct LogMessage: ExpressibleByStringInterpolation, Sendable {
+  /// 伏せずに組み立てた本文（ログファイル用）
+  let text: String
+  /// 非公開の値を `<private>` にした本文（統合ログ用）
+  let redactedText: String
+
+  public init(stringLiteral value: String) {
+    self.text = value
+    self.redactedText = value
+  }
+
+  public init(stringInterpolation: StringInterpolation) {
+    self.text = stringInterpolation.text
+    self.redactedText = stringInterpolation.redactedText
+  }
+
+  public struct StringInterpolation: StringInterpolationProtocol {
+    var text = ""
+    var redactedText = ""
+
+    public init(literalCapacity: Int, interpolationCount: Int) {
+      text.reserveCapacity(literalCapacity)
+      redactedText.reserveCapacity(literalCapacity)
+    }
+
+    public mutating func appendLiteral(_ literal: String) {
+      text += literal
+      redactedText += literal
+    }
+
+    public mutating func appendInterpolation<T>(_ value: T, privacy: LogPrivacy = .private) {
+      let description = String(describing: value)
+      text += description
+      redactedText += privacy == .public ? description : "<private>"
+    }
+
+    public mutating func appendInterpolation(_ value: some BinaryInteger) {
+      appendInterpolation(value, privacy: .public)
+    }
+
+    public mutating func appendInterpolation(_ value: Bool) {
+      appendInterpolation(value, privacy: .public)
+    }
+  }
+}
+
+// MARK: - AppLogger
+
+/// 統合ログ（Console.app）とログファイルの両方へ出力するロガー。
+///
+/// 本文は `LogMessage` で、統合ログには補間した値のうち公開するものだけを出力する。
+public struct AppLogger: Sendable {
+  private let logger: Logger
+  private let category: String
+  private let logFile: LogFile
+
+  /// AppLogger を初期化する。
+  ///
+  /// - Parameters:
+  ///   - category: 出力元のカテゴリ
+  ///   - logFile: 書き込むログファイル
+  public init(category: String, logFile: LogFile = .shared) {
+    self.logger = Logger(subsystem: "com.ignitero.launcher", category: category)
+    self.category = category
+    self.logFile = logFile
+  }
+
+  public func debug(_ message: @autoclosure () -> LogMessage) {
+    log(.debug, message())
+  }
+
+  public func info(_ message: @autoclosure () -> LogMessage) {
+    log(.info, message())
+  }
+
+  /// 統合ログには notice として、ログファイルには info として出力する。
+  public func notice(_ message: @autoclosure () -> LogMessage) {
+    let message = message()
+    logger.notice("\(message.redactedText, privacy: .public)")
+    logFile.write(.info, category: category, message: message.text)
+  }
+
+  public func warning(_ message: @autoclosure () -> LogMessage) {
+    log(.warning, message())
+  }
+
+  public func error(_ message: @autoclosure () -> LogMessage) {
+    log(.error, message())
+  }
+
+  private func log(_ level: LogLevel, _ message: LogMessage) {
+    let redacted = message.redactedText
+    switch level {
+    case .debug: logger.debug("\(redacted, privacy: .public)")
+    case .info: logger.info("\(redacted, privacy: .public)")
+    case .warning: logger.warning("\(redacted, privacy: .public)")
+    case .error: logger.error("\(redacted, privacy: .public)")
+    }
+    logFile.write(level, category: category, message: message.text)
+  }
+}
@@ -1,11 +1,10 @@
+import AppKit
 import Foundation
-import os
 
 @MainActor
 @Observable
 public final class CacheBootstrap {
-  private static let logger = Logger(
-    subsystem: "com.ignitero.launcher", category: "CacheBootstrap")
+  private static let logger = AppLogger(category: "CacheBootstrap")
 
   // MARK: - Dependencies
 
@@ -13,12 +12,41 @@ public final class CacheBootstrap {
   private let cacheDatabase: any CacheDatabaseProtocol
   private let appScanner: any AppScannerProtocol
   private let directoryScanner: any DirectoryScannerProtocol
+  /// スキャン後に不要なアイコンを掃除するキャッシュ（nil の場合は掃除しない）
+  private let iconCacheManager: IconCacheManager?
+  /// SSH ホスト・tmux セッションを返すプロバイダー
+  private let terminalTargetProvider: any TerminalTargetProviding
+  /// エディタで最近開いたフォルダを返すプロバイダー
+  private let recentWorkspaceProvider: any RecentWorkspaceProviding
+  /// 自動更新を見送るかの判定に使う電源と負荷の状態
+  private let systemLoadProvider: any SystemLoadProviding
 
   // MARK: - Observable Properties
 
   public private(set) var isScanning: Bool = false
   public var autoUpdateTask: Task<Void, Never>?
   public private(set) var lastScanDate: Date?
+  /// 直近のスキャンでの登録ディレクトリごとの状況（登録順）
+  public private(set) var scanStatuses: [DirectoryScanStatus] = []
+  /// バックグラウンドのインデックス作成（自動更新）を一時停止しているか
+  public private(set) var isIndexingPaused: Bool = false
+  /// 直近のスキャンの所要時間と件数（起動後にスキャンしていない場合は nil）
+  public private(set) var lastScanMetrics: ScanMetrics?
+  /// 直近の自動更新を見送った理由（実行した場合は nil）
+  public private(set) var lastAutoUpdateDeferral: AutoUpdateDeferral?
+
+  /// 一時停止した回数（実行中のスキャンが途中で一時停止されたかの判定に使う）
+  private var pauseGeneration = 0
+  /// 実行中のスキャンの打ち切り（一時停止すると走査をディレクトリ・バンドル単位で止める）
+  private var activeScanCancellation: ScanCancellation?
+
+  /// 自動更新の間隔の起点（起動後にスキャンしていない場合。最初に自動更新を開始した日時）
+  private var autoUpdateStartedAt: Date?
+  /// スリープからの復帰の通知の監視
+  private var wakeObserver: (any NSObjectProtocol)?
+
+  /// 自動更新を見送った後、状態を確かめ直すまでの時間（ナノ秒。15 分）
+  nonisolated static let deferredRetryNanoseconds: UInt64 = 15 * 60 * 1_000_000_000
 
   // MARK: - Callbacks
 
@@ -33,12 +61,20 @@ public final class CacheBootstrap {
     settingsManager: SettingsManager,
     cacheDatabase: any CacheDatabaseProtocol,
     appScanner: any AppScannerProtocol,
-    directoryScanner: any DirectoryScannerProtocol
+    directoryScanner: any DirectoryScannerProtocol,
+    iconCacheManager: IconCacheManager? = nil,
+    terminalTargetProvider: (any TerminalTargetProviding)? = nil,
+    recentWorkspaceProvider: (any RecentWorkspaceProviding)? = nil,
+    systemLoadProvider: (any SystemLoadProviding)? = nil
   ) {
     self.settingsManager = settingsManager
     self.cacheDatabase = cacheDatabase
     self.appScanner = appScanner
     self.directoryScanner = directoryScanner
+    self.iconCacheManager = iconCacheManager
+    self.terminalTargetProvider = terminalTargetProvider ?? TerminalTargetProvider()
+    self.recentWorkspaceProvider = recentWorkspaceProvider ?? RecentWorkspaceImporter()
+    self.systemLoadProvider = systemLoadProvider ?? SystemLoadMonitor()
   }
 
   // MARK: - Initial Scan
@@ -73,10 +109,17 @@ public final class CacheBootstrap {
   ///
   /// 既存のタイマーは常に停止してから設定を反映するため、
   /// 設定変更後の再呼び出しで「有効→無効」「間隔変更」のどちらも反映される。
+  /// 最初の更新は前回のスキャンから間隔が過ぎた時点（過ぎていればすぐ）に行う。
+  /// 待ち時間はスリープ中も進む時計（`ContinuousClock`）で測る。
   public func startAutoUpdate() {
     // 設定にかかわらず既存のタスクを止め、現在の設定でやり直す
     stopAutoUpdate()
 
+    guard !isIndexingPaused else {
+      Self.logger.info("Indexing is paused; not starting background task")
+      return
+    }
+
     let settings = settingsManager.settings.cacheUpdate
     guard settings.autoUpdateEnabled else {
       Self.logger.info("Auto update is disabled; not starting background task")
@@ -85,11 +128,16 @@ public final class CacheBootstrap {
 
     let intervalNanoseconds = Self.autoUpdateIntervalNanoseconds(
       hours: settings.autoUpdateIntervalHours)
+    let startedAt = autoUpdateStartedAt ?? Date()
+    autoUpdateStartedAt = startedAt
+    let initialDelay = Self.autoUpdateDelayNanoseconds(
+      interval: intervalNanoseconds, since: lastScanDate ?? startedAt, now: Date())
 
     autoUpdateTask = Task { [weak self] in
+      var delay = initialDelay
       while !Task.isCancelled {
         do {
-          try await Task.sleep(nanoseconds: intervalNanoseconds)
+          try await Task.sleep(for: .nanoseconds(delay), clock: .continuous)
         } catch {
           // スリープ中にタスクがキャンセルされた
           break
@@ -97,6 +145,12 @@ public final class CacheBootstrap {
 
         guard !Task.isCancelled else { break }
 
+        // バッテリー残量が少ない・負荷が高い間は見送り、間隔より短い時間で確かめ直す
+        if self?.evaluateAutoUpdateDeferral() != nil {
+          delay = min(Self.deferredRetryNanoseconds, intervalNanoseconds)
+          continue
+        }
+        delay = intervalNanoseconds
         await self?.runScan()
       }
     }
@@ -105,24 +159,165 @@ public final class CacheBootstrap {
       "Auto update started with interval: \(settings.autoUpdateIntervalHours) hours")
   }
 
+  /// スリープから復帰したときに、自動更新の予定を決め直す。
+  ///
+  /// スリープ中に間隔が過ぎていればすぐに更新する（自動更新が止まっている場合は何もしない）。
+  public func handleWake() {
+    guard autoUpdateTask != nil else { return }
+    Self.logger.info("System woke; rescheduling auto update")
+    startAutoUpdate()
+  }
+
+  /// スリープからの復帰の監視を始める（開始済みなら何もしない）。
+  public func startObservingWake() {
+    guard wakeObserver == nil else { return }
+    wakeObserver = NSWorkspace.shared.notificationCenter.addObserver(
+      forName: NSWorkspace.didWakeNotification, object: nil, queue: .main
+    ) { [weak self] _ in
+      Task { @MainActor in
+        self?.handleWake()
+      }
+    }
+  }
+
+  /// スリープからの復帰の監視をやめる。
+  public func stopObservingWake() {
+    if let wakeObserver {
+      NSWorkspace.shared.notificationCenter.removeObserver(wakeObserver)
+    }
+    wakeObserver = nil
+  }
+
+  /// 現在の電源と負荷の状態で自動更新を見送るかを判定し、`lastAutoUpdateDeferral` に記録する。
+  ///
+  /// - Returns: 見送る場合はその理由
+  @discardableResult
+  func evaluateAutoUpdateDeferral() -> AutoUpdateDeferral? {
+    let deferral = AutoUpdateDeferral.evaluate(
+      systemLoadProvider.snapshot(), settings: settingsManager.settings.cacheUpdate)
+    lastAutoUpdateDeferral = deferral
+    switch deferral {
+    case .lowBattery(let percent):
+      Self.logger.info("Auto update deferred: battery at \(percent)%")
+    case .heavyLoad(let load):
+      Self.logger.info("Auto update deferred: system under heavy load (\(load) per core)")
+    case nil:
+      break
+    }
+    return deferral
+  }
+
   /// バックグラウンド自動更新を停止する。
   public func stopAutoUpdate() {
     autoUpdateTask?.cancel()
     autoUpdateTask = nil
   }
 
+  /// バックグラウンドのインデックス作成を一時停止する。
+  ///
+  /// 実行中のスキャンは走査中のディレクトリ・バンドルの確認時に打ち切り、保存せずに
+  /// 既存のキャッシュを保持する。手動の再構築は引き続き実行できる。
+  public func pauseIndexing() {
+    isIndexingPaused = true
+    pauseGeneration += 1
+    activeScanCancellation?.cancel()
+    stopAutoUpdate()
+    Self.logger.info("Indexing paused")
+  }
+
+  /// 一時停止したインデックス作成を再開する。
+  public func resumeIndexing() {
+    isIndexingPaused = false
+    startAutoUpdate()
+    Self.logger.info("Indexing resumed")
+  }
+
   // MARK: - Rebuild Cache
 
   /// 強制的にキャッシュを再構築する（メニューバーアクションから呼び出される）。
   ///
-  /// saveApps/saveDirectories が DELETE+INSERT を同一トランザクションで行うため、
+  /// saveApps/saveDirectories が差分適用を同一トランザクションで行うため、
   /// 事前の clearCache は不要（スキャン失敗時に空キャッシュが残る事故も防げる）。
   public func rebuildCache() async {
     await runScan()
   }
 
+  // MARK: - Scoped Refresh
+
+  /// 登録ディレクトリ 1 件だけを再スキャンし、結果をキャッシュへマージする。
+  ///
+  /// /Applications や他の登録ディレクトリは再スキャンせず、キャッシュ中の
+  /// 該当ディレクトリ由来の項目（ルート自身と走査深さ以内の項目）のみを置き換える。
+  /// - Parameter path: 再スキャンする登録ディレクトリのパス
+  /// - Returns: キャッシュを更新した場合は `true`（未登録・スキャン中・失敗時は `false`）
+  @discardableResult
+  public func refreshDirectory(path: String) async -> Bool {
+    let target = RegisteredDirectory.normalized(path)
+    guard
+      let registered = settingsManager.settings.registeredDirectories.first(where: {
+        $0.normalizedPath == target
+      })
+    else {
+      Self.logger.warning("Directory is not registered; skipping refresh: \(path)")
+      return false
+    }
+    guard !isScanning else {
+      Self.logger.info("Scan already in progress; skipping")
+      return false
+    }
+    isScanning = true
+    let generation = pauseGeneration
+    let cancellation = ScanCancellation()
+    activeScanCancellation = cancellation
+    defer {
+      isScanning = false
+      activeScanCancellation = nil
+    }
+
+    let report = await scanRegisteredDirectories([registered], cancellation: cancellation)
+    guard !isInterrupted(since: generation) else { return false }
+    guard report.incompletePaths.isEmpty else {
+      // タイムアウト・読み取り失敗時は既存キャッシュを保持する
+      Self.logger.warning("Directory refresh did not complete: \(registered.normalizedPath)")
+      return false
+    }
+
+    do {
+      let scanResult = report.result
+      let cachedApps = try await cacheDatabase.loadApps()
+      let cachedDirectories = try await cacheDatabase.loadDirectories()
+
+      // アプリスキャン無効時は既存アプリ（/Applications 由来など）を残す
+      var apps = cachedApps.filter {
+        !(registered.scanForApps && registered.owns(itemPath: $0.path))
+      }
+      apps.append(contentsOf: scanResult.apps)
+      var directories = cachedDirectories.filter { !registered.owns(itemPath: $0.path) }
+      directories.append(contentsOf: scanResult.directories)
+
+      try await saveCatalog(apps: apps, directories: directories)
+      Self.logger.info(
+        "Refreshed \(registered.normalizedPath): \(scanResult.apps.count) apps, \(scanResult.directories.count) directories"
+      )
+      return true
+    } catch {
+      // 失敗時は既存キャッシュを保持する
+      Self.logger.error("Directory refresh failed: \(error.localizedDescription)")
+      return false
+    }
+  }
+
   // MARK: - Internal
 
+  /// スキャンの開始後にインデックス作成が一時停止されたか。
+  ///
+  /// 打ち切る場合は保存せずに既存のキャッシュを保持する。
+  private func isInterrupted(since generation: Int) -> Bool {
+    guard pauseGeneration != generation else { return false }
+    Self.logger.info("Scan interrupted by pause; keeping existing cache")
+    return true
+  }
+
   /// 自動更新インターバル（時間）をナノ秒に変換する。
   /// オーバーフロー防止のため 1〜8760 時間（1年）にクランプする。
   nonisolated static func autoUpdateIntervalNanoseconds(hours: Int) -> UInt64 {
@@ -130,8 +325,79 @@ public final class CacheBootstrap {
     return clamped * 3600 * 1_000_000_000
   }
 
+  /// 次の自動更新までの待ち時間（ナノ秒）。起点から間隔が過ぎていれば 0。
+  nonisolated static func autoUpdateDelayNanoseconds(
+    interval: UInt64, since start: Date, now: Date
+  ) -> UInt64 {
+    let elapsed = now.timeIntervalSince(start)
+    guard elapsed > 0 else { return interval }
+    let elapsedNanoseconds = elapsed * 1_000_000_000
+    guard elapsedNanoseconds < Double(interval) else { return 0 }
+    return interval - UInt64(elapsedNanoseconds)
+  }
+
+  /// 所要時間をミリ秒に切り捨てる。
+  nonisolated static func milliseconds(_ duration: Duration) -> Int {
+    Int(PerformanceMonitor.durationToMilliseconds(duration))
+  }
+
   // MARK: - Private
 
+  /// アプリとディレクトリをバックグラウンドで保存する。
+  ///
+  /// 差分適用のトランザクションは数千件で数百ミリ秒かかるため、メインアクターで実行すると
+  /// その間の検索・描画が止まる。
+  private func saveCatalog(apps: [AppItem], directories: [DirectoryItem]) async throws {
+    let cacheDatabase = cacheDatabase
+    try await Task.detached(priority: .utility) {
+      try cacheDatabase.saveApps(apps)
+      try cacheDatabase.saveDirectories(directories)
+    }.value
+  }
+
+  /// 参照されていないアイコンを削除し、設定の上限サイズに収める（バックグラウンドで実行する）。
+  private func collectIconGarbage(keeping appPaths: Set<String>) async {
+    guard let iconCacheManager else { return }
+    let maxBytes = settingsManager.settings.cacheUpdate.iconCacheMaxMegabytes.map {
+      Int64(max($0, 1)) * 1_000_000
+    }
+    let result = await Task.detached {
+      iconCacheManager.collectGarbage(keeping: appPaths, maxBytes: maxBytes)
+    }.value
+    if result.removedUnreferenced > 0 || result.evicted > 0 {
+      Self.logger.info(
+        "Icon cache cleaned: \(result.removedUnreferenced) unreferenced, \(result.evicted) evicted, \(result.freedBytes) bytes freed"
+      )
+    }
+  }
+
+  /// 登録ディレクトリを設定の制限時間・同時実行数でスキャンし、状況を記録する。
+  private func scanRegisteredDirectories(
+    _ directories: [RegisteredDirectory], cancellation: ScanCancellation
+  ) async -> DirectoryScanReport {
+    let cacheSettings = settingsManager.settings.cacheUpdate
+    let report = await directoryScanner.scanConcurrently(
+      directories: directories,
+      timeout: .seconds(max(cacheSettings.scanTimeoutSeconds, 1)),
+      maxConcurrentScans: cacheSettings.maxConcurrentScans,
+      cancellation: cancellation
+    )
+    for status in report.statuses where status.state != .completed {
+      Self.logger.warning(
+        "Directory scan incomplete: \(status.path) (\(String(describing: status.state)))"
+      )
+    }
+    // 単一ディレクトリの再スキャンでは該当ディレクトリの状況だけを置き換え、
+    // 登録解除されたディレクトリの状況は捨てる
+    let latest = Dictionary(
+      (scanStatuses + report.statuses).map { ($0.path, $0) },
+      uniquingKeysWith: { _, new in new })
+    scanStatuses = settingsManager.settings.registeredDirectories.compactMap {
+      latest[$0.normalizedPath]
+    }
+    return report
+  }
+
   /// アプリスキャンとディレクトリスキャンを実行し、結果をデータベースに保存する。
   /// - Returns: スキャンが完了しキャッシュが更新された場合は `true`
   @discardableResult
@@ -141,52 +407,144 @@ public final class CacheBootstrap {
       return false
     }
     isScanning = true
+    let cancellation = ScanCancellation()
+    activeScanCancellation = cancellation
     defer {
       isScanning = false
+      activeScanCancellation = nil
       lastScanDate = Date()
     }
 
     let settings = settingsManager.settings
+    let clock = ContinuousClock()
+    let scanStart = clock.now
+    let generation = pauseGeneration
 
     // アプリスキャン（除外フィルタ前の全アプリ。設定画面の除外アプリ一覧にも使う）
     // スキャンはバックグラウンドで実行されるため、メインスレッドはブロックされない。
-    let scannedAllApps: [AppItem]
+    // 更新日時が変わっていないバンドルはキャッシュの項目を使い、Info.plist を読み直さない。
+    var scannedAllApps: [AppItem]
+    let cachedModificationTimes: [String: TimeInterval]
     do {
-      scannedAllApps = try await appScanner.scanApplications(excludedApps: [])
+      let cachedApps = (try? await cacheDatabase.loadApps()) ?? []
+      cachedModificationTimes = Dictionary(
+        cachedApps.compactMap { app in app.bundleModificationTime.map { (app.path, $0) } },
+        uniquingKeysWith: { _, last in last })
+      scannedAllApps = try await appScanner.scanApplications(
+        excludedApps: [], previous: cachedApps, cancellation: cancellation)
     } catch {
       // 失敗時は既存キャッシュを保持する（空配列で上書きしない）
       Self.logger.error("App scan failed: \(error.localizedDescription)")
       return false
     }
+    let appScanDuration = clock.now - scanStart
+    guard !isInterrupted(since: generation) else { return false }
 
     // ランチャー用に除外フィルタを適用する
     var allApps = scannedAllApps.filter {
       !appScanner.isExcluded($0, excludedApps: settings.excludedApps)
     }
 
-    // ディレクトリスキャン
-    let allDirectories: [DirectoryItem]
+    // ディレクトリスキャン（ディレクトリごとに制限時間付きで並列実行する）
+    let directoryScanStart = clock.now
+    let report = await scanRegisteredDirectories(
+      settings.registeredDirectories, cancellation: cancellation)
+    let directoryScanDuration = clock.now - directoryScanStart
+    guard !isInterrupted(since: generation) else { return false }
+    var allDirectories = report.result.directories
+    allApps.append(contentsOf: report.result.apps)
+
+    // Launch Services に登録された、スキャン対象外の場所にあるアプリ（設定で有効な場合のみ）
+    if settings.cacheUpdate.discoverRegisteredApps {
+      let registeredApps = await appScanner.scanRegisteredApplications(
+        excludingPaths: Set(scannedAllApps.map(\.path)).union(allApps.map(\.path)))
+      scannedAllApps.append(contentsOf: registeredApps)
+      scannedAllApps.sort {
+        $0.name.localizedCaseInsensitiveCompare($1.name) == .orderedAscending
+      }
+      allApps.append(
+        contentsOf: registeredApps.filter {
+          !appScanner.isExcluded($0, excludedApps: settings.excludedApps)
+        })
+    }
+
+    // 完了しなかったディレクトリ（切断されたボリュームなど）はキャッシュの項目を残す
+    let incomplete = settings.registeredDirectories.filter {
+      report.incompletePaths.contains($0.normalizedPath)
+    }
+    if !incomplete.isEmpty {
+      do {
+        let cachedApps = try await cacheDatabase.loadApps()
+        let cachedDirectories = try await cacheDatabase.loadDirectories()
+        let scannedAppPaths = Set(allApps.map(\.path))
+        let scannedDirectoryPaths = Set(allDirectories.map(\.path))
+        allApps.append(
+          contentsOf: cachedApps.filter { app in
+            !scannedAppPaths.contains(app.path)
+              && incomplete.contains { $0.scanForApps && $0.owns(itemPath: app.path) }
+          })
+        allDirectories.append(
+          contentsOf: cachedDirectories.filter { dir in
+            !scannedDirectoryPaths.contains(dir.path)
+              && incomplete.contains { $0.owns(itemPath: dir.path) }
+          })
+      } catch {
+        Self.logger.error(
+          "Failed to load cache for incomplete directories: \(error.localizedDescription)")
+        return false
+      }
+    }
+
+    // エディタで最近開いたフォルダ（スキャン済みのディレクトリと重複するものは除く）
+    if !settings.recentWorkspaceEditors.isEmpty {
+      let scannedPaths = Set(allDirectories.map(\.path))
+      let recent = await recentWorkspaceProvider.recentWorkspaces(
+        for: settings.recentWorkspaceEditors)
+      allDirectories.append(contentsOf: recent.filter { !scannedPaths.contains($0.path) })
+    }
+    guard !isInterrupted(since: generation) else { return false }
+
+    // データベースに保存（saveApps/saveDirectories は path キーの差分適用を
+    // 同一トランザクションで行うため、更新はアトミック）
     do {
-      let scanResult = try directoryScanner.scan(directories: settings.registeredDirectories)
-      allDirectories = scanResult.directories
-      allApps.append(contentsOf: scanResult.apps)
+      try await saveCatalog(apps: allApps, directories: allDirectories)
+      // 保存したアプリと除外中のアプリ以外のアイコンを掃除する
+      await collectIconGarbage(
+        keeping: Set(scannedAllApps.map(\.path)).union(allApps.map(\.path)))
     } catch {
-      // 失敗時は既存キャッシュを保持する
-      Self.logger.error("Directory scan failed: \(error.localizedDescription)")
-      return false
+      Self.logger.error("Failed to save scan results: \(error.localizedDescription)")
     }
 
-    // データベースに保存（saveApps/saveDirectories は DELETE+INSERT を
-    // 同一トランザクションで行うため、置換はアトミック）
+    // SSH ホスト・tmux セッション（無効にした場合は保存済みの一覧を消す）
+    let terminalTargets =
+      settings.cacheUpdate.indexTerminalTargets ? await terminalTargetProvider.targets() : []
     do {
-      try cacheDatabase.saveApps(allApps)
-      try cacheDatabase.saveDirectories(allDirectories)
+      try cacheDatabase.saveTerminalTargets(terminalTargets)
     } catch {
-      Self.logger.error("Failed to save scan results: \(error.localizedDescription)")
+      Self.logger.error("Failed to save terminal targets: \(error.localizedDescription)")
+    }
+
+    // 所要時間と件数を記録する（更新日時が変わっていないアプリが多いほど速い）
+    let metrics = ScanMetrics(
+      totalMilliseconds: Self.milliseconds(clock.now - scanStart),
+      appScanMilliseconds: Self.milliseconds(appScanDuration),
+      directoryScanMilliseconds: Self.milliseconds(directoryScanDuration),
+      appCount: scannedAllApps.count,
+      unchangedAppCount: scannedAllApps.count(where: { app in
+        app.bundleModificationTime != nil
+          && cachedModificationTimes[app.path] == app.bundleModificationTime
+      }),
+      directoryCount: allDirectories.count)
+    lastScanMetrics = metrics
+    do {
+      try cacheDatabase.recordScanMetrics(metrics)
+    } catch {
+      Self.logger.error("Failed to record scan metrics: \(error.localizedDescription)")
     }
 
     Self.logger.info(
-      "Scan completed: \(allApps.count) apps, \(allDirectories.count) directories")
+      "Scan completed in \(metrics.totalMilliseconds)ms: \(allApps.count) apps (\(metrics.unchangedAppCount) unchanged), \(allDirectories.count) directories"
+    )
 
     // 全スキャン経路（起動時・自動更新・手動再構築）共通で完了を通知する
     await onScanCompleted?(scannedAllApps)
@@ -0,0 +1,280 @@
+import AppKit
+import Darwin
+import Foundation
+
+// MARK: - CrashReport
+
+/// 前回の異常終了の記録
+public struct CrashReport: Sendable, Equatable {
+  /// 不具合報告を受け付ける GitHub リポジトリ
+  public static let repository = "owayo/ignitero-launcher"
+
+  /// 記録を保存したファイル（不具合報告に添付する）
+  public let fileURL: URL
+  /// 原因とバックトレース
+  public let text: String
+
+  public init(fileURL: URL, text: String) {
+    self.fileURL = fileURL
+    self.text = text
+  }
+
+  /// 捕捉されない例外の記録の本文を組み立てる。
+  static func exceptionText(
+    name: String, reason: String?, callStack: [String], date: Date = Date()
+  ) -> String {
+    let header =
+      "Ignitero Launcher v\(Ignitero.version) crashed (\(date.formatted(.iso8601)))"
+    let lines =
+      [header, "Exception: \(name)", "Reason: \(reason ?? "(none)")", "", "Backtrace:"]
+      + callStack
+    return lines.joined(separator: "\n") + "\n"
+  }
+
+  /// 記録を貼り付けた GitHub の新しい Issue を開く URL。
+  ///
+  /// URL の長さの上限があるため、本文には記録の先頭 `maxLogLength` 文字だけを入れる。
+  /// 全文は `fileURL` のファイルを添付してもらう。
+  public func issueURL(maxLogLength: Int = 4000) -> URL? {
+    let firstLine = text.split(separator: "\n").dropFirst().first.map(String.init)
+    let log =
+      text.count > maxLogLength ? String(text.prefix(maxLogLength)) + "\n…" : text
+    let body = """
+      ## 発生時の操作
+
+      <!-- クラッシュする直前に行っていた操作を書いてください -->
+
+      ## クラッシュの記録
+
+      ```
+      \(log)
+      ```
+
+      全文: `\(fileURL.lastPathComponent)`（ログフォルダ内のファイルを添付してください）
+      """
+    var components = URLComponents(string: "https://github.com/\(Self.repository)/issues/new")
+    components?.queryItems = [
+      URLQueryItem(name: "title", value: "Crash: \(firstLine ?? "v\(Ignitero.version)")"),
+      URLQueryItem(name: "body", value: body),
+    ]
+    return components?.url
+  }
+}
+
+// MARK: - CrashReportStore
+
+/// 異常終了の記録をログディレクトリに保存する。
+///
+/// 記録は `crash.log` に書き込み、次回起動時に `takePendingReport` で
+/// `crash-<日時>.log` へ移して報告を促す（直近 `maxArchivedReports` 件を残す）。
+public struct CrashReportStore: Sendable {
+  /// アプリ全体で使う保存先（ログファイルと同じディレクトリ）
+  public static let shared = CrashReportStore(directory: LogFile.shared.directory)
+
+  /// 残しておく報告済みの記録の数
+  static let maxArchivedReports = 5
+
+  /// 記録を保存するディレクトリ
+  public let directory: URL
+
+  public init(directory: URL) {
+    self.directory = directory
+  }
+
+  /// まだ報告を促していない記録
+  public var pendingURL: URL {
+    directory.appendingPathComponent("crash.log")
+  }
+
+  /// 記録を書き込む（前回の未報告の記録は上書きする）。
+  public func record(_ text: String) throws {
+    try FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
+    try Data(text.utf8).write(to: pendingURL)
+  }
+
+  /// 未報告の記録があれば `crash-<日時>.log` へ移して返す。
+  ///
+  /// 移した後は同じ記録で再び報告を促さない。
+  public func takePendingReport(now: Date = Date()) -> CrashReport? {
+    let fileManager = FileManager.default
+    guard let data = fileManager.contents(atPath: pendingURL.path), !data.isEmpty else {
+      return nil
+    }
+    let text = String(decoding: data, as: UTF8.self)
+    let formatter = DateFormatter()
+    formatter.locale = Locale(identifier: "en_US_POSIX")
+    formatter.dateFormat = "yyyyMMdd-HHmmss"
+    let archiveURL = directory.appendingPathComponent("crash-\(formatter.string(from: now)).log")
+    do {
+      try? fileManager.removeItem(at: archiveURL)
+      try fileManager.moveItem(at: pendingURL, to: archiveURL)
+    } catch {
+      // 移せない場合は毎回促さないよう消しておく
+      try? fileManager.removeItem(at: pendingURL)
+      return CrashReport(fileURL: pendingURL, text: text)
+    }
+    pruneArchivedReports()
+    return CrashReport(fileURL: archiveURL, text: text)
+  }
+
+  /// 報告済みの記録のうち古いものを削除する。
+  private func pruneArchivedReports() {
+    let fileManager = FileManager.default
+    let names = (try? fileManager.contentsOfDirectory(atPath: directory.path)) ?? []
+    let archived = names.filter { $0.hasPrefix("crash-") && $0.hasSuffix(".log") }.sorted()
+    for name in archived.dropLast(Self.maxArchivedReports) {
+      try? fileManager.removeItem(at: directory.appendingPathComponent(name))
+    }
+  }
+}
+
+// MARK: - Crash Handlers
+
+/// シグナルハンドラから参照する書き込み先（install で確保し、以後解放しない）
+nonisolated(unsafe) private var crashLogPath: UnsafeMutablePointer<CChar>?
+/// シグナルハンドラが書き込む見出し
+nonisolated(unsafe) private var crashLogHeader: UnsafeMutablePointer<CChar>?
+/// 記録済みか（例外の記録後の abort で上書きしない）
+nonisolated(unsafe) private var isCrashRecorded = false
+/// 例外で終了する前に実行する後始末
+nonisolated(unsafe) private var crashCleanup: (@MainActor () -> Void)?
+
+/// 記録するシグナル（Swift の実行時エラーは SIGTRAP / SIGILL になる）
+private let crashSignals: [Int32] = [SIGABRT, SIGSEGV, SIGBUS, SIGILL, SIGTRAP, SIGFPE]
+
+/// クラッシュのシグナルのハンドラ。
+///
+/// async-signal-safe な `open` / `write` / `backtrace_symbols_fd` だけで記録し、
+/// 既定の動作に戻してシグナルを送り直す（クラッシュレポートも通常どおり作られる）。
+private func handleCrashSignal(_ signal: Int32) {
+  if !isCrashRecorded, let path = crashLogPath {
+    isCrashRecorded = true
+    let fd = open(path, O_WRONLY | O_CREAT | O_TRUNC, 0o600)
+    if fd >= 0 {
+      writeCString(fd, crashLogHeader)
+      writeCString(fd, "Signal: ")
+      writeDecimal(fd, signal)
+      writeCString(fd, "\n\nBacktrace:\n")
+      withUnsafeTemporaryAllocation(of: UnsafeMutableRawPointer?.self, capacity: 128) { frames in
+        let count = backtrace(frames.baseAddress, Int32(frames.count))
+        backtrace_symbols_fd(frames.baseAddress, count, fd)
+      }
+      close(fd)
+    }
+  }
+  Darwin.signal(signal, SIG_DFL)
+  raise(signal)
+}
+
+private func writeCString(_ fd: Int32, _ string: UnsafePointer<CChar>?) {
+  guard let string else { return }
+  _ = write(fd, string, strlen(string))
+}
+
+/// シグナル番号を 10 進数で書き込む（シグナルハンドラ内で String を作らないため）。
+private func writeDecimal(_ fd: Int32, _ value: Int32) {
+  withUnsafeTemporaryAllocation(of: UInt8.self, capacity: 12) { buffer in
+    var remaining = value.magnitude
+    var index = buffer.count
+    repeat {
+      index -= 1
+      buffer[index] = UInt8(ascii: "0") + UInt8(remaining % 10)
+      remaining /= 10
+    } while remaining > 0 && index > 0
+    _ = write(fd, buffer.baseAddress! + index, buffer.count - index)
+  }
+}
+
+// MARK: - CrashReporter
+
+/// 異常終了を記録し、次回起動時に不具合報告を促す。
+///
+/// どのスレッドで起きたクラッシュのシグナル（Swift の実行時エラーを含む）と捕捉されない
+/// 例外も `CrashReportStore` の `crash.log` に原因とバックトレースを書き込むため、
+/// バックグラウンドのタスクで落ちても記録が残る。例外がメインスレッドで起きた場合は
+/// 終了する前に `onCrash` の後始末（グローバルホットキーの解除・メニューバーアイコンの削除）
+/// を実行する。シグナルの場合はハンドラ内で安全に実行できないため、プロセスの終了時に
+/// OS が解放する。
+public enum CrashReporter {
+  private static let logger = AppLogger(category: "CrashReporter")
+
+  /// 記録を書き込む保存先
+  nonisolated(unsafe) private static var store = CrashReportStore.shared
+
+  /// ハンドラを登録する（起動時に 1 回だけ呼ぶ）。
+  ///
+  /// - Parameters:
+  ///   - store: 記録の保存先
+  ///   - onCrash: 捕捉されない例外で終了する前に実行する後始末
+  @MainActor
+  public static func install(
+    store: CrashReportStore = .shared, onCrash: @escaping @MainActor () -> Void
+  ) {
+    self.store = store
+    crashCleanup = onCrash
+    // シグナルハンドラ内でディレクトリを作れないため先に作っておく
+    try? FileManager.default.createDirectory(
+      at: store.directory, withIntermediateDirectories: true)
+    crashLogPath = strdup(store.pendingURL.path)
+    crashLogHeader = strdup("Ignitero Launcher v\(Ignitero.version) crashed\n")
+    for signal in crashSignals {
+      Darwin.signal(signal, handleCrashSignal)
+    }
+    NSSetUncaughtExceptionHandler { exception in
+      CrashReporter.recordException(exception)
+    }
+  }
+
+  /// 捕捉されない例外を記録し、メインスレッドであれば後始末を実行する。
+  private static func recordException(_ exception: NSException) {
+    guard !isCrashRecorded else { return }
+    isCrashRecorded = true
+    let text = CrashReport.exceptionText(
+      name: exception.name.rawValue, reason: exception.reason,
+      callStack: exception.callStackSymbols)
+    logger.error(
+      "Uncaught exception: \(exception.name.rawValue): \(exception.reason ?? "(none)")")
+    do {
+      try store.record(text)
+    } catch {
+      logger.error("Failed to record crash report: \(error.localizedDescription)")
+    }
+    if Thread.isMainThread {
+      MainActor.assumeIsolated {
+        crashCleanup?()
+      }
+    }
+  }
+
+  /// 前回の異常終了の記録があれば、不具合報告を促すダイアログを表示する。
+  ///
+  /// 「不具合を報告」は記録を貼り付けた Issue を開き、添付できるよう記録のファイルを Finder で示す。
+  @MainActor
+  public static func presentPendingReport(store: CrashReportStore = .shared) {
+    guard let report = store.takePendingReport() else { return }
+    logger.warning("Previous session crashed; report saved to \(report.fileURL.path)")
+
+    let alert = NSAlert()
+    alert.alertStyle = .warning
+    alert.messageText = "Ignitero Launcher が前回予期せず終了しました"
+    alert.informativeText =
+      "クラッシュの記録を \(report.fileURL.lastPathComponent) に保存しました。"
+      + "不具合として報告していただけると、原因の調査に役立ちます。"
+    alert.addButton(withTitle: "不具合を報告")
+    alert.addButton(withTitle: "Finder で表示")
+    alert.addButton(withTitle: "閉じる")
+    NSApp.activate(ignoringOtherApps: true)
+
+    switch alert.runModal() {
+    case .alertFirstButtonReturn:
+      if let url = report.issueURL() {
+        NSWorkspace.shared.open(url)
+      }
+      NSWorkspace.shared.activateFileViewerSelecting([report.fileURL])
+    case .alertSecondButtonReturn:
+      NSWorkspace.shared.activateFileViewerSelecting([report.fileURL])
+    default:
+      break
+    }
+  }
+}
@@ -1,7 +1,6 @@
 import AppKit
 import Carbon.HIToolbox
 import KeyboardShortcuts
-import os
 
 // MARK: - Shortcut Name Definition
 
@@ -41,8 +40,9 @@ private func carbonHotKeyEventHandler(
   }
 
   // MainActor のエグゼキュータへ確実にディスパッチする。
+  let id = hotKeyID.id
   Task { @MainActor in
-    GlobalShortcutManager.handleHotKeyEvent()
+    GlobalShortcutManager.handleHotKeyEvent(id: id)
   }
 
   return noErr
@@ -54,6 +54,8 @@ private func carbonHotKeyEventHandler(
 ///
 /// Carbon `RegisterEventHotKey` を直接使用して Option+Space ショートカットを登録し、
 /// ランチャーの表示/非表示トグルと IME の英数切り替えを行う。
+/// 設定の `hotkey_bindings` で追加されたショートカットも同じハンドラで登録し、
+/// 発火時は `onModeHotkey` でモードを通知する。
 @MainActor
 public final class GlobalShortcutManager {
 
@@ -62,6 +64,12 @@ public final class GlobalShortcutManager {
   /// Carbon hotkey signature "IGNT"
   nonisolated static let hotKeySignature: UInt32 = 0x4947_4E54
 
+  /// メインのトグルショートカットの hotkey ID
+  nonisolated static let toggleHotKeyID: UInt32 = 1
+
+  /// 追加バインディングに割り当てる hotkey ID の開始値
+  nonisolated static let bindingHotKeyIDBase: UInt32 = 100
+
   // MARK: - Properties
 
   /// ランチャーウィンドウの表示/非表示を管理する WindowManager
@@ -76,6 +84,39 @@ public final class GlobalShortcutManager {
   /// Carbon イベントハンドラ参照
   private var carbonEventHandlerRef: EventHandlerRef?
 
+  /// 追加バインディングの Carbon hotkey 参照
+  private var bindingHotKeyRefs: [EventHotKeyRef] = []
+
+  /// 追加バインディングの hotkey ID → モード
+  private var bindingModes: [UInt32: LauncherMode] = [:]
+
+  /// 追加バインディングの hotkey ID → 入力ソースの扱い（全体の設定に従うものは含めない）
+  private var bindingIMEBehaviors: [UInt32: IMEBehavior] = [:]
+
+  /// 修飾キーの 2 回押しのバインディング（修飾キー → hotkey ID）
+  private var doubleTapBindingIDs: [HotkeyModifier: UInt32] = [:]
+
+  /// 修飾キーの 2 回押しを検出するモニター
+  private let modifierTapMonitor: any ModifierTapMonitoring
+
+  /// ランチャー表示時の入力ソースの扱い（設定の `window_behavior.ime_behavior`）
+  public var imeBehavior: IMEBehavior = .restoreOnHide
+
+  /// 直近にランチャーを表示したときの入力ソースの扱い（閉じたときに戻すかの判定に使う）
+  public private(set) var activeIMEBehavior: IMEBehavior = .restoreOnHide
+
+  /// 設定から渡された追加バインディング（再登録時に復元する）
+  public private(set) var bindings: [HotkeyBinding] = []
+
+  /// ランチャー表示のホットキーを登録できているか（自己診断用）
+  public private(set) var isToggleHotKeyRegistered = false
+
+  /// 登録に失敗した追加バインディング（他アプリとの衝突など）
+  public private(set) var failedBindings: [HotkeyBinding] = []
+
+  /// 追加バインディングが発火したときのコールバック（モードとバインディングの入力ソースの扱い）
+  public var onModeHotkey: ((LauncherMode, IMEBehavior?) -> Void)?
+
   /// ショートカット変更通知の監視トークン
   private var shortcutChangeObserver: (any NSObjectProtocol)?
 
@@ -88,8 +129,7 @@ public final class GlobalShortcutManager {
   /// キーリピート抑制の最小間隔
   private let debounceInterval: Duration
 
-  private static let logger = Logger(
-    subsystem: "com.ignitero.launcher", category: "GlobalShortcut")
+  private static let logger = AppLogger(category: "GlobalShortcut")
 
   // MARK: - Initialization
 
@@ -99,21 +139,29 @@ public final class GlobalShortcutManager {
   ///   - windowManager: ランチャーウィンドウの管理を行う WindowManager
   ///   - imeController: IME の切り替えを行うコントローラ
   ///   - debounceInterval: キーリピート抑制の最小間隔。テスト時は `.zero` を指定可能。
+  ///   - modifierTapMonitor: 修飾キーの 2 回押しを検出するモニター（nil の場合はイベントタップ）
   public init(
     windowManager: WindowManager,
     imeController: any IMEControlling,
-    debounceInterval: Duration = .milliseconds(300)
+    debounceInterval: Duration = .milliseconds(300),
+    modifierTapMonitor: (any ModifierTapMonitoring)? = nil
   ) {
     self.windowManager = windowManager
     self.imeController = imeController
     self.debounceInterval = debounceInterval
+    self.modifierTapMonitor = modifierTapMonitor ?? ModifierTapMonitor()
   }
 
   // MARK: - Static Callback Entry Point
 
   /// Carbon イベントハンドラから呼び出されるエントリポイント。
-  static func handleHotKeyEvent() {
-    activeInstance?.handleShortcut()
+  static func handleHotKeyEvent(id: UInt32 = toggleHotKeyID) {
+    guard let instance = activeInstance else { return }
+    if id == toggleHotKeyID {
+      instance.handleShortcut()
+    } else {
+      instance.handleBindingHotKey(id: id)
+    }
   }
 
   // MARK: - Setup / Teardown
@@ -167,7 +215,7 @@ public final class GlobalShortcutManager {
     }
 
     // ホットキーを登録
-    let hotKeyID = EventHotKeyID(signature: Self.hotKeySignature, id: 1)
+    let hotKeyID = EventHotKeyID(signature: Self.hotKeySignature, id: Self.toggleHotKeyID)
     let regStatus = RegisterEventHotKey(
       keyCode,
       modifiers,
@@ -180,10 +228,13 @@ public final class GlobalShortcutManager {
     Self.logger.notice(
       "RegisterEventHotKey status: \(regStatus) (0=success)")
 
+    isToggleHotKeyRegistered = regStatus == noErr
     if regStatus != noErr {
       Self.logger.error("Failed to register Carbon hotkey: \(regStatus)")
     }
 
+    registerBindings()
+
     // ショートカット変更通知を監視
     // OperationQueue.main は MainActor のエグゼキュータと一致する保証がないため、
     // Task で MainActor に確実にディスパッチする。
@@ -208,10 +259,12 @@ public final class GlobalShortcutManager {
       NotificationCenter.default.removeObserver(observer)
       shortcutChangeObserver = nil
     }
+    unregisterBindings()
     if let ref = carbonHotKeyRef {
       UnregisterEventHotKey(ref)
       carbonHotKeyRef = nil
     }
+    isToggleHotKeyRegistered = false
     if let ref = carbonEventHandlerRef {
       RemoveEventHandler(ref)
       carbonEventHandlerRef = nil
@@ -229,13 +282,204 @@ public final class GlobalShortcutManager {
     setup()
   }
 
+  // MARK: - Launcher Shortcut
+
+  /// 今のランチャー表示のショートカット（プロファイルの切り替え時に書き戻す）
+  public var launcherShortcut: LauncherShortcut? {
+    KeyboardShortcuts.Name.toggleLauncher.shortcut.map {
+      LauncherShortcut(keyCode: $0.carbonKeyCode, modifiers: $0.carbonModifiers)
+    }
+  }
+
+  /// プロファイルのランチャー表示のショートカットに切り替える。
+  ///
+  /// KeyboardShortcuts の変更通知で Carbon ホットキーを再登録する。nil の場合は今のまま。
+  public func applyLauncherShortcut(_ shortcut: LauncherShortcut?) {
+    guard let shortcut, shortcut != launcherShortcut else { return }
+    KeyboardShortcuts.setShortcut(
+      KeyboardShortcuts.Shortcut(
+        carbonKeyCode: shortcut.keyCode, carbonModifiers: shortcut.modifiers),
+      for: .toggleLauncher)
+  }
+
+  // MARK: - Hotkey Bindings
+
+  /// 追加バインディングを差し替える。
+  ///
+  /// セットアップ済みの場合は既存の追加ホットキーを解除して即座に再登録する。
+  /// メインのトグルショートカットには影響しない。
+  public func updateBindings(_ newBindings: [HotkeyBinding]) {
+    bindings = newBindings
+    guard carbonEventHandlerRef != nil else { return }
+    unregisterBindings()
+    registerBindings()
+  }
+
+  /// 有効な追加バインディングを Carbon ホットキーとして登録する。
+  ///
+  /// 修飾キーの 2 回押し（`double:cmd`）は Carbon では登録できないため、イベントタップで監視する。
+  private func registerBindings() {
+    for (offset, binding) in bindings.enumerated() where binding.enabled {
+      let id = Self.bindingHotKeyIDBase + UInt32(offset)
+      if let modifier = binding.doubleTapModifier {
+        guard doubleTapBindingIDs[modifier] == nil else {
+          Self.logger.error("Duplicate double-tap binding: \(binding.shortcut)")
+          failedBindings.append(binding)
+          continue
+        }
+        doubleTapBindingIDs[modifier] = id
+        bindingModes[id] = binding.mode
+        bindingIMEBehaviors[id] = binding.imeBehavior
+        continue
+      }
+      guard let carbon = Self.carbonShortcut(for: binding) else {
+        Self.logger.error("Invalid hotkey binding: \(binding.shortcut)")
+        failedBindings.append(binding)
+        continue
+      }
+      var ref: EventHotKeyRef?
+      let status = RegisterEventHotKey(
+        carbon.keyCode,
+        carbon.modifiers,
+        EventHotKeyID(signature: Self.hotKeySignature, id: id),
+        GetApplicationEventTarget(),
+        0,
+        &ref
+      )
+      guard status == noErr, let ref else {
+        // 他アプリやメインショートカットと衝突した場合など
+        Self.logger.error(
+          "Failed to register hotkey binding \(binding.shortcut): \(status)")
+        failedBindings.append(binding)
+        continue
+      }
+      bindingHotKeyRefs.append(ref)
+      bindingModes[id] = binding.mode
+      bindingIMEBehaviors[id] = binding.imeBehavior
+    }
+    startDoubleTapMonitor()
+  }
+
+  /// 修飾キーの 2 回押しのバインディングがあれば、イベントタップでの監視を始める。
+  ///
+  /// 入力監視の権限がなく監視を始められない場合は、登録に失敗したバインディングとして扱う。
+  private func startDoubleTapMonitor() {
+    guard !doubleTapBindingIDs.isEmpty else { return }
+    let started = modifierTapMonitor.start(modifiers: Set(doubleTapBindingIDs.keys)) {
+      [weak self] modifier in
+      guard let self, let id = self.doubleTapBindingIDs[modifier] else { return }
+      self.handleBindingHotKey(id: id)
+    }
+    guard !started else { return }
+    for id in doubleTapBindingIDs.values.sorted() {
+      failedBindings.append(bindings[Int(id - Self.bindingHotKeyIDBase)])
+      bindingModes[id] = nil
+      bindingIMEBehaviors[id] = nil
+    }
+    doubleTapBindingIDs = [:]
+  }
+
+  /// 登録済みの追加バインディングをすべて解除する。
+  private func unregisterBindings() {
+    for ref in bindingHotKeyRefs {
+      UnregisterEventHotKey(ref)
+    }
+    bindingHotKeyRefs = []
+    modifierTapMonitor.stop()
+    doubleTapBindingIDs = [:]
+    bindingModes = [:]
+    bindingIMEBehaviors = [:]
+    failedBindings = []
+  }
+
+  /// バインディングを Carbon のキーコードと修飾キーへ変換する。
+  ///
+  /// - Returns: 無効なショートカット文字列の場合は nil
+  static func carbonShortcut(for binding: HotkeyBinding) -> (keyCode: UInt32, modifiers: UInt32)? {
+    guard let parsed = binding.parsed, let key = keyboardKey(named: parsed.key) else {
+      return nil
+    }
+    var flags: NSEvent.ModifierFlags = []
+    for modifier in parsed.modifiers {
+      switch modifier {
+      case .command: flags.insert(.command)
+      case .shift: flags.insert(.shift)
+      case .option: flags.insert(.option)
+      case .control: flags.insert(.control)
+      }
+    }
+    let shortcut = KeyboardShortcuts.Shortcut(key, modifiers: flags)
+    return (UInt32(shortcut.carbonKeyCode), UInt32(shortcut.carbonModifiers))
+  }
+
+  /// `HotkeyBinding.supportedKeys` のキー名を KeyboardShortcuts のキーへ変換する。
+  ///
+  /// `keycode:` 形式はキーコードをそのまま使う（JIS の英数・かなキーなど、名前のないキー向け）。
+  private static func keyboardKey(named name: String) -> KeyboardShortcuts.Key? {
+    if let code = HotkeyBinding.keyCode(fromKeyName: name) {
+      return KeyboardShortcuts.Key(rawValue: code)
+    }
+    let letters: [KeyboardShortcuts.Key] = [
+      .a, .b, .c, .d, .e, .f, .g, .h, .i, .j, .k, .l, .m,
+      .n, .o, .p, .q, .r, .s, .t, .u, .v, .w, .x, .y, .z,
+    ]
+    let digits: [KeyboardShortcuts.Key] = [
+      .zero, .one, .two, .three, .four, .five, .six, .seven, .eight, .nine,
+    ]
+    let functionKeys: [KeyboardShortcuts.Key] = [
+      .f1, .f2, .f3, .f4, .f5, .f6, .f7, .f8, .f9, .f10, .f11, .f12,
+    ]
+    if name.count == 1, let scalar = name.unicodeScalars.first {
+      switch scalar {
+      case "a"..."z": return letters[Int(scalar.value - 0x61)]
+      case "0"..."9": return digits[Int(scalar.value - 0x30)]
+      default: break
+      }
+    }
+    if name.hasPrefix("f"), let number = Int(name.dropFirst()), (1...12).contains(number) {
+      return functionKeys[number - 1]
+    }
+    switch name {
+    case "space": return .space
+    case "return": return .return
+    case "tab": return .tab
+    case "escape": return .escape
+    case ",": return .comma
+    case ".": return .period
+    case "/": return .slash
+    case ";": return .semicolon
+    case "'": return .quote
+    case "[": return .leftBracket
+    case "]": return .rightBracket
+    case "-": return .minus
+    case "=": return .equal
+    default: return nil
+    }
+  }
+
+  /// 追加バインディング発火時の処理。
+  ///
+  /// `launcher` モードはメインショートカットと同じトグル動作、それ以外は `onModeHotkey` に委譲する。
+  func handleBindingHotKey(id: UInt32) {
+    guard let mode = bindingModes[id] else { return }
+    if mode == .launcher {
+      handleShortcut(imeBehavior: bindingIMEBehaviors[id])
+      return
+    }
+    let now = ContinuousClock.now
+    guard now - lastShortcutTime >= debounceInterval else { return }
+    lastShortcutTime = now
+    onModeHotkey?(mode, bindingIMEBehaviors[id])
+  }
+
   // MARK: - Handler
 
   /// ショートカット発火時の処理。
   ///
   /// `windowManager.toggleLauncher()` を呼び出し、
-  /// ランチャーが表示状態になった場合は `imeController.switchToASCII()` で英数入力に切り替える。
-  public func handleShortcut() {
+  /// ランチャーが表示状態になった場合は `applyIMEBehavior(_:)` で入力ソースを切り替える。
+  /// - Parameter imeBehavior: 入力ソースの扱い（nil は `imeBehavior` に従う）
+  public func handleShortcut(imeBehavior: IMEBehavior? = nil) {
     // キーリピートによる連射を抑制
     let now = ContinuousClock.now
     guard now - lastShortcutTime >= debounceInterval else { return }
@@ -243,6 +487,18 @@ public final class GlobalShortcutManager {
 
     windowManager.toggleLauncher()
     if windowManager.isLauncherVisible {
+      applyIMEBehavior(imeBehavior)
+    }
+  }
+
+  /// ランチャーを表示したときに、設定に応じて英数入力へ切り替える。
+  ///
+  /// 使った扱いを `activeIMEBehavior` に記録し、閉じたときに戻すかの判定に使う。
+  /// - Parameter override: 入力ソースの扱い（nil は `imeBehavior` に従う）
+  public func applyIMEBehavior(_ override: IMEBehavior? = nil) {
+    let behavior = override ?? imeBehavior
+    activeIMEBehavior = behavior
+    if behavior.switchesToASCII {
       imeController.switchToASCII()
     }
   }
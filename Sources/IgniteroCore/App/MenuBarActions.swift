@@ -1,6 +1,5 @@
 import AppKit
 import Foundation
-import os
 
 // MARK: - MenuBarItem
 
@@ -9,29 +8,68 @@ public struct MenuBarItem: Sendable, Identifiable {
   public let id: String
   public let title: String
   public let action: @MainActor @Sendable () -> Void
+  /// 選択できるか（状態表示の項目は false）
+  public var isEnabled = true
+  /// チェックマークを付けるか
+  public var isChecked = false
+  /// サブメニューの項目（サブメニューを持たない場合は nil）
+  public var submenu: [MenuBarItem]?
+  /// 直前に区切り線を入れるか
+  public var startsSection = false
 
-  public init(id: String, title: String, action: @MainActor @Sendable @escaping () -> Void) {
+  public init(
+    id: String, title: String, isEnabled: Bool = true, isChecked: Bool = false,
+    submenu: [MenuBarItem]? = nil, startsSection: Bool = false,
+    action: @MainActor @Sendable @escaping () -> Void = {}
+  ) {
     self.id = id
     self.title = title
+    self.isEnabled = isEnabled
+    self.isChecked = isChecked
+    self.submenu = submenu
+    self.startsSection = startsSection
     self.action = action
   }
 }
 
 // MARK: - MenuBarActions
 
+/// メニューバーアイコンの表示状態。
+public enum MenuBarIconState: Equatable, Sendable {
+  /// 通常
+  case idle
+  /// 起動処理・キャッシュ更新中
+  case refreshing
+  /// アップデートあり（新バージョン）
+  case updateAvailable(String)
+
+  /// アイコンのツールチップ
+  public func toolTip(in language: AppLanguage) -> String {
+    switch self {
+    case .idle: "Ignitero Launcher"
+    case .refreshing: language.text(.refreshingToolTip)
+    case .updateAvailable(let version): language.text(.updateAvailableToolTip(version))
+    }
+  }
+}
+
 /// メニューバーのコンテキストメニュー操作を管理するクラス。
 ///
-/// `MenuBarExtra` のクリック時に表示されるメニュー項目のアクションを提供する。
+/// メニューバーアイコンの右クリック（control + クリック）で表示するメニュー項目のアクションを提供する。
 /// - ウィンドウを表示
+/// - 最近使った項目（直近 5 件）
+/// - キャッシュの状態（最終更新日時）
 /// - キャッシュを再構築
+/// - キャッシュをリセット（確認してから）
+/// - インデックス作成を一時停止
+/// - プロファイル（プロファイルを作成している場合のみ）
 /// - 設定
 /// - 終了
 @MainActor
 @Observable
 public final class MenuBarActions {
 
-  private static let logger = Logger(
-    subsystem: "com.ignitero.launcher", category: "MenuBarActions")
+  private static let logger = AppLogger(category: "MenuBarActions")
 
   // MARK: - Dependencies
 
@@ -44,6 +82,32 @@ public final class MenuBarActions {
   /// キャッシュ再構築の実体処理（AppCoordinator が注入し、スキャン結果を DB に保存・ビューモデルへ反映する）。
   public var onRebuildCache: (@MainActor @Sendable () async -> Void)?
 
+  /// キャッシュのリセットの実体処理（AppCoordinator が注入し、DB とアイコンを削除してスキャンし直す）。
+  public var onResetCache: (@MainActor @Sendable () async -> Void)?
+
+  /// キャッシュのリセットの前に確認する（お気に入り・履歴も消えるため。テスト時に差し替え可能）
+  public var confirmResetCache: @MainActor (AppLanguage) -> Bool = {
+    MenuBarActions.presentResetCacheConfirmation(in: $0)
+  }
+
+  /// キャッシュの状態とインデックス作成の一時停止（AppCoordinator が注入する）
+  public var cacheBootstrap: CacheBootstrap?
+
+  /// 最近使った項目を新しい順に返す（AppCoordinator が注入する）
+  public var recentItemsProvider: (@MainActor (Int) -> [SearchResult])?
+
+  /// 最近使った項目を開く（AppCoordinator が注入する）
+  public var onOpenRecentItem: (@MainActor (SearchResult) -> Void)?
+
+  /// 設定プロファイルを切り替える（AppCoordinator が注入する）
+  public var onSwitchProfile: (@MainActor (String) -> Void)?
+
+  /// 設定ウィンドウを表示する（アプリ側が注入する）
+  public var onOpenSettings: (@MainActor () -> Void)?
+
+  /// 「最近使った項目」に表示する件数
+  public static let recentItemsLimit = 5
+
   // MARK: - State
 
   /// キャッシュ再構築中かどうか
@@ -69,30 +133,98 @@ public final class MenuBarActions {
 
   // MARK: - Menu Items
 
+  /// メニューバー・設定ウィンドウの表示言語（`auto` は解決済み）
+  public var language: AppLanguage {
+    settingsManager.settings.language.resolved()
+  }
+
   /// メニュー項目の一覧を返す。
   public var menuItems: [MenuBarItem] {
-    [
-      MenuBarItem(id: "show-window", title: "ウィンドウを表示") { [weak self] in
+    let language = language
+    let recentItems = recentItemsProvider?(Self.recentItemsLimit) ?? []
+    let items = [
+      MenuBarItem(id: "show-window", title: language.text(.showWindow)) { [weak self] in
         self?.showWindow()
       },
+      MenuBarItem(
+        id: "recent-items", title: language.text(.recentItems), isEnabled: !recentItems.isEmpty,
+        submenu: recentItems.map { result in
+          MenuBarItem(id: "recent:\(result.path)", title: result.name) { [weak self] in
+            self?.onOpenRecentItem?(result)
+          }
+        }),
+      MenuBarItem(
+        id: "cache-status", title: cacheStatusTitle, isEnabled: false, startsSection: true),
       MenuBarItem(
         id: "rebuild-cache",
-        title: isRebuildingCache ? "キャッシュを再構築中..." : "キャッシュを再構築"
+        title: language.text(isRebuildingCache ? .rebuildingCache : .rebuildCache)
       ) { [weak self] in
         guard let self else { return }
         Task { @MainActor in
           await self.rebuildCache()
         }
       },
-      MenuBarItem(id: "settings", title: "設定") { [weak self] in
+      MenuBarItem(
+        id: "reset-cache", title: language.text(.resetCache), isEnabled: !isRebuildingCache
+      ) { [weak self] in
+        guard let self, self.confirmResetCache(self.language) else { return }
+        Task { @MainActor in
+          await self.resetCache()
+        }
+      },
+      MenuBarItem(
+        id: "pause-indexing", title: language.text(.pauseIndexing),
+        isChecked: cacheBootstrap?.isIndexingPaused ?? false
+      ) { [weak self] in
+        self?.toggleIndexingPause()
+      },
+    ]
+    return items + profileItems + [
+      MenuBarItem(
+        id: "settings", title: language.text(.settings), startsSection: true
+      ) { [weak self] in
         self?.openSettings()
       },
-      MenuBarItem(id: "quit", title: "終了") { [weak self] in
+      MenuBarItem(id: "quit", title: language.text(.quit), startsSection: true) { [weak self] in
         self?.quit()
       },
     ]
   }
 
+  /// プロファイルの切り替えメニュー（プロファイルを作成していない場合は空）
+  private var profileItems: [MenuBarItem] {
+    let settings = settingsManager.settings
+    guard !settings.profiles.isEmpty else { return [] }
+    return [
+      MenuBarItem(
+        id: "profiles", title: language.text(.profile(settings.activeProfile ?? "")),
+        submenu: settings.profiles.map { profile in
+          MenuBarItem(
+            id: "profile:\(profile.name)", title: profile.name,
+            isChecked: profile.name == settings.activeProfile
+          ) { [weak self] in
+            self?.onSwitchProfile?(profile.name)
+          }
+        },
+        startsSection: true)
+    ]
+  }
+
+  /// キャッシュの状態（更新中・最終更新日時・一時停止中）
+  var cacheStatusTitle: String {
+    let language = language
+    guard let cacheBootstrap else { return language.text(.cacheUnknown) }
+    if cacheBootstrap.isScanning {
+      return language.text(.cacheUpdating)
+    }
+    let lastUpdated = cacheBootstrap.lastScanDate.map {
+      $0.formatted(
+        Date.FormatStyle(date: .abbreviated, time: .shortened).locale(language.locale))
+    }
+    return language.text(
+      .cacheStatus(lastUpdated: lastUpdated, paused: cacheBootstrap.isIndexingPaused))
+  }
+
   // MARK: - Actions
 
   /// ランチャーウィンドウを表示する。
@@ -100,6 +232,23 @@ public final class MenuBarActions {
     windowManager.showLauncher()
   }
 
+  /// メニューバーアイコンの直下にランチャーを表示する（表示中なら閉じる）。
+  ///
+  /// - Parameter anchor: メニューバーアイコンの位置（スクリーン座標）
+  public func toggleWindow(below anchor: NSRect) {
+    windowManager.toggleLauncher(below: anchor)
+  }
+
+  /// バックグラウンドのインデックス作成の一時停止を切り替える。
+  public func toggleIndexingPause() {
+    guard let cacheBootstrap else { return }
+    if cacheBootstrap.isIndexingPaused {
+      cacheBootstrap.resumeIndexing()
+    } else {
+      cacheBootstrap.pauseIndexing()
+    }
+  }
+
   /// キャッシュを再構築する。
   ///
   /// 実体処理は `onRebuildCache` で注入された `AppCoordinator` のフローに委譲する。
@@ -120,9 +269,39 @@ public final class MenuBarActions {
     Self.logger.info("Cache rebuild completed")
   }
 
+  /// キャッシュの DB とアイコンキャッシュを削除し、空の状態からスキャンし直す。
+  ///
+  /// 実体処理は `onResetCache` で注入された `AppCoordinator` のフローに委譲する。
+  /// 実行中は再構築と同じく `isRebuildingCache` が `true` になる。
+  public func resetCache() async {
+    isRebuildingCache = true
+    defer { isRebuildingCache = false }
+
+    Self.logger.info("Starting cache reset")
+
+    if let onResetCache {
+      await onResetCache()
+    } else {
+      Self.logger.error("onResetCache is not configured; cache reset skipped")
+    }
+  }
+
+  /// キャッシュのリセットの確認ダイアログを表示し、「リセット」が選ばれたかを返す。
+  public static func presentResetCacheConfirmation(in language: AppLanguage) -> Bool {
+    let alert = NSAlert()
+    alert.alertStyle = .warning
+    alert.messageText = language.text(.resetCacheConfirmTitle)
+    alert.informativeText = language.text(.resetCacheConfirmMessage)
+    alert.addButton(withTitle: language.text(.reset)).hasDestructiveAction = true
+    alert.addButton(withTitle: language.text(.cancel))
+    NSApp.activate(ignoringOtherApps: true)
+    return alert.runModal() == .alertFirstButtonReturn
+  }
+
   /// 設定ウィンドウを開く。
   public func openSettings() {
     isSettingsOpen = true
+    onOpenSettings?()
   }
 
   /// 設定ウィンドウを閉じる。
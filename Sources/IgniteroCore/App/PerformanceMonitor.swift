@@ -1,3 +1,4 @@
+import Foundation
 import os
 
 /// アプリケーションのパフォーマンス計測ユーティリティ。
@@ -14,10 +15,7 @@ public enum PerformanceMonitor: Sendable {
     category: "Performance"
   )
 
-  private static let logger = Logger(
-    subsystem: "com.ignitero.launcher",
-    category: "Performance"
-  )
+  private static let logger = AppLogger(category: "Performance")
 
   // MARK: - Signpost API
 
@@ -60,7 +58,7 @@ public enum PerformanceMonitor: Sendable {
     try block()
     let duration = ContinuousClock.now - start
     let ms = durationToMilliseconds(duration)
-    logger.info("\(name): \(ms, format: .fixed(precision: 2))ms")
+    logger.info("\(name): \(String(format: "%.2f", ms))ms")
     return ms
   }
 
@@ -79,14 +77,14 @@ public enum PerformanceMonitor: Sendable {
     try await block()
     let duration = ContinuousClock.now - start
     let ms = durationToMilliseconds(duration)
-    logger.info("\(name): \(ms, format: .fixed(precision: 2))ms")
+    logger.info("\(name): \(String(format: "%.2f", ms))ms")
     return ms
   }
 
   // MARK: - Private Helpers
 
   /// `Duration` をミリ秒の `Double` 値に変換する。
-  private static func durationToMilliseconds(_ duration: Duration) -> Double {
+  static func durationToMilliseconds(_ duration: Duration) -> Double {
     let components = duration.components
     let secondsMs = Double(components.seconds) * 1000.0
     let attosecondsMs = Double(components.attoseconds) / 1_000_000_000_000_000.0
@@ -0,0 +1,40 @@
+import Foundation
+import IgniteroIPC
+
+/// AppleScript のコマンド（Resources/Ignitero.sdef）と IPC のリクエスト・レスポンスの変換。
+///
+/// コマンドのクラスは IgniteroLauncher の ScriptCommands にあり、引数と結果の変換だけをここに置く。
+public enum ScriptCommandRequest {
+  /// `search result` レコードのキー（sdef の `cocoa key` と一致させる）
+  public static let searchResultKeys = ["name", "path", "kind", "score"]
+
+  /// `search launcher "query"` のリクエスト。
+  ///
+  /// - Parameter query: 直接パラメータ（文字列でない場合や空の場合は nil を返す）
+  public static func search(_ query: Any?) -> IPCRequest? {
+    nonEmptyText(query).map { IPCRequest(command: .search, argument: $0) }
+  }
+
+  /// `open item "path"` のリクエスト（`~` を展開し、パスを正規化する）。
+  ///
+  /// - Parameter path: 直接パラメータ（文字列でない場合や空の場合は nil を返す）
+  public static func open(_ path: Any?) -> IPCRequest? {
+    nonEmptyText(path).map {
+      let expanded = ($0 as NSString).expandingTildeInPath
+      return IPCRequest(
+        command: .open, argument: URL(fileURLWithPath: expanded).standardizedFileURL.path)
+    }
+  }
+
+  /// `search launcher` の結果として AppleScript へ返すレコードの一覧。
+  public static func searchResultRecords(from response: IPCResponse) -> [[String: Any]] {
+    (response.results ?? []).map { result in
+      ["name": result.name, "path": result.path, "kind": result.kind, "score": result.score]
+    }
+  }
+
+  private static func nonEmptyText(_ value: Any?) -> String? {
+    guard let text = value as? String, !text.isEmpty else { return nil }
+    return text
+  }
+}
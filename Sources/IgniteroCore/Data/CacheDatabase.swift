@@ -1,5 +1,6 @@
 import Foundation
 import GRDB
+import Synchronization
 
 // MARK: - CacheDatabaseProtocol
 
@@ -7,37 +8,360 @@ public protocol CacheDatabaseProtocol: Sendable {
   func isEmpty() throws -> Bool
   func saveApps(_ apps: [AppItem]) throws
   func loadApps() async throws -> [AppItem]
+  func deleteApps(paths: [String]) throws
+  /// 表示時に変換したアプリのアイコンのパス（アプリのパス → PNG のパス）を記録する。
+  func updateAppIconPaths(_ iconPaths: [String: String]) throws
   func saveDirectories(_ dirs: [DirectoryItem]) throws
+  /// 指定パスのディレクトリと、その配下のディレクトリをキャッシュから削除する。
+  func deleteDirectories(paths: [String]) throws
   func loadDirectories() async throws -> [DirectoryItem]
   func clearCache() throws
+  func recordDirectoryOpen(path: String, editor: String?) throws
+  func recentProjects(limit: Int) async throws -> [RecentProject]
+  /// ディレクトリを次から開くエディタを記憶する（nil で記憶を消す）。
+  func setPreferredEditor(_ editor: String?, forPath path: String) throws
+  /// ディレクトリごとに記憶したエディタ（パス → EditorType の rawValue）を返す。
+  func preferredEditors() async throws -> [String: String]
+  func toggleFavorite(path: String) throws -> Bool
+  func favoritePaths() async throws -> Set<String>
+  func integrityProblems() async throws -> [String]
+  /// 検索語と、その検索語で選んだ結果を記録する。
+  func recordQuery(_ query: String, selectedPath: String) throws
+  /// 記録した検索語と選んだ結果を、最後に使った順に返す（選択の学習に使う）。
+  func queryHistory(limit: Int) async throws -> [SelectionHistoryEntry]
+  /// 前方一致する過去の検索語を、よく使う順に返す。
+  func querySuggestions(prefix: String, limit: Int) async throws -> [String]
+  /// ターミナルの接続先（SSH ホスト・tmux セッション）をまとめて置き換える。
+  func saveTerminalTargets(_ targets: [TerminalTarget]) throws
+  /// 保存したターミナルの接続先を記述順に返す。
+  func loadTerminalTargets() async throws -> [TerminalTarget]
+  /// 以降の読み書きに使うキャッシュのパーティション（プロファイルごとの DB）を切り替える。
+  ///
+  /// - Parameter name: パーティション名（nil で既定のパーティション）
+  func selectPartition(_ name: String?) throws
+  /// 使用中でないパーティションの DB を閉じてファイルごと削除する（削除したプロファイル用）。
+  ///
+  /// - Parameter name: パーティション名（既定のパーティションと使用中のパーティションは削除しない）
+  func removePartition(_ name: String) throws
+  /// 直近のスキャンの所要時間と件数を metadata テーブルに記録する。
+  func recordScanMetrics(_ metrics: ScanMetrics) throws
+  /// 記録した直近のスキャンの所要時間と件数を返す（記録がない場合は nil）。
+  func lastScanMetrics() async throws -> ScanMetrics?
+  /// 使用中のパーティションの DB を閉じてファイルごと削除し、空の DB を作り直す。
+  ///
+  /// スキャン結果に加えてお気に入り・オープン履歴・検索履歴も消える。
+  func resetStorage() throws
+}
+
+extension CacheDatabaseProtocol {
+  public func deleteApps(paths: [String]) throws {}
+  public func updateAppIconPaths(_ iconPaths: [String: String]) throws {}
+  public func deleteDirectories(paths: [String]) throws {}
+  public func recordDirectoryOpen(path: String, editor: String?) throws {}
+  public func recentProjects(limit: Int) async throws -> [RecentProject] { [] }
+  public func setPreferredEditor(_ editor: String?, forPath path: String) throws {}
+  public func preferredEditors() async throws -> [String: String] { [:] }
+  public func toggleFavorite(path: String) throws -> Bool { false }
+  public func favoritePaths() async throws -> Set<String> { [] }
+  public func integrityProblems() async throws -> [String] { [] }
+  public func recordQuery(_ query: String, selectedPath: String) throws {}
+  public func queryHistory(limit: Int) async throws -> [SelectionHistoryEntry] { [] }
+  public func querySuggestions(prefix: String, limit: Int) async throws -> [String] { [] }
+  public func saveTerminalTargets(_ targets: [TerminalTarget]) throws {}
+  public func loadTerminalTargets() async throws -> [TerminalTarget] { [] }
+  public func selectPartition(_ name: String?) throws {}
+  public func removePartition(_ name: String) throws {}
+  public func recordScanMetrics(_ metrics: ScanMetrics) throws {}
+  public func lastScanMetrics() async throws -> ScanMetrics? { nil }
+  public func resetStorage() throws { try clearCache() }
+}
+
+// MARK: - DirectoryFullTextSearching
+
+/// 全文検索で照合する列
+public enum FullTextSearchField: String, Sendable {
+  /// ディレクトリ名
+  case name
+  /// パス（区切り文字で分割した各要素）
+  case path
+}
+
+/// 全文検索インデックスからディレクトリを検索する（メモリに全件を読み込まずに検索する）。
+public protocol DirectoryFullTextSearching: Sendable {
+  /// 検索語のすべての単語に前方一致するディレクトリを一致度順に返す。
+  ///
+  /// - Parameters:
+  ///   - query: 検索語（空白区切りの各単語を前方一致で照合する）
+  ///   - field: 照合する列
+  ///   - limit: 最大件数
+  func searchDirectories(
+    matching query: String, in field: FullTextSearchField, limit: Int
+  ) throws -> [DirectoryItem]
+}
+
+// MARK: - CacheDiffSummary
+
+/// キャッシュへの差分適用結果（件数）。
+public struct CacheDiffSummary: Sendable, Equatable {
+  public var inserted: Int = 0
+  public var updated: Int = 0
+  public var deleted: Int = 0
+  public var unchanged: Int = 0
+
+  public init(inserted: Int = 0, updated: Int = 0, deleted: Int = 0, unchanged: Int = 0) {
+    self.inserted = inserted
+    self.updated = updated
+    self.deleted = deleted
+    self.unchanged = unchanged
+  }
+}
+
+// MARK: - ScanMetrics
+
+/// キャッシュ更新のスキャン 1 回分の所要時間と件数
+public struct ScanMetrics: Sendable, Equatable {
+  /// スキャン全体（保存まで）の所要時間（ミリ秒）
+  public var totalMilliseconds: Int
+  /// アプリのスキャンの所要時間（ミリ秒）
+  public var appScanMilliseconds: Int
+  /// 登録ディレクトリのスキャンの所要時間（ミリ秒）
+  public var directoryScanMilliseconds: Int
+  /// スキャンしたアプリの数（除外前）
+  public var appCount: Int
+  /// 前回のスキャンから更新日時が変わっていなかったアプリの数
+  public var unchangedAppCount: Int
+  /// 保存したディレクトリの数
+  public var directoryCount: Int
+  /// スキャンが終わった日時
+  public var finishedAt: Date
+
+  public init(
+    totalMilliseconds: Int, appScanMilliseconds: Int, directoryScanMilliseconds: Int,
+    appCount: Int, unchangedAppCount: Int, directoryCount: Int, finishedAt: Date = Date()
+  ) {
+    self.totalMilliseconds = totalMilliseconds
+    self.appScanMilliseconds = appScanMilliseconds
+    self.directoryScanMilliseconds = directoryScanMilliseconds
+    self.appCount = appCount
+    self.unchangedAppCount = unchangedAppCount
+    self.directoryCount = directoryCount
+    self.finishedAt = finishedAt
+  }
 }
 
 // MARK: - CacheDatabase
 
-public actor CacheDatabase: CacheDatabaseProtocol {
-  private let dbQueue: DatabaseQueue
+public actor CacheDatabase: CacheDatabaseProtocol, DirectoryFullTextSearching {
+  private static let logger = AppLogger(category: "CacheDatabase")
+
+  /// 現在のスキーマのバージョン（登録したマイグレーションの数。metadata の schema_version に記録する）
+  public static let schemaVersion = 19
+  /// ほかの接続がロックしている間に待つ最大の秒数
+  public static let busyTimeout: TimeInterval = 5
+  /// ファイルの DB で同時に開く読み込み用の接続の上限
+  static let maximumReaderCount = 4
+
+  /// 開いたパーティションと、読み書きに使うパーティション
+  private struct Partitions {
+    var current: any DatabaseWriter
+    /// 使用中のパーティション名
+    var currentName = ""
+    /// パーティション名（既定は空文字列）ごとの DB
+    var databases: [String: any DatabaseWriter]
+  }
+
+  /// 既定のパーティションのファイルパス（インメモリの場合は nil）
+  private let basePath: String?
+  private let partitions: Mutex<Partitions>
+  /// DB ファイルを削除する処理（テストで失敗を再現するために差し替える）
+  private let removeItem: @Sendable (String) throws -> Void
+
+  private nonisolated var database: any DatabaseWriter {
+    partitions.withLock { $0.current }
+  }
 
   public init(path: String) throws {
-    var config = Configuration()
-    config.prepareDatabase { db in
-      try db.execute(sql: "PRAGMA journal_mode = WAL")
-    }
-    let queue = try DatabaseQueue(path: path, configuration: config)
-    try Self.runMigrations(on: queue)
-    dbQueue = queue
+    try self.init(path: path, removeItem: { try FileManager.default.removeItem(atPath: $0) })
+  }
+
+  init(path: String, removeItem: @escaping @Sendable (String) throws -> Void) throws {
+    let database = try Self.makeDatabase(path: path)
+    basePath = path
+    partitions = Mutex(Partitions(current: database, databases: ["": database]))
+    self.removeItem = removeItem
   }
 
   public init(inMemory: Bool) throws {
+    let database = try Self.makeDatabase(path: nil)
+    basePath = nil
+    partitions = Mutex(Partitions(current: database, databases: ["": database]))
+    removeItem = { try FileManager.default.removeItem(atPath: $0) }
+  }
+
+  /// パーティションを切り替える（初めて使うパーティションは DB を作成してマイグレーションする）。
+  ///
+  /// ファイルの DB では `cache.db` に対して `cache-<name>.db` を使う。
+  nonisolated public func selectPartition(_ name: String?) throws {
+    let key = name ?? ""
+    let basePath = basePath
+    try partitions.withLock { partitions in
+      if let database = partitions.databases[key] {
+        partitions.current = database
+        partitions.currentName = key
+        return
+      }
+      let database = try Self.makeDatabase(
+        path: basePath.map { Self.partitionPath(base: $0, name: key) })
+      partitions.databases[key] = database
+      partitions.current = database
+      partitions.currentName = key
+    }
+  }
+
+  nonisolated public func removePartition(_ name: String) throws {
+    let basePath = basePath
+    let removeItem = removeItem
+    let removed = try partitions.withLock { partitions -> Bool in
+      guard !name.isEmpty, name != partitions.currentName else { return false }
+      try partitions.databases.removeValue(forKey: name)?.close()
+      if let basePath {
+        try Self.removeDatabaseFiles(
+          at: Self.partitionPath(base: basePath, name: name), removeItem: removeItem)
+      }
+      return true
+    }
+    if removed {
+      Self.logger.info("Cache partition removed: \(name)")
+    }
+  }
+
+  nonisolated public func resetStorage() throws {
+    let basePath = basePath
+    let removeItem = removeItem
+    let path = try partitions.withLock { partitions in
+      let key = partitions.currentName
+      let path = basePath.map { Self.partitionPath(base: $0, name: key) }
+      try partitions.current.close()
+      let database: any DatabaseWriter
+      do {
+        if let path {
+          try Self.removeDatabaseFiles(at: path, removeItem: removeItem)
+        }
+        database = try Self.makeDatabase(path: path)
+      } catch {
+        // 閉じた DB を使い続けないよう、残ったファイルを開き直してから失敗を返す
+        if let reopened = try? Self.makeDatabase(path: path) {
+          partitions.databases[key] = reopened
+          partitions.current = reopened
+        }
+        throw error
+      }
+      partitions.databases[key] = database
+      partitions.current = database
+      return path
+    }
+    Self.logger.info("Cache database reset: \(path ?? "in-memory")")
+  }
+
+  /// パーティションの DB ファイルのパスを返す（名前が空なら既定のパス）。
+  static func partitionPath(base: String, name: String) -> String {
+    guard !name.isEmpty else { return base }
+    let url = URL(fileURLWithPath: base)
+    let stem = url.deletingPathExtension().path
+    return url.pathExtension.isEmpty ? "\(stem)-\(name)" : "\(stem)-\(name).\(url.pathExtension)"
+  }
+
+  /// DB を開いてマイグレーションする。
+  ///
+  /// 開けない・移行できない DB ファイル（壊れたファイルや新しいバージョンのアプリが作った DB）は
+  /// `<path>.broken` に退避し、空の DB を作り直す。スキャン結果は次のスキャンで復元され、
+  /// お気に入りや履歴は退避したファイルに残る。
+  private static func makeDatabase(path: String?) throws -> any DatabaseWriter {
+    do {
+      return try openAndMigrate(path: path)
+    } catch {
+      guard let path else { throw error }
+      logger.error(
+        "Cache database could not be migrated; rebuilding: \(error.localizedDescription)")
+      try quarantineDatabaseFile(at: path)
+      return try openAndMigrate(path: path)
+    }
+  }
+
+  /// ファイルの DB は `DatabasePool`（WAL）で開く。読み込みは書き込み用とは別の接続で並行して
+  /// 実行されるため、スキャン結果の保存中もランチャーの検索・表示は待たされない。
+  /// 書き込みは 1 本の接続に直列化される。インメモリの DB は接続を共有できないため `DatabaseQueue`。
+  private static func openAndMigrate(path: String?) throws -> any DatabaseWriter {
     var config = Configuration()
-    config.prepareDatabase { db in
-      try db.execute(sql: "PRAGMA journal_mode = WAL")
+    // 別の接続（CLI の診断やほかのプロセス）が書き込み中でも `database is locked` で失敗せず待つ
+    config.busyMode = .timeout(busyTimeout)
+    config.maximumReaderCount = maximumReaderCount
+    let database: any DatabaseWriter =
+      if let path {
+        try DatabasePool(path: path, configuration: config)
+      } else {
+        try DatabaseQueue(configuration: config)
+      }
+    do {
+      try checkSchemaVersion(of: database)
+      try runMigrations(on: database)
+      try database.write { db in
+        try db.execute(
+          sql: "INSERT OR REPLACE INTO metadata (key, value) VALUES ('schema_version', ?)",
+          arguments: [String(schemaVersion)])
+      }
+    } catch {
+      try? database.close()
+      throw error
     }
-    let queue = try DatabaseQueue(configuration: config)
-    try Self.runMigrations(on: queue)
-    dbQueue = queue
+    return database
   }
 
-  private static func runMigrations(on queue: DatabaseQueue) throws {
+  /// 新しいバージョンのアプリが作った DB（知らない列やテーブルがある）を開かないようにする。
+  private static func checkSchemaVersion(of database: any DatabaseWriter) throws {
+    let stored = try database.read { db -> Int? in
+      guard try db.tableExists("metadata") else { return nil }
+      return try String.fetchOne(
+        db, sql: "SELECT value FROM metadata WHERE key = 'schema_version'"
+      ).flatMap { Int($0) }
+    }
+    if let stored, stored > schemaVersion {
+      throw LauncherError.cache(
+        "schema version \(stored) is newer than supported version \(schemaVersion)")
+    }
+  }
+
+  /// DB ファイルと一緒に作られる WAL・共有メモリのファイルの接尾辞（DB 本体は空文字列）
+  private static let databaseFileSuffixes = ["", "-wal", "-shm"]
+
+  /// DB ファイル（WAL・共有メモリのファイルを含む）を `<path>.broken` に移す（前回の退避分は消す）。
+  private static func quarantineDatabaseFile(at path: String) throws {
+    let fm = FileManager.default
+    for suffix in databaseFileSuffixes where fm.fileExists(atPath: path + suffix) {
+      let backup = path + suffix + ".broken"
+      try? fm.removeItem(atPath: backup)
+      try fm.moveItem(atPath: path + suffix, toPath: backup)
+    }
+  }
+
+  /// DB ファイル（WAL・共有メモリのファイルを含む）を削除する。
+  private static func removeDatabaseFiles(
+    at path: String, removeItem: (String) throws -> Void
+  ) throws {
+    for suffix in databaseFileSuffixes where FileManager.default.fileExists(atPath: path + suffix) {
+      try removeItem(path + suffix)
+    }
+  }
+
+  /// 記録されたスキーマのバージョン（記録がない場合は nil）
+  nonisolated public func storedSchemaVersion() throws -> Int? {
+    try database.read { db in
+      try String.fetchOne(db, sql: "SELECT value FROM metadata WHERE key = 'schema_version'")
+        .flatMap { Int($0) }
+    }
+  }
+
+  private static func runMigrations(on database: any DatabaseWriter) throws {
     var migrator = DatabaseMigrator()
     migrator.registerMigration("v1") { db in
       try db.create(table: "apps", ifNotExists: true) { t in
@@ -60,94 +384,647 @@ public actor CacheDatabase: CacheDatabaseProtocol {
         t.column("value", .text).notNull()
       }
     }
-    try migrator.migrate(queue)
+    migrator.registerMigration("v2") { db in
+      try db.create(table: "directory_opens", ifNotExists: true) { t in
+        t.primaryKey("path", .text)
+        t.column("editor", .text)
+        t.column("open_count", .integer).notNull().defaults(to: 0)
+        t.column("last_opened", .datetime).notNull().indexed()
+      }
+    }
+    migrator.registerMigration("v3") { db in
+      try db.alter(table: "directories") { t in
+        t.add(column: "is_git_repo", .boolean).notNull().defaults(to: false)
+        t.add(column: "git_branch", .text)
+      }
+    }
+    migrator.registerMigration("v4") { db in
+      try db.create(table: "favorites", ifNotExists: true) { t in
+        t.primaryKey("path", .text)
+        t.column("created_at", .datetime).notNull()
+      }
+    }
+    migrator.registerMigration("v5") { db in
+      // directories と同期する外部コンテンツの FTS5 テーブル（トリガーで自動更新される）
+      try db.create(virtualTable: "directories_fts", using: FTS5()) { t in
+        t.synchronize(withTable: "directories")
+        t.tokenizer = .unicode61()
+        t.prefixes = [2, 3]
+        t.column("name")
+        t.column("path")
+      }
+    }
+    migrator.registerMigration("v6") { db in
+      try db.alter(table: "apps") { t in
+        t.add(column: "source", .text).notNull().defaults(to: AppSource.scan.rawValue)
+      }
+    }
+    migrator.registerMigration("v7") { db in
+      try db.alter(table: "directories") { t in
+        t.add(column: "priority", .integer).notNull().defaults(to: 0)
+      }
+    }
+    migrator.registerMigration("v8") { db in
+      try db.alter(table: "directories") { t in
+        t.add(column: "original_name", .text)
+      }
+    }
+    migrator.registerMigration("v9") { db in
+      try db.create(table: "query_history", ifNotExists: true) { t in
+        t.column("query", .text).notNull()
+        t.column("selected_path", .text).notNull()
+        t.column("count", .integer).notNull().defaults(to: 0)
+        t.column("last_used", .datetime).notNull().indexed()
+        t.primaryKey(["query", "selected_path"])
+      }
+    }
+    migrator.registerMigration("v10") { db in
+      try db.alter(table: "directory_opens") { t in
+        t.add(column: "preferred_editor", .text)
+      }
+    }
+    migrator.registerMigration("v11") { db in
+      try db.create(table: "terminal_targets", ifNotExists: true) { t in
+        t.primaryKey("identifier", .text)
+        t.column("kind", .text).notNull()
+        t.column("name", .text).notNull()
+        t.column("detail", .text)
+        t.column("position", .integer).notNull()
+      }
+    }
+    migrator.registerMigration("v12") { db in
+      try db.alter(table: "directories") { t in
+        t.add(column: "xcode_project", .text)
+      }
+    }
+    migrator.registerMigration("v13") { db in
+      try db.alter(table: "directories") { t in
+        t.add(column: "project_type", .text)
+      }
+    }
+    migrator.registerMigration("v14") { db in
+      try db.alter(table: "directories") { t in
+        t.add(column: "finder_tags", .text)
+      }
+    }
+    migrator.registerMigration("v15") { db in
+      try db.alter(table: "apps") { t in
+        t.add(column: "icon_source_path", .text)
+      }
+    }
+    migrator.registerMigration("v16") { db in
+      try db.alter(table: "apps") { t in
+        t.add(column: "version", .text)
+        t.add(column: "bundle_id", .text)
+        t.add(column: "category", .text)
+      }
+    }
+    migrator.registerMigration("v17") { db in
+      try db.alter(table: "apps") { t in
+        t.add(column: "alternate_names", .text)
+      }
+    }
+    migrator.registerMigration("v18") { db in
+      try db.alter(table: "apps") { t in
+        t.add(column: "bundle_mtime", .double)
+      }
+    }
+    migrator.registerMigration("v19") { db in
+      try db.alter(table: "apps") { t in
+        t.add(column: "keywords", .text)
+      }
+      // 更新日時が同じバンドルは読み直さないため、次のスキャンで全アプリのキーワードを読ませる
+      try db.execute(sql: "UPDATE apps SET bundle_mtime = NULL")
+    }
+    try migrator.migrate(database)
   }
 
   // MARK: - Apps
 
+  /// アプリ一覧をキャッシュに保存する。
+  ///
+  /// 全件削除→再挿入ではなく `path` をキーにした差分適用を行うため、
+  /// 変更のない行はそのまま残り、行単位のメタデータ（利用回数など）も保持される。
   nonisolated public func saveApps(_ apps: [AppItem]) throws {
-    try dbQueue.write { db in
-      try db.execute(sql: "DELETE FROM apps")
+    try applyAppDiff(apps)
+  }
+
+  /// アプリ一覧の差分をキャッシュへ適用し、変更件数を返す。
+  ///
+  /// 新規パスは INSERT、内容が変わったパスはスキャン由来の列のみ UPDATE、
+  /// 入力に含まれないパスは DELETE する。同一パスが重複する場合は後勝ち。
+  @discardableResult
+  nonisolated public func applyAppDiff(_ apps: [AppItem]) throws -> CacheDiffSummary {
+    try database.write { db in
+      let existing = try AppItem.fetchAll(db)
+      let existingByPath = Dictionary(
+        existing.map { ($0.path, $0) }, uniquingKeysWith: { _, last in last })
+      let incomingByPath = Dictionary(
+        apps.map { ($0.path, $0) }, uniquingKeysWith: { _, last in last })
       let now = ISO8601DateFormatter().string(from: Date())
-      for app in apps {
-        try db.execute(
-          sql: """
-            INSERT OR REPLACE INTO apps (name, path, icon_path, original_name, last_updated)
-            VALUES (?, ?, ?, ?, ?)
-            """,
-          arguments: [app.name, app.path, app.iconPath, app.originalName, now]
-        )
+      var summary = CacheDiffSummary()
+      // 行ごとの文は接続ごとにキャッシュしたものを使い回す（スキャンのたびに数百〜数千回実行する）
+      let insert = try db.cachedStatement(
+        sql: """
+          INSERT OR REPLACE INTO apps
+            (name, path, icon_path, icon_source_path, original_name, alternate_names,
+             keywords, source, version, bundle_id, category, bundle_mtime, last_updated)
+          VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+          """)
+      let update = try db.cachedStatement(
+        sql: """
+          UPDATE apps SET name = ?, icon_path = ?, icon_source_path = ?, original_name = ?,
+            alternate_names = ?, keywords = ?, source = ?, version = ?, bundle_id = ?,
+            category = ?, bundle_mtime = ?, last_updated = ?
+          WHERE path = ?
+          """)
+      let delete = try db.cachedStatement(sql: "DELETE FROM apps WHERE path = ?")
+
+      for app in incomingByPath.values {
+        guard let current = existingByPath[app.path] else {
+          try insert.execute(arguments: [
+            app.name, app.path, app.iconPath, app.iconSourcePath, app.originalName,
+            try Self.stringListJSON(app.alternateNames), try Self.stringListJSON(app.keywords),
+            app.source.rawValue, app.version, app.bundleIdentifier, app.category,
+            app.bundleModificationTime, now,
+          ])
+          summary.inserted += 1
+          continue
+        }
+        guard current != app else {
+          summary.unchanged += 1
+          continue
+        }
+        try update.execute(arguments: [
+          app.name, app.iconPath, app.iconSourcePath, app.originalName,
+          try Self.stringListJSON(app.alternateNames), try Self.stringListJSON(app.keywords),
+          app.source.rawValue, app.version, app.bundleIdentifier, app.category,
+          app.bundleModificationTime, now, app.path,
+        ])
+        summary.updated += 1
       }
+
+      for path in existingByPath.keys where incomingByPath[path] == nil {
+        try delete.execute(arguments: [path])
+        summary.deleted += 1
+      }
+
       try db.execute(
         sql: "INSERT OR REPLACE INTO metadata (key, value) VALUES ('last_updated', ?)",
         arguments: [now]
       )
+      return summary
     }
   }
 
   public func loadApps() throws -> [AppItem] {
-    try dbQueue.read { db in
+    try database.read { db in
       try AppItem.fetchAll(db)
     }
   }
 
+  /// Finder のタグを directories.finder_tags 列の JSON にする（タグがなければ NULL）。
+  private static func finderTagsJSON(_ tags: [FinderTag]) throws -> String? {
+    guard !tags.isEmpty else { return nil }
+    return String(decoding: try JSONEncoder().encode(tags), as: UTF8.self)
+  }
+
+  /// アプリの別の名前・キーワードを apps.alternate_names / keywords 列の JSON にする（なければ NULL）。
+  private static func stringListJSON(_ names: [String]) throws -> String? {
+    guard !names.isEmpty else { return nil }
+    return String(decoding: try JSONEncoder().encode(names), as: UTF8.self)
+  }
+
+  /// 表示時に変換したアイコンのパスを記録する（スキャン由来の列・更新日時は変えない）。
+  nonisolated public func updateAppIconPaths(_ iconPaths: [String: String]) throws {
+    guard !iconPaths.isEmpty else { return }
+    try database.write { db in
+      let update = try db.cachedStatement(sql: "UPDATE apps SET icon_path = ? WHERE path = ?")
+      for (path, iconPath) in iconPaths {
+        try update.execute(arguments: [iconPath, path])
+      }
+    }
+  }
+
+  /// 指定パスのアプリをキャッシュから削除する（アンインストール済みのアプリの除去用）。
+  nonisolated public func deleteApps(paths: [String]) throws {
+    guard !paths.isEmpty else { return }
+    try database.write { db in
+      let delete = try db.cachedStatement(sql: "DELETE FROM apps WHERE path = ?")
+      for path in paths {
+        try delete.execute(arguments: [path])
+      }
+    }
+  }
+
   // MARK: - Directories
 
+  /// ディレクトリ一覧をキャッシュに保存する（`path` キーの差分適用）。
   nonisolated public func saveDirectories(_ dirs: [DirectoryItem]) throws {
-    try dbQueue.write { db in
-      try db.execute(sql: "DELETE FROM directories")
+    try applyDirectoryDiff(dirs)
+  }
+
+  /// 指定パスのディレクトリと、その配下のディレクトリをキャッシュから削除する
+  /// （ゴミ箱に入れたディレクトリの除去用）。
+  nonisolated public func deleteDirectories(paths: [String]) throws {
+    guard !paths.isEmpty else { return }
+    try database.write { db in
+      let delete = try db.cachedStatement(
+        sql: "DELETE FROM directories WHERE path = ? OR substr(path, 1, length(?)) = ?")
+      for path in paths {
+        let prefix = path + "/"
+        try delete.execute(arguments: [path, prefix, prefix])
+      }
+    }
+  }
+
+  /// ディレクトリ一覧の差分をキャッシュへ適用し、変更件数を返す。
+  @discardableResult
+  nonisolated public func applyDirectoryDiff(_ dirs: [DirectoryItem]) throws -> CacheDiffSummary {
+    try database.write { db in
+      let existing = try DirectoryItem.fetchAll(db)
+      let existingByPath = Dictionary(
+        existing.map { ($0.path, $0) }, uniquingKeysWith: { _, last in last })
+      let incomingByPath = Dictionary(
+        dirs.map { ($0.path, $0) }, uniquingKeysWith: { _, last in last })
       let now = ISO8601DateFormatter().string(from: Date())
-      for dir in dirs {
-        try db.execute(
-          sql: """
-            INSERT OR REPLACE INTO directories (name, path, editor, last_updated)
-            VALUES (?, ?, ?, ?)
-            """,
-          arguments: [dir.name, dir.path, dir.editor, now]
-        )
+      var summary = CacheDiffSummary()
+      let insert = try db.cachedStatement(
+        sql: """
+          INSERT OR REPLACE INTO directories
+            (name, path, editor, is_git_repo, git_branch, priority, original_name,
+             xcode_project, project_type, finder_tags, last_updated)
+          VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+          """)
+      let update = try db.cachedStatement(
+        sql: """
+          UPDATE directories
+          SET name = ?, editor = ?, is_git_repo = ?, git_branch = ?, priority = ?,
+            original_name = ?, xcode_project = ?, project_type = ?, finder_tags = ?,
+            last_updated = ?
+          WHERE path = ?
+          """)
+      let delete = try db.cachedStatement(sql: "DELETE FROM directories WHERE path = ?")
+
+      for dir in incomingByPath.values {
+        guard let current = existingByPath[dir.path] else {
+          try insert.execute(arguments: [
+            dir.name, dir.path, dir.editor, dir.isGitRepo, dir.gitBranch, dir.priority,
+            dir.originalName, dir.xcodeProject, dir.projectType?.rawValue,
+            try Self.finderTagsJSON(dir.finderTags), now,
+          ])
+          summary.inserted += 1
+          continue
+        }
+        guard current != dir else {
+          summary.unchanged += 1
+          continue
+        }
+        try update.execute(arguments: [
+          dir.name, dir.editor, dir.isGitRepo, dir.gitBranch, dir.priority, dir.originalName,
+          dir.xcodeProject, dir.projectType?.rawValue, try Self.finderTagsJSON(dir.finderTags),
+          now, dir.path,
+        ])
+        summary.updated += 1
+      }
+
+      for path in existingByPath.keys where incomingByPath[path] == nil {
+        try delete.execute(arguments: [path])
+        summary.deleted += 1
       }
+
       try db.execute(
         sql: "INSERT OR REPLACE INTO metadata (key, value) VALUES ('last_updated', ?)",
         arguments: [now]
       )
+      return summary
     }
   }
 
   public func loadDirectories() throws -> [DirectoryItem] {
-    try dbQueue.read { db in
+    try database.read { db in
       try DirectoryItem.fetchAll(db)
     }
   }
 
+  /// 全文検索インデックスでディレクトリを検索する（`bm25` の一致度順）。
+  nonisolated public func searchDirectories(
+    matching query: String, in field: FullTextSearchField, limit: Int
+  ) throws -> [DirectoryItem] {
+    guard limit > 0, let pattern = FTS5Pattern(matchingAllPrefixesIn: query) else { return [] }
+    return try database.read { db in
+      try DirectoryItem.fetchAll(
+        db,
+        sql: """
+          SELECT directories.* FROM directories
+          JOIN directories_fts ON directories_fts.rowid = directories.rowid
+          WHERE directories_fts MATCH ?
+          ORDER BY directories_fts.rank
+          LIMIT ?
+          """,
+        arguments: ["\(field.rawValue) : (\(pattern.rawPattern))", limit]
+      )
+    }
+  }
+
+  // MARK: - Directory Opens
+
+  /// ディレクトリを開いたことを記録する。
+  ///
+  /// 同一パスは回数を加算し、最終オープン日時とエディタを上書きする。
+  nonisolated public func recordDirectoryOpen(path: String, editor: String?) throws {
+    try recordDirectoryOpen(path: path, editor: editor, at: Date())
+  }
+
+  nonisolated func recordDirectoryOpen(path: String, editor: String?, at date: Date) throws {
+    try database.write { db in
+      try db.execute(
+        sql: """
+          INSERT INTO directory_opens (path, editor, open_count, last_opened)
+          VALUES (?, ?, 1, ?)
+          ON CONFLICT(path) DO UPDATE SET
+            editor = excluded.editor,
+            open_count = open_count + 1,
+            last_opened = excluded.last_opened
+          """,
+        arguments: [path, editor, date]
+      )
+    }
+  }
+
+  /// 最近開いたディレクトリを新しい順に返す。
+  public func recentProjects(limit: Int) throws -> [RecentProject] {
+    guard limit > 0 else { return [] }
+    return try database.read { db in
+      try RecentProject
+        .order(Column("last_opened").desc)
+        .limit(limit)
+        .fetchAll(db)
+    }
+  }
+
+  /// ディレクトリを次から開くエディタを記憶する。
+  ///
+  /// 開いた記録のあるディレクトリだけが対象のため、`recordDirectoryOpen` の後に呼ぶ。
+  /// - Parameters:
+  ///   - editor: エディタ（EditorType の rawValue、nil で記憶を消す）
+  ///   - path: ディレクトリのパス
+  nonisolated public func setPreferredEditor(_ editor: String?, forPath path: String) throws {
+    try database.write { db in
+      try db.execute(
+        sql: "UPDATE directory_opens SET preferred_editor = ? WHERE path = ?",
+        arguments: [editor, path])
+    }
+  }
+
+  /// ディレクトリごとに記憶したエディタを返す。
+  public func preferredEditors() throws -> [String: String] {
+    try database.read { db in
+      let rows = try Row.fetchAll(
+        db,
+        sql: """
+          SELECT path, preferred_editor FROM directory_opens
+          WHERE preferred_editor IS NOT NULL
+          """
+      )
+      return Dictionary(
+        rows.map { row -> (String, String) in (row["path"], row["preferred_editor"]) },
+        uniquingKeysWith: { $1 })
+    }
+  }
+
+  // MARK: - Query History
+
+  /// 検索語の履歴に残す最大件数（超えた分は使われていない順に削除する）
+  static let queryHistoryLimit = 1000
+
+  /// 検索語と、その検索語で選んだ結果を記録する。
+  ///
+  /// 同じ検索語と結果の組み合わせは回数を加算し、最終使用日時を上書きする。
+  /// - Parameters:
+  ///   - query: 正規化済みの検索語（空の場合は記録しない）
+  ///   - selectedPath: 選んだ結果のパス（カスタムコマンドは履歴用の識別子）
+  nonisolated public func recordQuery(_ query: String, selectedPath: String) throws {
+    try recordQuery(query, selectedPath: selectedPath, at: Date())
+  }
+
+  nonisolated func recordQuery(_ query: String, selectedPath: String, at date: Date) throws {
+    guard !query.isEmpty else { return }
+    try database.write { db in
+      try db.execute(
+        sql: """
+          INSERT INTO query_history (query, selected_path, count, last_used)
+          VALUES (?, ?, 1, ?)
+          ON CONFLICT(query, selected_path) DO UPDATE SET
+            count = count + 1,
+            last_used = excluded.last_used
+          """,
+        arguments: [query, selectedPath, date]
+      )
+      try db.execute(
+        sql: """
+          DELETE FROM query_history WHERE rowid NOT IN (
+            SELECT rowid FROM query_history ORDER BY last_used DESC LIMIT ?
+          )
+          """,
+        arguments: [Self.queryHistoryLimit]
+      )
+    }
+  }
+
+  /// 記録した検索語と選んだ結果を、最後に使った順に返す。
+  public func queryHistory(limit: Int) throws -> [SelectionHistoryEntry] {
+    guard limit > 0 else { return [] }
+    return try database.read { db in
+      try Row.fetchAll(
+        db,
+        sql: """
+          SELECT query, selected_path, count, last_used FROM query_history
+          ORDER BY last_used DESC LIMIT ?
+          """,
+        arguments: [limit]
+      ).map { row in
+        SelectionHistoryEntry(
+          keyword: row["query"], selectedPath: row["selected_path"], count: row["count"],
+          lastUsed: row["last_used"])
+      }
+    }
+  }
+
+  /// 前方一致する過去の検索語を、選んだ回数の合計が多い順（同数なら最近使った順）に返す。
+  ///
+  /// 入力途中の補完に使うため、`prefix` と同じ検索語は含めない。
+  /// - Parameters:
+  ///   - prefix: 正規化済みの入力中の検索語（空の場合はすべての検索語が対象）
+  ///   - limit: 最大件数
+  public func querySuggestions(prefix: String, limit: Int) throws -> [String] {
+    guard limit > 0 else { return [] }
+    return try database.read { db in
+      // LIKE は `%`・`_` を含む検索語を正しく扱えないため、先頭の部分文字列で比較する
+      try String.fetchAll(
+        db,
+        sql: """
+          SELECT query FROM query_history
+          WHERE substr(query, 1, length(?)) = ? AND query != ?
+          GROUP BY query
+          ORDER BY SUM(count) DESC, MAX(last_used) DESC
+          LIMIT ?
+          """,
+        arguments: [prefix, prefix, prefix, limit]
+      )
+    }
+  }
+
+  // MARK: - Terminal Targets
+
+  /// ターミナルの接続先をまとめて置き換える（同じ識別子が重複する場合は先勝ち）。
+  nonisolated public func saveTerminalTargets(_ targets: [TerminalTarget]) throws {
+    try database.write { db in
+      try db.execute(sql: "DELETE FROM terminal_targets")
+      let insert = try db.cachedStatement(
+        sql: """
+          INSERT OR IGNORE INTO terminal_targets (identifier, kind, name, detail, position)
+          VALUES (?, ?, ?, ?, ?)
+          """)
+      for (position, target) in targets.enumerated() {
+        try insert.execute(arguments: [
+          target.identifier, target.kind.rawValue, target.name, target.detail, position,
+        ])
+      }
+    }
+  }
+
+  /// 保存したターミナルの接続先を記述順に返す（不明な種別の行は読み飛ばす）。
+  public func loadTerminalTargets() throws -> [TerminalTarget] {
+    try database.read { db in
+      let rows = try Row.fetchAll(
+        db, sql: "SELECT kind, name, detail FROM terminal_targets ORDER BY position")
+      return rows.compactMap { row in
+        guard let kind = TerminalTarget.Kind(rawValue: row["kind"]) else { return nil }
+        return TerminalTarget(kind: kind, name: row["name"], detail: row["detail"])
+      }
+    }
+  }
+
+  // MARK: - Scan Metrics
+
+  /// metadata テーブルに記録するスキャンの計測値のキー
+  private enum ScanMetricsKey {
+    static let total = "scan_total_ms"
+    static let apps = "scan_apps_ms"
+    static let directories = "scan_directories_ms"
+    static let appCount = "scan_app_count"
+    static let unchangedAppCount = "scan_unchanged_app_count"
+    static let directoryCount = "scan_directory_count"
+    static let finishedAt = "scan_finished_at"
+  }
+
+  nonisolated public func recordScanMetrics(_ metrics: ScanMetrics) throws {
+    let values: [(String, String)] = [
+      (ScanMetricsKey.total, String(metrics.totalMilliseconds)),
+      (ScanMetricsKey.apps, String(metrics.appScanMilliseconds)),
+      (ScanMetricsKey.directories, String(metrics.directoryScanMilliseconds)),
+      (ScanMetricsKey.appCount, String(metrics.appCount)),
+      (ScanMetricsKey.unchangedAppCount, String(metrics.unchangedAppCount)),
+      (ScanMetricsKey.directoryCount, String(metrics.directoryCount)),
+      (ScanMetricsKey.finishedAt, ISO8601DateFormatter().string(from: metrics.finishedAt)),
+    ]
+    try database.write { db in
+      for (key, value) in values {
+        try db.execute(
+          sql: "INSERT OR REPLACE INTO metadata (key, value) VALUES (?, ?)",
+          arguments: [key, value])
+      }
+    }
+  }
+
+  public func lastScanMetrics() throws -> ScanMetrics? {
+    let values = try database.read { db in
+      Dictionary(
+        try Row.fetchAll(db, sql: "SELECT key, value FROM metadata WHERE key LIKE 'scan_%'")
+          .map { (row: Row) -> (String, String) in (row["key"], row["value"]) },
+        uniquingKeysWith: { _, last in last })
+    }
+    func int(_ key: String) -> Int? { values[key].flatMap { Int($0) } }
+    guard let total = int(ScanMetricsKey.total), let apps = int(ScanMetricsKey.apps),
+      let directories = int(ScanMetricsKey.directories),
+      let appCount = int(ScanMetricsKey.appCount),
+      let unchangedAppCount = int(ScanMetricsKey.unchangedAppCount),
+      let directoryCount = int(ScanMetricsKey.directoryCount),
+      let finishedAt = values[ScanMetricsKey.finishedAt].flatMap({
+        ISO8601DateFormatter().date(from: $0)
+      })
+    else { return nil }
+    return ScanMetrics(
+      totalMilliseconds: total, appScanMilliseconds: apps, directoryScanMilliseconds: directories,
+      appCount: appCount, unchangedAppCount: unchangedAppCount, directoryCount: directoryCount,
+      finishedAt: finishedAt)
+  }
+
+  // MARK: - Favorites
+
+  /// お気に入りを切り替える。
+  ///
+  /// - Parameter path: アプリまたはディレクトリのパス
+  /// - Returns: 切り替え後にお気に入りかどうか
+  @discardableResult
+  nonisolated public func toggleFavorite(path: String) throws -> Bool {
+    try database.write { db in
+      try db.execute(sql: "DELETE FROM favorites WHERE path = ?", arguments: [path])
+      guard db.changesCount == 0 else { return false }
+      try db.execute(
+        sql: "INSERT INTO favorites (path, created_at) VALUES (?, ?)",
+        arguments: [path, Date()])
+      return true
+    }
+  }
+
+  /// お気に入りのパス一覧を返す。
+  public func favoritePaths() throws -> Set<String> {
+    try database.read { db in
+      Set(try String.fetchAll(db, sql: "SELECT path FROM favorites"))
+    }
+  }
+
   // MARK: - Cache Status
 
   nonisolated public func isEmpty() throws -> Bool {
-    try dbQueue.read { db in
+    try database.read { db in
       let appCount = try Int.fetchOne(db, sql: "SELECT COUNT(*) FROM apps") ?? 0
       let dirCount = try Int.fetchOne(db, sql: "SELECT COUNT(*) FROM directories") ?? 0
       return appCount == 0 && dirCount == 0
     }
   }
 
+  /// スキャン結果のキャッシュを削除する（オープン履歴・検索履歴・お気に入りは利用者の記録のため保持する）。
   nonisolated public func clearCache() throws {
-    try dbQueue.write { db in
+    try database.write { db in
       try db.execute(sql: "DELETE FROM apps")
       try db.execute(sql: "DELETE FROM directories")
-      try db.execute(sql: "DELETE FROM metadata")
+      try db.execute(sql: "DELETE FROM terminal_targets")
+      // スキーマのバージョンはスキャン結果ではないため残す
+      try db.execute(sql: "DELETE FROM metadata WHERE key != 'schema_version'")
     }
   }
 
   // MARK: - Diagnostics
 
   public func journalMode() throws -> String {
-    try dbQueue.read { db in
+    try database.read { db in
       let mode = try String.fetchOne(db, sql: "PRAGMA journal_mode")
       return mode ?? "unknown"
     }
   }
 
+  /// `PRAGMA integrity_check` で見つかった問題を返す（正常な場合は空）。
+  public func integrityProblems() throws -> [String] {
+    try database.read { db in
+      try String.fetchAll(db, sql: "PRAGMA integrity_check").filter { $0 != "ok" }
+    }
+  }
+
   public func tableNames() throws -> [String] {
-    try dbQueue.read { db in
+    try database.read { db in
       try String.fetchAll(
         db, sql: "SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%'")
     }
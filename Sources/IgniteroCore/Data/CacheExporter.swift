@@ -0,0 +1,134 @@
+import Foundation
+
+// MARK: - CacheExportFormat
+
+/// キャッシュの書き出し形式
+public enum CacheExportFormat: String, Sendable, CaseIterable {
+  /// 種別ごとの配列（`apps` / `directories` / `terminal_targets`）にキャッシュの全項目を持つ JSON
+  case json
+  /// 1 行 1 項目の CSV（種別ごとに使わない列は空）
+  case csv
+
+  /// 書き出し先のパスの拡張子から形式を決める（`.csv` 以外は JSON）。
+  public static func inferred(fromPath path: String) -> CacheExportFormat {
+    URL(fileURLWithPath: path).pathExtension.lowercased() == "csv" ? .csv : .json
+  }
+}
+
+// MARK: - CacheExporter
+
+/// キャッシュに登録されたアプリ・ディレクトリ・ターミナルの接続先を書き出す。
+///
+/// 「検索に出てこない項目がキャッシュに入っているか」の確認や、外部のツールへの受け渡しに使う。
+public enum CacheExporter {
+  /// CSV の列（JSON のキーと同じ名前）
+  static let csvColumns = [
+    "kind", "name", "path", "original_name", "alternate_names", "keywords", "bundle_id",
+    "version", "category", "source", "editor", "is_git_repo", "git_branch", "priority",
+    "project_type", "finder_tags", "detail",
+  ]
+
+  /// 書き出す内容を作る。
+  public static func export(
+    apps: [AppItem], directories: [DirectoryItem], terminalTargets: [TerminalTarget],
+    format: CacheExportFormat
+  ) throws -> Data {
+    switch format {
+    case .json:
+      let encoder = JSONEncoder()
+      encoder.outputFormatting = [.prettyPrinted, .sortedKeys, .withoutEscapingSlashes]
+      return try encoder.encode(
+        JSONExport(
+          apps: apps, directories: directories,
+          terminalTargets: terminalTargets.map(TerminalTargetRecord.init)))
+    case .csv:
+      let rows =
+        apps.map(csvRow) + directories.map(csvRow) + terminalTargets.map(csvRow)
+      let lines = ([csvColumns] + rows).map { $0.map(csvField).joined(separator: ",") }
+      return Data((lines.joined(separator: "\r\n") + "\r\n").utf8)
+    }
+  }
+
+  /// 書き出してファイルに保存する。
+  ///
+  /// - Returns: 書き出した項目の数
+  @discardableResult
+  public static func write(
+    apps: [AppItem], directories: [DirectoryItem], terminalTargets: [TerminalTarget],
+    format: CacheExportFormat, to path: String
+  ) throws -> Int {
+    let data = try export(
+      apps: apps, directories: directories, terminalTargets: terminalTargets, format: format)
+    try data.write(to: URL(fileURLWithPath: path), options: .atomic)
+    return apps.count + directories.count + terminalTargets.count
+  }
+
+  // MARK: - JSON
+
+  private struct JSONExport: Encodable {
+    let apps: [AppItem]
+    let directories: [DirectoryItem]
+    let terminalTargets: [TerminalTargetRecord]
+
+    enum CodingKeys: String, CodingKey {
+      case apps
+      case directories
+      case terminalTargets = "terminal_targets"
+    }
+  }
+
+  private struct TerminalTargetRecord: Encodable {
+    let kind: String
+    let name: String
+    let detail: String?
+    let identifier: String
+
+    init(_ target: TerminalTarget) {
+      kind = target.kind.rawValue
+      name = target.name
+      detail = target.detail
+      identifier = target.identifier
+    }
+  }
+
+  // MARK: - CSV
+
+  private static func csvRow(_ app: AppItem) -> [String] {
+    row([
+      "kind": "app", "name": app.name, "path": app.path, "original_name": app.originalName,
+      "alternate_names": app.alternateNames.joined(separator: "; "),
+      "keywords": app.keywords.joined(separator: "; "),
+      "bundle_id": app.bundleIdentifier, "version": app.version, "category": app.category,
+      "source": app.source.rawValue,
+    ])
+  }
+
+  private static func csvRow(_ dir: DirectoryItem) -> [String] {
+    row([
+      "kind": "directory", "name": dir.name, "path": dir.path, "original_name": dir.originalName,
+      "editor": dir.editor, "is_git_repo": String(dir.isGitRepo), "git_branch": dir.gitBranch,
+      "priority": String(dir.priority), "project_type": dir.projectType?.rawValue,
+      "finder_tags": dir.finderTags.map(\.name).joined(separator: "; "),
+    ])
+  }
+
+  private static func csvRow(_ target: TerminalTarget) -> [String] {
+    row([
+      "kind": target.kind.rawValue, "name": target.name, "path": target.identifier,
+      "detail": target.detail,
+    ])
+  }
+
+  /// 列名 → 値を `csvColumns` の順に並べる（ない列は空）。
+  private static func row(_ values: [String: String?]) -> [String] {
+    csvColumns.map { (values[$0] ?? nil) ?? "" }
+  }
+
+  /// カンマ・ダブルクォート・改行を含む値をダブルクォートで囲む（RFC 4180）。
+  static func csvField(_ value: String) -> String {
+    guard value.contains(where: { $0 == "," || $0 == "\"" || $0.isNewline }) else {
+      return value
+    }
+    return "\"" + value.replacingOccurrences(of: "\"", with: "\"\"") + "\""
+  }
+}
@@ -7,12 +7,45 @@ public struct SelectionHistoryEntry: Codable, Sendable {
   public let selectedPath: String
   public var count: Int
   public var lastUsed: Date
+  /// 時刻（0〜23 時）ごとの使用回数（時間帯に合わせた候補の提案に使う。旧形式の履歴では空）
+  public var hourlyCounts: [Int]
 
-  public init(keyword: String, selectedPath: String, count: Int = 1, lastUsed: Date = Date()) {
+  public init(
+    keyword: String, selectedPath: String, count: Int = 1, lastUsed: Date = Date(),
+    hourlyCounts: [Int] = []
+  ) {
     self.keyword = keyword
     self.selectedPath = selectedPath
     self.count = count
     self.lastUsed = lastUsed
+    self.hourlyCounts = hourlyCounts
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case keyword, selectedPath, count, lastUsed, hourlyCounts
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    keyword = try container.decode(String.self, forKey: .keyword)
+    selectedPath = try container.decode(String.self, forKey: .selectedPath)
+    count = try container.decode(Int.self, forKey: .count)
+    lastUsed = try container.decode(Date.self, forKey: .lastUsed)
+    hourlyCounts = try container.decodeIfPresent([Int].self, forKey: .hourlyCounts) ?? []
+  }
+
+  /// 指定時刻の前後 1 時間を含む使用回数
+  public func usage(aroundHour hour: Int) -> Int {
+    guard hourlyCounts.count == 24 else { return 0 }
+    return (-1...1).reduce(0) { $0 + hourlyCounts[(hour + $1 + 24) % 24] }
+  }
+
+  /// 指定日時の時刻の使用回数を 1 増やす。
+  mutating func recordUsage(at date: Date) {
+    if hourlyCounts.count != 24 {
+      hourlyCounts = Array(repeating: 0, count: 24)
+    }
+    hourlyCounts[Calendar.current.component(.hour, from: date)] += 1
   }
 }
 
@@ -40,14 +73,17 @@ public final class SelectionHistory: Sendable {
   ///
   /// 同じキーワード+パスが既に存在する場合はカウントを増加し lastUsed を更新する。
   /// エントリ数が上限を超えた場合、lastUsed が最も古いエントリを削除する。
-  public func record(keyword: String, path: String) {
+  /// - Parameter date: 使用日時（時刻ごとの使用回数にも記録する）
+  public func record(keyword: String, path: String, at date: Date = Date()) {
     storage.withLock { entries in
       if let index = entries.firstIndex(where: { $0.keyword == keyword && $0.selectedPath == path })
       {
         entries[index].count += 1
-        entries[index].lastUsed = Date()
+        entries[index].lastUsed = date
+        entries[index].recordUsage(at: date)
       } else {
-        let entry = SelectionHistoryEntry(keyword: keyword, selectedPath: path)
+        var entry = SelectionHistoryEntry(keyword: keyword, selectedPath: path, lastUsed: date)
+        entry.recordUsage(at: date)
         entries.append(entry)
       }
 
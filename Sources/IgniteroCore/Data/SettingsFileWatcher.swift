@@ -0,0 +1,97 @@
+import Foundation
+
+/// 設定ファイルの外部での変更（手動での編集・dotfiles の同期）を監視するクラス。
+///
+/// エディタや同期ツールは一時ファイルに書いてから置き換えることが多いため、ファイルに加えて
+/// 親ディレクトリも監視し、イベントのたびにファイルを監視し直す。
+/// 1 回の保存で複数のイベントが届いても、`debounceInterval` の間にまとめて 1 回だけ通知する。
+@MainActor
+public final class SettingsFileWatcher {
+  private static let logger = AppLogger(category: "SettingsFileWatcher")
+
+  private let fileURL: URL
+  private let debounceInterval: Duration
+  private let onChange: @MainActor () -> Void
+  private var directorySource: (any DispatchSourceFileSystemObject)?
+  private var fileSource: (any DispatchSourceFileSystemObject)?
+  private var pendingNotification: Task<Void, Never>?
+
+  /// - Parameters:
+  ///   - fileURL: 監視する設定ファイル
+  ///   - debounceInterval: 変更をまとめる間隔
+  ///   - onChange: 変更があったときに呼ぶ処理（内容が変わったかは呼び出し側で判定する）
+  public init(
+    fileURL: URL,
+    debounceInterval: Duration = .milliseconds(300),
+    onChange: @escaping @MainActor () -> Void
+  ) {
+    self.fileURL = fileURL
+    self.debounceInterval = debounceInterval
+    self.onChange = onChange
+  }
+
+  /// 監視しているか
+  public var isWatching: Bool {
+    directorySource != nil
+  }
+
+  /// 監視を開始する（設定ファイルがまだない場合もディレクトリを作成して監視する）。
+  public func start() {
+    guard !isWatching else { return }
+    let directory = fileURL.deletingLastPathComponent()
+    try? FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
+    guard let source = makeSource(path: directory.path, events: .write) else {
+      Self.logger.warning("Failed to watch settings directory: \(directory.path)")
+      return
+    }
+    directorySource = source
+    watchFile()
+    Self.logger.info("Watching settings file: \(fileURL.path)")
+  }
+
+  /// 監視を停止する。
+  public func stop() {
+    pendingNotification?.cancel()
+    pendingNotification = nil
+    directorySource?.cancel()
+    directorySource = nil
+    fileSource?.cancel()
+    fileSource = nil
+  }
+
+  /// 変更を受け取り、`debounceInterval` の間に続いた変更とまとめて通知する。
+  func fileDidChange() {
+    // 置き換え・削除されたファイルへの監視は効かなくなるため、今のファイルを監視し直す
+    watchFile()
+    pendingNotification?.cancel()
+    pendingNotification = Task { [weak self, debounceInterval] in
+      try? await Task.sleep(for: debounceInterval)
+      guard !Task.isCancelled else { return }
+      self?.onChange()
+    }
+  }
+
+  // MARK: - 非公開メソッド
+
+  private func watchFile() {
+    fileSource?.cancel()
+    fileSource = makeSource(path: fileURL.path, events: [.write, .extend, .delete, .rename])
+  }
+
+  private func makeSource(
+    path: String, events: DispatchSource.FileSystemEvent
+  ) -> (any DispatchSourceFileSystemObject)? {
+    let fd = open(path, O_EVTONLY)
+    guard fd >= 0 else { return nil }
+    let source = DispatchSource.makeFileSystemObjectSource(
+      fileDescriptor: fd, eventMask: events, queue: .main)
+    source.setEventHandler { @Sendable [weak self] in
+      Task { @MainActor in self?.fileDidChange() }
+    }
+    source.setCancelHandler { @Sendable in
+      close(fd)
+    }
+    source.resume()
+    return source
+  }
+}
@@ -26,6 +26,26 @@ public struct RegisteredDirectory: Codable, Sendable, Equatable {
   public var subdirsOpenMode: OpenMode
   public var subdirsEditor: String?
   public var scanForApps: Bool
+  /// スキャン時に除外する gitignore 形式のパターン（例: `node_modules`, `.venv`）
+  public var excludePatterns: [String]
+  /// 配下ディレクトリを走査する深さ（1 で直下のみ）
+  public var subdirsDepth: Int
+  /// `.git` を含むディレクトリを深さに関わらずプロジェクト項目として検出するか
+  public var detectGitRepos: Bool
+  /// 検索順位の優先度（大きいほど配下の項目を上位に表示する。0 で調整なし）
+  public var priority: Int
+  /// 配下のディレクトリの表示名テンプレート（例: `work/{name}`。nil ならディレクトリ名のまま）
+  ///
+  /// `{name}` はディレクトリ名、`{parent}` は 1 つ上のディレクトリ名、`{root}` は登録ディレクトリ名に
+  /// 置き換える。`{name}` を含まない場合は末尾にディレクトリ名を付ける（`work/` → `work/api-server`）。
+  public var subdirsNameTemplate: String?
+
+  /// `subdirsDepth` に指定できる最大値
+  public static let maxSubdirsDepth = 5
+  /// Git リポジトリを探索する最大の深さ
+  public static let maxGitSearchDepth = 6
+  /// `priority` に指定できる範囲
+  public static let priorityRange = -5...5
 
   public init(
     path: String,
@@ -34,7 +54,12 @@ public struct RegisteredDirectory: Codable, Sendable, Equatable {
     parentSearchKeyword: String? = nil,
     subdirsOpenMode: OpenMode,
     subdirsEditor: String? = nil,
-    scanForApps: Bool
+    scanForApps: Bool,
+    excludePatterns: [String] = [],
+    subdirsDepth: Int = 1,
+    detectGitRepos: Bool = false,
+    priority: Int = 0,
+    subdirsNameTemplate: String? = nil
   ) {
     self.path = path
     self.parentOpenMode = parentOpenMode
@@ -43,6 +68,11 @@ public struct RegisteredDirectory: Codable, Sendable, Equatable {
     self.subdirsOpenMode = subdirsOpenMode
     self.subdirsEditor = subdirsEditor
     self.scanForApps = scanForApps
+    self.excludePatterns = excludePatterns
+    self.subdirsDepth = subdirsDepth
+    self.detectGitRepos = detectGitRepos
+    self.priority = priority
+    self.subdirsNameTemplate = subdirsNameTemplate
   }
 
   enum CodingKeys: String, CodingKey {
@@ -53,6 +83,32 @@ public struct RegisteredDirectory: Codable, Sendable, Equatable {
     case subdirsOpenMode = "subdirs_open_mode"
     case subdirsEditor = "subdirs_editor"
     case scanForApps = "scan_for_apps"
+    case excludePatterns = "exclude_patterns"
+    case subdirsDepth = "subdirs_depth"
+    case detectGitRepos = "detect_git_repos"
+    case priority
+    case subdirsNameTemplate = "subdirs_name_template"
+  }
+
+  /// 既存の設定ファイルとの後方互換デコード（新フィールドはデフォルト値で補完）。
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    path = try container.decode(String.self, forKey: .path)
+    parentOpenMode = try container.decode(OpenMode.self, forKey: .parentOpenMode)
+    parentEditor = try container.decodeIfPresent(String.self, forKey: .parentEditor)
+    parentSearchKeyword =
+      try container.decodeIfPresent(String.self, forKey: .parentSearchKeyword)
+    subdirsOpenMode = try container.decode(OpenMode.self, forKey: .subdirsOpenMode)
+    subdirsEditor = try container.decodeIfPresent(String.self, forKey: .subdirsEditor)
+    scanForApps = try container.decode(Bool.self, forKey: .scanForApps)
+    excludePatterns =
+      try container.decodeIfPresent([String].self, forKey: .excludePatterns) ?? []
+    subdirsDepth = try container.decodeIfPresent(Int.self, forKey: .subdirsDepth) ?? 1
+    detectGitRepos =
+      try container.decodeIfPresent(Bool.self, forKey: .detectGitRepos) ?? false
+    priority = try container.decodeIfPresent(Int.self, forKey: .priority) ?? 0
+    subdirsNameTemplate =
+      try container.decodeIfPresent(String.self, forKey: .subdirsNameTemplate)
   }
 }
 
@@ -94,17 +150,88 @@ public struct CacheUpdateSettings: Codable, Sendable, Equatable {
   public var updateOnStartup: Bool
   public var autoUpdateEnabled: Bool
   public var autoUpdateIntervalHours: Int
+  /// 登録ディレクトリ 1 件あたりのスキャンの制限時間（秒）
+  public var scanTimeoutSeconds: Int
+  /// 同時にスキャンする登録ディレクトリの上限
+  public var maxConcurrentScans: Int
+  /// アイコンキャッシュの上限サイズ（MB、nil の場合は上限なし）
+  public var iconCacheMaxMegabytes: Int?
+  /// Launch Services に登録された既定フォルダ外のアプリもキャッシュに含めるか
+  public var discoverRegisteredApps: Bool
+  /// `~/.ssh/config` の SSH ホストと `~/.tmux.conf` の tmux セッションを検索対象にするか
+  public var indexTerminalTargets: Bool
+  /// バッテリー駆動で残量が `lowBatteryThresholdPercent` 未満の間は自動更新を見送るか
+  public var skipOnLowBattery: Bool
+  /// 自動更新を見送るバッテリー残量（%、これ未満で見送る）
+  public var lowBatteryThresholdPercent: Int
+  /// CPU の負荷が高い・温度で性能が抑制されている間は自動更新を見送るか
+  public var skipUnderHeavyLoad: Bool
 
-  public init(updateOnStartup: Bool, autoUpdateEnabled: Bool, autoUpdateIntervalHours: Int) {
+  public static let defaultScanTimeoutSeconds = 30
+  public static let defaultMaxConcurrentScans = 4
+  public static let defaultLowBatteryThresholdPercent = 30
+
+  public init(
+    updateOnStartup: Bool, autoUpdateEnabled: Bool, autoUpdateIntervalHours: Int,
+    scanTimeoutSeconds: Int = defaultScanTimeoutSeconds,
+    maxConcurrentScans: Int = defaultMaxConcurrentScans,
+    iconCacheMaxMegabytes: Int? = nil,
+    discoverRegisteredApps: Bool = false,
+    indexTerminalTargets: Bool = true,
+    skipOnLowBattery: Bool = true,
+    lowBatteryThresholdPercent: Int = defaultLowBatteryThresholdPercent,
+    skipUnderHeavyLoad: Bool = true
+  ) {
     self.updateOnStartup = updateOnStartup
     self.autoUpdateEnabled = autoUpdateEnabled
     self.autoUpdateIntervalHours = autoUpdateIntervalHours
+    self.scanTimeoutSeconds = scanTimeoutSeconds
+    self.maxConcurrentScans = maxConcurrentScans
+    self.iconCacheMaxMegabytes = iconCacheMaxMegabytes
+    self.discoverRegisteredApps = discoverRegisteredApps
+    self.indexTerminalTargets = indexTerminalTargets
+    self.skipOnLowBattery = skipOnLowBattery
+    self.lowBatteryThresholdPercent = lowBatteryThresholdPercent
+    self.skipUnderHeavyLoad = skipUnderHeavyLoad
   }
 
   enum CodingKeys: String, CodingKey {
     case updateOnStartup = "update_on_startup"
     case autoUpdateEnabled = "auto_update_enabled"
     case autoUpdateIntervalHours = "auto_update_interval_hours"
+    case scanTimeoutSeconds = "scan_timeout_seconds"
+    case maxConcurrentScans = "max_concurrent_scans"
+    case iconCacheMaxMegabytes = "icon_cache_max_megabytes"
+    case discoverRegisteredApps = "discover_registered_apps"
+    case indexTerminalTargets = "index_terminal_targets"
+    case skipOnLowBattery = "skip_on_low_battery"
+    case lowBatteryThresholdPercent = "low_battery_threshold_percent"
+    case skipUnderHeavyLoad = "skip_under_heavy_load"
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    updateOnStartup = try container.decode(Bool.self, forKey: .updateOnStartup)
+    autoUpdateEnabled = try container.decode(Bool.self, forKey: .autoUpdateEnabled)
+    autoUpdateIntervalHours = try container.decode(Int.self, forKey: .autoUpdateIntervalHours)
+    scanTimeoutSeconds =
+      try container.decodeIfPresent(Int.self, forKey: .scanTimeoutSeconds)
+      ?? Self.defaultScanTimeoutSeconds
+    maxConcurrentScans =
+      try container.decodeIfPresent(Int.self, forKey: .maxConcurrentScans)
+      ?? Self.defaultMaxConcurrentScans
+    iconCacheMaxMegabytes = try container.decodeIfPresent(Int.self, forKey: .iconCacheMaxMegabytes)
+    discoverRegisteredApps =
+      try container.decodeIfPresent(Bool.self, forKey: .discoverRegisteredApps) ?? false
+    indexTerminalTargets =
+      try container.decodeIfPresent(Bool.self, forKey: .indexTerminalTargets) ?? true
+    skipOnLowBattery =
+      try container.decodeIfPresent(Bool.self, forKey: .skipOnLowBattery) ?? true
+    lowBatteryThresholdPercent =
+      try container.decodeIfPresent(Int.self, forKey: .lowBatteryThresholdPercent)
+      ?? Self.defaultLowBatteryThresholdPercent
+    skipUnderHeavyLoad =
+      try container.decodeIfPresent(Bool.self, forKey: .skipUnderHeavyLoad) ?? true
   }
 }
 
@@ -113,15 +240,35 @@ public struct UpdateCache: Codable, Sendable, Equatable {
   public var checkedAt: Date?
   public var dismissedVersion: String?
   public var downloadURL: String?
+  /// インストール用アーカイブの URL
+  public var assetURL: String?
+  /// アーカイブの SHA-256 チェックサムファイルの URL
+  public var checksumURL: String?
+  /// アーカイブの Ed25519 署名ファイルの URL
+  public var signatureURL: String?
+  /// 直近のアップデート確認が失敗した理由（成功すると nil に戻る）
+  public var lastCheckError: String?
+  /// 連続して失敗した回数（オフラインだった回は数えない）
+  public var consecutiveFailures: Int
+  /// 最後に失敗した日時（再試行までの待ち時間の起点）
+  public var lastFailedAt: Date?
 
   public init(
     latestVersion: String? = nil, checkedAt: Date? = nil, dismissedVersion: String? = nil,
-    downloadURL: String? = nil
+    downloadURL: String? = nil, assetURL: String? = nil, checksumURL: String? = nil,
+    signatureURL: String? = nil,
+    lastCheckError: String? = nil, consecutiveFailures: Int = 0, lastFailedAt: Date? = nil
   ) {
     self.latestVersion = latestVersion
     self.checkedAt = checkedAt
     self.dismissedVersion = dismissedVersion
     self.downloadURL = downloadURL
+    self.assetURL = assetURL
+    self.checksumURL = checksumURL
+    self.signatureURL = signatureURL
+    self.lastCheckError = lastCheckError
+    self.consecutiveFailures = consecutiveFailures
+    self.lastFailedAt = lastFailedAt
   }
 
   enum CodingKeys: String, CodingKey {
@@ -129,12 +276,586 @@ public struct UpdateCache: Codable, Sendable, Equatable {
     case checkedAt = "checked_at"
     case dismissedVersion = "dismissed_version"
     case downloadURL = "download_url"
+    case assetURL = "asset_url"
+    case checksumURL = "checksum_url"
+    case signatureURL = "signature_url"
+    case lastCheckError = "last_check_error"
+    case consecutiveFailures = "consecutive_failures"
+    case lastFailedAt = "last_failed_at"
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    latestVersion = try container.decodeIfPresent(String.self, forKey: .latestVersion)
+    checkedAt = try container.decodeIfPresent(Date.self, forKey: .checkedAt)
+    dismissedVersion = try container.decodeIfPresent(String.self, forKey: .dismissedVersion)
+    downloadURL = try container.decodeIfPresent(String.self, forKey: .downloadURL)
+    assetURL = try container.decodeIfPresent(String.self, forKey: .assetURL)
+    checksumURL = try container.decodeIfPresent(String.self, forKey: .checksumURL)
+    signatureURL = try container.decodeIfPresent(String.self, forKey: .signatureURL)
+    lastCheckError = try container.decodeIfPresent(String.self, forKey: .lastCheckError)
+    consecutiveFailures =
+      try container.decodeIfPresent(Int.self, forKey: .consecutiveFailures) ?? 0
+    lastFailedAt = try container.decodeIfPresent(Date.self, forKey: .lastFailedAt)
+  }
+}
+
+/// ランチャーを表示するスクリーン・位置の決め方
+public enum WindowPlacement: String, Codable, Sendable, CaseIterable {
+  /// 作業中のアプリ（最前面ウィンドウ）があるスクリーンの中央上部
+  case activeDisplay = "active_display"
+  /// カーソルがあるスクリーンの中央上部
+  case followMouse = "follow_mouse"
+  /// 最後に移動した位置（画面外になる場合はカーソルのあるスクリーン）
+  case rememberedPosition = "remembered_position"
+
+  public var displayName: String {
+    switch self {
+    case .activeDisplay: "作業中のディスプレイ"
+    case .followMouse: "カーソルのあるディスプレイ"
+    case .rememberedPosition: "最後に移動した位置"
+    }
+  }
+}
+
+/// ランチャーを表示したときの入力ソース（IME）の扱い
+public enum IMEBehavior: String, Codable, Sendable, CaseIterable {
+  /// 切り替えない（日本語で検索する場合など）
+  case off
+  /// 表示時に英字入力へ切り替える
+  case forceEnglish = "force_english"
+  /// 表示時に英字入力へ切り替え、閉じたら表示前の入力ソースに戻す
+  case restoreOnHide = "restore_on_hide"
+
+  public var displayName: String {
+    switch self {
+    case .off: "切り替えない"
+    case .forceEnglish: "英字入力に切り替える"
+    case .restoreOnHide: "英字入力に切り替え、閉じたら戻す"
+    }
+  }
+
+  /// 表示時に英字入力へ切り替えるか
+  public var switchesToASCII: Bool {
+    self != .off
+  }
+
+  /// 閉じたときに表示前の入力ソースへ戻すか
+  public var restoresOnHide: Bool {
+    self == .restoreOnHide
+  }
+}
+
+/// 記憶したランチャーウィンドウの位置（スクリーン座標）。
+///
+/// ウィンドウ高さは検索結果の件数で変わるため、下端ではなく上端の y 座標を保持する。
+public struct WindowPosition: Codable, Sendable, Equatable {
+  /// ウィンドウ左端の x 座標
+  public var x: Double
+  /// ウィンドウ上端の y 座標
+  public var top: Double
+
+  public init(x: Double, top: Double) {
+    self.x = x
+    self.top = top
+  }
+}
+
+/// ランチャーのアクセントカラー（選択行・ハイライトの色）
+public enum AccentColor: String, Codable, Sendable, CaseIterable {
+  case ember
+  case blue
+  case purple
+  case green
+  case pink
+  case graphite
+
+  public var displayName: String {
+    switch self {
+    case .ember: "エンバー"
+    case .blue: "ブルー"
+    case .purple: "パープル"
+    case .green: "グリーン"
+    case .pink: "ピンク"
+    case .graphite: "グラファイト"
+    }
+  }
+
+  /// 主色（RGB、0〜1）
+  public var primaryRGB: (red: Double, green: Double, blue: Double) {
+    switch self {
+    case .ember: (1.0, 0.47, 0.28)  // #ff7847
+    case .blue: (0.0, 0.48, 1.0)
+    case .purple: (0.69, 0.32, 0.87)
+    case .green: (0.20, 0.70, 0.35)
+    case .pink: (1.0, 0.29, 0.55)
+    case .graphite: (0.45, 0.45, 0.50)
+    }
+  }
+
+  /// グラデーションに重ねる副色（RGB、0〜1）
+  public var secondaryRGB: (red: Double, green: Double, blue: Double) {
+    switch self {
+    case .ember: (1.0, 0.70, 0.28)  // #ffb347
+    case .blue: (0.35, 0.78, 0.98)
+    case .purple: (0.85, 0.55, 0.95)
+    case .green: (0.55, 0.85, 0.45)
+    case .pink: (1.0, 0.60, 0.75)
+    case .graphite: (0.65, 0.65, 0.70)
+    }
+  }
+}
+
+/// 検索結果 1 行の高さ
+public enum ResultDensity: String, Codable, Sendable, CaseIterable {
+  case compact
+  case standard
+  case comfortable
+
+  public var displayName: String {
+    switch self {
+    case .compact: "コンパクト"
+    case .standard: "標準"
+    case .comfortable: "ゆったり"
+    }
+  }
+
+  /// 1 行の高さ (px)
+  public var rowHeight: Double {
+    switch self {
+    case .compact: 44
+    case .standard: 52
+    case .comfortable: 60
+    }
+  }
+}
+
+/// ランチャー背景のすりガラスの濃さ
+public enum LauncherMaterial: String, Codable, Sendable, CaseIterable {
+  case ultraThin = "ultra_thin"
+  case thin
+  case regular
+  case thick
+
+  public var displayName: String {
+    switch self {
+    case .ultraThin: "とても薄い"
+    case .thin: "薄い"
+    case .regular: "標準"
+    case .thick: "濃い"
+    }
+  }
+}
+
+/// ランチャーのライト/ダーク表示
+public enum AppearanceMode: String, Codable, Sendable, CaseIterable {
+  /// システムの設定に従う
+  case system
+  case light
+  case dark
+
+  public var displayName: String {
+    switch self {
+    case .system: "システムに合わせる"
+    case .light: "ライト"
+    case .dark: "ダーク"
+    }
+  }
+}
+
+/// ランチャーウィンドウの外観設定。
+///
+/// ウィンドウ幅と角丸は範囲外の値を手で書き込まれても崩れないよう、
+/// 適用時に `windowWidthRange` / `cornerRadiusRange` へ収める。
+public struct AppearanceSettings: Codable, Sendable, Equatable {
+  public var accentColor: AccentColor
+  /// ウィンドウ幅 (px)
+  public var windowWidth: Int
+  public var density: ResultDensity
+  public var material: LauncherMaterial
+  /// ウィンドウの角丸の半径 (px)
+  public var cornerRadius: Int
+  public var mode: AppearanceMode
+
+  public static let windowWidthRange = 520...1000
+  public static let cornerRadiusRange = 0...24
+
+  public init(
+    accentColor: AccentColor = .ember,
+    windowWidth: Int = 680,
+    density: ResultDensity = .standard,
+    material: LauncherMaterial = .ultraThin,
+    cornerRadius: Int = 12,
+    mode: AppearanceMode = .system
+  ) {
+    self.accentColor = accentColor
+    self.windowWidth = windowWidth
+    self.density = density
+    self.material = material
+    self.cornerRadius = cornerRadius
+    self.mode = mode
+  }
+
+  public static let `default` = AppearanceSettings()
+
+  /// 範囲内に収めたウィンドウ幅
+  public var clampedWindowWidth: Int {
+    min(max(windowWidth, Self.windowWidthRange.lowerBound), Self.windowWidthRange.upperBound)
+  }
+
+  /// 範囲内に収めた角丸の半径
+  public var clampedCornerRadius: Int {
+    min(max(cornerRadius, Self.cornerRadiusRange.lowerBound), Self.cornerRadiusRange.upperBound)
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case accentColor = "accent_color"
+    case windowWidth = "window_width"
+    case density
+    case material
+    case cornerRadius = "corner_radius"
+    case mode
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    let defaults = AppearanceSettings.default
+    accentColor =
+      try container.decodeIfPresent(AccentColor.self, forKey: .accentColor) ?? defaults.accentColor
+    windowWidth =
+      try container.decodeIfPresent(Int.self, forKey: .windowWidth) ?? defaults.windowWidth
+    density =
+      try container.decodeIfPresent(ResultDensity.self, forKey: .density) ?? defaults.density
+    material =
+      try container.decodeIfPresent(LauncherMaterial.self, forKey: .material) ?? defaults.material
+    cornerRadius =
+      try container.decodeIfPresent(Int.self, forKey: .cornerRadius) ?? defaults.cornerRadius
+    mode = try container.decodeIfPresent(AppearanceMode.self, forKey: .mode) ?? defaults.mode
+  }
+}
+
+/// スコアが同じ検索結果の並べ方
+public enum ResultTieBreak: String, Codable, Sendable, CaseIterable {
+  /// 使用回数と最終使用日時から求めた頻度の高い順
+  case frecency
+  /// 名前順
+  case alphabetical
+
+  public var displayName: String {
+    switch self {
+    case .frecency: "よく使う順"
+    case .alphabetical: "名前順"
+    }
+  }
+}
+
+/// 検索結果の件数と並べ方の設定。
+///
+/// 種別ごとの上限は nil の場合に制限せず、全体の上限 `maxResults` だけを適用する。
+public struct ResultSettings: Codable, Sendable, Equatable {
+  /// 表示する検索結果の最大件数
+  public var maxResults: Int
+  /// アプリの最大件数
+  public var maxApps: Int?
+  /// ディレクトリの最大件数
+  public var maxDirectories: Int?
+  /// カスタムコマンドの最大件数
+  public var maxCommands: Int?
+  public var tieBreak: ResultTieBreak
+  /// 検索結果をアプリ・プロジェクト・コマンド・アクションの見出しごとにまとめて表示するか
+  public var groupResults: Bool
+  /// 見出しの並び順（含まれない区分は既定の順で後ろに付ける）
+  public var groupOrder: [SearchResultGroup]
+
+  public static let maxResultsRange = 5...100
+
+  public init(
+    maxResults: Int = 20,
+    maxApps: Int? = nil,
+    maxDirectories: Int? = nil,
+    maxCommands: Int? = nil,
+    tieBreak: ResultTieBreak = .frecency,
+    groupResults: Bool = false,
+    groupOrder: [SearchResultGroup] = SearchResultGroup.allCases
+  ) {
+    self.maxResults = maxResults
+    self.maxApps = maxApps
+    self.maxDirectories = maxDirectories
+    self.maxCommands = maxCommands
+    self.tieBreak = tieBreak
+    self.groupResults = groupResults
+    self.groupOrder = groupOrder
+  }
+
+  public static let `default` = ResultSettings()
+
+  /// 範囲内に収めた最大件数
+  public var clampedMaxResults: Int {
+    min(max(maxResults, Self.maxResultsRange.lowerBound), Self.maxResultsRange.upperBound)
+  }
+
+  /// 指定種別の最大件数（制限しない種別は nil）
+  public func limit(for kind: SearchResultKind) -> Int? {
+    switch kind {
+    case .app: maxApps.map { max($0, 0) }
+    case .directory: maxDirectories.map { max($0, 0) }
+    case .command: maxCommands.map { max($0, 0) }
+    default: nil
+    }
+  }
+
+  /// 重複を除き、含まれない区分を既定の順で後ろに付けた見出しの並び順
+  public var orderedGroups: [SearchResultGroup] {
+    Self.completedGroupOrder(groupOrder)
+  }
+
+  static func completedGroupOrder(_ order: [SearchResultGroup]) -> [SearchResultGroup] {
+    var seen = Set<SearchResultGroup>()
+    return (order + SearchResultGroup.allCases).filter { seen.insert($0).inserted }
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case maxResults = "max_results"
+    case maxApps = "max_apps"
+    case maxDirectories = "max_directories"
+    case maxCommands = "max_commands"
+    case tieBreak = "tie_break"
+    case groupResults = "group_results"
+    case groupOrder = "group_order"
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    let defaults = ResultSettings.default
+    maxResults =
+      try container.decodeIfPresent(Int.self, forKey: .maxResults) ?? defaults.maxResults
+    maxApps = try container.decodeIfPresent(Int.self, forKey: .maxApps)
+    maxDirectories = try container.decodeIfPresent(Int.self, forKey: .maxDirectories)
+    maxCommands = try container.decodeIfPresent(Int.self, forKey: .maxCommands)
+    tieBreak =
+      try container.decodeIfPresent(ResultTieBreak.self, forKey: .tieBreak) ?? defaults.tieBreak
+    groupResults =
+      try container.decodeIfPresent(Bool.self, forKey: .groupResults) ?? defaults.groupResults
+    groupOrder =
+      try container.decodeIfPresent([SearchResultGroup].self, forKey: .groupOrder)
+      ?? defaults.groupOrder
+  }
+}
+
+/// 新バージョンが見つかったときの動作
+public enum UpdateInstallMode: String, Codable, Sendable, CaseIterable {
+  /// バナーで通知し、インストールはユーザーの操作で行う
+  case notify
+  /// バックグラウンドでダウンロード・検証まで済ませ、再起動するだけでインストールできるようにする
+  case autoDownload = "auto_download"
+
+  public var displayName: String {
+    switch self {
+    case .notify: "通知のみ"
+    case .autoDownload: "自動でダウンロード"
+    }
+  }
+}
+
+/// 通知するリリースの種類
+public enum ReleaseChannel: String, Codable, Sendable, CaseIterable {
+  /// 正式版のみ
+  case stable
+  /// ベータ版（プレリリース）も含める
+  case beta
+
+  public var displayName: String {
+    switch self {
+    case .stable: "安定版"
+    case .beta: "ベータ版"
+    }
+  }
+}
+
+/// アップデートの設定
+public struct UpdateSettings: Codable, Sendable, Equatable {
+  public var installMode: UpdateInstallMode
+  public var releaseChannel: ReleaseChannel
+
+  public init(installMode: UpdateInstallMode = .notify, releaseChannel: ReleaseChannel = .stable) {
+    self.installMode = installMode
+    self.releaseChannel = releaseChannel
+  }
+
+  public static let `default` = UpdateSettings()
+
+  enum CodingKeys: String, CodingKey {
+    case installMode = "install_mode"
+    case releaseChannel = "release_channel"
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    let defaults = UpdateSettings.default
+    installMode =
+      try container.decodeIfPresent(UpdateInstallMode.self, forKey: .installMode)
+      ?? defaults.installMode
+    releaseChannel =
+      try container.decodeIfPresent(ReleaseChannel.self, forKey: .releaseChannel)
+      ?? defaults.releaseChannel
+  }
+}
+
+/// 検索クエリの先頭に付けて検索対象を絞り込むプレフィックス設定。
+///
+/// 英数字のプレフィックスは直後に空白が必要（例: `d project`）。
+/// 記号のプレフィックスは空白なしでも認識する（例: `>deploy`）。
+/// 空文字列を設定したプレフィックスは無効になる。
+public struct SearchPrefixSettings: Codable, Sendable, Equatable {
+  public var enabled: Bool
+  public var app: String
+  public var directory: String
+  public var command: String
+  public var path: String
+  /// 絵文字・記号検索のプレフィックス
+  public var symbol: String
+  /// プロセス検索（終了）のプレフィックス
+  public var process: String
+  /// Docker のコンテナ・Compose のプロジェクト検索のプレフィックス
+  public var docker: String
+  /// Docker のコンテナを検索するか（`docker ps` を実行するため既定では無効）
+  public var dockerEnabled: Bool
+
+  public init(
+    enabled: Bool = true,
+    app: String = "a",
+    directory: String = "d",
+    command: String = ">",
+    path: String = "~",
+    symbol: String = ":",
+    process: String = "kill",
+    docker: String = "docker",
+    dockerEnabled: Bool = false
+  ) {
+    self.enabled = enabled
+    self.app = app
+    self.directory = directory
+    self.command = command
+    self.path = path
+    self.symbol = symbol
+    self.process = process
+    self.docker = docker
+    self.dockerEnabled = dockerEnabled
+  }
+
+  public static let `default` = SearchPrefixSettings()
+
+  enum CodingKeys: String, CodingKey {
+    case enabled
+    case app
+    case directory
+    case command
+    case path
+    case symbol
+    case process
+    case docker
+    case dockerEnabled = "docker_enabled"
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    let defaults = SearchPrefixSettings.default
+    enabled = try container.decodeIfPresent(Bool.self, forKey: .enabled) ?? defaults.enabled
+    app = try container.decodeIfPresent(String.self, forKey: .app) ?? defaults.app
+    directory =
+      try container.decodeIfPresent(String.self, forKey: .directory) ?? defaults.directory
+    command = try container.decodeIfPresent(String.self, forKey: .command) ?? defaults.command
+    path = try container.decodeIfPresent(String.self, forKey: .path) ?? defaults.path
+    symbol = try container.decodeIfPresent(String.self, forKey: .symbol) ?? defaults.symbol
+    process = try container.decodeIfPresent(String.self, forKey: .process) ?? defaults.process
+    docker = try container.decodeIfPresent(String.self, forKey: .docker) ?? defaults.docker
+    dockerEnabled =
+      try container.decodeIfPresent(Bool.self, forKey: .dockerEnabled) ?? defaults.dockerEnabled
+  }
+}
+
+/// ランチャーを自動で閉じる条件の設定。
+///
+/// 猶予時間は範囲外の値を手で書き込まれても `focusLossGracePeriodRange` へ収める。
+public struct WindowBehaviorSettings: Codable, Sendable, Equatable {
+  /// 他のアプリへの切り替えやランチャー外のクリックで閉じるか
+  public var hideOnFocusLoss: Bool
+  /// フォーカスを失ってから閉じるまでの猶予 (ms)。猶予中にランチャーへ戻れば閉じない
+  public var focusLossGracePeriodMs: Int
+  /// 項目を起動したら閉じるか
+  public var hideAfterLaunch: Bool
+  /// Escape などで閉じたときに、ランチャーを開く前のアプリを前面に戻すか
+  public var restoreFocusOnHide: Bool
+  /// 表示時の入力ソースの扱い（追加ショートカットごとの指定が優先）
+  public var imeBehavior: IMEBehavior
+  /// Dock にアイコンを表示するか（無効ならメニューバーのみ）
+  public var showDockIcon: Bool
+
+  public static let focusLossGracePeriodRange = 0...5000
+
+  public init(
+    hideOnFocusLoss: Bool = true,
+    focusLossGracePeriodMs: Int = 0,
+    hideAfterLaunch: Bool = true,
+    restoreFocusOnHide: Bool = true,
+    imeBehavior: IMEBehavior = .restoreOnHide,
+    showDockIcon: Bool = false
+  ) {
+    self.hideOnFocusLoss = hideOnFocusLoss
+    self.focusLossGracePeriodMs = focusLossGracePeriodMs
+    self.hideAfterLaunch = hideAfterLaunch
+    self.restoreFocusOnHide = restoreFocusOnHide
+    self.imeBehavior = imeBehavior
+    self.showDockIcon = showDockIcon
+  }
+
+  public static let `default` = WindowBehaviorSettings()
+
+  /// 範囲内に収めた猶予時間
+  public var clampedFocusLossGracePeriod: Duration {
+    .milliseconds(
+      min(
+        max(focusLossGracePeriodMs, Self.focusLossGracePeriodRange.lowerBound),
+        Self.focusLossGracePeriodRange.upperBound))
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case hideOnFocusLoss = "hide_on_focus_loss"
+    case focusLossGracePeriodMs = "focus_loss_grace_period_ms"
+    case hideAfterLaunch = "hide_after_launch"
+    case restoreFocusOnHide = "restore_focus_on_hide"
+    case imeBehavior = "ime_behavior"
+    case showDockIcon = "show_dock_icon"
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    let defaults = WindowBehaviorSettings.default
+    hideOnFocusLoss =
+      try container.decodeIfPresent(Bool.self, forKey: .hideOnFocusLoss)
+      ?? defaults.hideOnFocusLoss
+    focusLossGracePeriodMs =
+      try container.decodeIfPresent(Int.self, forKey: .focusLossGracePeriodMs)
+      ?? defaults.focusLossGracePeriodMs
+    hideAfterLaunch =
+      try container.decodeIfPresent(Bool.self, forKey: .hideAfterLaunch)
+      ?? defaults.hideAfterLaunch
+    restoreFocusOnHide =
+      try container.decodeIfPresent(Bool.self, forKey: .restoreFocusOnHide)
+      ?? defaults.restoreFocusOnHide
+    imeBehavior =
+      try container.decodeIfPresent(IMEBehavior.self, forKey: .imeBehavior)
+      ?? defaults.imeBehavior
+    showDockIcon =
+      try container.decodeIfPresent(Bool.self, forKey: .showDockIcon) ?? defaults.showDockIcon
   }
 }
 
 // MARK: - 設定
 
-public struct Settings: Codable, Sendable {
+public struct Settings: Codable, Sendable, Equatable {
+  /// 設定ファイルの形式のバージョン（`SettingsMigrator` が移行に使う）
+  public var schemaVersion: Int
   public var registeredDirectories: [RegisteredDirectory]
   public var customCommands: [CustomCommand]
   public var defaultEditor: EditorType
@@ -142,6 +863,53 @@ public struct Settings: Codable, Sendable {
   public var cacheUpdate: CacheUpdateSettings
   public var excludedApps: [String]
   public var updateCache: UpdateCache?
+  public var searchPrefixes: SearchPrefixSettings
+  public var hotkeyBindings: [HotkeyBinding]
+  public var launchAtLogin: Bool
+  /// 空クエリ時に表示する最近のプロジェクトの件数（0 で無効）
+  public var recentProjectsLimit: Int
+  /// エディタピッカーで既定以外のエディタを選んだディレクトリは、次からそのエディタで開くか
+  public var remembersDirectoryEditor: Bool
+  /// Xcode のワークスペース・プロジェクトがあるディレクトリを、設定したエディタではなく Xcode で開くか
+  public var opensXcodeProjectsInXcode: Bool
+  /// 最近開いたフォルダを検索対象に取り込むエディタ（登録ディレクトリ外のフォルダも含む）
+  public var recentWorkspaceEditors: [EditorType]
+  /// 右クリックメニューでディレクトリをゴミ箱に入れる前に確認するか（⌘⌫ では常に確認する）
+  public var confirmsMoveToTrash: Bool
+  /// ランチャーを表示するスクリーン・位置の決め方
+  public var windowPlacement: WindowPlacement
+  /// 記憶したランチャーの位置
+  public var windowPosition: WindowPosition?
+  /// 中国語・韓国語の名前をローマ字入力で検索できるようにするか
+  public var transliterationEnabled: Bool
+  /// 項目に割り当てた別名
+  public var itemAliases: [ItemAlias]
+  /// 検索結果から非表示にした項目のパス（カスタムコマンドは識別子）
+  public var excludedItems: [String]
+  /// ユーザー定義の URL ショートカット
+  public var quickLinks: [QuickLink]
+  /// 拡張子ごとにファイルを開くアプリ（一致しない場合は macOS の既定のアプリで開く）
+  public var fileOpeners: [FileOpener]
+  /// ディレクトリをメモリに読み込まず、キャッシュ DB の全文検索インデックスで検索するか
+  public var fullTextSearchEnabled: Bool
+  /// ログファイルに書き込む最低レベル
+  public var logLevel: LogLevel
+  /// スコアの内訳を返す IPC の `search_debug` を受け付けるか（開発者向け）
+  public var searchDebugEnabled: Bool
+  /// メニューバー・設定ウィンドウ・エラー表示の言語（既定は従来どおり日本語）
+  public var language: AppLanguage
+  /// ランチャーウィンドウの外観
+  public var appearance: AppearanceSettings
+  /// 検索結果の件数と並べ方
+  public var results: ResultSettings
+  /// ランチャーを自動で閉じる条件
+  public var windowBehavior: WindowBehaviorSettings
+  /// アップデートの設定
+  public var update: UpdateSettings
+  /// 名前付きの設定プロファイル（プロファイルを使わない場合は空）
+  public var profiles: [SettingsProfile]
+  /// 使用中のプロファイルの名前（プロファイルを使わない場合は nil）
+  public var activeProfile: String?
 
   public init(
     registeredDirectories: [RegisteredDirectory] = [],
@@ -151,8 +919,35 @@ public struct Settings: Codable, Sendable {
     cacheUpdate: CacheUpdateSettings = CacheUpdateSettings(
       updateOnStartup: true, autoUpdateEnabled: false, autoUpdateIntervalHours: 6),
     excludedApps: [String] = [],
-    updateCache: UpdateCache? = nil
+    updateCache: UpdateCache? = nil,
+    searchPrefixes: SearchPrefixSettings = .default,
+    hotkeyBindings: [HotkeyBinding] = [],
+    launchAtLogin: Bool = false,
+    recentProjectsLimit: Int = 5,
+    remembersDirectoryEditor: Bool = true,
+    opensXcodeProjectsInXcode: Bool = false,
+    recentWorkspaceEditors: [EditorType] = [],
+    confirmsMoveToTrash: Bool = true,
+    windowPlacement: WindowPlacement = .followMouse,
+    windowPosition: WindowPosition? = nil,
+    transliterationEnabled: Bool = false,
+    itemAliases: [ItemAlias] = [],
+    excludedItems: [String] = [],
+    quickLinks: [QuickLink] = [],
+    fileOpeners: [FileOpener] = [],
+    fullTextSearchEnabled: Bool = false,
+    logLevel: LogLevel = .info,
+    searchDebugEnabled: Bool = false,
+    language: AppLanguage = .ja,
+    appearance: AppearanceSettings = .default,
+    results: ResultSettings = .default,
+    windowBehavior: WindowBehaviorSettings = .default,
+    update: UpdateSettings = .default,
+    profiles: [SettingsProfile] = [],
+    activeProfile: String? = nil,
+    schemaVersion: Int = SettingsMigrator.currentVersion
   ) {
+    self.schemaVersion = schemaVersion
     self.registeredDirectories = registeredDirectories
     self.customCommands = customCommands
     self.defaultEditor = defaultEditor
@@ -160,11 +955,44 @@ public struct Settings: Codable, Sendable {
     self.cacheUpdate = cacheUpdate
     self.excludedApps = excludedApps
     self.updateCache = updateCache
+    self.searchPrefixes = searchPrefixes
+    self.hotkeyBindings = hotkeyBindings
+    self.launchAtLogin = launchAtLogin
+    self.recentProjectsLimit = recentProjectsLimit
+    self.remembersDirectoryEditor = remembersDirectoryEditor
+    self.opensXcodeProjectsInXcode = opensXcodeProjectsInXcode
+    self.recentWorkspaceEditors = recentWorkspaceEditors
+    self.confirmsMoveToTrash = confirmsMoveToTrash
+    self.windowPlacement = windowPlacement
+    self.windowPosition = windowPosition
+    self.transliterationEnabled = transliterationEnabled
+    self.itemAliases = itemAliases
+    self.excludedItems = excludedItems
+    self.quickLinks = quickLinks
+    self.fileOpeners = fileOpeners
+    self.fullTextSearchEnabled = fullTextSearchEnabled
+    self.logLevel = logLevel
+    self.searchDebugEnabled = searchDebugEnabled
+    self.language = language
+    self.appearance = appearance
+    self.results = results
+    self.windowBehavior = windowBehavior
+    self.update = update
+    self.profiles = profiles
+    self.activeProfile = activeProfile
   }
 
   public static let `default` = Settings()
 
+  /// Escape などで閉じたときに、ランチャーを開く前のアプリを前面に戻すか
+  /// （設定ファイルでは `window_behavior.restore_focus_on_hide`）
+  public var restoreFocusOnHide: Bool {
+    get { windowBehavior.restoreFocusOnHide }
+    set { windowBehavior.restoreFocusOnHide = newValue }
+  }
+
   enum CodingKeys: String, CodingKey {
+    case schemaVersion = "schema_version"
     case registeredDirectories = "registered_directories"
     case customCommands = "custom_commands"
     case defaultEditor = "default_editor"
@@ -172,11 +1000,39 @@ public struct Settings: Codable, Sendable {
     case cacheUpdate = "cache_update"
     case excludedApps = "excluded_apps"
     case updateCache = "update_cache"
+    case searchPrefixes = "search_prefixes"
+    case hotkeyBindings = "hotkey_bindings"
+    case launchAtLogin = "launch_at_login"
+    case recentProjectsLimit = "recent_projects_limit"
+    case remembersDirectoryEditor = "remember_directory_editor"
+    case opensXcodeProjectsInXcode = "open_xcode_projects_in_xcode"
+    case recentWorkspaceEditors = "recent_workspace_editors"
+    case confirmsMoveToTrash = "confirm_move_to_trash"
+    case windowPlacement = "window_placement"
+    case windowPosition = "window_position"
+    case transliterationEnabled = "transliteration_enabled"
+    case itemAliases = "item_aliases"
+    case excludedItems = "excluded_items"
+    case quickLinks = "quick_links"
+    case fileOpeners = "file_openers"
+    case fullTextSearchEnabled = "full_text_search_enabled"
+    case logLevel = "log_level"
+    case searchDebugEnabled = "search_debug_enabled"
+    case language
+    case appearance
+    case results
+    case windowBehavior = "window_behavior"
+    case update
+    case profiles
+    case activeProfile = "active_profile"
   }
 
   /// 既存の設定ファイルとの後方互換デコード（新フィールドはデフォルト値で補完）。
+  ///
+  /// 名前の変更・移動をした項目は、デコード前に `SettingsMigrator` で移行しておく。
   public init(from decoder: Decoder) throws {
     let container = try decoder.container(keyedBy: CodingKeys.self)
+    schemaVersion = try container.decodeIfPresent(Int.self, forKey: .schemaVersion) ?? 0
     registeredDirectories =
       try container.decodeIfPresent([RegisteredDirectory].self, forKey: .registeredDirectories)
       ?? []
@@ -194,6 +1050,104 @@ public struct Settings: Codable, Sendable {
       try container.decodeIfPresent([String].self, forKey: .excludedApps) ?? []
     updateCache =
       try container.decodeIfPresent(UpdateCache.self, forKey: .updateCache)
+    searchPrefixes =
+      try container.decodeIfPresent(SearchPrefixSettings.self, forKey: .searchPrefixes)
+      ?? .default
+    hotkeyBindings =
+      try container.decodeIfPresent([HotkeyBinding].self, forKey: .hotkeyBindings) ?? []
+    launchAtLogin =
+      try container.decodeIfPresent(Bool.self, forKey: .launchAtLogin) ?? false
+    recentProjectsLimit =
+      try container.decodeIfPresent(Int.self, forKey: .recentProjectsLimit) ?? 5
+    remembersDirectoryEditor =
+      try container.decodeIfPresent(Bool.self, forKey: .remembersDirectoryEditor) ?? true
+    opensXcodeProjectsInXcode =
+      try container.decodeIfPresent(Bool.self, forKey: .opensXcodeProjectsInXcode) ?? false
+    recentWorkspaceEditors =
+      try container.decodeIfPresent([EditorType].self, forKey: .recentWorkspaceEditors) ?? []
+    confirmsMoveToTrash =
+      try container.decodeIfPresent(Bool.self, forKey: .confirmsMoveToTrash) ?? true
+    windowPlacement =
+      try container.decodeIfPresent(WindowPlacement.self, forKey: .windowPlacement)
+      ?? .followMouse
+    windowPosition = try container.decodeIfPresent(WindowPosition.self, forKey: .windowPosition)
+    transliterationEnabled =
+      try container.decodeIfPresent(Bool.self, forKey: .transliterationEnabled) ?? false
+    itemAliases = try container.decodeIfPresent([ItemAlias].self, forKey: .itemAliases) ?? []
+    excludedItems = try container.decodeIfPresent([String].self, forKey: .excludedItems) ?? []
+    quickLinks = try container.decodeIfPresent([QuickLink].self, forKey: .quickLinks) ?? []
+    fileOpeners =
+      try container.decodeIfPresent([FileOpener].self, forKey: .fileOpeners) ?? []
+    fullTextSearchEnabled =
+      try container.decodeIfPresent(Bool.self, forKey: .fullTextSearchEnabled) ?? false
+    logLevel = try container.decodeIfPresent(LogLevel.self, forKey: .logLevel) ?? .info
+    searchDebugEnabled =
+      try container.decodeIfPresent(Bool.self, forKey: .searchDebugEnabled) ?? false
+    language = try container.decodeIfPresent(AppLanguage.self, forKey: .language) ?? .ja
+    appearance =
+      try container.decodeIfPresent(AppearanceSettings.self, forKey: .appearance) ?? .default
+    results = try container.decodeIfPresent(ResultSettings.self, forKey: .results) ?? .default
+    windowBehavior =
+      try container.decodeIfPresent(WindowBehaviorSettings.self, forKey: .windowBehavior)
+      ?? .default
+    update = try container.decodeIfPresent(UpdateSettings.self, forKey: .update) ?? .default
+    profiles = try container.decodeIfPresent([SettingsProfile].self, forKey: .profiles) ?? []
+    activeProfile = try container.decodeIfPresent(String.self, forKey: .activeProfile)
+  }
+
+  /// 使用中の設定（登録ディレクトリ・コマンド・追加ホットキー）をプロファイルとして取り出す。
+  func snapshot(named name: String) -> SettingsProfile {
+    SettingsProfile(
+      name: name, registeredDirectories: registeredDirectories, customCommands: customCommands,
+      hotkeyBindings: hotkeyBindings)
+  }
+
+  /// プロファイルの内容を使用中の設定に反映する。
+  mutating func apply(_ profile: SettingsProfile) {
+    registeredDirectories = profile.registeredDirectories
+    customCommands = profile.customCommands
+    hotkeyBindings = profile.hotkeyBindings
+    activeProfile = profile.name
+  }
+}
+
+// MARK: - 設定ファイルの状態
+
+/// 設定ファイルを読み込めるかどうか（自己診断用）
+public enum SettingsFileStatus: Sendable, Equatable {
+  /// 読み込める
+  case valid
+  /// まだ作成されていない
+  case missing
+  /// 読み込めない（理由）
+  case invalid(String)
+}
+
+// MARK: - 設定のバックアップ
+
+/// 保存のたびに残す、直前の設定ファイルのバックアップ（`settings.json.1` が最新）
+public struct SettingsBackup: Sendable, Equatable, Identifiable {
+  public var id: Int { generation }
+  /// 世代（1 が最新）
+  public let generation: Int
+  /// バックアップした日時（ファイルの更新日時）
+  public let modifiedAt: Date
+
+  public init(generation: Int, modifiedAt: Date) {
+    self.generation = generation
+    self.modifiedAt = modifiedAt
+  }
+}
+
+/// バックアップからの復元のエラー
+public enum SettingsBackupError: Error, LocalizedError, Equatable {
+  /// 指定した世代のバックアップがない
+  case notFound(generation: Int)
+
+  public var errorDescription: String? {
+    switch self {
+    case .notFound(let generation): "No settings backup #\(generation)"
+    }
   }
 }
 
@@ -202,6 +1156,8 @@ public struct Settings: Codable, Sendable {
 @MainActor
 @Observable
 public final class SettingsManager: @unchecked Sendable {
+  private static let logger = AppLogger(category: "SettingsManager")
+
   public var settings: Settings
 
   private let configDirectory: URL
@@ -211,15 +1167,50 @@ public final class SettingsManager: @unchecked Sendable {
     configDirectory.appendingPathComponent(fileName)
   }
 
-  public init(configDirectory: URL? = nil) {
+  /// 直近に読み込んだ・保存したファイルの内容（自分の保存による変更通知を無視するために使う）
+  private var persistedData: Data?
+
+  /// バックアップの世代を進める最短の間隔（秒）
+  private let backupInterval: TimeInterval
+  /// 起動後に最後にバックアップの世代を進めた日時
+  private var lastBackupDate: Date?
+
+  /// 設定ファイルの場所
+  public var fileURL: URL {
+    filePath
+  }
+
+  /// 外部で編集された設定ファイルを読み直して反映した後に投稿する通知（object は SettingsManager）
+  public static let didChangeNotification = Notification.Name("settings-changed")
+
+  /// - Parameters:
+  ///   - configDirectory: 設定ファイルを置くディレクトリ（nil の場合は `~/.config/ignitero-launcher`）
+  ///   - backupInterval: バックアップの世代を進める最短の間隔（秒。既定は `defaultBackupInterval`）
+  public init(configDirectory: URL? = nil, backupInterval: TimeInterval = defaultBackupInterval) {
     self.configDirectory =
       configDirectory
       ?? FileManager.default.homeDirectoryForCurrentUser
       .appendingPathComponent(".config/ignitero-launcher")
+    self.backupInterval = backupInterval
     self.settings = .default
   }
 
+  /// 残すバックアップの世代数
+  public static let backupLimit = 5
+  /// バックアップの世代を進める既定の間隔（1 時間）
+  public static let defaultBackupInterval: TimeInterval = 60 * 60
+
+  /// 設定を保存する。
+  ///
+  /// 内容が変わる場合は直前のファイルを `settings.json.1` 〜 `.5` へ世代をずらして残し、
+  /// `writeDurably(_:to:)` で書き込む（書き込みの途中で落ちても元のファイルが残る）。
+  /// 世代を進めるのは起動後の最初の保存と、前回から `backupInterval` 以上経った保存だけで、
+  /// スライダーの操作のような連続した保存で古い世代を押し出さない。
   public func save() throws {
+    try save(forcingBackup: false)
+  }
+
+  private func save(forcingBackup: Bool) throws {
     let fm = FileManager.default
     if !fm.fileExists(atPath: configDirectory.path) {
       try fm.createDirectory(at: configDirectory, withIntermediateDirectories: true)
@@ -228,7 +1219,114 @@ public final class SettingsManager: @unchecked Sendable {
     let encoder = JSONEncoder()
     encoder.outputFormatting = [.prettyPrinted, .sortedKeys]
     let data = try encoder.encode(settings)
-    try data.write(to: filePath, options: .atomic)
+    if let current = try? Data(contentsOf: filePath), current != data,
+      forcingBackup || isBackupDue(now: Date())
+    {
+      rotateBackups()
+    }
+    try Self.writeDurably(data, to: filePath)
+    persistedData = data
+  }
+
+  /// 一時ファイルへ書き込んで F_FULLFSYNC し、rename で置き換える。
+  ///
+  /// macOS の fsync はドライブのキャッシュまでは書き出さないため、`fullSync(_:)` を使う。
+  /// シンボリックリンク（dotfiles の管理など）はリンク先を置き換え、リンク自体は残す。
+  nonisolated static func writeDurably(_ data: Data, to url: URL) throws {
+    let target = url.resolvingSymlinksInPath()
+    let directory = target.deletingLastPathComponent()
+    let temp = directory.appendingPathComponent(
+      ".\(target.lastPathComponent).\(UUID().uuidString).tmp")
+    guard FileManager.default.createFile(atPath: temp.path, contents: nil) else {
+      throw CocoaError(.fileWriteUnknown, userInfo: [NSFilePathErrorKey: temp.path])
+    }
+    do {
+      let handle = try FileHandle(forWritingTo: temp)
+      defer { try? handle.close() }
+      try handle.write(contentsOf: data)
+      guard fullSync(handle.fileDescriptor) else {
+        throw POSIXError(POSIXErrorCode(rawValue: errno) ?? .EIO)
+      }
+    } catch {
+      try? FileManager.default.removeItem(at: temp)
+      throw error
+    }
+    guard rename(temp.path, target.path) == 0 else {
+      let code = errno
+      try? FileManager.default.removeItem(at: temp)
+      throw POSIXError(POSIXErrorCode(rawValue: code) ?? .EIO)
+    }
+    // rename をディスクへ反映するため、ディレクトリも同期する
+    let directoryFD = open(directory.path, O_RDONLY)
+    if directoryFD >= 0 {
+      _ = fullSync(directoryFD)
+      close(directoryFD)
+    }
+  }
+
+  /// ファイルの内容をドライブの不揮発領域まで書き出す。
+  ///
+  /// F_FULLFSYNC に対応しないファイルシステム（一部のネットワークボリュームなど）では fsync で代える。
+  /// - Returns: 書き出せた場合は `true`
+  private nonisolated static func fullSync(_ fd: Int32) -> Bool {
+    fcntl(fd, F_FULLFSYNC) == 0 || fsync(fd) == 0
+  }
+
+  // MARK: - バックアップ
+
+  /// 残っているバックアップを新しい順に返す。
+  public func backups() -> [SettingsBackup] {
+    (1...Self.backupLimit).compactMap { generation in
+      let attributes = try? FileManager.default.attributesOfItem(
+        atPath: backupURL(generation: generation).path)
+      guard let modifiedAt = attributes?[.modificationDate] as? Date else { return nil }
+      return SettingsBackup(generation: generation, modifiedAt: modifiedAt)
+    }
+  }
+
+  /// バックアップから設定を復元して保存する（復元前の設定は新しいバックアップとして残る）。
+  ///
+  /// - Parameter generation: 復元する世代（1 が最新）
+  /// - Returns: 復元前の設定
+  /// - Throws: バックアップがない場合は `SettingsBackupError.notFound`、
+  ///   読めない場合は読み込み・デコードのエラー
+  @discardableResult
+  public func restoreBackup(generation: Int = 1) throws -> Settings {
+    let url = backupURL(generation: generation)
+    guard FileManager.default.fileExists(atPath: url.path) else {
+      throw SettingsBackupError.notFound(generation: generation)
+    }
+    let restored = try Self.decodeSettings(from: Data(contentsOf: url)).settings
+    let previous = settings
+    settings = restored
+    try save(forcingBackup: true)
+    return previous
+  }
+
+  private func backupURL(generation: Int) -> URL {
+    configDirectory.appendingPathComponent("\(fileName).\(generation)")
+  }
+
+  /// 前回バックアップの世代を進めてから `backupInterval` 以上経ったか（起動後は初回のみ `true`）。
+  private func isBackupDue(now: Date) -> Bool {
+    guard let lastBackupDate else { return true }
+    return now.timeIntervalSince(lastBackupDate) >= backupInterval
+  }
+
+  /// バックアップの世代を 1 つずつずらし、今のファイルを最新のバックアップにする。
+  private func rotateBackups() {
+    lastBackupDate = Date()
+    let fm = FileManager.default
+    try? fm.removeItem(at: backupURL(generation: Self.backupLimit))
+    for generation in stride(from: Self.backupLimit - 1, through: 1, by: -1) {
+      try? fm.moveItem(
+        at: backupURL(generation: generation), to: backupURL(generation: generation + 1))
+    }
+    do {
+      try fm.copyItem(at: filePath.resolvingSymlinksInPath(), to: backupURL(generation: 1))
+    } catch {
+      Self.logger.warning("Failed to back up settings: \(error.localizedDescription)")
+    }
   }
 
   public func load() throws {
@@ -240,7 +1338,18 @@ public final class SettingsManager: @unchecked Sendable {
 
     do {
       let data = try Data(contentsOf: filePath)
-      settings = try JSONDecoder().decode(Settings.self, from: data)
+      let decoded = try Self.decodeSettings(from: data)
+      settings = decoded.settings
+      persistedData = data
+      if decoded.migrated {
+        // 移行前のファイルはバックアップ（settings.json.1）として残る
+        Self.logger.info("Migrated settings to schema version \(SettingsMigrator.currentVersion)")
+        do {
+          try save()
+        } catch {
+          Self.logger.warning("Failed to save migrated settings: \(error.localizedDescription)")
+        }
+      }
     } catch is DecodingError {
       // JSON が破損: バックアップを作成しデフォルト値に復元
       let backupPath = configDirectory.appendingPathComponent("\(fileName).backup")
@@ -253,6 +1362,47 @@ public final class SettingsManager: @unchecked Sendable {
     }
   }
 
+  /// 設定ファイルの内容を現在の形式へ移行してからデコードする。
+  ///
+  /// - Returns: 設定と、移行したかどうか
+  nonisolated static func decodeSettings(
+    from data: Data
+  ) throws -> (settings: Settings, migrated: Bool) {
+    let migrated = SettingsMigrator.migrate(data)
+    return (try JSONDecoder().decode(Settings.self, from: migrated ?? data), migrated != nil)
+  }
+
+  /// 外部で編集された設定ファイルを読み直す。
+  ///
+  /// `load()` と違い、ファイルが読めない JSON（編集の途中など）やファイルがない場合も
+  /// 現在の設定を既定値に戻さない。
+  /// - Returns: 直近の読み込み・保存から内容が変わって反映した場合は、反映前の設定
+  /// - Throws: ファイルを読めない、または JSON として解釈できない場合
+  @discardableResult
+  public func reloadFromDisk() throws -> Settings? {
+    guard FileManager.default.fileExists(atPath: filePath.path) else { return nil }
+    let data = try Data(contentsOf: filePath)
+    guard data != persistedData else { return nil }
+    let reloaded = try Self.decodeSettings(from: data).settings
+    persistedData = data
+    guard reloaded != settings else { return nil }
+    let previous = settings
+    settings = reloaded
+    return previous
+  }
+
+  /// 設定ファイルを読み込めるか検査する（現在の設定は変更しない）。
+  public func fileStatus() -> SettingsFileStatus {
+    guard FileManager.default.fileExists(atPath: filePath.path) else { return .missing }
+    do {
+      let data = try Data(contentsOf: filePath)
+      _ = try Self.decodeSettings(from: data)
+      return .valid
+    } catch {
+      return .invalid(error.localizedDescription)
+    }
+  }
+
   public func addDirectory(_ dir: RegisteredDirectory) throws {
     // path を一意キーとして扱う。SettingsView の一覧は id: \.path で行を識別するため、
     // 同一 path の重複登録を防ぐ。既存エントリがあれば設定を置き換える。
@@ -278,4 +1428,73 @@ public final class SettingsManager: @unchecked Sendable {
     settings.customCommands.removeAll { $0.alias == alias }
     try save()
   }
+
+  // MARK: - プロファイル
+
+  /// プロファイルの名前の一覧（プロファイルを使わない場合は空）
+  public var profileNames: [String] {
+    settings.profiles.map(\.name)
+  }
+
+  /// 使用中の設定を複製して新しいプロファイルを作る（切り替えはしない）。
+  ///
+  /// 初めて作る場合は、それまでの設定を `SettingsProfile.defaultName` のプロファイルとして
+  /// 保存し、使用中にする。
+  /// - Throws: 名前が空・重複している場合、キャッシュ DB のファイル名が既存のプロファイルと
+  ///   同じになる場合は `SettingsProfileError`
+  public func createProfile(named name: String) throws {
+    let name = name.trimmingCharacters(in: .whitespacesAndNewlines)
+    guard !name.isEmpty else { throw SettingsProfileError.emptyName }
+    if settings.profiles.isEmpty {
+      settings.profiles = [settings.snapshot(named: SettingsProfile.defaultName)]
+      settings.activeProfile = SettingsProfile.defaultName
+    }
+    guard !profileNames.contains(name) else { throw SettingsProfileError.duplicateName(name) }
+    if let existing = profileNames.first(where: {
+      SettingsProfile.sharesCachePartition(name, with: $0)
+    }) {
+      throw SettingsProfileError.conflictingName(name, existing: existing)
+    }
+    settings.profiles.append(settings.snapshot(named: name))
+    try save()
+  }
+
+  /// 使用中の設定を今のプロファイルへ書き戻し、指定したプロファイルに切り替える。
+  ///
+  /// - Parameters:
+  ///   - name: 切り替え先のプロファイル名
+  ///   - launcherShortcut: 今のランチャー表示のショートカット（今のプロファイルへ書き戻す。
+  ///     nil の場合はプロファイルに保存済みのものを残す）
+  /// - Returns: 切り替えた場合は `true`（すでに使用中なら `false`）
+  /// - Throws: プロファイルがない場合は `SettingsProfileError.notFound`
+  @discardableResult
+  public func switchProfile(
+    to name: String, launcherShortcut: LauncherShortcut? = nil
+  ) throws -> Bool {
+    guard let target = settings.profiles.first(where: { $0.name == name }) else {
+      throw SettingsProfileError.notFound(name)
+    }
+    guard settings.activeProfile != name else { return false }
+    if let active = settings.activeProfile,
+      let index = settings.profiles.firstIndex(where: { $0.name == active })
+    {
+      var snapshot = settings.snapshot(named: active)
+      snapshot.launcherShortcut = launcherShortcut ?? settings.profiles[index].launcherShortcut
+      settings.profiles[index] = snapshot
+    }
+    settings.apply(target)
+    try save()
+    return true
+  }
+
+  /// プロファイルを削除する。
+  ///
+  /// - Throws: 使用中のプロファイルは `SettingsProfileError.profileInUse`、
+  ///   ない場合は `SettingsProfileError.notFound`
+  public func deleteProfile(named name: String) throws {
+    guard profileNames.contains(name) else { throw SettingsProfileError.notFound(name) }
+    guard settings.activeProfile != name else { throw SettingsProfileError.profileInUse(name) }
+    settings.profiles.removeAll { $0.name == name }
+    try save()
+  }
 }
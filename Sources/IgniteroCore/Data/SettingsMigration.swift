@@ -0,0 +1,62 @@
+import Foundation
+
+/// 設定ファイルの形式の移行。
+///
+/// `schema_version` がない・古いファイルを、デコードする前に JSON のまま 1 段階ずつ
+/// 現在の形式へ変換する。項目の追加だけならデコード時の既定値で足りるため移行は不要で、
+/// 名前の変更・移動・削除をするときに `migrations` の末尾へ手順を追加する。
+///
+/// - 0: `schema_version` のないファイル（Tauri 版と、それを引き継いだ Swift 版）
+/// - 1: Tauri 版だけの項目を削除
+/// - 2: `restore_focus_on_hide` を `window_behavior` の下へ移動
+public enum SettingsMigrator {
+  /// 1 段階の移行（`migrations[n]` はバージョン n から n + 1 へ変換する）
+  typealias Migration = @Sendable (inout [String: Any]) -> Void
+
+  static let migrations: [Migration] = [
+    removeTauriOnlyFields,
+    moveRestoreFocusIntoWindowBehavior,
+  ]
+
+  /// 現在の形式のバージョン
+  public static var currentVersion: Int {
+    migrations.count
+  }
+
+  /// 設定ファイルの JSON を現在の形式へ移行する。
+  ///
+  /// JSON のオブジェクトとして読めない場合は変換せず、デコードでエラーにする。
+  /// 新しいバージョンのアプリが書いたファイルも変換しない。
+  /// - Parameter data: 設定ファイルの内容
+  /// - Returns: 移行した場合は移行後の JSON（移行が不要な場合は nil）
+  public static func migrate(_ data: Data) -> Data? {
+    guard var json = (try? JSONSerialization.jsonObject(with: data)) as? [String: Any] else {
+      return nil
+    }
+    let version = json[Settings.CodingKeys.schemaVersion.rawValue] as? Int ?? 0
+    guard version < currentVersion else { return nil }
+    for migration in migrations[max(version, 0)...] {
+      migration(&json)
+    }
+    json[Settings.CodingKeys.schemaVersion.rawValue] = currentVersion
+    return try? JSONSerialization.data(withJSONObject: json, options: [.sortedKeys])
+  }
+
+  // MARK: - 移行の手順
+
+  /// 0 → 1: Tauri 版のウィンドウ位置（左上原点の物理ピクセル）は `window_position` と
+  /// 座標系が異なり変換できないため削除する。
+  static func removeTauriOnlyFields(_ json: inout [String: Any]) {
+    json.removeValue(forKey: "main_window_position")
+  }
+
+  /// 1 → 2: ランチャーを閉じたときの動作を `window_behavior` にまとめる。
+  static func moveRestoreFocusIntoWindowBehavior(_ json: inout [String: Any]) {
+    guard let restoreFocus = json.removeValue(forKey: "restore_focus_on_hide") else { return }
+    var windowBehavior = json["window_behavior"] as? [String: Any] ?? [:]
+    if windowBehavior["restore_focus_on_hide"] == nil {
+      windowBehavior["restore_focus_on_hide"] = restoreFocus
+    }
+    json["window_behavior"] = windowBehavior
+  }
+}
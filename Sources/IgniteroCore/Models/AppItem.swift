@@ -1,25 +1,168 @@
 import Foundation
 import GRDB
 
+/// アプリを見つけた経路
+public enum AppSource: String, Codable, Sendable, Equatable {
+  /// /Applications などの既定フォルダ・登録ディレクトリのスキャン
+  case scan
+  /// Launch Services に登録されたアプリの列挙（既定フォルダ外のアプリ）
+  case launchServices = "launch_services"
+}
+
 public struct AppItem: Codable, Sendable, Identifiable, Equatable {
   public var id: String { path }
   public let name: String
   public let path: String
+  /// 変換済みのアイコン（128px の PNG）のパス。未変換の場合は nil
   public let iconPath: String?
+  /// アイコンの変換元（`.icns`、アセットカタログにのみアイコンがあるアプリはアプリ自身のパス）
+  public let iconSourcePath: String?
   public let originalName: String?
+  /// 表示名・元の名前以外の名前（他の言語のローカライズ名など。検索キーにも加える）
+  public let alternateNames: [String]
+  /// 名前より低い重みで照合するキーワード（`CFBundleSpotlightKeywords` など。例: Safari の `browser`）
+  public let keywords: [String]
+  public let source: AppSource
+  /// バージョン（Info.plist の `CFBundleShortVersionString`）
+  public let version: String?
+  /// バンドル ID（Info.plist の `CFBundleIdentifier`）
+  public let bundleIdentifier: String?
+  /// カテゴリ（Info.plist の `LSApplicationCategoryType`。例: `public.app-category.developer-tools`）
+  public let category: String?
+  /// バンドルと Info.plist のうち新しい方の更新日時（1970 年からの秒数。変わっていなければ再スキャンで読み直さない）
+  public let bundleModificationTime: TimeInterval?
 
   enum CodingKeys: String, CodingKey {
     case name
     case path
     case iconPath = "icon_path"
+    case iconSourcePath = "icon_source_path"
     case originalName = "original_name"
+    case alternateNames = "alternate_names"
+    case keywords
+    case source
+    case version
+    case bundleIdentifier = "bundle_id"
+    case category
+    case bundleModificationTime = "bundle_mtime"
   }
 
-  public init(name: String, path: String, iconPath: String? = nil, originalName: String? = nil) {
+  public init(
+    name: String, path: String, iconPath: String? = nil, iconSourcePath: String? = nil,
+    originalName: String? = nil, alternateNames: [String] = [], keywords: [String] = [],
+    source: AppSource = .scan, version: String? = nil, bundleIdentifier: String? = nil,
+    category: String? = nil, bundleModificationTime: TimeInterval? = nil
+  ) {
     self.name = name
     self.path = path
     self.iconPath = iconPath
+    self.iconSourcePath = iconSourcePath
     self.originalName = originalName
+    self.alternateNames = alternateNames
+    self.keywords = keywords
+    self.source = source
+    self.version = version
+    self.bundleIdentifier = bundleIdentifier
+    self.category = category
+    self.bundleModificationTime = bundleModificationTime
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    name = try container.decode(String.self, forKey: .name)
+    path = try container.decode(String.self, forKey: .path)
+    iconPath = try container.decodeIfPresent(String.self, forKey: .iconPath)
+    iconSourcePath = try container.decodeIfPresent(String.self, forKey: .iconSourcePath)
+    originalName = try container.decodeIfPresent(String.self, forKey: .originalName)
+    alternateNames =
+      try container.decodeIfPresent([String].self, forKey: .alternateNames) ?? []
+    keywords = try container.decodeIfPresent([String].self, forKey: .keywords) ?? []
+    source = try container.decodeIfPresent(AppSource.self, forKey: .source) ?? .scan
+    version = try container.decodeIfPresent(String.self, forKey: .version)
+    bundleIdentifier = try container.decodeIfPresent(String.self, forKey: .bundleIdentifier)
+    category = try container.decodeIfPresent(String.self, forKey: .category)
+    bundleModificationTime = try container.decodeIfPresent(
+      TimeInterval.self, forKey: .bundleModificationTime)
+  }
+
+  /// カテゴリの `public.app-category.` を除いた名前（例: `developer-tools`）
+  public var categoryName: String? {
+    category.map { $0.replacingOccurrences(of: "public.app-category.", with: "") }
+  }
+}
+
+/// 検索語からバンドル ID の絞り込み（`bundle:com.apple`、`id:com.google.chrome`）を取り出した結果
+public struct AppBundleQuery: Sendable, Equatable {
+  /// 正規化済みのバンドル ID の絞り込み語（前方一致。すべてに一致するアプリだけを返す）
+  public let bundleIdentifiers: [String]
+  /// 絞り込み語を除いた残りの検索語
+  public let term: String
+
+  /// 正規化済みの検索語を空白で区切り、`bundle:` / `id:` で始まる語を絞り込みとして取り出す。
+  ///
+  /// 絞り込み語がない場合は nil を返す（`bundle:` だけの語は通常の検索語として扱う）。
+  public static func parse(_ term: String) -> AppBundleQuery? {
+    var identifiers: [String] = []
+    var rest: [String] = []
+    for word in term.split(whereSeparator: \.isWhitespace) {
+      let identifier =
+        ["bundle:", "id:"].first { word.hasPrefix($0) }.map { String(word.dropFirst($0.count)) }
+      if let identifier, !identifier.isEmpty {
+        identifiers.append(identifier)
+      } else {
+        rest.append(String(word))
+      }
+    }
+    guard !identifiers.isEmpty else { return nil }
+    return AppBundleQuery(bundleIdentifiers: identifiers, term: rest.joined(separator: " "))
+  }
+
+  /// アプリのバンドル ID が絞り込み語すべてに前方一致するか（大文字小文字は区別しない）
+  public func matches(_ app: AppItem) -> Bool {
+    guard let bundleIdentifier = app.bundleIdentifier?.lowercased() else { return false }
+    return bundleIdentifiers.allSatisfy { bundleIdentifier.hasPrefix($0) }
+  }
+}
+
+/// 検索語からカテゴリの絞り込み（`category:developer`、`cat:games`）を取り出した結果
+public struct AppCategoryQuery: Sendable, Equatable {
+  /// 検索語に付けるカテゴリの絞り込みの接頭辞
+  public static let prefix = "category:"
+
+  /// 正規化済みのカテゴリの絞り込み語（すべてに一致するアプリだけを返す）
+  public let categories: [String]
+  /// 絞り込み語を除いた残りの検索語
+  public let term: String
+
+  /// 正規化済みの検索語を空白で区切り、`category:` / `cat:` で始まる語を絞り込みとして取り出す。
+  ///
+  /// 絞り込み語がない場合は nil を返す（`category:` だけの語は通常の検索語として扱う）。
+  public static func parse(_ term: String) -> AppCategoryQuery? {
+    var categories: [String] = []
+    var rest: [String] = []
+    for word in term.split(whereSeparator: \.isWhitespace) {
+      let category =
+        [prefix, "cat:"].first { word.hasPrefix($0) }.map { String(word.dropFirst($0.count)) }
+      if let category, !category.isEmpty {
+        categories.append(category)
+      } else {
+        rest.append(String(word))
+      }
+    }
+    guard !categories.isEmpty else { return nil }
+    return AppCategoryQuery(categories: categories, term: rest.joined(separator: " "))
+  }
+
+  /// アプリのカテゴリ名（`developer-tools` など）が絞り込み語すべてに一致するか。
+  ///
+  /// カテゴリ名全体か `-` で区切った語の前方一致で判定する
+  /// （`developer` は `developer-tools`、`games` は `action-games` にも一致する）。
+  public func matches(_ app: AppItem) -> Bool {
+    guard let categoryName = app.categoryName?.lowercased() else { return false }
+    let words = categoryName.split(separator: "-")
+    return categories.allSatisfy { category in
+      categoryName.hasPrefix(category) || words.contains { $0.hasPrefix(category) }
+    }
   }
 }
 
@@ -0,0 +1,138 @@
+import Foundation
+
+// MARK: - AppLanguage
+
+/// メニューバー・設定ウィンドウ・エラー表示の言語
+public enum AppLanguage: String, Codable, Sendable, CaseIterable {
+  /// システムの優先言語に合わせる（日本語以外は英語）
+  case auto
+  case ja
+  case en
+
+  public var displayName: String {
+    switch self {
+    case .auto: "システムに合わせる"
+    case .ja: "日本語"
+    case .en: "English"
+    }
+  }
+
+  /// `auto` をシステムの優先言語で `ja` / `en` に解決する（`ja` / `en` はそのまま返す）。
+  ///
+  /// - Parameter preferredLanguages: 優先言語（`ja-JP` などの言語タグ。先頭を使う）
+  public func resolved(
+    preferredLanguages: [String] = Locale.preferredLanguages
+  ) -> AppLanguage {
+    guard self == .auto else { return self }
+    return preferredLanguages.first?.hasPrefix("ja") == true ? .ja : .en
+  }
+
+  /// 日時の表示に使うロケール
+  public var locale: Locale {
+    switch resolved() {
+    case .ja: Locale(identifier: "ja_JP")
+    case .en, .auto: Locale(identifier: "en_US")
+    }
+  }
+
+  /// 表示する文字列を返す。
+  public func text(_ text: LocalizedText) -> String {
+    text.string(in: resolved())
+  }
+}
+
+// MARK: - LocalizedText
+
+/// 言語ごとに切り替える UI の文字列
+public enum LocalizedText: Sendable, Equatable {
+  case showWindow
+  case recentItems
+  case rebuildCache
+  case rebuildingCache
+  case resetCache
+  case pauseIndexing
+  case settings
+  case quit
+  /// 使用中のプロファイル
+  case profile(String)
+  /// キャッシュの状態が分からない
+  case cacheUnknown
+  /// キャッシュを更新中
+  case cacheUpdating
+  /// キャッシュの最終更新日時（nil は起動後に未更新）と一時停止中か
+  case cacheStatus(lastUpdated: String?, paused: Bool)
+  /// 設定ウィンドウのタイトル
+  case settingsWindowTitle
+  /// 起動処理・キャッシュ更新中のアイコンのツールチップ
+  case refreshingToolTip
+  /// アップデートがあるときのアイコンのツールチップ
+  case updateAvailableToolTip(String)
+  /// 読み込み中のアイコンの説明
+  case loading
+  /// キャッシュのリセットの確認の見出し
+  case resetCacheConfirmTitle
+  /// キャッシュのリセットで消えるものの説明
+  case resetCacheConfirmMessage
+  /// キャッシュのリセットを確定するボタン
+  case reset
+  /// 取り消すボタン
+  case cancel
+
+  /// 言語の文字列（`auto` は解決してから渡す。渡された場合は英語）
+  public func string(in language: AppLanguage) -> String {
+    let ja = language == .ja
+    switch self {
+    case .showWindow:
+      return ja ? "ウィンドウを表示" : "Show Window"
+    case .recentItems:
+      return ja ? "最近使った項目" : "Recent Items"
+    case .rebuildCache:
+      return ja ? "キャッシュを再構築" : "Rebuild Cache"
+    case .rebuildingCache:
+      return ja ? "キャッシュを再構築中..." : "Rebuilding Cache..."
+    case .resetCache:
+      return ja ? "キャッシュをリセット..." : "Reset Cache..."
+    case .pauseIndexing:
+      return ja ? "インデックス作成を一時停止" : "Pause Indexing"
+    case .settings:
+      return ja ? "設定" : "Settings"
+    case .quit:
+      return ja ? "終了" : "Quit"
+    case .profile(let name):
+      return ja ? "プロファイル: \(name)" : "Profile: \(name)"
+    case .cacheUnknown:
+      return ja ? "キャッシュ: 不明" : "Cache: Unknown"
+    case .cacheUpdating:
+      return ja ? "キャッシュ: 更新中..." : "Cache: Updating..."
+    case .cacheStatus(let lastUpdated, let paused):
+      if ja {
+        let status = lastUpdated.map { "最終更新 \($0)" } ?? "起動後は未更新"
+        return paused ? "キャッシュ: \(status)（一時停止中）" : "キャッシュ: \(status)"
+      }
+      let status = lastUpdated.map { "Last updated \($0)" } ?? "Not updated since launch"
+      return paused ? "Cache: \(status) (Paused)" : "Cache: \(status)"
+    case .settingsWindowTitle:
+      return ja ? "設定 - Ignitero" : "Settings - Ignitero"
+    case .refreshingToolTip:
+      return ja
+        ? "Ignitero Launcher - キャッシュを更新中..." : "Ignitero Launcher - Updating cache..."
+    case .updateAvailableToolTip(let version):
+      return ja
+        ? "Ignitero Launcher - v\(version) にアップデートできます"
+        : "Ignitero Launcher - v\(version) is available"
+    case .loading:
+      return ja ? "読み込み中" : "Loading"
+    case .resetCacheConfirmTitle:
+      return ja ? "キャッシュをリセットしますか？" : "Reset the cache?"
+    case .resetCacheConfirmMessage:
+      return ja
+        ? "スキャン結果に加えて、お気に入り・開いた履歴・検索履歴も削除されます。「キャッシュを再構築」で直らない場合に使ってください。"
+        : "Favorites, open history and search history are deleted along with the scan results."
+          + " Use this only if Rebuild Cache does not help."
+    case .reset:
+      return ja ? "リセット" : "Reset"
+    case .cancel:
+      return ja ? "キャンセル" : "Cancel"
+    }
+  }
+}
@@ -0,0 +1,45 @@
+import Foundation
+
+// MARK: - BatchLaunchFailure
+
+/// まとめて起動したうち、起動に失敗した 1 件
+public struct BatchLaunchFailure: Sendable, Equatable {
+  /// 失敗した結果の表示名
+  public let name: String
+  /// 失敗した結果のパス
+  public let path: String
+  public let error: LauncherError
+
+  public init(name: String, path: String, error: LauncherError) {
+    self.name = name
+    self.path = path
+    self.error = error
+  }
+}
+
+// MARK: - BatchLaunchReport
+
+/// 複数選択した結果をまとめて起動した結果。
+///
+/// 途中で失敗しても残りの起動は続け、失敗した項目ごとのエラーを保持する。
+public struct BatchLaunchReport: Sendable, Equatable {
+  /// 起動できた結果のパス（起動した順）
+  public let launchedPaths: [String]
+  public let failures: [BatchLaunchFailure]
+
+  public init(launchedPaths: [String], failures: [BatchLaunchFailure]) {
+    self.launchedPaths = launchedPaths
+    self.failures = failures
+  }
+
+  /// エラーバナーに表示するエラー（すべて成功した場合は nil）。
+  ///
+  /// 失敗が 1 件のみなら元のエラーをそのまま、複数なら件数と失敗した項目をまとめて返す。
+  public var error: LauncherError? {
+    guard let first = failures.first else { return nil }
+    guard failures.count > 1 else { return first.error }
+    let total = launchedPaths.count + failures.count
+    let names = failures.map(\.name).joined(separator: ", ")
+    return .launch("\(total) 件中 \(failures.count) 件（\(names)）")
+  }
+}
@@ -0,0 +1,44 @@
+import Foundation
+
+/// `ignitero://` URL で受け付ける操作。
+///
+/// - `ignitero://show`: ランチャーを表示する
+/// - `ignitero://search?q=foo`: 検索語を入力した状態でランチャーを表示する
+/// - `ignitero://open?path=/Applications/Safari.app`: 検索対象のアプリ・ディレクトリを開く
+///
+/// ブラウザ拡張や Web ページからも呼び出せるため、`open` で開けるのはランチャーの検索対象に
+/// 含まれるアプリ・ディレクトリだけにする（任意のファイルやスクリプトは開かない）。
+public enum DeepLink: Equatable, Sendable {
+  case show
+  case search(String)
+  case open(String)
+
+  /// 登録する URL スキーム
+  public static let scheme = "ignitero"
+
+  /// URL を解釈する。
+  ///
+  /// - Parameter url: `ignitero://` で始まる URL
+  /// - Returns: スキームが違う、未知の操作、必須のパラメータがない場合は `nil`
+  public init?(url: URL) {
+    guard url.scheme?.lowercased() == Self.scheme,
+      let components = URLComponents(url: url, resolvingAgainstBaseURL: false)
+    else { return nil }
+
+    func value(_ name: String) -> String? {
+      components.queryItems?.first { $0.name == name }?.value
+    }
+
+    switch components.host?.lowercased() {
+    case "show":
+      self = .show
+    case "search":
+      self = .search(value("q") ?? "")
+    case "open":
+      guard let path = value("path"), !path.isEmpty else { return nil }
+      self = .open((path as NSString).expandingTildeInPath)
+    default:
+      return nil
+    }
+  }
+}
@@ -6,17 +6,63 @@ public struct DirectoryItem: Codable, Sendable, Identifiable, Equatable {
   public let name: String
   public let path: String
   public let editor: String?
+  /// `.git` を含む Git リポジトリかどうか
+  public let isGitRepo: Bool
+  /// Git リポジトリの現在のブランチ（detached HEAD の場合は短縮コミットハッシュ）
+  public let gitBranch: String?
+  /// 登録ディレクトリの検索順位の優先度（`RegisteredDirectory.priority` を引き継ぐ）
+  public let priority: Int
+  /// 表示名テンプレートを適用する前のディレクトリ名（テンプレートがなければ nil）
+  public let originalName: String?
+  /// 直下の Xcode のワークスペース・プロジェクトのパス（ない場合は nil）
+  public let xcodeProject: String?
+  /// 直下のファイルから判定したプロジェクトの種類（判定できない場合は nil）
+  public let projectType: ProjectType?
+  /// Finder のタグ（キャッシュ DB には JSON で保存する）
+  public let finderTags: [FinderTag]
 
   enum CodingKeys: String, CodingKey {
     case name
     case path
     case editor
+    case isGitRepo = "is_git_repo"
+    case gitBranch = "git_branch"
+    case priority
+    case originalName = "original_name"
+    case xcodeProject = "xcode_project"
+    case projectType = "project_type"
+    case finderTags = "finder_tags"
   }
 
-  public init(name: String, path: String, editor: String? = nil) {
+  public init(
+    name: String, path: String, editor: String? = nil, isGitRepo: Bool = false,
+    gitBranch: String? = nil, priority: Int = 0, originalName: String? = nil,
+    xcodeProject: String? = nil, projectType: ProjectType? = nil, finderTags: [FinderTag] = []
+  ) {
     self.name = name
     self.path = path
     self.editor = editor
+    self.isGitRepo = isGitRepo
+    self.gitBranch = gitBranch
+    self.priority = priority
+    self.originalName = originalName
+    self.xcodeProject = xcodeProject
+    self.projectType = projectType
+    self.finderTags = finderTags
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    name = try container.decode(String.self, forKey: .name)
+    path = try container.decode(String.self, forKey: .path)
+    editor = try container.decodeIfPresent(String.self, forKey: .editor)
+    isGitRepo = try container.decodeIfPresent(Bool.self, forKey: .isGitRepo) ?? false
+    gitBranch = try container.decodeIfPresent(String.self, forKey: .gitBranch)
+    priority = try container.decodeIfPresent(Int.self, forKey: .priority) ?? 0
+    originalName = try container.decodeIfPresent(String.self, forKey: .originalName)
+    xcodeProject = try container.decodeIfPresent(String.self, forKey: .xcodeProject)
+    projectType = try container.decodeIfPresent(ProjectType.self, forKey: .projectType)
+    finderTags = try container.decodeIfPresent([FinderTag].self, forKey: .finderTags) ?? []
   }
 }
 
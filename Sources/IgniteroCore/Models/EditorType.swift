@@ -4,15 +4,53 @@ public enum EditorType: String, Codable, Sendable, CaseIterable {
   case vscode
   case antigravity
   case zed
+  case intellij
+  case pycharm
+  case webstorm
+  case xcode
 
   /// .code-workspace ファイルの読み込みに対応しているか
   public var supportsCodeWorkspace: Bool {
+    projectFileDetector == .codeWorkspace
+  }
+
+  /// ディレクトリを開くときに、ディレクトリの代わりに開くプロジェクトファイルの検出器
+  public var projectFileDetector: ProjectFileDetector? {
     switch self {
-    case .zed: false
-    default: true
+    case .windsurf, .cursor, .vscode, .antigravity: .codeWorkspace
+    case .xcode: .xcode
+    case .zed, .intellij, .pycharm, .webstorm: nil
     }
   }
 
+  /// 最近開いたフォルダを記録する `~/Library/Application Support` 配下のフォルダ名
+  /// （VS Code 系のエディタのみ。Zed は形式が異なるため nil）
+  public var applicationSupportFolderName: String? {
+    switch self {
+    case .windsurf: "Windsurf"
+    case .cursor: "Cursor"
+    case .vscode: "Code"
+    case .antigravity: "Antigravity"
+    case .zed, .intellij, .pycharm, .webstorm, .xcode: nil
+    }
+  }
+
+  /// `~/Library/Application Support/JetBrains` 配下の設定フォルダ名の接頭辞
+  /// （`IntelliJIdea2025.1` のように後ろにバージョンが付く。JetBrains の IDE 以外は空）
+  public var jetBrainsConfigPrefixes: [String] {
+    switch self {
+    case .intellij: ["IntelliJIdea", "IdeaIC"]
+    case .pycharm: ["PyCharm", "PyCharmCE"]
+    case .webstorm: ["WebStorm"]
+    default: []
+    }
+  }
+
+  /// 最近開いたフォルダを取り込めるか
+  public var supportsRecentWorkspaceImport: Bool {
+    applicationSupportFolderName != nil || !jetBrainsConfigPrefixes.isEmpty
+  }
+
   public var displayName: String {
     switch self {
     case .windsurf: "Windsurf"
@@ -20,6 +58,10 @@ public enum EditorType: String, Codable, Sendable, CaseIterable {
     case .vscode: "Visual Studio Code"
     case .antigravity: "Antigravity"
     case .zed: "Zed"
+    case .intellij: "IntelliJ IDEA"
+    case .pycharm: "PyCharm"
+    case .webstorm: "WebStorm"
+    case .xcode: "Xcode"
     }
   }
 }
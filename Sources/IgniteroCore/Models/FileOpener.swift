@@ -0,0 +1,63 @@
+import Foundation
+
+/// ファイルの拡張子ごとに、開くアプリを指定する設定（例: `md` → Typora、`sketch` → Sketch）。
+///
+/// 一致する設定がないファイルや、指定したアプリが見つからない場合は macOS の既定のアプリで開く。
+public struct FileOpener: Codable, Sendable, Equatable, Identifiable {
+  public let id: UUID
+  /// 拡張子（先頭の `.` は省略可。`tar.gz` のように複数の `.` を含めてもよい）
+  public var fileExtension: String
+  /// 開くアプリ（`.app` のパス、アプリ名、またはバンドル ID）
+  public var application: String
+
+  public init(id: UUID = UUID(), fileExtension: String, application: String) {
+    self.id = id
+    self.fileExtension = fileExtension
+    self.application = application
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case id
+    case fileExtension = "extension"
+    case application
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    self.id = try container.decodeIfPresent(UUID.self, forKey: .id) ?? UUID()
+    self.fileExtension = try container.decode(String.self, forKey: .fileExtension)
+    self.application = try container.decode(String.self, forKey: .application)
+  }
+
+  /// 照合に使う拡張子（小文字、先頭の `.` と前後の空白を除く）
+  public var normalizedExtension: String {
+    var text = fileExtension.trimmingCharacters(in: .whitespaces).lowercased()
+    while text.hasPrefix(".") {
+      text.removeFirst()
+    }
+    return text
+  }
+
+  /// 拡張子とアプリが登録可能な内容か（拡張子は空白・`/` を含まない）
+  public var isValid: Bool {
+    let fileExtension = normalizedExtension
+    return !fileExtension.isEmpty
+      && !fileExtension.contains(where: { $0.isWhitespace || $0 == "/" })
+      && !application.trimmingCharacters(in: .whitespaces).isEmpty
+  }
+
+  /// ファイルに一致する設定を探す。
+  ///
+  /// 拡張子は大文字小文字を区別せず、複数に一致する場合は長い方（`tar.gz` は `gz` より優先）を選ぶ。
+  /// - Parameters:
+  ///   - path: ファイルのパス
+  ///   - openers: 登録済みの設定
+  /// - Returns: 一致する設定がない場合は nil
+  public static func opener(forPath path: String, in openers: [FileOpener]) -> FileOpener? {
+    let fileName = (path as NSString).lastPathComponent.lowercased()
+    return
+      openers
+      .filter { $0.isValid && fileName.hasSuffix("." + $0.normalizedExtension) }
+      .max { $0.normalizedExtension.count < $1.normalizedExtension.count }
+  }
+}
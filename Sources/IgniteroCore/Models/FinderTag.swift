@@ -0,0 +1,89 @@
+import Foundation
+
+/// Finder のタグのラベルの色（`_kMDItemUserTags` の改行後の番号）
+public enum FinderTagColor: Int, Codable, Sendable, CaseIterable {
+  case none = 0
+  case gray
+  case green
+  case purple
+  case blue
+  case yellow
+  case red
+  case orange
+
+  /// `#red` のように色で絞り込むときの名前（英語名と日本語の標準タグ名）
+  public var keywords: [String] {
+    switch self {
+    case .none: []
+    case .gray: ["gray", "grey", "グレイ", "グレー"]
+    case .green: ["green", "グリーン"]
+    case .purple: ["purple", "パープル"]
+    case .blue: ["blue", "ブルー"]
+    case .yellow: ["yellow", "イエロー"]
+    case .red: ["red", "レッド"]
+    case .orange: ["orange", "オレンジ"]
+    }
+  }
+}
+
+/// Finder で項目に付けたタグ
+public struct FinderTag: Codable, Sendable, Equatable {
+  public let name: String
+  public let color: FinderTagColor
+
+  public init(name: String, color: FinderTagColor = .none) {
+    self.name = name
+    self.color = color
+  }
+
+  /// タグを保存する拡張属性の名前
+  public static let extendedAttributeName = "com.apple.metadata:_kMDItemUserTags"
+
+  /// `_kMDItemUserTags` の内容（`名前\n色番号` の文字列の配列のバイナリ plist）を解析する。
+  ///
+  /// 解析できない場合は空を返す。色番号がないタグは `.none` とする。
+  public static func parse(userTags data: Data) -> [FinderTag] {
+    guard
+      let entries = try? PropertyListSerialization.propertyList(from: data, format: nil)
+        as? [String]
+    else { return [] }
+    return entries.compactMap { entry in
+      let parts = entry.split(separator: "\n", maxSplits: 1, omittingEmptySubsequences: false)
+      guard let name = parts.first, !name.isEmpty else { return nil }
+      let color = parts.count > 1 ? Int(parts[1]).flatMap(FinderTagColor.init) : nil
+      return FinderTag(name: String(name), color: color ?? .none)
+    }
+  }
+
+  /// 正規化済みの絞り込み語がこのタグに一致するか（名前の前方一致、または色の名前と一致）
+  public func matches(_ filter: String) -> Bool {
+    SearchQueryNormalizer.normalize(name).hasPrefix(filter) || color.keywords.contains(filter)
+  }
+}
+
+/// 検索語からタグの絞り込み（`#red`、`tag:urgent`）を取り出した結果
+public struct FinderTagQuery: Sendable, Equatable {
+  /// 正規化済みのタグの絞り込み語（すべてに一致する項目だけを返す）
+  public let tags: [String]
+  /// 絞り込み語を除いた残りの検索語
+  public let term: String
+
+  /// 正規化済みの検索語を空白で区切り、`#` / `＃` / `tag:` で始まる語を絞り込みとして取り出す。
+  ///
+  /// 絞り込み語がない場合は nil を返す（`#` だけ、`tag:` だけの語は通常の検索語として扱う）。
+  public static func parse(_ term: String) -> FinderTagQuery? {
+    var tags: [String] = []
+    var rest: [String] = []
+    for word in term.split(whereSeparator: \.isWhitespace) {
+      let tag =
+        ["#", "＃", "tag:"].first { word.hasPrefix($0) }.map { String(word.dropFirst($0.count)) }
+      if let tag, !tag.isEmpty {
+        tags.append(tag)
+      } else {
+        rest.append(String(word))
+      }
+    }
+    guard !tags.isEmpty else { return nil }
+    return FinderTagQuery(tags: tags, term: rest.joined(separator: " "))
+  }
+}
@@ -0,0 +1,176 @@
+import Foundation
+
+// MARK: - LauncherMode
+
+/// 追加ホットキーで起動するランチャーのモード
+public enum LauncherMode: String, Codable, Sendable, CaseIterable {
+  /// 通常のランチャー（表示/非表示トグル）
+  case launcher
+  /// アプリのみを検索
+  case apps
+  /// ディレクトリのみを検索
+  case directories
+  /// カスタムコマンドのみを検索
+  case commands
+  /// Emoji ピッカー
+  case emoji
+  /// カラーピッカー
+  case colorPicker = "color_picker"
+
+  public var displayName: String {
+    switch self {
+    case .launcher: "ランチャー"
+    case .apps: "アプリ検索"
+    case .directories: "ディレクトリ検索"
+    case .commands: "コマンド検索"
+    case .emoji: "Emoji ピッカー"
+    case .colorPicker: "カラーピッカー"
+    }
+  }
+
+  /// 検索対象を絞り込むモードの場合、その検索対象を返す
+  public var searchScope: SearchScope? {
+    switch self {
+    case .apps: .apps
+    case .directories: .directories
+    case .commands: .commands
+    case .launcher, .emoji, .colorPicker: nil
+    }
+  }
+}
+
+// MARK: - HotkeyModifier
+
+/// ホットキーの修飾キー
+public enum HotkeyModifier: String, Sendable, CaseIterable {
+  case command
+  case shift
+  case option
+  case control
+
+  /// 設定文字列での表記揺れ（`cmd` / `⌘` など）を吸収する
+  static func parse(_ token: String) -> HotkeyModifier? {
+    switch token {
+    case "cmd", "command", "⌘": .command
+    case "shift", "⇧": .shift
+    case "opt", "option", "alt", "⌥": .option
+    case "ctrl", "control", "⌃": .control
+    default: nil
+    }
+  }
+}
+
+// MARK: - HotkeyBinding
+
+/// モードに割り当てる追加のグローバルショートカット。
+///
+/// `shortcut` は `cmd+shift+p` のように修飾キーとキー名を `+` で連結した文字列で、
+/// 修飾キーを 1 つ以上含む必要がある。キー名の代わりに `keycode:102` のように
+/// 物理キーのキーコード（`kVK_*`）も指定できる。`double:cmd` のように書くと、
+/// 修飾キーを 2 回続けて押したときに発火する（キーボード配列や入力ソースに依らない）。
+public struct HotkeyBinding: Codable, Sendable, Equatable, Identifiable {
+  public let id: UUID
+  public var shortcut: String
+  public var mode: LauncherMode
+  public var enabled: Bool
+  /// このショートカットで表示したときの入力ソースの扱い（nil は全体の設定に従う）
+  public var imeBehavior: IMEBehavior?
+
+  /// 修飾キー以外に指定できるキー名
+  public static let supportedKeys: Set<String> = {
+    var keys = Set("abcdefghijklmnopqrstuvwxyz0123456789".map(String.init))
+    keys.formUnion(["space", "return", "tab", "escape", ",", ".", "/", ";", "'", "[", "]", "-", "="])
+    keys.formUnion((1...12).map { "f\($0)" })
+    return keys
+  }()
+
+  /// 物理キーのキーコードで指定する場合の接頭辞（`keycode:49`）
+  public static let keyCodePrefix = "keycode:"
+
+  /// 修飾キーの 2 回押しで指定する場合の接頭辞（`double:cmd`）
+  public static let doubleTapPrefix = "double:"
+
+  public init(
+    id: UUID = UUID(), shortcut: String, mode: LauncherMode, enabled: Bool = true,
+    imeBehavior: IMEBehavior? = nil
+  ) {
+    self.id = id
+    self.shortcut = shortcut
+    self.mode = mode
+    self.enabled = enabled
+    self.imeBehavior = imeBehavior
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case id
+    case shortcut
+    case mode
+    case enabled
+    case imeBehavior = "ime_behavior"
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    self.id = try container.decodeIfPresent(UUID.self, forKey: .id) ?? UUID()
+    self.shortcut = try container.decode(String.self, forKey: .shortcut)
+    self.mode = try container.decode(LauncherMode.self, forKey: .mode)
+    self.enabled = try container.decodeIfPresent(Bool.self, forKey: .enabled) ?? true
+    self.imeBehavior = try container.decodeIfPresent(IMEBehavior.self, forKey: .imeBehavior)
+  }
+
+  /// ショートカット文字列を解析した結果（無効な場合は nil）
+  public var parsed: (key: String, modifiers: Set<HotkeyModifier>)? {
+    Self.parse(shortcut)
+  }
+
+  /// 2 回押しで発火する修飾キー（`double:` 形式でない場合は nil）
+  public var doubleTapModifier: HotkeyModifier? {
+    Self.parseDoubleTap(shortcut)
+  }
+
+  /// キーの組み合わせか修飾キーの 2 回押しとして解析できるか
+  public static func isValidShortcut(_ text: String) -> Bool {
+    parse(text) != nil || parseDoubleTap(text) != nil
+  }
+
+  /// `double:cmd` 形式の文字列を解析する。
+  ///
+  /// - Returns: 2 回押しで発火する修飾キー（形式が異なる・未対応の修飾キーの場合は nil）
+  public static func parseDoubleTap(_ text: String) -> HotkeyModifier? {
+    let normalized = text.lowercased().trimmingCharacters(in: .whitespaces)
+    guard normalized.hasPrefix(doubleTapPrefix) else { return nil }
+    return HotkeyModifier.parse(
+      normalized.dropFirst(doubleTapPrefix.count).trimmingCharacters(in: .whitespaces))
+  }
+
+  /// `keycode:49` 形式のキー名からキーコードを取り出す（0〜127 以外は nil）
+  public static func keyCode(fromKeyName name: String) -> Int? {
+    guard name.hasPrefix(keyCodePrefix), let code = Int(name.dropFirst(keyCodePrefix.count)),
+      (0...127).contains(code)
+    else { return nil }
+    return code
+  }
+
+  /// `cmd+shift+p` 形式の文字列を解析する。
+  ///
+  /// - Returns: キー名と修飾キーの組。キーが 1 つでない・修飾キーがない・未対応キーの場合は nil
+  public static func parse(_ text: String) -> (key: String, modifiers: Set<HotkeyModifier>)? {
+    let tokens = text.lowercased()
+      .split(separator: "+", omittingEmptySubsequences: false)
+      .map { $0.trimmingCharacters(in: .whitespaces) }
+    var modifiers: Set<HotkeyModifier> = []
+    var key: String?
+    for token in tokens {
+      if let modifier = HotkeyModifier.parse(token) {
+        modifiers.insert(modifier)
+      } else {
+        let name = token == "enter" ? "return" : token == "esc" ? "escape" : token
+        guard key == nil, supportedKeys.contains(name) || keyCode(fromKeyName: name) != nil
+        else { return nil }
+        key = name
+      }
+    }
+    guard let key, !modifiers.isEmpty else { return nil }
+    return (key, modifiers)
+  }
+}
@@ -0,0 +1,41 @@
+import Foundation
+
+/// 任意の項目に割り当てる短い別名（例: `vsc` → Visual Studio Code、`dl` → ~/Downloads）。
+///
+/// `path` はアプリ・ディレクトリのパス、またはカスタムコマンドの `historyIdentifier`。
+/// 検索語が別名に一致すると、その項目を強く優先して表示する。
+public struct ItemAlias: Codable, Sendable, Equatable, Identifiable {
+  public let id: UUID
+  public var alias: String
+  public var path: String
+
+  public init(id: UUID = UUID(), alias: String, path: String) {
+    self.id = id
+    self.alias = alias
+    self.path = path
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case id
+    case alias
+    case path
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    self.id = try container.decodeIfPresent(UUID.self, forKey: .id) ?? UUID()
+    self.alias = try container.decode(String.self, forKey: .alias)
+    self.path = try container.decode(String.self, forKey: .path)
+  }
+
+  /// 照合用に正規化した別名
+  public var normalizedAlias: String {
+    SearchQueryNormalizer.normalize(alias)
+  }
+
+  /// 別名として使える文字列か（空でなく、空白を含まない）
+  public static func isValid(alias: String) -> Bool {
+    let normalized = SearchQueryNormalizer.normalize(alias)
+    return !normalized.isEmpty && !normalized.contains(where: \.isWhitespace)
+  }
+}
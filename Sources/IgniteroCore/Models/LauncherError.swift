@@ -0,0 +1,160 @@
+import Foundation
+import GRDB
+
+/// ユーザーに表示する操作の失敗。
+///
+/// 各サービスが投げるエラーを分類し、ランチャーに表示するメッセージと対処方法、
+/// ログや不具合報告で参照する安定したエラーコードを持たせる。
+public enum LauncherError: Error, LocalizedError, Equatable, Sendable {
+  /// ファイルの読み書きに失敗した
+  case io(String)
+  /// キャッシュ DB の読み書きに失敗した
+  case cache(String)
+  /// アプリ・エディタ・ターミナル・コマンドの起動に失敗した
+  case launch(String)
+  /// 操作する権限がない
+  case permissionDenied(String)
+  /// 対象が見つからない
+  case notFound(String)
+
+  /// ログと不具合報告で使うエラーコード
+  public var code: String {
+    switch self {
+    case .io: "io_error"
+    case .cache: "cache_error"
+    case .launch: "launch_failed"
+    case .permissionDenied: "permission_denied"
+    case .notFound: "not_found"
+    }
+  }
+
+  /// 失敗した対象や元のエラーの説明
+  public var detail: String {
+    switch self {
+    case .io(let detail), .cache(let detail), .launch(let detail),
+      .permissionDenied(let detail), .notFound(let detail):
+      detail
+    }
+  }
+
+  public var errorDescription: String? {
+    message(in: .ja)
+  }
+
+  public var recoverySuggestion: String? {
+    recoverySuggestion(in: .ja)
+  }
+
+  /// ランチャーに表示するメッセージ（`auto` はシステムの優先言語で解決する）
+  public func message(in language: AppLanguage) -> String {
+    switch (self, language.resolved()) {
+    case (.io, .ja): "ファイルの読み書きに失敗しました: \(detail)"
+    case (.io, _): "Failed to read or write a file: \(detail)"
+    case (.cache, .ja): "キャッシュの読み書きに失敗しました: \(detail)"
+    case (.cache, _): "Failed to read or write the cache: \(detail)"
+    case (.launch, .ja): "起動に失敗しました: \(detail)"
+    case (.launch, _): "Failed to launch: \(detail)"
+    case (.permissionDenied, .ja): "権限がありません: \(detail)"
+    case (.permissionDenied, _): "Permission denied: \(detail)"
+    case (.notFound, .ja): "見つかりません: \(detail)"
+    case (.notFound, _): "Not found: \(detail)"
+    }
+  }
+
+  /// ランチャーに表示する対処方法（`auto` はシステムの優先言語で解決する）
+  public func recoverySuggestion(in language: AppLanguage) -> String {
+    switch (self, language.resolved()) {
+    case (.io, .ja): "ディスクの空き容量とファイルのアクセス権を確認してください。"
+    case (.io, _): "Check the free disk space and the file permissions."
+    case (.cache, .ja): "メニューバーの「キャッシュを再構築」を実行してください。"
+    case (.cache, _): "Choose \"Rebuild Cache\" from the menu bar."
+    case (.launch, .ja): "設定の既定エディタ・ターミナルを確認してください。"
+    case (.launch, _): "Check the default editor and terminal in Settings."
+    case (.permissionDenied, .ja):
+      "システム設定の「プライバシーとセキュリティ」で Ignitero Launcher を許可してください。"
+    case (.permissionDenied, _):
+      "Allow Ignitero Launcher in System Settings > Privacy & Security."
+    case (.notFound, .ja): "インストール済みか、移動・削除されていないか確認してください。"
+    case (.notFound, _): "Check that it is installed and has not been moved or deleted."
+    }
+  }
+
+  /// 各サービスのエラーを分類する（分類できないエラーは `.io` として扱う）。
+  ///
+  /// - Parameter error: サービスが投げたエラー
+  public init(_ error: any Error) {
+    switch error {
+    case let error as LauncherError:
+      self = error
+    case let error as LaunchError:
+      self = Self.classify(error)
+    case let error as ProcessControlError:
+      self = Self.classify(error)
+    case PasteError.accessibilityNotTrusted:
+      self = .permissionDenied("アクセシビリティ")
+    case let error as PasteError:
+      self = .launch(error.localizedDescription)
+    case WindowLayoutError.accessibilityNotTrusted:
+      self = .permissionDenied("アクセシビリティ")
+    case WindowLayoutError.noFocusedWindow:
+      self = .notFound("操作するウィンドウ")
+    case let error as WindowLayoutError:
+      self = .launch(error.localizedDescription)
+    case FileSystemError.directoryNotFound(let path):
+      self = .notFound(path)
+    case let error as DatabaseError:
+      self = .cache(error.message ?? error.description)
+    case let error as CocoaError:
+      self = Self.classify(error)
+    case let error as POSIXError:
+      self = Self.classify(code: error.code, description: error.localizedDescription)
+    default:
+      self = .io(error.localizedDescription)
+    }
+  }
+
+  private static func classify(_ error: LaunchError) -> LauncherError {
+    switch error {
+    case .editorNotFound(let editor):
+      return .notFound(editor.displayName)
+    case .terminalNotFound(let terminal):
+      return .notFound(LaunchService.displayName(for: terminal))
+    case .noApplicationForURL(let url):
+      return .notFound("\(url.absoluteString) を開くアプリ")
+    case .scriptExecutionFailed(let message):
+      // -1743: Apple Events の送信が許可されていない（オートメーションの権限）
+      return message.contains("-1743") ? .permissionDenied(message) : .launch(message)
+    }
+  }
+
+  private static func classify(_ error: ProcessControlError) -> LauncherError {
+    switch error {
+    case .notFound(let pid):
+      .notFound("PID \(pid)")
+    case .permissionDenied(let pid), .protectedProcess(let pid):
+      .permissionDenied("PID \(pid)")
+    case .failed:
+      .launch(error.localizedDescription)
+    }
+  }
+
+  private static func classify(_ error: CocoaError) -> LauncherError {
+    let subject = error.filePath ?? error.localizedDescription
+    switch error.code {
+    case .fileNoSuchFile, .fileReadNoSuchFile:
+      return .notFound(subject)
+    case .fileReadNoPermission, .fileWriteNoPermission:
+      return .permissionDenied(subject)
+    default:
+      return .io(error.localizedDescription)
+    }
+  }
+
+  private static func classify(code: POSIXErrorCode, description: String) -> LauncherError {
+    switch code {
+    case .ENOENT: .notFound(description)
+    case .EACCES, .EPERM: .permissionDenied(description)
+    default: .io(description)
+    }
+  }
+}
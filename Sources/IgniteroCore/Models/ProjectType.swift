@@ -0,0 +1,49 @@
+/// ディレクトリの直下のファイルから判定したプロジェクトの種類
+public enum ProjectType: String, Codable, Sendable, CaseIterable {
+  case xcode
+  case swiftPackage = "swift_package"
+  case rust
+  case node
+  case go
+  case python
+  case jvm
+  case ruby
+
+  /// 表示名
+  public var displayName: String {
+    switch self {
+    case .xcode: "Xcode"
+    case .swiftPackage: "Swift Package"
+    case .rust: "Rust"
+    case .node: "Node"
+    case .go: "Go"
+    case .python: "Python"
+    case .jvm: "Java/Kotlin"
+    case .ruby: "Ruby"
+    }
+  }
+
+  /// 結果のフォルダのアイコンに重ねる SF Symbols 名
+  public var symbolName: String {
+    switch self {
+    case .xcode: "hammer.fill"
+    case .swiftPackage: "swift"
+    case .rust: "gearshape.2.fill"
+    case .node: "shippingbox.fill"
+    case .go: "hare.fill"
+    case .python: "chevron.left.forwardslash.chevron.right"
+    case .jvm: "cup.and.saucer.fill"
+    case .ruby: "diamond.fill"
+    }
+  }
+
+  /// エディタピッカーで最初に選ぶエディタ（インストールされている場合。nil なら通常どおり）
+  public var preferredEditor: EditorType? {
+    switch self {
+    case .xcode, .swiftPackage: .xcode
+    case .python: .pycharm
+    case .jvm: .intellij
+    case .rust, .node, .go, .ruby: nil
+    }
+  }
+}
@@ -0,0 +1,86 @@
+import Foundation
+
+/// ユーザー定義の URL ショートカット。
+///
+/// `url` の `{query}` は検索語に置き換えられる（例: `https://developer.mozilla.org/search?q={query}`）。
+/// ランチャーで `名前 検索語`（例: `mdn fetch`）と入力すると、置き換えた URL を開く候補を先頭に表示する。
+public struct QuickLink: Codable, Sendable, Equatable, Identifiable {
+  public let id: UUID
+  public var name: String
+  public var url: String
+  /// 表示アイコン（SF Symbols 名。未指定の場合は地球儀）
+  public var icon: String?
+
+  /// 検索語を埋め込む位置を示すプレースホルダー
+  public static let placeholder = "{query}"
+
+  public init(id: UUID = UUID(), name: String, url: String, icon: String? = nil) {
+    self.id = id
+    self.name = name
+    self.url = url
+    self.icon = icon
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case id
+    case name
+    case url
+    case icon
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    self.id = try container.decodeIfPresent(UUID.self, forKey: .id) ?? UUID()
+    self.name = try container.decode(String.self, forKey: .name)
+    self.url = try container.decode(String.self, forKey: .url)
+    self.icon = try container.decodeIfPresent(String.self, forKey: .icon)
+  }
+
+  /// 入力の先頭と照合するキーワード（正規化した名前）
+  public var keyword: String {
+    SearchQueryNormalizer.normalize(name)
+  }
+
+  /// URL に検索語のプレースホルダーを含むか
+  public var acceptsQuery: Bool {
+    url.contains(Self.placeholder)
+  }
+
+  /// 検索語を埋め込んだ URL を返す。
+  ///
+  /// - Parameter query: 埋め込む検索語（URL エンコードする）
+  /// - Returns: 開く URL。テンプレートが URL として不正な場合は nil
+  public func resolvedURL(query: String = "") -> URL? {
+    var allowed = CharacterSet.urlQueryAllowed
+    allowed.remove(charactersIn: "&+=?#/")
+    let encoded = query.addingPercentEncoding(withAllowedCharacters: allowed) ?? ""
+    let resolved = url.replacingOccurrences(of: Self.placeholder, with: encoded)
+    guard let result = URL(string: resolved), result.scheme != nil else { return nil }
+    return result
+  }
+
+  /// 名前と URL が登録可能な内容か（名前は空白を含まず、URL はスキームを持つ）
+  public var isValid: Bool {
+    !keyword.isEmpty && !keyword.contains(where: \.isWhitespace) && resolvedURL() != nil
+  }
+
+  /// `名前 検索語` 形式の入力に一致するクイックリンクを探す。
+  ///
+  /// - Parameters:
+  ///   - query: ランチャーの入力
+  ///   - links: 登録済みのクイックリンク
+  /// - Returns: 一致したクイックリンクと検索語。検索語が空、またはプレースホルダーのない
+  ///   クイックリンクの場合は nil
+  public static func match(_ query: String, in links: [QuickLink]) -> (
+    link: QuickLink, argument: String
+  )? {
+    let trimmed = query.trimmingCharacters(in: .whitespaces)
+    guard let space = trimmed.firstIndex(where: \.isWhitespace) else { return nil }
+    let keyword = SearchQueryNormalizer.normalize(String(trimmed[..<space]))
+    let argument = trimmed[space...].trimmingCharacters(in: .whitespaces)
+    guard !argument.isEmpty,
+      let link = links.first(where: { $0.acceptsQuery && $0.keyword == keyword })
+    else { return nil }
+    return (link, argument)
+  }
+}
@@ -0,0 +1,29 @@
+import Foundation
+import GRDB
+
+/// ディレクトリをエディタで開いた記録（最近のプロジェクト）
+public struct RecentProject: Codable, Sendable, Equatable {
+  public let path: String
+  /// 最後に開いたエディタ（EditorType の rawValue）
+  public let editor: String?
+  public let openCount: Int
+  public let lastOpened: Date
+
+  enum CodingKeys: String, CodingKey {
+    case path
+    case editor
+    case openCount = "open_count"
+    case lastOpened = "last_opened"
+  }
+
+  public init(path: String, editor: String? = nil, openCount: Int = 1, lastOpened: Date = Date()) {
+    self.path = path
+    self.editor = editor
+    self.openCount = openCount
+    self.lastOpened = lastOpened
+  }
+}
+
+extension RecentProject: FetchableRecord, PersistableRecord {
+  public static var databaseTableName: String { "directory_opens" }
+}
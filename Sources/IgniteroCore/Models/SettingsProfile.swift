@@ -0,0 +1,118 @@
+import Foundation
+
+/// 名前付きの設定プロファイル（例: 仕事用・個人用・顧客ごとの環境）。
+///
+/// 登録ディレクトリ・カスタムコマンド・追加ホットキー・ランチャー表示のショートカットを
+/// プロファイルごとに持つ。アクティブなプロファイルの内容は `Settings` の同名の項目
+/// （ショートカットは KeyboardShortcuts の設定）が正で、別のプロファイルへ切り替えるときに
+/// ここへ書き戻す。
+public struct SettingsProfile: Codable, Sendable, Equatable, Identifiable {
+  public var id: String { name }
+  public var name: String
+  public var registeredDirectories: [RegisteredDirectory]
+  public var customCommands: [CustomCommand]
+  public var hotkeyBindings: [HotkeyBinding]
+  /// ランチャー表示のショートカット（nil は切り替え時に今のショートカットをそのまま使う）
+  public var launcherShortcut: LauncherShortcut?
+
+  /// 初めてプロファイルを作るときに、それまでの設定を保存するプロファイルの名前
+  public static let defaultName = "default"
+
+  public init(
+    name: String,
+    registeredDirectories: [RegisteredDirectory] = [],
+    customCommands: [CustomCommand] = [],
+    hotkeyBindings: [HotkeyBinding] = [],
+    launcherShortcut: LauncherShortcut? = nil
+  ) {
+    self.name = name
+    self.registeredDirectories = registeredDirectories
+    self.customCommands = customCommands
+    self.hotkeyBindings = hotkeyBindings
+    self.launcherShortcut = launcherShortcut
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case name
+    case registeredDirectories = "registered_directories"
+    case customCommands = "custom_commands"
+    case hotkeyBindings = "hotkey_bindings"
+    case launcherShortcut = "launcher_shortcut"
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    name = try container.decode(String.self, forKey: .name)
+    registeredDirectories =
+      try container.decodeIfPresent([RegisteredDirectory].self, forKey: .registeredDirectories)
+      ?? []
+    customCommands =
+      try container.decodeIfPresent([CustomCommand].self, forKey: .customCommands) ?? []
+    hotkeyBindings =
+      try container.decodeIfPresent([HotkeyBinding].self, forKey: .hotkeyBindings) ?? []
+    launcherShortcut = try container.decodeIfPresent(
+      LauncherShortcut.self, forKey: .launcherShortcut)
+  }
+
+  /// プロファイルのキャッシュ DB のファイル名に使う名前（英数字・`-`・`_` 以外は `_` に置き換える）
+  ///
+  /// `defaultName` のプロファイルはプロファイル導入前と同じキャッシュを使うため nil を返す。
+  public static func cachePartitionName(for name: String?) -> String? {
+    guard let name, name != defaultName else { return nil }
+    return String(
+      name.unicodeScalars.map {
+        CharacterSet.alphanumerics.contains($0) || $0 == "-" || $0 == "_" ? Character($0) : "_"
+      })
+  }
+
+  /// 2 つのプロファイル名が同じキャッシュ DB のファイルを指すか。
+  ///
+  /// 置き換え後の名前を大文字小文字を区別せずに比べる（macOS の既定のファイルシステムに合わせる）。
+  public static func sharesCachePartition(_ name: String, with other: String) -> Bool {
+    cachePartitionName(for: name)?.lowercased() == cachePartitionName(for: other)?.lowercased()
+  }
+}
+
+/// ランチャー表示のショートカット（Carbon のキーコードと修飾キー）
+public struct LauncherShortcut: Codable, Sendable, Equatable {
+  public var keyCode: Int
+  public var modifiers: Int
+
+  public init(keyCode: Int, modifiers: Int) {
+    self.keyCode = keyCode
+    self.modifiers = modifiers
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case keyCode = "key_code"
+    case modifiers
+  }
+}
+
+/// プロファイル操作のエラー
+public enum SettingsProfileError: Error, LocalizedError, Equatable {
+  /// 名前が空
+  case emptyName
+  /// 同じ名前のプロファイルがある
+  case duplicateName(String)
+  /// キャッシュ DB のファイル名が既存のプロファイルと同じになる（名前, 既存のプロファイル名）
+  case conflictingName(String, existing: String)
+  /// 指定した名前のプロファイルがない
+  case notFound(String)
+  /// 使用中のプロファイルは削除できない
+  case profileInUse(String)
+  /// キャッシュの更新中は切り替えられない
+  case cacheBusy
+
+  public var errorDescription: String? {
+    switch self {
+    case .emptyName: "Profile name is empty"
+    case .duplicateName(let name): "Profile already exists: \(name)"
+    case .conflictingName(let name, let existing):
+      "Profile name \(name) would share a cache with \(existing)"
+    case .notFound(let name): "No such profile: \(name)"
+    case .profileInUse(let name): "Cannot delete the active profile: \(name)"
+    case .cacheBusy: "Cannot switch profiles while the cache is being rebuilt"
+    }
+  }
+}
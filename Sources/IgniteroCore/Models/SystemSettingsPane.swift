@@ -0,0 +1,129 @@
+import Foundation
+
+/// システム設定のパネル（Wi-Fi、Bluetooth、ディスプレイなど）。
+///
+/// `x-apple.systempreferences:<identifier>` URL でシステム設定の該当パネルを直接開く。
+public struct SystemSettingsPane: Sendable, Equatable, Identifiable {
+  /// パネルの識別子（設定拡張・環境設定パネルのバンドル ID）
+  public let id: String
+  /// 表示名
+  public let name: String
+  /// 名前以外に照合する検索キーワード（英語名など）
+  public let keywords: [String]
+  /// 表示アイコン（SF Symbols 名）
+  public let symbolName: String
+
+  /// システム設定を開く URL スキーム
+  public static let urlScheme = "x-apple.systempreferences"
+
+  public init(id: String, name: String, keywords: [String] = [], symbolName: String = "gearshape") {
+    self.id = id
+    self.name = name
+    self.keywords = keywords
+    self.symbolName = symbolName
+  }
+
+  /// パネルを開く URL
+  public var url: URL? {
+    URL(string: "\(Self.urlScheme):\(id)")
+  }
+
+  /// 主なパネルの一覧（macOS 13 以降の設定拡張の識別子）
+  public static let curated: [SystemSettingsPane] = [
+    SystemSettingsPane(
+      id: "com.apple.wifi-settings-extension", name: "Wi-Fi",
+      keywords: ["wifi", "wireless"], symbolName: "wifi"),
+    SystemSettingsPane(
+      id: "com.apple.BluetoothSettings", name: "Bluetooth", symbolName: "dot.radiowaves.right"),
+    SystemSettingsPane(
+      id: "com.apple.Network-Settings.extension", name: "ネットワーク",
+      keywords: ["network", "vpn"], symbolName: "network"),
+    SystemSettingsPane(
+      id: "com.apple.Notifications-Settings.extension", name: "通知",
+      keywords: ["notifications"], symbolName: "bell.badge"),
+    SystemSettingsPane(
+      id: "com.apple.Sound-Settings.extension", name: "サウンド",
+      keywords: ["sound", "audio", "volume"], symbolName: "speaker.wave.2"),
+    SystemSettingsPane(
+      id: "com.apple.Focus-Settings.extension", name: "集中モード",
+      keywords: ["focus", "do not disturb"], symbolName: "moon"),
+    SystemSettingsPane(
+      id: "com.apple.Screen-Time-Settings.extension", name: "スクリーンタイム",
+      keywords: ["screen time"], symbolName: "hourglass"),
+    SystemSettingsPane(
+      id: "com.apple.systempreferences.GeneralSettings", name: "一般",
+      keywords: ["general"], symbolName: "gearshape"),
+    SystemSettingsPane(
+      id: "com.apple.Appearance-Settings.extension", name: "外観",
+      keywords: ["appearance", "dark mode"], symbolName: "circle.lefthalf.filled"),
+    SystemSettingsPane(
+      id: "com.apple.Accessibility-Settings.extension", name: "アクセシビリティ",
+      keywords: ["accessibility"], symbolName: "accessibility"),
+    SystemSettingsPane(
+      id: "com.apple.ControlCenter-Settings.extension", name: "コントロールセンター",
+      keywords: ["control center", "menu bar"], symbolName: "switch.2"),
+    SystemSettingsPane(
+      id: "com.apple.Desktop-Settings.extension", name: "デスクトップと Dock",
+      keywords: ["desktop", "dock", "mission control"], symbolName: "dock.rectangle"),
+    SystemSettingsPane(
+      id: "com.apple.Displays-Settings.extension", name: "ディスプレイ",
+      keywords: ["displays", "monitor", "resolution"], symbolName: "display"),
+    SystemSettingsPane(
+      id: "com.apple.Wallpaper-Settings.extension", name: "壁紙",
+      keywords: ["wallpaper"], symbolName: "photo"),
+    SystemSettingsPane(
+      id: "com.apple.ScreenSaver-Settings.extension", name: "スクリーンセーバ",
+      keywords: ["screen saver"], symbolName: "sparkles.tv"),
+    SystemSettingsPane(
+      id: "com.apple.Battery-Settings.extension", name: "バッテリー",
+      keywords: ["battery", "energy"], symbolName: "battery.75percent"),
+    SystemSettingsPane(
+      id: "com.apple.Lock-Screen-Settings.extension", name: "ロック画面",
+      keywords: ["lock screen"], symbolName: "lock.display"),
+    SystemSettingsPane(
+      id: "com.apple.settings.PrivacySecurity.extension", name: "プライバシーとセキュリティ",
+      keywords: ["privacy", "security"], symbolName: "hand.raised"),
+    SystemSettingsPane(
+      id: "com.apple.Touch-ID-Settings.extension", name: "Touch ID とパスワード",
+      keywords: ["touch id", "password"], symbolName: "touchid"),
+    SystemSettingsPane(
+      id: "com.apple.Users-Groups-Settings.extension", name: "ユーザとグループ",
+      keywords: ["users", "groups"], symbolName: "person.2"),
+    SystemSettingsPane(
+      id: "com.apple.Internet-Accounts-Settings.extension", name: "インターネットアカウント",
+      keywords: ["internet accounts", "mail"], symbolName: "at"),
+    SystemSettingsPane(
+      id: "com.apple.Keyboard-Settings.extension", name: "キーボード",
+      keywords: ["keyboard", "input sources"], symbolName: "keyboard"),
+    SystemSettingsPane(
+      id: "com.apple.Trackpad-Settings.extension", name: "トラックパッド",
+      keywords: ["trackpad"], symbolName: "rectangle.and.hand.point.up.left"),
+    SystemSettingsPane(
+      id: "com.apple.Mouse-Settings.extension", name: "マウス",
+      keywords: ["mouse"], symbolName: "computermouse"),
+    SystemSettingsPane(
+      id: "com.apple.Print-Scanner-Settings.extension", name: "プリンタとスキャナ",
+      keywords: ["printers", "scanners"], symbolName: "printer"),
+    SystemSettingsPane(
+      id: "com.apple.Software-Update-Settings.extension", name: "ソフトウェアアップデート",
+      keywords: ["software update"], symbolName: "arrow.triangle.2.circlepath"),
+    SystemSettingsPane(
+      id: "com.apple.settings.Storage", name: "ストレージ",
+      keywords: ["storage", "disk"], symbolName: "internaldrive"),
+    SystemSettingsPane(
+      id: "com.apple.Date-Time-Settings.extension", name: "日付と時刻",
+      keywords: ["date", "time", "clock"], symbolName: "clock"),
+    SystemSettingsPane(
+      id: "com.apple.Localization-Settings.extension", name: "言語と地域",
+      keywords: ["language", "region"], symbolName: "globe"),
+    SystemSettingsPane(
+      id: "com.apple.LoginItems-Settings.extension", name: "ログイン項目",
+      keywords: ["login items"], symbolName: "power"),
+    SystemSettingsPane(
+      id: "com.apple.Sharing-Settings.extension", name: "共有",
+      keywords: ["sharing", "airdrop"], symbolName: "person.2.wave.2"),
+    SystemSettingsPane(
+      id: "com.apple.Siri-Settings.extension", name: "Siri",
+      keywords: ["siri", "apple intelligence"], symbolName: "waveform"),
+  ]
+}
@@ -0,0 +1,65 @@
+import Foundation
+
+/// ターミナルで開く接続先（`~/.ssh/config` の SSH ホスト、`~/.tmux.conf` の tmux セッション）。
+///
+/// 確定すると既定のターミナルで `ssh <ホスト>` / `tmux new-session -A -s <セッション>` を実行する。
+public struct TerminalTarget: Sendable, Equatable, Hashable {
+  /// 接続先の種別
+  public enum Kind: String, Sendable, Codable, CaseIterable {
+    /// `~/.ssh/config` の `Host`
+    case sshHost = "ssh"
+    /// `~/.tmux.conf` で作成する tmux セッション
+    case tmuxSession = "tmux"
+  }
+
+  public let kind: Kind
+  /// SSH ホストの別名・tmux セッション名
+  public let name: String
+  /// 補足情報（SSH ホストの `HostName`、`User` があれば `user@host`）
+  public let detail: String?
+
+  public init(kind: Kind, name: String, detail: String? = nil) {
+    self.kind = kind
+    self.name = name
+    self.detail = detail
+  }
+
+  /// 選択履歴・キャッシュで使う識別子（`ssh://prod-web`、`tmux://main`）
+  public var identifier: String {
+    "\(kind.rawValue)://\(name)"
+  }
+
+  /// 照合に使う入力どおりの表記（`ssh prod-web`、`tmux main`）
+  public var invocation: String {
+    "\(kind.rawValue) \(name)"
+  }
+
+  /// ターミナルで実行するコマンド
+  public var command: String {
+    let quoted = Self.shellQuoted(name)
+    return switch kind {
+    case .sshHost: "ssh \(quoted)"
+    case .tmuxSession: "tmux new-session -A -s \(quoted)"
+    }
+  }
+
+  /// 表示アイコン（SF Symbols 名）
+  public var symbolName: String {
+    switch kind {
+    case .sshHost: "network"
+    case .tmuxSession: "rectangle.split.3x1"
+    }
+  }
+
+  /// 識別子が接続先のものか（選択履歴の復元で他の種別と区別するため）
+  public static func isIdentifier(_ identifier: String) -> Bool {
+    Kind.allCases.contains { identifier.hasPrefix("\($0.rawValue)://") }
+  }
+
+  /// 英数字と `-._@:` 以外を含む名前はシングルクォートで囲む。
+  static func shellQuoted(_ text: String) -> String {
+    let safe = CharacterSet.alphanumerics.union(CharacterSet(charactersIn: "-._@:"))
+    guard text.unicodeScalars.contains(where: { !safe.contains($0) }) else { return text }
+    return "'" + text.replacingOccurrences(of: "'", with: "'\\''") + "'"
+  }
+}
@@ -0,0 +1,135 @@
+import AppKit
+import ApplicationServices
+import Foundation
+
+// MARK: - AccessibilityPermissionProviding プロトコル
+
+public protocol AccessibilityPermissionProviding: Sendable {
+  /// アクセシビリティ権限が許可されているか
+  func isTrusted() -> Bool
+  /// 許可を求めるシステムのダイアログを表示し、現在の状態を返す（許可済みならダイアログは出ない）。
+  @MainActor func requestAccess() -> Bool
+  /// システム設定の「アクセシビリティ」を開く。
+  @MainActor func openSystemSettings()
+}
+
+// MARK: - AccessibilityPermissionService 本体
+
+/// アクセシビリティ権限（貼り付け・ウィンドウ操作に必要）を確認・要求する。
+public struct AccessibilityPermissionService: AccessibilityPermissionProviding, Sendable {
+  /// システム設定の「プライバシーとセキュリティ」→「アクセシビリティ」
+  public static let settingsURL = URL(
+    string: "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility")!
+
+  public init() {}
+
+  public func isTrusted() -> Bool {
+    AXIsProcessTrusted()
+  }
+
+  @MainActor
+  public func requestAccess() -> Bool {
+    // kAXTrustedCheckOptionPrompt の値（グローバル変数は Swift 6 で並行安全でないため文字列で渡す）
+    let options = ["AXTrustedCheckOptionPrompt": true] as CFDictionary
+    return AXIsProcessTrustedWithOptions(options)
+  }
+
+  @MainActor
+  public func openSystemSettings() {
+    NSWorkspace.shared.open(Self.settingsURL)
+  }
+}
+
+// MARK: - AccessibilityPermissionMonitor
+
+/// アクセシビリティ権限の変化を監視し、変わったときに通知する。
+///
+/// 権限の変更はシステムの分散通知（`com.apple.accessibility.api`）で知らされるが、通知の時点では
+/// まだ状態が変わっていないことがあるため、少し待ってから確認する。許可されていない間は
+/// 設定画面で許可した直後に反映できるよう `pollInterval` ごとにも確認する。
+@MainActor
+public final class AccessibilityPermissionMonitor {
+  private static let logger = AppLogger(category: "AccessibilityPermission")
+
+  /// 権限が変わったときに投稿する通知（object はモニター、userInfo の `trusted` に新しい状態）
+  public static let didChangeNotification = Notification.Name("accessibility-permission-changed")
+  /// システムが権限の変更時に投稿する分散通知
+  static let systemNotification = Notification.Name("com.apple.accessibility.api")
+
+  private let provider: any AccessibilityPermissionProviding
+  /// 許可されていない間に状態を確認する間隔
+  public let pollInterval: Duration
+
+  /// 最後に確認した状態
+  public private(set) var isTrusted: Bool
+  /// 権限が変わったときに呼ばれる（新しい状態）
+  public var onChange: (@MainActor (Bool) -> Void)?
+
+  private var observer: (any NSObjectProtocol)?
+  private var pollTask: Task<Void, Never>?
+
+  public init(
+    provider: any AccessibilityPermissionProviding, pollInterval: Duration = .seconds(2)
+  ) {
+    self.provider = provider
+    self.pollInterval = pollInterval
+    self.isTrusted = provider.isTrusted()
+  }
+
+  /// 監視を始める（開始済みなら何もしない）。
+  public func start() {
+    guard observer == nil else { return }
+    observer = DistributedNotificationCenter.default().addObserver(
+      forName: Self.systemNotification, object: nil, queue: .main
+    ) { [weak self] _ in
+      Task { @MainActor in
+        try? await Task.sleep(for: .milliseconds(500))
+        self?.check()
+      }
+    }
+    updatePolling()
+  }
+
+  /// 監視をやめる。
+  public func stop() {
+    if let observer {
+      DistributedNotificationCenter.default().removeObserver(observer)
+    }
+    observer = nil
+    pollTask?.cancel()
+    pollTask = nil
+  }
+
+  /// 現在の状態を確認し、変わっていれば通知する。
+  ///
+  /// - Returns: 現在の状態
+  @discardableResult
+  public func check() -> Bool {
+    let trusted = provider.isTrusted()
+    guard trusted != isTrusted else { return trusted }
+    isTrusted = trusted
+    Self.logger.info("Accessibility permission changed: \(trusted ? "granted" : "revoked")")
+    onChange?(trusted)
+    NotificationCenter.default.post(
+      name: Self.didChangeNotification, object: self, userInfo: ["trusted": trusted])
+    if observer != nil {
+      updatePolling()
+    }
+    return trusted
+  }
+
+  /// 許可されていない間だけ定期的に確認する。
+  private func updatePolling() {
+    pollTask?.cancel()
+    pollTask = nil
+    guard !isTrusted else { return }
+    let interval = pollInterval
+    pollTask = Task { @MainActor [weak self] in
+      while !Task.isCancelled {
+        try? await Task.sleep(for: interval)
+        guard let self, !Task.isCancelled else { return }
+        self.check()
+      }
+    }
+  }
+}
@@ -0,0 +1,28 @@
+import AppKit
+
+public protocol ActivationPolicyControlling: Sendable {
+  /// Dock にアイコンを表示する（`.regular`）か、メニューバーのみ（`.accessory`）にする。
+  @MainActor func setDockIconVisible(_ visible: Bool)
+}
+
+/// `NSApplication.setActivationPolicy` で Dock のアイコンの表示を切り替える。
+///
+/// Info.plist の `LSUIElement` でメニューバーのみとして起動し、設定で Dock に表示する場合は
+/// `.regular` に切り替える（⌘Tab でも選べるようになる）。再起動せずに戻せる。
+public struct ActivationPolicyService: ActivationPolicyControlling {
+  private static let logger = AppLogger(category: "ActivationPolicy")
+
+  public init() {}
+
+  @MainActor
+  public func setDockIconVisible(_ visible: Bool) {
+    let application = NSApplication.shared
+    let policy: NSApplication.ActivationPolicy = visible ? .regular : .accessory
+    guard application.activationPolicy() != policy else { return }
+    if application.setActivationPolicy(policy) {
+      Self.logger.info("Activation policy changed: \(visible ? "regular" : "accessory")")
+    } else {
+      Self.logger.error("Failed to change activation policy")
+    }
+  }
+}
@@ -1,5 +1,5 @@
 import Foundation
-import os
+import Synchronization
 
 // MARK: - AppScannerProtocol
 
@@ -10,11 +10,51 @@ public protocol AppScannerProtocol: Sendable {
   /// メインスレッドをブロックしない。
   func scanApplications(excludedApps: [String]) async throws -> [AppItem]
 
+  /// 前回のスキャン結果を使ってアプリケーションをスキャンする。
+  ///
+  /// 更新日時（`bundleModificationTime`）が前回と同じバンドルは Info.plist を読み直さず、
+  /// 前回の AppItem をそのまま使う。
+  /// - Parameter previous: 前回のスキャン結果（キャッシュから読み込んだアプリ）
+  func scanApplications(excludedApps: [String], previous: [AppItem]) async throws -> [AppItem]
+
+  /// 打ち切り可能な形でアプリケーションをスキャンする。
+  ///
+  /// 打ち切られた場合は残りのバンドルを読まず、そこまでに見つけたアプリを返す。
+  func scanApplications(
+    excludedApps: [String], previous: [AppItem], cancellation: ScanCancellation
+  ) async throws -> [AppItem]
+
+  /// Launch Services に登録されたアプリのうち、`knownPaths` に含まれないものを返す。
+  ///
+  /// 返すアプリの `source` は `.launchServices`。除外リストの判定は呼び出し側で行う。
+  func scanRegisteredApplications(excludingPaths knownPaths: Set<String>) async -> [AppItem]
+
   /// スキャン済みアプリが除外リストに該当するかを判定する。
   func isExcluded(_ app: AppItem, excludedApps: [String]) -> Bool
 }
 
 extension AppScannerProtocol {
+  /// 既定実装: 前回の結果を使わずにすべてスキャンする。
+  public func scanApplications(excludedApps: [String], previous: [AppItem]) async throws
+    -> [AppItem]
+  {
+    try await scanApplications(excludedApps: excludedApps)
+  }
+
+  /// 既定実装: 打ち切りを確認せずにスキャンする。
+  public func scanApplications(
+    excludedApps: [String], previous: [AppItem], cancellation: ScanCancellation
+  ) async throws -> [AppItem] {
+    try await scanApplications(excludedApps: excludedApps, previous: previous)
+  }
+
+  /// 既定実装: 登録済みアプリの探索元を持たないスキャナーは何も返さない。
+  public func scanRegisteredApplications(excludingPaths knownPaths: Set<String>) async
+    -> [AppItem]
+  {
+    []
+  }
+
   /// 既定実装: パス・バンドルファイル名・バンドル名・表示名・元名で照合する。
   public func isExcluded(_ app: AppItem, excludedApps: [String]) -> Bool {
     guard !excludedApps.isEmpty else { return false }
@@ -40,7 +80,7 @@ extension AppScannerProtocol {
 // MARK: - AppScanner
 
 public struct AppScanner: AppScannerProtocol, Sendable {
-  private static let logger = Logger(subsystem: "com.ignitero.launcher", category: "AppScanner")
+  private static let logger = AppLogger(category: "AppScanner")
 
   // MARK: - ScanTarget
 
@@ -58,6 +98,7 @@ public struct AppScanner: AppScannerProtocol, Sendable {
 
   public let scanTargets: [ScanTarget]
   private let iconCacheManager: IconCacheManager
+  private let registeredAppDiscovery: any RegisteredAppDiscovering
 
   // MARK: - Default Targets
 
@@ -70,63 +111,99 @@ public struct AppScanner: AppScannerProtocol, Sendable {
     ),
   ]
 
+  /// システムの言語に関わらず検索キーに加えるローカライズ名の言語
+  /// （日本語・英語のどちらの名前で入力しても一致させる）
+  public static let searchNameLocales = ["ja", "en"]
+
+  /// 旧形式の .lproj フォルダ名（`Japanese.lproj` など）
+  private static let legacyLprojNames = ["ja": "Japanese", "en": "English"]
+
   // MARK: - Initialization
 
   public init(
     scanTargets: [ScanTarget]? = nil,
-    iconCacheManager: IconCacheManager = IconCacheManager()
+    iconCacheManager: IconCacheManager = IconCacheManager(),
+    registeredAppDiscovery: (any RegisteredAppDiscovering)? = nil
   ) {
     self.scanTargets = scanTargets ?? Self.defaultScanTargets
     self.iconCacheManager = iconCacheManager
+    self.registeredAppDiscovery = registeredAppDiscovery ?? LaunchServicesAppDiscovery()
   }
 
   // MARK: - Core Scan
 
   public func scanApplications(excludedApps: [String]) async throws -> [AppItem] {
+    try await scanApplications(excludedApps: excludedApps, previous: [])
+  }
+
+  /// バンドルの探索だけを直列に行い、Info.plist の解析とアイコンの変換元の解決は
+  /// CPU コア数に合わせて並列に行う。
+  public func scanApplications(excludedApps: [String], previous: [AppItem]) async throws
+    -> [AppItem]
+  {
+    try await scanApplications(
+      excludedApps: excludedApps, previous: previous, cancellation: ScanCancellation())
+  }
+
+  /// 打ち切りはスキャン対象ごと・バンドルごとに確認する。
+  public func scanApplications(
+    excludedApps: [String], previous: [AppItem], cancellation: ScanCancellation
+  ) async throws -> [AppItem] {
     let excludedSet = Set(excludedApps)
     var seenPaths = Set<String>()
-    var results: [AppItem] = []
-
-    for target in scanTargets {
-      let bundles = findAppBundles(in: target.path, maxDepth: target.maxDepth)
-      for bundlePath in bundles {
-        // 重複排除
-        guard !seenPaths.contains(bundlePath) else { continue }
-        seenPaths.insert(bundlePath)
+    var bundlePaths: [String] = []
+    for target in scanTargets where !cancellation.isCancelled {
+      // 重複排除
+      for bundlePath in findAppBundles(in: target.path, maxDepth: target.maxDepth)
+      where seenPaths.insert(bundlePath).inserted {
+        bundlePaths.append(bundlePath)
+      }
+    }
 
+    let previousByPath = Dictionary(
+      previous.map { ($0.path, $0) }, uniquingKeysWith: { _, last in last })
+    let reusedCount = Atomic<Int>(0)
+    var results = Self.concurrentCompactMap(bundlePaths) { bundlePath -> AppItem? in
+      guard !cancellation.isCancelled else { return nil }
+
+      let appItem: AppItem
+      if let cached = previousByPath[bundlePath],
+        let modificationTime = cached.bundleModificationTime,
+        Self.bundleModificationTime(of: bundlePath) == modificationTime
+      {
+        // 変更のないバンドルは Info.plist を読み直さない（変換済みのアイコンの有無だけ確認する）。
+        // Info.plist の名前は前回の項目の名前・別名に含まれるため、除外判定は生成後の判定で足りる
+        appItem = resolveIconSource(for: cached, sourcePath: cached.iconSourcePath)
+        reusedCount.add(1, ordering: .relaxed)
+      } else {
+        // Info.plist はバンドルごとに 1 度だけ解析し、除外判定・名前・アイコンで使い回す
+        let plist = infoPlist(for: bundlePath) ?? [:]
         // 除外アプリフィルタ（既存設定のパス指定もここで扱う）
-        guard !isExcluded(bundlePath: bundlePath, appItem: nil, excludedSet: excludedSet) else {
+        guard
+          !isExcluded(
+            bundlePath: bundlePath, appItem: nil, excludedSet: excludedSet, infoPlist: plist)
+        else {
           Self.logger.debug("Excluded app: \(bundlePath)")
-          continue
+          return nil
         }
-
-        if var appItem = extractAppInfo(from: bundlePath) {
-          // 設定画面は表示名を保存するため、AppItem 生成後にも除外判定する。
-          guard !isExcluded(bundlePath: bundlePath, appItem: appItem, excludedSet: excludedSet)
-          else {
-            Self.logger.debug("Excluded app: \(bundlePath)")
-            continue
-          }
-
-          // アイコンキャッシュ生成
-          if let iconSrc = iconFilePath(for: bundlePath) {
-            do {
-              let cachedPath = try iconCacheManager.cacheIcon(
-                from: iconSrc, for: bundlePath)
-              appItem = AppItem(
-                name: appItem.name,
-                path: appItem.path,
-                iconPath: cachedPath,
-                originalName: appItem.originalName
-              )
-            } catch {
-              Self.logger.warning(
-                "Failed to cache icon for \(bundlePath): \(error.localizedDescription)")
-            }
-          }
-          results.append(appItem)
+        guard let extracted = extractAppInfo(from: bundlePath, infoPlist: plist) else {
+          return nil
         }
+        // アイコンは変換元だけを記録し、変換は表示時に IconConversionQueue が行う
+        appItem = resolveIconSource(for: extracted, infoPlist: plist)
       }
+
+      // 設定画面は表示名を保存するため、AppItem 生成後にも除外判定する。
+      guard !isExcluded(bundlePath: bundlePath, appItem: appItem, excludedSet: excludedSet)
+      else {
+        Self.logger.debug("Excluded app: \(bundlePath)")
+        return nil
+      }
+      return appItem
+    }
+    if !previous.isEmpty {
+      let reused = reusedCount.load(ordering: .relaxed)
+      Self.logger.info("Reused \(reused) of \(bundlePaths.count) unchanged app bundles")
     }
 
     // 名前でソート
@@ -134,6 +211,110 @@ public struct AppScanner: AppScannerProtocol, Sendable {
     return results
   }
 
+  /// 要素を CPU コア数に合わせた塊に分けて並列に変換し、nil を除いて元の順に返す。
+  private static func concurrentCompactMap<Element, Result>(
+    _ elements: [Element], _ transform: (Element) -> Result?
+  ) -> [Result] {
+    guard elements.count > 1 else { return elements.compactMap(transform) }
+    let chunkCount = min(ProcessInfo.processInfo.activeProcessorCount * 4, elements.count)
+    let chunkSize = (elements.count + chunkCount - 1) / chunkCount
+    let chunks = Mutex([[Result]](repeating: [], count: chunkCount))
+    DispatchQueue.concurrentPerform(iterations: chunkCount) { chunk in
+      let lower = min(chunk * chunkSize, elements.count)
+      let upper = min(lower + chunkSize, elements.count)
+      let results = elements[lower..<upper].compactMap(transform)
+      chunks.withLock { $0[chunk] = results }
+    }
+    return chunks.withLock { $0.flatMap { $0 } }
+  }
+
+  /// バンドルと Info.plist のうち新しい方の更新日時（1970 年からの秒数）を返す。
+  ///
+  /// アップデートでバンドルごと置き換えられた場合も、Info.plist だけ書き換えられた場合も変わる。
+  /// - Returns: バンドルの属性を読めない場合は nil
+  static func bundleModificationTime(of appPath: String) -> TimeInterval? {
+    let fm = FileManager.default
+    let plistPath = (appPath as NSString).appendingPathComponent("Contents/Info.plist")
+    let dates = [appPath, plistPath].compactMap {
+      (try? fm.attributesOfItem(atPath: $0))?[.modificationDate] as? Date
+    }
+    return dates.max()?.timeIntervalSince1970
+  }
+
+  public func scanRegisteredApplications(excludingPaths knownPaths: Set<String>) async
+    -> [AppItem]
+  {
+    // /Applications/Safari.app のようなシンボリックリンク経由の重複も除く
+    var seenPaths = knownPaths.union(knownPaths.map(Self.resolvedPath))
+    var results: [AppItem] = []
+
+    for bundlePath in await registeredAppDiscovery.registeredAppPaths() {
+      guard !seenPaths.contains(bundlePath),
+        !seenPaths.contains(Self.resolvedPath(bundlePath))
+      else { continue }
+      seenPaths.insert(bundlePath)
+      seenPaths.insert(Self.resolvedPath(bundlePath))
+
+      let plist = infoPlist(for: bundlePath) ?? [:]
+      if let app = extractAppInfo(from: bundlePath, infoPlist: plist) {
+        let registered = AppItem(
+          name: app.name, path: app.path, originalName: app.originalName,
+          alternateNames: app.alternateNames, keywords: app.keywords, source: .launchServices,
+          version: app.version, bundleIdentifier: app.bundleIdentifier, category: app.category,
+          bundleModificationTime: app.bundleModificationTime)
+        results.append(resolveIconSource(for: registered, infoPlist: plist))
+      }
+    }
+
+    results.sort { $0.name.localizedCaseInsensitiveCompare($1.name) == .orderedAscending }
+    Self.logger.info("Discovered \(results.count) registered apps outside scan targets")
+    return results
+  }
+
+  private static func resolvedPath(_ path: String) -> String {
+    URL(fileURLWithPath: path).resolvingSymlinksInPath().path
+  }
+
+  /// アイコンの変換元を記録し、変換済みのアイコンがあればそのパスを設定した AppItem を返す。
+  ///
+  /// 数百アプリ分の描画で初回のキャッシュ更新が数分かかっていたため、ここでは変換しない。
+  /// 変換元は `.icns`、`.icns` を持たずアセットカタログにのみアイコンがあるアプリはアプリ自身の
+  /// パスとし、どちらもないアプリは変換元なし（汎用アイコンで表示）とする。
+  func resolveIconSources(for apps: [AppItem]) -> [AppItem] {
+    apps.map { resolveIconSource(for: $0) }
+  }
+
+  /// 1 つのアプリのアイコンの変換元を解決する（`resolveIconSources(for:)` を参照）。
+  ///
+  /// - Parameter plist: 解析済みの Info.plist（nil の場合は読み込む）
+  private func resolveIconSource(for app: AppItem, infoPlist plist: [String: Any]? = nil)
+    -> AppItem
+  {
+    let plist = plist ?? infoPlist(for: app.path) ?? [:]
+    let sourcePath =
+      iconFilePath(for: app.path, infoPlist: plist)
+      ?? (hasAssetCatalog(for: app.path) ? app.path : nil)
+    return resolveIconSource(for: app, sourcePath: sourcePath)
+  }
+
+  /// 解決済みの変換元を記録し、変換済みのアイコンがあればそのパスを設定する。
+  private func resolveIconSource(for app: AppItem, sourcePath: String?) -> AppItem {
+    AppItem(
+      name: app.name,
+      path: app.path,
+      iconPath: sourcePath == nil ? nil : iconCacheManager.existingIconPath(for: app.path),
+      iconSourcePath: sourcePath,
+      originalName: app.originalName,
+      alternateNames: app.alternateNames,
+      keywords: app.keywords,
+      source: app.source,
+      version: app.version,
+      bundleIdentifier: app.bundleIdentifier,
+      category: app.category,
+      bundleModificationTime: app.bundleModificationTime
+    )
+  }
+
   /// スキャン済みアプリが除外リストに該当するかを判定する。
   ///
   /// AppItem の名前照合に加えて Info.plist の表示名/バンドル名でも照合する
@@ -147,10 +328,12 @@ public struct AppScanner: AppScannerProtocol, Sendable {
     return isExcluded(bundlePath: app.path, appItem: nil, excludedSet: excludedSet)
   }
 
+  /// - Parameter plist: 解析済みの Info.plist（`appItem` が nil で、これも nil の場合は読み込む）
   private func isExcluded(
     bundlePath: String,
     appItem: AppItem?,
-    excludedSet: Set<String>
+    excludedSet: Set<String>,
+    infoPlist plist: [String: Any]? = nil
   ) -> Bool {
     guard !excludedSet.isEmpty else { return false }
 
@@ -164,7 +347,7 @@ public struct AppScanner: AppScannerProtocol, Sendable {
     }
 
     guard let appItem else {
-      let names = plistNames(for: bundlePath)
+      let names = Self.plistNames(fromInfoPlist: plist ?? infoPlist(for: bundlePath) ?? [:])
       if let displayName = names.displayName, excludedSet.contains(displayName) {
         return true
       }
@@ -180,6 +363,9 @@ public struct AppScanner: AppScannerProtocol, Sendable {
     if let originalName = appItem.originalName, excludedSet.contains(originalName) {
       return true
     }
+    if appItem.alternateNames.contains(where: excludedSet.contains) {
+      return true
+    }
 
     return false
   }
@@ -237,35 +423,104 @@ public struct AppScanner: AppScannerProtocol, Sendable {
 
   // MARK: - Info.plist Extraction
 
+  /// Info.plist を読み込む（読めない場合は nil）
+  private func infoPlist(for appPath: String) -> [String: Any]? {
+    let plistPath = (appPath as NSString).appendingPathComponent("Contents/Info.plist")
+    guard let plistData = FileManager.default.contents(atPath: plistPath) else { return nil }
+    return try? PropertyListSerialization.propertyList(
+      from: plistData, options: [], format: nil) as? [String: Any]
+  }
+
   /// Info.plist から CFBundleDisplayName と CFBundleName を抽出する
   public func plistNames(for appPath: String) -> (
     displayName: String?, bundleName: String?
   ) {
-    let plistPath = (appPath as NSString).appendingPathComponent("Contents/Info.plist")
-    guard let plistData = FileManager.default.contents(atPath: plistPath),
-      let plist =
-        try? PropertyListSerialization.propertyList(
-          from: plistData, options: [], format: nil) as? [String: Any]
-    else {
-      return (nil, nil)
-    }
+    Self.plistNames(fromInfoPlist: infoPlist(for: appPath) ?? [:])
+  }
 
+  /// Info.plist の内容から CFBundleDisplayName と CFBundleName を取り出す
+  static func plistNames(fromInfoPlist plist: [String: Any]) -> (
+    displayName: String?, bundleName: String?
+  ) {
     let displayName = plist["CFBundleDisplayName"] as? String
     let bundleName = plist["CFBundleName"] as? String
     return (displayName, bundleName)
   }
 
+  /// Info.plist からバージョン・バンドル ID・カテゴリを読み取る
+  public func plistMetadata(for appPath: String) -> (
+    version: String?, bundleIdentifier: String?, category: String?
+  ) {
+    Self.plistMetadata(fromInfoPlist: infoPlist(for: appPath) ?? [:])
+  }
+
+  /// Info.plist の内容からバージョン・バンドル ID・カテゴリを取り出す
+  static func plistMetadata(fromInfoPlist plist: [String: Any]) -> (
+    version: String?, bundleIdentifier: String?, category: String?
+  ) {
+    let version = plist["CFBundleShortVersionString"] as? String
+    let bundleIdentifier = plist["CFBundleIdentifier"] as? String
+    let category = plist["LSApplicationCategoryType"] as? String
+    return (version, bundleIdentifier, category)
+  }
+
+  /// Info.plist から名前より低い重みで照合するキーワードを読み取る
+  public func spotlightKeywords(for appPath: String) -> [String] {
+    infoPlist(for: appPath).map(Self.spotlightKeywords(fromInfoPlist:)) ?? []
+  }
+
+  /// Info.plist の内容からキーワードを取り出す。
+  ///
+  /// 著作権表記などの自由記述（`CFBundleGetInfoString`）は同じ開発元のアプリすべてに一致してしまうため使わない。
+  /// - `CFBundleSpotlightKeywords`（配列、またはカンマ区切りの文字列）
+  /// - Chrome で作った Web アプリ（PWA）の manifest の名前（`CrAppModeShortcutName`）と
+  ///   URL のホスト（`CrAppModeShortcutURL`）
+  /// - http / https の URL を開けるアプリは `browser` / `ブラウザ`
+  static func spotlightKeywords(fromInfoPlist plist: [String: Any]) -> [String] {
+    var keywords: [String] = []
+    func add(_ keyword: String) {
+      let keyword = keyword.trimmingCharacters(in: .whitespacesAndNewlines)
+      guard !keyword.isEmpty, !keywords.contains(keyword) else { return }
+      keywords.append(keyword)
+    }
+
+    switch plist["CFBundleSpotlightKeywords"] {
+    case let list as [String]:
+      list.forEach(add)
+    case let text as String:
+      text.split(separator: ",").map(String.init).forEach(add)
+    default:
+      break
+    }
+
+    if let name = plist["CrAppModeShortcutName"] as? String {
+      add(name)
+    }
+    if let urlString = plist["CrAppModeShortcutURL"] as? String,
+      var host = URL(string: urlString)?.host()
+    {
+      if host.hasPrefix("www.") {
+        host.removeFirst(4)
+      }
+      add(host)
+    }
+
+    let urlTypes = plist["CFBundleURLTypes"] as? [[String: Any]] ?? []
+    let schemes = urlTypes.flatMap { $0["CFBundleURLSchemes"] as? [String] ?? [] }
+    if schemes.contains(where: { ["http", "https"].contains($0.lowercased()) }) {
+      add("browser")
+      add("ブラウザ")
+    }
+    return keywords
+  }
+
   /// Info.plist からアイコンファイルのパスを解決する
   public func iconFilePath(for appPath: String) -> String? {
-    let plistPath = (appPath as NSString).appendingPathComponent("Contents/Info.plist")
-    guard let plistData = FileManager.default.contents(atPath: plistPath),
-      let plist =
-        try? PropertyListSerialization.propertyList(
-          from: plistData, options: [], format: nil) as? [String: Any]
-    else {
-      return nil
-    }
+    infoPlist(for: appPath).flatMap { iconFilePath(for: appPath, infoPlist: $0) }
+  }
 
+  /// 解析済みの Info.plist の内容からアイコンファイルのパスを解決する
+  func iconFilePath(for appPath: String, infoPlist plist: [String: Any]) -> String? {
     let fm = FileManager.default
     let resourcesPath = (appPath as NSString).appendingPathComponent("Contents/Resources")
 
@@ -288,6 +543,12 @@ public struct AppScanner: AppScannerProtocol, Sendable {
     return nil
   }
 
+  /// アプリがアセットカタログ（Contents/Resources/Assets.car）を持つかどうか
+  public func hasAssetCatalog(for appPath: String) -> Bool {
+    let carPath = (appPath as NSString).appendingPathComponent("Contents/Resources/Assets.car")
+    return FileManager.default.fileExists(atPath: carPath)
+  }
+
   private func resolveIconFile(_ iconFile: String, in resourcesPath: String) -> String? {
     // 拡張子が .icns の場合そのまま使用
     if iconFile.hasSuffix(".icns") {
@@ -315,10 +576,9 @@ public struct AppScanner: AppScannerProtocol, Sendable {
     return trimmed.isEmpty ? nil : trimmed
   }
 
-  /// .lproj/InfoPlist.strings からローカライズ名を取得する
+  /// .lproj/InfoPlist.strings（なければ InfoPlist.loctable）からローカライズ名を取得する
   public func localizedNameFromLproj(for appPath: String, locale: String? = nil) -> String? {
     let resourcesPath = (appPath as NSString).appendingPathComponent("Contents/Resources")
-    let fm = FileManager.default
 
     // 優先するロケールリスト
     let preferredLocales: [String]
@@ -333,7 +593,35 @@ public struct AppScanner: AppScannerProtocol, Sendable {
     }
 
     for lang in preferredLocales {
-      let lprojPath = (resourcesPath as NSString).appendingPathComponent("\(lang).lproj")
+      if let name = bundleLocalizedName(in: resourcesPath, locale: lang) {
+        return name
+      }
+    }
+
+    return nil
+  }
+
+  /// 指定した言語すべてのローカライズ名を重複なく返す（見つからない言語は飛ばす）。
+  public func localizedNames(for appPath: String, locales: [String]) -> [String] {
+    let resourcesPath = (appPath as NSString).appendingPathComponent("Contents/Resources")
+    var names: [String] = []
+    for lang in locales {
+      if let name = bundleLocalizedName(in: resourcesPath, locale: lang), !names.contains(name) {
+        names.append(name)
+      }
+    }
+    return names
+  }
+
+  /// 1 つの言語のローカライズ名を取得する。
+  ///
+  /// `<言語>.lproj/InfoPlist.strings`（旧形式の `Japanese.lproj` なども）を優先し、
+  /// なければ macOS 14 以降のシステムアプリが使う `InfoPlist.loctable` を読む。
+  private func bundleLocalizedName(in resourcesPath: String, locale lang: String) -> String? {
+    let fm = FileManager.default
+    let lprojNames = [lang] + (Self.legacyLprojNames[lang].map { [$0] } ?? [])
+    for lprojName in lprojNames {
+      let lprojPath = (resourcesPath as NSString).appendingPathComponent("\(lprojName).lproj")
       let stringsPath = (lprojPath as NSString).appendingPathComponent("InfoPlist.strings")
 
       guard fm.fileExists(atPath: stringsPath) else { continue }
@@ -347,7 +635,22 @@ public struct AppScanner: AppScannerProtocol, Sendable {
       }
     }
 
-    return nil
+    return loctableName(in: resourcesPath, locale: lang)
+  }
+
+  /// `InfoPlist.loctable`（言語 → キー → 値の plist）からローカライズ名を取得する
+  private func loctableName(in resourcesPath: String, locale lang: String) -> String? {
+    let loctablePath = (resourcesPath as NSString).appendingPathComponent("InfoPlist.loctable")
+    guard let data = FileManager.default.contents(atPath: loctablePath),
+      let table =
+        try? PropertyListSerialization.propertyList(
+          from: data, options: [], format: nil) as? [String: Any],
+      let strings = table[lang] as? [String: Any]
+    else { return nil }
+
+    let name =
+      (strings["CFBundleDisplayName"] as? String) ?? (strings["CFBundleName"] as? String)
+    return name?.isEmpty == false ? name : nil
   }
 
   private func parseStringsFile(at path: String, key: String) -> String? {
@@ -378,7 +681,12 @@ public struct AppScanner: AppScannerProtocol, Sendable {
 
   /// .app バンドルから AppItem を組み立てる
   public func extractAppInfo(from appPath: String) -> AppItem? {
-    let (displayName, bundleName) = plistNames(for: appPath)
+    extractAppInfo(from: appPath, infoPlist: infoPlist(for: appPath) ?? [:])
+  }
+
+  /// 解析済みの Info.plist の内容と .app バンドルから AppItem を組み立てる
+  func extractAppInfo(from appPath: String, infoPlist plist: [String: Any]) -> AppItem? {
+    let (displayName, bundleName) = Self.plistNames(fromInfoPlist: plist)
 
     // ローカライズ名の取得を試行
     let localizedName =
@@ -402,11 +710,34 @@ public struct AppScanner: AppScannerProtocol, Sendable {
       originalName = nil
     }
 
+    // 日本語・英語のローカライズ名と Info.plist の名前のうち、表示名・元の名前以外も
+    // 検索キーに加え、システムの言語に関わらずどちらの名前でも一致させる
+    var alternateNames: [String] = []
+    let candidates =
+      [displayName, bundleName].compactMap { $0 }
+      + localizedNames(for: appPath, locales: Self.searchNameLocales)
+    for candidate in candidates
+    where candidate != name && candidate != originalName && !alternateNames.contains(candidate) {
+      alternateNames.append(candidate)
+    }
+
+    let metadata = Self.plistMetadata(fromInfoPlist: plist)
+    let names = [name, originalName].compactMap { $0 } + alternateNames
+    let keywords = Self.spotlightKeywords(fromInfoPlist: plist).filter { keyword in
+      !names.contains { $0.caseInsensitiveCompare(keyword) == .orderedSame }
+    }
+
     return AppItem(
       name: name,
       path: appPath,
       iconPath: nil,  // アイコンは scanApplications で後から設定
-      originalName: originalName
+      originalName: originalName,
+      alternateNames: alternateNames,
+      keywords: keywords,
+      version: metadata.version,
+      bundleIdentifier: metadata.bundleIdentifier,
+      category: metadata.category,
+      bundleModificationTime: Self.bundleModificationTime(of: appPath)
     )
   }
 
@@ -0,0 +1,107 @@
+import Foundation
+
+/// 絵文字と Unicode 記号を名前・キーワードで検索するプロバイダー。
+///
+/// 絵文字は `emoji_keywords_ja.json`（`EmojiKeywordSearch`）、記号は `SymbolCatalog` を使う。
+/// `:shrug:` のようなショートコード表記にも対応し、前後の `:` は無視する。
+/// 完全一致 → 前方一致 → 部分一致の順に並べる。
+public final class CharacterSearch: Sendable {
+
+  /// アプリ全体で共有するインスタンス（初回アクセス時にデータセットを読み込む）
+  public static let shared = CharacterSearch()
+
+  private struct Entry: Sendable {
+    let character: String
+    let name: String
+    let keywords: [String]
+  }
+
+  /// 完全一致のスコア
+  static let exactScore = 0.0
+  /// 前方一致のスコア
+  static let prefixScore = 0.2
+  /// 部分一致のスコア
+  static let containsScore = 0.5
+
+  private let entries: [Entry]
+
+  /// CharacterSearch を初期化する。
+  ///
+  /// - Parameters:
+  ///   - emoji: 絵文字のキーワード辞書
+  ///   - symbols: 記号のデータセット
+  public init(
+    emoji: EmojiKeywordSearch = EmojiKeywordSearch(),
+    symbols: [SymbolEntry] = SymbolCatalog.entries
+  ) {
+    let emojiEntries = emoji.keywords.map { character, tags in
+      Entry(
+        character: character, name: tags.first ?? character,
+        keywords: tags.map { $0.lowercased() })
+    }
+    let symbolEntries = symbols.map { symbol in
+      Entry(
+        character: symbol.character, name: symbol.name,
+        keywords: ([symbol.name] + symbol.keywords).map { $0.lowercased() })
+    }
+    // 辞書の列挙順に依存しないよう文字で並べておく（同スコア時の表示順を安定させる）
+    self.entries = (symbolEntries + emojiEntries).sorted { $0.character < $1.character }
+  }
+
+  /// クエリに一致する絵文字・記号を返す。
+  ///
+  /// - Parameters:
+  ///   - query: 検索語（前後の `:` と空白は無視し、`_` は空白として扱う）
+  ///   - limit: 最大件数
+  /// - Returns: 一致度順の検索結果（kind は `.character`、path は文字そのもの）
+  public func search(_ query: String, limit: Int = 20) -> [SearchResult] {
+    let term = Self.normalizedTerm(query)
+    guard !term.isEmpty else { return [] }
+
+    var matches: [(entry: Entry, score: Double)] = []
+    for entry in entries {
+      if let score = Self.score(term: term, keywords: entry.keywords) {
+        matches.append((entry, score))
+      }
+    }
+    return matches
+      .enumerated()
+      .sorted { ($0.element.score, $0.offset) < ($1.element.score, $1.offset) }
+      .prefix(limit)
+      .map {
+        SearchResult(
+          character: $0.element.entry.character, name: $0.element.entry.name,
+          score: $0.element.score)
+      }
+  }
+
+  /// 検索語を照合用に整える（`:shrug:` → `shrug`、`check_mark` → `check mark`）。
+  static func normalizedTerm(_ query: String) -> String {
+    SearchQueryNormalizer.normalize(query)
+      .trimmingCharacters(in: CharacterSet(charactersIn: ": "))
+      .replacingOccurrences(of: "_", with: " ")
+  }
+
+  /// キーワードのうち最も良い一致のスコアを返す（一致しない場合は nil）。
+  private static func score(term: String, keywords: [String]) -> Double? {
+    var best: Double?
+    for keyword in keywords {
+      let candidate = keyword.replacingOccurrences(of: "_", with: " ")
+      let score: Double? =
+        if candidate == term {
+          exactScore
+        } else if candidate.hasPrefix(term) {
+          prefixScore
+        } else if candidate.contains(term) {
+          containsScore
+        } else {
+          nil
+        }
+      if let score, score < (best ?? .infinity) {
+        best = score
+        if score == exactScore { break }
+      }
+    }
+    return best
+  }
+}
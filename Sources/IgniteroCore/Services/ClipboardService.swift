@@ -0,0 +1,70 @@
+import AppKit
+import Foundation
+
+// MARK: - ClipboardCopyFormat
+
+/// 検索結果をクリップボードにコピーするときの形式
+public enum ClipboardCopyFormat: String, Sendable, CaseIterable {
+  /// パス（Web 検索・URL は URL）
+  case path
+  /// `file://` URL
+  case fileURL
+  /// 表示名
+  case name
+
+  /// コンテキストメニューに表示する名前
+  public var label: String {
+    switch self {
+    case .path: "パスをコピー"
+    case .fileURL: "file:// URL をコピー"
+    case .name: "名前をコピー"
+    }
+  }
+
+  /// 結果をこの形式でコピーするときのテキスト（この形式でコピーできない結果は nil）
+  ///
+  /// パスと `file://` URL はファイルやフォルダを指す結果（アプリ・ディレクトリ）だけ、
+  /// 名前はすべての結果でコピーできる。
+  public func text(for result: SearchResult) -> String? {
+    switch self {
+    case .path:
+      switch result.kind {
+      case .app, .directory, .webSearch:
+        result.path.isEmpty ? nil : result.path
+      case .command, .character, .emoji, .colorPicker, .process, .systemSettings, .systemControl,
+        .windowLayout:
+        nil
+      }
+    case .fileURL:
+      guard result.kind == .app || result.kind == .directory, !result.path.isEmpty else {
+        return nil
+      }
+      return URL(fileURLWithPath: result.path).absoluteString
+    case .name:
+      result.name.isEmpty ? nil : result.name
+    }
+  }
+}
+
+// MARK: - ClipboardWriting プロトコル
+
+public protocol ClipboardWriting: Sendable {
+  /// テキストをクリップボードに置く（それまでの内容は置き換える）。
+  @MainActor func copy(_ text: String)
+}
+
+// MARK: - ClipboardService 本体
+
+/// システムのクリップボード（`NSPasteboard.general`）にテキストを書き込む。
+///
+/// 書き込みはその場で終わるため、コピー直後にランチャーを閉じても内容は失われない。
+public struct ClipboardService: ClipboardWriting, Sendable {
+  public init() {}
+
+  @MainActor
+  public func copy(_ text: String) {
+    let pasteboard = NSPasteboard.general
+    pasteboard.clearContents()
+    pasteboard.setString(text, forType: .string)
+  }
+}
@@ -0,0 +1,178 @@
+import Foundation
+
+// MARK: - DiagnosticCheck
+
+/// 診断項目 1 件の結果
+public struct DiagnosticCheck: Sendable, Equatable, Identifiable {
+
+  /// 診断結果の状態
+  public enum Status: String, Sendable {
+    /// 問題なし
+    case ok
+    /// 動作はするが確認が必要
+    case warning
+    /// 機能が動作しない
+    case failed
+  }
+
+  /// 項目の識別子（不具合報告で参照する）
+  public let id: String
+  /// 表示名
+  public let title: String
+  public let status: Status
+  /// 結果の詳細と対処方法
+  public let detail: String
+
+  public init(id: String, title: String, status: Status, detail: String) {
+    self.id = id
+    self.title = title
+    self.status = status
+    self.detail = detail
+  }
+}
+
+// MARK: - DiagnosticReport
+
+/// 自己診断の結果一覧
+public struct DiagnosticReport: Sendable, Equatable {
+  public let checks: [DiagnosticCheck]
+  /// 診断を実行した日時
+  public let generatedAt: Date
+
+  public init(checks: [DiagnosticCheck], generatedAt: Date = Date()) {
+    self.checks = checks
+    self.generatedAt = generatedAt
+  }
+
+  /// 警告または失敗の項目があるか
+  public var hasProblems: Bool {
+    checks.contains { $0.status != .ok }
+  }
+
+  /// 不具合報告に貼り付けるテキスト
+  public var summary: String {
+    let header = "Ignitero Launcher v\(Ignitero.version) diagnostics (\(generatedAt.formatted(.iso8601)))"
+    let lines = checks.map { "[\($0.status.rawValue.uppercased())] \($0.id): \($0.detail)" }
+    return ([header] + lines).joined(separator: "\n")
+  }
+}
+
+// MARK: - Diagnostics
+
+/// 自己診断の各項目を判定する。
+///
+/// 状態の取得（権限の問い合わせ・DB の検査など）は呼び出し側で行い、ここでは結果の判定と
+/// 表示する対処方法の組み立てだけを行う。
+public enum Diagnostics {
+
+  /// アクセシビリティ権限（キー入力の監視とパネル操作に必要）
+  public static func accessibility(isTrusted: Bool) -> DiagnosticCheck {
+    DiagnosticCheck(
+      id: "accessibility", title: "アクセシビリティ権限",
+      status: isTrusted ? .ok : .failed,
+      detail: isTrusted
+        ? "許可されています"
+        : "許可されていません。システム設定の「プライバシーとセキュリティ」→「アクセシビリティ」で許可してください。"
+    )
+  }
+
+  /// グローバルホットキーの登録状況
+  ///
+  /// - Parameters:
+  ///   - isToggleRegistered: ランチャー表示のホットキーを登録できたか
+  ///   - failedBindings: 登録に失敗した追加ショートカット
+  public static func hotkeys(
+    isToggleRegistered: Bool, failedBindings: [HotkeyBinding]
+  ) -> DiagnosticCheck {
+    let status: DiagnosticCheck.Status
+    let detail: String
+    if !isToggleRegistered {
+      status = .failed
+      detail = "ランチャーのホットキーを登録できませんでした。他のアプリと重複していないか確認してください。"
+    } else if !failedBindings.isEmpty {
+      status = .warning
+      let shortcuts = failedBindings.map(\.shortcut).joined(separator: ", ")
+      detail = "追加ショートカットを登録できませんでした: \(shortcuts)"
+    } else {
+      status = .ok
+      detail = "登録済み"
+    }
+    return DiagnosticCheck(id: "hotkeys", title: "ホットキー", status: status, detail: detail)
+  }
+
+  /// キャッシュ DB の整合性
+  ///
+  /// - Parameter result: `PRAGMA integrity_check` で見つかった問題（空なら正常）、または検査の失敗
+  public static func cacheIntegrity(_ result: Result<[String], any Error>) -> DiagnosticCheck {
+    let status: DiagnosticCheck.Status
+    let detail: String
+    switch result {
+    case .success(let problems) where problems.isEmpty:
+      status = .ok
+      detail = "整合性に問題はありません"
+    case .success(let problems):
+      status = .failed
+      detail = "破損しています（\(problems.prefix(3).joined(separator: " / "))）。キャッシュを再構築してください。"
+    case .failure(let error):
+      status = .failed
+      detail = "検査できませんでした: \(error.localizedDescription)"
+    }
+    return DiagnosticCheck(id: "cache_db", title: "キャッシュ DB", status: status, detail: detail)
+  }
+
+  /// 設定ファイルの妥当性
+  public static func settingsFile(_ fileStatus: SettingsFileStatus) -> DiagnosticCheck {
+    let status: DiagnosticCheck.Status
+    let detail: String
+    switch fileStatus {
+    case .valid:
+      status = .ok
+      detail = "読み込めます"
+    case .missing:
+      status = .ok
+      detail = "未作成のため既定の設定を使用しています"
+    case .invalid(let reason):
+      status = .failed
+      detail = "読み込めません（\(reason)）。起動時に既定の設定へ戻し、元のファイルを settings.json.backup に保存します。"
+    }
+    return DiagnosticCheck(id: "settings_file", title: "設定ファイル", status: status, detail: detail)
+  }
+
+  /// アイコンキャッシュの使用量
+  ///
+  /// - Parameters:
+  ///   - usage: キャッシュ済みのファイル数と合計サイズ
+  ///   - warningBytes: 警告とするサイズ
+  public static func iconCache(
+    _ usage: IconCacheUsage, warningBytes: Int64 = 500_000_000
+  ) -> DiagnosticCheck {
+    let size = ByteCountFormatter.string(fromByteCount: usage.totalBytes, countStyle: .file)
+    let isLarge = usage.totalBytes >= warningBytes
+    return DiagnosticCheck(
+      id: "icon_cache", title: "アイコンキャッシュ",
+      status: isLarge ? .warning : .ok,
+      detail: isLarge
+        ? "\(usage.fileCount) ファイル / \(size)。設定の「アイコンキャッシュ」で上限サイズを設定するか、削除してください。"
+        : "\(usage.fileCount) ファイル / \(size)"
+    )
+  }
+
+  /// 登録ディレクトリのスキャン状況
+  public static func scanHealth(_ statuses: [DirectoryScanStatus]) -> DiagnosticCheck {
+    let incomplete = statuses.filter { $0.state != .completed }
+    guard !statuses.isEmpty else {
+      return DiagnosticCheck(
+        id: "scan", title: "ディレクトリのスキャン", status: .ok, detail: "まだスキャンしていません")
+    }
+    guard !incomplete.isEmpty else {
+      return DiagnosticCheck(
+        id: "scan", title: "ディレクトリのスキャン", status: .ok,
+        detail: "\(statuses.count) 件のディレクトリをスキャン済み")
+    }
+    let paths = incomplete.map(\.path).joined(separator: ", ")
+    return DiagnosticCheck(
+      id: "scan", title: "ディレクトリのスキャン", status: .warning,
+      detail: "スキャンが完了しなかったディレクトリがあります: \(paths)。前回のキャッシュを使用しています。"
+    )
+  }
+}
@@ -1,5 +1,5 @@
 import Foundation
-import os
+import Synchronization
 
 // MARK: - ファイルシステムエラー
 
@@ -13,6 +13,18 @@ public protocol FileSystemProvider: Sendable {
   func contentsOfDirectory(atPath path: String) throws -> [String]
   func isDirectory(atPath path: String) -> Bool
   func fileExists(atPath path: String) -> Bool
+  func isSymbolicLink(atPath path: String) -> Bool
+  func canonicalPath(atPath path: String) -> String
+  func contentsOfFile(atPath path: String) -> String?
+  /// Finder のタグ（拡張属性 `_kMDItemUserTags`）。タグがなければ空
+  func finderTags(atPath path: String) -> [FinderTag]
+}
+
+extension FileSystemProvider {
+  public func isSymbolicLink(atPath path: String) -> Bool { false }
+  public func canonicalPath(atPath path: String) -> String { path }
+  public func contentsOfFile(atPath path: String) -> String? { nil }
+  public func finderTags(atPath path: String) -> [FinderTag] { [] }
 }
 
 // MARK: - デフォルトファイルシステムプロバイダー
@@ -33,6 +45,29 @@ public struct DefaultFileSystemProvider: FileSystemProvider, Sendable {
   public func fileExists(atPath path: String) -> Bool {
     FileManager.default.fileExists(atPath: path)
   }
+
+  public func isSymbolicLink(atPath path: String) -> Bool {
+    let attributes = try? FileManager.default.attributesOfItem(atPath: path)
+    return attributes?[.type] as? FileAttributeType == .typeSymbolicLink
+  }
+
+  public func canonicalPath(atPath path: String) -> String {
+    URL(fileURLWithPath: path).resolvingSymlinksInPath().path
+  }
+
+  public func contentsOfFile(atPath path: String) -> String? {
+    try? String(contentsOfFile: path, encoding: .utf8)
+  }
+
+  public func finderTags(atPath path: String) -> [FinderTag] {
+    let name = FinderTag.extendedAttributeName
+    let size = getxattr(path, name, nil, 0, 0, 0)
+    guard size > 0 else { return [] }
+    var data = Data(count: size)
+    let read = data.withUnsafeMutableBytes { getxattr(path, name, $0.baseAddress, size, 0, 0) }
+    guard read > 0 else { return [] }
+    return FinderTag.parse(userTags: data.prefix(read))
+  }
 }
 
 // MARK: - スキャン結果
@@ -47,22 +82,252 @@ public struct ScanResult: Sendable, Equatable {
   }
 }
 
+// MARK: - Git リポジトリ情報
+
+/// スキャン中に検出した Git リポジトリの情報
+struct GitRepositoryInfo: Sendable, Equatable {
+  /// 現在のブランチ（取得できない場合は nil）
+  let branch: String?
+}
+
+// MARK: - スキャン状況
+
+/// 登録ディレクトリ 1 件分のスキャン状況
+public struct DirectoryScanStatus: Sendable, Equatable {
+  public enum State: Sendable, Equatable {
+    /// スキャンが完了した
+    case completed
+    /// 制限時間内に完了しなかった（切断されたネットワークボリュームなど）
+    case timedOut
+    /// ディレクトリを読み取れなかった（未接続のボリューム・権限不足など）
+    case unavailable(reason: String)
+  }
+
+  /// 登録ディレクトリのパス（末尾スラッシュなし）
+  public let path: String
+  public let state: State
+  /// スキャンに要した時間（秒）
+  public let duration: TimeInterval
+  /// 検出した項目数（ディレクトリとアプリの合計）
+  public let itemCount: Int
+
+  public init(path: String, state: State, duration: TimeInterval, itemCount: Int = 0) {
+    self.path = path
+    self.state = state
+    self.duration = duration
+    self.itemCount = itemCount
+  }
+}
+
+/// 登録ディレクトリ全体のスキャン結果と、ディレクトリごとのスキャン状況
+public struct DirectoryScanReport: Sendable, Equatable {
+  /// 完了したディレクトリの結果を登録順に連結したもの
+  public let result: ScanResult
+  /// 登録順のスキャン状況
+  public let statuses: [DirectoryScanStatus]
+
+  public init(result: ScanResult, statuses: [DirectoryScanStatus]) {
+    self.result = result
+    self.statuses = statuses
+  }
+
+  /// 完了しなかった登録ディレクトリのパス
+  public var incompletePaths: Set<String> {
+    Set(statuses.filter { $0.state != .completed }.map(\.path))
+  }
+}
+
+// MARK: - スキャンのキャンセル
+
+/// 実行中のスキャンに打ち切りを伝えるフラグ。
+///
+/// スキャンは Swift Concurrency の外（ディスパッチキュー）でも動くため、`Task.isCancelled` の
+/// 代わりにこのフラグを走査中に確認する。
+public final class ScanCancellation: Sendable {
+  private let cancelled = Mutex(false)
+  private let parent: ScanCancellation?
+
+  /// - Parameter parent: 打ち切りを引き継ぐフラグ（親が打ち切られるとこのフラグも打ち切られる）
+  public init(parent: ScanCancellation? = nil) {
+    self.parent = parent
+  }
+
+  /// 打ち切りが要求されたか（親の打ち切りを含む）
+  public var isCancelled: Bool {
+    cancelled.withLock { $0 } || parent?.isCancelled == true
+  }
+
+  /// 打ち切りを要求する（走査は次のディレクトリの確認時に止まる）
+  public func cancel() {
+    cancelled.withLock { $0 = true }
+  }
+}
+
 // MARK: - DirectoryScanner プロトコル
 
 public protocol DirectoryScannerProtocol: Sendable {
   func scan(directories: [RegisteredDirectory]) throws -> ScanResult
+  /// 登録ディレクトリ 1 件をスキャンする（ルートを読み取れない場合はエラーを投げる）。
+  func scan(directory: RegisteredDirectory) throws -> ScanResult
+  /// 登録ディレクトリ 1 件を、打ち切り可能な形でスキャンする。
+  ///
+  /// 打ち切られた場合はそこまでに見つけた項目を返す。
+  func scan(directory: RegisteredDirectory, cancellation: ScanCancellation) throws -> ScanResult
+}
+
+/// 登録ディレクトリのスキャンを実行するキュー。
+///
+/// 応答しないネットワークボリュームでブロックしても Swift Concurrency の協調スレッドを
+/// 占有しないよう、スキャンはこのキューのスレッドで実行する。
+private let directoryScanQueue = DispatchQueue(
+  label: "com.owayo.ignitero.launcher.directory-scan", qos: .utility, attributes: .concurrent)
+
+extension DirectoryScannerProtocol {
+  public func scan(directory: RegisteredDirectory) throws -> ScanResult {
+    try scan(directories: [directory])
+  }
+
+  public func scan(
+    directory: RegisteredDirectory, cancellation: ScanCancellation
+  ) throws -> ScanResult {
+    try scan(directory: directory)
+  }
+
+  /// 登録ディレクトリを並列にスキャンし、ディレクトリごとの状況を返す。
+  ///
+  /// 同時に実行するスキャンは `maxConcurrentScans` 件までに抑える。各スキャンは専用の
+  /// ディスパッチキューで実行し、制限時間を過ぎたディレクトリは結果を待たずに `.timedOut` として
+  /// 走査を打ち切る（ブロック中のファイルシステム呼び出しは戻るまでキューのスレッドに残るが、
+  /// 他のディレクトリのスキャンや Swift Concurrency のスレッドは止めない）。
+  /// - Parameters:
+  ///   - directories: 登録ディレクトリ
+  ///   - timeout: 1 ディレクトリあたりの制限時間
+  ///   - maxConcurrentScans: 同時に実行するスキャンの上限
+  ///   - cancellation: 全体の打ち切り（打ち切られると実行中の走査を止め、残りのディレクトリは
+  ///     スキャンしない）
+  /// - Returns: 完了したディレクトリの結果と、全ディレクトリのスキャン状況
+  public func scanConcurrently(
+    directories: [RegisteredDirectory],
+    timeout: Duration = .seconds(30),
+    maxConcurrentScans: Int = 4,
+    cancellation: ScanCancellation = ScanCancellation()
+  ) async -> DirectoryScanReport {
+    let limit = max(maxConcurrentScans, 1)
+    var outcomes = [(status: DirectoryScanStatus, result: ScanResult?)?](
+      repeating: nil, count: directories.count)
+
+    let scanOne: @Sendable (Int, RegisteredDirectory) async -> (
+      Int, DirectoryScanStatus, ScanResult?
+    ) = { index, directory in
+      let (status, result) = await scanWithTimeout(
+        directory, timeout: timeout, cancellation: cancellation)
+      return (index, status, result)
+    }
+    await withTaskGroup(of: (Int, DirectoryScanStatus, ScanResult?).self) { group in
+      var pending = directories.enumerated().makeIterator()
+      for _ in 0..<limit {
+        guard let (index, directory) = pending.next() else { break }
+        group.addTask { await scanOne(index, directory) }
+      }
+      // 1 件完了するごとに次のディレクトリを投入し、同時実行数を上限以内に保つ
+      for await (index, status, result) in group {
+        outcomes[index] = (status, result)
+        if !cancellation.isCancelled, let (nextIndex, directory) = pending.next() {
+          group.addTask { await scanOne(nextIndex, directory) }
+        }
+      }
+    }
+
+    var directoriesFound: [DirectoryItem] = []
+    var apps: [AppItem] = []
+    var statuses: [DirectoryScanStatus] = []
+    for outcome in outcomes {
+      guard let outcome else { continue }
+      statuses.append(outcome.status)
+      if let result = outcome.result {
+        directoriesFound.append(contentsOf: result.directories)
+        apps.append(contentsOf: result.apps)
+      }
+    }
+    return DirectoryScanReport(
+      result: ScanResult(directories: directoriesFound, apps: apps), statuses: statuses)
+  }
+
+  /// 登録ディレクトリ 1 件を制限時間付きでスキャンする。
+  private func scanWithTimeout(
+    _ directory: RegisteredDirectory, timeout: Duration, cancellation parent: ScanCancellation
+  ) async -> (DirectoryScanStatus, ScanResult?) {
+    let path = directory.normalizedPath
+    let start = ContinuousClock.now
+    let elapsed: @Sendable () -> TimeInterval = {
+      let duration = ContinuousClock.now - start
+      return Double(duration.components.seconds)
+        + Double(duration.components.attoseconds) / 1e18
+    }
+
+    let gate = ResumeGate()
+    let cancellation = ScanCancellation(parent: parent)
+    return await withCheckedContinuation { continuation in
+      let timer = Task {
+        try? await Task.sleep(for: timeout)
+        guard !Task.isCancelled, gate.open() else { return }
+        cancellation.cancel()
+        continuation.resume(
+          returning: (DirectoryScanStatus(path: path, state: .timedOut, duration: elapsed()), nil))
+      }
+      directoryScanQueue.async {
+        let outcome: (DirectoryScanStatus, ScanResult?)
+        do {
+          let result = try self.scan(directory: directory, cancellation: cancellation)
+          outcome = (
+            DirectoryScanStatus(
+              path: path, state: .completed, duration: elapsed(),
+              itemCount: result.directories.count + result.apps.count),
+            result
+          )
+        } catch {
+          outcome = (
+            DirectoryScanStatus(
+              path: path, state: .unavailable(reason: error.localizedDescription),
+              duration: elapsed()),
+            nil
+          )
+        }
+        // 完了したスキャンの制限時間のタイマーは待たずに止める
+        timer.cancel()
+        if gate.open() { continuation.resume(returning: outcome) }
+      }
+    }
+  }
+}
+
+/// 継続の再開を 1 度だけ許可するゲート（スキャン完了と制限時間の早い方を採用する）
+private final class ResumeGate: Sendable {
+  private let opened = Mutex(false)
+
+  /// 初回の呼び出しのみ `true` を返す
+  func open() -> Bool {
+    opened.withLock { opened in
+      defer { opened = true }
+      return !opened
+    }
+  }
 }
 
 // MARK: - DirectoryScanner 本体
 
 public struct DirectoryScanner: DirectoryScannerProtocol, Sendable {
-  private static let logger = Logger(
-    subsystem: "com.ignitero.launcher", category: "DirectoryScanner")
+  private static let logger = AppLogger(category: "DirectoryScanner")
 
   private let fileSystemProvider: FileSystemProvider
+  private let projectTypeRegistry: ProjectTypeRegistry
 
-  public init(fileSystemProvider: FileSystemProvider = DefaultFileSystemProvider()) {
+  public init(
+    fileSystemProvider: FileSystemProvider = DefaultFileSystemProvider(),
+    projectTypeRegistry: ProjectTypeRegistry = .standard
+  ) {
     self.fileSystemProvider = fileSystemProvider
+    self.projectTypeRegistry = projectTypeRegistry
   }
 
   public func scan(directories: [RegisteredDirectory]) throws -> ScanResult {
@@ -70,69 +335,209 @@ public struct DirectoryScanner: DirectoryScannerProtocol, Sendable {
     var allApps: [AppItem] = []
 
     for registered in directories {
-      let normalizedPath = normalizePath(registered.path)
-
-      // ディレクトリ内容の取得を試行。失敗時はスキップ
-      let contents: [String]
+      // ディレクトリ内容の取得に失敗した場合はスキップ
       do {
-        contents = try fileSystemProvider.contentsOfDirectory(atPath: normalizedPath)
+        let result = try scan(directory: registered)
+        allDirectories.append(contentsOf: result.directories)
+        allApps.append(contentsOf: result.apps)
       } catch {
         Self.logger.warning(
-          "Skipping directory \(normalizedPath): \(error.localizedDescription)")
-        continue
+          "Skipping directory \(registered.normalizedPath): \(error.localizedDescription)")
       }
+    }
 
-      // 親ディレクトリを DirectoryItem として追加（mode が .none ならスキップ）
-      if registered.parentOpenMode != .none {
-        let parentName = parentDirectoryName(
-          for: registered,
-          normalizedPath: normalizedPath
-        )
-        let parentEditor = editorForOpenMode(
-          registered.parentOpenMode, editor: registered.parentEditor)
-        allDirectories.append(
-          DirectoryItem(name: parentName, path: normalizedPath, editor: parentEditor))
-      }
+    return ScanResult(directories: allDirectories, apps: allApps)
+  }
+
+  public func scan(directory registered: RegisteredDirectory) throws -> ScanResult {
+    try scan(directory: registered, cancellation: ScanCancellation())
+  }
+
+  public func scan(
+    directory registered: RegisteredDirectory, cancellation: ScanCancellation
+  ) throws -> ScanResult {
+    let normalizedPath = normalizePath(registered.path)
+    let contents = try fileSystemProvider.contentsOfDirectory(atPath: normalizedPath)
+    var directories: [DirectoryItem] = []
+
+    // 親ディレクトリを DirectoryItem として追加（mode が .none ならスキップ）
+    if registered.parentOpenMode != .none {
+      let parentName = parentDirectoryName(
+        for: registered,
+        normalizedPath: normalizedPath
+      )
+      let parentEditor = editorForOpenMode(
+        registered.parentOpenMode, editor: registered.parentEditor)
+      let git = registered.detectGitRepos ? gitRepository(at: normalizedPath) : nil
+      directories.append(
+        DirectoryItem(
+          name: parentName, path: normalizedPath, editor: parentEditor,
+          isGitRepo: git != nil, gitBranch: git?.branch, priority: registered.effectivePriority,
+          xcodeProject: xcodeProject(in: normalizedPath, contents: contents),
+          projectType: projectTypeRegistry.detect(in: contents),
+          finderTags: fileSystemProvider.finderTags(atPath: normalizedPath)))
+    }
+
+    // 配下を subdirsDepth 階層まで走査する（ディレクトリ項目・アプリ・Git リポジトリの
+    // いずれも不要なら走査しない）
+    guard
+      registered.subdirsOpenMode != .none || registered.scanForApps || registered.detectGitRepos
+    else { return ScanResult(directories: directories, apps: []) }
+    var context = TraversalContext(
+      registered: registered,
+      cancellation: cancellation,
+      excludeMatcher: ExcludePatternMatcher(patterns: registered.excludePatterns),
+      subEditor: editorForOpenMode(registered.subdirsOpenMode, editor: registered.subdirsEditor)
+    )
+    context.visited.insert(fileSystemProvider.canonicalPath(atPath: normalizedPath))
+    scanChildren(
+      of: normalizedPath, contents: contents, relativePath: "", level: 1, context: &context)
+    directories.append(contentsOf: context.directories)
+    return ScanResult(directories: directories, apps: context.apps)
+  }
 
-      // 直下の子エントリを処理
-      for entry in contents {
-        // 隠しエントリをスキップ
-        guard !entry.hasPrefix(".") else { continue }
-
-        let childPath = (normalizedPath as NSString).appendingPathComponent(entry)
-
-        // .app バンドルかどうかを判定（.app 拡張子の通常ファイルを誤って起動対象にしない）
-        if entry.hasSuffix(".app") {
-          if registered.scanForApps, fileSystemProvider.isDirectory(atPath: childPath) {
-            let appName = String(entry.dropLast(4))  // ".app" サフィックスを除去
-            allApps.append(AppItem(name: appName, path: childPath))
-          }
-          // .app バンドルは scanForApps の設定に関わらずディレクトリ項目には含めない
-          continue
+  // MARK: - 再帰走査
+
+  /// 登録ディレクトリ 1 件分の走査状態
+  private struct TraversalContext {
+    let registered: RegisteredDirectory
+    let cancellation: ScanCancellation
+    let excludeMatcher: ExcludePatternMatcher
+    let subEditor: String?
+    /// 走査済みディレクトリの実体パス（シンボリックリンクによる循環の検出用）
+    var visited: Set<String> = []
+    var directories: [DirectoryItem] = []
+    var apps: [AppItem] = []
+  }
+
+  /// ディレクトリの子エントリを処理し、深さの上限まで再帰的に走査する。
+  ///
+  /// シンボリックリンクのディレクトリは項目としては登録するが、その先へは降りない。
+  /// 実体パスが走査済みのディレクトリにも降りないため、循環参照があっても停止する。
+  private func scanChildren(
+    of path: String,
+    contents: [String],
+    relativePath: String,
+    level: Int,
+    context: inout TraversalContext
+  ) {
+    let registered = context.registered
+    for entry in contents where !entry.hasPrefix(".") {
+      // 制限時間を過ぎて打ち切られたスキャンは走査をやめる
+      guard !context.cancellation.isCancelled, !Task.isCancelled else { return }
+      let childRelativePath = relativePath.isEmpty ? entry : relativePath + "/" + entry
+      // 除外パターンに一致するエントリはスキップ（配下も走査しない）
+      guard !context.excludeMatcher.isExcluded(relativePath: childRelativePath) else { continue }
+
+      let childPath = (path as NSString).appendingPathComponent(entry)
+
+      // .app バンドルかどうかを判定（.app 拡張子の通常ファイルを誤って起動対象にしない）
+      if entry.hasSuffix(".app") {
+        if registered.scanForApps, fileSystemProvider.isDirectory(atPath: childPath) {
+          let appName = String(entry.dropLast(4))  // ".app" サフィックスを除去
+          context.apps.append(AppItem(name: appName, path: childPath))
         }
+        // .app バンドルは scanForApps の設定に関わらずディレクトリ項目には含めず、配下にも降りない
+        continue
+      }
 
-        // ディレクトリのみ対象（通常ファイルは除外）。subdirs mode が .none ならスキップ
-        guard registered.subdirsOpenMode != .none,
-          fileSystemProvider.isDirectory(atPath: childPath)
-        else { continue }
+      // ディレクトリのみ対象（通常ファイルは除外）
+      guard fileSystemProvider.isDirectory(atPath: childPath) else { continue }
+
+      // Git リポジトリは深さや subdirs mode に関わらずプロジェクト項目として扱う
+      let git = registered.detectGitRepos ? gitRepository(at: childPath) : nil
+      let withinDepth = level <= registered.effectiveSubdirsDepth
+      let isItem = git != nil || (withinDepth && registered.subdirsOpenMode != .none)
+
+      // 深さの上限を超えた後は、Git リポジトリ探索のためにリポジトリ外だけを降りる
+      let descendForGit =
+        registered.detectGitRepos && git == nil && level < RegisteredDirectory.maxGitSearchDepth
+      let descends =
+        (level < registered.effectiveSubdirsDepth || descendForGit)
+        && !fileSystemProvider.isSymbolicLink(atPath: childPath)
+        && context.visited.insert(fileSystemProvider.canonicalPath(atPath: childPath)).inserted
+      guard isItem || descends else { continue }
+
+      // 項目のプロジェクトの検出と配下の走査で、ディレクトリの読み込みを共有する
+      let grandchildren: [String]?
+      do {
+        grandchildren = try fileSystemProvider.contentsOfDirectory(atPath: childPath)
+      } catch {
+        if descends {
+          Self.logger.warning("Skipping directory \(childPath): \(error.localizedDescription)")
+        }
+        grandchildren = nil
+      }
 
-        let subEditor = editorForOpenMode(
-          registered.subdirsOpenMode, editor: registered.subdirsEditor)
-        allDirectories.append(
-          DirectoryItem(name: entry, path: childPath, editor: subEditor))
+      if isItem {
+        let displayName = registered.subdirectoryDisplayName(for: entry, parentPath: path)
+        context.directories.append(
+          DirectoryItem(
+            name: displayName, path: childPath, editor: context.subEditor,
+            isGitRepo: git != nil, gitBranch: git?.branch,
+            priority: registered.effectivePriority,
+            originalName: displayName == entry ? nil : entry,
+            xcodeProject: grandchildren.flatMap { xcodeProject(in: childPath, contents: $0) },
+            projectType: grandchildren.flatMap { projectTypeRegistry.detect(in: $0) },
+            finderTags: fileSystemProvider.finderTags(atPath: childPath)))
       }
+
+      guard descends, let grandchildren else { continue }
+      scanChildren(
+        of: childPath, contents: grandchildren, relativePath: childRelativePath,
+        level: level + 1, context: &context)
     }
+  }
 
-    return ScanResult(directories: allDirectories, apps: allApps)
+  // MARK: - Git
+
+  /// `.git` を持つディレクトリの Git 情報を返す（リポジトリでなければ nil）。
+  ///
+  /// ワークツリーやサブモジュールの `.git` ファイル（`gitdir: <path>`）にも対応する。
+  func gitRepository(at path: String) -> GitRepositoryInfo? {
+    let dotGit = (path as NSString).appendingPathComponent(".git")
+    guard fileSystemProvider.fileExists(atPath: dotGit) else { return nil }
+
+    var gitDirectory = dotGit
+    if !fileSystemProvider.isDirectory(atPath: dotGit),
+      let content = fileSystemProvider.contentsOfFile(atPath: dotGit),
+      let line = content.split(whereSeparator: \.isNewline).first,
+      line.hasPrefix("gitdir:")
+    {
+      let target = line.dropFirst("gitdir:".count).trimmingCharacters(in: .whitespaces)
+      gitDirectory =
+        target.hasPrefix("/") ? target : (path as NSString).appendingPathComponent(target)
+    }
+
+    let head = fileSystemProvider.contentsOfFile(
+      atPath: (gitDirectory as NSString).appendingPathComponent("HEAD"))
+    return GitRepositoryInfo(branch: Self.branchName(fromHead: head))
+  }
+
+  /// `.git/HEAD` の内容から現在のブランチ名を取り出す。
+  ///
+  /// detached HEAD の場合は先頭 7 文字のコミットハッシュを返す。
+  static func branchName(fromHead head: String?) -> String? {
+    guard let line = head?.trimmingCharacters(in: .whitespacesAndNewlines), !line.isEmpty
+    else { return nil }
+    let refPrefix = "ref: refs/heads/"
+    if line.hasPrefix(refPrefix) {
+      return String(line.dropFirst(refPrefix.count))
+    }
+    guard !line.hasPrefix("ref:") else { return nil }
+    return String(line.prefix(7))
   }
 
   // MARK: - 非公開ヘルパー
 
+  /// ディレクトリ直下の Xcode のワークスペース・プロジェクトのパスを返す。
+  private func xcodeProject(in path: String, contents: [String]) -> String? {
+    ProjectFileDetector.xcode.detect(in: contents)
+      .map { (path as NSString).appendingPathComponent($0) }
+  }
+
   private func normalizePath(_ path: String) -> String {
-    if path != "/", path.hasSuffix("/") {
-      return String(path.dropLast())
-    }
-    return path
+    RegisteredDirectory.normalized(path)
   }
 
   private func lastPathComponent(of path: String) -> String {
@@ -161,3 +566,63 @@ public struct DirectoryScanner: DirectoryScannerProtocol, Sendable {
     }
   }
 }
+
+// MARK: - 登録ディレクトリの所有判定
+
+extension RegisteredDirectory {
+  /// 末尾スラッシュを除いた登録パス
+  var normalizedPath: String {
+    Self.normalized(path)
+  }
+
+  /// パス末尾のスラッシュを除去する（ルート `/` はそのまま）。
+  static func normalized(_ path: String) -> String {
+    if path != "/", path.hasSuffix("/") {
+      return String(path.dropLast())
+    }
+    return path
+  }
+
+  /// スキャンで実際に使う深さ（1〜`maxSubdirsDepth` に丸める）
+  var effectiveSubdirsDepth: Int {
+    min(max(subdirsDepth, 1), Self.maxSubdirsDepth)
+  }
+
+  /// 配下のディレクトリの表示名を `subdirsNameTemplate` から作る。
+  ///
+  /// - Parameters:
+  ///   - name: ディレクトリ名
+  ///   - parentPath: 1 つ上のディレクトリのパス
+  /// - Returns: テンプレートを展開した表示名（テンプレートが空ならディレクトリ名）
+  func subdirectoryDisplayName(for name: String, parentPath: String) -> String {
+    guard let template = subdirsNameTemplate?.trimmingCharacters(in: .whitespaces),
+      !template.isEmpty
+    else { return name }
+    let expanded =
+      template
+      .replacingOccurrences(of: "{parent}", with: (parentPath as NSString).lastPathComponent)
+      .replacingOccurrences(of: "{root}", with: (normalizedPath as NSString).lastPathComponent)
+    return expanded.contains("{name}")
+      ? expanded.replacingOccurrences(of: "{name}", with: name) : expanded + name
+  }
+
+  /// 検索順位に使う優先度（`priorityRange` に丸める）
+  var effectivePriority: Int {
+    min(max(priority, Self.priorityRange.lowerBound), Self.priorityRange.upperBound)
+  }
+
+  /// 指定パスの項目がこの登録ディレクトリのスキャンで生成され得るかを返す。
+  ///
+  /// 単一ディレクトリの再スキャン時に、キャッシュから置き換える範囲を決めるために使う。
+  func owns(itemPath: String) -> Bool {
+    let root = normalizedPath
+    if itemPath == root { return true }
+    let prefix = root == "/" ? root : root + "/"
+    guard itemPath.hasPrefix(prefix) else { return false }
+    let depth = itemPath.dropFirst(prefix.count).split(separator: "/").count
+    guard !detectGitRepos else {
+      return depth <= max(effectiveSubdirsDepth, Self.maxGitSearchDepth)
+    }
+    return depth <= effectiveSubdirsDepth
+  }
+}
@@ -0,0 +1,67 @@
+import AppKit
+import Foundation
+
+// MARK: - DisplayInfo
+
+/// 接続中のディスプレイ 1 台の情報
+public struct DisplayInfo: Sendable, Equatable {
+  /// ディスプレイ名（`NSScreen.localizedName`）
+  public let name: String
+  /// 倍率（Retina は 2）
+  public let scale: Double
+  /// 幅（ポイント）
+  public let width: Double
+  /// 高さ（ポイント）
+  public let height: Double
+  /// ランチャーを開くディスプレイ（カーソルのあるディスプレイ）か
+  public let isActive: Bool
+
+  public init(name: String, scale: Double, width: Double, height: Double, isActive: Bool) {
+    self.name = name
+    self.scale = scale
+    self.width = width
+    self.height = height
+    self.isActive = isActive
+  }
+}
+
+// MARK: - DisplayInfoProviding プロトコル
+
+public protocol DisplayInfoProviding: Sendable {
+  /// 接続中のディスプレイを返す（メインディスプレイが先頭）。
+  @MainActor func displays() -> [DisplayInfo]
+}
+
+extension DisplayInfoProviding {
+  /// ランチャーを開くディスプレイの倍率（特定できない場合はメインディスプレイ、なければ 1）
+  @MainActor public func activeScale() -> Double {
+    let connected = displays()
+    return (connected.first(where: \.isActive) ?? connected.first)?.scale ?? 1
+  }
+}
+
+// MARK: - DisplayInfoProvider 本体
+
+/// `NSScreen` からディスプレイの倍率と大きさを取得する。
+///
+/// ピッカーと同じくカーソルのあるディスプレイをランチャーを開くディスプレイとみなす。
+public struct DisplayInfoProvider: DisplayInfoProviding, Sendable {
+  public init() {}
+
+  @MainActor
+  public func displays() -> [DisplayInfo] {
+    let mouseLocation = NSEvent.mouseLocation
+    let active =
+      NSScreen.screens.first { NSMouseInRect(mouseLocation, $0.frame, false) }
+      ?? NSScreen.main
+    return NSScreen.screens.map { screen in
+      DisplayInfo(
+        name: screen.localizedName,
+        scale: Double(screen.backingScaleFactor),
+        width: Double(screen.frame.width),
+        height: Double(screen.frame.height),
+        isActive: screen == active
+      )
+    }
+  }
+}
@@ -0,0 +1,153 @@
+import Foundation
+import Synchronization
+
+/// Docker のコンテナ（`docker ps` の 1 行）
+public struct DockerContainer: Sendable, Equatable {
+  public let id: String
+  public let name: String
+  public let image: String
+  /// 状態（`running`、`exited` など）
+  public let state: String
+  /// Compose のプロジェクト名（Compose で作成したコンテナのみ）
+  public let composeProject: String?
+  /// Compose のプロジェクトのフォルダ（Compose で作成したコンテナのみ）
+  public let composeWorkingDirectory: String?
+
+  public init(
+    id: String, name: String, image: String, state: String = "running",
+    composeProject: String? = nil, composeWorkingDirectory: String? = nil
+  ) {
+    self.id = id
+    self.name = name
+    self.image = image
+    self.state = state
+    self.composeProject = composeProject
+    self.composeWorkingDirectory = composeWorkingDirectory
+  }
+
+  /// 実行中か（シェルを開けるのは実行中のコンテナのみ）
+  public var isRunning: Bool {
+    state == "running"
+  }
+
+  /// 選択履歴で使う識別子
+  public var identifier: String {
+    "docker://\(name)"
+  }
+
+  /// コンテナでシェルを開くコマンド（bash があれば bash、なければ sh）
+  public var shellCommand: String {
+    let shell = "if command -v bash >/dev/null; then exec bash; else exec sh; fi"
+    return "docker exec -it \(TerminalTarget.shellQuoted(name)) sh -c '\(shell)'"
+  }
+}
+
+public protocol DockerListing: Sendable {
+  /// 取得済みのコンテナを返す（コマンドは実行しない。未取得の場合は空）。
+  func cachedContainers() -> [DockerContainer]
+  /// コンテナの一覧を取得し直して返す（Docker が使えない場合は空）。
+  func refresh() async -> [DockerContainer]
+}
+
+/// `docker ps` でコンテナと Compose のプロジェクトを取得するサービス。
+///
+/// 検索は取得済みの一覧（`cachedContainers`）だけを使い、`docker ps` は `docker`
+/// プレフィックスで検索したときに `refresh` で非同期に実行する。キー入力ごとにコマンドを
+/// 起動しないよう `refreshInterval` の間は前回の結果を返す。
+/// Docker がインストールされていない・デーモンが起動していない場合は空を返す。
+public final class DockerService: DockerListing {
+  private static let logger = AppLogger(category: "DockerService")
+
+  /// アプリ全体で共有するインスタンス（取得結果を検索間で使い回すため）
+  public static let shared = DockerService()
+
+  /// `docker` の場所の候補（GUI アプリにはシェルの PATH が引き継がれないため直接探す）
+  public static let defaultExecutablePaths = [
+    "/opt/homebrew/bin/docker",
+    "/usr/local/bin/docker",
+    NSString(string: "~/.docker/bin/docker").expandingTildeInPath,
+    NSString(string: "~/.orbstack/bin/docker").expandingTildeInPath,
+    "/Applications/Docker.app/Contents/Resources/bin/docker",
+  ]
+
+  /// 前回の結果を使い回す間隔（秒）
+  static let refreshInterval: TimeInterval = 5
+  /// `docker ps` の応答を待つ上限（秒）
+  static let timeout: TimeInterval = 3
+
+  private let executablePaths: [String]
+  private let cache = Mutex<(date: Date, containers: [DockerContainer])?>(nil)
+
+  public init(executablePaths: [String] = DockerService.defaultExecutablePaths) {
+    self.executablePaths = executablePaths
+  }
+
+  public func cachedContainers() -> [DockerContainer] {
+    cache.withLock { $0?.containers ?? [] }
+  }
+
+  public func refresh() async -> [DockerContainer] {
+    if let cached = cache.withLock({ $0 }),
+      Date().timeIntervalSince(cached.date) < Self.refreshInterval
+    {
+      return cached.containers
+    }
+    let containers = await runPS().map(Self.containers(fromPS:)) ?? []
+    cache.withLock { $0 = (Date(), containers) }
+    return containers
+  }
+
+  /// `docker ps --format '{{json .}}'` の出力（1 行に 1 コンテナの JSON）を解析する。
+  static func containers(fromPS output: String) -> [DockerContainer] {
+    output.split(whereSeparator: \.isNewline).compactMap { line in
+      guard
+        let object = try? JSONSerialization.jsonObject(with: Data(line.utf8)) as? [String: Any],
+        let id = object["ID"] as? String,
+        // 複数の名前を持つ場合は先頭の名前を使う
+        let name = (object["Names"] as? String)?.split(separator: ",").first.map(String.init)
+      else { return nil }
+      let labels = Self.labels(from: object["Labels"] as? String ?? "")
+      return DockerContainer(
+        id: id, name: name, image: object["Image"] as? String ?? "",
+        state: object["State"] as? String ?? "",
+        composeProject: labels["com.docker.compose.project"],
+        composeWorkingDirectory: labels["com.docker.compose.project.working_dir"])
+    }
+  }
+
+  /// `key=value,key=value` 形式のラベルを辞書にする。
+  static func labels(from text: String) -> [String: String] {
+    var labels: [String: String] = [:]
+    for pair in text.split(separator: ",") {
+      let parts = pair.split(separator: "=", maxSplits: 1)
+      guard parts.count == 2 else { continue }
+      labels[String(parts[0])] = String(parts[1])
+    }
+    return labels
+  }
+
+  // MARK: - 非公開メソッド
+
+  /// `docker ps` を `ProcessRunner` で実行して出力を返す（失敗した場合は nil）。
+  private func runPS() async -> String? {
+    let fm = FileManager.default
+    guard let executable = executablePaths.first(where: fm.isExecutableFile(atPath:)) else {
+      return nil
+    }
+    let output: ProcessRunner.Output
+    do {
+      // デーモンが応答しない場合に結果を待ち続けないよう、上限を過ぎたら終了させる
+      output = try await ProcessRunner.run(
+        executable, arguments: ["ps", "--all", "--no-trunc", "--format", "{{json .}}"],
+        timeout: Self.timeout)
+    } catch {
+      Self.logger.warning("Failed to run docker: \(error.localizedDescription)")
+      return nil
+    }
+    guard output.status == 0 else {
+      Self.logger.info("docker ps exited with status \(output.status)")
+      return nil
+    }
+    return String(decoding: output.standardOutput, as: UTF8.self)
+  }
+}
@@ -5,12 +5,19 @@ import Foundation
 ///
 /// バンドルリソース `emoji_keywords_ja.json` を読み込み、
 /// 絵文字文字列 → キーワード配列のマッピングで検索を行う。
+/// 絵文字のキーワードには emojibase の英語名とショートコード（`thumbs_up` など）も含まれ、
+/// 英語のキーワードがない記号には読み込み時に Unicode の文字名を加える。
 /// EmojiKit の `localizedName` 検索では対応できない
 /// 「いいね」→ 👍 のような日本語キーワード検索を提供する。
 public final class EmojiKeywordSearch: Sendable {
 
-  /// emoji 文字列 → キーワード配列
-  private let keywords: [String: [String]]
+  /// emoji 文字列 → キーワード配列（先頭は日本語の名前）
+  let keywords: [String: [String]]
+
+  /// キーワード辞書を指定して初期化する（テスト用）。
+  init(keywords: [String: [String]]) {
+    self.keywords = keywords
+  }
 
   public init() {
     guard
@@ -21,7 +28,23 @@ public final class EmojiKeywordSearch: Sendable {
       self.keywords = [:]
       return
     }
-    self.keywords = dict
+    self.keywords = Self.addingUnicodeNames(to: dict)
+  }
+
+  /// 単一のコードポイントの文字に、小文字にした Unicode の文字名をキーワードとして加える。
+  ///
+  /// CLDR の日本語の注釈だけの記号（`{` など）を英語でも引けるようにする。
+  /// すでに同じキーワードがある項目と、複数のコードポイントからなる絵文字はそのまま。
+  static func addingUnicodeNames(to keywords: [String: [String]]) -> [String: [String]] {
+    var result = keywords
+    for (character, tags) in keywords {
+      let scalars = character.removingVariationSelectors().unicodeScalars
+      guard scalars.count == 1, let name = scalars.first?.properties.name?.lowercased(),
+        !tags.contains(where: { $0.lowercased() == name })
+      else { continue }
+      result[character] = tags + [name]
+    }
+    return result
   }
 
   /// クエリにマッチする絵文字の集合を返す。
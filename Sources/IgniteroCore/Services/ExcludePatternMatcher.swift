@@ -0,0 +1,102 @@
+import Foundation
+
+/// gitignore 形式の除外パターンで、登録ディレクトリからの相対パスを判定する。
+///
+/// 対応する書式:
+/// - `node_modules` のように `/` を含まないパターンは任意の階層の名前に一致する
+/// - `build/cache` や `/dist` のように `/` を含むパターンは登録ディレクトリ起点で一致する
+/// - `*`（区切りを除く任意の文字列）、`?`（任意の 1 文字）、`**`（任意の階層）
+/// - 末尾の `/` は無視する（スキャン対象はすべてディレクトリのため）
+/// - `!` で始まるパターンは直前までの除外を取り消す（後勝ち）
+/// - 空行と `#` で始まる行は無視する
+public struct ExcludePatternMatcher: Sendable, Equatable {
+  struct Rule: Sendable, Equatable {
+    let segments: [String]
+    let negated: Bool
+  }
+
+  let rules: [Rule]
+
+  public init(patterns: [String]) {
+    rules = patterns.compactMap(Self.parse)
+  }
+
+  /// 有効なパターンが 1 つもないか
+  public var isEmpty: Bool { rules.isEmpty }
+
+  /// 相対パス（`a/b/c` 形式）が除外対象かを返す。
+  ///
+  /// 親ディレクトリが除外されている場合は、その配下もすべて除外対象とする。
+  public func isExcluded(relativePath: String) -> Bool {
+    guard !rules.isEmpty else { return false }
+    let components = relativePath.split(separator: "/").map(String.init)
+    guard !components.isEmpty else { return false }
+    for length in 1...components.count where matches(Array(components.prefix(length))) {
+      return true
+    }
+    return false
+  }
+
+  // MARK: - 非公開ヘルパー
+
+  private func matches(_ components: [String]) -> Bool {
+    var excluded = false
+    for rule in rules where Self.match(rule.segments[...], components[...]) {
+      excluded = !rule.negated
+    }
+    return excluded
+  }
+
+  private static func parse(_ raw: String) -> Rule? {
+    var pattern = raw.trimmingCharacters(in: .whitespaces)
+    guard !pattern.isEmpty, !pattern.hasPrefix("#") else { return nil }
+
+    let negated = pattern.hasPrefix("!")
+    if negated { pattern.removeFirst() }
+    while pattern.hasSuffix("/") { pattern.removeLast() }
+
+    // 先頭以外にも `/` を含むかで、名前一致か起点一致かが決まる
+    let anchored = pattern.contains("/")
+    if pattern.hasPrefix("/") { pattern.removeFirst() }
+    var segments = pattern.split(separator: "/").map(String.init)
+    guard !segments.isEmpty else { return nil }
+    if !anchored { segments.insert("**", at: 0) }
+    return Rule(segments: segments, negated: negated)
+  }
+
+  private static func match(_ pattern: ArraySlice<String>, _ path: ArraySlice<String>) -> Bool {
+    guard let head = pattern.first else { return path.isEmpty }
+    if head == "**" {
+      let rest = pattern.dropFirst()
+      return (path.startIndex...path.endIndex).contains { match(rest, path[$0...]) }
+    }
+    guard let name = path.first, wildcardMatch(Array(head), Array(name)) else { return false }
+    return match(pattern.dropFirst(), path.dropFirst())
+  }
+
+  /// `*` と `?` を含むパターンで 1 階層分の名前を照合する。
+  static func wildcardMatch(_ pattern: [Character], _ text: [Character]) -> Bool {
+    var p = 0
+    var t = 0
+    var starIndex: Int?
+    var matchIndex = 0
+    while t < text.count {
+      if p < pattern.count, pattern[p] == "*" {
+        starIndex = p
+        matchIndex = t
+        p += 1
+      } else if p < pattern.count, pattern[p] == "?" || pattern[p] == text[t] {
+        p += 1
+        t += 1
+      } else if let star = starIndex {
+        p = star + 1
+        matchIndex += 1
+        t = matchIndex
+      } else {
+        return false
+      }
+    }
+    while p < pattern.count, pattern[p] == "*" { p += 1 }
+    return p == pattern.count
+  }
+}
@@ -1,36 +1,114 @@
 import Carbon.HIToolbox
 import Dispatch
 import Foundation
-import os
+import Synchronization
 
 public protocol IMEControlling: Sendable {
+  /// 英数入力に切り替える（切り替える前の入力ソースを記憶する）。
   func switchToASCII()
+  /// `switchToASCII()` で切り替える前の入力ソースに戻す（記憶していなければ何もしない）。
+  func restoreInputSource()
+  /// 記憶した入力ソースを戻さずに破棄する（閉じても戻さない設定の場合）。
+  func discardSavedInputSource()
 }
 
-public struct IMEController: IMEControlling, Sendable {
-  private static let logger = Logger(subsystem: "com.ignitero.launcher", category: "IME")
+/// TIS API で入力ソースを切り替える。
+///
+/// ランチャーを表示するときに英数入力へ切り替え、閉じるときに元の入力ソースへ戻す。
+/// キー入力を合成しないため、JIS 以外のキーボードでも動き、アクセシビリティ権限も要らない。
+/// 入力ソースはオブジェクトではなく ID（`kTISPropertyInputSourceID`）で記憶し、戻すときに探し直す。
+public final class IMEController: IMEControlling, Sendable {
+  private static let logger = AppLogger(category: "IME")
+
+  /// 切り替える前の入力ソースの ID（戻すまでの間だけ保持する）
+  private let savedSourceID = Mutex<String?>(nil)
 
   public init() {}
 
   public func switchToASCII() {
-    // TIS/TSM API はメインスレッドで直列実行しないと abort することがある。
+    performOnMain { self.selectASCIIInputSource() }
+  }
+
+  public func restoreInputSource() {
+    performOnMain { self.restoreSavedInputSource() }
+  }
+
+  public func discardSavedInputSource() {
+    savedSourceID.withLock { $0 = nil }
+  }
+
+  /// TIS/TSM API はメインスレッドで直列実行しないと abort することがある。
+  private func performOnMain(_ body: () -> Void) {
     if Thread.isMainThread {
-      Self.selectASCIIInputSource()
+      body()
     } else {
-      DispatchQueue.main.sync {
-        Self.selectASCIIInputSource()
-      }
+      DispatchQueue.main.sync(execute: body)
     }
   }
 
-  private static func selectASCIIInputSource() {
+  private func selectASCIIInputSource() {
+    let current = TISCopyCurrentKeyboardInputSource()?.takeRetainedValue()
+    // 表示中に再度切り替えた場合は英数入力を記憶しないよう、最初の 1 回だけ記憶する
+    if let current, let id = Self.sourceID(of: current) {
+      savedSourceID.withLock { saved in
+        if saved == nil { saved = id }
+      }
+    }
+    // ABC・Dvorak・ことえりの英字モードなど、英字を入力できるソースならそのまま使う
+    if let current, Self.isASCIICapable(current) {
+      return
+    }
     guard let source = TISCopyCurrentASCIICapableKeyboardInputSource()?.takeRetainedValue() else {
-      logger.error("Failed to get ASCII input source")
+      Self.logger.error("Failed to get ASCII input source")
+      return
+    }
+    let status = TISSelectInputSource(source)
+    if status != noErr {
+      Self.logger.error("Failed to select input source: \(status)")
+    }
+  }
+
+  private func restoreSavedInputSource() {
+    let savedID = savedSourceID.withLock { saved in
+      let id = saved
+      saved = nil
+      return id
+    }
+    guard let id = savedID else { return }
+    if let current = TISCopyCurrentKeyboardInputSource()?.takeRetainedValue(),
+      Self.sourceID(of: current) == id
+    {
+      return
+    }
+    let filter = [kTISPropertyInputSourceID as String: id] as CFDictionary
+    guard
+      let sources = TISCreateInputSourceList(filter, false)?.takeRetainedValue()
+        as? [TISInputSource],
+      let source = sources.first
+    else {
+      Self.logger.warning("Input source to restore is no longer available: \(id)")
       return
     }
     let status = TISSelectInputSource(source)
     if status != noErr {
-      logger.error("Failed to select input source: \(status)")
+      Self.logger.error("Failed to restore input source \(id): \(status)")
+    }
+  }
+
+  /// 英字を入力できる入力ソースか（`kTISPropertyInputSourceIsASCIICapable`）
+  private static func isASCIICapable(_ source: TISInputSource) -> Bool {
+    guard let pointer = TISGetInputSourceProperty(source, kTISPropertyInputSourceIsASCIICapable)
+    else {
+      return false
+    }
+    return CFBooleanGetValue(Unmanaged<CFBoolean>.fromOpaque(pointer).takeUnretainedValue())
+  }
+
+  /// 入力ソースの ID（`com.apple.inputmethod.Kotoeri.RomajiTyping.Japanese` など）
+  private static func sourceID(of source: TISInputSource) -> String? {
+    guard let pointer = TISGetInputSourceProperty(source, kTISPropertyInputSourceID) else {
+      return nil
     }
+    return Unmanaged<CFString>.fromOpaque(pointer).takeUnretainedValue() as String
   }
 }
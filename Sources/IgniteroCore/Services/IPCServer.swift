@@ -0,0 +1,173 @@
+import Foundation
+import IgniteroIPC
+
+/// `ignitero` CLI からのリクエストを Unix ドメインソケットで受け付けるサーバー。
+///
+/// 接続ごとに改行終端の JSON リクエストを 1 件読み、ハンドラの結果を 1 行返して切断する。
+/// ソケットは所有者だけが読み書きできるパーミッション（0600）で作成し、同じユーザー以外の
+/// プロセスからの接続は読まずに切断する。
+@MainActor
+public final class IPCServer {
+  private nonisolated static let logger = AppLogger(category: "IPCServer")
+
+  /// リクエストを処理するハンドラ
+  public typealias Handler = @Sendable (IPCRequest) async -> IPCResponse
+
+  private let socketPath: String
+  private let handler: Handler
+  private let ioTimeout: Duration
+  private var source: (any DispatchSourceRead)?
+  private let queue = DispatchQueue(label: "com.owayo.ignitero.launcher.ipc")
+  /// 接続ごとの読み書き（ブロックする）を行うキュー
+  private nonisolated static let connectionQueue = DispatchQueue(
+    label: "com.owayo.ignitero.launcher.ipc.connection", attributes: .concurrent)
+
+  /// - Parameters:
+  ///   - socketPath: 待ち受けるソケット
+  ///   - ioTimeout: 接続ごとの読み書きの制限時間（リクエストを送らない接続はこの時間で切る）
+  ///   - handler: リクエストを処理するハンドラ
+  public init(
+    socketPath: String = IPCSocket.defaultPath,
+    ioTimeout: Duration = .seconds(5),
+    handler: @escaping Handler
+  ) {
+    self.socketPath = socketPath
+    self.ioTimeout = ioTimeout
+    self.handler = handler
+  }
+
+  /// 待ち受けているか
+  public var isRunning: Bool {
+    source != nil
+  }
+
+  /// 待ち受けを開始する。
+  ///
+  /// 前回の異常終了で残ったソケットファイルは削除してから作成する。パーミッションは umask で
+  /// 作成した時点から 0600 にする（bind の後の chmod では、それまでの間に接続できてしまう）。
+  /// - Throws: 別のプロセスが同じソケットで待ち受けている場合は `IPCError.alreadyRunning`
+  public func start() throws {
+    guard !isRunning else { return }
+    var address = try IPCSocket.address(for: socketPath)
+    try FileManager.default.createDirectory(
+      atPath: (socketPath as NSString).deletingLastPathComponent,
+      withIntermediateDirectories: true)
+    // 接続できるソケットは使用中のため、削除して乗っ取らない
+    if FileManager.default.fileExists(atPath: socketPath) {
+      guard !Self.acceptsConnections(at: &address) else {
+        throw IPCError.alreadyRunning(socketPath)
+      }
+      unlink(socketPath)
+    }
+
+    let fd = socket(AF_UNIX, SOCK_STREAM, 0)
+    guard fd >= 0 else { throw IPCError.socketFailed(errno: errno) }
+    let previousMask = umask(0o177)
+    let bound = withUnsafePointer(to: &address) {
+      $0.withMemoryRebound(to: sockaddr.self, capacity: 1) {
+        bind(fd, $0, socklen_t(MemoryLayout<sockaddr_un>.size))
+      }
+    }
+    let bindError = errno
+    umask(previousMask)
+    guard bound == 0 else {
+      close(fd)
+      throw IPCError.socketFailed(errno: bindError)
+    }
+    guard listen(fd, 8) == 0 else {
+      let code = errno
+      close(fd)
+      throw IPCError.socketFailed(errno: code)
+    }
+
+    // ハンドラはソケット用のキューで呼ばれるため、MainActor に隔離しない
+    let handler = handler
+    let timeout = Self.socketTimeout(from: ioTimeout)
+    let source = DispatchSource.makeReadSource(fileDescriptor: fd, queue: queue)
+    source.setEventHandler { @Sendable in
+      Self.acceptConnection(on: fd, timeout: timeout, handler: handler)
+    }
+    source.setCancelHandler { @Sendable in
+      close(fd)
+    }
+    self.source = source
+    source.resume()
+    Self.logger.info("IPC server listening at \(socketPath)")
+  }
+
+  /// 待ち受けを停止し、ソケットファイルを削除する。
+  public func stop() {
+    guard let source else { return }
+    source.cancel()
+    self.source = nil
+    unlink(socketPath)
+    Self.logger.info("IPC server stopped")
+  }
+
+  // MARK: - 非公開メソッド
+
+  /// ソケットに接続できるか（別のプロセスが待ち受けているか）を確かめる。
+  private static func acceptsConnections(at address: inout sockaddr_un) -> Bool {
+    let fd = socket(AF_UNIX, SOCK_STREAM, 0)
+    guard fd >= 0 else { return false }
+    defer { close(fd) }
+    let connected = withUnsafePointer(to: &address) {
+      $0.withMemoryRebound(to: sockaddr.self, capacity: 1) {
+        connect(fd, $0, socklen_t(MemoryLayout<sockaddr_un>.size))
+      }
+    }
+    return connected == 0
+  }
+
+  private nonisolated static func socketTimeout(from duration: Duration) -> timeval {
+    let (seconds, attoseconds) = duration.components
+    return timeval(tv_sec: Int(seconds), tv_usec: Int32(attoseconds / 1_000_000_000_000))
+  }
+
+  private nonisolated static func acceptConnection(
+    on listenerFD: Int32, timeout: timeval, handler: @escaping Handler
+  ) {
+    let connection = accept(listenerFD, nil, nil)
+    guard connection >= 0 else { return }
+
+    var uid: uid_t = 0
+    var gid: gid_t = 0
+    guard getpeereid(connection, &uid, &gid) == 0, uid == getuid() else {
+      Self.logger.warning("Rejected IPC connection from another user")
+      close(connection)
+      return
+    }
+    var timeout = timeout
+    let size = socklen_t(MemoryLayout<timeval>.size)
+    setsockopt(connection, SOL_SOCKET, SO_RCVTIMEO, &timeout, size)
+    setsockopt(connection, SOL_SOCKET, SO_SNDTIMEO, &timeout, size)
+
+    // 読み書きはブロックするため、Swift Concurrency のスレッドではなく専用のキューで行う
+    connectionQueue.async {
+      let request: IPCRequest
+      do {
+        request = try IPCCodec.decode(
+          IPCRequest.self, from: try IPCSocket.readLine(from: connection))
+      } catch {
+        Self.logger.warning("Invalid IPC request: \(error.localizedDescription)")
+        respond(.failure("Invalid request"), to: connection)
+        return
+      }
+      Self.logger.debug("IPC request: \(request.command.rawValue, privacy: .public)")
+      Task {
+        let response = await handler(request)
+        connectionQueue.async { respond(response, to: connection) }
+      }
+    }
+  }
+
+  /// レスポンスを書き込んで接続を閉じる。
+  private nonisolated static func respond(_ response: IPCResponse, to connection: Int32) {
+    defer { close(connection) }
+    do {
+      try IPCSocket.write(try IPCCodec.encode(response), to: connection)
+    } catch {
+      Self.logger.warning("Failed to send IPC response: \(error.localizedDescription)")
+    }
+  }
+}
@@ -2,17 +2,182 @@ import AppKit
 import CryptoKit
 import Foundation
 
+/// キャッシュするアイコンの出力サイズ（ピクセル）
+public enum IconSize: Int, Sendable, CaseIterable, Comparable {
+  case small = 32
+  case medium = 64
+  case large = 128
+
+  public static func < (lhs: IconSize, rhs: IconSize) -> Bool {
+    lhs.rawValue < rhs.rawValue
+  }
+
+  /// 指定ポイントサイズを指定倍率のディスプレイに拡大せず表示できる最小のサイズ
+  ///
+  /// 最大サイズでも足りない場合は `large` を返す。
+  /// - Parameters:
+  ///   - pointSize: 表示するポイントサイズ
+  ///   - scale: ディスプレイの倍率（Retina は 2、1 未満は 1 とみなす）
+  public static func fitting(pointSize: Double, scale: Double) -> IconSize {
+    let pixels = pointSize * max(scale, 1)
+    return allCases.sorted().first { Double($0.rawValue) >= pixels } ?? .large
+  }
+}
+
+/// アイコンキャッシュの使用量
+public struct IconCacheUsage: Sendable, Equatable {
+  public let fileCount: Int
+  public let totalBytes: Int64
+
+  public init(fileCount: Int, totalBytes: Int64) {
+    self.fileCount = fileCount
+    self.totalBytes = totalBytes
+  }
+}
+
+/// アイコンキャッシュの掃除の結果
+public struct IconCacheCleanupResult: Sendable, Equatable {
+  /// 現在のアプリから参照されていないため削除したファイル数
+  public let removedUnreferenced: Int
+  /// 上限サイズを超えたため削除したファイル数
+  public let evicted: Int
+  /// 削除したファイルの合計サイズ（バイト）
+  public let freedBytes: Int64
+
+  public init(removedUnreferenced: Int, evicted: Int, freedBytes: Int64) {
+    self.removedUnreferenced = removedUnreferenced
+    self.evicted = evicted
+    self.freedBytes = freedBytes
+  }
+}
+
 public struct IconCacheManager: Sendable {
   private let cacheDirectory: String
 
-  public init(cacheDirectory: String = "~/.cache/ignitero/icons/") {
+  /// 生成する出力サイズ（`large` は常に生成される）
+  public let outputSizes: [IconSize]
+
+  public init(
+    cacheDirectory: String = "~/.cache/ignitero/icons/",
+    outputSizes: [IconSize] = IconSize.allCases
+  ) {
     self.cacheDirectory = NSString(string: cacheDirectory).expandingTildeInPath
+    self.outputSizes = Array(Set(outputSizes).union([.large])).sorted()
   }
 
-  public func cachedIconPath(for appPath: String) -> String {
+  /// 指定サイズのキャッシュ PNG パスを返す。
+  ///
+  /// `large`（128px）は従来のファイル名（`<hash>.png`）を維持し、既存キャッシュをそのまま使う。
+  /// それ以外のサイズは `<hash>_<px>.png` とする。
+  public func cachedIconPath(for appPath: String, size: IconSize = .large) -> String {
     let hash = SHA256.hash(data: Data(appPath.utf8))
     let hashString = hash.prefix(16).map { String(format: "%02x", $0) }.joined()
-    return (cacheDirectory as NSString).appendingPathComponent("\(hashString).png")
+    let fileName =
+      size == .large ? "\(hashString).png" : "\(hashString)_\(size.rawValue).png"
+    return (cacheDirectory as NSString).appendingPathComponent(fileName)
+  }
+
+  /// 指定ポイントサイズ・倍率で表示するアイコンのキャッシュ PNG パスを返す（未キャッシュなら nil）。
+  ///
+  /// 拡大しない最小のサイズを選び、そのサイズが生成されていない場合は 128px を返す。
+  public func cachedIconPath(for appPath: String, pointSize: Double, scale: Double) -> String? {
+    guard let largePath = existingIconPath(for: appPath) else { return nil }
+    return Self.iconPath(largePath, pointSize: pointSize, scale: scale)
+  }
+
+  /// 128px のキャッシュ PNG パス（`AppItem.iconPath`）を、表示サイズ・倍率に合うサイズのパスに置き換える。
+  ///
+  /// 1x のディスプレイに 128px を読み込んで縮小したり、Retina で小さい PNG を拡大したりしないよう、
+  /// ビューは表示前にこのパスを使う。合うサイズが生成されていない場合やキャッシュ以外のパス
+  /// （`.icns` など）はそのまま返す。
+  public static func iconPath(_ largePath: String, pointSize: Double, scale: Double) -> String {
+    let size = IconSize.fitting(pointSize: pointSize, scale: scale)
+    guard size != .large, largePath.hasSuffix(".png") else { return largePath }
+    let path = (largePath as NSString).deletingPathExtension + "_\(size.rawValue).png"
+    return FileManager.default.fileExists(atPath: path) ? path : largePath
+  }
+
+  /// キャッシュ済みの PNG のファイル数と合計サイズを返す（ディレクトリがない場合は 0）。
+  public func usage() -> IconCacheUsage {
+    let entries = cacheEntries()
+    return IconCacheUsage(
+      fileCount: entries.count, totalBytes: entries.reduce(Int64(0)) { $0 + $1.size })
+  }
+
+  /// 現在のアプリから参照されていない PNG を削除し、上限サイズを超えていれば
+  /// 最終アクセス日時の古いものから削除する。
+  ///
+  /// 上限超過で削除したアプリのアイコンは、次回のスキャンで作り直される。
+  /// - Parameters:
+  ///   - appPaths: アイコンを残すアプリのパス（除外設定前のスキャン済みアプリ全件）
+  ///   - maxBytes: キャッシュの上限サイズ（nil の場合は上限なし）
+  @discardableResult
+  public func collectGarbage(
+    keeping appPaths: some Sequence<String>, maxBytes: Int64? = nil
+  ) -> IconCacheCleanupResult {
+    let referenced = Set(
+      appPaths.flatMap { appPath in
+        IconSize.allCases.map {
+          (cachedIconPath(for: appPath, size: $0) as NSString).lastPathComponent
+        }
+      })
+
+    var removedUnreferenced = 0
+    var evicted = 0
+    var freedBytes: Int64 = 0
+    var remaining: [CacheEntry] = []
+    for entry in cacheEntries() {
+      if referenced.contains(entry.url.lastPathComponent) {
+        remaining.append(entry)
+      } else if (try? FileManager.default.removeItem(at: entry.url)) != nil {
+        removedUnreferenced += 1
+        freedBytes += entry.size
+      }
+    }
+
+    if let maxBytes {
+      var totalBytes = remaining.reduce(Int64(0)) { $0 + $1.size }
+      for entry in remaining.sorted(by: { $0.lastUsed < $1.lastUsed }) where totalBytes > maxBytes {
+        guard (try? FileManager.default.removeItem(at: entry.url)) != nil else { continue }
+        evicted += 1
+        freedBytes += entry.size
+        totalBytes -= entry.size
+      }
+    }
+
+    return IconCacheCleanupResult(
+      removedUnreferenced: removedUnreferenced, evicted: evicted, freedBytes: freedBytes)
+  }
+
+  /// キャッシュ済みの PNG をすべて削除する（ディレクトリがない場合は何もしない）。
+  public func removeAll() throws {
+    for entry in cacheEntries() {
+      try FileManager.default.removeItem(at: entry.url)
+    }
+  }
+
+  /// キャッシュ済みの PNG 1 件
+  private struct CacheEntry {
+    let url: URL
+    let size: Int64
+    /// 最終アクセス日時（取得できない場合は更新日時）
+    let lastUsed: Date
+  }
+
+  private func cacheEntries() -> [CacheEntry] {
+    let keys: [URLResourceKey] = [.fileSizeKey, .contentAccessDateKey, .contentModificationDateKey]
+    guard
+      let urls = try? FileManager.default.contentsOfDirectory(
+        at: URL(fileURLWithPath: cacheDirectory), includingPropertiesForKeys: keys)
+    else { return [] }
+    return urls.filter { $0.pathExtension == "png" }.map { url in
+      let values = try? url.resourceValues(forKeys: Set(keys))
+      return CacheEntry(
+        url: url,
+        size: Int64(values?.fileSize ?? 0),
+        lastUsed: values?.contentAccessDate ?? values?.contentModificationDate ?? .distantPast
+      )
+    }
   }
 
   public func ensureCacheDirectory() throws {
@@ -22,24 +187,79 @@ public struct IconCacheManager: Sendable {
     }
   }
 
-  /// キャッシュ PNG のピクセルサイズ（Retina 対応のため 128px）
-  private static let cachePixelSize = 128
-
+  /// アイコンを `outputSizes` の各サイズの PNG としてキャッシュし、128px のパスを返す。
+  ///
+  /// 外部コマンド（sips 等）は使わず、NSImage でプロセス内描画する。
+  /// ソース画像の読み込みは未生成のサイズがある場合のみ 1 回だけ行う。
   public func cacheIcon(from icnsPath: String, for appPath: String) throws -> String {
+    try cacheIcon(for: appPath, sourcePath: icnsPath) {
+      NSImage(contentsOfFile: icnsPath)
+    }
+  }
+
+  /// アセットカタログ（Assets.car）に格納されたアプリアイコンをキャッシュし、128px のパスを返す。
+  ///
+  /// `CFBundleIconFile` を持たないアプリ向け。アセットカタログの解析は
+  /// `NSWorkspace.icon(forFile:)` に任せ、外部コマンド（iconutil / actool）は使わない。
+  public func cacheAssetCatalogIcon(for appPath: String) throws -> String {
+    try cacheIcon(for: appPath, sourcePath: appPath) {
+      NSWorkspace.shared.icon(forFile: appPath)
+    }
+  }
+
+  /// 変換元（`AppItem.iconSourcePath`）からアイコンをキャッシュし、128px のパスを返す。
+  ///
+  /// `.icns` は `cacheIcon(from:for:)`、それ以外（アセットカタログのみのアプリ・変換元が不明な
+  /// 場合）は `cacheAssetCatalogIcon(for:)` で描画する。生成済みのサイズは作り直さない。
+  public func cacheIcon(forApp appPath: String, sourcePath: String?) throws -> String {
+    if let sourcePath, sourcePath.hasSuffix(".icns") {
+      return try cacheIcon(from: sourcePath, for: appPath)
+    }
+    return try cacheAssetCatalogIcon(for: appPath)
+  }
+
+  /// 変換済みの 128px のアイコンがあればそのパスを返す。
+  public func existingIconPath(for appPath: String) -> String? {
+    let path = cachedIconPath(for: appPath)
+    return FileManager.default.fileExists(atPath: path) ? path : nil
+  }
+
+  private func cacheIcon(
+    for appPath: String,
+    sourcePath icnsPath: String,
+    loadImage: () -> NSImage?
+  ) throws -> String {
     let outputPath = cachedIconPath(for: appPath)
+    let missingSizes = outputSizes.filter {
+      !FileManager.default.fileExists(atPath: cachedIconPath(for: appPath, size: $0))
+    }
 
-    if FileManager.default.fileExists(atPath: outputPath) {
+    if missingSizes.isEmpty {
       return outputPath
     }
 
     try ensureCacheDirectory()
 
-    guard let image = NSImage(contentsOfFile: icnsPath) else {
+    guard let image = loadImage() else {
+      // 128px が既にあれば追加サイズは諦めて既存キャッシュを使う
+      if FileManager.default.fileExists(atPath: outputPath) {
+        return outputPath
+      }
       throw IconCacheError.failedToLoadImage(icnsPath)
     }
 
-    // 高解像度: 128x128 ピクセルで描画してキャッシュ
-    let px = Self.cachePixelSize
+    for size in missingSizes {
+      let pngData = try Self.renderPNG(image, pixelSize: size.rawValue, sourcePath: icnsPath)
+      // 自動更新スキャンと手動再構築が重なった場合に同じパスへ並行書き込みが発生し得るため、
+      // 一時ファイル + リネームで原子的に書き込み、中途半端な PNG が残らないようにする。
+      let path = cachedIconPath(for: appPath, size: size)
+      try pngData.write(to: URL(fileURLWithPath: path), options: .atomic)
+    }
+    return outputPath
+  }
+
+  /// 画像を指定ピクセルサイズの正方形 PNG に描画する。
+  static func renderPNG(_ image: NSImage, pixelSize px: Int, sourcePath: String) throws -> Data {
     let bitmap = NSBitmapImageRep(
       bitmapDataPlanes: nil,
       pixelsWide: px,
@@ -54,7 +274,7 @@ public struct IconCacheManager: Sendable {
     )
 
     guard let bitmap else {
-      throw IconCacheError.failedToConvertToPNG(icnsPath)
+      throw IconCacheError.failedToConvertToPNG(sourcePath)
     }
 
     bitmap.size = NSSize(width: px, height: px)
@@ -70,13 +290,9 @@ public struct IconCacheManager: Sendable {
     NSGraphicsContext.restoreGraphicsState()
 
     guard let pngData = bitmap.representation(using: .png, properties: [:]) else {
-      throw IconCacheError.failedToConvertToPNG(icnsPath)
+      throw IconCacheError.failedToConvertToPNG(sourcePath)
     }
-
-    // 自動更新スキャンと手動再構築が重なった場合に同じパスへ並行書き込みが発生し得るため、
-    // 一時ファイル + リネームで原子的に書き込み、中途半端な PNG が残らないようにする。
-    try pngData.write(to: URL(fileURLWithPath: outputPath), options: .atomic)
-    return outputPath
+    return pngData
   }
 }
 
@@ -0,0 +1,95 @@
+import Foundation
+
+/// アプリのアイコンを表示時に PNG へ変換するワーカープール。
+///
+/// スキャンでは変換元（`AppItem.iconSourcePath`）だけを記録し、変換はここで必要になった
+/// ものから行う。同時に変換する数を `maxConcurrency` までに抑え、同じアプリへの変換要求は
+/// 1 回の変換にまとめる。
+public actor IconConversionQueue {
+  private static let logger = AppLogger(category: "IconConversion")
+
+  private let iconCacheManager: IconCacheManager
+  /// 同時に変換する最大数
+  public let maxConcurrency: Int
+
+  private var runningCount = 0
+  private var waiters: [CheckedContinuation<Void, Never>] = []
+  private var inFlight: [String: Task<String?, Never>] = [:]
+
+  /// - Parameters:
+  ///   - iconCacheManager: 変換した PNG を書き込むアイコンキャッシュ
+  ///   - maxConcurrency: 同時に変換する最大数（既定は CPU コア数の半分。検索中の描画を妨げない）
+  public init(
+    iconCacheManager: IconCacheManager,
+    maxConcurrency: Int = max(1, ProcessInfo.processInfo.activeProcessorCount / 2)
+  ) {
+    self.iconCacheManager = iconCacheManager
+    self.maxConcurrency = max(1, maxConcurrency)
+  }
+
+  /// アプリのアイコンを変換済みにし、128px の PNG のパスを返す（変換できない場合は nil）。
+  ///
+  /// - Parameters:
+  ///   - appPath: アプリのパス
+  ///   - sourcePath: 変換元（nil の場合は Finder と同じアイコンを使う）
+  public func ensureIcon(appPath: String, sourcePath: String?) async -> String? {
+    if let task = inFlight[appPath] {
+      return await task.value
+    }
+    let task = Task { await convert(appPath: appPath, sourcePath: sourcePath) }
+    inFlight[appPath] = task
+    let iconPath = await task.value
+    inFlight[appPath] = nil
+    return iconPath
+  }
+
+  /// 複数のアプリのアイコンを同時実行数の上限内で変換する。
+  ///
+  /// - Returns: 変換できたアプリのパス → 128px の PNG のパス
+  public func prefetch(_ apps: [AppItem]) async -> [String: String] {
+    await withTaskGroup(of: (String, String?).self) { group in
+      for app in apps {
+        group.addTask {
+          (app.path, await self.ensureIcon(appPath: app.path, sourcePath: app.iconSourcePath))
+        }
+      }
+      var iconPaths: [String: String] = [:]
+      for await (appPath, iconPath) in group {
+        iconPaths[appPath] = iconPath
+      }
+      return iconPaths
+    }
+  }
+
+  private func convert(appPath: String, sourcePath: String?) async -> String? {
+    await acquireSlot()
+    defer { releaseSlot() }
+    let iconCacheManager = iconCacheManager
+    do {
+      return try await Task.detached(priority: .utility) {
+        try iconCacheManager.cacheIcon(forApp: appPath, sourcePath: sourcePath)
+      }.value
+    } catch {
+      Self.logger.warning("Failed to convert icon for \(appPath): \(error.localizedDescription)")
+      return nil
+    }
+  }
+
+  /// 変換の枠が空くまで待つ。
+  private func acquireSlot() async {
+    guard runningCount >= maxConcurrency else {
+      runningCount += 1
+      return
+    }
+    await withCheckedContinuation { waiters.append($0) }
+  }
+
+  /// 変換の枠を返し、待っている変換があれば枠をそのまま渡す。
+  private func releaseSlot() {
+    if waiters.isEmpty {
+      runningCount -= 1
+    } else {
+      waiters.removeFirst().resume()
+    }
+  }
+}
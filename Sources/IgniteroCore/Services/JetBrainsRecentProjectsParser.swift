@@ -0,0 +1,112 @@
+import Foundation
+
+/// JetBrains の IDE（IntelliJ IDEA・PyCharm・WebStorm）の `options/recentProjects.xml` から
+/// 最近開いたプロジェクトのパスを取り出すパーサー。
+///
+/// `RecentProjectsManager` の `additionalInfo` の `entry`（2020 以降の形式）と、旧形式の
+/// `recentPaths` の一覧を読み、`projectOpenTimestamp` があれば新しい順に並べる。
+/// パス中の `$USER_HOME$` はホームディレクトリに置き換える。
+public enum JetBrainsRecentProjectsParser {
+  /// `~/Library/Application Support/JetBrains`
+  public static let defaultConfigRoot = NSString(
+    string: "~/Library/Application Support/JetBrains"
+  ).expandingTildeInPath
+
+  /// 最近開いたプロジェクトのパスを新しい順に返す（重複なし、解析できない場合は空）。
+  public static func projectPaths(
+    in data: Data, homeDirectory: String = NSHomeDirectory()
+  ) -> [String] {
+    let delegate = RecentProjectsXMLDelegate()
+    let parser = XMLParser(data: data)
+    parser.delegate = delegate
+    guard parser.parse() else { return [] }
+    var seen = Set<String>()
+    return delegate.projects.enumerated()
+      // タイムスタンプのない項目は記述順のまま後ろに並べる
+      .sorted { lhs, rhs in
+        let (left, right) = (lhs.element.timestamp ?? 0, rhs.element.timestamp ?? 0)
+        return left != right ? left > right : lhs.offset < rhs.offset
+      }
+      .map { $0.element.path.replacingOccurrences(of: "$USER_HOME$", with: homeDirectory) }
+      .filter { seen.insert($0).inserted }
+  }
+
+  /// 設定フォルダのうち、接頭辞に一致する最も新しいバージョンのフォルダを返す。
+  ///
+  /// `IntelliJIdea2024.3` と `IdeaIC2025.1` のように接頭辞が異なる場合もバージョンで比べる。
+  public static func latestConfigDirectory(in root: String, prefixes: [String]) -> String? {
+    let names = (try? FileManager.default.contentsOfDirectory(atPath: root)) ?? []
+    let candidates = names.compactMap { name -> (name: String, version: String)? in
+      for prefix in prefixes where name.hasPrefix(prefix) {
+        let version = String(name.dropFirst(prefix.count))
+        // `PyCharm` が `PyCharmCE2024.3` に一致しないよう、接頭辞の直後は数字に限る
+        if version.first?.isNumber == true {
+          return (name, version)
+        }
+      }
+      return nil
+    }
+    let latest = candidates.max { lhs, rhs in
+      lhs.version.compare(rhs.version, options: .numeric) == .orderedAscending
+    }
+    return latest.map { (root as NSString).appendingPathComponent($0.name) }
+  }
+}
+
+// MARK: - XML の解析
+
+/// `recentProjects.xml` の要素を順に読み、プロジェクトのパスとタイムスタンプを集める。
+private final class RecentProjectsXMLDelegate: NSObject, XMLParserDelegate {
+  private(set) var projects: [(path: String, timestamp: Int64?)] = []
+  /// 開いている `option` 要素の name 属性（name がない要素は空文字）
+  private var optionNames: [String] = []
+  /// 読み込み中の `additionalInfo` の entry の位置
+  private var currentEntry: Int?
+  /// 開いている `entry` 要素の数（プロジェクトの情報の中の entry を除くため）
+  private var entryDepth = 0
+
+  func parser(
+    _ parser: XMLParser, didStartElement elementName: String, namespaceURI: String?,
+    qualifiedName: String?, attributes: [String: String] = [:]
+  ) {
+    switch elementName {
+    case "entry":
+      entryDepth += 1
+      guard entryDepth == 1, optionNames.contains("additionalInfo"), let key = attributes["key"]
+      else { return }
+      currentEntry = projects.count
+      projects.append((key, nil))
+    case "option":
+      let name = attributes["name"] ?? ""
+      if name == "projectOpenTimestamp", let currentEntry,
+        let value = attributes["value"].flatMap(Int64.init)
+      {
+        projects[currentEntry].timestamp = value
+      } else if name.isEmpty, optionNames.contains("recentPaths"),
+        let value = attributes["value"]
+      {
+        projects.append((value, nil))
+      }
+      optionNames.append(name)
+    default:
+      break
+    }
+  }
+
+  func parser(
+    _ parser: XMLParser, didEndElement elementName: String, namespaceURI: String?,
+    qualifiedName: String?
+  ) {
+    switch elementName {
+    case "entry":
+      entryDepth -= 1
+      if entryDepth == 0 {
+        currentEntry = nil
+      }
+    case "option":
+      _ = optionNames.popLast()
+    default:
+      break
+    }
+  }
+}
@@ -1,6 +1,5 @@
 import AppKit
 import Foundation
-import os
 
 // MARK: - Launching プロトコル
 
@@ -13,12 +12,25 @@ public protocol Launching: Sendable {
   ) async throws
   func availableEditors() -> [EditorInfo]
   func availableTerminals() -> [TerminalInfo]
+  func openURL(_ url: URL) async throws
+  func openFile(_ path: String, applicationURL: URL?) async throws
+}
+
+extension Launching {
+  public func openURL(_ url: URL) async throws {
+    guard LaunchService.canOpen(url) else { throw LaunchError.noApplicationForURL(url) }
+    _ = await MainActor.run { NSWorkspace.shared.open(url) }
+  }
+
+  public func openFile(_ path: String, applicationURL: URL?) async throws {
+    try await openURL(URL(fileURLWithPath: path))
+  }
 }
 
 // MARK: - LaunchService 本体
 
 public struct LaunchService: Launching, Sendable {
-  private static let logger = Logger(subsystem: "com.ignitero.launcher", category: "Launch")
+  private static let logger = AppLogger(category: "Launch")
   private static let commandScriptPrefix = "ignitero-"
   private static let commandScriptExtension = "command"
   private static let staleCommandScriptTTL: TimeInterval = 5 * 60
@@ -47,6 +59,10 @@ public struct LaunchService: Launching, Sendable {
     case .vscode: "Visual Studio Code.app"
     case .antigravity: "Antigravity.app"
     case .zed: "Zed.app"
+    case .intellij: "IntelliJ IDEA.app"
+    case .pycharm: "PyCharm.app"
+    case .webstorm: "WebStorm.app"
+    case .xcode: "Xcode.app"
     }
   }
 
@@ -57,6 +73,10 @@ public struct LaunchService: Launching, Sendable {
     case .vscode: "Visual Studio Code"
     case .antigravity: "Antigravity"
     case .zed: "Zed"
+    case .intellij: "IntelliJ IDEA"
+    case .pycharm: "PyCharm"
+    case .webstorm: "WebStorm"
+    case .xcode: "Xcode"
     }
   }
 
@@ -85,7 +105,25 @@ public struct LaunchService: Launching, Sendable {
   // MARK: - アプリケーションパス
 
   public static func applicationPath(for editor: EditorType) -> String {
-    "/Applications/\(appName(for: editor))"
+    let defaultPath = "/Applications/\(appName(for: editor))"
+    guard !editor.jetBrainsConfigPrefixes.isEmpty else { return defaultPath }
+    // JetBrains の IDE は Community 版の名前や、Toolbox による ~/Applications への
+    // インストールもあるため、存在するものを探す
+    let names = [appName(for: editor)] + communityAppNames(for: editor)
+    let directories = ["/Applications", NSString(string: "~/Applications").expandingTildeInPath]
+    let fm = FileManager.default
+    return directories.lazy
+      .flatMap { directory in names.map { "\(directory)/\($0)" } }
+      .first { fm.fileExists(atPath: $0) } ?? defaultPath
+  }
+
+  /// JetBrains の IDE の Community 版のアプリ名
+  static func communityAppNames(for editor: EditorType) -> [String] {
+    switch editor {
+    case .intellij: ["IntelliJ IDEA CE.app"]
+    case .pycharm: ["PyCharm CE.app"]
+    default: []
+    }
   }
 
   public static func applicationPath(for terminal: TerminalType) -> String {
@@ -103,18 +141,6 @@ public struct LaunchService: Launching, Sendable {
     }
   }
 
-  // MARK: - ワークスペース検出
-
-  private func findWorkspaceFile(in directoryPath: String) -> String? {
-    let fm = FileManager.default
-    let normalized = Self.normalizedDirectoryPath(directoryPath)
-    guard let contents = try? fm.contentsOfDirectory(atPath: normalized) else {
-      return nil
-    }
-    return contents.first { $0.hasSuffix(".code-workspace") }
-      .map { (normalized as NSString).appendingPathComponent($0) }
-  }
-
   // MARK: - AppleScript 生成
 
   public static func appleScript(
@@ -255,13 +281,127 @@ public struct LaunchService: Launching, Sendable {
         removedCount += 1
       } catch {
         Self.logger.debug(
-          "Failed to remove stale command script: \(fileURL.path, privacy: .public)")
+          "Failed to remove stale command script: \(fileURL.path)")
       }
     }
 
     return removedCount
   }
 
+  // MARK: - URL
+
+  /// 常に開けるとみなすスキーム（ハンドラの確認を省略する）
+  private static let wellKnownSchemes: Set<String> = ["http", "https", "mailto"]
+
+  /// 開くことを許可しないスキーム（ローカルファイルの実行やスクリプト注入を防ぐ）
+  private static let blockedSchemes: Set<String> = ["file", "javascript", "data"]
+
+  /// 入力文字列を開ける URL として解釈する。
+  ///
+  /// `https://`・`mailto:` のほか、ハンドラとなるアプリが登録されているカスタムスキーム
+  /// （`slack://` や `vscode://` など）を受け付ける。`www.` で始まる文字列と
+  /// `localhost:3000` のようなホスト:ポート形式は `https://`・`http://` を補う。
+  /// - Parameters:
+  ///   - text: 入力文字列（前後の空白は無視する）
+  ///   - canOpen: カスタムスキームの URL を開けるかの判定（テスト時に差し替え可能）
+  /// - Returns: 開ける URL。URL でない場合や開けない場合は nil
+  public static func validatedURL(
+    from text: String,
+    canOpen: (URL) -> Bool = LaunchService.canOpen
+  ) -> URL? {
+    let trimmed = text.trimmingCharacters(in: .whitespacesAndNewlines)
+    guard !trimmed.isEmpty, !trimmed.contains(where: \.isWhitespace) else { return nil }
+
+    let candidate: String
+    if trimmed.lowercased().hasPrefix("www.") {
+      candidate = "https://" + trimmed
+    } else if trimmed.range(
+      of: #"^(localhost|[a-z0-9-]+(\.[a-z0-9-]+)+):\d+(/.*)?$"#,
+      options: [.regularExpression, .caseInsensitive]) != nil
+    {
+      candidate = "http://" + trimmed
+    } else {
+      candidate = trimmed
+    }
+
+    guard let url = URL(string: candidate), let scheme = url.scheme?.lowercased(),
+      scheme.range(of: #"^[a-z][a-z0-9+.-]*$"#, options: .regularExpression) != nil,
+      !blockedSchemes.contains(scheme)
+    else { return nil }
+
+    switch scheme {
+    case "http", "https":
+      guard let host = url.host, !host.isEmpty else { return nil }
+    case "mailto":
+      guard candidate.count > "mailto:".count else { return nil }
+    default:
+      // `scheme:` だけの入力は URL とみなさない
+      guard candidate.count > scheme.count + 1 else { return nil }
+    }
+    return wellKnownSchemes.contains(scheme) || canOpen(url) ? url : nil
+  }
+
+  /// URL を開けるアプリが登録されているか
+  public static func canOpen(_ url: URL) -> Bool {
+    NSWorkspace.shared.urlForApplication(toOpen: url) != nil
+  }
+
+  /// URL を既定のアプリで開く。
+  ///
+  /// - Throws: 開けるアプリがない場合は `LaunchError.noApplicationForURL`
+  public func openURL(_ url: URL) async throws {
+    guard Self.canOpen(url) else { throw LaunchError.noApplicationForURL(url) }
+    let config = NSWorkspace.OpenConfiguration()
+    _ = try await NSWorkspace.shared.open(url, configuration: config)
+  }
+
+  /// ファイルを指定したアプリで開く。
+  ///
+  /// アプリが指定されていない場合は macOS の既定のアプリで開く。
+  /// - Parameters:
+  ///   - path: ファイルのパス
+  ///   - applicationURL: 開くアプリの場所（`applicationURL(for:)` で解決したもの）
+  public func openFile(_ path: String, applicationURL: URL?) async throws {
+    let url = URL(fileURLWithPath: path)
+    guard let applicationURL else {
+      try await openURL(url)
+      return
+    }
+    let config = NSWorkspace.OpenConfiguration()
+    _ = try await NSWorkspace.shared.open(
+      [url], withApplicationAt: applicationURL, configuration: config)
+  }
+
+  /// ファイルを開くアプリの指定からアプリの場所を探す。
+  ///
+  /// `.app` のパス → バンドル ID → アプリ名（`/Applications` などの標準の場所）の順に解決する。
+  /// ファイルシステムを調べるため、メインアクターでは呼ばずにバックグラウンドで解決してから使う。
+  static func applicationURL(for application: String) -> URL? {
+    let name = application.trimmingCharacters(in: .whitespaces)
+    guard !name.isEmpty else { return nil }
+    let fm = FileManager.default
+    let expanded = (name as NSString).expandingTildeInPath
+    if expanded.hasPrefix("/") {
+      return fm.fileExists(atPath: expanded) ? URL(fileURLWithPath: expanded) : nil
+    }
+    if let url = NSWorkspace.shared.urlForApplication(withBundleIdentifier: name) {
+      return url
+    }
+    let bundleName = name.lowercased().hasSuffix(".app") ? name : name + ".app"
+    let directories = [
+      "/Applications",
+      "/System/Applications",
+      "/Applications/Utilities",
+      "/System/Applications/Utilities",
+      (NSHomeDirectory() as NSString).appendingPathComponent("Applications"),
+    ]
+    return
+      directories
+      .map { ($0 as NSString).appendingPathComponent(bundleName) }
+      .first { fm.fileExists(atPath: $0) }
+      .map { URL(fileURLWithPath: $0) }
+  }
+
   // MARK: - 実行処理
 
   public func launchApp(at path: String) async throws {
@@ -281,10 +421,13 @@ public struct LaunchService: Launching, Sendable {
         throw LaunchError.editorNotFound(editor)
       }
 
-      // .code-workspace ファイルがあればそちらを開く（Zed は非対応のためディレクトリを直接開く）
+      // .code-workspace や Xcode のプロジェクトがあればそちらを開く
+      // （検出器のないエディタはディレクトリを直接開く）
       let targetURL: URL
-      if editor.supportsCodeWorkspace, let workspacePath = findWorkspaceFile(in: path) {
-        targetURL = URL(fileURLWithPath: workspacePath)
+      if let detector = editor.projectFileDetector,
+        let projectPath = detector.detect(inDirectory: Self.normalizedDirectoryPath(path))
+      {
+        targetURL = URL(fileURLWithPath: projectPath)
       } else {
         targetURL = URL(fileURLWithPath: path)
       }
@@ -344,7 +487,7 @@ public struct LaunchService: Launching, Sendable {
         case .ghostty:
           // Ghostty は設定やバージョン差分で AppleScript が無効な場合があるため .command 方式にフォールバックする。
           Self.logger.debug(
-            "Ghostty AppleScript failed. Falling back to command script: \(error.localizedDescription, privacy: .public)"
+            "Ghostty AppleScript failed. Falling back to command script: \(error.localizedDescription)"
           )
           try await Self.executeCommandViaCommandScript(
             command,
@@ -354,7 +497,7 @@ public struct LaunchService: Launching, Sendable {
         case .cmux:
           // cmux は古いバージョンや設定差分を考慮し、既存の CLI 実行へフォールバックする。
           Self.logger.debug(
-            "cmux AppleScript failed. Falling back to CLI: \(error.localizedDescription, privacy: .public)"
+            "cmux AppleScript failed. Falling back to CLI: \(error.localizedDescription)"
           )
           try await Self.executeCommandViaCmuxCLI(
             command,
@@ -395,7 +538,7 @@ public struct LaunchService: Launching, Sendable {
           "osascript exited with status \(process.terminationStatus)"
         }
       Self.logger.error(
-        "AppleScript execution failed (\(terminal.rawValue), status: \(process.terminationStatus)): \(message, privacy: .public)"
+        "AppleScript execution failed (\(terminal.rawValue), status: \(process.terminationStatus)): \(message)"
       )
       throw LaunchError.scriptExecutionFailed(message)
     }
@@ -507,7 +650,7 @@ public struct LaunchService: Launching, Sendable {
     do {
       try process.run()
     } catch {
-      logger.debug("cmux ping failed to launch: \(error.localizedDescription, privacy: .public)")
+      logger.debug("cmux ping failed to launch: \(error.localizedDescription)")
       return false
     }
 
@@ -559,7 +702,7 @@ public struct LaunchService: Launching, Sendable {
         } else {
           "cmux CLI exited with status \(process.terminationStatus)"
         }
-      logger.error("cmux CLI failed: \(message, privacy: .public)")
+      logger.error("cmux CLI failed: \(message)")
       throw LaunchError.scriptExecutionFailed(message)
     }
     return String(data: stdoutData, encoding: .utf8)?
@@ -606,7 +749,7 @@ public struct LaunchService: Launching, Sendable {
       try activateProcess.run()
     } catch {
       logger.debug(
-        "cmux activation failed to launch: \(error.localizedDescription, privacy: .public)")
+        "cmux activation failed to launch: \(error.localizedDescription)")
       return
     }
 
@@ -667,4 +810,5 @@ public enum LaunchError: Error, Sendable {
   case editorNotFound(EditorType)
   case terminalNotFound(TerminalType)
   case scriptExecutionFailed(String)
+  case noApplicationForURL(URL)
 }
@@ -0,0 +1,102 @@
+import Foundation
+import ServiceManagement
+
+public protocol LoginItemControlling: Sendable {
+  func isEnabled() -> Bool
+  func setEnabled(_ enabled: Bool) throws
+}
+
+/// ログイン時起動の登録/解除を行うサービス。
+///
+/// `SMAppService.mainApp` での登録を優先し、失敗した場合（.app バンドル外から
+/// 実行している場合など）は `~/Library/LaunchAgents` への plist 配置にフォールバックする。
+public struct LoginItemService: LoginItemControlling, Sendable {
+  private static let logger = AppLogger(category: "LoginItem")
+
+  /// LaunchAgent のラベル（plist ファイル名にも使用）
+  public let label: String
+
+  private let launchAgentsDirectory: URL
+  private let executablePath: String
+  private let preferLaunchAgent: Bool
+
+  /// LoginItemService を初期化する。
+  ///
+  /// - Parameters:
+  ///   - launchAgentsDirectory: LaunchAgent plist の配置先（テスト時に差し替え可能）
+  ///   - executablePath: ログイン時に起動する実行ファイルのパス
+  ///   - preferLaunchAgent: `true` の場合は SMAppService を使わず常に LaunchAgent を使う
+  public init(
+    launchAgentsDirectory: URL? = nil,
+    executablePath: String? = nil,
+    preferLaunchAgent: Bool = false
+  ) {
+    self.label = Bundle.main.bundleIdentifier ?? "com.owayo.ignitero.launcher"
+    self.launchAgentsDirectory =
+      launchAgentsDirectory
+      ?? FileManager.default.homeDirectoryForCurrentUser
+      .appendingPathComponent("Library/LaunchAgents")
+    self.executablePath = executablePath ?? Bundle.main.executablePath ?? ""
+    self.preferLaunchAgent = preferLaunchAgent
+  }
+
+  /// LaunchAgent plist のパス
+  public var launchAgentURL: URL {
+    launchAgentsDirectory.appendingPathComponent("\(label).plist")
+  }
+
+  public func isEnabled() -> Bool {
+    if FileManager.default.fileExists(atPath: launchAgentURL.path) {
+      return true
+    }
+    guard !preferLaunchAgent else { return false }
+    return SMAppService.mainApp.status == .enabled
+  }
+
+  public func setEnabled(_ enabled: Bool) throws {
+    if enabled {
+      if !preferLaunchAgent {
+        do {
+          try SMAppService.mainApp.register()
+          // 二重起動を防ぐため、過去のフォールバック plist は削除する
+          try removeLaunchAgent()
+          return
+        } catch {
+          Self.logger.error(
+            "SMAppService register failed, falling back to LaunchAgent: \(error.localizedDescription)"
+          )
+        }
+      }
+      try writeLaunchAgent()
+    } else {
+      if !preferLaunchAgent, SMAppService.mainApp.status == .enabled {
+        try SMAppService.mainApp.unregister()
+      }
+      try removeLaunchAgent()
+    }
+  }
+
+  // MARK: - LaunchAgent
+
+  private func writeLaunchAgent() throws {
+    let fm = FileManager.default
+    if !fm.fileExists(atPath: launchAgentsDirectory.path) {
+      try fm.createDirectory(at: launchAgentsDirectory, withIntermediateDirectories: true)
+    }
+    let plist: [String: Any] = [
+      "Label": label,
+      "ProgramArguments": [executablePath],
+      "RunAtLoad": true,
+      "ProcessType": "Interactive",
+    ]
+    let data = try PropertyListSerialization.data(
+      fromPropertyList: plist, format: .xml, options: 0)
+    try data.write(to: launchAgentURL, options: .atomic)
+  }
+
+  private func removeLaunchAgent() throws {
+    let fm = FileManager.default
+    guard fm.fileExists(atPath: launchAgentURL.path) else { return }
+    try fm.removeItem(at: launchAgentURL)
+  }
+}
@@ -0,0 +1,192 @@
+import CoreGraphics
+import Foundation
+
+// MARK: - ModifierDoubleTapDetector
+
+/// 修飾キーの 2 回押し（`double:cmd` など）を判定する。
+///
+/// 修飾キーを 1 つだけ押して `interval` 以内に離し、離してから `interval` 以内に
+/// もう一度押したときに成立する。間に他のキーを押した場合（`cmd+c` など）や、
+/// 他の修飾キーと同時に押した場合は数え直す。
+public struct ModifierDoubleTapDetector: Sendable {
+  /// 押してから離すまで・離してから 2 回目を押すまでの既定の最大間隔
+  public static let defaultInterval: Duration = .milliseconds(350)
+
+  private let interval: Duration
+  /// 単独で押している修飾キーと押した時刻
+  private var pressed: (modifier: HotkeyModifier, at: ContinuousClock.Instant)?
+  /// 直前に単独で押して離した修飾キーと離した時刻
+  private var released: (modifier: HotkeyModifier, at: ContinuousClock.Instant)?
+
+  public init(interval: Duration = defaultInterval) {
+    self.interval = interval
+  }
+
+  /// 押している修飾キーが変わったときに呼ぶ。
+  ///
+  /// - Returns: 2 回押しが成立した場合はその修飾キー
+  public mutating func modifiersChanged(
+    _ modifiers: Set<HotkeyModifier>, at now: ContinuousClock.Instant
+  ) -> HotkeyModifier? {
+    if modifiers.isEmpty {
+      if let pressed, now - pressed.at <= interval {
+        released = (pressed.modifier, now)
+      } else {
+        released = nil
+      }
+      pressed = nil
+      return nil
+    }
+    guard modifiers.count == 1, let modifier = modifiers.first, pressed == nil else {
+      pressed = nil
+      released = nil
+      return nil
+    }
+    if let released, released.modifier == modifier, now - released.at <= interval {
+      self.released = nil
+      return modifier
+    }
+    pressed = (modifier, now)
+    released = nil
+    return nil
+  }
+
+  /// 修飾キー以外のキーを押したときに呼ぶ。
+  public mutating func keyPressed() {
+    pressed = nil
+    released = nil
+  }
+}
+
+extension HotkeyModifier {
+  /// イベントのフラグから押している修飾キーを取り出す。
+  static func modifiers(in flags: CGEventFlags) -> Set<HotkeyModifier> {
+    var modifiers: Set<HotkeyModifier> = []
+    if flags.contains(.maskCommand) { modifiers.insert(.command) }
+    if flags.contains(.maskShift) { modifiers.insert(.shift) }
+    if flags.contains(.maskAlternate) { modifiers.insert(.option) }
+    if flags.contains(.maskControl) { modifiers.insert(.control) }
+    return modifiers
+  }
+}
+
+// MARK: - ModifierTapMonitoring
+
+@MainActor
+public protocol ModifierTapMonitoring: AnyObject {
+  /// 修飾キーの 2 回押しの監視を始める（監視中なら対象を差し替える）。
+  ///
+  /// - Returns: イベントタップを作れなかった場合（入力監視の権限がないなど）は false
+  func start(
+    modifiers: Set<HotkeyModifier>, onDoubleTap: @escaping @MainActor (HotkeyModifier) -> Void
+  ) -> Bool
+  /// 監視をやめる。
+  func stop()
+}
+
+// MARK: - Event Tap C Callback
+
+/// イベントタップのコールバック。`@convention(c)` 互換のトップレベル関数。
+///
+/// タップはメインスレッドのランループに登録するため、MainActor 上で呼ばれる。
+private func modifierTapCallback(
+  _: CGEventTapProxy,
+  type: CGEventType,
+  event: CGEvent,
+  refcon: UnsafeMutableRawPointer?
+) -> Unmanaged<CGEvent>? {
+  if let refcon {
+    let monitor = Unmanaged<ModifierTapMonitor>.fromOpaque(refcon).takeUnretainedValue()
+    let flags = event.flags
+    MainActor.assumeIsolated {
+      monitor.handle(type: type, flags: flags)
+    }
+  }
+  return Unmanaged.passUnretained(event)
+}
+
+// MARK: - ModifierTapMonitor
+
+/// `CGEvent.tapCreate` の読み取り専用のイベントタップで修飾キーの 2 回押しを検出する。
+///
+/// Carbon の `RegisterEventHotKey` は修飾キーだけの組み合わせを登録できないため、
+/// `double:cmd` などのショートカットはキーボード配列に依らずキーイベントを直接見て判定する。
+/// イベントタップにはシステム設定の「入力監視」（またはアクセシビリティ）の許可が要る。
+@MainActor
+public final class ModifierTapMonitor: ModifierTapMonitoring {
+  private static let logger = AppLogger(category: "ModifierTap")
+
+  private var tap: CFMachPort?
+  private var runLoopSource: CFRunLoopSource?
+  private var detector = ModifierDoubleTapDetector()
+  private var modifiers: Set<HotkeyModifier> = []
+  private var onDoubleTap: (@MainActor (HotkeyModifier) -> Void)?
+
+  public init() {}
+
+  public func start(
+    modifiers: Set<HotkeyModifier>, onDoubleTap: @escaping @MainActor (HotkeyModifier) -> Void
+  ) -> Bool {
+    self.modifiers = modifiers
+    self.onDoubleTap = onDoubleTap
+    detector = ModifierDoubleTapDetector()
+    guard tap == nil else { return true }
+
+    let mask =
+      CGEventMask(1 << CGEventType.flagsChanged.rawValue)
+      | CGEventMask(1 << CGEventType.keyDown.rawValue)
+    guard
+      let tap = CGEvent.tapCreate(
+        tap: .cgSessionEventTap,
+        place: .headInsertEventTap,
+        options: .listenOnly,
+        eventsOfInterest: mask,
+        callback: modifierTapCallback,
+        userInfo: Unmanaged.passUnretained(self).toOpaque()
+      )
+    else {
+      Self.logger.error("Failed to create event tap; input monitoring may not be permitted")
+      return false
+    }
+    let source = CFMachPortCreateRunLoopSource(kCFAllocatorDefault, tap, 0)
+    CFRunLoopAddSource(CFRunLoopGetMain(), source, .commonModes)
+    CGEvent.tapEnable(tap: tap, enable: true)
+    self.tap = tap
+    runLoopSource = source
+    Self.logger.info("Started modifier tap monitor")
+    return true
+  }
+
+  public func stop() {
+    if let runLoopSource {
+      CFRunLoopRemoveSource(CFRunLoopGetMain(), runLoopSource, .commonModes)
+    }
+    if let tap {
+      CGEvent.tapEnable(tap: tap, enable: false)
+      CFMachPortInvalidate(tap)
+    }
+    tap = nil
+    runLoopSource = nil
+    onDoubleTap = nil
+  }
+
+  fileprivate func handle(type: CGEventType, flags: CGEventFlags) {
+    switch type {
+    case .tapDisabledByTimeout, .tapDisabledByUserInput:
+      // 処理が遅れてシステムに無効化された場合は有効にし直す
+      if let tap {
+        CGEvent.tapEnable(tap: tap, enable: true)
+      }
+    case .keyDown:
+      detector.keyPressed()
+    case .flagsChanged:
+      let pressed = HotkeyModifier.modifiers(in: flags)
+      guard let modifier = detector.modifiersChanged(pressed, at: .now),
+        modifiers.contains(modifier)
+      else { return }
+      onDoubleTap?(modifier)
+    default:
+      break
+    }
+  }
+}
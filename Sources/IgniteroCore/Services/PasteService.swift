@@ -0,0 +1,90 @@
+import AppKit
+import ApplicationServices
+import Carbon.HIToolbox
+import Foundation
+
+// MARK: - PasteError
+
+/// 貼り付けのエラー
+public enum PasteError: Error, LocalizedError, Equatable {
+  /// キー入力を送るためのアクセシビリティ権限がない
+  case accessibilityNotTrusted
+  /// キーボードイベントを作成できなかった
+  case eventCreationFailed
+
+  public var errorDescription: String? {
+    switch self {
+    case .accessibilityNotTrusted: "Accessibility permission is required to paste"
+    case .eventCreationFailed: "Failed to create keyboard events"
+    }
+  }
+}
+
+// MARK: - Pasting プロトコル
+
+public protocol Pasting: Sendable {
+  /// テキストをクリップボードに置き、指定したアプリを前面に戻して ⌘V を送る。
+  ///
+  /// - Parameters:
+  ///   - text: 貼り付けるテキスト
+  ///   - processIdentifier: 貼り付け先アプリのプロセス ID（nil なら現在の最前面アプリ）
+  func paste(_ text: String, into processIdentifier: pid_t?) async throws
+}
+
+// MARK: - PasteService 本体
+
+/// 選択した結果のパスやコマンドを、ランチャーを開く前に使っていたアプリへ貼り付ける。
+///
+/// CGEvent で ⌘V を合成して送るため、アクセシビリティ権限が必要。
+public struct PasteService: Pasting, Sendable {
+  private static let logger = AppLogger(category: "Paste")
+  /// 貼り付け先のアプリが前面に戻るまで待つ時間
+  private static let activationDelay: Duration = .milliseconds(150)
+
+  public init() {}
+
+  /// 検索結果を貼り付けるときのテキスト（貼り付けできない種別は nil）
+  ///
+  /// アプリ・ディレクトリはパス、コマンドはコマンド文字列、記号・絵文字は文字そのもの、
+  /// Web 検索・URL は URL を返す。
+  public static func text(for result: SearchResult) -> String? {
+    switch result.kind {
+    case .app, .directory, .character, .webSearch:
+      result.path.isEmpty ? nil : result.path
+    case .command:
+      result.command
+    case .emoji, .colorPicker, .process, .systemSettings, .systemControl, .windowLayout:
+      nil
+    }
+  }
+
+  public func paste(_ text: String, into processIdentifier: pid_t?) async throws {
+    guard AXIsProcessTrusted() else { throw PasteError.accessibilityNotTrusted }
+
+    await MainActor.run {
+      ClipboardService().copy(text)
+      if let processIdentifier,
+        let app = NSRunningApplication(processIdentifier: processIdentifier)
+      {
+        app.activate()
+      }
+    }
+    try await Task.sleep(for: Self.activationDelay)
+    try Self.postCommandV()
+    let target = processIdentifier.map(String.init) ?? "frontmost"
+    Self.logger.info("Pasted \(text.count) characters into pid \(target)")
+  }
+
+  /// ⌘V のキーダウン・キーアップを HID イベントとして送る。
+  private static func postCommandV() throws {
+    let source = CGEventSource(stateID: .combinedSessionState)
+    let keyCode = CGKeyCode(kVK_ANSI_V)
+    guard let keyDown = CGEvent(keyboardEventSource: source, virtualKey: keyCode, keyDown: true),
+      let keyUp = CGEvent(keyboardEventSource: source, virtualKey: keyCode, keyDown: false)
+    else { throw PasteError.eventCreationFailed }
+    keyDown.flags = .maskCommand
+    keyUp.flags = .maskCommand
+    keyDown.post(tap: .cghidEventTap)
+    keyUp.post(tap: .cghidEventTap)
+  }
+}
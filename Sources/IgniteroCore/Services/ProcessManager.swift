@@ -0,0 +1,160 @@
+import Darwin
+import Foundation
+import Synchronization
+
+/// 実行中のプロセス
+public struct RunningProcess: Sendable, Equatable {
+  public let pid: Int32
+  public let name: String
+  /// 実行ファイルのパス（取得できない場合は nil）
+  public let path: String?
+  /// 直前の取得時からの CPU 使用率（%。初回の取得では nil）
+  public let cpuUsage: Double?
+  /// 常駐メモリ（バイト）
+  public let memoryBytes: UInt64
+
+  public init(
+    pid: Int32, name: String, path: String? = nil, cpuUsage: Double? = nil,
+    memoryBytes: UInt64 = 0
+  ) {
+    self.pid = pid
+    self.name = name
+    self.path = path
+    self.cpuUsage = cpuUsage
+    self.memoryBytes = memoryBytes
+  }
+}
+
+/// プロセス操作のエラー
+public enum ProcessControlError: Error, LocalizedError, Equatable {
+  /// プロセスが存在しない（既に終了している）
+  case notFound(pid: Int32)
+  /// 他ユーザーのプロセスなど、終了させる権限がない
+  case permissionDenied(pid: Int32)
+  /// 自身やシステムのプロセスなど、終了させてはいけないプロセス
+  case protectedProcess(pid: Int32)
+  /// その他のエラー
+  case failed(pid: Int32, errno: Int32)
+
+  public var errorDescription: String? {
+    switch self {
+    case .notFound(let pid): "Process \(pid) not found"
+    case .permissionDenied(let pid): "Permission denied to signal process \(pid)"
+    case .protectedProcess(let pid): "Process \(pid) is protected"
+    case .failed(let pid, let errno): "Failed to signal process \(pid): \(String(cString: strerror(errno)))"
+    }
+  }
+}
+
+public protocol ProcessControlling: Sendable {
+  /// 現在のユーザーが所有する実行中のプロセスを返す。
+  func runningProcesses() -> [RunningProcess]
+  /// プロセスを終了させる（`force` が true の場合は SIGKILL、それ以外は SIGTERM）。
+  func terminate(pid: Int32, force: Bool) throws
+}
+
+/// libproc でプロセス一覧と CPU・メモリ使用量を取得し、シグナルで終了させるサービス。
+///
+/// CPU 使用率は前回の取得時との CPU 時間の差分から求めるため、初回の取得では nil になる。
+/// 他ユーザーのプロセスは情報を取得できず、終了もできないため一覧に含めない。
+public final class ProcessManager: ProcessControlling {
+
+  /// アプリ全体で共有するインスタンス（CPU 使用率の算出に前回の取得結果を使うため）
+  public static let shared = ProcessManager()
+
+  private struct Sample {
+    /// 取得時刻（ナノ秒）
+    var uptime: UInt64
+    /// pid ごとの累積 CPU 時間（ナノ秒）
+    var cpuTimes: [Int32: UInt64]
+  }
+
+  private let previousSample = Mutex<Sample?>(nil)
+
+  /// Mach 絶対時間をナノ秒に変換する係数
+  private let timebase: (numer: UInt64, denom: UInt64) = {
+    var info = mach_timebase_info_data_t()
+    mach_timebase_info(&info)
+    return (UInt64(info.numer), UInt64(max(info.denom, 1)))
+  }()
+
+  public init() {}
+
+  public func runningProcesses() -> [RunningProcess] {
+    let capacity = proc_listallpids(nil, 0)
+    guard capacity > 0 else { return [] }
+    // 取得までの間に増えたプロセスのために余裕を持たせる
+    var pids = [pid_t](repeating: 0, count: Int(capacity) + 64)
+    let count = pids.withUnsafeMutableBytes { buffer in
+      proc_listallpids(buffer.baseAddress, Int32(buffer.count))
+    }
+    guard count > 0 else { return [] }
+
+    let uptime = DispatchTime.now().uptimeNanoseconds
+    let previous = previousSample.withLock { $0 }
+    let ownPID = getpid()
+    var cpuTimes: [Int32: UInt64] = [:]
+    var processes: [RunningProcess] = []
+
+    for pid in pids.prefix(Int(count)) where pid > 0 && pid != ownPID {
+      var info = proc_taskallinfo()
+      let size = Int32(MemoryLayout<proc_taskallinfo>.size)
+      guard proc_pidinfo(pid, PROC_PIDTASKALLINFO, 0, &info, size) == size,
+        info.pbsd.pbi_uid == getuid()
+      else { continue }
+
+      let cpuTime =
+        (info.ptinfo.pti_total_user + info.ptinfo.pti_total_system) * timebase.numer
+        / timebase.denom
+      cpuTimes[pid] = cpuTime
+
+      var cpuUsage: Double?
+      if let previous, let before = previous.cpuTimes[pid], uptime > previous.uptime,
+        cpuTime >= before
+      {
+        cpuUsage = Double(cpuTime - before) / Double(uptime - previous.uptime) * 100
+      }
+
+      processes.append(
+        RunningProcess(
+          pid: pid,
+          name: Self.name(of: pid, info: info),
+          path: Self.executablePath(of: pid),
+          cpuUsage: cpuUsage,
+          memoryBytes: info.ptinfo.pti_resident_size
+        ))
+    }
+
+    previousSample.withLock { $0 = Sample(uptime: uptime, cpuTimes: cpuTimes) }
+    return processes
+  }
+
+  public func terminate(pid: Int32, force: Bool) throws {
+    guard pid > 1, pid != getpid() else { throw ProcessControlError.protectedProcess(pid: pid) }
+    guard kill(pid, force ? SIGKILL : SIGTERM) == 0 else {
+      switch errno {
+      case ESRCH: throw ProcessControlError.notFound(pid: pid)
+      case EPERM: throw ProcessControlError.permissionDenied(pid: pid)
+      default: throw ProcessControlError.failed(pid: pid, errno: errno)
+      }
+    }
+  }
+
+  /// プロセス名を返す（`proc_name` で取得できない場合は 16 文字までの `pbi_comm` を使う）。
+  private static func name(of pid: Int32, info: proc_taskallinfo) -> String {
+    var buffer = [CChar](repeating: 0, count: 256)
+    if proc_name(pid, &buffer, UInt32(buffer.count)) > 0 {
+      return String(cString: buffer)
+    }
+    return withUnsafeBytes(of: info.pbsd.pbi_comm) { raw in
+      String(decoding: raw.prefix(while: { $0 != 0 }), as: UTF8.self)
+    }
+  }
+
+  /// 実行ファイルのパスを返す。
+  private static func executablePath(of pid: Int32) -> String? {
+    var buffer = [CChar](repeating: 0, count: Int(MAXPATHLEN) * 4)
+    guard proc_pidpath(pid, &buffer, UInt32(buffer.count)) > 0 else { return nil }
+    return String(cString: buffer)
+  }
+}
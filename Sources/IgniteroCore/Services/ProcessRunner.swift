@@ -0,0 +1,82 @@
+import Foundation
+
+/// 外部コマンドを非同期に実行する。
+///
+/// 終了は `terminationHandler` で受け取り、標準出力・標準エラー出力は子プロセスがパイプの
+/// バッファで止まらないよう、終了を待つ間もディスパッチキューで読み続ける。読み込みは
+/// ブロックするため、Swift Concurrency のスレッドでは行わない。
+enum ProcessRunner {
+  /// コマンドの実行結果
+  struct Output: Sendable {
+    /// 終了コード（上限を過ぎて終了させた場合はシグナルの番号）
+    let status: Int32
+    let standardOutput: Data
+    let standardError: Data
+
+    /// 前後の空白を除いた標準エラー出力
+    var errorMessage: String {
+      String(decoding: standardError, as: UTF8.self)
+        .trimmingCharacters(in: .whitespacesAndNewlines)
+    }
+  }
+
+  /// コマンドを実行し、終了を待って出力を返す。
+  ///
+  /// - Parameters:
+  ///   - executable: 実行ファイルのパス
+  ///   - arguments: 引数
+  ///   - timeout: 待つ上限の秒数（過ぎたら SIGTERM で終了させる。nil の場合は終了まで待つ）
+  /// - Throws: 起動できない場合は `Process.run()` のエラー（終了コードが 0 以外でも投げない）
+  static func run(
+    _ executable: String, arguments: [String], timeout: TimeInterval? = nil
+  ) async throws -> Output {
+    let process = Process()
+    let stdoutPipe = Pipe()
+    let stderrPipe = Pipe()
+    process.executableURL = URL(fileURLWithPath: executable)
+    process.arguments = arguments
+    process.standardOutput = stdoutPipe
+    process.standardError = stderrPipe
+
+    async let stdoutData = readToEnd(stdoutPipe.fileHandleForReading)
+    async let stderrData = readToEnd(stderrPipe.fileHandleForReading)
+    var watchdog: DispatchWorkItem?
+    let status: Int32
+    do {
+      status = try await withCheckedThrowingContinuation { continuation in
+        process.terminationHandler = { continuation.resume(returning: $0.terminationStatus) }
+        do {
+          try process.run()
+        } catch {
+          process.terminationHandler = nil
+          // 読み込み側に終端を知らせる
+          try? stdoutPipe.fileHandleForWriting.close()
+          try? stderrPipe.fileHandleForWriting.close()
+          continuation.resume(throwing: error)
+          return
+        }
+        if let timeout {
+          let pid = process.processIdentifier
+          let item = DispatchWorkItem { kill(pid, SIGTERM) }
+          DispatchQueue.global().asyncAfter(deadline: .now() + timeout, execute: item)
+          watchdog = item
+        }
+      }
+    } catch {
+      _ = await (stdoutData, stderrData)
+      throw error
+    }
+    watchdog?.cancel()
+    let (output, errorOutput) = await (stdoutData, stderrData)
+    return Output(status: status, standardOutput: output, standardError: errorOutput)
+  }
+
+  /// パイプの終端まで読む。
+  private static func readToEnd(_ handle: FileHandle) async -> Data {
+    await withCheckedContinuation { continuation in
+      DispatchQueue.global(qos: .utility).async {
+        continuation.resume(returning: handle.readDataToEndOfFile())
+      }
+    }
+  }
+}
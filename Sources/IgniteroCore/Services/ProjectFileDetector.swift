@@ -0,0 +1,45 @@
+import Foundation
+
+/// ディレクトリ直下のプロジェクトファイル（`.code-workspace`、Xcode のワークスペース・
+/// プロジェクトなど）を検出する。
+///
+/// エディタでディレクトリを開くとき、そのエディタの検出器が見つけたファイルを
+/// ディレクトリの代わりに開く（`EditorType.projectFileDetector`）。
+/// 新しい形式に対応する場合は検出器を追加し、`EditorType.projectFileDetector` で
+/// 対応するエディタに割り当てる。
+public struct ProjectFileDetector: Sendable, Equatable {
+  /// 検出する拡張子（先にあるものを優先する）
+  public let pathExtensions: [String]
+
+  public init(pathExtensions: [String]) {
+    self.pathExtensions = pathExtensions
+  }
+
+  /// VS Code 系のエディタのワークスペース
+  public static let codeWorkspace = ProjectFileDetector(pathExtensions: ["code-workspace"])
+  /// Xcode のワークスペース・プロジェクト（CocoaPods などが作るワークスペースを優先する）
+  public static let xcode = ProjectFileDetector(pathExtensions: ["xcworkspace", "xcodeproj"])
+
+  /// ディレクトリ直下のエントリ名から、検出したファイル名を返す。
+  ///
+  /// 同じ拡張子のファイルが複数ある場合は名前順で先頭のものを選ぶ。隠しファイルは除く。
+  public func detect(in entries: [String]) -> String? {
+    for pathExtension in pathExtensions {
+      let matches = entries.filter {
+        !$0.hasPrefix(".") && ($0 as NSString).pathExtension == pathExtension
+      }
+      if let first = matches.min() {
+        return first
+      }
+    }
+    return nil
+  }
+
+  /// ディレクトリを読み、検出したファイルのパスを返す（読めない場合は nil）。
+  public func detect(inDirectory path: String) -> String? {
+    guard let entries = try? FileManager.default.contentsOfDirectory(atPath: path) else {
+      return nil
+    }
+    return detect(in: entries).map { (path as NSString).appendingPathComponent($0) }
+  }
+}
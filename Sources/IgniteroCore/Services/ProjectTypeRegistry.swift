@@ -0,0 +1,58 @@
+import Foundation
+
+/// 1 種類のプロジェクトを、直下にある目印のファイルから判定する検出器。
+public struct ProjectTypeDetector: Sendable, Equatable {
+  public let type: ProjectType
+  /// 目印のファイル名（`Cargo.toml` など）
+  public let fileNames: Set<String>
+  /// 目印の拡張子（`xcodeproj` など）
+  public let pathExtensions: Set<String>
+
+  public init(type: ProjectType, fileNames: Set<String> = [], pathExtensions: Set<String> = []) {
+    self.type = type
+    self.fileNames = fileNames
+    self.pathExtensions = pathExtensions
+  }
+
+  /// 直下のエントリ名に目印があるか
+  public func matches(_ entries: [String]) -> Bool {
+    entries.contains { entry in
+      fileNames.contains(entry) || pathExtensions.contains((entry as NSString).pathExtension)
+    }
+  }
+}
+
+/// プロジェクトの種類の検出器を登録した一覧。
+///
+/// スキャン時に DirectoryScanner が項目ごとに `detect(in:)` を呼び、結果を
+/// `DirectoryItem.projectType` に保存する。先に登録した検出器ほど優先する
+/// （`Package.swift` と `.xcodeproj` の両方があれば Xcode のプロジェクトとして扱う）。
+/// 新しい種類は `ProjectType` に追加し、`standard` に検出器を登録する。
+public struct ProjectTypeRegistry: Sendable {
+  public var detectors: [ProjectTypeDetector]
+
+  public init(detectors: [ProjectTypeDetector]) {
+    self.detectors = detectors
+  }
+
+  /// 標準の検出器
+  public static let standard = ProjectTypeRegistry(detectors: [
+    ProjectTypeDetector(type: .xcode, pathExtensions: ["xcworkspace", "xcodeproj"]),
+    ProjectTypeDetector(type: .swiftPackage, fileNames: ["Package.swift"]),
+    ProjectTypeDetector(type: .rust, fileNames: ["Cargo.toml"]),
+    ProjectTypeDetector(type: .go, fileNames: ["go.mod"]),
+    ProjectTypeDetector(
+      type: .jvm,
+      fileNames: ["pom.xml", "build.gradle", "build.gradle.kts", "settings.gradle.kts"]),
+    ProjectTypeDetector(
+      type: .python, fileNames: ["pyproject.toml", "setup.py", "requirements.txt", "Pipfile"]),
+    ProjectTypeDetector(type: .ruby, fileNames: ["Gemfile"]),
+    // package.json は他の種類のプロジェクトにも置かれるため最後に判定する
+    ProjectTypeDetector(type: .node, fileNames: ["package.json"]),
+  ])
+
+  /// 直下のエントリ名からプロジェクトの種類を返す（どれにも当てはまらなければ nil）。
+  public func detect(in entries: [String]) -> ProjectType? {
+    detectors.first { $0.matches(entries) }?.type
+  }
+}
@@ -0,0 +1,135 @@
+import Foundation
+import GRDB
+
+// MARK: - RecentWorkspaceProviding
+
+/// エディタが記録している最近開いたフォルダを返すプロバイダー。
+public protocol RecentWorkspaceProviding: Sendable {
+  /// 指定したエディタで最近開いたフォルダを、エディタの指定順・新しい順に返す（重複なし）。
+  func recentWorkspaces(for editors: [EditorType]) async -> [DirectoryItem]
+}
+
+// MARK: - RecentWorkspaceImporter
+
+/// エディタの「最近開いた項目」を読み込む。
+///
+/// VS Code 系のエディタ（VS Code・Cursor・Windsurf・Antigravity）は
+/// `User/globalStorage/state.vscdb`（SQLite）の `history.recentlyOpenedPathsList` を読み、
+/// ない場合は旧形式の `storage.json` の `openedPathsList` を読む。
+/// `.code-workspace` のワークスペースはファイルのあるフォルダとして取り込み（開くときに
+/// LaunchService がフォルダ内の `.code-workspace` を選ぶ）、単体で開いたファイルと
+/// リモート（`vscode-remote://`）、既に存在しないフォルダは除く。
+/// JetBrains の IDE は最も新しいバージョンの設定フォルダの `options/recentProjects.xml` を読む。
+public struct RecentWorkspaceImporter: RecentWorkspaceProviding {
+  private static let logger = AppLogger(category: "RecentWorkspaceImporter")
+
+  /// `state.vscdb` で最近開いた項目を保存しているキー
+  static let recentlyOpenedKey = "history.recentlyOpenedPathsList"
+
+  private let applicationSupportDirectory: String
+  private let jetBrainsConfigRoot: String
+
+  public init(
+    applicationSupportDirectory: String = NSString(string: "~/Library/Application Support")
+      .expandingTildeInPath,
+    jetBrainsConfigRoot: String = JetBrainsRecentProjectsParser.defaultConfigRoot
+  ) {
+    self.applicationSupportDirectory = applicationSupportDirectory
+    self.jetBrainsConfigRoot = jetBrainsConfigRoot
+  }
+
+  public func recentWorkspaces(for editors: [EditorType]) async -> [DirectoryItem] {
+    let fm = FileManager.default
+    var seen = Set<String>()
+    var items: [DirectoryItem] = []
+    for editor in editors {
+      for path in recentPaths(for: editor) {
+        var isDirectory: ObjCBool = false
+        guard fm.fileExists(atPath: path, isDirectory: &isDirectory), isDirectory.boolValue,
+          seen.insert(path).inserted
+        else { continue }
+        items.append(
+          DirectoryItem(
+            name: (path as NSString).lastPathComponent, path: path, editor: editor.rawValue,
+            isGitRepo: fm.fileExists(atPath: (path as NSString).appendingPathComponent(".git"))))
+      }
+    }
+    Self.logger.debug("Imported \(items.count) recent workspaces")
+    return items
+  }
+
+  /// 最近開いた項目の JSON（`{"entries": [...]}`）からフォルダのパスを取り出す。
+  static func folderPaths(fromRecentlyOpened json: Any) -> [String] {
+    guard let entries = (json as? [String: Any])?["entries"] as? [[String: Any]] else {
+      return []
+    }
+    return entries.compactMap { entry in
+      if let folderURI = entry["folderUri"] as? String {
+        return localPath(fromURI: folderURI)
+      }
+      if let workspace = entry["workspace"] as? [String: Any],
+        let configPath = workspace["configPath"] as? String
+      {
+        return localPath(fromURI: configPath).map { ($0 as NSString).deletingLastPathComponent }
+      }
+      return nil
+    }
+  }
+
+  /// `file://` URI をパスに変換する（他のスキームは nil）。
+  static func localPath(fromURI uri: String) -> String? {
+    guard let url = URL(string: uri), url.isFileURL else { return nil }
+    return url.standardizedFileURL.path
+  }
+
+  // MARK: - 非公開メソッド
+
+  private func recentPaths(for editor: EditorType) -> [String] {
+    if let folder = editor.applicationSupportFolderName {
+      return recentPaths(
+        userDirectory: (applicationSupportDirectory as NSString)
+          .appendingPathComponent("\(folder)/User"))
+    }
+    guard
+      let configDirectory = JetBrainsRecentProjectsParser.latestConfigDirectory(
+        in: jetBrainsConfigRoot, prefixes: editor.jetBrainsConfigPrefixes),
+      let data = FileManager.default.contents(
+        atPath: (configDirectory as NSString).appendingPathComponent("options/recentProjects.xml"))
+    else { return [] }
+    return JetBrainsRecentProjectsParser.projectPaths(in: data)
+  }
+
+  private func recentPaths(userDirectory: String) -> [String] {
+    let databasePath = (userDirectory as NSString)
+      .appendingPathComponent("globalStorage/state.vscdb")
+    if let json = Self.recentlyOpenedJSON(databasePath: databasePath) {
+      return Self.folderPaths(fromRecentlyOpened: json)
+    }
+    let storagePath = (userDirectory as NSString)
+      .appendingPathComponent("globalStorage/storage.json")
+    guard let data = FileManager.default.contents(atPath: storagePath),
+      let storage = (try? JSONSerialization.jsonObject(with: data)) as? [String: Any],
+      let list = storage["openedPathsList"]
+    else { return [] }
+    return Self.folderPaths(fromRecentlyOpened: list)
+  }
+
+  /// `state.vscdb` から最近開いた項目の JSON を読む（エディタの起動中でも読めるよう読み取り専用で開く）。
+  private static func recentlyOpenedJSON(databasePath: String) -> Any? {
+    guard FileManager.default.fileExists(atPath: databasePath) else { return nil }
+    var config = Configuration()
+    config.readonly = true
+    do {
+      let queue = try DatabaseQueue(path: databasePath, configuration: config)
+      // 値はバージョンによって TEXT と BLOB のどちらでも保存されている
+      let value = try queue.read { db in
+        try Data.fetchOne(
+          db, sql: "SELECT value FROM ItemTable WHERE key = ?", arguments: [recentlyOpenedKey])
+      }
+      return try value.map { try JSONSerialization.jsonObject(with: $0) }
+    } catch {
+      logger.warning("Failed to read \(databasePath): \(error.localizedDescription)")
+      return nil
+    }
+  }
+}
@@ -0,0 +1,84 @@
+import Foundation
+
+// MARK: - RegisteredAppDiscovering
+
+/// 既定のスキャン対象フォルダ以外にあるアプリを列挙する探索元。
+public protocol RegisteredAppDiscovering: Sendable {
+  /// 登録されているアプリバンドル（.app）のパスを返す。
+  ///
+  /// 列挙に失敗した場合は空配列を返す（スキャン全体は失敗させない）。
+  func registeredAppPaths() async -> [String]
+}
+
+// MARK: - LaunchServicesAppDiscovery
+
+/// Launch Services のデータベース（`lsregister -dump`）から登録済みアプリを列挙する。
+///
+/// ~/Library 配下や Setapp、独自の場所に置かれた Homebrew Cask のアプリなど、
+/// /Applications 以外にあっても一度起動・登録されたアプリを見つけられる。
+public struct LaunchServicesAppDiscovery: RegisteredAppDiscovering {
+  private static let logger = AppLogger(category: "LaunchServicesAppDiscovery")
+
+  static let lsregisterPath =
+    "/System/Library/Frameworks/CoreServices.framework/Versions/A/Frameworks/"
+    + "LaunchServices.framework/Versions/A/Support/lsregister"
+
+  /// 列挙結果から除く場所（OS 内部の補助アプリやシステムボリュームの別名パス）
+  static let ignoredPathPrefixes = [
+    "/System/Library/",
+    "/System/Volumes/",
+    "/Library/Apple/",
+    "/usr/",
+  ]
+
+  public init() {}
+
+  public func registeredAppPaths() async -> [String] {
+    guard let dump = await Self.runDump() else { return [] }
+    let fm = FileManager.default
+    return Self.appPaths(fromDump: dump).filter { fm.fileExists(atPath: $0) }
+  }
+
+  /// `lsregister -dump` の出力からアプリバンドルのパスを取り出す（出現順・重複なし）。
+  ///
+  /// 対象は `path:` 行のうち .app で終わるもの。ほかのアプリに同梱された補助アプリ、
+  /// ゴミ箱内のアプリ、`ignoredPathPrefixes` 配下のアプリは除く。
+  static func appPaths(fromDump dump: String) -> [String] {
+    var seen = Set<String>()
+    var paths: [String] = []
+    for line in dump.split(whereSeparator: \.isNewline) {
+      let trimmed = line.trimmingCharacters(in: .whitespaces)
+      guard trimmed.hasPrefix("path:") else { continue }
+      var path = trimmed.dropFirst("path:".count).trimmingCharacters(in: .whitespaces)
+      // 末尾の " (0x1a2b)" はデータベース内の ID
+      if let idRange = path.range(of: #" \(0x[0-9a-fA-F]+\)$"#, options: .regularExpression) {
+        path.removeSubrange(idRange)
+      }
+      guard path.hasPrefix("/"), path.hasSuffix(".app"),
+        !path.dropLast(4).contains(".app/"),
+        !path.contains("/.Trash/"),
+        !ignoredPathPrefixes.contains(where: { path.hasPrefix($0) }),
+        seen.insert(path).inserted
+      else { continue }
+      paths.append(path)
+    }
+    return paths
+  }
+
+  /// `lsregister -dump` を実行する（数秒かかり出力は数 MB になるため、`ProcessRunner` で
+  /// Swift Concurrency のスレッドを塞がずに待つ）。
+  private static func runDump() async -> String? {
+    let output: ProcessRunner.Output
+    do {
+      output = try await ProcessRunner.run(lsregisterPath, arguments: ["-dump"])
+    } catch {
+      logger.warning("Failed to run lsregister: \(error.localizedDescription)")
+      return nil
+    }
+    guard output.status == 0 else {
+      logger.warning("lsregister exited with status \(output.status)")
+      return nil
+    }
+    return String(decoding: output.standardOutput, as: UTF8.self)
+  }
+}
@@ -0,0 +1,183 @@
+import Foundation
+
+// MARK: - SSHConfigParser
+
+/// `~/.ssh/config` から接続できる SSH ホストを取り出すパーサー。
+///
+/// `Host` に書かれた別名のうち、ワイルドカード（`*`、`?`）と否定（`!`）を含まないものを
+/// ホストとして返す。`Include` は ssh と同じく相対パスを `~/.ssh` から解決し、
+/// ファイル名のワイルドカードも展開する。
+public enum SSHConfigParser {
+  /// `Include` をたどる深さの上限（ssh と同じ）
+  static let maxIncludeDepth = 16
+
+  /// 設定ファイルを読み込み、ホストを記述順に返す（読めない場合は空）。
+  ///
+  /// - Parameters:
+  ///   - path: 設定ファイルのパス
+  ///   - includeBase: `Include` の相対パスの基準ディレクトリ（省略時は設定ファイルのディレクトリ）
+  public static func hosts(atPath path: String, includeBase: String? = nil) -> [TerminalTarget] {
+    let base = includeBase ?? (path as NSString).deletingLastPathComponent
+    var hosts: [TerminalTarget] = []
+    var seen = Set<String>()
+    collect(path: path, base: base, depth: 0, into: &hosts, seen: &seen)
+    return hosts
+  }
+
+  /// 設定ファイルの内容からホストを記述順に返す。
+  ///
+  /// - Parameters:
+  ///   - contents: 設定ファイルの内容
+  ///   - includeBase: `Include` の相対パスの基準ディレクトリ（nil の場合は `Include` を無視する）
+  public static func hosts(in contents: String, includeBase: String? = nil) -> [TerminalTarget] {
+    var hosts: [TerminalTarget] = []
+    var seen = Set<String>()
+    parse(contents, base: includeBase, depth: 0, into: &hosts, seen: &seen)
+    return hosts
+  }
+
+  // MARK: - 非公開メソッド
+
+  private static func collect(
+    path: String, base: String, depth: Int,
+    into hosts: inout [TerminalTarget], seen: inout Set<String>
+  ) {
+    guard depth <= maxIncludeDepth,
+      let contents = try? String(contentsOfFile: path, encoding: .utf8)
+    else { return }
+    parse(contents, base: base, depth: depth, into: &hosts, seen: &seen)
+  }
+
+  private static func parse(
+    _ contents: String, base: String?, depth: Int,
+    into hosts: inout [TerminalTarget], seen: inout Set<String>
+  ) {
+    // 現在の Host ブロックの別名と、ブロック内で最初に指定された HostName・User
+    var blockNames: [String] = []
+    var hostName: String?
+    var user: String?
+
+    func flushBlock() {
+      let detail = hostName.map { host in user.map { "\($0)@\(host)" } ?? host }
+      for name in blockNames where seen.insert(name).inserted {
+        hosts.append(TerminalTarget(kind: .sshHost, name: name, detail: detail))
+      }
+      blockNames = []
+      hostName = nil
+      user = nil
+    }
+
+    for line in contents.components(separatedBy: .newlines) {
+      let tokens = tokenize(line)
+      guard let keyword = tokens.first?.lowercased() else { continue }
+      let arguments = Array(tokens.dropFirst())
+      switch keyword {
+      case "host":
+        flushBlock()
+        blockNames = arguments.filter { !$0.contains(where: { "*?!".contains($0) }) }
+      case "match":
+        flushBlock()
+      case "hostname":
+        hostName = hostName ?? arguments.first
+      case "user":
+        user = user ?? arguments.first
+      case "include":
+        guard let base else { continue }
+        // Include はブロックの途中でも、その位置に展開した内容として扱う
+        flushBlock()
+        for path in arguments.flatMap({ expandInclude($0, base: base) }) {
+          collect(path: path, base: base, depth: depth + 1, into: &hosts, seen: &seen)
+        }
+      default:
+        continue
+      }
+    }
+    flushBlock()
+  }
+
+  /// 行をキーワードと引数に分割する（`Key=Value` 形式、二重引用符、行末コメントに対応）。
+  static func tokenize(_ line: String) -> [String] {
+    var tokens: [String] = []
+    var current = ""
+    var inQuotes = false
+    var hasToken = false
+    for character in line {
+      if character == "\"" {
+        inQuotes.toggle()
+        hasToken = true
+      } else if !inQuotes, character == "#" {
+        break
+      } else if !inQuotes,
+        character.isWhitespace
+          || (character == "=" && (tokens.isEmpty || (tokens.count == 1 && !hasToken)))
+      {
+        if hasToken {
+          tokens.append(current)
+          current = ""
+          hasToken = false
+        }
+      } else {
+        current.append(character)
+        hasToken = true
+      }
+    }
+    if hasToken {
+      tokens.append(current)
+    }
+    return tokens
+  }
+
+  /// `Include` の引数をファイルのパスへ展開する（ファイル名のワイルドカードは名前順に展開する）。
+  static func expandInclude(_ argument: String, base: String) -> [String] {
+    let expanded = NSString(string: argument).expandingTildeInPath
+    let path =
+      expanded.hasPrefix("/") ? expanded : (base as NSString).appendingPathComponent(expanded)
+    let pattern = (path as NSString).lastPathComponent
+    guard pattern.contains(where: { "*?[".contains($0) }) else { return [path] }
+    let directory = (path as NSString).deletingLastPathComponent
+    let names = (try? FileManager.default.contentsOfDirectory(atPath: directory)) ?? []
+    return names.filter { fnmatch(pattern, $0, 0) == 0 }.sorted()
+      .map { (directory as NSString).appendingPathComponent($0) }
+  }
+}
+
+// MARK: - TmuxConfigParser
+
+/// `~/.tmux.conf` で作成する tmux セッションの名前を取り出すパーサー。
+///
+/// `new-session -s <名前>`（`new -s`、`-s<名前>` の形も含む）を記述順に返す。
+public enum TmuxConfigParser {
+  public static func sessions(in contents: String) -> [TerminalTarget] {
+    var sessions: [TerminalTarget] = []
+    var seen = Set<String>()
+    for line in contents.components(separatedBy: .newlines) {
+      // `;`（`\;`）で区切った複数のコマンドを 1 行に書ける
+      let separated = line.replacingOccurrences(of: "\\;", with: ";")
+        .replacingOccurrences(of: ";", with: " ; ")
+      let commands = SSHConfigParser.tokenize(separated).split(separator: ";")
+      for command in commands {
+        guard let name = sessionName(in: Array(command)), seen.insert(name).inserted else {
+          continue
+        }
+        sessions.append(TerminalTarget(kind: .tmuxSession, name: name))
+      }
+    }
+    return sessions
+  }
+
+  /// `new-session` コマンドの `-s` の値を返す。
+  private static func sessionName(in tokens: [String]) -> String? {
+    guard let command = tokens.first, command == "new-session" || command == "new" else {
+      return nil
+    }
+    for (offset, token) in tokens.enumerated().dropFirst() {
+      if token == "-s" {
+        return offset + 1 < tokens.count ? tokens[offset + 1] : nil
+      }
+      if token.hasPrefix("-s"), token.count > 2 {
+        return String(token.dropFirst(2))
+      }
+    }
+    return nil
+  }
+}
@@ -0,0 +1,238 @@
+import Foundation
+
+/// 検索用に正規化済みのキーを持つ項目
+struct IndexedItem<Item: Sendable>: Sendable {
+  let item: Item
+  /// 照合に使う正規化済みのキー（アプリは表示名と元の名前、かな名はひらがな・ローマ字も含む）
+  let names: [String]
+  /// `~` 表記に短縮した小文字化済みのパス（パス検索用）
+  let abbreviatedPath: String
+  /// 名前より低い重みで照合する正規化済みのキーワード（アプリの Spotlight キーワードなど）
+  var keywords: [String] = []
+}
+
+/// 検索対象を正規化済みのキーとともに保持するインデックス。
+///
+/// キャッシュ読込時や設定変更時に 1 度だけ構築し、キー入力ごとの検索では
+/// 数千件の名前・パスを毎回小文字化し直さないようにする。
+public struct SearchIndex: Sendable {
+  public let apps: [AppItem]
+  public let directories: [DirectoryItem]
+  public let commands: [CustomCommand]
+
+  let indexedApps: [IndexedItem<AppItem>]
+  let indexedDirectories: [IndexedItem<DirectoryItem>]
+  let indexedCommands: [IndexedItem<CustomCommand>]
+  let indexedQuickLinks: [IndexedItem<QuickLink>]
+  let indexedSystemSettingsPanes: [IndexedItem<SystemSettingsPane>]
+  let indexedSystemControls: [IndexedItem<SystemControl>]
+  let indexedWindowLayoutActions: [IndexedItem<WindowLayoutAction>]
+  let indexedTerminalTargets: [IndexedItem<TerminalTarget>]
+
+  /// お気に入りのパス
+  public let favorites: Set<String>
+
+  /// ディレクトリの全文検索（設定時は `directories` ではなくキャッシュ DB を検索する）
+  public let directorySearcher: (any DirectoryFullTextSearching)?
+
+  /// 検索結果から除外するパス（全文検索の結果の絞り込みに使う）
+  let hiddenPaths: Set<String>
+
+  /// 正規化済みの別名と対象パスの組
+  let aliasTargets: [(key: String, path: String)]
+
+  /// アプリ・ディレクトリのパスごとの実体のパス（結果の重複除去に使う）
+  let canonicalPaths: [String: String]
+
+  private let appsByPath: [String: AppItem]
+  private let directoriesByPath: [String: DirectoryItem]
+  private let commandsByIdentifier: [String: CustomCommand]
+  private let terminalTargetsByIdentifier: [String: TerminalTarget]
+
+  /// 空のインデックス
+  public static let empty = SearchIndex(apps: [], directories: [], commands: [])
+
+  /// SearchIndex を構築する。
+  ///
+  /// - Parameters:
+  ///   - apps: アプリケーション一覧
+  ///   - directories: ディレクトリ一覧
+  ///   - commands: カスタムコマンド一覧
+  ///   - home: パスの `~` 短縮に使うホームディレクトリ
+  ///   - transliterators: 名前から追加の検索キー（ピンインなど）を生成する変換器
+  ///   - aliases: 項目に割り当てた別名
+  ///   - favorites: お気に入りのパス
+  ///   - hiddenPaths: 検索結果から除外するパス（カスタムコマンドは識別子）
+  ///   - quickLinks: ユーザー定義の URL ショートカット
+  ///   - systemSettingsPanes: システム設定のパネル
+  ///   - systemControls: 消音・音量・ダークモードなどのシステム操作
+  ///   - windowLayoutActions: 他のアプリのウィンドウを移動・リサイズする操作
+  ///   - terminalTargets: ターミナルで開く SSH ホスト・tmux セッション
+  ///   - directorySearcher: ディレクトリの全文検索（設定時は名前・パス検索で `directories` を照合しない）
+  ///   - canonicalPaths: 解決済みの実体のパス（前回のインデックスのもの。含まれないパスだけ解決する）
+  public init(
+    apps: [AppItem],
+    directories: [DirectoryItem],
+    commands: [CustomCommand],
+    quickLinks: [QuickLink] = [],
+    systemSettingsPanes: [SystemSettingsPane] = [],
+    systemControls: [SystemControl] = [],
+    windowLayoutActions: [WindowLayoutAction] = [],
+    terminalTargets: [TerminalTarget] = [],
+    home: String = NSHomeDirectory(),
+    transliterators: [any Transliterator] = [],
+    aliases: [ItemAlias] = [],
+    favorites: Set<String> = [],
+    hiddenPaths: Set<String> = [],
+    directorySearcher: (any DirectoryFullTextSearching)? = nil,
+    canonicalPaths: [String: String] = [:]
+  ) {
+    func keys(_ name: String) -> [String] {
+      Self.searchKeys(for: name, transliterators: transliterators)
+    }
+    let apps = apps.filter { !hiddenPaths.contains($0.path) }
+    let directories = directories.filter { !hiddenPaths.contains($0.path) }
+    let commands = commands.filter { !hiddenPaths.contains($0.historyIdentifier) }
+    self.apps = apps
+    self.directories = directories
+    self.commands = commands
+    self.indexedApps = apps.map { app in
+      IndexedItem(
+        item: app,
+        names: keys(app.name) + (app.originalName.map(keys) ?? [])
+          + app.alternateNames.flatMap(keys),
+        abbreviatedPath: SearchService.abbreviatedPath(app.path, home: home),
+        keywords: app.keywords.flatMap(keys))
+    }
+    self.indexedDirectories = directories.map { dir in
+      IndexedItem(
+        item: dir,
+        names: keys(dir.name) + (dir.originalName.map(keys) ?? []),
+        abbreviatedPath: SearchService.abbreviatedPath(dir.path, home: home))
+    }
+    self.indexedCommands = commands.map { cmd in
+      IndexedItem(item: cmd, names: keys(cmd.alias), abbreviatedPath: "")
+    }
+    self.indexedQuickLinks = quickLinks.map { link in
+      IndexedItem(item: link, names: keys(link.name), abbreviatedPath: "")
+    }
+    self.indexedSystemSettingsPanes = systemSettingsPanes.compactMap { pane in
+      guard let url = pane.url, !hiddenPaths.contains(url.absoluteString) else { return nil }
+      return IndexedItem(
+        item: pane,
+        names: keys(pane.name) + pane.keywords.map(SearchQueryNormalizer.normalize),
+        abbreviatedPath: "")
+    }
+    self.indexedSystemControls = systemControls.filter {
+      !hiddenPaths.contains($0.identifier)
+    }.map { control in
+      IndexedItem(
+        item: control,
+        names: keys(control.displayName) + control.keywords.map(SearchQueryNormalizer.normalize),
+        abbreviatedPath: "")
+    }
+    self.indexedWindowLayoutActions = windowLayoutActions.filter {
+      !hiddenPaths.contains($0.identifier)
+    }.map { action in
+      IndexedItem(
+        item: action,
+        names: keys(action.displayName) + action.keywords.map(SearchQueryNormalizer.normalize),
+        abbreviatedPath: "")
+    }
+    let terminalTargets = terminalTargets.filter { !hiddenPaths.contains($0.identifier) }
+    // `ssh prod-web` のように入力どおりの表記と、接続先のホスト名でも一致させる
+    self.indexedTerminalTargets = terminalTargets.map { target in
+      IndexedItem(
+        item: target,
+        names: keys(target.name)
+          + [target.invocation, target.detail].compactMap { $0 }
+          .map(SearchQueryNormalizer.normalize),
+        abbreviatedPath: "")
+    }
+    self.favorites = favorites
+    self.directorySearcher = directorySearcher
+    self.hiddenPaths = hiddenPaths
+    self.aliasTargets = aliases.compactMap { alias in
+      let key = alias.normalizedAlias
+      return key.isEmpty || hiddenPaths.contains(alias.path) ? nil : (key, alias.path)
+    }
+    self.appsByPath = Dictionary(
+      apps.map { ($0.path, $0) }, uniquingKeysWith: { first, _ in first })
+    self.directoriesByPath = Dictionary(
+      directories.map { ($0.path, $0) }, uniquingKeysWith: { first, _ in first })
+    self.commandsByIdentifier = Dictionary(
+      commands.map { ($0.historyIdentifier, $0) }, uniquingKeysWith: { first, _ in first })
+    self.terminalTargetsByIdentifier = Dictionary(
+      terminalTargets.map { ($0.identifier, $0) }, uniquingKeysWith: { first, _ in first })
+    // シンボリックリンクの解決はファイルシステムを読むため、キー入力ごとではなく構築時に済ませる
+    var resolved: [String: String] = [:]
+    for path in apps.map(\.path) + directories.map(\.path) where resolved[path] == nil {
+      resolved[path] = canonicalPaths[path] ?? SearchService.canonicalPath(path)
+    }
+    self.canonicalPaths = resolved
+  }
+
+  /// 別名の対象パスを検索結果に変換する。
+  ///
+  /// インデックスにない場合は、`.app` で終わるパスをアプリ、それ以外をディレクトリとして扱う
+  /// （`~/Downloads` のように登録ディレクトリ外のパスにも別名を付けられるようにする）。
+  /// - Returns: 検索結果。削除済みのカスタムコマンドなど解決できない場合は nil
+  func aliasResult(for path: String, score: Double) -> SearchResult? {
+    if let result = indexedResult(for: path, score: score) {
+      return result
+    }
+    guard !path.hasPrefix("command://"), !path.hasPrefix("docker://"),
+      !TerminalTarget.isIdentifier(path)
+    else { return nil }
+    let name = (path as NSString).lastPathComponent
+    if path.hasSuffix(".app") {
+      return SearchResult(
+        appItem: AppItem(name: (name as NSString).deletingPathExtension, path: path),
+        score: score)
+    }
+    return SearchResult(directoryItem: DirectoryItem(name: name, path: path), score: score)
+  }
+
+  /// インデックスに含まれるアプリ・ディレクトリ・カスタムコマンド・接続先を検索結果に変換する。
+  ///
+  /// - Parameter path: パス（カスタムコマンド・接続先は履歴用の識別子）
+  /// - Returns: インデックスにない場合は nil
+  public func indexedResult(for path: String, score: Double = 0) -> SearchResult? {
+    if let app = appsByPath[path] {
+      return SearchResult(appItem: app, score: score)
+    }
+    if let dir = directoriesByPath[path] {
+      return SearchResult(directoryItem: dir, score: score)
+    }
+    if let command = commandsByIdentifier[path] {
+      return SearchResult(customCommand: command, score: score)
+    }
+    if let target = terminalTargetsByIdentifier[path] {
+      return SearchResult(terminalTarget: target, score: score)
+    }
+    return nil
+  }
+
+  /// 名前の検索キーを生成する（正規化キーに変換器のキーを重複なく追加する）。
+  static func searchKeys(for name: String, transliterators: [any Transliterator]) -> [String] {
+    var keys = SearchQueryNormalizer.searchKeys(for: name)
+    for transliterator in transliterators {
+      for key in transliterator.searchKeys(for: name) where !keys.contains(key) {
+        keys.append(key)
+      }
+    }
+    return keys
+  }
+
+  /// 結果の重複除去に使う実体のパス。
+  ///
+  /// インデックスにないパス（全文検索の結果など）はファイルシステムを読まず、`.` / `..` だけを解決する。
+  func canonicalPath(for path: String) -> String {
+    canonicalPaths[path] ?? URL(fileURLWithPath: path).standardizedFileURL.path
+  }
+
+  /// 項目の総数
+  public var count: Int {
+    apps.count + directories.count + commands.count
+  }
+}
@@ -0,0 +1,65 @@
+import Foundation
+
+// MARK: - SearchResultGroup
+
+/// 検索結果を見出しごとにまとめる区分
+public enum SearchResultGroup: String, Codable, Sendable, CaseIterable {
+  /// アプリ
+  case apps
+  /// ディレクトリ（プロジェクト）
+  case projects
+  /// カスタムコマンド・クイックリンク・SSH ホスト・tmux セッション
+  case commands
+  /// Web 検索・絵文字・プロセス・システム設定・システム操作など、確定で操作を実行する結果
+  case actions
+
+  /// 結果の種別が属する区分
+  public init(kind: SearchResultKind) {
+    switch kind {
+    case .app: self = .apps
+    case .directory: self = .projects
+    case .command: self = .commands
+    case .webSearch, .colorPicker, .emoji, .character, .process, .systemSettings,
+      .systemControl, .windowLayout:
+      self = .actions
+    }
+  }
+
+  /// 見出しに表示する名前
+  public var title: String {
+    switch self {
+    case .apps: "アプリ"
+    case .projects: "プロジェクト"
+    case .commands: "コマンド"
+    case .actions: "アクション"
+    }
+  }
+}
+
+// MARK: - SearchResultSection
+
+/// 見出し 1 つ分の検索結果
+public struct SearchResultSection: Sendable {
+  public let group: SearchResultGroup
+  /// 区分内の結果（スコア順）
+  public let results: [SearchResult]
+
+  public init(group: SearchResultGroup, results: [SearchResult]) {
+    self.group = group
+    self.results = results
+  }
+
+  /// 検索結果を区分ごとにまとめる。
+  ///
+  /// 区分は `order` の順に並べ（`order` にない区分は既定の順で後ろに付ける）、
+  /// 結果のない区分は含めない。区分内は元の順序（スコア順）を保つ。
+  public static func sections(
+    of results: [SearchResult], order: [SearchResultGroup] = SearchResultGroup.allCases
+  ) -> [SearchResultSection] {
+    let grouped = Dictionary(grouping: results) { SearchResultGroup(kind: $0.kind) }
+    return ResultSettings.completedGroupOrder(order).compactMap { group in
+      guard let results = grouped[group] else { return nil }
+      return SearchResultSection(group: group, results: results)
+    }
+  }
+}
@@ -1,5 +1,6 @@
 import Foundation
 import Fuse
+import Synchronization
 
 // MARK: - 検索クエリ正規化
 
@@ -31,6 +32,129 @@ public enum SearchQueryNormalizer: Sendable {
 
     return result.lowercased()
   }
+
+  /// カタカナをひらがなに畳み込む（`メモ` と `めも` を同一視するため）。
+  public static func foldKana(_ text: String) -> String {
+    var result = String.UnicodeScalarView()
+    for scalar in text.unicodeScalars {
+      // カタカナ (ァ-ヶ: U+30A1-30F6) → ひらがな (ぁ-ゖ: U+3041-3096)
+      if scalar.value >= 0x30A1, scalar.value <= 0x30F6,
+        let hiragana = UnicodeScalar(scalar.value - 0x60)
+      {
+        result.append(hiragana)
+      } else {
+        result.append(scalar)
+      }
+    }
+    return String(result)
+  }
+
+  /// かなをローマ字に変換する（かなを含まない場合は nil）。
+  ///
+  /// 漢字など、かな以外の文字はそのまま残す。撥音の区切り（`kin'en` の `'`）と
+  /// 長音記号のダイアクリティカルマークは除去する。
+  public static func romaji(_ text: String) -> String? {
+    let folded = foldKana(text)
+    guard folded.unicodeScalars.contains(where: { (0x3041...0x3096).contains($0.value) }) else {
+      return nil
+    }
+    guard
+      let latin = folded.applyingTransform(.latinToHiragana, reverse: true)?
+        .applyingTransform(.stripDiacritics, reverse: false)
+    else { return nil }
+    return latin.replacingOccurrences(of: "'", with: "").lowercased()
+  }
+
+  /// 項目名から照合に使うキーを生成する（小文字化した名前・かな畳み込み・ローマ字）。
+  public static func searchKeys(for name: String) -> [String] {
+    let lowered = name.lowercased()
+    var keys = [lowered]
+    let folded = foldKana(lowered)
+    if folded != lowered {
+      keys.append(folded)
+    }
+    if let romaji = romaji(lowered), !keys.contains(romaji) {
+      keys.append(romaji)
+    }
+    return keys
+  }
+}
+
+// MARK: - 検索クエリパーサー
+
+/// プレフィックスによって絞り込まれた検索対象
+public enum SearchScope: Sendable, Equatable {
+  /// すべて（プレフィックスなし）
+  case all
+  /// アプリケーションのみ
+  case apps
+  /// ディレクトリのみ
+  case directories
+  /// カスタムコマンドのみ
+  case commands
+  /// アプリ・ディレクトリをパス（ホームは `~` 表記）で検索
+  case paths
+  /// 絵文字・記号のみ
+  case characters
+  /// 実行中のプロセスのみ
+  case processes
+  /// Docker のコンテナ・Compose のプロジェクトのみ
+  case docker
+}
+
+/// プレフィックス解析済みの検索クエリ
+public struct ParsedSearchQuery: Sendable, Equatable {
+  /// 検索対象
+  public let scope: SearchScope
+  /// プレフィックスを除いた正規化済み検索語
+  public let term: String
+
+  public init(scope: SearchScope, term: String) {
+    self.scope = scope
+    self.term = term
+  }
+}
+
+/// 検索クエリ先頭のプレフィックス（`d project`、`>deploy` など）を解析するパーサー
+public enum SearchQueryParser: Sendable {
+  /// クエリを正規化し、設定されたプレフィックスに一致すれば検索対象を絞り込む。
+  ///
+  /// 英数字のプレフィックスは直後に空白がある場合のみ認識する（`da` は通常検索）。
+  /// 記号のプレフィックスは空白なし・検索語なしでも認識する。
+  public static func parse(
+    _ query: String,
+    prefixes: SearchPrefixSettings = .default
+  ) -> ParsedSearchQuery {
+    let normalized = SearchQueryNormalizer.normalize(query)
+    guard prefixes.enabled, !normalized.isEmpty else {
+      return ParsedSearchQuery(scope: .all, term: normalized)
+    }
+
+    let candidates = [
+      (prefixes.app, SearchScope.apps),
+      (prefixes.directory, SearchScope.directories),
+      (prefixes.command, SearchScope.commands),
+      (prefixes.path, SearchScope.paths),
+      (prefixes.symbol, SearchScope.characters),
+      (prefixes.process, SearchScope.processes),
+      (prefixes.dockerEnabled ? prefixes.docker : "", SearchScope.docker),
+    ]
+    .map { (prefix: SearchQueryNormalizer.normalize($0.0), scope: $0.1) }
+    .filter { !$0.prefix.isEmpty }
+    // 長いプレフィックスを優先して判定する（`>` と `>>` の共存など）
+    .sorted { $0.prefix.count > $1.prefix.count }
+
+    for candidate in candidates where normalized.hasPrefix(candidate.prefix) {
+      let rest = normalized.dropFirst(candidate.prefix.count)
+      let requiresSpace = candidate.prefix.allSatisfy { $0.isLetter || $0.isNumber }
+      if requiresSpace {
+        guard let first = rest.first, first.isWhitespace else { continue }
+      }
+      let term = rest.trimmingCharacters(in: .whitespaces)
+      return ParsedSearchQuery(scope: candidate.scope, term: term)
+    }
+    return ParsedSearchQuery(scope: .all, term: normalized)
+  }
 }
 
 // MARK: - 検索結果
@@ -43,6 +167,50 @@ public enum SearchResultKind: Sendable {
   case webSearch
   case colorPicker
   case emoji
+  /// 絵文字・記号（確定でクリップボードにコピー）
+  case character
+  /// 実行中のプロセス（確定で終了させる）
+  case process
+  /// システム設定のパネル（確定でシステム設定を開く）
+  case systemSettings
+  /// 消音・音量・ダークモードなどのシステム操作（確定で実行する）
+  case systemControl
+  /// 他のアプリのウィンドウの移動・リサイズ（確定で直前のアプリのウィンドウを操作する）
+  case windowLayout
+}
+
+/// 検索結果のスコアの内訳（`search_debug` 用。いずれも値が小さいほど上位）
+public struct SearchScoreBreakdown: Sendable, Equatable {
+  /// 名前のファジー一致のスコア（0 が完全一致、別名だけで一致した場合は 1）
+  public var fuzzy: Double
+  /// 種別ごとの補正（Git リポジトリ・登録ディレクトリの優先度）
+  public var categoryAdjustment: Double
+  /// 選択履歴による補正
+  public var historyBoost: Double
+  /// 別名に一致した場合のスコア（ほかの補正より優先する）
+  public var aliasScore: Double?
+
+  public init(
+    fuzzy: Double, categoryAdjustment: Double = 0, historyBoost: Double = 0,
+    aliasScore: Double? = nil
+  ) {
+    self.fuzzy = fuzzy
+    self.categoryAdjustment = categoryAdjustment
+    self.historyBoost = historyBoost
+    self.aliasScore = aliasScore
+  }
+
+  func withHistoryBoost(_ boost: Double) -> SearchScoreBreakdown {
+    var breakdown = self
+    breakdown.historyBoost = boost
+    return breakdown
+  }
+
+  func withAliasScore(_ score: Double) -> SearchScoreBreakdown {
+    var breakdown = self
+    breakdown.aliasScore = score
+    return breakdown
+  }
 }
 
 /// 統一された検索結果
@@ -56,6 +224,39 @@ public struct SearchResult: Sendable {
   public let editor: String?
   public let command: String?
   public let workingDirectory: String?
+  /// ディレクトリが Git リポジトリかどうか
+  public let isGitRepo: Bool
+  /// Git リポジトリの現在のブランチ
+  public let gitBranch: String?
+  /// お気に入りに登録されているか
+  public var isFavorite = false
+  /// 表示アイコンの SF Symbols 名（クイックリンク・システム設定・システム操作・ウィンドウ操作・接続先のみ）
+  public var symbolName: String?
+  /// 同じ名前の結果が他にある場合に見分けるための親ディレクトリ（`~` 表記。アプリ・ディレクトリのみ）
+  public var disambiguation: String?
+  /// プロセスの情報（プロセスのみ）
+  public var process: RunningProcess?
+  /// 直下の Xcode のワークスペース・プロジェクトのパス（ディレクトリのみ）
+  public var xcodeProject: String?
+  /// プロジェクトの種類（ディレクトリのみ）
+  public var projectType: ProjectType?
+  /// Finder のタグ（ディレクトリのみ）
+  public var finderTags: [FinderTag] = []
+  /// バージョン（アプリのみ）
+  public var version: String?
+  /// バンドル ID（アプリのみ）
+  public var bundleIdentifier: String?
+  /// カテゴリ名（アプリのみ。`developer-tools` など）
+  public var categoryName: String?
+  /// `name` のうち検索語に一致した文字の位置（Character 単位の昇順。強調表示用）
+  public var matchedIndices: [Int] = []
+  /// スコアの内訳（補正を記録した結果のみ。`breakdown` で参照する）
+  var scoreBreakdown: SearchScoreBreakdown?
+
+  /// スコアの内訳（補正を記録していなければ、現在のスコアをファジー一致のスコアとみなす）
+  public var breakdown: SearchScoreBreakdown {
+    scoreBreakdown ?? SearchScoreBreakdown(fuzzy: score)
+  }
 
   public init(appItem: AppItem, score: Double) {
     self.name = appItem.name
@@ -67,6 +268,11 @@ public struct SearchResult: Sendable {
     self.editor = nil
     self.command = nil
     self.workingDirectory = nil
+    self.isGitRepo = false
+    self.gitBranch = nil
+    self.version = appItem.version
+    self.bundleIdentifier = appItem.bundleIdentifier
+    self.categoryName = appItem.categoryName
   }
 
   public init(directoryItem: DirectoryItem, score: Double) {
@@ -79,6 +285,11 @@ public struct SearchResult: Sendable {
     self.editor = directoryItem.editor
     self.command = nil
     self.workingDirectory = nil
+    self.isGitRepo = directoryItem.isGitRepo
+    self.gitBranch = directoryItem.gitBranch
+    self.xcodeProject = directoryItem.xcodeProject
+    self.projectType = directoryItem.projectType
+    self.finderTags = directoryItem.finderTags
   }
 
   public init(customCommand: CustomCommand, score: Double) {
@@ -91,6 +302,100 @@ public struct SearchResult: Sendable {
     self.editor = nil
     self.command = customCommand.command
     self.workingDirectory = customCommand.workingDirectory
+    self.isGitRepo = false
+    self.gitBranch = nil
+  }
+
+  /// ランチャーに入力したコマンドをそのまま実行する結果を作る（「ここでコマンドを実行」用）。
+  ///
+  /// 検索結果へ復元できないため path は空にし、選択履歴には記録しない。
+  public init(shellCommand: String, score: Double) {
+    self.name = shellCommand
+    self.path = ""
+    self.kind = .command
+    self.score = score
+    self.iconPath = nil
+    self.originalName = nil
+    self.editor = nil
+    self.command = shellCommand
+    self.workingDirectory = nil
+    self.isGitRepo = false
+    self.gitBranch = nil
+  }
+
+  /// SSH ホスト・tmux セッションを既定のターミナルで開く結果を作る（path は接続先の識別子）。
+  public init(terminalTarget target: TerminalTarget, score: Double) {
+    self.name = target.name
+    self.path = target.identifier
+    self.kind = .command
+    self.score = score
+    self.iconPath = nil
+    self.originalName = nil
+    self.editor = nil
+    self.command = target.command
+    self.workingDirectory = nil
+    self.isGitRepo = false
+    self.gitBranch = nil
+    self.symbolName = target.symbolName
+  }
+
+  /// Docker のコンテナでシェルを開く結果を作る（path はコンテナの識別子）。
+  public init(dockerContainer container: DockerContainer, score: Double) {
+    self.name = container.name
+    self.path = container.identifier
+    self.kind = .command
+    self.score = score
+    self.iconPath = nil
+    self.originalName = nil
+    self.editor = nil
+    self.command = container.shellCommand
+    self.workingDirectory = nil
+    self.isGitRepo = false
+    self.gitBranch = nil
+    self.symbolName = "shippingbox"
+  }
+
+  /// クイックリンクを開く結果を作る。
+  ///
+  /// - Parameters:
+  ///   - quickLink: クイックリンク
+  ///   - url: 検索語を埋め込んだ URL
+  ///   - score: スコア
+  ///   - name: 表示名（省略時はクイックリンクの名前）
+  public init(quickLink: QuickLink, url: URL, score: Double, name: String? = nil) {
+    self.init(name: name ?? quickLink.name, kind: .webSearch, score: score, path: url.absoluteString)
+    self.symbolName = quickLink.icon
+  }
+
+  /// システム設定のパネルを開く結果を作る（path はパネルの URL）。
+  public init(systemSettingsPane pane: SystemSettingsPane, url: URL, score: Double) {
+    self.init(name: pane.name, kind: .systemSettings, score: score, path: url.absoluteString)
+    self.symbolName = pane.symbolName
+  }
+
+  /// システム操作の結果を作る（path は操作の識別子）。
+  public init(systemControl control: SystemControl, score: Double) {
+    self.init(
+      name: control.displayName, kind: .systemControl, score: score, path: control.identifier)
+    self.symbolName = control.symbolName
+  }
+
+  /// ウィンドウ操作の結果を作る（path は操作の識別子）。
+  public init(windowLayout action: WindowLayoutAction, score: Double) {
+    self.init(
+      name: action.displayName, kind: .windowLayout, score: score, path: action.identifier)
+    self.symbolName = action.symbolName
+  }
+
+  /// 絵文字・記号の結果を作る（path は文字そのもの）。
+  public init(character: String, name: String, score: Double) {
+    self.init(name: "\(character)  \(name)", kind: .character, score: score, path: character)
+  }
+
+  /// 実行中のプロセスの結果を作る（path は実行ファイルのパス、取得できない場合は名前）。
+  public init(process: RunningProcess, score: Double) {
+    self.init(name: process.name, kind: .process, score: score, path: process.path ?? process.name)
+    self.process = process
   }
 
   public init(name: String, kind: SearchResultKind, score: Double, path: String = "") {
@@ -103,6 +408,8 @@ public struct SearchResult: Sendable {
     self.editor = nil
     self.command = nil
     self.workingDirectory = nil
+    self.isGitRepo = false
+    self.gitBranch = nil
   }
 }
 
@@ -111,11 +418,63 @@ public struct SearchResult: Sendable {
 /// ファジー検索サービス
 ///
 /// アプリケーション、ディレクトリ、カスタムコマンドを Fuse-Swift で並列ファジー検索し、
-/// スコア順にマージして上位20件を返す。選択履歴による優先度調整も行う。
+/// スコア順にマージして上位の結果（既定は20件、設定で変更可能）を返す。選択履歴による優先度調整も行う。
+/// キー入力ごとの検索では、正規化済みの `SearchIndex` を渡して再正規化を避ける。
 public struct SearchService: Sendable {
-  private static let maxResults = 20
+  /// Git リポジトリのスコアから差し引く値（履歴ブーストより小さく、一致度の近い候補間でのみ効く）
+  static let gitRepoScoreBonus = 0.05
+  /// 登録ディレクトリの優先度 1 あたりにスコアから差し引く値（優先度 5 でも履歴ブーストより小さい）
+  static let directoryPriorityStep = 0.04
+  /// 検索語が別名と完全一致した項目のスコア（履歴ブーストより優先する）
+  static let aliasExactScore = -3.0
+  /// 検索語が別名の前方に一致した項目のスコア
+  static let aliasPrefixScore = -2.0
+  /// 全文検索で一致したがファジー照合では一致しなかった候補のスコア
+  static let fullTextMatchScore = 0.5
+  /// キーワード（アプリの `CFBundleSpotlightKeywords` など）だけで一致した場合にスコアへ加える値
+  /// （キーワードに完全一致しても、名前にある程度一致した項目より下に並べる）
+  static let keywordScorePenalty = 0.3
+  /// 全文検索で取得する候補の最大件数
+  static let fullTextCandidateLimit = 200
+  /// この件数以上のアプリ・ディレクトリは並列に照合する（少ない場合はスレッドの切り替えの方が高くつく）
+  public static let defaultParallelMatchThreshold = 2_000
+
+  /// 並列に照合するアプリ・ディレクトリの件数の下限
+  let parallelMatchThreshold: Int
+
+  /// 絵文字・記号の検索プロバイダー
+  private let characterSearch: CharacterSearch
+  /// 実行中のプロセスの一覧を返すプロバイダー
+  private let processes: any ProcessControlling
+  /// Docker のコンテナの一覧を返すプロバイダー
+  private let docker: any DockerListing
+
+  /// SearchService を初期化する。
+  ///
+  /// - Parameters:
+  ///   - characterSearch: 絵文字・記号の検索プロバイダー（記号プレフィックス用）
+  ///   - processes: 実行中のプロセスの一覧を返すプロバイダー（`kill` プレフィックス用）
+  ///   - docker: Docker のコンテナの一覧を返すプロバイダー（`docker` プレフィックス用）
+  ///   - parallelMatchThreshold: この件数以上のアプリ・ディレクトリは並列に照合する
+  public init(
+    characterSearch: CharacterSearch = .shared,
+    processes: any ProcessControlling = ProcessManager.shared,
+    docker: any DockerListing = DockerService.shared,
+    parallelMatchThreshold: Int = SearchService.defaultParallelMatchThreshold
+  ) {
+    self.characterSearch = characterSearch
+    self.processes = processes
+    self.docker = docker
+    self.parallelMatchThreshold = parallelMatchThreshold
+  }
 
-  public init() {}
+  /// Docker のコンテナの一覧を取得し直す。
+  ///
+  /// - Returns: 一覧が変わった場合は true（呼び出し側は検索し直す）
+  public func refreshDockerContainers() async -> Bool {
+    let previous = docker.cachedContainers()
+    return await docker.refresh() != previous
+  }
 
   /// 統合検索を実行する
   ///
@@ -125,64 +484,578 @@ public struct SearchService: Sendable {
   ///   - directories: ディレクトリ一覧
   ///   - commands: カスタムコマンド一覧
   ///   - history: 選択履歴エントリ
-  /// - Returns: スコア順にソートされた検索結果（最大20件）
+  ///   - prefixes: 検索対象を絞り込むプレフィックス設定
+  ///   - defaultScope: プレフィックスがない場合の検索対象（モード別ホットキー用）
+  ///   - recentProjects: 空クエリ時に先頭へ表示する最近開いたディレクトリ（新しい順）
+  ///   - resultSettings: 結果の最大件数（全体・種別ごと）と同スコア時の並べ方
+  /// - Returns: スコア順にソートされた検索結果（最大 `resultSettings.maxResults` 件）
   public func search(
     query: String,
     apps: [AppItem],
     directories: [DirectoryItem],
     commands: [CustomCommand],
-    history: [SelectionHistoryEntry]
+    history: [SelectionHistoryEntry],
+    prefixes: SearchPrefixSettings = .default,
+    defaultScope: SearchScope = .all,
+    recentProjects: [RecentProject] = [],
+    resultSettings: ResultSettings = .default
   ) -> [SearchResult] {
+    (try? cancellableSearch(
+      query: query, apps: apps, directories: directories, commands: commands,
+      history: history, prefixes: prefixes, defaultScope: defaultScope,
+      recentProjects: recentProjects, resultSettings: resultSettings)) ?? []
+  }
+
+  /// 統合検索を実行する（キャンセル対応版）。
+  ///
+  /// 各項目の照合前に現在のタスクのキャンセルを確認し、キャンセルされていれば
+  /// 残りの照合を打ち切る。引数は `search` と同じ。
+  /// - Throws: 実行中のタスクがキャンセルされた場合は `CancellationError`
+  public func cancellableSearch(
+    query: String,
+    apps: [AppItem],
+    directories: [DirectoryItem],
+    commands: [CustomCommand],
+    history: [SelectionHistoryEntry],
+    prefixes: SearchPrefixSettings = .default,
+    defaultScope: SearchScope = .all,
+    recentProjects: [RecentProject] = [],
+    resultSettings: ResultSettings = .default
+  ) throws -> [SearchResult] {
+    try cancellableSearch(
+      query: query,
+      index: SearchIndex(apps: apps, directories: directories, commands: commands),
+      history: history, prefixes: prefixes, defaultScope: defaultScope,
+      recentProjects: recentProjects, resultSettings: resultSettings)
+  }
+
+  /// 構築済みのインデックスに対して統合検索を実行する。
+  ///
+  /// - Parameters:
+  ///   - query: 検索クエリ（全角英数字は自動で半角に正規化される）
+  ///   - index: 正規化済みの検索対象
+  ///   - history: 選択履歴エントリ
+  ///   - prefixes: 検索対象を絞り込むプレフィックス設定
+  ///   - defaultScope: プレフィックスがない場合の検索対象（モード別ホットキー用）
+  ///   - recentProjects: 空クエリ時に先頭へ表示する最近開いたディレクトリ（新しい順）
+  ///   - resultSettings: 結果の最大件数（全体・種別ごと）と同スコア時の並べ方
+  /// - Returns: スコア順にソートされた検索結果（最大 `resultSettings.maxResults` 件）
+  public func search(
+    query: String,
+    index: SearchIndex,
+    history: [SelectionHistoryEntry],
+    prefixes: SearchPrefixSettings = .default,
+    defaultScope: SearchScope = .all,
+    recentProjects: [RecentProject] = [],
+    resultSettings: ResultSettings = .default
+  ) -> [SearchResult] {
+    (try? cancellableSearch(
+      query: query, index: index, history: history, prefixes: prefixes,
+      defaultScope: defaultScope, recentProjects: recentProjects,
+      resultSettings: resultSettings)) ?? []
+  }
+
+  /// 構築済みのインデックスに対して統合検索を実行する（キャンセル対応版）。
+  ///
+  /// 返す結果には、名前のうち検索語に一致した文字の位置（`matchedIndices`）を付ける。
+  /// `resultSettings.groupResults` が有効な場合は、見出しの順（`orderedGroups`）に並べ替える。
+  /// - Throws: 実行中のタスクがキャンセルされた場合は `CancellationError`
+  public func cancellableSearch(
+    query: String,
+    index: SearchIndex,
+    history: [SelectionHistoryEntry],
+    prefixes: SearchPrefixSettings = .default,
+    defaultScope: SearchScope = .all,
+    recentProjects: [RecentProject] = [],
+    resultSettings: ResultSettings = .default
+  ) throws -> [SearchResult] {
+    let results = try matchingResults(
+      query: query, index: index, history: history, prefixes: prefixes,
+      defaultScope: defaultScope, recentProjects: recentProjects,
+      resultSettings: resultSettings)
+    let highlighted = Self.highlighted(
+      results, term: SearchQueryParser.parse(query, prefixes: prefixes).term)
+    guard resultSettings.groupResults else { return highlighted }
+    return SearchResultSection.sections(of: highlighted, order: resultSettings.groupOrder)
+      .flatMap(\.results)
+  }
+
+  /// スコアの内訳を確認するため、件数の上限（種別ごとを含む）を最大にして検索する。
+  ///
+  /// 結果の `breakdown` に、ファジー一致・種別ごとの補正・選択履歴・別名のスコアが入る。
+  /// - Returns: スコア順にソートされた検索結果（最大 `ResultSettings.maxResultsRange` の上限件）
+  public func debugSearch(
+    query: String,
+    index: SearchIndex,
+    history: [SelectionHistoryEntry],
+    prefixes: SearchPrefixSettings = .default,
+    tieBreak: ResultTieBreak = .frecency
+  ) -> [SearchResult] {
+    search(
+      query: query, index: index, history: history, prefixes: prefixes,
+      resultSettings: ResultSettings(
+        maxResults: ResultSettings.maxResultsRange.upperBound, tieBreak: tieBreak))
+  }
+
+  /// 検索語に一致した結果をスコア順に返す（`cancellableSearch` の本体）。
+  private func matchingResults(
+    query: String,
+    index: SearchIndex,
+    history: [SelectionHistoryEntry],
+    prefixes: SearchPrefixSettings,
+    defaultScope: SearchScope,
+    recentProjects: [RecentProject],
+    resultSettings: ResultSettings
+  ) throws -> [SearchResult] {
     let normalized = SearchQueryNormalizer.normalize(query)
-    guard !normalized.isEmpty else {
-      return recentHistoryResults(
-        apps: apps, directories: directories, commands: commands, history: history)
+    let parsed = SearchQueryParser.parse(query, prefixes: prefixes)
+    let scope = parsed.scope == .all ? defaultScope : parsed.scope
+    let term = parsed.term
+    let maxResults = resultSettings.clampedMaxResults
+
+    // 絵文字・記号検索（`:` プレフィックス）はアプリ等と混ぜずに返す
+    if scope == .characters {
+      return characterSearch.search(term, limit: maxResults)
+    }
+
+    // プロセス検索（`kill` プレフィックス）は検索のたびに最新の一覧を取得する
+    if scope == .processes {
+      return try processResults(term: term, limit: maxResults)
+    }
+
+    // Docker 検索（`docker` プレフィックス）は取得済みの一覧から探す（取得し直しは
+    // LauncherViewModel が refreshDockerContainers で非同期に行う）
+    if scope == .docker {
+      return try dockerResults(term: term, limit: maxResults)
+    }
+
+    // バンドル ID（`bundle:com.apple`）・カテゴリ（`category:developer`）の絞り込みは
+    // すべての条件に一致するアプリだけを返す
+    let bundleQuery = AppBundleQuery.parse(term)
+    let categoryQuery = AppCategoryQuery.parse(bundleQuery?.term ?? term)
+    if scope.includesApps, bundleQuery != nil || categoryQuery != nil {
+      return try filteredAppResults(
+        term: categoryQuery?.term ?? bundleQuery?.term ?? term,
+        index: index, history: history, query: normalized, resultSettings: resultSettings
+      ) { app in
+        bundleQuery?.matches(app) != false && categoryQuery?.matches(app) != false
+      }
+    }
+
+    // Finder のタグの絞り込み（`#red`、`tag:urgent`）はタグに一致するディレクトリだけを返す
+    if scope.includesDirectories, let tagQuery = FinderTagQuery.parse(term) {
+      return try taggedDirectoryResults(
+        tagQuery, index: index, history: history, query: normalized,
+        resultSettings: resultSettings)
+    }
+
+    guard !term.isEmpty else {
+      return defaultItems(
+        index: index, history: history, scope: scope, recentProjects: recentProjects,
+        resultSettings: resultSettings)
     }
 
+    // パターンはクエリごとに 1 度だけ構築し、全項目の照合で使い回す
+    // （カタカナで入力してもひらがなのキーと一致するよう畳み込む）
     let fuse = Fuse(threshold: 0.4)
+    let pattern = fuse.createPattern(from: SearchQueryNormalizer.foldKana(term))
 
     var results: [SearchResult] = []
 
-    // アプリケーション検索
-    for app in apps {
-      let nameScore = fuseScore(fuse: fuse, pattern: normalized, text: app.name)
-      let originalScore: Double? =
-        if let original = app.originalName {
-          fuseScore(fuse: fuse, pattern: normalized, text: original)
-        } else {
-          nil
+    // パス検索（`~` プレフィックス）
+    if scope == .paths {
+      for (entry, score) in try matches(
+        in: index.indexedApps, fuse: fuse, pattern: pattern, byPath: true)
+      {
+        results.append(SearchResult(appItem: entry.item, score: score))
+      }
+      if let searcher = index.directorySearcher {
+        results += fullTextDirectoryResults(
+          searcher: searcher, term: term, field: .path, fuse: fuse, pattern: pattern,
+          index: index)
+      } else {
+        for (entry, score) in try matches(
+          in: index.indexedDirectories, fuse: fuse, pattern: pattern, byPath: true)
+        {
+          results.append(Self.directoryResult(entry.item, fuzzyScore: score))
         }
+      }
+      applyHistoryBoost(results: &results, query: normalized, history: history)
+      rank(&results, index: index, history: history, tieBreak: resultSettings.tieBreak)
+      return limited(results, settings: resultSettings, index: index)
+    }
 
-      let bestScore = min(nameScore ?? 1.0, originalScore ?? 1.0)
-      if bestScore < 1.0 {
-        results.append(SearchResult(appItem: app, score: bestScore))
+    // アプリケーション検索（表示名と元の名前のうち良い方のスコアを採用）
+    if scope.includesApps {
+      for (entry, score) in try matches(in: index.indexedApps, fuse: fuse, pattern: pattern) {
+        results.append(SearchResult(appItem: entry.item, score: score))
       }
     }
 
-    // ディレクトリ検索
-    for dir in directories {
-      if let score = fuseScore(fuse: fuse, pattern: normalized, text: dir.name), score < 1.0 {
-        results.append(SearchResult(directoryItem: dir, score: score))
+    // ディレクトリ検索（Git リポジトリはプロジェクトとして優先する）
+    if let searcher = index.directorySearcher {
+      if scope.includesDirectories {
+        try Task.checkCancellation()
+        results += fullTextDirectoryResults(
+          searcher: searcher, term: term, field: .name, fuse: fuse, pattern: pattern,
+          index: index)
+      }
+    } else if scope.includesDirectories {
+      for (entry, score) in try matches(
+        in: index.indexedDirectories, fuse: fuse, pattern: pattern)
+      {
+        results.append(Self.directoryResult(entry.item, fuzzyScore: score))
       }
     }
 
     // カスタムコマンド検索
-    for cmd in commands {
-      if let score = fuseScore(fuse: fuse, pattern: normalized, text: cmd.alias), score < 1.0 {
-        results.append(SearchResult(customCommand: cmd, score: score))
+    for entry in index.indexedCommands where scope.includesCommands {
+      try Task.checkCancellation()
+      let score = bestScore(fuse: fuse, pattern: pattern, names: entry.names)
+      if score < 1.0 {
+        results.append(SearchResult(customCommand: entry.item, score: score))
+      }
+    }
+
+    // SSH ホスト・tmux セッション検索（`ssh prod-web` と入力しても一致する）
+    for entry in index.indexedTerminalTargets where scope.includesCommands {
+      try Task.checkCancellation()
+      let score = bestScore(fuse: fuse, pattern: pattern, names: entry.names)
+      if score < 1.0 {
+        results.append(SearchResult(terminalTarget: entry.item, score: score))
+      }
+    }
+
+    // クイックリンク検索（検索語なしで開く）
+    for entry in index.indexedQuickLinks where scope == .all {
+      try Task.checkCancellation()
+      let score = bestScore(fuse: fuse, pattern: pattern, names: entry.names)
+      if score < 1.0, let url = entry.item.resolvedURL() {
+        results.append(SearchResult(quickLink: entry.item, url: url, score: score))
+      }
+    }
+
+    // システム設定のパネル検索（英語名などのキーワードでも一致させる）
+    for entry in index.indexedSystemSettingsPanes where scope == .all {
+      try Task.checkCancellation()
+      let score = bestScore(fuse: fuse, pattern: pattern, names: entry.names)
+      if score < 1.0, let url = entry.item.url {
+        results.append(SearchResult(systemSettingsPane: entry.item, url: url, score: score))
+      }
+    }
+
+    // システム操作検索
+    for entry in index.indexedSystemControls where scope == .all {
+      try Task.checkCancellation()
+      let score = bestScore(fuse: fuse, pattern: pattern, names: entry.names)
+      if score < 1.0 {
+        results.append(SearchResult(systemControl: entry.item, score: score))
+      }
+    }
+
+    // ウィンドウ操作検索
+    for entry in index.indexedWindowLayoutActions where scope == .all {
+      try Task.checkCancellation()
+      let score = bestScore(fuse: fuse, pattern: pattern, names: entry.names)
+      if score < 1.0 {
+        results.append(SearchResult(windowLayout: entry.item, score: score))
       }
     }
 
     // 選択履歴による優先度調整
     applyHistoryBoost(results: &results, query: normalized, history: history)
 
-    // スコア順にソートし上位20件を返す
+    // 別名に一致した項目を最優先にする
+    applyAliases(results: &results, term: term, index: index, scope: scope)
+
+    // お気に入りを先頭に、その中と残りはスコア順にソートし、設定の件数まで返す
+    rank(&results, index: index, history: history, tieBreak: resultSettings.tieBreak)
+    return limited(results, settings: resultSettings, index: index)
+  }
+
+  /// Finder のタグの絞り込み語すべてに一致するディレクトリを返す。
+  ///
+  /// 残りの検索語があれば名前で照合し、なければ一致したディレクトリをすべて返す。
+  private func taggedDirectoryResults(
+    _ tagQuery: FinderTagQuery,
+    index: SearchIndex,
+    history: [SelectionHistoryEntry],
+    query: String,
+    resultSettings: ResultSettings
+  ) throws -> [SearchResult] {
+    let fuse = Fuse(threshold: 0.4)
+    let pattern =
+      tagQuery.term.isEmpty
+      ? nil : fuse.createPattern(from: SearchQueryNormalizer.foldKana(tagQuery.term))
+    var results: [SearchResult] = []
+    for entry in index.indexedDirectories {
+      try Task.checkCancellation()
+      let tags = entry.item.finderTags
+      guard tagQuery.tags.allSatisfy({ filter in tags.contains { $0.matches(filter) } })
+      else { continue }
+      let score = pattern == nil ? 0 : bestScore(fuse: fuse, pattern: pattern, names: entry.names)
+      if score < 1.0 {
+        results.append(Self.directoryResult(entry.item, fuzzyScore: score))
+      }
+    }
+    applyHistoryBoost(results: &results, query: query, history: history)
+    rank(&results, index: index, history: history, tieBreak: resultSettings.tieBreak)
+    return limited(results, settings: resultSettings, index: index)
+  }
+
+  /// バンドル ID・カテゴリの絞り込み（`isIncluded`）に一致するアプリを返す。
+  ///
+  /// 残りの検索語（`term`）があれば名前で照合し、なければ一致したアプリをすべて返す。
+  private func filteredAppResults(
+    term: String,
+    index: SearchIndex,
+    history: [SelectionHistoryEntry],
+    query: String,
+    resultSettings: ResultSettings,
+    where isIncluded: (AppItem) -> Bool
+  ) throws -> [SearchResult] {
+    let fuse = Fuse(threshold: 0.4)
+    let pattern =
+      term.isEmpty ? nil : fuse.createPattern(from: SearchQueryNormalizer.foldKana(term))
+    var results: [SearchResult] = []
+    for entry in index.indexedApps {
+      try Task.checkCancellation()
+      guard isIncluded(entry.item) else { continue }
+      let score = pattern == nil ? 0 : bestScore(fuse: fuse, pattern: pattern, names: entry.names)
+      if score < 1.0 {
+        results.append(SearchResult(appItem: entry.item, score: score))
+      }
+    }
+    applyHistoryBoost(results: &results, query: query, history: history)
+    rank(&results, index: index, history: history, tieBreak: resultSettings.tieBreak)
+    return limited(results, settings: resultSettings, index: index)
+  }
+
+  /// 実行中のプロセスを名前で検索する。
+  ///
+  /// 検索語が空の場合は CPU 使用率、メモリ使用量の順に多いものを返す。
+  private func processResults(term: String, limit: Int) throws -> [SearchResult] {
+    let running = processes.runningProcesses()
+    guard !term.isEmpty else {
+      return
+        running
+        .sorted {
+          ($0.cpuUsage ?? 0, $0.memoryBytes) > ($1.cpuUsage ?? 0, $1.memoryBytes)
+        }
+        .prefix(limit)
+        .map { SearchResult(process: $0, score: 0) }
+    }
+
+    let fuse = Fuse(threshold: 0.4)
+    let pattern = fuse.createPattern(from: term)
+    var results: [SearchResult] = []
+    for process in running {
+      try Task.checkCancellation()
+      if let score = fuseScore(fuse: fuse, pattern: pattern, text: process.name.lowercased()),
+        score < 1.0
+      {
+        results.append(SearchResult(process: process, score: score))
+      }
+    }
     results.sort { $0.score < $1.score }
-    return Array(results.prefix(Self.maxResults))
+    return Array(results.prefix(limit))
+  }
+
+  /// Docker のコンテナと Compose のプロジェクトを検索する。
+  ///
+  /// 実行中のコンテナはシェルを開く結果、Compose のプロジェクトはフォルダを開く
+  /// ディレクトリの結果にする。検索語が空の場合は `docker ps` の順に返す。
+  private func dockerResults(term: String, limit: Int) throws -> [SearchResult] {
+    var candidates: [(keys: [String], result: SearchResult)] = []
+    var projectDirectories = Set<String>()
+    for container in docker.cachedContainers() {
+      if let project = container.composeProject, let directory = container.composeWorkingDirectory,
+        projectDirectories.insert(directory).inserted
+      {
+        let item = DirectoryItem(name: project, path: directory)
+        candidates.append(([project.lowercased()], SearchResult(directoryItem: item, score: 0)))
+      }
+      if container.isRunning {
+        let keys = [container.name, container.image, container.composeProject].compactMap {
+          $0?.lowercased()
+        }
+        candidates.append((keys, SearchResult(dockerContainer: container, score: 0)))
+      }
+    }
+    guard !term.isEmpty else {
+      return candidates.prefix(limit).map(\.result)
+    }
+
+    let fuse = Fuse(threshold: 0.4)
+    let pattern = fuse.createPattern(from: term)
+    var results: [SearchResult] = []
+    for candidate in candidates {
+      try Task.checkCancellation()
+      let score = bestScore(fuse: fuse, pattern: pattern, names: candidate.keys)
+      if score < 1.0 {
+        var result = candidate.result
+        result.score = score
+        results.append(result)
+      }
+    }
+    results.sort { $0.score < $1.score }
+    return Array(results.prefix(limit))
+  }
+
+  /// ホームディレクトリ配下のパスを `~` 表記に短縮し、小文字化する。
+  static func abbreviatedPath(_ path: String, home: String) -> String {
+    guard !home.isEmpty, path == home || path.hasPrefix(home + "/") else {
+      return path.lowercased()
+    }
+    return ("~" + path.dropFirst(home.count)).lowercased()
   }
 
   // MARK: - 非公開メソッド
 
+  /// 全文検索インデックスで絞り込んだディレクトリ候補をファジー照合でスコア付けする。
+  ///
+  /// 全文検索は前方一致のため、ファジー照合で一致しない候補にも一定のスコアを与えて残す。
+  /// DB の読み込みに失敗した場合はディレクトリを結果に含めない。
+  private func fullTextDirectoryResults(
+    searcher: any DirectoryFullTextSearching,
+    term: String,
+    field: FullTextSearchField,
+    fuse: Fuse,
+    pattern: Fuse.Pattern?,
+    index: SearchIndex
+  ) -> [SearchResult] {
+    let candidates =
+      (try? searcher.searchDirectories(
+        matching: term, in: field, limit: Self.fullTextCandidateLimit)) ?? []
+    return candidates.filter { !index.hiddenPaths.contains($0.path) }.map { dir in
+      let fuzzy: Double
+      switch field {
+      case .name:
+        let names =
+          SearchQueryNormalizer.searchKeys(for: dir.name)
+          + (dir.originalName.map(SearchQueryNormalizer.searchKeys) ?? [])
+        fuzzy = bestScore(fuse: fuse, pattern: pattern, names: names)
+      case .path:
+        let text = Self.abbreviatedPath(dir.path, home: NSHomeDirectory())
+        fuzzy = fuseScore(fuse: fuse, pattern: pattern, text: text) ?? 1.0
+      }
+      return Self.directoryResult(dir, fuzzyScore: fuzzy < 1.0 ? fuzzy : Self.fullTextMatchScore)
+    }
+  }
+
+  /// ディレクトリ名の一致スコアに Git リポジトリと登録ディレクトリの優先度を反映する。
+  static func adjustedScore(_ score: Double, for directory: DirectoryItem) -> Double {
+    let gitBonus = directory.isGitRepo ? gitRepoScoreBonus : 0
+    return score - gitBonus - Double(directory.priority) * directoryPriorityStep
+  }
+
+  /// `adjustedScore` を反映したディレクトリの結果（補正前のスコアを内訳に残す）
+  static func directoryResult(_ directory: DirectoryItem, fuzzyScore: Double) -> SearchResult {
+    let score = adjustedScore(fuzzyScore, for: directory)
+    var result = SearchResult(directoryItem: directory, score: score)
+    result.scoreBreakdown = SearchScoreBreakdown(
+      fuzzy: fuzzyScore, categoryAdjustment: score - fuzzyScore)
+    return result
+  }
+
+  /// 空クエリ時に表示する既定の項目を返す。
+  ///
+  /// お気に入り → 最近のプロジェクト → 今の時間帯によく使う項目 → 使用回数の多い履歴の順に並べ、
+  /// 同じパスは先に出たものだけ残す。ランチャーを開いた直前から選べるようにする。
+  /// - Parameters:
+  ///   - index: 正規化済みの検索対象
+  ///   - history: 選択履歴エントリ
+  ///   - scope: 検索対象（プレフィックス・モード別ホットキーで絞り込んだ場合）
+  ///   - recentProjects: 最近開いたディレクトリ（新しい順）
+  ///   - resultSettings: 結果の最大件数（全体・種別ごと）と同スコア時の並べ方
+  ///   - now: 時間帯の判定に使う現在日時
+  /// - Returns: 既定の項目（最大 `resultSettings.maxResults` 件）
+  public func defaultItems(
+    index: SearchIndex,
+    history: [SelectionHistoryEntry],
+    scope: SearchScope = .all,
+    recentProjects: [RecentProject] = [],
+    resultSettings: ResultSettings = .default,
+    now: Date = Date()
+  ) -> [SearchResult] {
+    let projects: [SearchResult] =
+      if scope.includesDirectories {
+        recentProjectResults(recentProjects, directories: index.directories)
+      } else {
+        []
+      }
+    let suggested = timeOfDaySuggestions(
+      index: index, scope: scope, history: history,
+      hour: Calendar.current.component(.hour, from: now), now: now)
+    let recent = recentHistoryResults(
+      apps: scope.includesApps ? index.apps : [],
+      directories: scope.includesDirectories ? index.directories : [],
+      commands: scope.includesCommands ? index.commands : [],
+      history: history,
+      tieBreak: resultSettings.tieBreak
+    )
+    var seen = Set<String>()
+    let merged = (favoriteResults(index: index, scope: scope) + projects + suggested + recent)
+      .filter { seen.insert($0.path).inserted }
+      .map { markingFavorite($0, index: index) }
+    return limited(merged, settings: resultSettings, index: index)
+  }
+
+  /// 時間帯の提案として表示する最大件数
+  static let timeOfDaySuggestionLimit = 3
+
+  /// 今の時間帯（前後 1 時間）に繰り返し使っている項目を、頻度の高い順に返す。
+  ///
+  /// 1 度しか使っていない項目は偶然の可能性が高いため提案しない。
+  private func timeOfDaySuggestions(
+    index: SearchIndex,
+    scope: SearchScope,
+    history: [SelectionHistoryEntry],
+    hour: Int,
+    now: Date
+  ) -> [SearchResult] {
+    var usage: [String: Int] = [:]
+    for entry in history {
+      usage[entry.selectedPath, default: 0] += entry.usage(aroundHour: hour)
+    }
+    let frecency = Self.frecencyScores(history: history, now: now)
+    return usage.filter { $0.value >= 2 }
+      .sorted {
+        if $0.value != $1.value { return $0.value > $1.value }
+        return (frecency[$0.key] ?? 0) > (frecency[$1.key] ?? 0)
+      }
+      .compactMap { index.indexedResult(for: $0.key, score: -Double($0.value)) }
+      .filter { scope.includes($0.kind) }
+      .prefix(Self.timeOfDaySuggestionLimit)
+      .map { $0 }
+  }
+
+  /// 最近開いたディレクトリを検索結果に変換する。
+  ///
+  /// キャッシュ済みのディレクトリに存在するものだけを新しい順に返し、
+  /// 最後に開いたエディタがあればそのエディタで開くようにする。
+  private func recentProjectResults(
+    _ recentProjects: [RecentProject],
+    directories: [DirectoryItem]
+  ) -> [SearchResult] {
+    guard !recentProjects.isEmpty else { return [] }
+    let dirsByPath = Dictionary(
+      directories.map { ($0.path, $0) }, uniquingKeysWith: { first, _ in first })
+    var seen = Set<String>()
+    var results: [SearchResult] = []
+    for project in recentProjects.sorted(by: { $0.lastOpened > $1.lastOpened }) {
+      guard let dir = dirsByPath[project.path], seen.insert(project.path).inserted else {
+        continue
+      }
+      let item = DirectoryItem(
+        name: dir.name, path: dir.path, editor: project.editor ?? dir.editor,
+        isGitRepo: dir.isGitRepo, gitBranch: dir.gitBranch, priority: dir.priority,
+        originalName: dir.originalName)
+      results.append(SearchResult(directoryItem: item, score: -Double(project.openCount)))
+    }
+    return results
+  }
+
   /// 空クエリ時に選択履歴から最近使った項目を返す。
   ///
   /// 同一パスの履歴を集約し、使用回数と最終使用日時で優先度を決定する。
@@ -190,7 +1063,8 @@ public struct SearchService: Sendable {
     apps: [AppItem],
     directories: [DirectoryItem],
     commands: [CustomCommand],
-    history: [SelectionHistoryEntry]
+    history: [SelectionHistoryEntry],
+    tieBreak: ResultTieBreak
   ) -> [SearchResult] {
     guard !history.isEmpty else { return [] }
 
@@ -233,16 +1107,261 @@ public struct SearchService: Sendable {
       if $0.result.score != $1.result.score {
         return $0.result.score < $1.result.score
       }
-      if $0.lastUsed != $1.lastUsed {
+      if tieBreak == .frecency, $0.lastUsed != $1.lastUsed {
         return $0.lastUsed > $1.lastUsed
       }
       return $0.result.name.localizedCaseInsensitiveCompare($1.result.name) == .orderedAscending
     }
-    return Array(results.prefix(Self.maxResults).map(\.result))
+    return results.map(\.result)
+  }
+
+  /// 正規化済みのテキストとパターンを照合する（一致しない場合は nil）。
+  private func fuseScore(fuse: Fuse, pattern: Fuse.Pattern?, text: String) -> Double? {
+    fuse.search(pattern, in: text)?.score
+  }
+
+  /// 複数の名前のうち最も良いスコアを返す（一致しない場合は 1.0）。
+  ///
+  /// キー入力ごとに全項目で呼ばれるため、クロージャや中間の配列を作らずに照合し、
+  /// 完全一致した時点で残りの名前の照合を省く。
+  private func bestScore(fuse: Fuse, pattern: Fuse.Pattern?, names: [String]) -> Double {
+    var best = 1.0
+    for name in names {
+      if let score = fuseScore(fuse: fuse, pattern: pattern, text: name), score < best {
+        best = score
+        if best == 0 { break }
+      }
+    }
+    return best
+  }
+
+  /// 項目を照合し、一致した項目とスコアを元の順に返す。
+  ///
+  /// 名前のスコアが `keywordScorePenalty` より悪い項目は、キーワードのスコアに
+  /// `keywordScorePenalty` を加えた値と比べて良い方を採用する。
+  /// `parallelMatchThreshold` 件以上の場合は CPU コア数に合わせた塊に分けて並列に照合する。
+  /// 塊ごとの結果を元の順に連結するため、結果の順序は直列に照合した場合と変わらない。
+  /// - Parameter byPath: 名前ではなく `~` 表記のパスと照合する（パス検索用）
+  /// - Throws: 実行中のタスクがキャンセルされた場合は `CancellationError`
+  private func matches<Item: Sendable>(
+    in entries: [IndexedItem<Item>], fuse: Fuse, pattern: Fuse.Pattern?, byPath: Bool = false
+  ) throws -> [(entry: IndexedItem<Item>, score: Double)] {
+    typealias Match = (entry: IndexedItem<Item>, score: Double)
+    func score(_ entry: IndexedItem<Item>) -> Double {
+      if byPath {
+        return fuseScore(fuse: fuse, pattern: pattern, text: entry.abbreviatedPath) ?? 1.0
+      }
+      let nameScore = bestScore(fuse: fuse, pattern: pattern, names: entry.names)
+      guard nameScore > Self.keywordScorePenalty, !entry.keywords.isEmpty else { return nameScore }
+      let keywordScore = bestScore(fuse: fuse, pattern: pattern, names: entry.keywords)
+      guard keywordScore < 1.0 else { return nameScore }
+      return min(nameScore, keywordScore + Self.keywordScorePenalty)
+    }
+
+    guard entries.count >= parallelMatchThreshold else {
+      var matches: [Match] = []
+      for entry in entries {
+        try Task.checkCancellation()
+        let score = score(entry)
+        if score < 1.0 {
+          matches.append((entry, score))
+        }
+      }
+      return matches
+    }
+
+    // ワーカースレッドではタスクのキャンセルを確認できないため、照合の前後で確認する
+    try Task.checkCancellation()
+    let chunkCount = min(ProcessInfo.processInfo.activeProcessorCount * 4, entries.count)
+    let chunkSize = (entries.count + chunkCount - 1) / chunkCount
+    let chunks = Mutex([[Match]](repeating: [], count: chunkCount))
+    // Fuse の照合はパターンと引数を読むだけなので、同じインスタンスを複数のスレッドから使える
+    DispatchQueue.concurrentPerform(iterations: chunkCount) { chunk in
+      let lower = min(chunk * chunkSize, entries.count)
+      let upper = min(lower + chunkSize, entries.count)
+      var matches: [Match] = []
+      for entry in entries[lower..<upper] {
+        let score = score(entry)
+        if score < 1.0 {
+          matches.append((entry, score))
+        }
+      }
+      chunks.withLock { $0[chunk] = matches }
+    }
+    try Task.checkCancellation()
+    return chunks.withLock { $0.flatMap { $0 } }
+  }
+
+  /// お気に入りを先頭に、それぞれスコア順に並べる。
+  ///
+  /// スコアが同じ結果は `tieBreak` に従い、頻度（使用回数を最終使用からの経過日数で減衰させた値）
+  /// の高い順、または名前順に並べる。頻度も同じ場合は照合した順（アプリ → ディレクトリ → コマンド）を保つ。
+  private func rank(
+    _ results: inout [SearchResult],
+    index: SearchIndex,
+    history: [SelectionHistoryEntry],
+    tieBreak: ResultTieBreak
+  ) {
+    results = results.map { markingFavorite($0, index: index) }
+    let frecency = tieBreak == .frecency ? Self.frecencyScores(history: history) : [:]
+    results.sort {
+      if $0.isFavorite != $1.isFavorite {
+        return $0.isFavorite
+      }
+      if $0.score != $1.score {
+        return $0.score < $1.score
+      }
+      switch tieBreak {
+      case .frecency:
+        return (frecency[$0.path] ?? 0) > (frecency[$1.path] ?? 0)
+      case .alphabetical:
+        return $0.name.localizedCaseInsensitiveCompare($1.name) == .orderedAscending
+      }
+    }
+  }
+
+  /// パスごとの頻度（使用回数 ÷ (1 + 最終使用からの経過日数 / 7)）を返す。
+  static func frecencyScores(
+    history: [SelectionHistoryEntry], now: Date = Date()
+  ) -> [String: Double] {
+    var scores: [String: Double] = [:]
+    for entry in history {
+      let days = max(now.timeIntervalSince(entry.lastUsed), 0) / 86_400
+      scores[entry.selectedPath, default: 0] += Double(entry.count) / (1 + days / 7)
+    }
+    return scores
+  }
+
+  /// 全体と種別ごとの最大件数まで、並び順を保って結果を絞り込む。
+  ///
+  /// シンボリックリンクなどで実体が同じアプリ・ディレクトリ（実体のパスはインデックスの構築時に
+  /// 解決済み）は先に出たものだけ残し、同じ名前の結果には見分けるための親ディレクトリを付ける。
+  private func limited(
+    _ results: [SearchResult], settings: ResultSettings, index: SearchIndex
+  ) -> [SearchResult] {
+    let maxResults = settings.clampedMaxResults
+    var counts: [SearchResultKind: Int] = [:]
+    var seenCanonicalPaths = Set<String>()
+    var limited: [SearchResult] = []
+    for result in results {
+      guard limited.count < maxResults else { break }
+      if result.kind == .app || result.kind == .directory {
+        guard seenCanonicalPaths.insert(index.canonicalPath(for: result.path)).inserted else {
+          continue
+        }
+      }
+      if let limit = settings.limit(for: result.kind) {
+        guard counts[result.kind, default: 0] < limit else { continue }
+        counts[result.kind, default: 0] += 1
+      }
+      limited.append(result)
+    }
+    return Self.disambiguated(limited)
+  }
+
+  /// 結果の名前のうち検索語に一致した文字の位置を `matchedIndices` に設定する。
+  ///
+  /// 件数を絞り込んだ後の結果だけを照合し直す。`bundle:` / `category:` / `#` などの絞り込み語は除いた
+  /// 残りの語で照合し、名前以外（別名・パス・ローマ字など）で一致した結果は空のままにする。
+  static func highlighted(_ results: [SearchResult], term: String) -> [SearchResult] {
+    let bundleQuery = AppBundleQuery.parse(term)
+    let appTerm = AppCategoryQuery.parse(bundleQuery?.term ?? term)?.term ?? bundleQuery?.term
+    let text = appTerm ?? FinderTagQuery.parse(term)?.term ?? term
+    guard !text.isEmpty, !results.isEmpty else { return results }
+    let fuse = Fuse(threshold: 0.4)
+    let pattern = fuse.createPattern(from: SearchQueryNormalizer.foldKana(text))
+    return results.map { result in
+      var highlighted = result
+      highlighted.matchedIndices = matchedIndices(
+        in: result.name, fuse: fuse, pattern: pattern)
+      return highlighted
+    }
+  }
+
+  /// 名前のうちパターンに一致した文字の位置（Character 単位の昇順。一致しない場合は空）
+  static func matchedIndices(in name: String, fuse: Fuse, pattern: Fuse.Pattern?) -> [Int] {
+    // カタカナの名前もひらがなに畳み込んだ検索語と照合する（文字数は変わらない）
+    guard let match = fuse.search(pattern, in: SearchQueryNormalizer.foldKana(name)) else {
+      return []
+    }
+    let count = name.count
+    return Array(Set(match.ranges.flatMap { Array($0) }).filter { $0 < count }).sorted()
+  }
+
+  /// シンボリックリンクと `.` / `..` を解決した実体のパス（ファイルシステムを読む）
+  static func canonicalPath(_ path: String) -> String {
+    URL(fileURLWithPath: path).resolvingSymlinksInPath().standardizedFileURL.path
+  }
+
+  /// 同じ名前（大文字小文字を区別しない）のアプリ・ディレクトリが複数ある場合に、
+  /// それぞれへ親ディレクトリを `disambiguation` として付ける。
+  static func disambiguated(_ results: [SearchResult]) -> [SearchResult] {
+    func key(_ result: SearchResult) -> String? {
+      result.kind == .app || result.kind == .directory ? result.name.lowercased() : nil
+    }
+    var nameCounts: [String: Int] = [:]
+    for result in results {
+      if let key = key(result) { nameCounts[key, default: 0] += 1 }
+    }
+    guard nameCounts.values.contains(where: { $0 > 1 }) else { return results }
+    return results.map { result in
+      guard let key = key(result), nameCounts[key, default: 0] > 1 else { return result }
+      var disambiguated = result
+      disambiguated.disambiguation =
+        ((result.path as NSString).deletingLastPathComponent as NSString)
+        .abbreviatingWithTildeInPath
+      return disambiguated
+    }
+  }
+
+  /// お気に入りであれば結果に印を付ける。
+  private func markingFavorite(_ result: SearchResult, index: SearchIndex) -> SearchResult {
+    guard index.favorites.contains(result.path) else { return result }
+    var marked = result
+    marked.isFavorite = true
+    return marked
   }
 
-  private func fuseScore(fuse: Fuse, pattern: String, text: String) -> Double? {
-    fuse.search(pattern, in: text.lowercased())?.score
+  /// 空クエリ時に表示するお気に入りの項目を名前順に返す。
+  private func favoriteResults(index: SearchIndex, scope: SearchScope) -> [SearchResult] {
+    guard !index.favorites.isEmpty else { return [] }
+    var results: [SearchResult] = []
+    if scope.includesApps {
+      results += index.apps.filter { index.favorites.contains($0.path) }
+        .map { SearchResult(appItem: $0, score: 0) }
+    }
+    if scope.includesDirectories {
+      results += index.directories.filter { index.favorites.contains($0.path) }
+        .map { SearchResult(directoryItem: $0, score: 0) }
+    }
+    return results.sorted {
+      $0.name.localizedCaseInsensitiveCompare($1.name) == .orderedAscending
+    }
+  }
+
+  /// 検索語に一致する別名の項目を結果に加え、強く優先する。
+  ///
+  /// 既に結果に含まれる項目はスコアを引き上げ、含まれない項目は追加する。
+  private func applyAliases(
+    results: inout [SearchResult],
+    term: String,
+    index: SearchIndex,
+    scope: SearchScope
+  ) {
+    for target in index.aliasTargets where target.key.hasPrefix(term) {
+      let score = target.key == term ? Self.aliasExactScore : Self.aliasPrefixScore
+      if let i = results.firstIndex(where: { $0.path == target.path }) {
+        if score < results[i].score {
+          results[i].scoreBreakdown = results[i].breakdown.withAliasScore(score)
+          results[i].score = score
+        }
+      } else if var result = index.aliasResult(for: target.path, score: score),
+        scope.includes(result.kind)
+      {
+        result.scoreBreakdown = SearchScoreBreakdown(fuzzy: 1.0, aliasScore: score)
+        results.append(result)
+      }
+    }
   }
 
   private func applyHistoryBoost(
@@ -262,14 +1381,48 @@ public struct SearchService: Sendable {
       }) {
         // 完全一致は最高優先度: スコアを大幅に下げる（負のスコアを許可）
         let countBoost = min(Double(exactEntry.count) * 0.01, 0.5)
+        results[i].scoreBreakdown = results[i].breakdown.withHistoryBoost(-(1.0 + countBoost))
         results[i].score -= 1.0 + countBoost
       } else if let prefixEntry = history.first(where: {
         SearchQueryNormalizer.normalize($0.keyword).hasPrefix(query) && $0.selectedPath == path
       }) {
         // 前方一致は中程度の優先度
         let countBoost = min(Double(prefixEntry.count) * 0.005, 0.2)
+        results[i].scoreBreakdown = results[i].breakdown.withHistoryBoost(-(0.5 + countBoost))
         results[i].score -= 0.5 + countBoost
       }
     }
   }
 }
+
+// MARK: - SearchScope ヘルパー
+
+extension SearchScope {
+  /// アプリケーションを検索対象に含むか
+  var includesApps: Bool {
+    self == .all || self == .apps || self == .paths
+  }
+
+  /// ディレクトリを検索対象に含むか
+  var includesDirectories: Bool {
+    self == .all || self == .directories || self == .paths
+  }
+
+  /// カスタムコマンドを検索対象に含むか
+  var includesCommands: Bool {
+    self == .all || self == .commands
+  }
+
+  /// 指定種別の検索結果を検索対象に含むか
+  func includes(_ kind: SearchResultKind) -> Bool {
+    switch kind {
+    case .app: includesApps
+    case .directory: includesDirectories
+    case .command: includesCommands
+    case .webSearch, .colorPicker, .emoji, .systemSettings, .systemControl, .windowLayout:
+      self == .all
+    case .character: self == .characters
+    case .process: self == .processes
+    }
+  }
+}
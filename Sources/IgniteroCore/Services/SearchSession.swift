@@ -0,0 +1,66 @@
+import Foundation
+
+/// 入力中の検索クエリを順に処理する検索セッション。
+///
+/// 新しいクエリを受け付けると待機中・実行中の検索をキャンセルし、最新のクエリの結果だけを
+/// 反映する。検索本体はメインスレッド外で実行し、キー入力の連打で UI が詰まらないようにする。
+@MainActor
+public final class SearchSession {
+
+  /// 最後の入力から検索を開始するまでの待ち時間
+  public var debounce: Duration
+
+  /// 待機中または実行中の検索があるか
+  public var isPending: Bool { task != nil }
+
+  private var task: Task<Void, Never>?
+
+  /// 送信ごとに増える世代番号（古い検索の結果を破棄するために使う）
+  private var generation = 0
+
+  /// SearchSession を初期化する。
+  ///
+  /// - Parameter debounce: 最後の入力から検索を開始するまでの待ち時間
+  public init(debounce: Duration = .milliseconds(30)) {
+    self.debounce = debounce
+  }
+
+  /// 検索を送信する。待機中・実行中の検索はキャンセルされる。
+  ///
+  /// - Parameters:
+  ///   - work: バックグラウンドで実行する検索処理（キャンセル時は `CancellationError` を投げる）
+  ///   - onComplete: 最新の検索が完了した場合にメインアクターで呼ばれる
+  public func submit(
+    _ work: @escaping @Sendable () throws -> [SearchResult],
+    onComplete: @escaping @MainActor ([SearchResult]) -> Void
+  ) {
+    cancel()
+    let current = generation
+    task = Task { [weak self, debounce] in
+      if debounce > .zero {
+        do { try await Task.sleep(for: debounce) } catch { return }
+      }
+      let search = Task.detached(priority: .userInitiated) { try work() }
+      let results: [SearchResult]
+      do {
+        results = try await withTaskCancellationHandler {
+          try await search.value
+        } onCancel: {
+          search.cancel()
+        }
+      } catch {
+        return
+      }
+      guard let self, !Task.isCancelled, current == self.generation else { return }
+      self.task = nil
+      onComplete(results)
+    }
+  }
+
+  /// 待機中・実行中の検索をキャンセルする（結果は反映されない）。
+  public func cancel() {
+    generation += 1
+    task?.cancel()
+    task = nil
+  }
+}
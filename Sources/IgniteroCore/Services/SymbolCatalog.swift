@@ -0,0 +1,87 @@
+import Foundation
+
+/// 記号 1 文字と検索用の名前・キーワード
+public struct SymbolEntry: Sendable, Equatable {
+  /// 記号の文字
+  public let character: String
+  /// 表示名
+  public let name: String
+  /// 検索キーワード（英語・日本語）
+  public let keywords: [String]
+
+  public init(character: String, name: String, keywords: [String]) {
+    self.character = character
+    self.name = name
+    self.keywords = keywords
+  }
+}
+
+/// 絵文字データセットに含まれない Unicode 記号の組み込みデータセット。
+///
+/// キーボード記号・矢印・数学記号・通貨記号・約物など、入力しにくいが頻繁に使う記号を収録する。
+public enum SymbolCatalog {
+
+  public static let entries: [SymbolEntry] = [
+    // チェック・バツ
+    SymbolEntry(character: "✓", name: "チェックマーク", keywords: ["check mark", "check", "tick", "チェック"]),
+    SymbolEntry(character: "✔", name: "太字チェックマーク", keywords: ["heavy check mark", "check", "tick", "チェック"]),
+    SymbolEntry(character: "✗", name: "バツ印", keywords: ["ballot x", "cross mark", "x mark", "バツ"]),
+    SymbolEntry(character: "✘", name: "太字バツ印", keywords: ["heavy ballot x", "cross mark", "x mark", "バツ"]),
+    SymbolEntry(character: "☐", name: "チェックボックス", keywords: ["ballot box", "checkbox", "チェックボックス"]),
+    // キーボード記号
+    SymbolEntry(character: "⌘", name: "Command キー", keywords: ["command", "cmd", "place of interest", "コマンド"]),
+    SymbolEntry(character: "⌥", name: "Option キー", keywords: ["option", "alt", "オプション"]),
+    SymbolEntry(character: "⇧", name: "Shift キー", keywords: ["shift", "シフト"]),
+    SymbolEntry(character: "⌃", name: "Control キー", keywords: ["control", "ctrl", "コントロール"]),
+    SymbolEntry(character: "⇪", name: "Caps Lock キー", keywords: ["caps lock", "キャプスロック"]),
+    SymbolEntry(character: "⏎", name: "Return キー", keywords: ["return", "enter", "リターン"]),
+    SymbolEntry(character: "⌫", name: "Delete キー", keywords: ["delete", "backspace", "削除"]),
+    SymbolEntry(character: "⌦", name: "前方削除キー", keywords: ["forward delete", "delete right", "削除"]),
+    SymbolEntry(character: "⎋", name: "Escape キー", keywords: ["escape", "esc", "エスケープ"]),
+    SymbolEntry(character: "⇥", name: "Tab キー", keywords: ["tab", "タブ"]),
+    SymbolEntry(character: "␣", name: "スペース記号", keywords: ["space", "open box", "スペース"]),
+    // 矢印
+    SymbolEntry(character: "→", name: "右矢印", keywords: ["right arrow", "arrow", "矢印", "みぎ"]),
+    SymbolEntry(character: "←", name: "左矢印", keywords: ["left arrow", "arrow", "矢印", "ひだり"]),
+    SymbolEntry(character: "↑", name: "上矢印", keywords: ["up arrow", "arrow", "矢印", "うえ"]),
+    SymbolEntry(character: "↓", name: "下矢印", keywords: ["down arrow", "arrow", "矢印", "した"]),
+    SymbolEntry(character: "↔", name: "左右矢印", keywords: ["left right arrow", "arrow", "矢印"]),
+    SymbolEntry(character: "⇒", name: "右二重矢印", keywords: ["rightwards double arrow", "implies", "ならば"]),
+    SymbolEntry(character: "⇔", name: "左右二重矢印", keywords: ["left right double arrow", "iff", "同値"]),
+    SymbolEntry(character: "↩", name: "左向きフック矢印", keywords: ["return arrow", "undo", "戻る"]),
+    // 数学記号
+    SymbolEntry(character: "±", name: "プラスマイナス", keywords: ["plus minus", "プラスマイナス"]),
+    SymbolEntry(character: "×", name: "乗算記号", keywords: ["multiplication", "times", "かける"]),
+    SymbolEntry(character: "÷", name: "除算記号", keywords: ["division", "divide", "わる"]),
+    SymbolEntry(character: "≠", name: "等しくない", keywords: ["not equal", "ノットイコール"]),
+    SymbolEntry(character: "≈", name: "ほぼ等しい", keywords: ["almost equal", "approximately", "ニアリーイコール"]),
+    SymbolEntry(character: "≤", name: "小なりイコール", keywords: ["less than or equal", "以下"]),
+    SymbolEntry(character: "≥", name: "大なりイコール", keywords: ["greater than or equal", "以上"]),
+    SymbolEntry(character: "∞", name: "無限大", keywords: ["infinity", "むげん"]),
+    SymbolEntry(character: "√", name: "平方根", keywords: ["square root", "root", "ルート"]),
+    SymbolEntry(character: "∑", name: "総和", keywords: ["sum", "sigma", "シグマ"]),
+    SymbolEntry(character: "π", name: "パイ", keywords: ["pi", "パイ"]),
+    SymbolEntry(character: "°", name: "度", keywords: ["degree", "ど"]),
+    SymbolEntry(character: "µ", name: "マイクロ", keywords: ["micro", "mu", "マイクロ"]),
+    // 通貨
+    SymbolEntry(character: "€", name: "ユーロ", keywords: ["euro", "currency", "ユーロ"]),
+    SymbolEntry(character: "£", name: "ポンド", keywords: ["pound", "currency", "ポンド"]),
+    SymbolEntry(character: "¥", name: "円", keywords: ["yen", "yuan", "currency", "えん"]),
+    SymbolEntry(character: "₩", name: "ウォン", keywords: ["won", "currency", "ウォン"]),
+    SymbolEntry(character: "₿", name: "ビットコイン", keywords: ["bitcoin", "currency", "ビットコイン"]),
+    // 約物・その他
+    SymbolEntry(character: "…", name: "三点リーダー", keywords: ["ellipsis", "dots", "さんてんりーだー"]),
+    SymbolEntry(character: "—", name: "全角ダッシュ", keywords: ["em dash", "dash", "ダッシュ"]),
+    SymbolEntry(character: "–", name: "半角ダッシュ", keywords: ["en dash", "dash", "ダッシュ"]),
+    SymbolEntry(character: "•", name: "中黒（ビュレット）", keywords: ["bullet", "ビュレット"]),
+    SymbolEntry(character: "·", name: "中点", keywords: ["middle dot", "interpunct", "なかてん"]),
+    SymbolEntry(character: "§", name: "節記号", keywords: ["section", "セクション"]),
+    SymbolEntry(character: "¶", name: "段落記号", keywords: ["pilcrow", "paragraph", "だんらく"]),
+    SymbolEntry(character: "©", name: "著作権記号", keywords: ["copyright", "コピーライト"]),
+    SymbolEntry(character: "®", name: "登録商標", keywords: ["registered", "trademark", "とうろくしょうひょう"]),
+    SymbolEntry(character: "™", name: "商標", keywords: ["trademark", "tm", "しょうひょう"]),
+    SymbolEntry(character: "※", name: "米印", keywords: ["reference mark", "note", "こめじるし"]),
+    SymbolEntry(character: "〒", name: "郵便記号", keywords: ["postal mark", "ゆうびん"]),
+    SymbolEntry(character: "¯\\_(ツ)_/¯", name: "肩をすくめる顔文字", keywords: ["shrug", "shruggie", "かおもじ"]),
+  ]
+}
@@ -0,0 +1,177 @@
+import Foundation
+
+// MARK: - SystemControl
+
+/// ランチャーから確認なしで実行できるシステム操作（消音・音量・ダークモードなど）。
+public enum SystemControl: String, CaseIterable, Sendable {
+  case toggleMute = "toggle_mute"
+  case volumeUp = "volume_up"
+  case volumeDown = "volume_down"
+  case toggleDoNotDisturb = "toggle_do_not_disturb"
+  case toggleDarkMode = "toggle_dark_mode"
+  case startScreenSaver = "start_screen_saver"
+
+  /// 検索結果の path に使う識別子のスキーム
+  public static let identifierScheme = "system-control"
+
+  /// 音量の 1 回の変化量（音量キー 1 回分 = 100 / 16）
+  static let volumeStep = 6.25
+
+  /// おやすみモードの切り替えに実行するショートカット（ショートカット.app で作成する）
+  ///
+  /// 集中モードを切り替える公開 API がないため、「集中モードを設定」アクションを含む
+  /// ショートカットを `shortcuts run` で実行する。
+  public static let doNotDisturbShortcutName = "Toggle Do Not Disturb"
+
+  /// 検索結果の path（履歴・非表示設定で項目を識別する）
+  public var identifier: String {
+    "\(Self.identifierScheme)://\(rawValue)"
+  }
+
+  /// 表示名
+  public var displayName: String {
+    switch self {
+    case .toggleMute: "消音を切り替え"
+    case .volumeUp: "音量を上げる"
+    case .volumeDown: "音量を下げる"
+    case .toggleDoNotDisturb: "おやすみモードを切り替え"
+    case .toggleDarkMode: "ダークモードを切り替え"
+    case .startScreenSaver: "スクリーンセーバを開始"
+    }
+  }
+
+  /// 表示名以外に照合する検索キーワード
+  public var keywords: [String] {
+    switch self {
+    case .toggleMute: ["mute", "unmute"]
+    case .volumeUp: ["volume up", "louder"]
+    case .volumeDown: ["volume down", "quieter"]
+    case .toggleDoNotDisturb: ["do not disturb", "dnd", "focus"]
+    case .toggleDarkMode: ["dark mode", "light mode"]
+    case .startScreenSaver: ["screen saver", "screensaver"]
+    }
+  }
+
+  /// 表示アイコン（SF Symbols 名）
+  public var symbolName: String {
+    switch self {
+    case .toggleMute: "speaker.slash"
+    case .volumeUp: "speaker.plus"
+    case .volumeDown: "speaker.minus"
+    case .toggleDoNotDisturb: "moon"
+    case .toggleDarkMode: "circle.lefthalf.filled"
+    case .startScreenSaver: "sparkles.tv"
+    }
+  }
+
+  /// 操作を実行する AppleScript（ショートカットで実行する操作は nil）
+  var appleScript: String? {
+    switch self {
+    case .toggleMute:
+      "set volume output muted not (output muted of (get volume settings))"
+    case .volumeUp:
+      "set volume output volume ((output volume of (get volume settings)) + \(Self.volumeStep))"
+    case .volumeDown:
+      "set volume output volume ((output volume of (get volume settings)) - \(Self.volumeStep))"
+    case .toggleDarkMode:
+      """
+      tell application "System Events" to tell appearance preferences \
+      to set dark mode to not dark mode
+      """
+    case .startScreenSaver:
+      "tell application \"System Events\" to start current screen saver"
+    case .toggleDoNotDisturb:
+      nil
+    }
+  }
+
+  /// 作成済みのショートカットで実行できる操作（おやすみモードはショートカットがある場合のみ）。
+  ///
+  /// - Parameter shortcutNames: ショートカット.app に作成済みのショートカットの名前
+  public static func available(shortcutNames: Set<String>) -> [SystemControl] {
+    allCases.filter {
+      $0 != .toggleDoNotDisturb || shortcutNames.contains(doNotDisturbShortcutName)
+    }
+  }
+
+  /// 検索結果の path から操作を復元する。
+  public init?(identifier: String) {
+    let prefix = "\(Self.identifierScheme)://"
+    guard identifier.hasPrefix(prefix) else { return nil }
+    self.init(rawValue: String(identifier.dropFirst(prefix.count)))
+  }
+}
+
+// MARK: - SystemControlling
+
+public protocol SystemControlling: Sendable {
+  /// システム操作を実行する。
+  ///
+  /// - Throws: スクリプト・ショートカットの実行に失敗した場合は `LaunchError.scriptExecutionFailed`
+  func perform(_ control: SystemControl) async throws
+
+  /// 検索結果に出すシステム操作（実行に必要なショートカットがない操作を除く）。
+  func availableControls() async -> [SystemControl]
+}
+
+extension SystemControlling {
+  /// 既定実装: すべての操作を出す。
+  public func availableControls() async -> [SystemControl] {
+    SystemControl.allCases
+  }
+}
+
+// MARK: - SystemControlService
+
+/// `osascript` と `shortcuts` コマンドでシステム操作を実行するサービス。
+///
+/// コマンドは `ProcessRunner` で実行するため、Swift Concurrency のスレッドをブロックしない。
+public struct SystemControlService: SystemControlling {
+  private static let logger = AppLogger(category: "SystemControl")
+  private static let shortcutsPath = "/usr/bin/shortcuts"
+
+  public init() {}
+
+  public func perform(_ control: SystemControl) async throws {
+    Self.logger.info("Performing system control: \(control.rawValue)")
+    if let script = control.appleScript {
+      try await Self.run("/usr/bin/osascript", arguments: ["-e", script])
+    } else {
+      try await Self.run(
+        Self.shortcutsPath, arguments: ["run", SystemControl.doNotDisturbShortcutName])
+    }
+  }
+
+  /// `shortcuts list` で作成済みのショートカットを確かめる（一覧を取れない場合はおやすみモードを除く）。
+  public func availableControls() async -> [SystemControl] {
+    let output = try? await Self.run(Self.shortcutsPath, arguments: ["list"])
+    let names = Set(
+      String(decoding: output ?? Data(), as: UTF8.self)
+        .split(whereSeparator: \.isNewline)
+        .map { $0.trimmingCharacters(in: .whitespaces) })
+    return SystemControl.available(shortcutNames: names)
+  }
+
+  /// コマンドを実行し、標準出力を返す。
+  ///
+  /// - Throws: 起動できない場合や終了コードが 0 以外の場合は `LaunchError.scriptExecutionFailed`
+  @discardableResult
+  private static func run(_ executable: String, arguments: [String]) async throws -> Data {
+    let name = (executable as NSString).lastPathComponent
+    let output: ProcessRunner.Output
+    do {
+      output = try await ProcessRunner.run(executable, arguments: arguments)
+    } catch {
+      logger.error("System control failed (\(name)): \(error.localizedDescription)")
+      throw LaunchError.scriptExecutionFailed(error.localizedDescription)
+    }
+    guard output.status == 0 else {
+      let stderrText = output.errorMessage
+      let message =
+        stderrText.isEmpty ? "\(name) exited with status \(output.status)" : stderrText
+      logger.error("System control failed (\(name)): \(message)")
+      throw LaunchError.scriptExecutionFailed(message)
+    }
+    return output.standardOutput
+  }
+}
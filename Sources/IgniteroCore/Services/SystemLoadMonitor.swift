@@ -0,0 +1,111 @@
+import Darwin
+import Foundation
+import IOKit.ps
+
+// MARK: - SystemLoadSnapshot
+
+/// 電源と負荷の状態（自動更新を見送るかの判定に使う）
+public struct SystemLoadSnapshot: Sendable, Equatable {
+  /// バッテリーで動作しているか（電源アダプタにつながっていない）
+  public var isOnBattery: Bool
+  /// 内蔵バッテリーの残量（%）。バッテリーがない Mac では nil
+  public var batteryPercent: Int?
+  /// 直近 1 分間の平均負荷を論理コア数で割った値（1 で全コアが埋まっている）
+  public var loadPerCore: Double
+  /// 温度による性能の抑制の状態
+  public var thermalState: ProcessInfo.ThermalState
+
+  public init(
+    isOnBattery: Bool = false, batteryPercent: Int? = nil, loadPerCore: Double = 0,
+    thermalState: ProcessInfo.ThermalState = .nominal
+  ) {
+    self.isOnBattery = isOnBattery
+    self.batteryPercent = batteryPercent
+    self.loadPerCore = loadPerCore
+    self.thermalState = thermalState
+  }
+}
+
+// MARK: - AutoUpdateDeferral
+
+/// 自動更新を見送った理由
+public enum AutoUpdateDeferral: Sendable, Equatable {
+  /// バッテリー駆動で残量が少ない
+  case lowBattery(percent: Int)
+  /// CPU の負荷が高い、または温度で性能が抑制されている
+  case heavyLoad(loadPerCore: Double)
+
+  /// 1 コアあたりの平均負荷がこれ以上なら負荷が高いとみなす
+  public static let heavyLoadThreshold = 0.75
+
+  /// 設定と現在の状態から、自動更新を見送るかを判定する。
+  ///
+  /// - Returns: 見送る場合はその理由（実行してよい場合は nil）
+  public static func evaluate(
+    _ snapshot: SystemLoadSnapshot, settings: CacheUpdateSettings
+  ) -> AutoUpdateDeferral? {
+    if settings.skipOnLowBattery, snapshot.isOnBattery,
+      let percent = snapshot.batteryPercent,
+      percent < settings.lowBatteryThresholdPercent
+    {
+      return .lowBattery(percent: percent)
+    }
+    if settings.skipUnderHeavyLoad,
+      snapshot.loadPerCore >= heavyLoadThreshold || snapshot.thermalState.rawValue
+        >= ProcessInfo.ThermalState.serious.rawValue
+    {
+      return .heavyLoad(loadPerCore: snapshot.loadPerCore)
+    }
+    return nil
+  }
+}
+
+// MARK: - SystemLoadProviding
+
+public protocol SystemLoadProviding: Sendable {
+  /// 現在の電源と負荷の状態を返す。
+  func snapshot() -> SystemLoadSnapshot
+}
+
+/// IOKit の電源情報と `getloadavg` で電源と負荷の状態を調べる。
+public struct SystemLoadMonitor: SystemLoadProviding {
+  public init() {}
+
+  public func snapshot() -> SystemLoadSnapshot {
+    let power = Self.powerState()
+    return SystemLoadSnapshot(
+      isOnBattery: power.isOnBattery, batteryPercent: power.batteryPercent,
+      loadPerCore: Self.loadPerCore(), thermalState: ProcessInfo.processInfo.thermalState)
+  }
+
+  /// 電源の種類と内蔵バッテリーの残量
+  private static func powerState() -> (isOnBattery: Bool, batteryPercent: Int?) {
+    guard let info = IOPSCopyPowerSourcesInfo()?.takeRetainedValue() else {
+      return (false, nil)
+    }
+    let providing = IOPSGetProvidingPowerSourceType(info)?.takeUnretainedValue() as String?
+    let isOnBattery = providing == kIOPSBatteryPowerValue
+    guard let sources = IOPSCopyPowerSourcesList(info)?.takeRetainedValue() as? [CFTypeRef]
+    else {
+      return (isOnBattery, nil)
+    }
+    for source in sources {
+      guard
+        let description = IOPSGetPowerSourceDescription(info, source)?.takeUnretainedValue()
+          as? [String: Any],
+        description[kIOPSTypeKey] as? String == kIOPSInternalBatteryType,
+        let current = description[kIOPSCurrentCapacityKey] as? Int,
+        let max = description[kIOPSMaxCapacityKey] as? Int, max > 0
+      else { continue }
+      return (isOnBattery, current * 100 / max)
+    }
+    return (isOnBattery, nil)
+  }
+
+  /// 直近 1 分間の平均負荷を論理コア数で割った値（取得できない場合は 0）
+  private static func loadPerCore() -> Double {
+    var loads = [Double](repeating: 0, count: 1)
+    guard getloadavg(&loads, 1) == 1 else { return 0 }
+    return loads[0] / Double(max(ProcessInfo.processInfo.activeProcessorCount, 1))
+  }
+}
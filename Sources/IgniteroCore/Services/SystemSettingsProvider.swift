@@ -0,0 +1,99 @@
+import Foundation
+
+// MARK: - SystemSettingsPaneProviding
+
+/// ランチャーで検索できるシステム設定のパネルを返すプロバイダー。
+public protocol SystemSettingsPaneProviding: Sendable {
+  func panes() async -> [SystemSettingsPane]
+}
+
+// MARK: - SystemSettingsProvider
+
+/// 主なパネルの一覧に、インストールされている設定拡張・環境設定パネルを加えて返す。
+///
+/// 一覧にないパネル（OS の更新で増えたもの、サードパーティ製の .prefPane）は
+/// バンドルの Info.plist から識別子と表示名を読み取って追加する。
+public struct SystemSettingsProvider: SystemSettingsPaneProviding {
+  private static let logger = AppLogger(category: "SystemSettingsProvider")
+
+  /// システム設定の設定拡張（.appex）が置かれる場所
+  public static let defaultExtensionDirectories = ["/System/Library/ExtensionKit/Extensions"]
+  /// 環境設定パネル（.prefPane）が置かれる場所
+  public static let defaultPreferencePaneDirectories = [
+    "/Library/PreferencePanes",
+    NSString(string: "~/Library/PreferencePanes").expandingTildeInPath,
+  ]
+  /// システム設定のパネルを提供する拡張の拡張ポイント
+  static let settingsExtensionPoint = "com.apple.Settings.extension.ui"
+
+  private let extensionDirectories: [String]
+  private let preferencePaneDirectories: [String]
+
+  public init(
+    extensionDirectories: [String] = Self.defaultExtensionDirectories,
+    preferencePaneDirectories: [String] = Self.defaultPreferencePaneDirectories
+  ) {
+    self.extensionDirectories = extensionDirectories
+    self.preferencePaneDirectories = preferencePaneDirectories
+  }
+
+  public func panes() async -> [SystemSettingsPane] {
+    var panes = SystemSettingsPane.curated
+    var knownIDs = Set(panes.map(\.id))
+    for pane in discoveredPanes() where knownIDs.insert(pane.id).inserted {
+      panes.append(pane)
+    }
+    return panes
+  }
+
+  /// インストールされている設定拡張・環境設定パネルを名前順に返す。
+  func discoveredPanes() -> [SystemSettingsPane] {
+    var panes: [SystemSettingsPane] = []
+    for path in bundlePaths(in: extensionDirectories, pathExtension: "appex") {
+      guard let info = Self.infoDictionary(at: path),
+        let attributes = info["EXAppExtensionAttributes"] as? [String: Any],
+        attributes["EXExtensionPointIdentifier"] as? String == Self.settingsExtensionPoint,
+        let pane = Self.pane(from: info, nameKeys: ["CFBundleDisplayName", "CFBundleName"])
+      else { continue }
+      panes.append(pane)
+    }
+    for path in bundlePaths(in: preferencePaneDirectories, pathExtension: "prefPane") {
+      guard let info = Self.infoDictionary(at: path),
+        let pane = Self.pane(
+          from: info, nameKeys: ["NSPrefPaneIconLabel", "CFBundleDisplayName", "CFBundleName"],
+          symbolName: "puzzlepiece.extension")
+      else { continue }
+      panes.append(pane)
+    }
+    Self.logger.debug("Discovered \(panes.count) settings panes")
+    return panes.sorted {
+      $0.name.localizedCaseInsensitiveCompare($1.name) == .orderedAscending
+    }
+  }
+
+  // MARK: - 非公開メソッド
+
+  private func bundlePaths(in directories: [String], pathExtension: String) -> [String] {
+    let fm = FileManager.default
+    return directories.flatMap { directory in
+      ((try? fm.contentsOfDirectory(atPath: directory)) ?? [])
+        .filter { ($0 as NSString).pathExtension == pathExtension }
+        .map { (directory as NSString).appendingPathComponent($0) }
+    }
+  }
+
+  /// ローカライズ済みの Info.plist の内容を返す（ローカライズがないキーは元の値）。
+  private static func infoDictionary(at path: String) -> [String: Any]? {
+    guard let bundle = Bundle(path: path), let info = bundle.infoDictionary else { return nil }
+    return info.merging(bundle.localizedInfoDictionary ?? [:]) { _, localized in localized }
+  }
+
+  private static func pane(
+    from info: [String: Any], nameKeys: [String], symbolName: String = "gearshape"
+  ) -> SystemSettingsPane? {
+    guard let id = info["CFBundleIdentifier"] as? String,
+      let name = nameKeys.lazy.compactMap({ info[$0] as? String }).first(where: { !$0.isEmpty })
+    else { return nil }
+    return SystemSettingsPane(id: id, name: name, symbolName: symbolName)
+  }
+}
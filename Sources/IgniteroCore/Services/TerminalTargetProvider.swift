@@ -0,0 +1,40 @@
+import Foundation
+
+// MARK: - TerminalTargetProviding
+
+/// ランチャーで検索できるターミナルの接続先（SSH ホスト・tmux セッション）を返すプロバイダー。
+public protocol TerminalTargetProviding: Sendable {
+  func targets() async -> [TerminalTarget]
+}
+
+// MARK: - TerminalTargetProvider
+
+/// `~/.ssh/config` の SSH ホストと `~/.tmux.conf` の tmux セッションを返す。
+///
+/// キャッシュ更新のたびに読み直し、結果はキャッシュ DB に保存する。
+public struct TerminalTargetProvider: TerminalTargetProviding {
+  private static let logger = AppLogger(category: "TerminalTargetProvider")
+
+  public static let defaultSSHConfigPath = NSString(string: "~/.ssh/config").expandingTildeInPath
+  public static let defaultTmuxConfigPath = NSString(string: "~/.tmux.conf").expandingTildeInPath
+
+  private let sshConfigPath: String
+  private let tmuxConfigPath: String
+
+  public init(
+    sshConfigPath: String = Self.defaultSSHConfigPath,
+    tmuxConfigPath: String = Self.defaultTmuxConfigPath
+  ) {
+    self.sshConfigPath = sshConfigPath
+    self.tmuxConfigPath = tmuxConfigPath
+  }
+
+  public func targets() async -> [TerminalTarget] {
+    let hosts = SSHConfigParser.hosts(atPath: sshConfigPath)
+    let sessions =
+      (try? String(contentsOfFile: tmuxConfigPath, encoding: .utf8))
+      .map(TmuxConfigParser.sessions(in:)) ?? []
+    Self.logger.debug("Found \(hosts.count) SSH hosts and \(sessions.count) tmux sessions")
+    return hosts + sessions
+  }
+}
@@ -0,0 +1,50 @@
+import Foundation
+
+/// 項目名をローマ字表記などに変換し、追加の検索キーを生成する変換器。
+///
+/// `SearchIndex` の構築時に項目ごとに 1 度だけ呼ばれ、結果はインデックスに保持される。
+/// 対象外の文字しか含まない名前には空配列を返す。
+public protocol Transliterator: Sendable {
+  /// 変換器の識別子
+  var id: String { get }
+
+  /// 名前から検索キーを生成する（小文字化済みで返す）。
+  func searchKeys(for name: String) -> [String]
+}
+
+/// 中国語（ピンイン）と韓国語（ローマ字）の変換器。
+///
+/// ICU の `Any-Latin` 変換を使う。漢字はピンインの連結（`weixin`）と各音節の頭文字（`wx`）、
+/// ハングルは音節を連結したローマ字（`kakaotog`）をキーにする。
+public struct ICUTransliterator: Transliterator {
+  public let id = "icu-latin"
+
+  public init() {}
+
+  public func searchKeys(for name: String) -> [String] {
+    guard name.unicodeScalars.contains(where: Self.isTransliterationTarget) else { return [] }
+    guard
+      let latin = name.applyingTransform(.toLatin, reverse: false)?
+        .applyingTransform(.stripDiacritics, reverse: false)?
+        .lowercased()
+    else { return [] }
+
+    let syllables = latin.split(whereSeparator: { $0.isWhitespace || $0 == "-" || $0 == "'" })
+    guard !syllables.isEmpty else { return [] }
+    var keys = [syllables.joined()]
+    let initials = String(syllables.compactMap(\.first))
+    if syllables.count > 1, !keys.contains(initials) {
+      keys.append(initials)
+    }
+    return keys
+  }
+
+  /// 漢字（CJK 統合漢字）またはハングルか
+  static func isTransliterationTarget(_ scalar: Unicode.Scalar) -> Bool {
+    switch scalar.value {
+    case 0x4E00...0x9FFF, 0x3400...0x4DBF: true  // CJK 統合漢字（拡張 A を含む）
+    case 0xAC00...0xD7AF, 0x1100...0x11FF, 0x3130...0x318F: true  // ハングル
+    default: false
+    }
+  }
+}
@@ -0,0 +1,57 @@
+import Foundation
+
+// MARK: - TrashedItem
+
+/// ゴミ箱に入れた項目（元に戻すために元の場所と移動先を持つ）
+public struct TrashedItem: Sendable, Equatable {
+  /// 表示名
+  public let name: String
+  /// ゴミ箱に入れる前のパス
+  public let originalPath: String
+  /// ゴミ箱の中のパス（同名の項目があると Finder と同じく名前が変わる）
+  public let trashedPath: String
+
+  public init(name: String, originalPath: String, trashedPath: String) {
+    self.name = name
+    self.originalPath = originalPath
+    self.trashedPath = trashedPath
+  }
+}
+
+// MARK: - Trashing プロトコル
+
+public protocol Trashing: Sendable {
+  /// 項目をゴミ箱に入れる（削除はしない）。
+  ///
+  /// - Throws: 項目がない、またはゴミ箱に移動できない場合
+  func moveToTrash(path: String) throws -> TrashedItem
+  /// ゴミ箱に入れた項目を元の場所へ戻す。
+  ///
+  /// - Throws: ゴミ箱から項目がなくなった、または元の場所に同名の項目がある場合
+  func restore(_ item: TrashedItem) throws
+}
+
+// MARK: - TrashService 本体
+
+/// Finder と同じゴミ箱（`FileManager.trashItem`）へ項目を移し、元に戻す。
+///
+/// `rm` と違いゴミ箱を空にするまで取り戻せる。
+public struct TrashService: Trashing, Sendable {
+  public init() {}
+
+  public func moveToTrash(path: String) throws -> TrashedItem {
+    let name = FileManager.default.displayName(atPath: path)
+    var resultingURL: NSURL?
+    try FileManager.default.trashItem(
+      at: URL(fileURLWithPath: path), resultingItemURL: &resultingURL)
+    return TrashedItem(
+      name: name, originalPath: path, trashedPath: (resultingURL as URL?)?.path ?? "")
+  }
+
+  public func restore(_ item: TrashedItem) throws {
+    guard !item.trashedPath.isEmpty else {
+      throw CocoaError(.fileNoSuchFile, userInfo: [NSFilePathErrorKey: item.originalPath])
+    }
+    try FileManager.default.moveItem(atPath: item.trashedPath, toPath: item.originalPath)
+  }
+}
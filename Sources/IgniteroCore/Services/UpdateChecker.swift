@@ -1,5 +1,4 @@
 import Foundation
-import os
 
 // MARK: - URLSession プロトコル
 
@@ -14,16 +13,59 @@ extension URLSession: URLSessionProtocol {}
 
 // MARK: - GitHub リリースモデル
 
+/// GitHub Releases API から返されるリリースの添付ファイル。
+public struct GitHubReleaseAsset: Codable, Sendable, Equatable {
+  public let name: String
+  public let browserDownloadURL: String
+
+  public init(name: String, browserDownloadURL: String) {
+    self.name = name
+    self.browserDownloadURL = browserDownloadURL
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case name
+    case browserDownloadURL = "browser_download_url"
+  }
+}
+
 /// GitHub Releases API から返されるリリース情報。
 public struct GitHubRelease: Codable, Sendable {
   public let tagName: String
   public let prerelease: Bool
   public let htmlURL: String
+  public let assets: [GitHubReleaseAsset]
 
   enum CodingKeys: String, CodingKey {
     case tagName = "tag_name"
     case prerelease
     case htmlURL = "html_url"
+    case assets
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    tagName = try container.decode(String.self, forKey: .tagName)
+    prerelease = try container.decode(Bool.self, forKey: .prerelease)
+    htmlURL = try container.decode(String.self, forKey: .htmlURL)
+    assets = try container.decodeIfPresent([GitHubReleaseAsset].self, forKey: .assets) ?? []
+  }
+
+  /// インストール用アーカイブ（`IgniteroLauncher*.zip`）
+  public var archiveAsset: GitHubReleaseAsset? {
+    assets.first { $0.name.hasPrefix("IgniteroLauncher") && $0.name.hasSuffix(".zip") }
+  }
+
+  /// アーカイブの SHA-256 チェックサム（`<アーカイブ名>.sha256`）
+  public var checksumAsset: GitHubReleaseAsset? {
+    guard let archive = archiveAsset else { return nil }
+    return assets.first { $0.name == "\(archive.name).sha256" }
+  }
+
+  /// アーカイブの Ed25519 署名（`<アーカイブ名>.sig`）
+  public var signatureAsset: GitHubReleaseAsset? {
+    guard let archive = archiveAsset else { return nil }
+    return assets.first { $0.name == "\(archive.name).sig" }
   }
 }
 
@@ -35,15 +77,103 @@ public struct UpdateCheckResult: Sendable, Equatable {
   public let latestVersion: String
   /// ダウンロード URL
   public let downloadURL: String
+  /// インストール用アーカイブの URL（添付されていないリリースは nil）
+  public let assetURL: String?
+  /// アーカイブの SHA-256 チェックサムファイルの URL
+  public let checksumURL: String?
+  /// アーカイブの Ed25519 署名ファイルの URL
+  public let signatureURL: String?
 
-  public init(latestVersion: String, downloadURL: String) {
+  public init(
+    latestVersion: String, downloadURL: String, assetURL: String? = nil,
+    checksumURL: String? = nil, signatureURL: String? = nil
+  ) {
     self.latestVersion = latestVersion
     self.downloadURL = downloadURL
+    self.assetURL = assetURL
+    self.checksumURL = checksumURL
+    self.signatureURL = signatureURL
+  }
+
+  /// アプリ内でインストールできるか（アーカイブ・チェックサム・署名が揃っている）
+  public var isInstallable: Bool {
+    assetURL != nil && checksumURL != nil && signatureURL != nil
   }
 }
 
 // MARK: - バージョン比較
 
+/// セマンティックバージョン（"v" プレフィックスとビルドメタデータは無視する）。
+///
+/// コア部分は "." 区切りの数値として長さの違いを 0 埋めで比較し、同じコアではプレリリースの
+/// ない方を新しいとみなす。プレリリース識別子は SemVer 2.0 の規則で比較するため、
+/// `0.2.0-beta.10` は `0.2.0-beta.2` より新しい。
+public struct SemanticVersion: Comparable, Sendable {
+  /// コア部分（数値化できないセグメントは 0）
+  public let core: [Int]
+  /// プレリリース識別子（正式版は空）
+  public let prerelease: [String]
+
+  public init(_ version: String) {
+    var v = version
+    if v.hasPrefix("v") || v.hasPrefix("V") {
+      v = String(v.dropFirst())
+    }
+    // ビルドメタデータ(+...)とプレリリース(-...)を切り離してからコア部分を 0 埋めでパースする。
+    // compactMap だと "0-beta" のような不正セグメントが脱落して後続が繰り上がり、
+    // "1.2.0-beta.1" が [1, 2, 1]（=1.2.1）と誤認される。
+    let withoutBuildMetadata =
+      v.split(separator: "+", maxSplits: 1, omittingEmptySubsequences: false).first ?? ""
+    let parts = withoutBuildMetadata.split(
+      separator: "-", maxSplits: 1, omittingEmptySubsequences: false)
+    core = (parts.first ?? "")
+      .split(separator: ".", omittingEmptySubsequences: false)
+      .map { Int($0) ?? 0 }
+    prerelease =
+      parts.count > 1
+      ? parts[1].split(separator: ".", omittingEmptySubsequences: false).map(String.init)
+      : []
+  }
+
+  /// プレリリースか
+  public var isPrerelease: Bool {
+    !prerelease.isEmpty
+  }
+
+  public static func < (lhs: SemanticVersion, rhs: SemanticVersion) -> Bool {
+    for i in 0..<max(lhs.core.count, rhs.core.count) {
+      let l = i < lhs.core.count ? lhs.core[i] : 0
+      let r = i < rhs.core.count ? rhs.core[i] : 0
+      if l != r { return l < r }
+    }
+    // コアが同じなら正式版の方が新しい
+    switch (lhs.isPrerelease, rhs.isPrerelease) {
+    case (false, _): return false
+    case (true, false): return true
+    case (true, true): break
+    }
+    for (l, r) in zip(lhs.prerelease, rhs.prerelease) where l != r {
+      return isLowerIdentifier(l, than: r)
+    }
+    // 共通部分が同じなら識別子の少ない方が古い
+    return lhs.prerelease.count < rhs.prerelease.count
+  }
+
+  public static func == (lhs: SemanticVersion, rhs: SemanticVersion) -> Bool {
+    !(lhs < rhs) && !(rhs < lhs)
+  }
+
+  /// 数値の識別子は数値として比較し、英数字の識別子より古いとみなす。
+  private static func isLowerIdentifier(_ lhs: String, than rhs: String) -> Bool {
+    switch (Int(lhs), Int(rhs)) {
+    case (let l?, let r?): l < r
+    case (.some, nil): true
+    case (nil, .some): false
+    case (nil, nil): lhs < rhs
+    }
+  }
+}
+
 /// セマンティックバージョニングの比較ユーティリティ。
 public enum VersionComparator {
   /// `candidate` が `current` より新しいかどうかを判定する。
@@ -53,43 +183,84 @@ public enum VersionComparator {
   ///   - current: 現在のバージョン文字列
   /// - Returns: `candidate` が `current` より新しい場合は `true`
   public static func isNewer(_ candidate: String, than current: String) -> Bool {
-    let candidateParts = parseVersion(candidate)
-    let currentParts = parseVersion(current)
+    SemanticVersion(candidate) > SemanticVersion(current)
+  }
+}
 
-    let maxLength = max(candidateParts.count, currentParts.count)
+// MARK: - プロキシ
 
-    for i in 0..<maxLength {
-      let c = i < candidateParts.count ? candidateParts[i] : 0
-      let v = i < currentParts.count ? currentParts[i] : 0
+/// 環境変数 `HTTPS_PROXY` / `HTTP_PROXY` で指定されたプロキシ。
+///
+/// URLSession はシステム設定のプロキシにしか従わないため、シェルやプロファイル
+/// （`launchctl setenv` など）でプロキシを指定している環境では明示的に設定する。
+public struct ProxyConfiguration: Sendable, Equatable {
+  public let host: String
+  public let port: Int
+
+  public init(host: String, port: Int) {
+    self.host = host
+    self.port = port
+  }
 
-      if c > v { return true }
-      if c < v { return false }
+  /// 環境変数からプロキシを読み取る（大文字・小文字どちらの変数名も受け付ける）。
+  ///
+  /// スキームのない `proxy.example.com:8080` も受け付け、ポート省略時は 8080 とみなす。
+  ///
+  /// - Parameter environment: 環境変数
+  /// - Returns: プロキシが指定されていない、または解釈できない場合は `nil`
+  public init?(environment: [String: String]) {
+    let keys = ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"]
+    guard
+      let value = keys.lazy.compactMap({ environment[$0] })
+        .map({ $0.trimmingCharacters(in: .whitespaces) })
+        .first(where: { !$0.isEmpty })
+    else { return nil }
+    let urlString = value.contains("://") ? value : "http://\(value)"
+    guard let url = URL(string: urlString), let host = url.host, !host.isEmpty else {
+      return nil
     }
+    self.init(host: host, port: url.port ?? 8080)
+  }
 
-    return false  // 同じバージョン
+  /// `URLSessionConfiguration.connectionProxyDictionary` に設定する値
+  public var connectionProxyDictionary: [AnyHashable: Any] {
+    [
+      kCFNetworkProxiesHTTPEnable: true,
+      kCFNetworkProxiesHTTPProxy: host,
+      kCFNetworkProxiesHTTPPort: port,
+      kCFNetworkProxiesHTTPSEnable: true,
+      kCFNetworkProxiesHTTPSProxy: host,
+      kCFNetworkProxiesHTTPSPort: port,
+    ]
   }
 
-  /// バージョン文字列を数値配列にパースする。
+  /// アップデートの確認・ダウンロードに使うセッションを作成する。
   ///
-  /// "v" プレフィックスを除去し、"." で分割して各セグメントを数値に変換する。
-  private static func parseVersion(_ version: String) -> [Int] {
-    var v = version
-    if v.hasPrefix("v") || v.hasPrefix("V") {
-      v = String(v.dropFirst())
+  /// - Parameter environment: 環境変数。プロキシが指定されていなければ `URLSession.shared` を返す。
+  public static func makeSession(
+    environment: [String: String] = ProcessInfo.processInfo.environment
+  ) -> URLSession {
+    guard let proxy = ProxyConfiguration(environment: environment) else {
+      return URLSession.shared
+    }
+    let configuration = URLSessionConfiguration.default
+    configuration.connectionProxyDictionary = proxy.connectionProxyDictionary
+    return URLSession(configuration: configuration)
+  }
+}
+
+// MARK: - エラー
+
+/// アップデート確認のエラー
+public enum UpdateCheckError: Error, LocalizedError, Equatable {
+  /// GitHub API が成功以外のステータスを返した（プロキシの拒否・レート制限など）
+  case httpStatus(Int)
+
+  public var errorDescription: String? {
+    switch self {
+    case .httpStatus(407): "Proxy authentication required (HTTP 407)"
+    case .httpStatus(let statusCode): "GitHub API returned HTTP \(statusCode)"
     }
-    // ビルドメタデータ(+...)とプレリリース(-...)を切り離し、コア部分のみを比較対象にする。
-    // compactMap だと "0-beta" のような不正セグメントが脱落して後続が繰り上がり、
-    // "1.2.0-beta.1" が [1, 2, 1]（=1.2.1）と誤認される。コア抽出後に 0 埋めでパースして防ぐ。
-    let withoutBuildMetadata =
-      v.split(separator: "+", maxSplits: 1, omittingEmptySubsequences: false).first ?? ""
-    let core =
-      withoutBuildMetadata
-      .split(separator: "-", maxSplits: 1, omittingEmptySubsequences: false)
-      .first ?? ""
-    return
-      core
-      .split(separator: ".", omittingEmptySubsequences: false)
-      .map { Int($0) ?? 0 }
   }
 }
 
@@ -100,12 +271,11 @@ public enum VersionComparator {
 /// 以下の機能を提供する:
 /// - GitHub Releases API で最新バージョンを確認
 /// - 12時間のキャッシュでAPIコールを削減
-/// - プレリリースバージョンのスキップ
+/// - プレリリースバージョンのスキップ（ベータチャンネルでは含める）
 /// - ユーザーが非表示にしたバージョンの通知抑制
-/// - ネットワークエラー時のサイレント失敗
+/// - ネットワークエラー時のサイレント失敗（失敗が続く場合は指数バックオフで再試行を間引く）
 public struct UpdateChecker: Sendable {
-  private static let logger = Logger(
-    subsystem: "com.ignitero.launcher", category: "UpdateChecker")
+  private static let logger = AppLogger(category: "UpdateChecker")
 
   /// キャッシュの有効期間（12時間）
   private static let cacheExpiry: TimeInterval = 12 * 3600
@@ -113,6 +283,9 @@ public struct UpdateChecker: Sendable {
   /// API リクエストのタイムアウト（10秒）
   private static let requestTimeout: TimeInterval = 10
 
+  /// 失敗後に再試行するまでの最初の待ち時間（30分）。失敗が続くたびに倍にする。
+  private static let initialRetryDelay: TimeInterval = 30 * 60
+
   // MARK: - 依存関係
 
   private let session: any URLSessionProtocol
@@ -148,10 +321,14 @@ public struct UpdateChecker: Sendable {
   /// 以下のロジックで動作する:
   /// 1. キャッシュが12時間以内であればキャッシュ値を使用
   /// 2. GitHub Releases API から最新リリースを取得
-  /// 3. プレリリースをフィルタし、最新の安定版を選択
+  /// 3. 安定版チャンネルではプレリリースをフィルタし、最新のバージョンを選択
   /// 4. 現在のバージョンより新しい場合のみ結果を返す
   /// 5. ユーザーが非表示にしたバージョンは結果を返さない
   /// 6. ネットワークエラー時はキャッシュ値にフォールバック
+  /// 7. 失敗が続いている間は待ち時間（30分から倍々、最大12時間）が過ぎるまで API を呼ばない
+  ///
+  /// オフライン時の失敗はバックオフの回数に数えず、ログも debug に留める。
+  /// 失敗の理由は `UpdateCache.lastCheckError` に記録し、設定画面に表示する。
   ///
   /// - Parameter currentVersion: 現在のアプリバージョン
   /// - Returns: 新しいバージョンがある場合は `UpdateCheckResult`、なければ `nil`
@@ -166,11 +343,18 @@ public struct UpdateChecker: Sendable {
     {
       Self.logger.debug("Using cached update check result")
       return buildResult(
-        cachedVersion: cache.latestVersion,
-        currentVersion: currentVersion,
-        dismissedVersion: dismissedVersion,
-        downloadURL: cache.downloadURL
-      )
+        cache: cache, currentVersion: currentVersion, dismissedVersion: dismissedVersion)
+    }
+
+    // 失敗が続いている場合は待ち時間が過ぎるまで再試行しない
+    if let cache, let lastFailedAt = cache.lastFailedAt,
+      Date().timeIntervalSince(lastFailedAt)
+        < Self.retryDelay(afterFailures: cache.consecutiveFailures)
+    {
+      Self.logger.debug(
+        "Skipping update check after \(cache.consecutiveFailures) consecutive failures")
+      return buildResult(
+        cache: cache, currentVersion: currentVersion, dismissedVersion: dismissedVersion)
     }
 
     // API からフェッチ
@@ -179,7 +363,7 @@ public struct UpdateChecker: Sendable {
 
       // キャッシュを更新
       if let result {
-        updateCache(latestVersion: result.latestVersion, downloadURL: result.downloadURL)
+        updateCache(latestVersion: result.latestVersion, result: result)
       } else {
         // 新しいバージョンがない場合でもチェック日時を更新
         updateCache(latestVersion: currentVersion)
@@ -194,22 +378,68 @@ public struct UpdateChecker: Sendable {
 
       return result
     } catch {
-      Self.logger.warning("Update check failed: \(error.localizedDescription)")
+      recordFailure(error)
       // ネットワークエラー時はキャッシュ値を使用（downloadURLもキャッシュから復元）
       // await 中にユーザーがバナーを閉じている可能性があるため、最新値を再取得する
       let currentDismissedVersion = settingsManager.settings.updateCache?.dismissedVersion
+      guard let cache else { return nil }
       return buildResult(
-        cachedVersion: cache?.latestVersion,
-        currentVersion: currentVersion,
-        dismissedVersion: currentDismissedVersion,
-        downloadURL: cache?.downloadURL
-      )
+        cache: cache, currentVersion: currentVersion, dismissedVersion: currentDismissedVersion)
+    }
+  }
+
+  /// 連続して `failures` 回失敗した後、再試行するまでの待ち時間。
+  ///
+  /// 30分から倍々に延ばし、キャッシュの有効期間（12時間）で頭打ちにする。
+  static func retryDelay(afterFailures failures: Int) -> TimeInterval {
+    guard failures > 0 else { return 0 }
+    let exponent = Double(min(failures - 1, 16))
+    return min(initialRetryDelay * pow(2, exponent), cacheExpiry)
+  }
+
+  /// ネットワークに接続されていないことによる失敗か。
+  static func isOffline(_ error: any Error) -> Bool {
+    guard let urlError = error as? URLError else { return false }
+    switch urlError.code {
+    case .notConnectedToInternet, .networkConnectionLost, .dataNotAllowed,
+      .internationalRoamingOff:
+      return true
+    default:
+      return false
     }
   }
 
   // MARK: - 非公開メソッド
 
-  /// GitHub Releases API から最新の安定リリースを取得する。
+  /// 失敗をキャッシュに記録する。
+  ///
+  /// オフラインの場合は接続し直せばすぐ確認できるよう、バックオフの回数を増やさない。
+  /// プロキシに阻まれる環境で起動のたびに警告が出ないよう、警告は連続失敗の初回だけにする。
+  @MainActor
+  private func recordFailure(_ error: any Error) {
+    var cache = settingsManager.settings.updateCache ?? UpdateCache()
+    cache.lastCheckError = error.localizedDescription
+
+    if Self.isOffline(error) {
+      Self.logger.debug("Skipped update check while offline: \(error.localizedDescription)")
+    } else {
+      cache.consecutiveFailures += 1
+      cache.lastFailedAt = Date()
+      let message: LogMessage =
+        "Update check failed (\(cache.consecutiveFailures) in a row): \(error.localizedDescription)"
+      if cache.consecutiveFailures == 1 {
+        Self.logger.warning(message)
+      } else {
+        Self.logger.debug(message)
+      }
+    }
+
+    settingsManager.settings.updateCache = cache
+    // 保存エラーは黙殺
+    try? settingsManager.save()
+  }
+
+  /// GitHub Releases API から設定したチャンネルの最新リリースを取得する。
   private func fetchLatestRelease(currentVersion: String) async throws -> UpdateCheckResult? {
     let urlString = "https://api.github.com/repos/\(owner)/\(repo)/releases"
     guard let url = URL(string: urlString) else {
@@ -221,19 +451,23 @@ public struct UpdateChecker: Sendable {
     request.timeoutInterval = Self.requestTimeout
     request.setValue("application/vnd.github+json", forHTTPHeaderField: "Accept")
 
-    let (data, _) = try await session.data(for: request)
+    let (data, response) = try await session.data(for: request)
+    if let http = response as? HTTPURLResponse, !(200..<300).contains(http.statusCode) {
+      throw UpdateCheckError.httpStatus(http.statusCode)
+    }
 
     let releases = try JSONDecoder().decode([GitHubRelease].self, from: data)
 
-    // プレリリースをフィルタし、安定版のみを対象にする
-    let stableReleases = releases.filter { !$0.prerelease }
+    // 安定版チャンネルではプレリリースをフィルタし、安定版のみを対象にする
+    let channel = await settingsManager.settings.update.releaseChannel
+    let candidates = releases.filter { channel == .beta || !$0.prerelease }
 
     guard
-      let latestRelease = stableReleases.max(by: {
+      let latestRelease = candidates.max(by: {
         VersionComparator.isNewer(stripVPrefix($1.tagName), than: stripVPrefix($0.tagName))
       })
     else {
-      Self.logger.debug("No stable releases found")
+      Self.logger.debug("No \(channel.rawValue) releases found")
       return nil
     }
 
@@ -247,18 +481,28 @@ public struct UpdateChecker: Sendable {
 
     return UpdateCheckResult(
       latestVersion: latestVersion,
-      downloadURL: latestRelease.htmlURL
+      downloadURL: latestRelease.htmlURL,
+      assetURL: latestRelease.archiveAsset?.browserDownloadURL,
+      checksumURL: latestRelease.checksumAsset?.browserDownloadURL,
+      signatureURL: latestRelease.signatureAsset?.browserDownloadURL
     )
   }
 
   /// キャッシュから結果を構築する。
+  @MainActor
   private func buildResult(
-    cachedVersion: String?,
+    cache: UpdateCache,
     currentVersion: String,
-    dismissedVersion: String?,
-    downloadURL: String?
+    dismissedVersion: String?
   ) -> UpdateCheckResult? {
-    guard let cachedVersion else { return nil }
+    guard let cachedVersion = cache.latestVersion else { return nil }
+
+    // ベータ版を確認した後に安定版チャンネルへ戻した場合は、キャッシュのプレリリースを通知しない
+    if settingsManager.settings.update.releaseChannel == .stable,
+      SemanticVersion(cachedVersion).isPrerelease
+    {
+      return nil
+    }
 
     // 非表示済みバージョンのチェック
     if let dismissedVersion, cachedVersion == dismissedVersion {
@@ -272,18 +516,24 @@ public struct UpdateChecker: Sendable {
 
     return UpdateCheckResult(
       latestVersion: cachedVersion,
-      downloadURL: downloadURL ?? ""
+      downloadURL: cache.downloadURL ?? "",
+      assetURL: cache.assetURL,
+      checksumURL: cache.checksumURL,
+      signatureURL: cache.signatureURL
     )
   }
 
   /// キャッシュを更新する。
   @MainActor
-  private func updateCache(latestVersion: String, downloadURL: String? = nil) {
+  private func updateCache(latestVersion: String, result: UpdateCheckResult? = nil) {
     settingsManager.settings.updateCache = UpdateCache(
       latestVersion: latestVersion,
       checkedAt: Date(),
       dismissedVersion: settingsManager.settings.updateCache?.dismissedVersion,
-      downloadURL: downloadURL
+      downloadURL: result?.downloadURL,
+      assetURL: result?.assetURL,
+      checksumURL: result?.checksumURL,
+      signatureURL: result?.signatureURL
     )
     // 保存エラーは黙殺
     try? settingsManager.save()
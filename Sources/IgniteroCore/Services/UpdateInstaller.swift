@@ -0,0 +1,266 @@
+import CryptoKit
+import Foundation
+
+// MARK: - 進行状況
+
+/// アップデートのダウンロードからインストールまでの進行状況
+public enum UpdateInstallState: Sendable, Equatable {
+  /// 何もしていない
+  case idle
+  /// アーカイブをダウンロード中
+  case downloading
+  /// チェックサム・署名を検証中
+  case verifying
+  /// 検証済みで、再起動すればインストールできる
+  case readyToInstall
+  /// 現在のアプリを置き換え中
+  case installing
+  /// 失敗した（理由）
+  case failed(String)
+
+  /// ダウンロードからインストールまでの処理中か
+  public var isInProgress: Bool {
+    switch self {
+    case .downloading, .verifying, .installing: true
+    case .idle, .readyToInstall, .failed: false
+    }
+  }
+}
+
+// MARK: - エラー
+
+/// アップデートのインストールのエラー
+public enum UpdateInstallError: Error, LocalizedError, Equatable {
+  /// リリースにインストール用アーカイブまたはチェックサムが添付されていない
+  case artifactNotFound
+  /// ダウンロードに失敗した（HTTP ステータス）
+  case downloadFailed(statusCode: Int)
+  /// アーカイブのチェックサムが一致しない
+  case checksumMismatch
+  /// アプリに署名検証用の公開鍵が埋め込まれていない
+  case publicKeyMissing
+  /// アーカイブの署名が公開鍵と一致しない
+  case signatureInvalid
+  /// アーカイブを展開できない
+  case extractionFailed(String)
+  /// 展開したアプリが Ignitero Launcher でない、または署名が壊れている
+  case invalidBundle(String)
+
+  public var errorDescription: String? {
+    switch self {
+    case .artifactNotFound: "The release has no installable archive"
+    case .downloadFailed(let statusCode): "Download failed with HTTP \(statusCode)"
+    case .checksumMismatch: "The downloaded archive does not match its checksum"
+    case .publicKeyMissing: "This build has no public key to verify updates"
+    case .signatureInvalid: "The downloaded archive is not signed by the release key"
+    case .extractionFailed(let reason): "Failed to extract the archive: \(reason)"
+    case .invalidBundle(let reason): "The downloaded app is invalid: \(reason)"
+    }
+  }
+}
+
+// MARK: - UpdateInstalling プロトコル
+
+public protocol UpdateInstalling: Sendable {
+  /// アーカイブの署名を検証できるか（検証できない場合はアプリ内でインストールしない）
+  var canVerifySignatures: Bool { get }
+
+  /// アップデートをダウンロード・検証して展開し、展開した `.app` の URL を返す。
+  ///
+  /// - Parameters:
+  ///   - update: インストールするアップデート
+  ///   - progress: 進行状況の通知
+  func stage(
+    _ update: UpdateCheckResult, progress: @escaping @Sendable (UpdateInstallState) -> Void
+  ) async throws -> URL
+
+  /// 展開済みの `.app` で現在のアプリを置き換える。
+  ///
+  /// - Parameters:
+  ///   - stagedApp: `stage(_:progress:)` が返した `.app`
+  ///   - appURL: 置き換える現在のアプリ
+  func install(stagedApp: URL, replacing appURL: URL) async throws
+}
+
+extension UpdateInstalling {
+  public var canVerifySignatures: Bool { true }
+}
+
+// MARK: - UpdateInstaller 本体
+
+/// GitHub Releases に添付されたアーカイブでアプリを更新する。
+///
+/// リリースには `IgniteroLauncher*.zip` と、その SHA-256 を書いた `<アーカイブ名>.sha256`、
+/// リリース用の秘密鍵による Ed25519 署名 `<アーカイブ名>.sig` を添付する（`make release-archive` で作成できる）。
+/// ダウンロードしたアーカイブはチェックサムと、Info.plist の `IgniteroUpdatePublicKey` に埋め込んだ公開鍵で
+/// 署名を検証してから展開する。同じリリースのアセットだけでは検証を通せないため、アセットを差し替えられても
+/// 署名のないアプリはインストールしない。展開後はバンドル ID と `codesign --verify` で中身を確かめてから
+/// 現在のアプリと置き換える。
+public struct UpdateInstaller: UpdateInstalling, Sendable {
+  private static let logger = AppLogger(category: "UpdateInstaller")
+
+  /// ダウンロードのタイムアウト（5分）
+  private static let downloadTimeout: TimeInterval = 300
+
+  /// 署名検証用の公開鍵（Base64 の Ed25519 公開鍵）を書く Info.plist のキー
+  public static let publicKeyInfoKey = "IgniteroUpdatePublicKey"
+
+  /// アプリに埋め込んだ署名検証用の公開鍵（開発ビルドなど、未設定の場合は nil）
+  public static var embeddedPublicKey: Curve25519.Signing.PublicKey? {
+    (Bundle.main.object(forInfoDictionaryKey: publicKeyInfoKey) as? String)
+      .flatMap(publicKey(base64:))
+  }
+
+  /// Base64 の Ed25519 公開鍵を読み込む（不正な値は nil）。
+  public static func publicKey(base64: String) -> Curve25519.Signing.PublicKey? {
+    Data(base64Encoded: base64.trimmingCharacters(in: .whitespacesAndNewlines))
+      .flatMap { try? Curve25519.Signing.PublicKey(rawRepresentation: $0) }
+  }
+
+  private let session: any URLSessionProtocol
+  private let stagingDirectory: URL
+  private let bundleIdentifier: String
+  private let publicKey: Curve25519.Signing.PublicKey?
+
+  /// UpdateInstaller を初期化する。
+  ///
+  /// - Parameters:
+  ///   - session: ダウンロードに使用するセッション。テスト時に差し替え可能。
+  ///   - stagingDirectory: アーカイブを展開するディレクトリ
+  ///   - bundleIdentifier: 展開したアプリに期待するバンドル ID
+  ///   - publicKey: アーカイブの署名を検証する公開鍵（nil の場合はインストールしない）
+  public init(
+    session: any URLSessionProtocol = URLSession.shared,
+    stagingDirectory: URL = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-update"),
+    bundleIdentifier: String = "com.owayo.ignitero.launcher",
+    publicKey: Curve25519.Signing.PublicKey? = UpdateInstaller.embeddedPublicKey
+  ) {
+    self.session = session
+    self.stagingDirectory = stagingDirectory
+    self.bundleIdentifier = bundleIdentifier
+    self.publicKey = publicKey
+  }
+
+  public var canVerifySignatures: Bool { publicKey != nil }
+
+  public func stage(
+    _ update: UpdateCheckResult, progress: @escaping @Sendable (UpdateInstallState) -> Void
+  ) async throws -> URL {
+    guard let assetURL = update.assetURL.flatMap(URL.init(string:)),
+      let checksumURL = update.checksumURL.flatMap(URL.init(string:)),
+      let signatureURL = update.signatureURL.flatMap(URL.init(string:))
+    else { throw UpdateInstallError.artifactNotFound }
+    guard let publicKey else { throw UpdateInstallError.publicKeyMissing }
+
+    progress(.downloading)
+    let archive = try await download(assetURL)
+    let checksum = String(decoding: try await download(checksumURL), as: UTF8.self)
+    let signature = String(decoding: try await download(signatureURL), as: UTF8.self)
+
+    progress(.verifying)
+    try Self.verifyChecksum(of: archive, expected: checksum)
+    // 展開する前に、リリース用の鍵で署名されたアーカイブか確かめる
+    try Self.verifySignature(of: archive, signature: signature, publicKey: publicKey)
+
+    let fm = FileManager.default
+    let versionDirectory = stagingDirectory.appendingPathComponent(update.latestVersion)
+    try? fm.removeItem(at: versionDirectory)
+    try fm.createDirectory(at: versionDirectory, withIntermediateDirectories: true)
+    let archiveURL = versionDirectory.appendingPathComponent(assetURL.lastPathComponent)
+    try archive.write(to: archiveURL, options: .atomic)
+
+    let extractedDirectory = versionDirectory.appendingPathComponent("extracted")
+    try await Self.run(
+      "/usr/bin/ditto", ["-x", "-k", archiveURL.path, extractedDirectory.path],
+      failure: UpdateInstallError.extractionFailed)
+    guard
+      let app = try fm.contentsOfDirectory(
+        at: extractedDirectory, includingPropertiesForKeys: nil
+      ).first(where: { $0.pathExtension == "app" })
+    else { throw UpdateInstallError.extractionFailed("no .app in archive") }
+
+    try Self.validateBundle(at: app, bundleIdentifier: bundleIdentifier)
+    try await Self.run(
+      "/usr/bin/codesign", ["--verify", "--deep", "--strict", app.path],
+      failure: UpdateInstallError.invalidBundle)
+
+    Self.logger.info("Staged update \(update.latestVersion) at \(app.path)")
+    progress(.readyToInstall)
+    return app
+  }
+
+  public func install(stagedApp: URL, replacing appURL: URL) async throws {
+    // 置き換えに失敗した場合は元のアプリを残す（replaceItemAt は失敗時に元へ戻す）
+    _ = try FileManager.default.replaceItemAt(appURL, withItemAt: stagedApp)
+    Self.logger.info("Installed update to \(appURL.path)")
+  }
+
+  // MARK: - 検証
+
+  /// アーカイブの SHA-256 がチェックサムファイルの値と一致するか確かめる。
+  ///
+  /// - Parameters:
+  ///   - data: ダウンロードしたアーカイブ
+  ///   - expected: `shasum -a 256` 形式（先頭がハッシュ値）のチェックサムファイルの内容
+  static func verifyChecksum(of data: Data, expected: String) throws {
+    let expectedHash = expected.split(whereSeparator: \.isWhitespace).first.map {
+      $0.lowercased()
+    }
+    let actualHash = SHA256.hash(data: data).map { String(format: "%02x", $0) }.joined()
+    guard expectedHash == actualHash else { throw UpdateInstallError.checksumMismatch }
+  }
+
+  /// アーカイブの Ed25519 署名を公開鍵で検証する。
+  ///
+  /// - Parameters:
+  ///   - data: ダウンロードしたアーカイブ
+  ///   - signature: 署名ファイルの内容（Base64 の署名）
+  ///   - publicKey: アプリに埋め込んだ公開鍵
+  static func verifySignature(
+    of data: Data, signature: String, publicKey: Curve25519.Signing.PublicKey
+  ) throws {
+    guard
+      let signatureData = Data(
+        base64Encoded: signature.trimmingCharacters(in: .whitespacesAndNewlines)),
+      publicKey.isValidSignature(signatureData, for: data)
+    else { throw UpdateInstallError.signatureInvalid }
+  }
+
+  /// 展開したアプリのバンドル ID を確かめる。
+  static func validateBundle(at appURL: URL, bundleIdentifier: String) throws {
+    guard let bundle = Bundle(url: appURL) else {
+      throw UpdateInstallError.invalidBundle("not an app bundle")
+    }
+    guard bundle.bundleIdentifier == bundleIdentifier else {
+      throw UpdateInstallError.invalidBundle(
+        "unexpected bundle identifier \(bundle.bundleIdentifier ?? "nil")")
+    }
+  }
+
+  // MARK: - 非公開メソッド
+
+  private func download(_ url: URL) async throws -> Data {
+    var request = URLRequest(url: url)
+    request.timeoutInterval = Self.downloadTimeout
+    let (data, response) = try await session.data(for: request)
+    if let http = response as? HTTPURLResponse, !(200..<300).contains(http.statusCode) {
+      throw UpdateInstallError.downloadFailed(statusCode: http.statusCode)
+    }
+    return data
+  }
+
+  /// 外部コマンドを `ProcessRunner` で実行し、異常終了した場合は標準エラー出力を添えたエラーを投げる。
+  private static func run(
+    _ executable: String, _ arguments: [String],
+    failure: (String) -> UpdateInstallError
+  ) async throws {
+    let output: ProcessRunner.Output
+    do {
+      output = try await ProcessRunner.run(executable, arguments: arguments)
+    } catch {
+      throw failure(error.localizedDescription)
+    }
+    guard output.status == 0 else { throw failure(output.errorMessage) }
+  }
+}
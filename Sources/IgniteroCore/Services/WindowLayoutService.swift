@@ -0,0 +1,227 @@
+import AppKit
+import ApplicationServices
+import Foundation
+
+// MARK: - WindowLayoutAction
+
+/// 他のアプリの最前面ウィンドウを移動・リサイズする操作。
+public enum WindowLayoutAction: String, CaseIterable, Sendable {
+  case leftHalf = "left_half"
+  case rightHalf = "right_half"
+  case maximize
+  case center
+
+  /// 検索結果の path に使う識別子のスキーム
+  public static let identifierScheme = "window-layout"
+
+  /// 検索結果の path（履歴・非表示設定で項目を識別する）
+  public var identifier: String {
+    "\(Self.identifierScheme)://\(rawValue)"
+  }
+
+  /// 表示名
+  public var displayName: String {
+    switch self {
+    case .leftHalf: "ウィンドウを左半分に配置"
+    case .rightHalf: "ウィンドウを右半分に配置"
+    case .maximize: "ウィンドウを最大化"
+    case .center: "ウィンドウを中央に配置"
+    }
+  }
+
+  /// 表示名以外に照合する検索キーワード
+  public var keywords: [String] {
+    switch self {
+    case .leftHalf: ["left half"]
+    case .rightHalf: ["right half"]
+    case .maximize: ["maximize", "fill screen"]
+    case .center: ["center window"]
+    }
+  }
+
+  /// 表示アイコン（SF Symbols 名）
+  public var symbolName: String {
+    switch self {
+    case .leftHalf: "rectangle.lefthalf.filled"
+    case .rightHalf: "rectangle.righthalf.filled"
+    case .maximize: "rectangle.fill"
+    case .center: "rectangle.center.inset.filled"
+    }
+  }
+
+  /// 検索結果の path から操作を復元する。
+  public init?(identifier: String) {
+    let prefix = "\(Self.identifierScheme)://"
+    guard identifier.hasPrefix(prefix) else { return nil }
+    self.init(rawValue: String(identifier.dropFirst(prefix.count)))
+  }
+
+  /// 操作後のウィンドウのフレームを返す。
+  ///
+  /// - Parameters:
+  ///   - window: 現在のウィンドウのフレーム
+  ///   - visibleFrame: ウィンドウがある画面のメニューバー・Dock を除いた領域
+  ///     （いずれも同じ座標系。アクセシビリティ API の左上原点の座標を想定）
+  public func frame(for window: CGRect, in visibleFrame: CGRect) -> CGRect {
+    let halfWidth = (visibleFrame.width / 2).rounded(.down)
+    switch self {
+    case .leftHalf:
+      return CGRect(
+        x: visibleFrame.minX, y: visibleFrame.minY, width: halfWidth,
+        height: visibleFrame.height)
+    case .rightHalf:
+      return CGRect(
+        x: visibleFrame.minX + halfWidth, y: visibleFrame.minY,
+        width: visibleFrame.width - halfWidth, height: visibleFrame.height)
+    case .maximize:
+      return visibleFrame
+    case .center:
+      // 画面より大きいウィンドウは画面に収まる大きさに縮める
+      let width = min(window.width, visibleFrame.width)
+      let height = min(window.height, visibleFrame.height)
+      return CGRect(
+        x: (visibleFrame.midX - width / 2).rounded(),
+        y: (visibleFrame.midY - height / 2).rounded(),
+        width: width, height: height)
+    }
+  }
+}
+
+// MARK: - WindowLayoutError
+
+/// ウィンドウ操作のエラー
+public enum WindowLayoutError: Error, LocalizedError, Equatable {
+  /// ウィンドウを操作するためのアクセシビリティ権限がない
+  case accessibilityNotTrusted
+  /// 操作するウィンドウが見つからない
+  case noFocusedWindow
+  /// ウィンドウの移動・リサイズに失敗した
+  case operationFailed(Int32)
+
+  public var errorDescription: String? {
+    switch self {
+    case .accessibilityNotTrusted: "Accessibility permission is required to move windows"
+    case .noFocusedWindow: "No window to arrange"
+    case .operationFailed(let code): "Failed to arrange the window (AXError \(code))"
+    }
+  }
+}
+
+// MARK: - WindowArranging
+
+/// ウィンドウを移動・リサイズするサービス（テスト時に差し替え可能）
+public protocol WindowArranging: Sendable {
+  /// 指定したアプリの最前面ウィンドウを移動・リサイズする。
+  ///
+  /// - Parameters:
+  ///   - action: 操作
+  ///   - processIdentifier: 対象アプリのプロセス ID
+  func arrange(_ action: WindowLayoutAction, processIdentifier: pid_t) async throws
+}
+
+// MARK: - WindowLayoutService
+
+/// アクセシビリティ API（AXUIElement）でウィンドウを移動・リサイズするサービス。
+///
+/// 貼り付けと同じくアクセシビリティ権限が必要。
+public struct WindowLayoutService: WindowArranging {
+  private static let logger = AppLogger(category: "WindowLayout")
+
+  public init() {}
+
+  public func arrange(_ action: WindowLayoutAction, processIdentifier: pid_t) async throws {
+    guard AXIsProcessTrusted() else { throw WindowLayoutError.accessibilityNotTrusted }
+    try await MainActor.run {
+      let window = try Self.focusedWindow(of: processIdentifier)
+      let current = try Self.frame(of: window)
+      guard let visibleFrame = Self.visibleFrame(containing: current) else {
+        throw WindowLayoutError.noFocusedWindow
+      }
+      let target = action.frame(for: current, in: visibleFrame)
+      // 別の画面へ移る場合に備え、移動の前後でサイズを設定する
+      try Self.setSize(target.size, of: window)
+      try Self.setPosition(target.origin, of: window)
+      try Self.setSize(target.size, of: window)
+    }
+    Self.logger.info("Arranged window of pid \(processIdentifier): \(action.rawValue)")
+  }
+
+  /// メニューバーを持つ画面の高さを基準に、AppKit の座標（左下原点）を
+  /// アクセシビリティ API の座標（左上原点）に変換する。
+  static func accessibilityFrame(fromScreenFrame frame: CGRect, primaryScreenHeight: CGFloat)
+    -> CGRect
+  {
+    CGRect(
+      x: frame.minX, y: primaryScreenHeight - frame.maxY, width: frame.width, height: frame.height)
+  }
+
+  // MARK: - 非公開メソッド
+
+  @MainActor
+  private static func focusedWindow(of processIdentifier: pid_t) throws -> AXUIElement {
+    let app = AXUIElementCreateApplication(processIdentifier)
+    var value: CFTypeRef?
+    guard
+      AXUIElementCopyAttributeValue(app, kAXFocusedWindowAttribute as CFString, &value)
+        == .success,
+      let value, CFGetTypeID(value) == AXUIElementGetTypeID()
+    else { throw WindowLayoutError.noFocusedWindow }
+    return unsafeDowncast(value, to: AXUIElement.self)
+  }
+
+  @MainActor
+  private static func frame(of window: AXUIElement) throws -> CGRect {
+    var position = CGPoint.zero
+    var size = CGSize.zero
+    guard let positionValue = attribute(kAXPositionAttribute, of: window),
+      let sizeValue = attribute(kAXSizeAttribute, of: window),
+      AXValueGetValue(positionValue, .cgPoint, &position),
+      AXValueGetValue(sizeValue, .cgSize, &size)
+    else { throw WindowLayoutError.noFocusedWindow }
+    return CGRect(origin: position, size: size)
+  }
+
+  @MainActor
+  private static func attribute(_ name: String, of element: AXUIElement) -> AXValue? {
+    var value: CFTypeRef?
+    guard AXUIElementCopyAttributeValue(element, name as CFString, &value) == .success,
+      let value, CFGetTypeID(value) == AXValueGetTypeID()
+    else { return nil }
+    return unsafeDowncast(value, to: AXValue.self)
+  }
+
+  @MainActor
+  private static func setPosition(_ position: CGPoint, of window: AXUIElement) throws {
+    var position = position
+    guard let value = AXValueCreate(.cgPoint, &position) else {
+      throw WindowLayoutError.operationFailed(AXError.failure.rawValue)
+    }
+    let result = AXUIElementSetAttributeValue(window, kAXPositionAttribute as CFString, value)
+    guard result == .success else { throw WindowLayoutError.operationFailed(result.rawValue) }
+  }
+
+  @MainActor
+  private static func setSize(_ size: CGSize, of window: AXUIElement) throws {
+    var size = size
+    guard let value = AXValueCreate(.cgSize, &size) else {
+      throw WindowLayoutError.operationFailed(AXError.failure.rawValue)
+    }
+    let result = AXUIElementSetAttributeValue(window, kAXSizeAttribute as CFString, value)
+    guard result == .success else { throw WindowLayoutError.operationFailed(result.rawValue) }
+  }
+
+  /// ウィンドウの中心がある画面（見つからない場合はメイン画面）の可視領域を AX 座標で返す。
+  @MainActor
+  private static func visibleFrame(containing window: CGRect) -> CGRect? {
+    guard let primaryHeight = NSScreen.screens.first?.frame.height else { return nil }
+    let screens = NSScreen.screens.map {
+      (
+        frame: accessibilityFrame(fromScreenFrame: $0.frame, primaryScreenHeight: primaryHeight),
+        visible: accessibilityFrame(
+          fromScreenFrame: $0.visibleFrame, primaryScreenHeight: primaryHeight)
+      )
+    }
+    let center = CGPoint(x: window.midX, y: window.midY)
+    return (screens.first { $0.frame.contains(center) } ?? screens.first)?.visible
+  }
+}
@@ -0,0 +1,74 @@
+import AppKit
+import CoreGraphics
+
+/// ユーザーが作業中のスクリーンを特定するヘルパー。
+///
+/// 最前面アプリの最前面ウィンドウ（通常レイヤー）の位置から、そのウィンドウと
+/// 最も広く重なるスクリーンを返す。ウィンドウ一覧の取得には画面収録の権限は不要
+/// （ウィンドウ名は取得できないが、位置と所有プロセスは取得できる）。
+@MainActor
+enum ActiveScreenLocator {
+
+  /// 作業中のスクリーンを返す。
+  ///
+  /// 最前面アプリが自分自身の場合やウィンドウを持たない場合（Finder のデスクトップなど）は nil。
+  static func activeScreen() -> NSScreen? {
+    guard
+      let app = NSWorkspace.shared.frontmostApplication,
+      app.processIdentifier != ProcessInfo.processInfo.processIdentifier,
+      let primaryHeight = NSScreen.screens.first?.frame.maxY,
+      let windowRect = frontmostWindowBounds(ownerPID: app.processIdentifier)
+    else { return nil }
+
+    let screens = NSScreen.screens
+    let rect = cocoaRect(fromQuartz: windowRect, primaryScreenHeight: primaryHeight)
+    guard let index = bestScreenIndex(for: rect, screens: screens.map(\.frame)) else {
+      return nil
+    }
+    return screens[index]
+  }
+
+  /// 指定プロセスの最前面ウィンドウの位置（Quartz 座標）を返す。
+  private static func frontmostWindowBounds(ownerPID: pid_t) -> CGRect? {
+    let options: CGWindowListOption = [.optionOnScreenOnly, .excludeDesktopElements]
+    guard
+      let windows = CGWindowListCopyWindowInfo(options, kCGNullWindowID)
+        as? [[String: Any]]
+    else { return nil }
+
+    // ウィンドウ一覧は前面から順に並んでいる
+    for window in windows {
+      guard (window[kCGWindowOwnerPID as String] as? pid_t) == ownerPID,
+        (window[kCGWindowLayer as String] as? Int) == 0,
+        let boundsDict = window[kCGWindowBounds as String] as? NSDictionary,
+        let bounds = CGRect(dictionaryRepresentation: boundsDict),
+        bounds.width > 0, bounds.height > 0
+      else { continue }
+      return bounds
+    }
+    return nil
+  }
+
+  /// Quartz 座標（プライマリスクリーン左上が原点、y 下向き）を
+  /// Cocoa 座標（プライマリスクリーン左下が原点、y 上向き）へ変換する。
+  nonisolated static func cocoaRect(fromQuartz rect: CGRect, primaryScreenHeight: CGFloat)
+    -> CGRect
+  {
+    CGRect(
+      x: rect.minX, y: primaryScreenHeight - rect.maxY, width: rect.width, height: rect.height)
+  }
+
+  /// 矩形と最も広く重なるスクリーンのインデックスを返す（重ならない場合は nil）。
+  nonisolated static func bestScreenIndex(for rect: CGRect, screens: [CGRect]) -> Int? {
+    var best: (index: Int, area: CGFloat)?
+    for (index, screen) in screens.enumerated() {
+      let intersection = screen.intersection(rect)
+      guard !intersection.isNull else { continue }
+      let area = intersection.width * intersection.height
+      if area > 0, area > (best?.area ?? 0) {
+        best = (index, area)
+      }
+    }
+    return best?.index
+  }
+}
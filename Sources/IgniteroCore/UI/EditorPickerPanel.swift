@@ -21,6 +21,10 @@ public final class EditorPickerState {
     case .vscode: "v"
     case .antigravity: "a"
     case .zed: "z"
+    case .intellij: "i"
+    case .pycharm: "p"
+    case .webstorm: "s"
+    case .xcode: "x"
     }
   }
 
@@ -35,6 +39,10 @@ public final class EditorPickerState {
     case "v": .vscode
     case "a": .antigravity
     case "z": .zed
+    case "i": .intellij
+    case "p": .pycharm
+    case "s": .webstorm
+    case "x": .xcode
     default: nil
     }
   }
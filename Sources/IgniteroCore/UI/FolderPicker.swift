@@ -0,0 +1,60 @@
+import AppKit
+
+/// フォルダ選択ダイアログを非同期に表示する。
+///
+/// `runModal()` でメインスレッドをブロックせず、`begin(completionHandler:)` の完了を待って
+/// 選択結果を返す。呼び出し元の Task がキャンセルされた場合はダイアログを閉じて nil を返す。
+@MainActor
+public enum FolderPicker {
+
+  /// フォルダを 1 つ選択させる。
+  ///
+  /// - Parameters:
+  ///   - message: ダイアログに表示する説明文
+  ///   - prompt: 決定ボタンのラベル（nil の場合はシステム既定）
+  ///   - initialDirectory: 最初に表示するディレクトリ
+  /// - Returns: 選択されたフォルダの URL。キャンセル時は nil
+  public static func chooseDirectory(
+    message: String,
+    prompt: String? = nil,
+    initialDirectory: URL? = nil
+  ) async -> URL? {
+    guard !Task.isCancelled else { return nil }
+
+    let panel = NSOpenPanel()
+    panel.canChooseDirectories = true
+    panel.canChooseFiles = false
+    panel.allowsMultipleSelection = false
+    panel.message = message
+    if let prompt {
+      panel.prompt = prompt
+    }
+    if let initialDirectory {
+      panel.directoryURL = initialDirectory
+    }
+
+    let holder = PanelHolder(panel: panel)
+    return await withTaskCancellationHandler {
+      await withCheckedContinuation { continuation in
+        panel.begin { response in
+          continuation.resume(returning: response == .OK ? panel.url : nil)
+        }
+      }
+    } onCancel: {
+      // キャンセルでダイアログを閉じると begin の完了ハンドラが .cancel で呼ばれる
+      Task { @MainActor in
+        holder.panel.cancel(nil)
+      }
+    }
+  }
+
+  /// キャンセルハンドラ（Sendable クロージャ）からパネルを参照するための入れ物
+  @MainActor
+  private final class PanelHolder {
+    let panel: NSOpenPanel
+
+    init(panel: NSOpenPanel) {
+      self.panel = panel
+    }
+  }
+}
@@ -103,3 +103,16 @@ public final class LauncherPanel: NSPanel {
     hasShadow = true
   }
 }
+
+// MARK: - AppearanceMode
+
+extension AppearanceMode {
+  /// パネルに設定する外観（`system` の場合は nil でシステムの設定に従う）
+  var nsAppearance: NSAppearance? {
+    switch self {
+    case .system: nil
+    case .light: NSAppearance(named: .aqua)
+    case .dark: NSAppearance(named: .darkAqua)
+    }
+  }
+}
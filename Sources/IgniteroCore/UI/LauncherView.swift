@@ -15,12 +15,17 @@ public struct LauncherView: View {
 
   @FocusState private var isSearchFieldFocused: Bool
   @State private var scanRotation: Double = 0
+  /// ランチャーを表示しているディスプレイの倍率（アイコンの PNG サイズの選択に使う）
+  @Environment(\.displayScale) private var displayScale
 
   // MARK: - Callbacks
 
   /// 検索結果が選択・実行された際のコールバック
   var onExecute: ((SearchResult) -> Void)?
 
+  /// 複数選択した結果をまとめて実行する際のコールバック
+  var onExecuteMarked: (() -> Void)?
+
   /// ランチャーを非表示にする際のコールバック
   var onDismiss: (() -> Void)?
 
@@ -49,6 +54,7 @@ public struct LauncherView: View {
   /// - Parameters:
   ///   - viewModel: ランチャービューモデル
   ///   - onExecute: 結果実行コールバック
+  ///   - onExecuteMarked: 複数選択の一括実行コールバック
   ///   - onDismiss: 非表示コールバック
   ///   - onShowEditorPicker: エディタピッカー表示コールバック
   ///   - onShowTerminalPicker: ターミナルピッカー表示コールバック
@@ -58,6 +64,7 @@ public struct LauncherView: View {
   public init(
     viewModel: LauncherViewModel,
     onExecute: ((SearchResult) -> Void)? = nil,
+    onExecuteMarked: (() -> Void)? = nil,
     onDismiss: (() -> Void)? = nil,
     onShowEditorPicker: ((String) -> Void)? = nil,
     onShowTerminalPicker: ((String) -> Void)? = nil,
@@ -68,6 +75,7 @@ public struct LauncherView: View {
   ) {
     self.viewModel = viewModel
     self.onExecute = onExecute
+    self.onExecuteMarked = onExecuteMarked
     self.onDismiss = onDismiss
     self.onShowEditorPicker = onShowEditorPicker
     self.onShowTerminalPicker = onShowTerminalPicker
@@ -81,6 +89,18 @@ public struct LauncherView: View {
 
   public var body: some View {
     VStack(spacing: 0) {
+      // エラーバナー
+      if let error = viewModel.lastError {
+        errorBanner(error)
+      }
+
+      // ゴミ箱に入れる確認・元に戻すバナー
+      if let result = viewModel.pendingTrash {
+        trashConfirmationBanner(result)
+      } else if let item = viewModel.trashUndo {
+        trashUndoBanner(item)
+      }
+
       // アップデートバナー
       if viewModel.shouldShowUpdateBanner, let version = viewModel.updateBannerVersion {
         updateBanner(version: version)
@@ -99,18 +119,18 @@ public struct LauncherView: View {
         resultsList
       }
     }
-    .frame(width: WindowManager.width)
+    .frame(width: CGFloat(viewModel.appearance.clampedWindowWidth))
     .background {
       ZStack {
-        RoundedRectangle(cornerRadius: 12)
-          .fill(.ultraThinMaterial)
-        RoundedRectangle(cornerRadius: 12)
+        windowShape
+          .fill(viewModel.appearance.material.swiftUIMaterial)
+        windowShape
           .fill(warmGradient)
       }
     }
-    .clipShape(RoundedRectangle(cornerRadius: 12))
+    .clipShape(windowShape)
     .overlay {
-      RoundedRectangle(cornerRadius: 12)
+      windowShape
         .strokeBorder(.white.opacity(0.3), lineWidth: 1)
     }
     .onAppear {
@@ -124,16 +144,25 @@ public struct LauncherView: View {
     }
   }
 
+  /// 外観設定の角丸を適用したウィンドウの形
+  private var windowShape: RoundedRectangle {
+    RoundedRectangle(cornerRadius: CGFloat(viewModel.appearance.clampedCornerRadius))
+  }
+
   // MARK: - Warm Gradient
 
   /// Tauri 版と同様のウォームグラデーション。
-  /// マテリアル背景の上に重ねて暖色のティントを加える。
+  /// マテリアル背景の上に重ねてアクセントカラーのティントを加える（既定のエンバーは暖色）。
   private var warmGradient: LinearGradient {
-    LinearGradient(
+    let tint: (middle: Color, end: Color) =
+      viewModel.appearance.accentColor == .ember
+      ? (Color(red: 1.0, green: 0.71, blue: 0.51), Color(red: 1.0, green: 0.39, blue: 0.31))
+      : (accentSecondary, accent)
+    return LinearGradient(
       stops: [
         .init(color: Color(red: 1.0, green: 0.98, blue: 0.96).opacity(0.5), location: 0.0),
-        .init(color: Color(red: 1.0, green: 0.71, blue: 0.51).opacity(0.25), location: 0.55),
-        .init(color: Color(red: 1.0, green: 0.39, blue: 0.31).opacity(0.25), location: 1.0),
+        .init(color: tint.middle.opacity(0.25), location: 0.55),
+        .init(color: tint.end.opacity(0.25), location: 1.0),
       ],
       startPoint: .topLeading,
       endPoint: .bottomTrailing
@@ -182,7 +211,7 @@ public struct LauncherView: View {
       if viewModel.isScanning {
         Image(systemName: "arrow.trianglehead.2.counterclockwise")
           .font(.system(size: 24))
-          .foregroundStyle(Self.ember)
+          .foregroundStyle(accent)
           .rotationEffect(.degrees(scanRotation))
       } else if let url = Bundle.main.url(forResource: "AppIcon", withExtension: "icns"),
         let nsImage = NSImage(contentsOf: url)
@@ -223,11 +252,11 @@ public struct LauncherView: View {
           .environment(\.colorScheme, .light)
       } else {
         Image(systemName: "magnifyingglass")
-          .foregroundStyle(Self.ember.opacity(0.7))
+          .foregroundStyle(accent.opacity(0.7))
           .font(.system(size: 14))
 
         TextField(
-          "Search apps and directories",
+          viewModel.searchPlaceholder,
           text: $viewModel.searchQuery
         )
         .textFieldStyle(.plain)
@@ -236,7 +265,7 @@ public struct LauncherView: View {
         .environment(\.colorScheme, .light)
         .focused($isSearchFieldFocused)
         .onChange(of: viewModel.searchQuery) {
-          viewModel.updateSearch()
+          viewModel.scheduleSearch()
         }
         .onSubmit {
           handleEnterKey()
@@ -249,7 +278,7 @@ public struct LauncherView: View {
     .clipShape(RoundedRectangle(cornerRadius: 8))
     .overlay {
       RoundedRectangle(cornerRadius: 8)
-        .strokeBorder(Self.ember.opacity(0.2), lineWidth: 1)
+        .strokeBorder(accent.opacity(0.2), lineWidth: 1)
     }
   }
 
@@ -266,7 +295,7 @@ public struct LauncherView: View {
         .clipShape(RoundedRectangle(cornerRadius: 14))
         .overlay {
           RoundedRectangle(cornerRadius: 14)
-            .strokeBorder(Self.ember.opacity(0.45), lineWidth: 1.5)
+            .strokeBorder(accent.opacity(0.45), lineWidth: 1.5)
         }
     }
     .buttonStyle(.plain)
@@ -278,7 +307,7 @@ public struct LauncherView: View {
   private func calculatorRow(result: String) -> some View {
     HStack {
       Image(systemName: "equal")
-        .foregroundStyle(Self.ember)
+        .foregroundStyle(accent)
         .font(.system(size: 14))
       Text(result)
         .font(.system(size: 16, weight: .medium, design: .monospaced))
@@ -292,14 +321,14 @@ public struct LauncherView: View {
     .padding(.vertical, 8)
     .background(
       LinearGradient(
-        colors: [Self.ember.opacity(0.12), Self.ember.opacity(0.06)],
+        colors: [accent.opacity(0.12), accent.opacity(0.06)],
         startPoint: .leading,
         endPoint: .trailing
       )
     )
     .overlay(alignment: .leading) {
       Rectangle()
-        .fill(Self.ember.opacity(0.8))
+        .fill(accent.opacity(0.8))
         .frame(width: 3)
     }
   }
@@ -307,12 +336,18 @@ public struct LauncherView: View {
   // MARK: - Results List
 
   private var resultsList: some View {
-    ScrollViewReader { proxy in
+    let headers = viewModel.sectionHeaders
+    return ScrollViewReader { proxy in
       ScrollView {
         LazyVStack(spacing: 0) {
           ForEach(Array(viewModel.searchResults.enumerated()), id: \.offset) { index, result in
-            resultRow(result: result, index: index)
-              .id(index)
+            VStack(spacing: 0) {
+              if let group = headers[index] {
+                sectionHeader(group)
+              }
+              resultRow(result: result, index: index)
+            }
+            .id(index)
           }
         }
       }
@@ -325,24 +360,65 @@ public struct LauncherView: View {
     }
   }
 
+  /// 検索結果の見出し（設定で見出しごとにまとめる場合のみ）
+  private func sectionHeader(_ group: SearchResultGroup) -> some View {
+    HStack {
+      Text(group.title)
+        .font(.system(size: 11, weight: .semibold))
+        .foregroundStyle(.secondary)
+      Spacer()
+    }
+    .padding(.horizontal, 16)
+    .padding(.top, 8)
+    .padding(.bottom, 2)
+  }
+
   // MARK: - Theme Colors
 
-  private static let ember = Color(red: 1.0, green: 0.47, blue: 0.28)  // #ff7847
-  private static let plasma = Color(red: 1.0, green: 0.70, blue: 0.28)  // #ffb347
+  /// アクセントカラーの主色（既定は #ff7847）
+  private var accent: Color {
+    Color(viewModel.appearance.accentColor.primaryRGB)
+  }
+
+  /// アクセントカラーの副色（既定は #ffb347）
+  private var accentSecondary: Color {
+    Color(viewModel.appearance.accentColor.secondaryRGB)
+  }
 
   // MARK: - Result Row
 
   private func resultRow(result: SearchResult, index: Int) -> some View {
     let isSelected = index == viewModel.selectedIndex
+    let isMarked = viewModel.isMarked(result)
 
     return HStack(spacing: 12) {
+      if !viewModel.markedResults.isEmpty {
+        Image(systemName: isMarked ? "checkmark.circle.fill" : "circle")
+          .font(.system(size: 14))
+          .foregroundStyle(isMarked ? accent : .secondary)
+      }
+
       resultIcon(for: result, isSelected: isSelected)
 
       VStack(alignment: .leading, spacing: 2) {
-        Text(result.name)
-          .font(.system(size: isSelected ? 17 : 14, weight: isSelected ? .semibold : .medium))
-          .lineLimit(1)
-          .animation(.easeInOut(duration: 0.14), value: isSelected)
+        HStack(spacing: 4) {
+          Text(highlightedName(result))
+            .font(.system(size: isSelected ? 17 : 14, weight: isSelected ? .semibold : .medium))
+            .lineLimit(1)
+            .animation(.easeInOut(duration: 0.14), value: isSelected)
+          if let disambiguation = result.disambiguation {
+            Text(disambiguation)
+              .font(.system(size: 12))
+              .foregroundStyle(.secondary)
+              .lineLimit(1)
+              .truncationMode(.head)
+          }
+          if result.isFavorite {
+            Image(systemName: "star.fill")
+              .font(.system(size: 10))
+              .foregroundStyle(.yellow)
+          }
+        }
 
         Text(resultSubtitle(for: result))
           .font(.system(size: 12))
@@ -350,7 +426,7 @@ public struct LauncherView: View {
           .lineLimit(1)
 
         if result.kind == .directory {
-          let editor = result.editor ?? viewModel.defaultEditorRawValue
+          let editor = viewModel.editorRawValue(for: result)
           Text("\(editorDisplayName(editor))で開く")
             .font(.system(size: 11))
             .foregroundStyle(.secondary)
@@ -367,7 +443,7 @@ public struct LauncherView: View {
     }
     .padding(.horizontal, 16)
     .padding(.vertical, 6)
-    .frame(height: WindowManager.rowHeight)
+    .frame(height: CGFloat(viewModel.appearance.density.rowHeight))
     .background {
       if isSelected {
         selectedRowBackground
@@ -380,20 +456,50 @@ public struct LauncherView: View {
         onExecute?(selected)
       }
     }
+    .contextMenu {
+      resultContextMenu(for: result)
+    }
+  }
+
+  /// 結果の右クリックメニュー（コピーできる形式と、アプリのカテゴリの絞り込み・ディレクトリのゴミ箱を並べる）
+  @ViewBuilder
+  private func resultContextMenu(for result: SearchResult) -> some View {
+    ForEach(ClipboardCopyFormat.allCases, id: \.self) { format in
+      if format.text(for: result) != nil {
+        Button(format.label) {
+          viewModel.onCopyResult?(result, format)
+        }
+      }
+    }
+    if let categoryName = result.categoryName {
+      Divider()
+      Button(
+        viewModel.isFilteringCategory(categoryName)
+          ? "カテゴリの絞り込みを解除" : "「\(categoryName)」のアプリだけを表示"
+      ) {
+        viewModel.toggleCategoryFilter(categoryName)
+      }
+    }
+    if result.kind == .directory {
+      Divider()
+      Button("ゴミ箱に入れる…", role: .destructive) {
+        viewModel.onMoveToTrash?(result)
+      }
+    }
   }
 
   /// 選択行の背景: 左ボーダー + オレンジグラデーション
   private var selectedRowBackground: some View {
     HStack(spacing: 0) {
       Rectangle()
-        .fill(Self.ember.opacity(0.9))
+        .fill(accent.opacity(0.9))
         .frame(width: 3)
       Rectangle()
         .fill(
           LinearGradient(
             colors: [
-              Self.ember.opacity(0.25),
-              Self.plasma.opacity(0.18),
+              accent.opacity(0.25),
+              accentSecondary.opacity(0.18),
             ],
             startPoint: .leading,
             endPoint: .trailing
@@ -402,32 +508,47 @@ public struct LauncherView: View {
     }
   }
 
+  /// 検索語に一致した文字をアクセントカラーで強調した名前
+  private func highlightedName(_ result: SearchResult) -> AttributedString {
+    var name = AttributedString(result.name)
+    let count = name.characters.count
+    for offset in result.matchedIndices where offset < count {
+      let start = name.characters.index(name.startIndex, offsetBy: offset)
+      name[start..<name.characters.index(after: start)].foregroundColor = accent
+    }
+    return name
+  }
+
   // MARK: - Result Icon
 
   private func resultIcon(for result: SearchResult, isSelected: Bool) -> some View {
     Group {
       switch result.kind {
       case .app:
-        if let iconPath = result.iconPath,
-          let nsImage = NSImage(contentsOfFile: iconPath)
+        if let iconPath = viewModel.iconPath(for: result),
+          let nsImage = NSImage(
+            contentsOfFile: IconCacheManager.iconPath(iconPath, pointSize: 32, scale: displayScale))
         {
           Image(nsImage: nsImage)
             .resizable()
             .frame(width: 32, height: 32)
             .clipShape(RoundedRectangle(cornerRadius: 4))
         } else {
+          // 未変換のアイコンは表示したときに変換を要求し、終わるまで汎用アイコンを出す
           Image(systemName: "app.fill")
             .font(.system(size: 26))
             .foregroundStyle(.secondary)
+            .onAppear { viewModel.requestIcon(for: result) }
         }
       case .directory:
-        directoryIcon(editor: result.editor ?? viewModel.defaultEditorRawValue)
+        directoryIcon(
+          editor: viewModel.editorRawValue(for: result), projectType: result.projectType)
       case .command:
-        Image(systemName: "terminal.fill")
+        Image(systemName: result.symbolName ?? "terminal.fill")
           .font(.system(size: 26))
-          .foregroundStyle(Self.plasma)
+          .foregroundStyle(accentSecondary)
       case .webSearch:
-        Image(systemName: "globe")
+        Image(systemName: result.symbolName ?? "globe")
           .font(.system(size: 26))
           .foregroundStyle(.blue)
       case .colorPicker:
@@ -438,19 +559,40 @@ public struct LauncherView: View {
         Image(systemName: "face.smiling")
           .font(.system(size: 26))
           .foregroundStyle(.orange)
+      case .character:
+        Text(result.path)
+          .font(.system(size: 24))
+          .lineLimit(1)
+          .minimumScaleFactor(0.3)
+      case .process:
+        Image(systemName: "cpu")
+          .font(.system(size: 26))
+          .foregroundStyle(.red)
+      case .systemSettings:
+        Image(systemName: result.symbolName ?? "gearshape")
+          .font(.system(size: 26))
+          .foregroundStyle(.gray)
+      case .systemControl:
+        Image(systemName: result.symbolName ?? "switch.2")
+          .font(.system(size: 26))
+          .foregroundStyle(.indigo)
+      case .windowLayout:
+        Image(systemName: result.symbolName ?? "macwindow")
+          .font(.system(size: 26))
+          .foregroundStyle(.teal)
       }
     }
     .frame(width: 36, height: 36)
     .scaleEffect(isSelected ? 1.25 : 1.0)
     .shadow(
-      color: isSelected ? Self.ember.opacity(0.18) : .clear,
+      color: isSelected ? accent.opacity(0.18) : .clear,
       radius: isSelected ? 6 : 0, y: isSelected ? 2 : 0
     )
     .animation(.easeInOut(duration: 0.14), value: isSelected)
   }
 
-  /// ディレクトリアイコン: フォルダ + エディタオーバーレイ
-  private func directoryIcon(editor: String?) -> some View {
+  /// ディレクトリアイコン: フォルダ + エディタオーバーレイ + プロジェクトの種類のバッジ
+  private func directoryIcon(editor: String?, projectType: ProjectType?) -> some View {
     ZStack {
       Image(systemName: "folder.fill")
         .font(.system(size: 26))
@@ -465,6 +607,16 @@ public struct LauncherView: View {
           .clipShape(RoundedRectangle(cornerRadius: 2))
           .offset(y: 2)
       }
+
+      if let projectType {
+        Image(systemName: projectType.symbolName)
+          .font(.system(size: 9, weight: .bold))
+          .foregroundStyle(.white)
+          .frame(width: 15, height: 15)
+          .background(Circle().fill(accentSecondary))
+          .offset(x: 13, y: 11)
+          .accessibilityLabel(projectType.displayName)
+      }
     }
   }
 
@@ -478,20 +630,51 @@ public struct LauncherView: View {
   private func resultSubtitle(for result: SearchResult) -> String {
     switch result.kind {
     case .app:
-      result.path
+      // バンドル ID で絞り込んだときに見分けられるよう、バージョンとバンドル ID を併記する
+      [result.path, result.version.map { "v\($0)" }, result.bundleIdentifier]
+        .compactMap { $0 }.joined(separator: "  ")
     case .directory:
-      result.path
+      // プロジェクトの種類・Git リポジトリの現在のブランチ・Finder のタグを、Xcode の
+      // プロジェクトは ⌘↩ で開けることを併記する
+      [
+        result.path, result.projectType?.displayName, result.gitBranch.map { "⎇ \($0)" },
+        result.finderTags.isEmpty
+          ? nil : result.finderTags.map { "#\($0.name)" }.joined(separator: " "),
+        result.xcodeProject.map { _ in "⌘↩ Xcode" },
+      ].compactMap { $0 }.joined(separator: "  ")
     case .command:
-      result.command ?? ""
+      if let directory = viewModel.commandTargetDirectory {
+        "\(result.command ?? "")  （\((directory as NSString).abbreviatingWithTildeInPath) で実行）"
+      } else {
+        result.command ?? ""
+      }
     case .webSearch:
-      "ブラウザで検索を開く"
+      "ブラウザまたは対応アプリで開く"
     case .colorPicker:
       "画面上の色を選択してクリップボードにコピー"
     case .emoji:
       "絵文字ピッカーを開く"
+    case .character:
+      "クリップボードにコピー"
+    case .process:
+      result.process.map(processSubtitle) ?? ""
+    case .systemSettings:
+      "システム設定で開く"
+    case .systemControl:
+      "すぐに実行"
+    case .windowLayout:
+      "直前に使っていたアプリのウィンドウを操作"
     }
   }
 
+  /// プロセスの PID・CPU・メモリと終了操作を示す
+  private func processSubtitle(_ process: RunningProcess) -> String {
+    let cpu = process.cpuUsage.map { String(format: "%.1f%%", $0) } ?? "-"
+    let memory = ByteCountFormatter.string(
+      fromByteCount: Int64(clamping: process.memoryBytes), countStyle: .memory)
+    return "PID \(process.pid) · CPU \(cpu) · \(memory) · Enter で終了 / ⌘Enter で強制終了"
+  }
+
   // MARK: - Directory Key Hints
 
   private var directoryKeyHints: some View {
@@ -524,7 +707,7 @@ public struct LauncherView: View {
     .clipShape(RoundedRectangle(cornerRadius: 4))
     .overlay {
       RoundedRectangle(cornerRadius: 4)
-        .strokeBorder(Self.ember.opacity(0.4), lineWidth: 1)
+        .strokeBorder(accent.opacity(0.4), lineWidth: 1)
     }
   }
 
@@ -536,7 +719,23 @@ public struct LauncherView: View {
         .foregroundStyle(.blue)
       Text("v\(version) available")
         .font(.system(size: 12))
+      if let status = updateStatusText {
+        Text(status)
+          .font(.system(size: 11))
+          .foregroundStyle(.secondary)
+      }
       Spacer()
+      if viewModel.updateInstallState.isInProgress {
+        ProgressView()
+          .controlSize(.small)
+      } else {
+        Button(viewModel.updateInstallState == .readyToInstall ? "再起動して更新" : "アップデート") {
+          viewModel.onInstallUpdate?()
+        }
+        .font(.system(size: 11))
+        .buttonStyle(.plain)
+        .foregroundStyle(.blue)
+      }
       Button {
         viewModel.dismissUpdateBanner(version: version)
       } label: {
@@ -551,6 +750,97 @@ public struct LauncherView: View {
     .background(Color.blue.opacity(0.08))
   }
 
+  /// アップデートの進行状況の表示
+  private var updateStatusText: String? {
+    switch viewModel.updateInstallState {
+    case .idle, .readyToInstall: nil
+    case .downloading: "ダウンロード中…"
+    case .verifying: "検証中…"
+    case .installing: "インストール中…"
+    case .failed(let reason): "失敗: \(reason)"
+    }
+  }
+
+  // MARK: - Error Banner
+
+  private func errorBanner(_ error: LauncherError) -> some View {
+    HStack(alignment: .top) {
+      Image(systemName: "exclamationmark.triangle.fill")
+        .foregroundStyle(.orange)
+      VStack(alignment: .leading, spacing: 2) {
+        Text(error.message(in: viewModel.language))
+          .font(.system(size: 12))
+          .lineLimit(2)
+        Text(error.recoverySuggestion(in: viewModel.language))
+          .font(.system(size: 11))
+          .foregroundStyle(.secondary)
+      }
+      .help(error.code)
+      Spacer()
+      Button {
+        viewModel.dismissError()
+      } label: {
+        Image(systemName: "xmark")
+          .font(.system(size: 10))
+          .foregroundStyle(.secondary)
+      }
+      .buttonStyle(.plain)
+    }
+    .padding(.horizontal, 16)
+    .padding(.vertical, 8)
+    .background(Color.orange.opacity(0.08))
+  }
+
+  // MARK: - Trash Banner
+
+  private func trashConfirmationBanner(_ result: SearchResult) -> some View {
+    HStack {
+      Image(systemName: "trash.fill")
+        .foregroundStyle(.red)
+      Text("「\(result.name)」をゴミ箱に入れますか？")
+        .font(.system(size: 12))
+        .lineLimit(1)
+      Spacer()
+      Button("ゴミ箱に入れる") {
+        viewModel.onConfirmTrash?(result)
+      }
+      .font(.system(size: 11))
+      .buttonStyle(.plain)
+      .foregroundStyle(.red)
+      Button {
+        viewModel.cancelTrashConfirmation()
+      } label: {
+        Image(systemName: "xmark")
+          .font(.system(size: 10))
+          .foregroundStyle(.secondary)
+      }
+      .buttonStyle(.plain)
+    }
+    .padding(.horizontal, 16)
+    .padding(.vertical, 8)
+    .background(Color.red.opacity(0.08))
+  }
+
+  private func trashUndoBanner(_ item: TrashedItem) -> some View {
+    HStack {
+      Image(systemName: "trash")
+        .foregroundStyle(.secondary)
+      Text("「\(item.name)」をゴミ箱に入れました")
+        .font(.system(size: 12))
+        .lineLimit(1)
+      Spacer()
+      Button("元に戻す（⌘Z）") {
+        viewModel.onUndoTrash?()
+      }
+      .font(.system(size: 11))
+      .buttonStyle(.plain)
+      .foregroundStyle(.blue)
+    }
+    .padding(.horizontal, 16)
+    .padding(.vertical, 8)
+    .background(Color.secondary.opacity(0.08))
+  }
+
   // MARK: - Key Event Handling
 
   /// Enter キーが押された際の処理。
@@ -565,8 +855,31 @@ public struct LauncherView: View {
       if let result = viewModel.confirmSelection() {
         onExecute?(result)
       }
+    case .executeMarked:
+      onExecuteMarked?()
     default:
       break
     }
   }
 }
+
+// MARK: - Appearance Mapping
+
+extension Color {
+  /// 外観設定の RGB（0〜1）から色を作る。
+  fileprivate init(_ rgb: (red: Double, green: Double, blue: Double)) {
+    self.init(red: rgb.red, green: rgb.green, blue: rgb.blue)
+  }
+}
+
+extension LauncherMaterial {
+  /// 背景に使う SwiftUI のマテリアル
+  fileprivate var swiftUIMaterial: Material {
+    switch self {
+    case .ultraThin: .ultraThinMaterial
+    case .thin: .thinMaterial
+    case .regular: .regularMaterial
+    case .thick: .thickMaterial
+    }
+  }
+}
@@ -19,8 +19,12 @@ public enum SpecialKeyAction: Sendable, Equatable {
   case showEditorPicker
   /// ターミナルピッカーを表示する
   case showTerminalPicker
+  /// ディレクトリでコマンドを選んで実行するモードに入る
+  case runCommandHere
   /// 選択された結果を実行する
   case execute
+  /// 複数選択した結果をまとめて実行する
+  case executeMarked
   /// ランチャーを非表示にする
   case dismiss
   /// 計算結果をクリップボードにコピーする
@@ -51,6 +55,27 @@ public final class LauncherViewModel {
   /// 現在選択中のインデックス
   public var selectedIndex: Int = 0
 
+  /// 見出しを表示する結果の位置と、その見出しの区分（見出しごとにまとめない設定では空）
+  ///
+  /// 結果は `applySearchResults` で特殊アクションを挿入した後に見出しの順へ並べ直して
+  /// あるため、区分が変わる位置に見出しを置けば区分ごとに 1 つの見出しになる。
+  public var sectionHeaders: [Int: SearchResultGroup] {
+    guard resultSettings.groupResults else { return [:] }
+    var headers: [Int: SearchResultGroup] = [:]
+    var previous: SearchResultGroup?
+    for (index, result) in searchResults.enumerated() {
+      let group = SearchResultGroup(kind: result.kind)
+      if group != previous {
+        headers[index] = group
+      }
+      previous = group
+    }
+    return headers
+  }
+
+  /// まとめて起動するために複数選択した結果（選択した順。検索し直しても保持する）
+  public private(set) var markedResults: [SearchResult] = []
+
   /// 計算式の評価結果（計算式でない場合は nil）
   public private(set) var calculatorResult: String?
 
@@ -60,43 +85,180 @@ public final class LauncherViewModel {
   /// 現在のバージョンのアップデートバナーが非表示にされたか
   public private(set) var isUpdateBannerDismissed: Bool = false
 
+  /// バナーのバージョンをアプリ内でインストールできるか（できない場合はリリースページを開く）
+  public var isUpdateInstallable: Bool = false
+
+  /// アップデートのダウンロード・インストールの進行状況
+  public var updateInstallState: UpdateInstallState = .idle
+
+  /// 直前の操作の失敗（エラーバナーに表示する。ない場合は nil）
+  public private(set) var lastError: LauncherError?
+
+  /// ゴミ箱に入れる確認中の結果（確認しない場合・確認中でない場合は nil）
+  public private(set) var pendingTrash: SearchResult?
+
+  /// 直前にゴミ箱に入れた項目（元に戻せる間だけバナーに表示する）
+  public private(set) var trashUndo: TrashedItem?
+
   // MARK: - データソース
 
   /// 検索対象のアプリケーション一覧
-  public var apps: [AppItem] = []
+  public var apps: [AppItem] = [] {
+    didSet { rebuildSearchIndex() }
+  }
 
   /// 検索対象のディレクトリ一覧
-  public var directories: [DirectoryItem] = []
+  public var directories: [DirectoryItem] = [] {
+    didSet { rebuildSearchIndex() }
+  }
 
   /// 検索対象のカスタムコマンド一覧
-  public var commands: [CustomCommand] = []
+  public var commands: [CustomCommand] = [] {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// 名前から追加の検索キーを生成する変換器（設定で有効な場合のみ）
+  public var transliterators: [any Transliterator] = [] {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// 項目に割り当てた別名
+  public var itemAliases: [ItemAlias] = [] {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// お気に入りのパス（一致する結果と空クエリ時の一覧で先頭に表示する）
+  public var favoritePaths: Set<String> = [] {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// ユーザー定義の URL ショートカット
+  public var quickLinks: [QuickLink] = [] {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// 検索対象のシステム設定のパネル
+  public var systemSettingsPanes: [SystemSettingsPane] = [] {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// 検索対象のシステム操作
+  public var systemControls: [SystemControl] = [] {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// 検索対象のウィンドウ操作
+  public var windowLayoutActions: [WindowLayoutAction] = [] {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// 検索対象の SSH ホスト・tmux セッション
+  public var terminalTargets: [TerminalTarget] = [] {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// 検索結果から非表示にするパス
+  public var hiddenPaths: Set<String> = [] {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// ディレクトリの全文検索（設定時は `directories` ではなくキャッシュ DB を検索する）
+  public var directorySearcher: (any DirectoryFullTextSearching)? {
+    didSet { rebuildSearchIndex() }
+  }
+
+  /// 検索対象の正規化済みインデックス（データソースの更新時に再構築する）
+  public private(set) var searchIndex: SearchIndex = .empty
 
   /// 選択履歴
   public var history: [SelectionHistoryEntry] = []
 
+  /// 最近開いたディレクトリ（空クエリ時に先頭へ表示する）
+  public var recentProjects: [RecentProject] = []
+
+  /// 検索対象を絞り込むプレフィックス設定
+  public var searchPrefixes: SearchPrefixSettings = .default
+
+  /// 検索結果の件数と並べ方
+  public var resultSettings: ResultSettings = .default
+
+  /// モード別ホットキーで指定された検索対象（通常表示時は nil）
+  public var scopeOverride: SearchScope?
+
+  /// 「ここでコマンドを実行」で選んだコマンドを実行するディレクトリ（通常の検索時は nil）
+  public private(set) var commandTargetDirectory: String?
+
   /// エディタ名（rawValue）→ キャッシュ済みアイコンパスのマッピング
   public var editorIconPaths: [String: String] = [:]
 
+  /// 表示時に変換したアプリのアイコン（アプリのパス → PNG のパス）
+  ///
+  /// キャッシュを読み直すまでは `AppItem.iconPath` が nil のままのため、こちらを優先して使う。
+  public var convertedIconPaths: [String: String] = [:]
+
+  /// 変換を要求したアプリのパス（同じアプリを何度も要求しない）
+  private var requestedIconPaths: Set<String> = []
+
   /// デフォルトエディタの rawValue（アイコン表示フォールバック用）
   public var defaultEditorRawValue: String = EditorType.cursor.rawValue
 
+  /// ディレクトリのパス → 記憶したエディタの rawValue（記憶を使わない設定では空）
+  public var directoryEditors: [String: String] = [:]
+
+  /// Xcode のプロジェクトがあるディレクトリを Xcode で開くか
+  public var opensXcodeProjectsInXcode = false
+
   /// デフォルトターミナルの表示名
   public var defaultTerminalName: String = "Terminal"
 
+  /// エラーバナーの表示言語（`auto` は解決済み）
+  public var language: AppLanguage = .ja
+
+  /// ランチャーの外観（アクセントカラー・角丸・背景・行の高さ）
+  public var appearance: AppearanceSettings = .default
+
   /// 検索フィールドへのフォーカス要求トリガー（インクリメントで発火）
   public var focusTrigger: Int = 0
 
   /// アップデートバナーが非表示にされたときに呼ばれるコールバック（非表示状態の永続化用）
   public var onUpdateBannerDismissed: ((String) -> Void)?
 
+  /// アップデートバナーの「アップデート」ボタンが押されたときに呼ばれるコールバック
+  public var onInstallUpdate: (() -> Void)?
+
+  /// ゴミ箱に入れる確認の「ゴミ箱に入れる」ボタンが押されたときに呼ばれるコールバック
+  public var onConfirmTrash: ((SearchResult) -> Void)?
+
+  /// 結果のコンテキストメニューで「ゴミ箱に入れる」が選ばれたときに呼ばれるコールバック
+  public var onMoveToTrash: ((SearchResult) -> Void)?
+
+  /// ゴミ箱のバナーの「元に戻す」ボタンが押されたときに呼ばれるコールバック
+  public var onUndoTrash: (() -> Void)?
+
+  /// 結果のコンテキストメニューでコピーが選ばれたときに呼ばれるコールバック
+  public var onCopyResult: ((SearchResult, ClipboardCopyFormat) -> Void)?
+
+  /// アイコンが未変換のアプリを表示したときに呼ばれるコールバック（アプリのパスを渡す）
+  public var onEnsureIcon: ((String) -> Void)?
+
   // MARK: - 依存関係
 
   private let searchService: SearchService
   private let calculatorEngine: CalculatorEngine
+  private let searchSession: SearchSession
 
   /// clearSearch() 実行中に onChange が updateSearch() を呼ばないようにする抑制フラグ
   private var isClearingSearch = false
 
+  /// データソースをまとめて更新中は didSet で検索インデックスを再構築しない
+  private var isBatchingDataSourceUpdates = false
+
+  /// 検索インデックスの世代（バックグラウンド構築中に再構築された場合、古い構築結果を破棄する）
+  private var searchIndexGeneration = 0
+
+  /// Docker のコンテナの一覧を取得し直している間は true（キー入力ごとに重ねて実行しない）
+  private var isRefreshingDockerContainers = false
+
   // MARK: - 計算プロパティ
 
   /// アップデートバナーを表示すべきかどうか
@@ -104,6 +266,23 @@ public final class LauncherViewModel {
     updateBannerVersion != nil && !isUpdateBannerDismissed
   }
 
+  /// 検索フィールドのプレースホルダー（モード別ホットキーで絞り込み中は対象を示す）
+  public var searchPlaceholder: String {
+    if let commandTargetDirectory {
+      return "Run a command in \((commandTargetDirectory as NSString).lastPathComponent)"
+    }
+    return switch scopeOverride {
+    case .apps: "Search apps"
+    case .directories: "Search directories"
+    case .commands: "Search commands"
+    case .paths: "Search paths"
+    case .characters: "Search emoji and symbols"
+    case .processes: "Search running processes"
+    case .docker: "Search Docker containers"
+    case .all, nil: "Search apps and directories"
+    }
+  }
+
   // MARK: - 初期化
 
   /// LauncherViewModel を初期化する。
@@ -111,34 +290,175 @@ public final class LauncherViewModel {
   /// - Parameters:
   ///   - searchService: ファジー検索サービス
   ///   - calculatorEngine: 計算式評価エンジン
+  ///   - searchSession: 入力中のクエリの検索をデバウンス・キャンセルするセッション
   public init(
     searchService: SearchService = SearchService(),
-    calculatorEngine: CalculatorEngine = CalculatorEngine()
+    calculatorEngine: CalculatorEngine = CalculatorEngine(),
+    searchSession: SearchSession? = nil
   ) {
     self.searchService = searchService
     self.calculatorEngine = calculatorEngine
+    self.searchSession = searchSession ?? SearchSession()
   }
 
   // MARK: - 検索
 
-  /// 検索クエリに基づいて検索を実行し、結果と計算式評価を更新する。
+  /// 検索クエリに基づいて検索を即座に実行し、結果と計算式評価を更新する。
   ///
-  /// 検索クエリが変更されるたびに呼び出す。結果更新時に selectedIndex を 0 にリセットする。
+  /// 待機中の検索（`scheduleSearch`）はキャンセルする。結果更新時に selectedIndex を 0 にリセットする。
   public func updateSearch() {
     // clearSearch() 中は再検索しない（レイアウト再帰防止）
     guard !isClearingSearch else { return }
 
-    // 検索実行
-    searchResults = searchService.search(
+    searchSession.cancel()
+    let results = searchService.search(
       query: searchQuery,
-      apps: apps,
-      directories: directories,
-      commands: commands,
-      history: history
+      index: searchIndex,
+      history: history,
+      prefixes: searchPrefixes,
+      defaultScope: scopeOverride ?? .all,
+      recentProjects: recentProjects,
+      resultSettings: resultSettings
     )
+    applySearchResults(results)
+    refreshDockerContainersIfNeeded()
+  }
+
+  /// 検索クエリの変更に応じて検索を予約する。
+  ///
+  /// 入力中に呼び出すと直前の検索（待機中・実行中）をキャンセルし、最新のクエリだけを
+  /// バックグラウンドで検索して結果を反映する。
+  public func scheduleSearch() {
+    guard !isClearingSearch else { return }
+
+    let searchService = searchService
+    let query = searchQuery
+    let index = searchIndex
+    let history = history
+    let prefixes = searchPrefixes
+    let scope = scopeOverride ?? .all
+    let recentProjects = recentProjects
+    let resultSettings = resultSettings
+    searchSession.submit {
+      try searchService.cancellableSearch(
+        query: query,
+        index: index,
+        history: history,
+        prefixes: prefixes,
+        defaultScope: scope,
+        recentProjects: recentProjects,
+        resultSettings: resultSettings
+      )
+    } onComplete: { [weak self] results in
+      self?.applySearchResults(results)
+    }
+    refreshDockerContainersIfNeeded()
+  }
+
+  /// Docker 検索中であればコンテナの一覧を非同期に取得し直し、変わった場合は検索し直す。
+  ///
+  /// 検索自体は取得済みの一覧で先に表示するため、`docker ps` の完了を待たない。
+  private func refreshDockerContainersIfNeeded() {
+    guard !isRefreshingDockerContainers,
+      SearchQueryParser.parse(searchQuery, prefixes: searchPrefixes).scope == .docker
+    else { return }
+    isRefreshingDockerContainers = true
+    let searchService = searchService
+    Task { [weak self] in
+      let changed = await searchService.refreshDockerContainers()
+      guard let self else { return }
+      self.isRefreshingDockerContainers = false
+      if changed,
+        SearchQueryParser.parse(self.searchQuery, prefixes: self.searchPrefixes).scope == .docker
+      {
+        self.scheduleSearch()
+      }
+    }
+  }
+
+  /// 予約中の検索があれば即座に実行する（確定操作が古い結果に対して行われないようにする）。
+  public func flushPendingSearch() {
+    guard searchSession.isPending else { return }
+    updateSearch()
+  }
+
+  // MARK: - データソースの更新
+
+  /// 複数のデータソースをまとめて更新し、検索インデックスを 1 回だけ再構築する。
+  ///
+  /// - Parameter updates: データソースを更新する処理（この間は didSet による再構築を行わない）
+  public func updateDataSources(_ updates: () -> Void) {
+    isBatchingDataSourceUpdates = true
+    updates()
+    isBatchingDataSourceUpdates = false
+    rebuildSearchIndex()
+  }
+
+  /// 複数のデータソースをまとめて更新し、検索インデックスをバックグラウンドで構築して差し替える。
+  ///
+  /// 構築中もそれまでのインデックスで検索を続けられるため、キャッシュ更新で大量の項目を
+  /// 読み込む場合も入力が止まらない。構築中に別の更新で再構築された場合は、
+  /// より新しいデータソースを反映済みのため構築結果を破棄する。
+  ///
+  /// - Parameter updates: データソースを更新する処理（この間は didSet による再構築を行わない）
+  public func updateDataSourcesInBackground(_ updates: () -> Void) async {
+    isBatchingDataSourceUpdates = true
+    updates()
+    isBatchingDataSourceUpdates = false
+
+    searchIndexGeneration += 1
+    let generation = searchIndexGeneration
+    let apps = apps
+    let directories = directories
+    let commands = commands
+    let quickLinks = quickLinks
+    let systemSettingsPanes = systemSettingsPanes
+    let systemControls = systemControls
+    let windowLayoutActions = windowLayoutActions
+    let terminalTargets = terminalTargets
+    let transliterators = transliterators
+    let aliases = itemAliases
+    let favorites = favoritePaths
+    let hiddenPaths = hiddenPaths
+    let directorySearcher = directorySearcher
+    let canonicalPaths = searchIndex.canonicalPaths
+    let index = await Task.detached(priority: .userInitiated) {
+      SearchIndex(
+        apps: apps, directories: directories, commands: commands, quickLinks: quickLinks,
+        systemSettingsPanes: systemSettingsPanes, systemControls: systemControls,
+        windowLayoutActions: windowLayoutActions, terminalTargets: terminalTargets,
+        transliterators: transliterators, aliases: aliases, favorites: favorites,
+        hiddenPaths: hiddenPaths, directorySearcher: directorySearcher,
+        canonicalPaths: canonicalPaths)
+    }.value
+
+    guard generation == searchIndexGeneration else { return }
+    searchIndex = index
+  }
+
+  /// データソースから検索インデックスを再構築する（まとめて更新中は何もしない）。
+  private func rebuildSearchIndex() {
+    guard !isBatchingDataSourceUpdates else { return }
+    searchIndexGeneration += 1
+    searchIndex = SearchIndex(
+      apps: apps, directories: directories, commands: commands, quickLinks: quickLinks,
+      systemSettingsPanes: systemSettingsPanes, systemControls: systemControls,
+      windowLayoutActions: windowLayoutActions, terminalTargets: terminalTargets,
+      transliterators: transliterators, aliases: itemAliases, favorites: favoritePaths,
+      hiddenPaths: hiddenPaths, directorySearcher: directorySearcher,
+      canonicalPaths: searchIndex.canonicalPaths)
+  }
+
+  /// 検索結果を反映し、特殊アクションと計算式評価を更新する。
+  private func applySearchResults(_ results: [SearchResult]) {
+    searchResults = results
 
     // 特殊アクション挿入
     insertSpecialActions()
+    if resultSettings.groupResults {
+      let leadingActions = commandTargetDirectory == nil && searchResults.count > results.count
+      regroupSearchResults(leadingActions: leadingActions)
+    }
 
     // 選択インデックスをリセット
     selectedIndex = 0
@@ -151,12 +471,14 @@ public final class LauncherViewModel {
 
   /// 選択を 1 つ上に移動する。先頭の場合は移動しない。
   public func moveSelectionUp() {
+    flushPendingSearch()
     guard selectedIndex > 0 else { return }
     selectedIndex -= 1
   }
 
   /// 選択を 1 つ下に移動する。末尾の場合は移動しない。
   public func moveSelectionDown() {
+    flushPendingSearch()
     guard !searchResults.isEmpty else { return }
     let maxIndex = searchResults.count - 1
     guard selectedIndex < maxIndex else { return }
@@ -170,20 +492,70 @@ public final class LauncherViewModel {
   /// コントローラー側で結果を受け取り、アプリ起動やディレクトリオープンを実行する。
   /// - Returns: 選択中の検索結果、または nil
   public func confirmSelection() -> SearchResult? {
+    flushPendingSearch()
     guard !searchResults.isEmpty, selectedIndex < searchResults.count else {
       return nil
     }
     return searchResults[selectedIndex]
   }
 
+  /// ディレクトリの結果を開くエディタの rawValue を返す。
+  ///
+  /// 記憶したエディタ → Xcode（設定で有効な場合）→ 登録ディレクトリに設定したエディタ →
+  /// デフォルトエディタの順に使う。
+  public func editorRawValue(for result: SearchResult) -> String {
+    let xcode =
+      opensXcodeProjectsInXcode && result.xcodeProject != nil ? EditorType.xcode.rawValue : nil
+    return directoryEditors[result.path] ?? xcode ?? result.editor ?? defaultEditorRawValue
+  }
+
+  // MARK: - 複数選択
+
+  /// 選択中の結果を複数選択に追加する（選択済みなら外す）。
+  ///
+  /// まとめて起動できるアプリ・ディレクトリ・コマンドのみ対象とする。
+  /// - Returns: 追加・解除した場合は `true`
+  @discardableResult
+  public func toggleMark() -> Bool {
+    guard let result = confirmSelection(),
+      [.app, .directory, .command].contains(result.kind)
+    else { return false }
+    if let index = markedResults.firstIndex(where: { $0.path == result.path }) {
+      markedResults.remove(at: index)
+    } else {
+      markedResults.append(result)
+    }
+    return true
+  }
+
+  /// 指定パスの結果が複数選択されているか
+  public func isMarked(_ result: SearchResult) -> Bool {
+    markedResults.contains { $0.path == result.path }
+  }
+
+  // MARK: - アイコン
+
+  /// 結果に表示するアイコンのパス（変換済みの 128px の PNG。未変換なら nil）
+  public func iconPath(for result: SearchResult) -> String? {
+    result.iconPath ?? convertedIconPaths[result.path]
+  }
+
+  /// アプリのアイコンが未変換なら変換を要求する（変換元がないアプリは汎用アイコンのまま）。
+  public func requestIcon(for result: SearchResult) {
+    guard result.kind == .app, iconPath(for: result) == nil,
+      !requestedIconPaths.contains(result.path),
+      apps.first(where: { $0.path == result.path })?.iconSourcePath != nil
+    else { return }
+    requestedIconPaths.insert(result.path)
+    onEnsureIcon?(result.path)
+  }
+
   // MARK: - クリップボード
 
   /// 計算結果をクリップボードにコピーする。計算結果がない場合は何もしない。
   public func copyCalculatorResult() {
     guard let result = calculatorResult else { return }
-    let pasteboard = NSPasteboard.general
-    pasteboard.clearContents()
-    pasteboard.setString(result, forType: .string)
+    ClipboardService().copy(result)
   }
 
   // MARK: - 特殊キー処理
@@ -198,11 +570,16 @@ public final class LauncherViewModel {
     _ key: SpecialKey,
     modifiers: NSEvent.ModifierFlags
   ) -> SpecialKeyAction? {
+    flushPendingSearch()
     switch key {
     case .escape:
       return .dismiss
 
     case .enter:
+      // 複数選択がある場合はまとめて起動
+      if !markedResults.isEmpty {
+        return .executeMarked
+      }
       // 計算結果がある場合はコピー
       if calculatorResult != nil {
         return .copyCalculator
@@ -218,6 +595,9 @@ public final class LauncherViewModel {
       if modifiers.contains(.command) {
         return .showTerminalPicker
       }
+      if modifiers.contains(.option) {
+        return .runCommandHere
+      }
       return .openInTerminal
 
     case .left:
@@ -233,13 +613,71 @@ public final class LauncherViewModel {
   /// 検索状態をすべてリセットする。フォーカス喪失時に呼び出す。
   public func clearSearch() {
     isClearingSearch = true
+    searchSession.cancel()
     searchQuery = ""
     searchResults = []
     selectedIndex = 0
     calculatorResult = nil
+    scopeOverride = nil
+    commandTargetDirectory = nil
+    markedResults = []
+    pendingTrash = nil
     isClearingSearch = false
   }
 
+  // MARK: - カテゴリの絞り込み
+
+  /// 検索語にアプリのカテゴリの絞り込み（`category:<カテゴリ名>`）が含まれているか
+  public func isFilteringCategory(_ categoryName: String) -> Bool {
+    searchQuery.split(whereSeparator: \.isWhitespace)
+      .contains { $0 == AppCategoryQuery.prefix + categoryName }
+  }
+
+  /// アプリのカテゴリで結果を絞り込む（絞り込み中なら解除する）。
+  ///
+  /// 検索プレフィックスを壊さないよう、絞り込み語は検索語の末尾に付け外しする。
+  /// - Parameter categoryName: カテゴリ名（`developer-tools` など）
+  public func toggleCategoryFilter(_ categoryName: String) {
+    let filter = AppCategoryQuery.prefix + categoryName
+    var words = searchQuery.split(whereSeparator: \.isWhitespace).map(String.init)
+    if words.contains(filter) {
+      words.removeAll { $0 == filter }
+    } else {
+      words.append(filter)
+    }
+    searchQuery = words.joined(separator: " ")
+    markedResults = []
+    updateSearch()
+  }
+
+  // MARK: - ここでコマンドを実行
+
+  /// ディレクトリで実行するコマンドを選ぶモードに入る。
+  ///
+  /// 検索対象をカスタムコマンドに絞り、入力したコマンドそのものも候補に加える。
+  /// 選んだコマンドは登録時の作業ディレクトリではなく `path` で実行する。
+  /// - Parameter path: コマンドを実行するディレクトリ
+  public func beginRunCommand(in path: String) {
+    searchQuery = ""
+    markedResults = []
+    commandTargetDirectory = path
+    scopeOverride = .commands
+    updateSearch()
+  }
+
+  /// コマンドを選ぶモードを終え、通常の検索に戻る。
+  ///
+  /// - Returns: モード中だった場合は `true`
+  @discardableResult
+  public func endRunCommand() -> Bool {
+    guard commandTargetDirectory != nil else { return false }
+    commandTargetDirectory = nil
+    scopeOverride = nil
+    searchQuery = ""
+    updateSearch()
+    return true
+  }
+
   // MARK: - アップデートバナー
 
   /// 新バージョンのアップデートバナーを表示する。
@@ -262,6 +700,43 @@ public final class LauncherViewModel {
     onUpdateBannerDismissed?(version)
   }
 
+  // MARK: - エラーバナー
+
+  /// 操作の失敗をエラーバナーに表示する（表示中のエラーは置き換える）。
+  ///
+  /// - Parameter error: 表示するエラー
+  public func showError(_ error: LauncherError) {
+    lastError = error
+  }
+
+  /// エラーバナーを非表示にする。
+  public func dismissError() {
+    lastError = nil
+  }
+
+  // MARK: - ゴミ箱
+
+  /// 結果をゴミ箱に入れる前の確認を表示する。
+  public func requestTrashConfirmation(for result: SearchResult) {
+    pendingTrash = result
+  }
+
+  /// ゴミ箱に入れる確認を取り消す。
+  public func cancelTrashConfirmation() {
+    pendingTrash = nil
+  }
+
+  /// ゴミ箱に入れた項目を、元に戻せることと合わせてバナーに表示する。
+  public func showTrashUndo(_ item: TrashedItem) {
+    trashUndo = item
+  }
+
+  /// 元に戻すバナーを非表示にする（`item` を指定した場合は表示中の項目と同じときだけ）。
+  public func dismissTrashUndo(_ item: TrashedItem? = nil) {
+    guard item == nil || trashUndo == item else { return }
+    trashUndo = nil
+  }
+
   // MARK: - 特殊アクション
 
   /// 検索クエリに応じた特殊アクション（Web検索、カラーピッカー、Emoji）を結果先頭に挿入する。
@@ -269,8 +744,39 @@ public final class LauncherViewModel {
     let normalized = SearchQueryNormalizer.normalize(searchQuery)
     guard !normalized.isEmpty else { return }
 
+    // ここでコマンドを実行: 登録したコマンドに加えて、入力したコマンドそのものも実行できる
+    if commandTargetDirectory != nil {
+      let command = searchQuery.trimmingCharacters(in: .whitespaces)
+      if !searchResults.contains(where: { $0.command == command }) {
+        searchResults.append(SearchResult(shellCommand: command, score: 0))
+      }
+      return
+    }
+
+    // URL: 貼り付けた URL やカスタムスキームをそのまま開く
+    if let url = LaunchService.validatedURL(from: searchQuery) {
+      searchResults.insert(
+        SearchResult(name: "URL を開く: \(url.absoluteString)", kind: .webSearch, score: -10,
+          path: url.absoluteString),
+        at: 0
+      )
+      return
+    }
+
+    // クイックリンク: <名前> <検索語>
+    if let match = QuickLink.match(searchQuery, in: quickLinks),
+      let url = match.link.resolvedURL(query: match.argument)
+    {
+      searchResults.insert(
+        SearchResult(
+          quickLink: match.link, url: url, score: -10,
+          name: "\(match.link.name) で「\(match.argument)」を開く"),
+        at: 0
+      )
+    }
+
     // Web検索: g <keyword>
-    if normalized.hasPrefix("g ") {
+    else if normalized.hasPrefix("g ") {
       let keyword = String(normalized.dropFirst(2)).trimmingCharacters(in: .whitespaces)
       if !keyword.isEmpty, let url = Self.webSearchURL(host: "www.google.com", keyword: keyword) {
         searchResults.insert(
@@ -308,6 +814,21 @@ public final class LauncherViewModel {
     }
   }
 
+  /// 特殊アクションを挿入した結果を、見出しの順に並べ直す。
+  ///
+  /// 入力から作った操作（URL・Web 検索など）を先頭に挿入した場合は、アクションの見出しを
+  /// 先頭に移して 1 つの見出しにまとめる（Enter で入力した URL などをそのまま開けるようにする）。
+  /// それ以外は IPC の search と同じ `SearchResultSection.sections` の並びになる。
+  /// - Parameter leadingActions: 特殊アクションを先頭に挿入したか
+  private func regroupSearchResults(leadingActions: Bool) {
+    var order = ResultSettings.completedGroupOrder(resultSettings.groupOrder)
+    if leadingActions {
+      order = [.actions] + order.filter { $0 != .actions }
+    }
+    searchResults = SearchResultSection.sections(of: searchResults, order: order)
+      .flatMap(\.results)
+  }
+
   /// URL クエリ値として安全な検索 URL を組み立てる。
   private static func webSearchURL(host: String, keyword: String) -> String? {
     var allowed = CharacterSet.urlQueryAllowed
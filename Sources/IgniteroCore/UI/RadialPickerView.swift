@@ -108,12 +108,16 @@ public enum RadialPickerItemFactory {
     case .vscode: "v"
     case .antigravity: "a"
     case .zed: "z"
+    case .intellij: "i"
+    case .pycharm: "p"
+    case .webstorm: "s"
+    case .xcode: "x"
     }
   }
 
   /// EditorInfo 配列から RadialPickerItem 配列を生成する。
   ///
-  /// 各エディタにはショートカットキーが付与される（w/c/v/a/z）。
+  /// 各エディタにはショートカットキーが付与される（w/c/v/a/z/i/p/s/x）。
   ///
   /// - Parameter editors: エディタ情報の配列
   /// - Returns: ラジアルピッカーアイテムの配列
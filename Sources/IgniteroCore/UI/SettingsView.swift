@@ -1,3 +1,4 @@
+import AppKit
 import KeyboardShortcuts
 import SwiftUI
 
@@ -5,11 +6,12 @@ import SwiftUI
 
 /// 設定画面のメインビュー。
 ///
-/// macOS 標準の `TabView` を使用し、4つのタブで設定を提供する:
+/// macOS 標準の `TabView` を使用し、5つのタブで設定を提供する:
 /// - 全般: バージョン表示、デフォルトターミナル選択、キャッシュ更新設定
 /// - ディレクトリ: 登録ディレクトリの追加・編集・削除
 /// - コマンド: カスタムコマンドの追加・編集・削除
 /// - 除外アプリ: スキャン済みアプリの除外切替
+/// - 診断: 権限・ホットキー・キャッシュ・設定ファイル・スキャン状況の自己診断
 public struct SettingsView: View {
 
   @Bindable var viewModel: SettingsViewModel
@@ -32,6 +34,9 @@ public struct SettingsView: View {
       Tab("除外アプリ", systemImage: "xmark.app", value: SettingsTab.excludedApps) {
         ExcludedAppsSettingsTab(viewModel: viewModel)
       }
+      Tab("診断", systemImage: "stethoscope", value: SettingsTab.diagnostics) {
+        DiagnosticsSettingsTab(viewModel: viewModel)
+      }
     }
     .frame(minWidth: 520, minHeight: 400)
   }
@@ -44,6 +49,16 @@ struct GeneralSettingsTab: View {
 
   @Bindable var viewModel: SettingsViewModel
   @State private var errorMessage: String?
+  @State private var newHotkeyShortcut = ""
+  @State private var newHotkeyMode: LauncherMode = .directories
+  @State private var newAlias = ""
+  @State private var newAliasPath = ""
+  @State private var newQuickLinkName = ""
+  @State private var newQuickLinkURL = ""
+  @State private var newQuickLinkIcon = ""
+  @State private var newFileOpenerExtension = ""
+  @State private var newFileOpenerApplication = ""
+  @State private var newProfileName = ""
 
   var body: some View {
     Form {
@@ -54,8 +69,168 @@ struct GeneralSettingsTab: View {
         }
       }
 
+      // 許可されるまで表示し、許可すると自動で消える
+      if !viewModel.isAccessibilityTrusted {
+        Section("アクセシビリティ権限") {
+          Label(
+            "直前のアプリへの貼り付け・ウィンドウ操作には許可が必要です",
+            systemImage: "exclamationmark.triangle.fill"
+          )
+          .foregroundStyle(.orange)
+          HStack {
+            Button("許可する") {
+              viewModel.requestAccessibilityPermission()
+            }
+            Button("システム設定を開く") {
+              viewModel.openAccessibilitySettings()
+            }
+          }
+          Text("システム設定の「プライバシーとセキュリティ」→「アクセシビリティ」で Ignitero Launcher をオンにしてください")
+            .font(.caption)
+            .foregroundStyle(.secondary)
+        }
+      }
+
       Section("起動") {
         Toggle("ログイン時に開く", isOn: launchAtLoginBinding)
+        Toggle("Dock にアイコンを表示する", isOn: windowBehaviorBinding(\.showDockIcon))
+        Picker("表示位置", selection: windowPlacementBinding) {
+          ForEach(WindowPlacement.allCases, id: \.self) { placement in
+            Text(placement.displayName).tag(placement)
+          }
+        }
+        .pickerStyle(.menu)
+        Toggle("閉じたときに直前のアプリへ戻る", isOn: restoreFocusOnHideBinding)
+        Toggle("他のアプリに切り替えたら閉じる", isOn: windowBehaviorBinding(\.hideOnFocusLoss))
+        if viewModel.settings.windowBehavior.hideOnFocusLoss {
+          Stepper(
+            "閉じるまでの猶予: \(viewModel.settings.windowBehavior.focusLossGracePeriodMs) ms",
+            value: windowBehaviorBinding(\.focusLossGracePeriodMs),
+            in: WindowBehaviorSettings.focusLossGracePeriodRange,
+            step: 250
+          )
+        }
+        Toggle("項目を起動したら閉じる", isOn: windowBehaviorBinding(\.hideAfterLaunch))
+        Picker("入力ソース", selection: windowBehaviorBinding(\.imeBehavior)) {
+          ForEach(IMEBehavior.allCases, id: \.self) { behavior in
+            Text(behavior.displayName).tag(behavior)
+          }
+        }
+        .pickerStyle(.menu)
+      }
+
+      Section("プロファイル") {
+        ForEach(viewModel.settings.profiles) { profile in
+          HStack {
+            Image(systemName: "checkmark")
+              .opacity(profile.name == viewModel.settings.activeProfile ? 1 : 0)
+              .frame(width: 16)
+            Text(profile.name)
+            Text("ディレクトリ \(profile.registeredDirectories.count) 件")
+              .font(.caption)
+              .foregroundStyle(.secondary)
+            Spacer()
+            Button("切り替え") {
+              switchProfile(to: profile.name)
+            }
+            .disabled(profile.name == viewModel.settings.activeProfile)
+            Button(role: .destructive) {
+              do {
+                try viewModel.deleteProfile(named: profile.name)
+                errorMessage = nil
+              } catch {
+                errorMessage = "プロファイルの削除に失敗しました"
+              }
+            } label: {
+              Image(systemName: "trash")
+            }
+            .buttonStyle(.borderless)
+            .disabled(profile.name == viewModel.settings.activeProfile)
+          }
+        }
+
+        HStack {
+          TextField("例: work", text: $newProfileName)
+          Button("現在の設定から作成") {
+            addProfile()
+          }
+          .disabled(newProfileName.trimmingCharacters(in: .whitespaces).isEmpty)
+        }
+        Text("登録ディレクトリ・カスタムコマンド・追加ショートカットをプロファイルごとに切り替えます。キャッシュもプロファイルごとに保存します")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("アップデート") {
+        Picker("チャンネル", selection: updateSettingBinding(\.releaseChannel)) {
+          ForEach(ReleaseChannel.allCases, id: \.self) { channel in
+            Text(channel.displayName).tag(channel)
+          }
+        }
+        .pickerStyle(.menu)
+        Picker("新しいバージョン", selection: updateSettingBinding(\.installMode)) {
+          ForEach(UpdateInstallMode.allCases, id: \.self) { mode in
+            Text(mode.displayName).tag(mode)
+          }
+        }
+        .pickerStyle(.menu)
+        if let lastCheckError = viewModel.settings.updateCache?.lastCheckError {
+          Text("前回の確認に失敗しました: \(lastCheckError)")
+            .font(.caption)
+            .foregroundStyle(.secondary)
+        }
+      }
+
+      Section("外観") {
+        Picker("言語", selection: languageBinding) {
+          ForEach(AppLanguage.allCases, id: \.self) { language in
+            Text(language.displayName).tag(language)
+          }
+        }
+        Text("メニューバーのメニュー・設定ウィンドウのタイトル・エラー表示の言語です")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+        Picker("モード", selection: appearanceBinding(\.mode)) {
+          ForEach(AppearanceMode.allCases, id: \.self) { mode in
+            Text(mode.displayName).tag(mode)
+          }
+        }
+        Picker("アクセントカラー", selection: appearanceBinding(\.accentColor)) {
+          ForEach(AccentColor.allCases, id: \.self) { color in
+            Text(color.displayName).tag(color)
+          }
+        }
+        Picker("背景", selection: appearanceBinding(\.material)) {
+          ForEach(LauncherMaterial.allCases, id: \.self) { material in
+            Text(material.displayName).tag(material)
+          }
+        }
+        Picker("行の高さ", selection: appearanceBinding(\.density)) {
+          ForEach(ResultDensity.allCases, id: \.self) { density in
+            Text(density.displayName).tag(density)
+          }
+        }
+        Stepper(
+          "ウィンドウ幅: \(viewModel.settings.appearance.clampedWindowWidth) px",
+          value: appearanceBinding(\.windowWidth),
+          in: AppearanceSettings.windowWidthRange,
+          step: 20
+        )
+        Stepper(
+          "角丸: \(viewModel.settings.appearance.clampedCornerRadius) px",
+          value: appearanceBinding(\.cornerRadius),
+          in: AppearanceSettings.cornerRadiusRange,
+          step: 2
+        )
+        Button("既定に戻す") {
+          do {
+            try viewModel.setAppearance(.default)
+            errorMessage = nil
+          } catch {
+            errorMessage = "外観設定の保存に失敗しました"
+          }
+        }
+        .disabled(viewModel.settings.appearance == .default)
       }
 
       Section("ショートカット") {
@@ -65,6 +240,53 @@ struct GeneralSettingsTab: View {
         }
       }
 
+      Section("追加ショートカット") {
+        ForEach(Array(viewModel.settings.hotkeyBindings.enumerated()), id: \.element.id) {
+          index, binding in
+          HotkeyBindingRow(
+            binding: binding,
+            onUpdate: { updated in
+              do {
+                try viewModel.updateHotkeyBinding(at: index, updated)
+                errorMessage = nil
+              } catch {
+                errorMessage = "ショートカットの保存に失敗しました"
+              }
+            },
+            onDelete: {
+              do {
+                try viewModel.removeHotkeyBinding(at: index)
+                errorMessage = nil
+              } catch {
+                errorMessage = "ショートカットの削除に失敗しました"
+              }
+            }
+          )
+        }
+
+        HStack {
+          TextField("例: cmd+shift+p", text: $newHotkeyShortcut)
+            .font(.system(.body, design: .monospaced))
+          Picker("", selection: $newHotkeyMode) {
+            ForEach(LauncherMode.allCases, id: \.self) { mode in
+              Text(mode.displayName).tag(mode)
+            }
+          }
+          .labelsHidden()
+          .frame(width: 150)
+          Button("追加") {
+            addHotkeyBinding()
+          }
+          .disabled(!HotkeyBinding.isValidShortcut(newHotkeyShortcut))
+        }
+        Text(
+          "修飾キー（cmd / shift / option / ctrl）とキーを + で連結して入力します。"
+            + "keycode:102 でキーコードを、double:cmd で修飾キーの 2 回押しを指定できます"
+        )
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
       Section("デフォルトエディタ") {
         if viewModel.installedEditors.isEmpty {
           Text("インストール済みエディタが見つかりません")
@@ -87,6 +309,33 @@ struct GeneralSettingsTab: View {
         }
       }
 
+      Section("ディレクトリごとのエディタ") {
+        Toggle("エディタピッカーで選んだエディタを記憶する", isOn: remembersDirectoryEditorBinding)
+        Toggle("Xcode のプロジェクトがあるディレクトリは Xcode で開く", isOn: opensXcodeProjectsBinding)
+        ForEach(viewModel.directoryEditors.keys.sorted(), id: \.self) { path in
+          HStack {
+            Text((path as NSString).abbreviatingWithTildeInPath)
+              .lineLimit(1)
+              .truncationMode(.middle)
+            Spacer()
+            Picker("", selection: directoryEditorBinding(for: path)) {
+              ForEach(viewModel.installedEditors) { editor in
+                Text(editor.name).tag(editor.id)
+              }
+            }
+            .pickerStyle(.menu)
+            .labelsHidden()
+            .frame(width: 160)
+            Button("削除") {
+              setDirectoryEditor(nil, forPath: path)
+            }
+          }
+        }
+        Text("既定以外のエディタで開いたディレクトリは、次からそのエディタで開きます。削除すると既定のエディタに戻ります。")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
       Section("デフォルトターミナル") {
         if viewModel.installedTerminals.isEmpty {
           Text("インストール済みターミナルが見つかりません")
@@ -126,7 +375,337 @@ struct GeneralSettingsTab: View {
             value: cacheIntervalBinding,
             in: 1...24
           )
+          Toggle(
+            "バッテリー残量が少ないときは自動更新しない",
+            isOn: cacheSettingBinding(\.skipOnLowBattery))
+          if viewModel.settings.cacheUpdate.skipOnLowBattery {
+            Stepper(
+              "残量: \(viewModel.settings.cacheUpdate.lowBatteryThresholdPercent)% 未満",
+              value: cacheSettingBinding(\.lowBatteryThresholdPercent),
+              in: 5...95,
+              step: 5
+            )
+          }
+          Toggle(
+            "CPU の負荷が高いときは自動更新しない",
+            isOn: cacheSettingBinding(\.skipUnderHeavyLoad))
+          Text("見送った場合は 15 分ごとに状態を確かめ、条件を外れたら更新します。手動の更新はいつでも実行できます")
+            .font(.caption)
+            .foregroundStyle(.secondary)
+        }
+
+        Stepper(
+          "ディレクトリごとのスキャン制限時間: \(viewModel.settings.cacheUpdate.scanTimeoutSeconds) 秒",
+          value: cacheSettingBinding(\.scanTimeoutSeconds),
+          in: 5...300,
+          step: 5
+        )
+        Stepper(
+          "同時にスキャンするディレクトリ数: \(viewModel.settings.cacheUpdate.maxConcurrentScans)",
+          value: cacheSettingBinding(\.maxConcurrentScans),
+          in: 1...8
+        )
+        Text("ネットワークドライブなど応答しないディレクトリは制限時間で打ち切り、前回の結果を保持します")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+        Toggle(
+          "Launch Services に登録されたアプリも検索対象にする",
+          isOn: cacheSettingBinding(\.discoverRegisteredApps))
+        Text("~/Library や Setapp など /Applications 以外にあるアプリも次回の更新から見つけます")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+        Toggle(
+          "SSH ホストと tmux セッションを検索対象にする",
+          isOn: cacheSettingBinding(\.indexTerminalTargets))
+        Text("~/.ssh/config の Host と ~/.tmux.conf のセッションを読み込み、既定のターミナルで接続します")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("アイコンキャッシュ") {
+        if let usage = viewModel.iconCacheUsage {
+          LabeledContent(
+            "使用量",
+            value:
+              "\(usage.fileCount) ファイル / \(ByteCountFormatter.string(fromByteCount: usage.totalBytes, countStyle: .file))"
+          )
+        }
+        Toggle("上限サイズを設定", isOn: iconCacheLimitEnabledBinding)
+        if let megabytes = viewModel.settings.cacheUpdate.iconCacheMaxMegabytes {
+          Stepper(
+            "上限: \(megabytes) MB",
+            value: iconCacheMaxMegabytesBinding,
+            in: 50...2000,
+            step: 50
+          )
+        }
+        HStack {
+          Button("アイコンキャッシュを削除") {
+            Task { await viewModel.clearIconCache() }
+          }
+          .disabled(viewModel.isClearingIconCache)
+          if viewModel.isClearingIconCache {
+            ProgressView()
+              .controlSize(.small)
+          }
+        }
+        Text("キャッシュ更新時にアンインストールされたアプリのアイコンを削除し、上限を超えた分は最近使われていないものから削除します。削除後はスキャンし直してアイコンを作り直します。")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("検索結果") {
+        Stepper(
+          "最大件数: \(viewModel.settings.results.clampedMaxResults)",
+          value: resultSettingBinding(\.maxResults),
+          in: ResultSettings.maxResultsRange,
+          step: 5
+        )
+        categoryLimitPicker("アプリ", keyPath: \.maxApps)
+        categoryLimitPicker("ディレクトリ", keyPath: \.maxDirectories)
+        categoryLimitPicker("コマンド", keyPath: \.maxCommands)
+        Picker("同じ一致度の並び順", selection: resultSettingBinding(\.tieBreak)) {
+          ForEach(ResultTieBreak.allCases, id: \.self) { tieBreak in
+            Text(tieBreak.displayName).tag(tieBreak)
+          }
+        }
+        Text("種別ごとの上限を設定すると、一致度の高い結果が 1 種類で埋まらないようにできます。")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+        Toggle("見出しごとにまとめて表示する", isOn: resultSettingBinding(\.groupResults))
+        if viewModel.settings.results.groupResults {
+          let groups = viewModel.settings.results.orderedGroups
+          ForEach(Array(groups.enumerated()), id: \.element) { position, group in
+            HStack {
+              Text(group.title)
+              Spacer()
+              Button {
+                moveResultGroup(group, by: -1)
+              } label: {
+                Image(systemName: "chevron.up")
+              }
+              .buttonStyle(.borderless)
+              .disabled(position == 0)
+              Button {
+                moveResultGroup(group, by: 1)
+              } label: {
+                Image(systemName: "chevron.down")
+              }
+              .buttonStyle(.borderless)
+              .disabled(position == groups.count - 1)
+            }
+          }
+        }
+        Toggle("ゴミ箱に入れる前に確認する", isOn: confirmsMoveToTrashBinding)
+        Text("結果の右クリックメニュー（検索語が空のときは ⌘⌫）でディレクトリをゴミ箱に入れ、⌘Z で元に戻せます。⌘⌫ では常に確認を表示します。")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("検索プレフィックス") {
+        Toggle("プレフィックスで検索対象を絞り込む", isOn: searchPrefixesEnabledBinding)
+
+        if viewModel.settings.searchPrefixes.enabled {
+          TextField("アプリ", text: searchPrefixBinding(\.app))
+          TextField("ディレクトリ", text: searchPrefixBinding(\.directory))
+          TextField("コマンド", text: searchPrefixBinding(\.command))
+          TextField("パス", text: searchPrefixBinding(\.path))
+          TextField("絵文字・記号", text: searchPrefixBinding(\.symbol))
+          TextField("プロセス終了", text: searchPrefixBinding(\.process))
+          Toggle("Docker のコンテナを検索する", isOn: dockerSearchEnabledBinding)
+          if viewModel.settings.searchPrefixes.dockerEnabled {
+            TextField("Docker", text: searchPrefixBinding(\.docker))
+          }
+          Text("英数字のプレフィックスは後ろに空白を入れて使用します（例: d project）。空欄で無効になります。Docker の検索はプレフィックスを使ったときに docker ps を実行します。")
+            .font(.caption)
+            .foregroundStyle(.secondary)
+        }
+      }
+
+      Section("ローマ字検索") {
+        Toggle("中国語・韓国語の名前をローマ字で検索", isOn: transliterationBinding)
+        Text("漢字はピンイン（例: weixin / wx）、ハングルはローマ字表記でも一致するようになります。")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("全文検索") {
+        Toggle("ディレクトリをキャッシュ DB から直接検索する", isOn: fullTextSearchBinding)
+        Text("数万件のディレクトリを登録している場合に、メモリへ読み込まず SQLite の全文検索で前方一致検索します。あいまい一致はしません。")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("ログ") {
+        Picker("記録するレベル", selection: logLevelBinding) {
+          ForEach(LogLevel.allCases, id: \.self) { level in
+            Text(level.displayName).tag(level)
+          }
+        }
+        HStack {
+          Button("直近のログをコピー") {
+            ClipboardService().copy(viewModel.recentLogs())
+          }
+          Button("Finder で表示") {
+            NSWorkspace.shared.activateFileViewerSelecting([viewModel.logFile.fileURL])
+          }
+        }
+        Text("不具合を報告する際は、コピーしたログを添付してください。")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("開発者向け") {
+        Toggle("検索スコアの内訳を確認できるようにする", isOn: searchDebugBinding)
+        Text("`ignitero search_debug <検索語>` で、候補ごとの一致度・補正・最終スコアを表示します。")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("エイリアス") {
+        ForEach(viewModel.settings.itemAliases) { alias in
+          HStack {
+            Text(alias.alias)
+              .font(.system(.body, design: .monospaced))
+            Image(systemName: "arrow.right")
+              .foregroundStyle(.secondary)
+            Text(alias.path)
+              .lineLimit(1)
+              .truncationMode(.middle)
+              .foregroundStyle(.secondary)
+            Spacer()
+            Button(role: .destructive) {
+              do {
+                try viewModel.removeItemAlias(alias.alias)
+                errorMessage = nil
+              } catch {
+                errorMessage = "エイリアスの削除に失敗しました"
+              }
+            } label: {
+              Image(systemName: "trash")
+            }
+            .buttonStyle(.borderless)
+          }
         }
+
+        HStack {
+          TextField("例: vsc", text: $newAlias)
+            .font(.system(.body, design: .monospaced))
+            .frame(width: 100)
+          TextField("例: /Applications/Visual Studio Code.app", text: $newAliasPath)
+          Button("追加") {
+            addItemAlias()
+          }
+          .disabled(!ItemAlias.isValid(alias: newAlias) || newAliasPath.isEmpty)
+        }
+        Text("アプリ・ディレクトリのパスに短い別名を付けると、別名の入力で最優先に表示します（~ も使用可）")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("クイックリンク") {
+        ForEach(viewModel.settings.quickLinks) { link in
+          HStack {
+            Image(systemName: link.icon ?? "globe")
+              .frame(width: 16)
+            Text(link.name)
+              .font(.system(.body, design: .monospaced))
+            Text(link.url)
+              .lineLimit(1)
+              .truncationMode(.middle)
+              .foregroundStyle(.secondary)
+            Spacer()
+            Button(role: .destructive) {
+              do {
+                try viewModel.removeQuickLink(id: link.id)
+                errorMessage = nil
+              } catch {
+                errorMessage = "クイックリンクの削除に失敗しました"
+              }
+            } label: {
+              Image(systemName: "trash")
+            }
+            .buttonStyle(.borderless)
+          }
+        }
+
+        HStack {
+          TextField("例: mdn", text: $newQuickLinkName)
+            .font(.system(.body, design: .monospaced))
+            .frame(width: 80)
+          TextField("例: https://developer.mozilla.org/search?q={query}", text: $newQuickLinkURL)
+          TextField("アイコン", text: $newQuickLinkIcon)
+            .frame(width: 90)
+          Button("追加") {
+            addQuickLink()
+          }
+          .disabled(!QuickLink(name: newQuickLinkName, url: newQuickLinkURL).isValid)
+        }
+        Text("「名前 検索語」と入力すると {query} を検索語に置き換えた URL を開きます。アイコンは SF Symbols 名で指定します")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("ファイルを開くアプリ") {
+        ForEach(viewModel.fileOpeners) { opener in
+          HStack {
+            Text(".\(opener.normalizedExtension)")
+              .font(.system(.body, design: .monospaced))
+            Image(systemName: "arrow.right")
+              .foregroundStyle(.secondary)
+            Text(opener.application)
+              .lineLimit(1)
+              .truncationMode(.middle)
+            Spacer()
+            Button(role: .destructive) {
+              do {
+                try viewModel.removeFileOpener(id: opener.id)
+                errorMessage = nil
+              } catch {
+                errorMessage = "ファイルを開くアプリの削除に失敗しました"
+              }
+            } label: {
+              Image(systemName: "trash")
+            }
+            .buttonStyle(.borderless)
+          }
+        }
+
+        HStack {
+          TextField("例: md", text: $newFileOpenerExtension)
+            .font(.system(.body, design: .monospaced))
+            .frame(width: 80)
+          TextField("例: Typora", text: $newFileOpenerApplication)
+          Button("追加") {
+            setFileOpener()
+          }
+          .disabled(
+            !FileOpener(
+              fileExtension: newFileOpenerExtension, application: newFileOpenerApplication
+            ).isValid)
+        }
+        Text("ignitero open や AppleScript の open item でファイルを開くときに拡張子ごとのアプリを使います（ランチャーの検索結果には使いません）。アプリは名前・.app のパス・バンドル ID で指定し、割り当てのない拡張子や見つからないアプリは macOS の既定のアプリで開きます")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+      }
+
+      Section("最近のプロジェクト") {
+        Stepper(
+          "空の検索で表示する件数: \(viewModel.settings.recentProjectsLimit)",
+          value: recentProjectsLimitBinding,
+          in: 0...20
+        )
+        Text("ランチャーを開いた直後に、最近エディタで開いたディレクトリを表示します。0 で無効になります。")
+          .font(.caption)
+          .foregroundStyle(.secondary)
+        ForEach(recentWorkspaceEditorCandidates, id: \.self) { editor in
+          Toggle(
+            "\(editor.displayName) で最近開いたフォルダを取り込む",
+            isOn: recentWorkspaceBinding(for: editor))
+        }
+        Text("登録ディレクトリ外のフォルダも、キャッシュの更新時にそのエディタで開く項目として追加します")
+          .font(.caption)
+          .foregroundStyle(.secondary)
       }
 
       if let errorMessage {
@@ -136,13 +715,294 @@ struct GeneralSettingsTab: View {
             .font(.caption)
         }
       }
-    }
-    .formStyle(.grouped)
-    .padding()
+    }
+    .formStyle(.grouped)
+    .padding()
+  }
+
+  private func addItemAlias() {
+    do {
+      try viewModel.setItemAlias(newAlias, for: newAliasPath)
+      newAlias = ""
+      newAliasPath = ""
+      errorMessage = nil
+    } catch {
+      errorMessage = "エイリアスの保存に失敗しました"
+    }
+  }
+
+  private func addProfile() {
+    do {
+      try viewModel.createProfile(named: newProfileName)
+      newProfileName = ""
+      errorMessage = nil
+    } catch SettingsProfileError.duplicateName {
+      errorMessage = "同じ名前のプロファイルがあります"
+    } catch {
+      errorMessage = "プロファイルの作成に失敗しました"
+    }
+  }
+
+  private func switchProfile(to name: String) {
+    Task {
+      do {
+        try await viewModel.switchProfile(to: name)
+        errorMessage = nil
+      } catch SettingsProfileError.cacheBusy {
+        errorMessage = "キャッシュの更新中はプロファイルを切り替えられません"
+      } catch {
+        errorMessage = "プロファイルの切り替えに失敗しました"
+      }
+    }
+  }
+
+  private func addQuickLink() {
+    do {
+      try viewModel.addQuickLink(
+        name: newQuickLinkName, url: newQuickLinkURL, icon: newQuickLinkIcon)
+      newQuickLinkName = ""
+      newQuickLinkURL = ""
+      newQuickLinkIcon = ""
+      errorMessage = nil
+    } catch {
+      errorMessage = "クイックリンクの保存に失敗しました"
+    }
+  }
+
+  private func setFileOpener() {
+    do {
+      try viewModel.setFileOpener(
+        fileExtension: newFileOpenerExtension, application: newFileOpenerApplication)
+      newFileOpenerExtension = ""
+      newFileOpenerApplication = ""
+      errorMessage = nil
+    } catch {
+      errorMessage = "ファイルを開くアプリの保存に失敗しました"
+    }
+  }
+
+  private func addHotkeyBinding() {
+    do {
+      try viewModel.addHotkeyBinding(shortcut: newHotkeyShortcut, mode: newHotkeyMode)
+      newHotkeyShortcut = ""
+      errorMessage = nil
+    } catch {
+      errorMessage = "ショートカットの追加に失敗しました"
+    }
+  }
+
+  // MARK: - Bindings
+
+  private var remembersDirectoryEditorBinding: Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.remembersDirectoryEditor },
+      set: { newValue in
+        do {
+          try viewModel.setRemembersDirectoryEditor(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private var confirmsMoveToTrashBinding: Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.confirmsMoveToTrash },
+      set: { newValue in
+        do {
+          try viewModel.setConfirmsMoveToTrash(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private var opensXcodeProjectsBinding: Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.opensXcodeProjectsInXcode },
+      set: { newValue in
+        do {
+          try viewModel.setOpensXcodeProjectsInXcode(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private func directoryEditorBinding(for path: String) -> Binding<EditorType> {
+    Binding(
+      get: { viewModel.directoryEditors[path] ?? viewModel.settings.defaultEditor },
+      set: { setDirectoryEditor($0, forPath: path) }
+    )
+  }
+
+  private func setDirectoryEditor(_ editor: EditorType?, forPath path: String) {
+    do {
+      try viewModel.setDirectoryEditor(editor, forPath: path)
+      errorMessage = nil
+    } catch {
+      errorMessage = "エディタ設定の保存に失敗しました"
+    }
+  }
+
+  private var transliterationBinding: Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.transliterationEnabled },
+      set: { newValue in
+        do {
+          try viewModel.setTransliterationEnabled(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private var languageBinding: Binding<AppLanguage> {
+    Binding(
+      get: { viewModel.settings.language },
+      set: { newValue in
+        do {
+          try viewModel.setLanguage(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private var logLevelBinding: Binding<LogLevel> {
+    Binding(
+      get: { viewModel.settings.logLevel },
+      set: { newValue in
+        do {
+          try viewModel.setLogLevel(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private var searchDebugBinding: Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.searchDebugEnabled },
+      set: { newValue in
+        do {
+          try viewModel.setSearchDebugEnabled(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private var fullTextSearchBinding: Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.fullTextSearchEnabled },
+      set: { newValue in
+        do {
+          try viewModel.setFullTextSearchEnabled(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private func appearanceBinding<Value>(
+    _ keyPath: WritableKeyPath<AppearanceSettings, Value>
+  ) -> Binding<Value> {
+    Binding(
+      get: { viewModel.settings.appearance[keyPath: keyPath] },
+      set: { newValue in
+        var appearance = viewModel.settings.appearance
+        appearance[keyPath: keyPath] = newValue
+        do {
+          try viewModel.setAppearance(appearance)
+          errorMessage = nil
+        } catch {
+          errorMessage = "外観設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private var windowPlacementBinding: Binding<WindowPlacement> {
+    Binding(
+      get: { viewModel.settings.windowPlacement },
+      set: { newValue in
+        do {
+          try viewModel.setWindowPlacement(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private func updateSettingBinding<Value>(
+    _ keyPath: WritableKeyPath<UpdateSettings, Value>
+  ) -> Binding<Value> {
+    Binding(
+      get: { viewModel.settings.update[keyPath: keyPath] },
+      set: { newValue in
+        var update = viewModel.settings.update
+        update[keyPath: keyPath] = newValue
+        do {
+          try viewModel.setUpdateSettings(update)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private func windowBehaviorBinding<Value>(
+    _ keyPath: WritableKeyPath<WindowBehaviorSettings, Value>
+  ) -> Binding<Value> {
+    Binding(
+      get: { viewModel.settings.windowBehavior[keyPath: keyPath] },
+      set: { newValue in
+        var behavior = viewModel.settings.windowBehavior
+        behavior[keyPath: keyPath] = newValue
+        do {
+          try viewModel.setWindowBehavior(behavior)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  private var restoreFocusOnHideBinding: Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.restoreFocusOnHide },
+      set: { newValue in
+        do {
+          try viewModel.setRestoreFocusOnHide(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "設定の保存に失敗しました"
+        }
+      }
+    )
   }
 
-  // MARK: - Bindings
-
   private var launchAtLoginBinding: Binding<Bool> {
     Binding(
       get: { viewModel.launchAtLogin },
@@ -189,12 +1049,172 @@ struct GeneralSettingsTab: View {
     )
   }
 
+  private func resultSettingBinding<Value>(
+    _ keyPath: WritableKeyPath<ResultSettings, Value>
+  ) -> Binding<Value> {
+    Binding(
+      get: { viewModel.settings.results[keyPath: keyPath] },
+      set: { newValue in
+        var results = viewModel.settings.results
+        results[keyPath: keyPath] = newValue
+        do {
+          try viewModel.setResultSettings(results)
+          errorMessage = nil
+        } catch {
+          errorMessage = "検索結果の設定の保存に失敗しました"
+        }
+      }
+    )
+  }
+
+  /// 見出しの並び順で区分を 1 つ上または下へ動かす。
+  private func moveResultGroup(_ group: SearchResultGroup, by offset: Int) {
+    var order = viewModel.settings.results.orderedGroups
+    guard let index = order.firstIndex(of: group), order.indices.contains(index + offset)
+    else { return }
+    order.swapAt(index, index + offset)
+    resultSettingBinding(\.groupOrder).wrappedValue = order
+  }
+
+  /// 種別ごとの最大件数の選択（設定ファイルで任意の値を指定した場合はその値も選択肢に含める）
+  private func categoryLimitPicker(
+    _ title: String, keyPath: WritableKeyPath<ResultSettings, Int?>
+  ) -> some View {
+    let current = viewModel.settings.results[keyPath: keyPath]
+    let options = Array(Set([3, 5, 10] + (current.map { [$0] } ?? []))).sorted()
+    return Picker("\(title)の上限", selection: resultSettingBinding(keyPath)) {
+      Text("制限なし").tag(Int?.none)
+      ForEach(options, id: \.self) { limit in
+        Text("\(limit) 件").tag(Int?.some(limit))
+      }
+    }
+  }
+
+  private var searchPrefixesEnabledBinding: Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.searchPrefixes.enabled },
+      set: { newValue in
+        var prefixes = viewModel.settings.searchPrefixes
+        prefixes.enabled = newValue
+        saveSearchPrefixes(prefixes)
+      }
+    )
+  }
+
+  private var dockerSearchEnabledBinding: Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.searchPrefixes.dockerEnabled },
+      set: { newValue in
+        var prefixes = viewModel.settings.searchPrefixes
+        prefixes.dockerEnabled = newValue
+        saveSearchPrefixes(prefixes)
+      }
+    )
+  }
+
+  private func searchPrefixBinding(
+    _ keyPath: WritableKeyPath<SearchPrefixSettings, String>
+  ) -> Binding<String> {
+    Binding(
+      get: { viewModel.settings.searchPrefixes[keyPath: keyPath] },
+      set: { newValue in
+        var prefixes = viewModel.settings.searchPrefixes
+        prefixes[keyPath: keyPath] = newValue.trimmingCharacters(in: .whitespaces)
+        saveSearchPrefixes(prefixes)
+      }
+    )
+  }
+
+  private func saveSearchPrefixes(_ prefixes: SearchPrefixSettings) {
+    do {
+      try viewModel.setSearchPrefixes(prefixes)
+      errorMessage = nil
+    } catch {
+      errorMessage = "検索プレフィックスの保存に失敗しました: \(error.localizedDescription)"
+    }
+  }
+
+  /// 最近開いたフォルダを読み込めるエディタ
+  private var recentWorkspaceEditorCandidates: [EditorType] {
+    EditorType.allCases.filter(\.supportsRecentWorkspaceImport)
+  }
+
+  private func recentWorkspaceBinding(for editor: EditorType) -> Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.recentWorkspaceEditors.contains(editor) },
+      set: { newValue in
+        do {
+          try viewModel.setImportsRecentWorkspaces(newValue, for: editor)
+          errorMessage = nil
+        } catch {
+          errorMessage = "最近開いたフォルダの設定の保存に失敗しました: \(error.localizedDescription)"
+        }
+      }
+    )
+  }
+
+  private var recentProjectsLimitBinding: Binding<Int> {
+    Binding(
+      get: { viewModel.settings.recentProjectsLimit },
+      set: { newValue in
+        do {
+          try viewModel.setRecentProjectsLimit(newValue)
+          errorMessage = nil
+        } catch {
+          errorMessage = "最近のプロジェクト設定の保存に失敗しました: \(error.localizedDescription)"
+        }
+      }
+    )
+  }
+
   private var cacheIntervalBinding: Binding<Int> {
+    cacheSettingBinding(\.autoUpdateIntervalHours)
+  }
+
+  private var iconCacheLimitEnabledBinding: Binding<Bool> {
+    Binding(
+      get: { viewModel.settings.cacheUpdate.iconCacheMaxMegabytes != nil },
+      set: { enabled in
+        var cache = viewModel.settings.cacheUpdate
+        cache.iconCacheMaxMegabytes = enabled ? Self.defaultIconCacheMaxMegabytes : nil
+        saveCacheUpdateSettings(cache)
+      }
+    )
+  }
+
+  private var iconCacheMaxMegabytesBinding: Binding<Int> {
+    Binding(
+      get: {
+        viewModel.settings.cacheUpdate.iconCacheMaxMegabytes ?? Self.defaultIconCacheMaxMegabytes
+      },
+      set: { newValue in
+        var cache = viewModel.settings.cacheUpdate
+        cache.iconCacheMaxMegabytes = newValue
+        saveCacheUpdateSettings(cache)
+      }
+    )
+  }
+
+  /// 上限サイズを有効にしたときの初期値（MB）
+  private static let defaultIconCacheMaxMegabytes = 200
+
+  private func saveCacheUpdateSettings(_ cache: CacheUpdateSettings) {
+    do {
+      try viewModel.setCacheUpdateSettings(cache)
+      errorMessage = nil
+    } catch {
+      errorMessage = "キャッシュ設定の保存に失敗しました: \(error.localizedDescription)"
+    }
+  }
+
+  private func cacheSettingBinding<Value>(
+    _ keyPath: WritableKeyPath<CacheUpdateSettings, Value>
+  ) -> Binding<Value> {
     Binding(
-      get: { viewModel.settings.cacheUpdate.autoUpdateIntervalHours },
+      get: { viewModel.settings.cacheUpdate[keyPath: keyPath] },
       set: { newValue in
         var cache = viewModel.settings.cacheUpdate
-        cache.autoUpdateIntervalHours = newValue
+        cache[keyPath: keyPath] = newValue
         do {
           try viewModel.setCacheUpdateSettings(cache)
           errorMessage = nil
@@ -206,6 +1226,72 @@ struct GeneralSettingsTab: View {
   }
 }
 
+// MARK: - HotkeyBindingRow
+
+/// 追加ショートカット一覧の各行。
+struct HotkeyBindingRow: View {
+
+  let binding: HotkeyBinding
+  let onUpdate: (HotkeyBinding) -> Void
+  let onDelete: () -> Void
+
+  private var enabledBinding: Binding<Bool> {
+    Binding(
+      get: { binding.enabled },
+      set: { newValue in
+        var updated = binding
+        updated.enabled = newValue
+        onUpdate(updated)
+      }
+    )
+  }
+
+  private var imeBehaviorBinding: Binding<IMEBehavior?> {
+    Binding(
+      get: { binding.imeBehavior },
+      set: { newValue in
+        var updated = binding
+        updated.imeBehavior = newValue
+        onUpdate(updated)
+      }
+    )
+  }
+
+  var body: some View {
+    HStack {
+      Toggle("", isOn: enabledBinding)
+        .labelsHidden()
+      Text(binding.shortcut)
+        .font(.system(.body, design: .monospaced))
+      if !HotkeyBinding.isValidShortcut(binding.shortcut) {
+        Image(systemName: "exclamationmark.triangle.fill")
+          .foregroundStyle(.orange)
+          .help("ショートカットの書式が正しくありません")
+      }
+      Spacer()
+      Text(binding.mode.displayName)
+        .foregroundStyle(.secondary)
+      // 入力ソースの扱い（ピッカーは開かないので Emoji・カラーピッカーでは指定しない）
+      if binding.mode != .emoji, binding.mode != .colorPicker {
+        Picker("", selection: imeBehaviorBinding) {
+          Text("入力ソース: 全体の設定").tag(IMEBehavior?.none)
+          ForEach(IMEBehavior.allCases, id: \.self) { behavior in
+            Text(behavior.displayName).tag(IMEBehavior?.some(behavior))
+          }
+        }
+        .labelsHidden()
+        .frame(width: 200)
+      }
+      Button(role: .destructive) {
+        onDelete()
+      } label: {
+        Image(systemName: "trash")
+      }
+      .buttonStyle(.borderless)
+    }
+  }
+}
+
 // MARK: - DirectoriesSettingsTab
 
 /// ディレクトリタブ: 登録ディレクトリの追加・編集・削除。
@@ -213,6 +1299,8 @@ struct DirectoriesSettingsTab: View {
 
   @Bindable var viewModel: SettingsViewModel
   @State private var errorMessage: String?
+  /// 表示中のフォルダ選択ダイアログ（画面を閉じたときにキャンセルする）
+  @State private var folderPickerTask: Task<Void, Never>?
 
   var body: some View {
     VStack(alignment: .leading, spacing: 0) {
@@ -226,6 +1314,7 @@ struct DirectoriesSettingsTab: View {
           ForEach(viewModel.settings.registeredDirectories, id: \.path) { directory in
             DirectoryRow(
               directory: directory,
+              scanStatus: viewModel.scanStatus(for: directory),
               onUpdate: { updated in
                 do {
                   // path をキーに現在のインデックスを再検索し、削除・並び替えによる
@@ -242,6 +1331,14 @@ struct DirectoriesSettingsTab: View {
                   errorMessage = "ディレクトリの更新に失敗しました"
                 }
               },
+              onRefresh: {
+                guard
+                  let currentIndex = viewModel.settings.registeredDirectories.firstIndex(where: {
+                    $0.path == directory.path
+                  })
+                else { return }
+                viewModel.refreshDirectory(at: currentIndex)
+              },
               onDelete: {
                 do {
                   // 削除時も path で再検索し、インデックスずれによる誤削除を防ぐ。
@@ -269,6 +1366,7 @@ struct DirectoriesSettingsTab: View {
         } label: {
           Label("ディレクトリを追加", systemImage: "plus")
         }
+        .disabled(folderPickerTask != nil)
 
         Spacer()
 
@@ -280,27 +1378,31 @@ struct DirectoriesSettingsTab: View {
       }
       .padding(12)
     }
+    .onDisappear {
+      folderPickerTask?.cancel()
+    }
   }
 
   private func addDirectory() {
-    let panel = NSOpenPanel()
-    panel.canChooseDirectories = true
-    panel.canChooseFiles = false
-    panel.allowsMultipleSelection = false
-    panel.message = "登録するディレクトリを選択してください"
-
-    guard panel.runModal() == .OK, let url = panel.url else { return }
-
-    do {
-      try viewModel.addDirectory(
-        path: url.path,
-        parentOpenMode: .editor,
-        subdirsOpenMode: .editor,
-        scanForApps: false
-      )
-      errorMessage = nil
-    } catch {
-      errorMessage = "ディレクトリの追加に失敗しました"
+    // ダイアログの多重表示を防ぐ
+    guard folderPickerTask == nil else { return }
+    folderPickerTask = Task {
+      defer { folderPickerTask = nil }
+      guard
+        let url = await FolderPicker.chooseDirectory(message: "登録するディレクトリを選択してください")
+      else { return }
+
+      do {
+        try viewModel.addDirectory(
+          path: url.path,
+          parentOpenMode: .editor,
+          subdirsOpenMode: .editor,
+          scanForApps: false
+        )
+        errorMessage = nil
+      } catch {
+        errorMessage = "ディレクトリの追加に失敗しました"
+      }
     }
   }
 }
@@ -311,7 +1413,9 @@ struct DirectoriesSettingsTab: View {
 struct DirectoryRow: View {
 
   let directory: RegisteredDirectory
+  var scanStatus: DirectoryScanStatus?
   let onUpdate: (RegisteredDirectory) -> Void
+  let onRefresh: () -> Void
   let onDelete: () -> Void
 
   @State private var isEditing = false
@@ -324,8 +1428,22 @@ struct DirectoryRow: View {
         Text(directory.path)
           .lineLimit(1)
           .truncationMode(.middle)
+        if let warning = scanWarning {
+          Image(systemName: "exclamationmark.triangle.fill")
+            .foregroundStyle(.orange)
+            .help(warning)
+        }
         Spacer()
         HStack(spacing: 20) {
+          Button {
+            onRefresh()
+          } label: {
+            Image(systemName: "arrow.clockwise")
+          }
+          .buttonStyle(.borderless)
+          .foregroundStyle(.secondary)
+          .help("このディレクトリだけを再スキャン")
+
           Button {
             isEditing.toggle()
           } label: {
@@ -363,11 +1481,43 @@ struct DirectoryRow: View {
               .font(.caption)
               .foregroundStyle(.secondary)
           }
+          if directory.subdirsDepth > 1 {
+            Label("\(directory.subdirsDepth) 階層", systemImage: "list.bullet.indent")
+              .font(.caption)
+              .foregroundStyle(.secondary)
+          }
+          if directory.detectGitRepos {
+            Label("Git 検出", systemImage: "arrow.triangle.branch")
+              .font(.caption)
+              .foregroundStyle(.secondary)
+          }
+          if directory.priority != 0 {
+            Label("優先度 \(directory.priority)", systemImage: "arrow.up.arrow.down")
+              .font(.caption)
+              .foregroundStyle(.secondary)
+          }
+          if !directory.excludePatterns.isEmpty {
+            Label("除外 \(directory.excludePatterns.count) 件", systemImage: "eye.slash")
+              .font(.caption)
+              .foregroundStyle(.secondary)
+          }
         }
       }
     }
     .padding(.vertical, 4)
   }
+
+  /// 直近のスキャンが完了しなかった場合の説明
+  private var scanWarning: String? {
+    switch scanStatus?.state {
+    case .timedOut:
+      "前回のスキャンが制限時間内に完了しませんでした（以前の検索結果を保持しています）"
+    case .unavailable(let reason):
+      "前回のスキャンでディレクトリを読み取れませんでした: \(reason)"
+    case .completed, nil:
+      nil
+    }
+  }
 }
 
 // MARK: - DirectoryEditForm
@@ -376,10 +1526,14 @@ struct DirectoryRow: View {
 struct DirectoryEditForm: View {
 
   @State private var editedDirectory: RegisteredDirectory
+  /// 除外パターンの入力中テキスト（カンマ区切り、保存時に配列へ変換する）
+  @State private var excludePatternsText: String
   let onSave: (RegisteredDirectory) -> Void
 
   init(directory: RegisteredDirectory, onSave: @escaping (RegisteredDirectory) -> Void) {
     self._editedDirectory = State(initialValue: directory)
+    self._excludePatternsText = State(
+      initialValue: directory.excludePatterns.joined(separator: ", "))
     self.onSave = onSave
   }
 
@@ -397,6 +1551,14 @@ struct DirectoryEditForm: View {
     )
   }
 
+  /// 表示名テンプレート（空欄は nil として保存し、ディレクトリ名のまま表示する）
+  private var subdirsNameTemplateBinding: Binding<String> {
+    Binding(
+      get: { editedDirectory.subdirsNameTemplate ?? "" },
+      set: { editedDirectory.subdirsNameTemplate = $0.isEmpty ? nil : $0 }
+    )
+  }
+
   var body: some View {
     VStack(alignment: .leading, spacing: 8) {
       Picker("親ディレクトリ", selection: $editedDirectory.parentOpenMode) {
@@ -433,12 +1595,38 @@ struct DirectoryEditForm: View {
         .pickerStyle(.menu)
       }
 
+      TextField("サブディレクトリの表示名（例: work/{name}）", text: subdirsNameTemplateBinding)
+        .font(.system(.body, design: .monospaced))
+        .help("{name} はディレクトリ名、{parent} は 1 つ上のディレクトリ名、{root} は登録ディレクトリ名")
+
+      Stepper(
+        "走査する深さ: \(editedDirectory.subdirsDepth) 階層",
+        value: $editedDirectory.subdirsDepth,
+        in: 1...RegisteredDirectory.maxSubdirsDepth
+      )
+
+      Stepper(
+        "検索順位の優先度: \(editedDirectory.priority)",
+        value: $editedDirectory.priority,
+        in: RegisteredDirectory.priorityRange
+      )
+      .help("大きいほどこのディレクトリの項目を検索結果の上位に表示します")
+
       Toggle("アプリをスキャン", isOn: $editedDirectory.scanForApps)
 
+      Toggle("Git リポジトリを深さに関わらず検出", isOn: $editedDirectory.detectGitRepos)
+
+      TextField("除外パターン（例: node_modules, target, .venv）", text: $excludePatternsText)
+        .font(.system(.body, design: .monospaced))
+
       HStack {
         Spacer()
         Button("保存") {
-          onSave(editedDirectory)
+          var directory = editedDirectory
+          directory.excludePatterns = excludePatternsText.split(separator: ",")
+            .map { $0.trimmingCharacters(in: .whitespaces) }
+            .filter { !$0.isEmpty }
+          onSave(directory)
         }
         .buttonStyle(.borderedProminent)
         .controlSize(.small)
@@ -458,6 +1646,9 @@ private struct CommandFields: View {
   @Binding var command: String
   @Binding var workingDirectory: String
 
+  /// 表示中のフォルダ選択ダイアログ（フォームを閉じたときにキャンセルする）
+  @State private var folderPickerTask: Task<Void, Never>?
+
   var body: some View {
     Grid(alignment: .leading, horizontalSpacing: 10, verticalSpacing: 8) {
       GridRow {
@@ -480,30 +1671,36 @@ private struct CommandFields: View {
             Image(systemName: "folder")
           }
           .buttonStyle(.borderless)
+          .disabled(folderPickerTask != nil)
         }
       }
     }
     .textFieldStyle(.roundedBorder)
+    .onDisappear {
+      folderPickerTask?.cancel()
+    }
   }
 
   private func chooseDirectory() {
-    let panel = NSOpenPanel()
-    panel.canChooseFiles = false
-    panel.canChooseDirectories = true
-    panel.allowsMultipleSelection = false
-    panel.prompt = "選択"
-    panel.message = "作業ディレクトリを選択してください"
+    guard folderPickerTask == nil else { return }
+    var initialDirectory: URL?
     if !workingDirectory.isEmpty {
       let expanded = NSString(string: workingDirectory).expandingTildeInPath
       let url = URL(fileURLWithPath: expanded)
       if FileManager.default.fileExists(atPath: expanded) {
-        panel.directoryURL = url
+        initialDirectory = url
       } else if let parent = parentDirectory(of: url) {
-        panel.directoryURL = parent
+        initialDirectory = parent
       }
     }
-    if panel.runModal() == .OK, let url = panel.url {
-      workingDirectory = url.path
+    folderPickerTask = Task {
+      defer { folderPickerTask = nil }
+      if let url = await FolderPicker.chooseDirectory(
+        message: "作業ディレクトリを選択してください", prompt: "選択",
+        initialDirectory: initialDirectory)
+      {
+        workingDirectory = url.path
+      }
     }
   }
 
@@ -797,6 +1994,33 @@ struct ExcludedAppsSettingsTab: View {
         }
       }
 
+      if !viewModel.excludedItems.isEmpty {
+        Divider()
+        VStack(alignment: .leading, spacing: 6) {
+          Text("ランチャーで非表示にした項目（⌘⇧H）")
+            .font(.caption)
+            .foregroundStyle(.secondary)
+          ForEach(viewModel.excludedItems, id: \.self) { path in
+            HStack {
+              Text(path)
+                .lineLimit(1)
+                .truncationMode(.middle)
+              Spacer()
+              Button("再表示") {
+                do {
+                  try viewModel.restoreExcludedItem(path: path)
+                  errorMessage = nil
+                } catch {
+                  errorMessage = "除外設定の保存に失敗しました"
+                }
+              }
+              .buttonStyle(.borderless)
+            }
+          }
+        }
+        .padding(8)
+      }
+
       if let errorMessage {
         Divider()
         Text(errorMessage)
@@ -816,12 +2040,14 @@ struct ExcludedAppRow: View {
   let app: AppItem
   let viewModel: SettingsViewModel
   let onError: (String?) -> Void
+  @Environment(\.displayScale) private var displayScale
 
   var body: some View {
     let isExcluded = viewModel.isAppExcluded(app.name)
     HStack(spacing: 12) {
       if let iconPath = app.iconPath,
-        let nsImage = NSImage(contentsOfFile: iconPath)
+        let nsImage = NSImage(
+          contentsOfFile: IconCacheManager.iconPath(iconPath, pointSize: 28, scale: displayScale))
       {
         Image(nsImage: nsImage)
           .resizable()
@@ -864,6 +2090,148 @@ struct ExcludedAppRow: View {
   }
 }
 
+// MARK: - DiagnosticsSettingsTab
+
+/// 診断タブ: 自己診断の実行と結果の表示・コピー。
+struct DiagnosticsSettingsTab: View {
+
+  @Bindable var viewModel: SettingsViewModel
+  /// 復元・リセットの結果
+  @State private var statusMessage: String?
+  @State private var isConfirmingReset = false
+
+  var body: some View {
+    VStack(alignment: .leading, spacing: 0) {
+      HStack {
+        Button("診断を実行") {
+          Task { await viewModel.runDiagnostics() }
+        }
+        .disabled(viewModel.isRunningDiagnostics)
+        if viewModel.isRunningDiagnostics || viewModel.isResettingCache {
+          ProgressView()
+            .controlSize(.small)
+        }
+        Spacer()
+        if let statusMessage {
+          Text(statusMessage)
+            .font(.caption)
+            .foregroundStyle(.secondary)
+        }
+        Button("キャッシュをリセット...") {
+          isConfirmingReset = true
+        }
+        .disabled(viewModel.isResettingCache)
+        .help("cache.db とアイコンキャッシュを削除し、空の状態からスキャンし直します")
+        .confirmationDialog(
+          "キャッシュをリセットしますか？", isPresented: $isConfirmingReset
+        ) {
+          Button("リセット", role: .destructive) {
+            resetCache()
+          }
+        } message: {
+          Text("スキャン結果に加えて、お気に入り・開いた履歴・検索履歴も削除されます。「キャッシュを再構築」で直らない場合に使ってください。")
+        }
+        Menu("設定を復元") {
+          ForEach(viewModel.settingsBackups) { backup in
+            let savedAt = backup.modifiedAt.formatted(date: .abbreviated, time: .standard)
+            Button("\(backup.generation): \(savedAt)") {
+              restoreBackup(backup.generation)
+            }
+          }
+        }
+        .fixedSize()
+        .disabled(viewModel.settingsBackups.isEmpty)
+        .help("保存前の settings.json を直近 \(SettingsManager.backupLimit) 世代まで残しています")
+        if let report = viewModel.diagnosticReport {
+          Button("結果をコピー") {
+            ClipboardService().copy(report.summary)
+          }
+          .help("不具合報告に貼り付けられる形式でコピーします")
+        }
+      }
+      .padding(8)
+
+      Divider()
+
+      if let report = viewModel.diagnosticReport {
+        List(report.checks) { check in
+          DiagnosticCheckRow(check: check)
+        }
+      } else {
+        VStack {
+          Spacer()
+          Text("「診断を実行」で権限・ホットキー・キャッシュの状態を確認できます")
+            .foregroundStyle(.secondary)
+          Spacer()
+        }
+        .frame(maxWidth: .infinity)
+      }
+    }
+  }
+
+  private func restoreBackup(_ generation: Int) {
+    Task {
+      do {
+        try await viewModel.restoreSettingsBackup(generation: generation)
+        statusMessage = "バックアップ \(generation) から復元しました"
+      } catch {
+        statusMessage = "設定の復元に失敗しました"
+      }
+    }
+  }
+
+  private func resetCache() {
+    Task {
+      do {
+        try await viewModel.resetCache()
+        statusMessage = "キャッシュをリセットしました"
+      } catch {
+        statusMessage = "キャッシュのリセットに失敗しました"
+      }
+    }
+  }
+}
+
+// MARK: - DiagnosticCheckRow
+
+/// 診断結果の各行（状態アイコン + 項目名 + 詳細）。
+struct DiagnosticCheckRow: View {
+
+  let check: DiagnosticCheck
+
+  var body: some View {
+    HStack(alignment: .top, spacing: 10) {
+      Image(systemName: symbolName)
+        .foregroundStyle(color)
+        .frame(width: 20)
+      VStack(alignment: .leading, spacing: 2) {
+        Text(check.title)
+        Text(check.detail)
+          .font(.caption)
+          .foregroundStyle(.secondary)
+          .textSelection(.enabled)
+      }
+    }
+    .padding(.vertical, 2)
+  }
+
+  private var symbolName: String {
+    switch check.status {
+    case .ok: "checkmark.circle.fill"
+    case .warning: "exclamationmark.triangle.fill"
+    case .failed: "xmark.octagon.fill"
+    }
+  }
+
+  private var color: Color {
+    switch check.status {
+    case .ok: .green
+    case .warning: .orange
+    case .failed: .red
+    }
+  }
+}
+
 // MARK: - EditorTerminalRow
 
 /// エディタ/ターミナル選択行（アイコン + 名前 + チェックマーク）。
@@ -1,5 +1,4 @@
 import Foundation
-import ServiceManagement
 
 // MARK: - SettingsTab
 
@@ -9,6 +8,7 @@ public enum SettingsTab: String, CaseIterable, Sendable {
   case directories
   case commands
   case excludedApps
+  case diagnostics
 }
 
 // MARK: - SettingsChange
@@ -21,6 +21,12 @@ public enum SettingsChange: Sendable, Equatable {
   case cacheInvalidated
   /// 自動更新タイマーの再起動が必要な変更（キャッシュ更新設定）
   case updateScheduleChanged
+  /// 追加ホットキーの再登録が必要な変更（ホットキーバインディング）
+  case hotkeysChanged
+  /// アップデートの再確認が必要な変更（リリースチャンネル）
+  case releaseChannelChanged
+  /// 指定した登録ディレクトリのみ再スキャンする要求
+  case directoryRefreshRequested(path: String)
 }
 
 // MARK: - SettingsViewModel
@@ -38,11 +44,44 @@ public final class SettingsViewModel {
   /// 設定の永続化を担う SettingsManager
   public let settingsManager: SettingsManager
 
+  /// ログイン時起動の登録/解除を担うサービス
+  private let loginItemService: any LoginItemControlling
+
+  /// 出力レベルを反映し、直近のログを読み出すログファイル
+  public let logFile: LogFile
+
   // MARK: - Callbacks
 
   /// 設定が保存された後に呼ばれるコールバック
   public var onSettingsChanged: ((SettingsChange) -> Void)?
 
+  /// 自己診断の実体処理（AppCoordinator が注入する）
+  public var onRunDiagnostics: (@MainActor () async -> DiagnosticReport?)?
+
+  /// アイコンキャッシュの削除と再生成の実体処理（AppCoordinator が注入する）
+  public var onClearIconCache: (@MainActor () async -> Void)?
+
+  /// キャッシュの DB・アイコンの削除と再スキャンの実体処理（AppCoordinator が注入する）
+  public var onResetCache: (@MainActor () async throws -> Void)?
+
+  /// 設定プロファイルの切り替えの実体処理（AppCoordinator が注入する）
+  public var onSwitchProfile: (@MainActor (String) async throws -> Void)?
+
+  /// 削除したプロファイルのキャッシュ DB の削除処理（AppCoordinator が注入する）
+  public var onProfileDeleted: (@MainActor (String) throws -> Void)?
+
+  /// 設定のバックアップからの復元の実体処理（AppCoordinator が注入する）
+  public var onRestoreSettingsBackup: (@MainActor (Int) async throws -> Void)?
+
+  /// ディレクトリごとに記憶したエディタの変更の実体処理（AppCoordinator が注入する）
+  public var onSetDirectoryEditor: (@MainActor (EditorType?, String) throws -> Void)?
+
+  /// アクセシビリティ権限の要求の実体処理（AppCoordinator が注入する）
+  public var onRequestAccessibilityPermission: (@MainActor () -> Void)?
+
+  /// システム設定の「アクセシビリティ」を開く実体処理（AppCoordinator が注入する）
+  public var onOpenAccessibilitySettings: (@MainActor () -> Void)?
+
   // MARK: - State
 
   /// 現在選択中のタブ
@@ -57,6 +96,38 @@ public final class SettingsViewModel {
   /// インストール済みターミナル一覧（外部から設定）
   public var installedTerminals: [TerminalInfo] = []
 
+  /// 直近のスキャンでの登録ディレクトリごとの状況（外部から設定）
+  public var scanStatuses: [DirectoryScanStatus] = []
+
+  /// 直近の自己診断の結果（未実行の場合は nil）
+  public private(set) var diagnosticReport: DiagnosticReport?
+
+  /// 自己診断を実行中かどうか
+  public private(set) var isRunningDiagnostics = false
+
+  /// アクセシビリティ権限が許可されているか（権限の変化に合わせて外部から設定）
+  public var isAccessibilityTrusted = true
+
+  /// ディレクトリごとに記憶したエディタ（外部から設定）
+  public var directoryEditors: [String: EditorType] = [:]
+
+  /// アイコンキャッシュの使用量（外部から設定）
+  public var iconCacheUsage: IconCacheUsage?
+
+  /// アイコンキャッシュを削除中かどうか
+  public private(set) var isClearingIconCache = false
+
+  /// キャッシュをリセット中かどうか
+  public private(set) var isResettingCache = false
+
+  /// 登録ディレクトリの直近のスキャン状況を返す。
+  ///
+  /// - Parameter directory: 登録ディレクトリ
+  /// - Returns: スキャン状況（まだスキャンしていない場合は nil）
+  public func scanStatus(for directory: RegisteredDirectory) -> DirectoryScanStatus? {
+    scanStatuses.first { $0.path == directory.normalizedPath }
+  }
+
   // MARK: - Computed Properties
 
   /// 現在の設定（SettingsManager のプロキシ）
@@ -73,28 +144,108 @@ public final class SettingsViewModel {
 
   /// SettingsViewModel を初期化する。
   ///
-  /// - Parameter settingsManager: 設定の永続化を担う SettingsManager
-  public init(settingsManager: SettingsManager) {
+  /// - Parameters:
+  ///   - settingsManager: 設定の永続化を担う SettingsManager
+  ///   - loginItemService: ログイン時起動の登録/解除を担うサービス（テスト時に差し替え可能）
+  ///   - logFile: 出力レベルを反映し、直近のログを読み出すログファイル
+  public init(
+    settingsManager: SettingsManager,
+    loginItemService: any LoginItemControlling = LoginItemService(),
+    logFile: LogFile = .shared
+  ) {
     self.settingsManager = settingsManager
+    self.loginItemService = loginItemService
+    self.logFile = logFile
   }
 
   // MARK: - Launch at Login
 
-  /// ログイン時に起動するかどうか（SMAppService の状態を反映）。
+  /// ログイン時に起動するかどうか（設定の `launch_at_login` を反映）。
   public var launchAtLogin: Bool {
-    SMAppService.mainApp.status == .enabled
+    settingsManager.settings.launchAtLogin
   }
 
-  /// ログイン時起動の有効/無効を切り替える。
+  /// ログイン時起動の有効/無効を切り替え、設定へ保存する。
   ///
   /// - Parameter enabled: `true` で有効化、`false` で無効化
-  /// - Throws: SMAppService の register/unregister に失敗した場合
+  /// - Throws: 登録/解除または設定の保存に失敗した場合
   public func setLaunchAtLogin(_ enabled: Bool) throws {
-    if enabled {
-      try SMAppService.mainApp.register()
-    } else {
-      try SMAppService.mainApp.unregister()
+    try loginItemService.setEnabled(enabled)
+    settingsManager.settings.launchAtLogin = enabled
+    try settingsManager.save()
+  }
+
+  /// 設定値とシステムの登録状態を同期する（起動時に呼び出す）。
+  ///
+  /// 設定が有効なのに未登録（アプリの移動・再インストール後など）の場合は再登録する。
+  /// 設定項目の追加前にログイン項目を有効化していた場合は、登録状態を設定へ取り込む。
+  public func syncLaunchAtLogin() throws {
+    let registered = loginItemService.isEnabled()
+    if settingsManager.settings.launchAtLogin, !registered {
+      try loginItemService.setEnabled(true)
+    } else if !settingsManager.settings.launchAtLogin, registered {
+      settingsManager.settings.launchAtLogin = true
+      try settingsManager.save()
+    }
+  }
+
+  /// ランチャーの外観を変更する。
+  ///
+  /// - Parameter appearance: 新しい外観設定
+  /// - Throws: 設定の保存に失敗した場合
+  public func setAppearance(_ appearance: AppearanceSettings) throws {
+    settingsManager.settings.appearance = appearance
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// ランチャーの表示位置の決め方を変更する。
+  ///
+  /// 位置の記憶以外に切り替えた場合は保存済みの位置も破棄する。
+  /// - Parameter placement: 新しい表示位置の決め方
+  /// - Throws: 設定の保存に失敗した場合
+  public func setWindowPlacement(_ placement: WindowPlacement) throws {
+    settingsManager.settings.windowPlacement = placement
+    if placement != .rememberedPosition {
+      settingsManager.settings.windowPosition = nil
     }
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// Escape などで閉じたときにランチャーを開く前のアプリを前面に戻すかを変更する。
+  ///
+  /// - Parameter enabled: 前面に戻す場合は `true`
+  /// - Throws: 設定の保存に失敗した場合
+  public func setRestoreFocusOnHide(_ enabled: Bool) throws {
+    settingsManager.settings.restoreFocusOnHide = enabled
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// ランチャーを自動で閉じる条件を変更する。
+  ///
+  /// - Parameter behavior: 新しい設定
+  /// - Throws: 設定の保存に失敗した場合
+  public func setWindowBehavior(_ behavior: WindowBehaviorSettings) throws {
+    settingsManager.settings.windowBehavior = behavior
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// アップデートの設定を変更する。
+  ///
+  /// リリースチャンネルを変更した場合は、キャッシュした確認結果を使わずに再確認させる。
+  /// - Parameter update: 新しいアップデートの設定
+  /// - Throws: 設定の保存に失敗した場合
+  public func setUpdateSettings(_ update: UpdateSettings) throws {
+    let channelChanged = settingsManager.settings.update.releaseChannel != update.releaseChannel
+    settingsManager.settings.update = update
+    if channelChanged {
+      settingsManager.settings.updateCache?.checkedAt = nil
+    }
+    try settingsManager.save()
+    onSettingsChanged?(channelChanged ? .releaseChannelChanged : .reloadOnly)
   }
 
   // MARK: - General Tab
@@ -129,6 +280,416 @@ public final class SettingsViewModel {
     onSettingsChanged?(.updateScheduleChanged)
   }
 
+  /// 検索プレフィックス設定を変更する。
+  ///
+  /// - Parameter prefixes: 新しい検索プレフィックス設定
+  /// - Throws: 設定の保存に失敗した場合
+  public func setSearchPrefixes(_ prefixes: SearchPrefixSettings) throws {
+    settingsManager.settings.searchPrefixes = prefixes
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// 検索結果の件数と並べ方を変更する。
+  ///
+  /// - Parameter results: 新しい検索結果の設定
+  /// - Throws: 設定の保存に失敗した場合
+  public func setResultSettings(_ results: ResultSettings) throws {
+    settingsManager.settings.results = results
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// 空クエリ時に表示する最近のプロジェクトの件数を設定する。
+  ///
+  /// - Parameter limit: 表示件数（0 で無効、0〜20 に丸める）
+  /// - Throws: 設定の保存に失敗した場合
+  public func setRecentProjectsLimit(_ limit: Int) throws {
+    settingsManager.settings.recentProjectsLimit = min(max(limit, 0), 20)
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// エディタピッカーで選んだエディタをディレクトリごとに記憶するかを切り替える。
+  ///
+  /// 無効にしても記憶したエディタは消さず、使わないだけにする。
+  /// - Parameter enabled: `true` で記憶したエディタで開く
+  /// - Throws: 設定の保存に失敗した場合
+  public func setRemembersDirectoryEditor(_ enabled: Bool) throws {
+    settingsManager.settings.remembersDirectoryEditor = enabled
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// Xcode のプロジェクトがあるディレクトリを Xcode で開くかを切り替える。
+  ///
+  /// エディタピッカーで記憶したエディタがある場合はそちらを優先する。
+  /// - Parameter enabled: `true` で Xcode で開く
+  /// - Throws: 設定の保存に失敗した場合
+  public func setOpensXcodeProjectsInXcode(_ enabled: Bool) throws {
+    settingsManager.settings.opensXcodeProjectsInXcode = enabled
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// 項目をゴミ箱に入れる前に確認するかを切り替える。
+  ///
+  /// - Parameter enabled: `true` で確認する
+  /// - Throws: 設定の保存に失敗した場合
+  public func setConfirmsMoveToTrash(_ enabled: Bool) throws {
+    settingsManager.settings.confirmsMoveToTrash = enabled
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// エディタの最近開いたフォルダを検索対象に取り込むかを切り替える。
+  ///
+  /// 取り込みはキャッシュの更新時に行うため、変更後はキャッシュを再構築する。
+  /// - Parameters:
+  ///   - enabled: `true` で取り込む
+  ///   - editor: 対象のエディタ
+  /// - Throws: 設定の保存に失敗した場合
+  public func setImportsRecentWorkspaces(_ enabled: Bool, for editor: EditorType) throws {
+    let current = settingsManager.settings.recentWorkspaceEditors
+    guard current.contains(editor) != enabled else { return }
+    settingsManager.settings.recentWorkspaceEditors =
+      enabled ? current + [editor] : current.filter { $0 != editor }
+    try settingsManager.save()
+    onSettingsChanged?(.cacheInvalidated)
+  }
+
+  /// ディレクトリに記憶したエディタを変更する（反映は AppCoordinator が行う）。
+  ///
+  /// - Parameters:
+  ///   - editor: 次から開くエディタ（nil で記憶を消し、既定のエディタに戻す）
+  ///   - path: ディレクトリのパス
+  /// - Throws: キャッシュ DB への保存に失敗した場合
+  public func setDirectoryEditor(_ editor: EditorType?, forPath path: String) throws {
+    guard let onSetDirectoryEditor else { return }
+    try onSetDirectoryEditor(editor, path)
+  }
+
+  /// 中国語・韓国語の名前のローマ字検索を切り替える。
+  ///
+  /// - Parameter enabled: `true` でピンイン・ハングルのローマ字表記でも検索できるようにする
+  /// - Throws: 設定の保存に失敗した場合
+  public func setTransliterationEnabled(_ enabled: Bool) throws {
+    settingsManager.settings.transliterationEnabled = enabled
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// ディレクトリの全文検索（キャッシュ DB を直接検索する方式）を切り替える。
+  ///
+  /// - Parameter enabled: `true` でディレクトリをメモリに読み込まず SQLite の FTS5 インデックスで検索する
+  /// - Throws: 設定の保存に失敗した場合
+  public func setFullTextSearchEnabled(_ enabled: Bool) throws {
+    settingsManager.settings.fullTextSearchEnabled = enabled
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// スコアの内訳を返す IPC の `search_debug` を受け付けるかを変更する。
+  ///
+  /// - Parameter enabled: `true` で `ignitero search_debug` を使えるようにする
+  /// - Throws: 設定の保存に失敗した場合
+  public func setSearchDebugEnabled(_ enabled: Bool) throws {
+    settingsManager.settings.searchDebugEnabled = enabled
+    try settingsManager.save()
+  }
+
+  /// ログファイルに書き込む最低レベルを変更し、即座に反映する。
+  ///
+  /// - Parameter level: 書き込む最低レベル
+  /// - Throws: 設定の保存に失敗した場合
+  public func setLogLevel(_ level: LogLevel) throws {
+    settingsManager.settings.logLevel = level
+    try settingsManager.save()
+    logFile.level = level
+  }
+
+  /// メニューバー・設定ウィンドウ・エラー表示の言語を変更する。
+  ///
+  /// - Parameter language: 表示言語（`auto` はシステムの優先言語に合わせる）
+  /// - Throws: 設定の保存に失敗した場合
+  public func setLanguage(_ language: AppLanguage) throws {
+    settingsManager.settings.language = language
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// 不具合報告に添付する直近のログを返す。
+  ///
+  /// - Parameter limit: 最大行数
+  /// - Returns: 古い順に改行で連結したログ（ログがない場合は空文字列）
+  public func recentLogs(limit: Int = 500) -> String {
+    logFile.recentLines(limit: limit).joined(separator: "\n")
+  }
+
+  // MARK: - Permissions
+
+  /// アクセシビリティ権限を求めるシステムのダイアログを表示する。
+  public func requestAccessibilityPermission() {
+    onRequestAccessibilityPermission?()
+  }
+
+  /// システム設定の「アクセシビリティ」を開く。
+  public func openAccessibilitySettings() {
+    onOpenAccessibilitySettings?()
+  }
+
+  // MARK: - Diagnostics
+
+  /// 自己診断を実行し、結果を `diagnosticReport` に反映する（実行中の再要求は無視する）。
+  public func runDiagnostics() async {
+    guard !isRunningDiagnostics, let onRunDiagnostics else { return }
+    isRunningDiagnostics = true
+    defer { isRunningDiagnostics = false }
+    diagnosticReport = await onRunDiagnostics()
+  }
+
+  // MARK: - Icon Cache
+
+  /// アイコンキャッシュを削除し、スキャンし直してアイコンを作り直す（実行中の再要求は無視する）。
+  public func clearIconCache() async {
+    guard !isClearingIconCache, let onClearIconCache else { return }
+    isClearingIconCache = true
+    defer { isClearingIconCache = false }
+    await onClearIconCache()
+  }
+
+  /// キャッシュの DB とアイコンキャッシュを削除し、空の状態からスキャンし直す（実行中の再要求は無視する）。
+  ///
+  /// - Throws: キャッシュの更新中、または DB を作り直せない場合
+  public func resetCache() async throws {
+    guard !isResettingCache, let onResetCache else { return }
+    isResettingCache = true
+    defer { isResettingCache = false }
+    try await onResetCache()
+  }
+
+  // MARK: - Profiles
+
+  /// 使用中の設定を複製して新しいプロファイルを作る。
+  ///
+  /// - Parameter name: プロファイル名
+  /// - Throws: 名前が空・重複している場合、または設定の保存に失敗した場合
+  public func createProfile(named name: String) throws {
+    try settingsManager.createProfile(named: name)
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// 使用中でないプロファイルを、プロファイルのキャッシュ DB ごと削除する。
+  ///
+  /// - Parameter name: プロファイル名
+  /// - Throws: 使用中・存在しないプロファイルの場合、または設定の保存・キャッシュ DB の削除に
+  ///   失敗した場合
+  public func deleteProfile(named name: String) throws {
+    try settingsManager.deleteProfile(named: name)
+    onSettingsChanged?(.reloadOnly)
+    try onProfileDeleted?(name)
+  }
+
+  /// プロファイルを切り替える（キャッシュとホットキーの反映は AppCoordinator が行う）。
+  ///
+  /// - Parameter name: 切り替え先のプロファイル名
+  /// - Throws: キャッシュの更新中・存在しないプロファイルの場合、または保存に失敗した場合
+  public func switchProfile(to name: String) async throws {
+    guard let onSwitchProfile else { return }
+    try await onSwitchProfile(name)
+  }
+
+  // MARK: - Settings Backups
+
+  /// 残っている設定ファイルのバックアップ（新しい順）
+  public var settingsBackups: [SettingsBackup] {
+    settingsManager.backups()
+  }
+
+  /// 設定ファイルのバックアップから復元する（反映は AppCoordinator が行う）。
+  ///
+  /// - Parameter generation: 復元する世代（1 が最新）
+  /// - Throws: バックアップがない・読めない場合、または保存に失敗した場合
+  public func restoreSettingsBackup(generation: Int) async throws {
+    guard let onRestoreSettingsBackup else { return }
+    try await onRestoreSettingsBackup(generation)
+  }
+
+  // MARK: - Item Aliases
+
+  /// 項目に別名を割り当てる。
+  ///
+  /// 同じ別名が既にある場合は割り当て先を置き換える。パスの `~` はホームディレクトリに展開する。
+  /// 別名が空または空白を含む場合は何もしない。
+  /// - Parameters:
+  ///   - alias: 別名（例: `vsc`）
+  ///   - path: 対象のパス、またはカスタムコマンドの識別子
+  /// - Throws: 設定の保存に失敗した場合
+  public func setItemAlias(_ alias: String, for path: String) throws {
+    let trimmedPath = path.trimmingCharacters(in: .whitespaces)
+    guard ItemAlias.isValid(alias: alias), !trimmedPath.isEmpty else { return }
+    let key = SearchQueryNormalizer.normalize(alias)
+    let target =
+      trimmedPath.hasPrefix("command://")
+      ? trimmedPath : (trimmedPath as NSString).expandingTildeInPath
+    var aliases = settingsManager.settings.itemAliases
+    if let index = aliases.firstIndex(where: { $0.normalizedAlias == key }) {
+      aliases[index].path = target
+    } else {
+      aliases.append(ItemAlias(alias: key, path: target))
+    }
+    settingsManager.settings.itemAliases = aliases
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// 別名を削除する。
+  ///
+  /// - Parameter alias: 削除する別名
+  /// - Throws: 設定の保存に失敗した場合
+  public func removeItemAlias(_ alias: String) throws {
+    let key = SearchQueryNormalizer.normalize(alias)
+    let before = settingsManager.settings.itemAliases.count
+    settingsManager.settings.itemAliases.removeAll { $0.normalizedAlias == key }
+    guard settingsManager.settings.itemAliases.count != before else { return }
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  // MARK: - Quick Links
+
+  /// 登録済みのクイックリンク
+  public var quickLinks: [QuickLink] {
+    settingsManager.settings.quickLinks
+  }
+
+  /// クイックリンクを追加する。
+  ///
+  /// 名前が空・空白を含む場合や URL が不正な場合、同じ名前が既にある場合は何もしない。
+  /// - Parameters:
+  ///   - name: 名前（ランチャーで入力するキーワード。例: `mdn`）
+  ///   - url: `{query}` を含められる URL（例: `https://developer.mozilla.org/search?q={query}`）
+  ///   - icon: 表示アイコンの SF Symbols 名
+  /// - Throws: 設定の保存に失敗した場合
+  public func addQuickLink(name: String, url: String, icon: String? = nil) throws {
+    let link = QuickLink(
+      name: name.trimmingCharacters(in: .whitespaces),
+      url: url.trimmingCharacters(in: .whitespaces),
+      icon: icon.flatMap { $0.trimmingCharacters(in: .whitespaces).isEmpty ? nil : $0 })
+    guard link.isValid,
+      !settingsManager.settings.quickLinks.contains(where: { $0.keyword == link.keyword })
+    else { return }
+    settingsManager.settings.quickLinks.append(link)
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// クイックリンクを更新する。
+  ///
+  /// - Parameter link: 更新後のクイックリンク（`id` で対象を特定する。不正な内容の場合は何もしない）
+  /// - Throws: 設定の保存に失敗した場合
+  public func updateQuickLink(_ link: QuickLink) throws {
+    guard link.isValid,
+      let index = settingsManager.settings.quickLinks.firstIndex(where: { $0.id == link.id })
+    else { return }
+    settingsManager.settings.quickLinks[index] = link
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// クイックリンクを削除する。
+  ///
+  /// - Parameter id: 削除するクイックリンクの ID
+  /// - Throws: 設定の保存に失敗した場合
+  public func removeQuickLink(id: UUID) throws {
+    let before = settingsManager.settings.quickLinks.count
+    settingsManager.settings.quickLinks.removeAll { $0.id == id }
+    guard settingsManager.settings.quickLinks.count != before else { return }
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  // MARK: - File Openers
+
+  /// 拡張子ごとにファイルを開くアプリ
+  public var fileOpeners: [FileOpener] {
+    settingsManager.settings.fileOpeners
+  }
+
+  /// 拡張子にファイルを開くアプリを割り当てる。
+  ///
+  /// 同じ拡張子が既にある場合はアプリを置き換える。拡張子やアプリが不正な場合は何もしない。
+  /// - Parameters:
+  ///   - fileExtension: 拡張子（例: `md`。先頭の `.` は省略可）
+  ///   - application: `.app` のパス、アプリ名、またはバンドル ID（例: `Typora`）
+  /// - Throws: 設定の保存に失敗した場合
+  public func setFileOpener(fileExtension: String, application: String) throws {
+    var opener = FileOpener(
+      fileExtension: fileExtension, application: application.trimmingCharacters(in: .whitespaces))
+    guard opener.isValid else { return }
+    opener.fileExtension = opener.normalizedExtension
+    if let index = settingsManager.settings.fileOpeners.firstIndex(where: {
+      $0.normalizedExtension == opener.normalizedExtension
+    }) {
+      settingsManager.settings.fileOpeners[index].application = opener.application
+    } else {
+      settingsManager.settings.fileOpeners.append(opener)
+    }
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// 拡張子へのアプリの割り当てを削除する。
+  ///
+  /// - Parameter id: 削除する割り当ての ID
+  /// - Throws: 設定の保存に失敗した場合
+  public func removeFileOpener(id: UUID) throws {
+    let before = settingsManager.settings.fileOpeners.count
+    settingsManager.settings.fileOpeners.removeAll { $0.id == id }
+    guard settingsManager.settings.fileOpeners.count != before else { return }
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  // MARK: - Hotkey Bindings
+
+  /// 追加ホットキーを登録する。
+  ///
+  /// - Parameters:
+  ///   - shortcut: `cmd+shift+p` 形式のショートカット文字列
+  ///   - mode: 起動するモード
+  /// - Throws: 設定の保存に失敗した場合
+  public func addHotkeyBinding(shortcut: String, mode: LauncherMode) throws {
+    let binding = HotkeyBinding(shortcut: shortcut, mode: mode)
+    settingsManager.settings.hotkeyBindings.append(binding)
+    try settingsManager.save()
+    onSettingsChanged?(.hotkeysChanged)
+  }
+
+  /// 指定インデックスの追加ホットキーを削除する。
+  ///
+  /// - Parameter index: 削除するバインディングのインデックス
+  /// - Throws: 設定の保存に失敗した場合
+  public func removeHotkeyBinding(at index: Int) throws {
+    guard settingsManager.settings.hotkeyBindings.indices.contains(index) else { return }
+    settingsManager.settings.hotkeyBindings.remove(at: index)
+    try settingsManager.save()
+    onSettingsChanged?(.hotkeysChanged)
+  }
+
+  /// 指定インデックスの追加ホットキーを更新する。
+  ///
+  /// - Parameters:
+  ///   - index: 更新するバインディングのインデックス
+  ///   - binding: 新しいバインディング
+  /// - Throws: 設定の保存に失敗した場合
+  public func updateHotkeyBinding(at index: Int, _ binding: HotkeyBinding) throws {
+    guard settingsManager.settings.hotkeyBindings.indices.contains(index) else { return }
+    settingsManager.settings.hotkeyBindings[index] = binding
+    try settingsManager.save()
+    onSettingsChanged?(.hotkeysChanged)
+  }
+
   // MARK: - Directory Tab
 
   /// ディレクトリを追加する。
@@ -166,6 +727,16 @@ public final class SettingsViewModel {
     onSettingsChanged?(.cacheInvalidated)
   }
 
+  /// 指定インデックスのディレクトリだけを再スキャンするよう要求する。
+  ///
+  /// 設定は変更せず、キャッシュ全体の再構築も行わない。
+  /// - Parameter index: 再スキャンするディレクトリのインデックス
+  public func refreshDirectory(at index: Int) {
+    guard settingsManager.settings.registeredDirectories.indices.contains(index) else { return }
+    let path = settingsManager.settings.registeredDirectories[index].path
+    onSettingsChanged?(.directoryRefreshRequested(path: path))
+  }
+
   /// 指定インデックスのディレクトリを更新する。
   ///
   /// - Parameters:
@@ -250,4 +821,34 @@ public final class SettingsViewModel {
   public func isAppExcluded(_ appName: String) -> Bool {
     settingsManager.settings.excludedApps.contains(appName)
   }
+
+  // MARK: - Excluded Items
+
+  /// 検索結果から非表示にした項目のパス一覧
+  public var excludedItems: [String] {
+    settingsManager.settings.excludedItems
+  }
+
+  /// 項目を検索結果から非表示にする。
+  ///
+  /// スキャン対象からは外さないため、再表示するとキャッシュの再構築なしで元に戻る。
+  /// - Parameter path: アプリ・ディレクトリのパス、またはカスタムコマンドの識別子
+  /// - Throws: 設定の保存に失敗した場合
+  public func excludeItem(path: String) throws {
+    guard !path.isEmpty, !settingsManager.settings.excludedItems.contains(path) else { return }
+    settingsManager.settings.excludedItems.append(path)
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
+
+  /// 非表示にした項目を再び検索結果に表示する。
+  ///
+  /// - Parameter path: 非表示を解除するパス
+  /// - Throws: 設定の保存に失敗した場合
+  public func restoreExcludedItem(path: String) throws {
+    guard let index = settingsManager.settings.excludedItems.firstIndex(of: path) else { return }
+    settingsManager.settings.excludedItems.remove(at: index)
+    try settingsManager.save()
+    onSettingsChanged?(.reloadOnly)
+  }
 }
@@ -4,7 +4,8 @@ import Foundation
 /// ランチャーウィンドウの表示/非表示、リサイズを統括するマネージャ。
 ///
 /// `@MainActor` でスレッド安全性を保証し、`@Observable` で SwiftUI バインディングに対応。
-/// パネルは表示のたびにカーソルのあるスクリーンの中央上部へ配置する（Spotlight 風）。
+/// パネルは表示のたびに `placement` に応じたスクリーンの中央上部へ配置する（Spotlight 風）。
+/// 位置の記憶を選んだ場合は、ユーザーが移動した位置へ復元する。
 @MainActor
 @Observable
 public final class WindowManager {
@@ -28,6 +29,14 @@ public final class WindowManager {
   /// ランチャーウィンドウの幅 (px)
   public static let width: CGFloat = 680
 
+  // MARK: - Appearance
+
+  /// ランチャーウィンドウの幅（外観設定から反映する）
+  public var windowWidth: CGFloat = WindowManager.width
+
+  /// 検索結果 1 行あたりの高さ（外観設定の行の高さから反映する）
+  public var resultRowHeight: CGFloat = WindowManager.rowHeight
+
   // MARK: - Published State
 
   /// ランチャーウィンドウが表示中かどうか
@@ -36,6 +45,18 @@ public final class WindowManager {
   /// エディタ/ターミナルピッカーが表示中かどうか
   public private(set) var isPickerVisible: Bool = false
 
+  /// ランチャーを表示する直前に最前面だったアプリ（自身が最前面だった場合は前回の値を保持する）
+  public private(set) var previousApplication: NSRunningApplication?
+
+  /// Escape などで閉じたときに `previousApplication` を前面に戻すか
+  public var restoresFocusOnHide: Bool = true
+
+  /// 他のアプリへの切り替えやランチャー外のクリックで自動的に閉じるか
+  public var hidesOnFocusLoss: Bool = true
+
+  /// フォーカスを失ってから自動的に閉じるまでの猶予（猶予中にランチャーへ戻れば閉じない）
+  public var focusLossGracePeriod: Duration = .zero
+
   /// 現在のウィンドウ高さ
   public private(set) var currentHeight: CGFloat = WindowManager.minHeight
 
@@ -48,6 +69,9 @@ public final class WindowManager {
   /// ランチャー表示直前のコールバック（検索クリアなど）。
   public var onShowLauncher: (() -> Void)?
 
+  /// ランチャーを非表示にした直後のコールバック（入力ソースの復元など）。
+  public var onHideLauncher: (() -> Void)?
+
   /// ランチャーパネルのキーダウンイベントハンドラ。
   /// `true` を返すとイベントを消費する。
   public var onKeyEvent: ((NSEvent) -> Bool)?
@@ -56,6 +80,18 @@ public final class WindowManager {
   /// ピッカーを閉じるために AppCoordinator から設定される。
   public var onCloseAllPickers: (() -> Void)?
 
+  /// ランチャーを表示するスクリーン・位置の決め方
+  public var placement: WindowPlacement = .followMouse
+
+  /// 復元に使う位置（`placement` が `.rememberedPosition` の場合のみ使用）
+  public var savedPosition: WindowPosition?
+
+  /// ユーザーがランチャーを移動した際のコールバック（デバウンス後に 1 回呼ばれる）。
+  public var onPositionChanged: ((WindowPosition) -> Void)?
+
+  /// 移動イベントをまとめて保存するまでの待ち時間
+  public var positionSaveDebounce: Duration = .milliseconds(500)
+
   // MARK: - Private
 
   /// クリック外イベント監視トークン
@@ -67,6 +103,21 @@ public final class WindowManager {
   /// キーダウンイベントのローカルモニター
   private var keyEventMonitor: Any?
 
+  /// パネル移動通知の監視トークン
+  private var moveObserver: (any NSObjectProtocol)?
+
+  /// 位置保存のデバウンスタスク
+  private var positionSaveTask: Task<Void, Never>?
+
+  /// プログラムからフレームを変更中か（自前の配置・リサイズを移動として扱わない）
+  private var isApplyingFrame = false
+
+  /// 次の表示で直下に配置するメニューバーアイコンの位置（スクリーン座標）
+  private var pendingAnchor: NSRect?
+
+  /// 猶予時間の経過後に自動非表示するタスク
+  private var pendingAutoDismiss: Task<Void, Never>?
+
   // MARK: - Initialization
 
   public init() {}
@@ -83,7 +134,7 @@ public final class WindowManager {
       isPickerVisible = false
       showLauncher()
     } else if isLauncherVisible {
-      hideLauncher()
+      hideLauncher(restoringFocus: true)
     } else {
       showLauncher()
     }
@@ -91,39 +142,178 @@ public final class WindowManager {
 
   /// ランチャーを表示し、カーソルがあるスクリーンの中央最前面に配置する。
   public func showLauncher() {
+    let frontmost = NSWorkspace.shared.frontmostApplication
+    if frontmost?.processIdentifier != ProcessInfo.processInfo.processIdentifier {
+      previousApplication = frontmost
+    }
     onShowLauncher?()
     isLauncherVisible = true
-    centerOnScreen()
+    startMoveObserver()
+    if let anchor = pendingAnchor {
+      pendingAnchor = nil
+      positionBelow(anchor)
+    } else if !restoreSavedPosition() {
+      centerOnScreen()
+    }
     launcherPanel?.makeKeyAndOrderFront(nil)
     startDismissMonitors()
     startKeyEventMonitor()
   }
 
+  /// メニューバーアイコンの直下にランチャーを表示する（表示中なら閉じる）。
+  ///
+  /// - Parameter anchor: メニューバーアイコンの位置（スクリーン座標）
+  public func toggleLauncher(below anchor: NSRect) {
+    if isLauncherVisible, !isPickerVisible {
+      hideLauncher(restoringFocus: true)
+      return
+    }
+    pendingAnchor = anchor
+    toggleLauncher()
+  }
+
   // MARK: - Screen Centering
 
-  /// カーソルがあるスクリーンの上部寄りにパネルを配置する。
+  /// `placement` に応じたスクリーンの上部寄りにパネルを配置する。
   private func centerOnScreen() {
-    guard let panel = launcherPanel else { return }
-
-    let mouseNS = NSEvent.mouseLocation
-    let screen =
-      NSScreen.screens.first { NSMouseInRect(mouseNS, $0.frame, false) }
-      ?? NSScreen.main
-    guard let screen else { return }
+    guard let panel = launcherPanel, let screen = targetScreen() else { return }
 
     let vis = screen.visibleFrame
     let x = vis.midX - panel.frame.width / 2
     // 画面上端から約 1/4 の位置に配置（Spotlight 風）
     let y = vis.maxY - vis.height * 0.25 - panel.frame.height / 2
-    panel.setFrameOrigin(NSPoint(x: x, y: y))
+    applyFrame { panel.setFrameOrigin(NSPoint(x: x, y: y)) }
+  }
+
+  /// メニューバーアイコンの直下にパネルを配置する。
+  private func positionBelow(_ anchor: NSRect) {
+    guard let panel = launcherPanel,
+      let screen = NSScreen.screens.first(where: { $0.frame.intersects(anchor) }) ?? NSScreen.main
+    else { return }
+    let origin = Self.origin(below: anchor, size: panel.frame.size, screen: screen.visibleFrame)
+    applyFrame { panel.setFrameOrigin(origin) }
+  }
+
+  /// メニューバーアイコンの直下に置くパネルの原点を返す。
+  ///
+  /// アイコンの中央に揃え、画面の左右端からはみ出す場合は可視領域に収める。
+  /// - Parameters:
+  ///   - anchor: メニューバーアイコンの位置
+  ///   - size: パネルのサイズ
+  ///   - screen: アイコンがあるスクリーンの可視領域
+  /// - Returns: パネルの原点（左下）
+  nonisolated static func origin(below anchor: CGRect, size: CGSize, screen: CGRect) -> CGPoint {
+    let x = min(
+      max(anchor.midX - size.width / 2, screen.minX), max(screen.maxX - size.width, screen.minX))
+    let top = min(anchor.minY, screen.maxY) - 4
+    return CGPoint(x: x, y: top - size.height)
+  }
+
+  /// 配置先のスクリーンを返す。
+  ///
+  /// 作業中のディスプレイを特定できない場合はカーソルのあるスクリーンにフォールバックする。
+  private func targetScreen() -> NSScreen? {
+    if placement == .activeDisplay, let screen = ActiveScreenLocator.activeScreen() {
+      return screen
+    }
+    let mouseNS = NSEvent.mouseLocation
+    return NSScreen.screens.first { NSMouseInRect(mouseNS, $0.frame, false) }
+      ?? NSScreen.main
+  }
+
+  // MARK: - Position Persistence
+
+  /// 記憶した位置へパネルを配置する。
+  ///
+  /// - Returns: 配置した場合は `true`（記憶が無効・位置が未保存・画面外の場合は `false`）
+  private func restoreSavedPosition() -> Bool {
+    guard placement == .rememberedPosition, let savedPosition, let panel = launcherPanel else {
+      return false
+    }
+    guard
+      let origin = Self.validatedOrigin(
+        for: savedPosition,
+        size: panel.frame.size,
+        screens: NSScreen.screens.map(\.visibleFrame))
+    else { return false }
+    applyFrame { panel.setFrameOrigin(origin) }
+    return true
+  }
+
+  /// 記憶した位置を現在のスクリーン構成で検証し、パネルの原点を返す。
+  ///
+  /// ウィンドウ上端中央がいずれのスクリーンにも含まれない場合（モニターの取り外しなど）は
+  /// nil を返す。含まれる場合は、そのスクリーンの可視領域に収まるよう位置を補正する。
+  /// - Parameters:
+  ///   - position: 記憶した位置
+  ///   - size: パネルのサイズ
+  ///   - screens: 各スクリーンの可視領域
+  /// - Returns: パネルの原点（左下）。復元できない場合は nil
+  nonisolated static func validatedOrigin(
+    for position: WindowPosition, size: CGSize, screens: [CGRect]
+  ) -> CGPoint? {
+    let anchor = CGPoint(x: position.x + size.width / 2, y: position.top - 1)
+    guard let screen = screens.first(where: { $0.contains(anchor) }) else { return nil }
+    let x = min(max(position.x, screen.minX), max(screen.maxX - size.width, screen.minX))
+    let top = min(max(position.top, screen.minY + size.height), screen.maxY)
+    return CGPoint(x: x, y: top - size.height)
+  }
+
+  /// パネルの移動通知の監視を開始する（初回表示時に 1 度だけ登録する）。
+  ///
+  /// `applyFrame` 中の移動を除外するため、通知はキューを介さず投稿元（メインスレッド）で
+  /// 同期的に受け取り、`isApplyingFrame` をその場で判定する。
+  private func startMoveObserver() {
+    guard moveObserver == nil, let panel = launcherPanel else { return }
+    moveObserver = NotificationCenter.default.addObserver(
+      forName: NSWindow.didMoveNotification,
+      object: panel,
+      queue: nil
+    ) { [weak self] _ in
+      MainActor.assumeIsolated {
+        self?.panelDidMove()
+      }
+    }
+  }
+
+  /// ユーザー操作による移動をデバウンスして通知する。
+  private func panelDidMove() {
+    guard !isApplyingFrame, placement == .rememberedPosition, isLauncherVisible,
+      let frame = launcherPanel?.frame
+    else { return }
+    let position = WindowPosition(x: frame.minX, top: frame.maxY)
+    savedPosition = position
+    positionSaveTask?.cancel()
+    positionSaveTask = Task { [weak self, positionSaveDebounce] in
+      try? await Task.sleep(for: positionSaveDebounce)
+      guard !Task.isCancelled else { return }
+      self?.onPositionChanged?(position)
+    }
+  }
+
+  /// プログラムからのフレーム変更を移動イベントとして扱わないように実行する。
+  private func applyFrame(_ change: () -> Void) {
+    isApplyingFrame = true
+    change()
+    isApplyingFrame = false
   }
 
   /// ランチャーを非表示にする。
-  public func hideLauncher() {
+  ///
+  /// - Parameter restoringFocus: ランチャーを開く前のアプリを前面に戻すか。
+  ///   項目を起動した場合や他のアプリへ切り替えた場合は戻さない。
+  public func hideLauncher(restoringFocus: Bool = false) {
+    let wasVisible = isLauncherVisible
     isLauncherVisible = false
     stopKeyEventMonitor()
     stopDismissMonitors()
     launcherPanel?.orderOut(nil)
+    if restoringFocus, restoresFocusOnHide, let app = previousApplication, !app.isTerminated {
+      app.activate()
+    }
+    if wasVisible {
+      onHideLauncher?()
+    }
   }
 
   // MARK: - Dismiss Monitors
@@ -139,7 +329,7 @@ public final class WindowManager {
       matching: [.leftMouseDown, .rightMouseDown]
     ) { [weak self] _ in
       Task { @MainActor in
-        self?.autoDismiss()
+        self?.handleFocusLoss()
       }
     }
 
@@ -157,11 +347,30 @@ public final class WindowManager {
         app.bundleIdentifier != Bundle.main.bundleIdentifier
       else { return }
       Task { @MainActor in
-        self?.autoDismiss()
+        self?.handleFocusLoss()
       }
     }
   }
 
+  /// モニターがフォーカスの喪失を検知したときに、設定に応じて自動非表示する。
+  ///
+  /// 猶予時間がある場合は経過後にもう一度確認し、その間にランチャーへ戻っていれば閉じない。
+  func handleFocusLoss() {
+    guard isLauncherVisible, hidesOnFocusLoss else { return }
+    guard focusLossGracePeriod > .zero else {
+      autoDismiss()
+      return
+    }
+    guard pendingAutoDismiss == nil else { return }
+    pendingAutoDismiss = Task { [weak self, focusLossGracePeriod] in
+      try? await Task.sleep(for: focusLossGracePeriod)
+      guard !Task.isCancelled, let self else { return }
+      self.pendingAutoDismiss = nil
+      if NSApp?.isActive == true, self.launcherPanel?.isKeyWindow == true { return }
+      self.autoDismiss()
+    }
+  }
+
   /// モニター検知による自動非表示。
   private func autoDismiss() {
     guard isLauncherVisible else { return }
@@ -196,6 +405,8 @@ public final class WindowManager {
 
   /// 自動非表示監視を停止する。
   private func stopDismissMonitors() {
+    pendingAutoDismiss?.cancel()
+    pendingAutoDismiss = nil
     if let monitor = clickMonitor {
       NSEvent.removeMonitor(monitor)
       clickMonitor = nil
@@ -226,10 +437,31 @@ public final class WindowManager {
   /// - Returns: 計算されたウィンドウ高さ。`minHeight` 以上 `maxHeight` 以下。
   public func heightForResults(count: Int) -> CGFloat {
     let effectiveCount = max(0, count)
-    let computed = Self.minHeight + CGFloat(effectiveCount) * Self.rowHeight
+    let computed = Self.minHeight + CGFloat(effectiveCount) * resultRowHeight
     return min(computed, Self.maxHeight)
   }
 
+  /// 外観設定のウィンドウ幅・行の高さを反映し、表示中のパネルをリサイズする。
+  ///
+  /// - Parameters:
+  ///   - width: ウィンドウ幅
+  ///   - rowHeight: 検索結果 1 行あたりの高さ
+  ///   - resultCount: 現在の検索結果の件数
+  public func applyAppearance(width: CGFloat, rowHeight: CGFloat, resultCount: Int) {
+    windowWidth = width
+    resultRowHeight = rowHeight
+    guard let panel = launcherPanel, panel.frame.width != width else {
+      resizeForResults(count: resultCount)
+      return
+    }
+    var frame = panel.frame
+    // 中央を保ったまま幅を変える
+    frame.origin.x += (frame.width - width) / 2
+    frame.size.width = width
+    applyFrame { panel.setFrame(frame, display: true, animate: false) }
+    resizeForResults(count: resultCount)
+  }
+
   /// 検索結果の件数に応じてウィンドウをリサイズする。
   ///
   /// パネルが設定されている場合、フレームを更新して即座に反映する。
@@ -243,6 +475,6 @@ public final class WindowManager {
     let heightDelta = newHeight - frame.height
     frame.size.height = newHeight
     frame.origin.y -= heightDelta  // macOS は下端が原点のためリサイズ時に y を調整
-    panel.setFrame(frame, display: true, animate: false)
+    applyFrame { panel.setFrame(frame, display: true, animate: false) }
   }
 }
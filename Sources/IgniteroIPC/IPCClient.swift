@@ -0,0 +1,38 @@
+import Foundation
+
+/// 起動中のアプリへリクエストを送るクライアント。
+///
+/// 1 リクエストごとに接続し、レスポンスを 1 行受け取って切断する。
+public struct IPCClient: Sendable {
+  private let socketPath: String
+
+  /// - Parameter socketPath: 接続先のソケット
+  public init(socketPath: String = IPCSocket.defaultPath) {
+    self.socketPath = socketPath
+  }
+
+  /// リクエストを送り、レスポンスを返す。
+  ///
+  /// - Throws: アプリが起動していない場合は `IPCError.notRunning`
+  public func send(_ request: IPCRequest) throws -> IPCResponse {
+    var address = try IPCSocket.address(for: socketPath)
+    let fd = socket(AF_UNIX, SOCK_STREAM, 0)
+    guard fd >= 0 else { throw IPCError.socketFailed(errno: errno) }
+    defer { close(fd) }
+
+    let connected = withUnsafePointer(to: &address) {
+      $0.withMemoryRebound(to: sockaddr.self, capacity: 1) {
+        connect(fd, $0, socklen_t(MemoryLayout<sockaddr_un>.size))
+      }
+    }
+    guard connected == 0 else { throw IPCError.notRunning(socketPath) }
+
+    try IPCSocket.write(try IPCCodec.encode(request), to: fd)
+    let data = try IPCSocket.readLine(from: fd)
+    do {
+      return try IPCCodec.decode(IPCResponse.self, from: data)
+    } catch {
+      throw IPCError.invalidResponse
+    }
+  }
+}
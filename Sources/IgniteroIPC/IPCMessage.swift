@@ -0,0 +1,347 @@
+import Foundation
+
+// MARK: - コマンド
+
+/// `ignitero` CLI から起動中のアプリへ送るコマンド
+public enum IPCCommand: String, Codable, Sendable, CaseIterable {
+  /// ランチャーを表示する
+  case show
+  /// キャッシュを再構築する
+  case refresh
+  /// 検索して結果を返す（引数: クエリ）
+  case search
+  /// 検索語が空のときにランチャーが表示する既定の項目を返す
+  case getDefaultItems = "get_default_items"
+  /// パスを開く（引数: パス）
+  case open
+  /// 設定プロファイルの一覧を返す
+  case listProfiles = "list_profiles"
+  /// 設定プロファイルを切り替える（引数: プロファイル名）
+  case switchProfile = "switch_profile"
+  /// 設定ファイルのバックアップから復元する（引数: 世代。省略時は最新の 1）
+  case restoreSettingsBackup = "restore_settings_backup"
+  /// 過去の検索語から入力中の検索語の候補を返す（引数: 入力中の検索語）
+  case getQuerySuggestions = "get_query_suggestions"
+  /// テキストをクリップボードにコピーする（引数: コピーするテキスト）
+  case copyToClipboard = "copy_to_clipboard"
+  /// 表示中のディスプレイの倍率に合うアイコン PNG のパスを返す（引数: パス、`size`: ポイントサイズ）
+  case getIcon = "get_icon"
+  /// アプリのアイコンを変換済みにし、128px の PNG のパスを返す（引数: パス）
+  case ensureIcon = "ensure_icon"
+  /// 接続中のディスプレイの倍率と大きさを返す
+  case displayInfo = "display_info"
+  /// アクセシビリティ権限が許可されているかを返す
+  case getPermissionStatus = "get_permission_status"
+  /// アクセシビリティ権限を求めるシステムのダイアログを表示し、現在の状態を返す
+  case requestAccessibilityPermission = "request_accessibility_permission"
+  /// 検索候補ごとのスコアの内訳を返す（設定の `search_debug_enabled` が有効な場合のみ）
+  case searchDebug = "search_debug"
+  /// キャッシュの全項目をファイルに書き出す（引数: 書き出し先のパス、`format`: json / csv）
+  case exportCache = "export_cache"
+  /// キャッシュの DB とアイコンキャッシュを削除し、空の状態からスキャンし直す
+  case resetCache = "reset_cache"
+  /// バックグラウンドのインデックス作成（自動更新・実行中のスキャン）を一時停止する
+  case pauseIndexing = "pause_indexing"
+  /// 一時停止したインデックス作成を再開する
+  case resumeIndexing = "resume_indexing"
+  /// Dock にアイコンを表示するかを切り替えて保存する（引数: regular / accessory）
+  case setActivationPolicy = "set_activation_policy"
+}
+
+// MARK: - リクエスト / レスポンス
+
+/// CLI からアプリへのリクエスト
+public struct IPCRequest: Codable, Sendable, Equatable {
+  public let command: IPCCommand
+  /// 検索クエリ・開くパス・プロファイル名・入力中の検索語・コピーするテキスト
+  public let argument: String?
+  /// `get_icon` で表示するアイコンのポイントサイズ（省略時は検索結果と同じ 32）
+  public let size: Int?
+  /// `export_cache` の形式（`json` / `csv`。省略時は書き出し先の拡張子から決める）
+  public let format: String?
+
+  public init(
+    command: IPCCommand, argument: String? = nil, size: Int? = nil, format: String? = nil
+  ) {
+    self.command = command
+    self.argument = argument
+    self.size = size
+    self.format = format
+  }
+}
+
+/// `search` の結果 1 件
+public struct IPCSearchItem: Codable, Sendable, Equatable {
+  public let name: String
+  public let path: String
+  /// 結果の種別（app / directory / command など）
+  public let kind: String
+  public let score: Double
+  /// 名前のうち検索語に一致した文字の位置（Character 単位の昇順。強調表示用）
+  public let matchedIndices: [Int]?
+  /// 見出しの区分（apps / projects / commands / actions）
+  public let group: String?
+
+  public init(
+    name: String, path: String, kind: String, score: Double, matchedIndices: [Int]? = nil,
+    group: String? = nil
+  ) {
+    self.name = name
+    self.path = path
+    self.kind = kind
+    self.score = score
+    self.matchedIndices = matchedIndices
+    self.group = group
+  }
+}
+
+/// `search` の見出し 1 つ分（`results` の先頭から順に `count` 件ずつが各見出しに属する）
+public struct IPCSearchSection: Codable, Sendable, Equatable {
+  /// 区分（apps / projects / commands / actions）
+  public let group: String
+  /// 見出しに表示する名前
+  public let title: String
+  public let count: Int
+
+  public init(group: String, title: String, count: Int) {
+    self.group = group
+    self.title = title
+    self.count = count
+  }
+}
+
+/// `search_debug` の結果 1 件（スコアはいずれも小さいほど上位）
+public struct IPCSearchDebugItem: Codable, Sendable, Equatable {
+  public let name: String
+  public let path: String
+  /// 結果の種別（app / directory / command など）
+  public let kind: String
+  /// 名前のファジー一致のスコア（0 が完全一致）
+  public let fuzzyScore: Double
+  /// 種別ごとの補正（Git リポジトリ・登録ディレクトリの優先度）
+  public let categoryAdjustment: Double
+  /// 選択履歴による補正
+  public let historyBoost: Double
+  /// 別名に一致した場合のスコア
+  public let aliasScore: Double?
+  /// 並べ替えに使う最終スコア
+  public let finalScore: Double
+  /// お気に入り（スコアに関わらず先頭に並ぶ）か
+  public let favorite: Bool
+
+  public init(
+    name: String, path: String, kind: String, fuzzyScore: Double, categoryAdjustment: Double,
+    historyBoost: Double, aliasScore: Double?, finalScore: Double, favorite: Bool
+  ) {
+    self.name = name
+    self.path = path
+    self.kind = kind
+    self.fuzzyScore = fuzzyScore
+    self.categoryAdjustment = categoryAdjustment
+    self.historyBoost = historyBoost
+    self.aliasScore = aliasScore
+    self.finalScore = finalScore
+    self.favorite = favorite
+  }
+}
+
+/// `display_info` の結果 1 件
+public struct IPCDisplay: Codable, Sendable, Equatable {
+  public let name: String
+  /// 倍率（Retina は 2）
+  public let scale: Double
+  /// 幅・高さ（ポイント）
+  public let width: Double
+  public let height: Double
+  /// ランチャーを開くディスプレイか
+  public let active: Bool
+
+  public init(name: String, scale: Double, width: Double, height: Double, active: Bool) {
+    self.name = name
+    self.scale = scale
+    self.width = width
+    self.height = height
+    self.active = active
+  }
+}
+
+/// `get_permission_status` / `request_accessibility_permission` の結果
+public struct IPCPermissionStatus: Codable, Sendable, Equatable {
+  /// アクセシビリティ権限が許可されているか
+  public let accessibility: Bool
+
+  public init(accessibility: Bool) {
+    self.accessibility = accessibility
+  }
+}
+
+/// アプリから CLI へのレスポンス
+public struct IPCResponse: Codable, Sendable, Equatable {
+  public let ok: Bool
+  /// 失敗した理由
+  public let error: String?
+  /// `search` の結果
+  public let results: [IPCSearchItem]?
+  /// `search` の見出し（設定で見出しごとにまとめる場合のみ。`results` はこの順に並ぶ）
+  public let sections: [IPCSearchSection]?
+  /// `list_profiles` の結果（プロファイル名）
+  public let profiles: [String]?
+  /// `list_profiles` の結果（使用中のプロファイル名）
+  public let activeProfile: String?
+  /// `get_query_suggestions` の結果（よく使う順の検索語）
+  public let suggestions: [String]?
+  /// `get_icon` / `ensure_icon` の結果（PNG のパス）
+  public let iconPath: String?
+  /// `display_info` の結果
+  public let displays: [IPCDisplay]?
+  /// `get_permission_status` / `request_accessibility_permission` の結果
+  public let permissions: IPCPermissionStatus?
+  /// `search_debug` の結果（並び順）
+  public let debugResults: [IPCSearchDebugItem]?
+  /// `export_cache` で書き出した項目の数
+  public let exportedCount: Int?
+
+  public init(
+    ok: Bool, error: String? = nil, results: [IPCSearchItem]? = nil,
+    profiles: [String]? = nil, activeProfile: String? = nil, suggestions: [String]? = nil,
+    iconPath: String? = nil, displays: [IPCDisplay]? = nil,
+    permissions: IPCPermissionStatus? = nil, debugResults: [IPCSearchDebugItem]? = nil,
+    exportedCount: Int? = nil, sections: [IPCSearchSection]? = nil
+  ) {
+    self.ok = ok
+    self.error = error
+    self.results = results
+    self.sections = sections
+    self.profiles = profiles
+    self.activeProfile = activeProfile
+    self.suggestions = suggestions
+    self.iconPath = iconPath
+    self.displays = displays
+    self.permissions = permissions
+    self.debugResults = debugResults
+    self.exportedCount = exportedCount
+  }
+
+  /// 成功（結果なし）
+  public static let success = IPCResponse(ok: true)
+
+  /// 失敗
+  public static func failure(_ message: String) -> IPCResponse {
+    IPCResponse(ok: false, error: message)
+  }
+}
+
+// MARK: - フレーミング
+
+/// 1 メッセージを 1 行の JSON として送受信する。
+public enum IPCCodec {
+  /// 1 メッセージの最大サイズ（検索結果を含むレスポンスでも十分な 1MB）
+  public static let maxMessageSize = 1 << 20
+
+  /// メッセージを改行終端の JSON にエンコードする。
+  public static func encode(_ message: some Encodable) throws -> Data {
+    let encoder = JSONEncoder()
+    encoder.outputFormatting = .sortedKeys
+    var data = try encoder.encode(message)
+    data.append(UInt8(ascii: "\n"))
+    return data
+  }
+
+  /// 改行終端の JSON をデコードする。
+  public static func decode<Message: Decodable>(_ type: Message.Type, from data: Data) throws
+    -> Message
+  {
+    let line = data.split(separator: UInt8(ascii: "\n"), maxSplits: 1).first ?? Data()
+    return try JSONDecoder().decode(type, from: Data(line))
+  }
+}
+
+// MARK: - ソケット
+
+/// アプリが待ち受ける Unix ドメインソケット
+public enum IPCSocket {
+  /// 既定のソケットパス（`~/.config/ignitero-launcher/ignitero.sock`）
+  public static var defaultPath: String {
+    FileManager.default.homeDirectoryForCurrentUser
+      .appendingPathComponent(".config/ignitero-launcher/ignitero.sock").path
+  }
+
+  /// パスから `sockaddr_un` を作成する。
+  ///
+  /// - Throws: パスが `sun_path` に収まらない場合は `IPCError.socketPathTooLong`
+  public static func address(for path: String) throws -> sockaddr_un {
+    var address = sockaddr_un()
+    address.sun_family = sa_family_t(AF_UNIX)
+    let bytes = Array(path.utf8)
+    let capacity = MemoryLayout.size(ofValue: address.sun_path)
+    guard bytes.count < capacity else { throw IPCError.socketPathTooLong(path) }
+    withUnsafeMutableBytes(of: &address.sun_path) { buffer in
+      buffer.copyBytes(from: bytes)
+    }
+    address.sun_len = UInt8(MemoryLayout<sockaddr_un>.size)
+    return address
+  }
+
+  /// ソケットから改行までを読む（相手が閉じた場合はそこまで）。
+  ///
+  /// - Throws: `IPCCodec.maxMessageSize` を超えた場合は `IPCError.messageTooLarge`
+  public static func readLine(from fd: Int32) throws -> Data {
+    var data = Data()
+    var buffer = [UInt8](repeating: 0, count: 4096)
+    while !data.contains(UInt8(ascii: "\n")) {
+      let count = read(fd, &buffer, buffer.count)
+      if count < 0 {
+        if errno == EINTR { continue }
+        throw IPCError.socketFailed(errno: errno)
+      }
+      if count == 0 { break }
+      data.append(contentsOf: buffer[0..<count])
+      guard data.count <= IPCCodec.maxMessageSize else { throw IPCError.messageTooLarge }
+    }
+    return data
+  }
+
+  /// データをすべて書き込む。
+  public static func write(_ data: Data, to fd: Int32) throws {
+    try data.withUnsafeBytes { buffer in
+      var offset = 0
+      while offset < buffer.count {
+        let count = Darwin.write(fd, buffer.baseAddress! + offset, buffer.count - offset)
+        if count < 0 {
+          if errno == EINTR { continue }
+          throw IPCError.socketFailed(errno: errno)
+        }
+        offset += count
+      }
+    }
+  }
+}
+
+// MARK: - エラー
+
+/// IPC のエラー
+public enum IPCError: Error, LocalizedError, Equatable {
+  /// ソケットパスが長すぎる
+  case socketPathTooLong(String)
+  /// ソケット操作に失敗した
+  case socketFailed(errno: Int32)
+  /// アプリが起動していない（ソケットに接続できない）
+  case notRunning(String)
+  /// 別のプロセスが同じソケットで待ち受けている
+  case alreadyRunning(String)
+  /// メッセージが大きすぎる
+  case messageTooLarge
+  /// 応答を解釈できない
+  case invalidResponse
+
+  public var errorDescription: String? {
+    switch self {
+    case .socketPathTooLong(let path): "Socket path is too long: \(path)"
+    case .socketFailed(let code): "Socket error: \(String(cString: strerror(code)))"
+    case .notRunning(let path): "Ignitero Launcher is not running (no socket at \(path))"
+    case .alreadyRunning(let path): "Another Ignitero Launcher is listening at \(path)"
+    case .messageTooLarge: "Message exceeds \(IPCCodec.maxMessageSize) bytes"
+    case .invalidResponse: "Invalid response from Ignitero Launcher"
+    }
+  }
+}
@@ -9,98 +9,94 @@ let sharedCoordinator = AppCoordinator()
 // MARK: - AppDelegate
 
 @MainActor
-final class IgniteroAppDelegate: NSObject, NSApplicationDelegate {
+final class IgniteroAppDelegate: NSObject, NSApplicationDelegate, NSWindowDelegate {
+  private var statusItemController: StatusItemController?
+  private var settingsWindow: NSWindow?
+
   func applicationDidFinishLaunching(_ notification: Notification) {
+    CrashReporter.install { [weak self] in
+      sharedCoordinator.globalShortcut.teardown()
+      self?.statusItemController?.remove()
+    }
     NSApplication.shared.setActivationPolicy(.accessory)
+    statusItemController = StatusItemController(coordinator: sharedCoordinator)
+    sharedCoordinator.menuBarActions.onOpenSettings = { [weak self] in
+      self?.showSettingsWindow()
+    }
     Task { @MainActor in
       await sharedCoordinator.start()
+      CrashReporter.presentPendingReport()
     }
   }
 
-  func applicationWillTerminate(_ notification: Notification) {
-    sharedCoordinator.shutdown()
+  /// Dock のアイコンをクリックしたときはランチャーを表示する（Dock に表示する設定の場合）。
+  ///
+  /// アクティブでない状態からのクリックは `applicationDidBecomeActive(_:)` で表示済みのため、
+  /// 表示中なら何もしない。
+  func applicationShouldHandleReopen(
+    _ sender: NSApplication, hasVisibleWindows flag: Bool
+  ) -> Bool {
+    if !sharedCoordinator.windowManager.isLauncherVisible {
+      sharedCoordinator.menuBarActions.showWindow()
+    }
+    return false
   }
-}
-
-// MARK: - App
-
-@main
-struct IgniteroApp: App {
-  @NSApplicationDelegateAdaptor(IgniteroAppDelegate.self) var appDelegate
-  @Environment(\.openWindow) private var openWindow
-
-  var body: some Scene {
-    MenuBarExtra {
-      Button("ウィンドウを表示") {
-        sharedCoordinator.menuBarActions.showWindow()
-      }
-      .keyboardShortcut("o")
 
-      Button("キャッシュを再構築") {
-        Task {
-          await sharedCoordinator.rebuildCacheAndReload()
-        }
-      }
+  /// ⌘Tab などでアクティブになったときも、ウィンドウがなければランチャーを表示する。
+  func applicationDidBecomeActive(_ notification: Notification) {
+    sharedCoordinator.handleDidBecomeActive(hasVisibleWindows: settingsWindow?.isVisible == true)
+  }
 
-      Divider()
+  func applicationWillTerminate(_ notification: Notification) {
+    sharedCoordinator.shutdown()
+  }
 
-      Button("設定...") {
-        NSApp.activate(ignoringOtherApps: true)
-        openWindow(id: "settings")
-      }
-      .keyboardShortcut(",")
+  func application(_ application: NSApplication, open urls: [URL]) {
+    urls.forEach(sharedCoordinator.handleDeepLink)
+  }
 
-      Divider()
+  // MARK: - 設定ウィンドウ
 
-      Button("終了") {
-        NSApplication.shared.terminate(nil)
-      }
-      .keyboardShortcut("q")
-    } label: {
-      MenuBarLabel()
-        .onChange(of: sharedCoordinator.menuBarActions.isSettingsOpen) { _, isOpen in
-          if isOpen {
-            NSApp.activate(ignoringOtherApps: true)
-            openWindow(id: "settings")
-            sharedCoordinator.menuBarActions.isSettingsOpen = false
-          }
-        }
-    }
+  private func showSettingsWindow() {
+    let window = settingsWindow ?? makeSettingsWindow()
+    settingsWindow = window
+    // 言語の設定を変えた後に開き直した場合もタイトルを合わせる
+    window.title = sharedCoordinator.menuBarActions.language.text(.settingsWindowTitle)
+    NSApp.activate(ignoringOtherApps: true)
+    window.makeKeyAndOrderFront(nil)
+  }
 
-    Window("設定 - Ignitero", id: "settings") {
-      SettingsView(viewModel: sharedCoordinator.settingsViewModel)
-    }
-    .defaultSize(width: 520, height: 400)
+  private func makeSettingsWindow() -> NSWindow {
+    let window = NSWindow(
+      contentRect: NSRect(x: 0, y: 0, width: 520, height: 400),
+      styleMask: [.titled, .closable, .miniaturizable, .resizable],
+      backing: .buffered, defer: false)
+    window.title = sharedCoordinator.menuBarActions.language.text(.settingsWindowTitle)
+    window.contentViewController = NSHostingController(
+      rootView: SettingsView(viewModel: sharedCoordinator.settingsViewModel))
+    window.setContentSize(NSSize(width: 520, height: 400))
+    window.isReleasedWhenClosed = false
+    window.delegate = self
+    window.center()
+    return window
   }
 
-  fileprivate static func loadMenuBarIcon() -> NSImage? {
-    guard let bundlePath = Bundle.main.resourcePath else { return nil }
-    let url2x = URL(fileURLWithPath: bundlePath).appendingPathComponent("MenuBarIcon@2x.png")
-    let url1x = URL(fileURLWithPath: bundlePath).appendingPathComponent("MenuBarIcon.png")
-    let url = FileManager.default.fileExists(atPath: url2x.path) ? url2x : url1x
-    guard let image = NSImage(contentsOf: url) else { return nil }
-    image.size = NSSize(width: 18, height: 18)
-    image.isTemplate = false
-    return image
+  func windowWillClose(_ notification: Notification) {
+    sharedCoordinator.menuBarActions.closeSettings()
   }
 }
 
-// MARK: - MenuBarLabel
+// MARK: - App
 
-/// メニューバーアイコン。起動処理完了までローディングアニメーションを表示する。
-private struct MenuBarLabel: View {
-  private var isLoading: Bool {
-    !sharedCoordinator.isReady || sharedCoordinator.cacheBootstrap.isScanning
-  }
+/// メニューバーアイコンと設定ウィンドウは AppDelegate が AppKit で管理する
+/// （左クリックでランチャー、右クリックでメニューと操作を分けるため）。
+@main
+struct IgniteroApp: App {
+  @NSApplicationDelegateAdaptor(IgniteroAppDelegate.self) var appDelegate
 
-  var body: some View {
-    if isLoading {
-      Image(systemName: "arrow.trianglehead.2.counterclockwise")
-        .symbolEffect(.rotate, isActive: true)
-    } else if let icon = IgniteroApp.loadMenuBarIcon() {
-      Image(nsImage: icon)
-    } else {
-      Image(systemName: "magnifyingglass")
+  var body: some Scene {
+    Settings {
+      EmptyView()
     }
   }
 }
@@ -0,0 +1,74 @@
+import AppKit
+import IgniteroCore
+import IgniteroIPC
+
+// AppleScript（ショートカットの「AppleScript を実行」を含む）から呼び出すコマンド。
+// 用語は Resources/Ignitero.sdef で定義し、引数と結果の変換は ScriptCommandRequest、
+// 処理は ignitero CLI と同じ AppCoordinator.handleIPCRequest に委譲する。
+
+/// スクリプトコマンドの共通処理。
+///
+/// 処理は非同期のため AppleScript の実行を一時停止し、完了後に結果を返して再開する。
+class IgniteroScriptCommand: NSScriptCommand {
+  /// 送るリクエスト（引数が不正な場合は nil）
+  var request: IPCRequest? { nil }
+
+  /// レスポンスから AppleScript へ返す値を作る。
+  func scriptResult(from response: IPCResponse) -> Any? { nil }
+
+  override func performDefaultImplementation() -> Any? {
+    guard let request else {
+      scriptErrorNumber = errAEParamMissed
+      scriptErrorString = "Missing parameter"
+      return nil
+    }
+    suspendExecution()
+    // スクリプトコマンドはメインスレッドで実行され、再開するまで Cocoa Scripting が保持する
+    nonisolated(unsafe) let command = self
+    MainActor.assumeIsolated {
+      Task {
+        let response = await sharedCoordinator.handleIPCRequest(request)
+        command.finish(with: response)
+      }
+    }
+    return nil
+  }
+
+  private func finish(with response: IPCResponse) {
+    if response.ok {
+      resumeExecution(withResult: scriptResult(from: response))
+    } else {
+      scriptErrorNumber = errAEEventFailed
+      scriptErrorString = response.error
+      resumeExecution(withResult: nil)
+    }
+  }
+}
+
+/// `show launcher`
+@objc(IgniteroShowCommand)
+final class IgniteroShowCommand: IgniteroScriptCommand {
+  override var request: IPCRequest? { IPCRequest(command: .show) }
+}
+
+/// `search launcher "query"`
+@objc(IgniteroSearchCommand)
+final class IgniteroSearchCommand: IgniteroScriptCommand {
+  override var request: IPCRequest? { ScriptCommandRequest.search(directParameter) }
+
+  override func scriptResult(from response: IPCResponse) -> Any? {
+    ScriptCommandRequest.searchResultRecords(from: response)
+  }
+}
+
+/// `open item "path"`
+@objc(IgniteroOpenCommand)
+final class IgniteroOpenCommand: IgniteroScriptCommand {
+  override var request: IPCRequest? { ScriptCommandRequest.open(directParameter) }
+}
+
+/// `refresh cache`
+@objc(IgniteroRefreshCommand)
+final class IgniteroRefreshCommand: IgniteroScriptCommand {
+  override var request: IPCRequest? { IPCRequest(command: .refresh) }
+}
@@ -0,0 +1,158 @@
+import AppKit
+import IgniteroCore
+
+/// メニューバーアイコン（NSStatusItem）を管理するクラス。
+///
+/// - 左クリック: アイコンの直下にランチャーを表示する（表示中なら閉じる）
+/// - 右クリック / control + クリック: `MenuBarActions.menuItems` のメニューを表示する
+///
+/// 起動処理やキャッシュ更新の間はローディングアイコンに切り替え、
+/// アップデートがある場合はアイコンにバッジを付ける。
+@MainActor
+final class StatusItemController: NSObject {
+  private let statusItem = NSStatusBar.system.statusItem(withLength: NSStatusItem.squareLength)
+  private let coordinator: AppCoordinator
+
+  init(coordinator: AppCoordinator) {
+    self.coordinator = coordinator
+    super.init()
+    guard let button = statusItem.button else { return }
+    button.target = self
+    button.action = #selector(statusItemClicked(_:))
+    button.sendAction(on: [.leftMouseUp, .rightMouseUp])
+    observeIconState()
+  }
+
+  /// メニューバーからアイコンを取り除く（異常終了する前の後始末）。
+  func remove() {
+    NSStatusBar.system.removeStatusItem(statusItem)
+  }
+
+  // MARK: - クリック
+
+  @objc private func statusItemClicked(_ sender: NSStatusBarButton) {
+    guard let event = NSApp.currentEvent else { return }
+    if event.type == .rightMouseUp || event.modifierFlags.contains(.control) {
+      showMenu()
+    } else if let window = sender.window {
+      coordinator.menuBarActions.toggleWindow(below: window.frame)
+    }
+  }
+
+  /// メニューを表示する。
+  ///
+  /// 左クリックでランチャーを開けるよう、表示する間だけ `statusItem.menu` を設定する。
+  private func showMenu() {
+    statusItem.menu = makeMenu(coordinator.menuBarActions.menuItems)
+    statusItem.button?.performClick(nil)
+    statusItem.menu = nil
+  }
+
+  private func makeMenu(_ items: [MenuBarItem]) -> NSMenu {
+    let menu = NSMenu()
+    menu.autoenablesItems = false
+    for item in items {
+      if item.startsSection, !menu.items.isEmpty {
+        menu.addItem(.separator())
+      }
+      menu.addItem(makeMenuItem(item))
+    }
+    return menu
+  }
+
+  private func makeMenuItem(_ item: MenuBarItem) -> NSMenuItem {
+    let menuItem = NSMenuItem(title: item.title, action: nil, keyEquivalent: "")
+    menuItem.isEnabled = item.isEnabled
+    menuItem.state = item.isChecked ? .on : .off
+    if let submenu = item.submenu {
+      menuItem.submenu = makeMenu(submenu)
+    } else if item.isEnabled {
+      menuItem.target = self
+      menuItem.action = #selector(menuItemSelected(_:))
+      menuItem.representedObject = MenuAction(item.action)
+    }
+    return menuItem
+  }
+
+  @objc private func menuItemSelected(_ sender: NSMenuItem) {
+    (sender.representedObject as? MenuAction)?.perform()
+  }
+
+  // MARK: - アイコン
+
+  /// キャッシュ更新・アップデート確認による状態の変化を監視してアイコンを更新する。
+  private func observeIconState() {
+    let state = withObservationTracking {
+      coordinator.menuBarIconState
+    } onChange: { [weak self] in
+      Task { @MainActor in self?.observeIconState() }
+    }
+    updateIcon(state)
+  }
+
+  private func updateIcon(_ state: MenuBarIconState) {
+    guard let button = statusItem.button else { return }
+    let language = coordinator.menuBarActions.language
+    button.toolTip = state.toolTip(in: language)
+    switch state {
+    case .refreshing:
+      button.image = NSImage(
+        systemSymbolName: "arrow.trianglehead.2.counterclockwise",
+        accessibilityDescription: language.text(.loading))
+    case .idle:
+      button.image = Self.baseIcon
+    case .updateAvailable:
+      button.image = Self.badged(Self.baseIcon)
+    }
+  }
+
+  private static var baseIcon: NSImage? {
+    menuBarIcon
+      ?? NSImage(systemSymbolName: "magnifyingglass", accessibilityDescription: "Ignitero")
+  }
+
+  /// アイコンの右上にアップデートありのバッジ（赤い点）を重ねる。
+  private static func badged(_ icon: NSImage?) -> NSImage? {
+    guard let icon else { return nil }
+    let size = NSSize(width: 18, height: 18)
+    let image = NSImage(size: size, flipped: false) { rect in
+      icon.draw(in: rect)
+      let diameter: CGFloat = 7
+      NSColor.systemRed.setFill()
+      NSBezierPath(
+        ovalIn: NSRect(
+          x: rect.maxX - diameter, y: rect.maxY - diameter, width: diameter, height: diameter)
+      ).fill()
+      return true
+    }
+    image.isTemplate = false
+    return image
+  }
+
+  private static let menuBarIcon: NSImage? = {
+    guard let bundlePath = Bundle.main.resourcePath else { return nil }
+    let url2x = URL(fileURLWithPath: bundlePath).appendingPathComponent("MenuBarIcon@2x.png")
+    let url1x = URL(fileURLWithPath: bundlePath).appendingPathComponent("MenuBarIcon.png")
+    let url = FileManager.default.fileExists(atPath: url2x.path) ? url2x : url1x
+    guard let image = NSImage(contentsOf: url) else { return nil }
+    image.size = NSSize(width: 18, height: 18)
+    image.isTemplate = false
+    return image
+  }()
+}
+
+// MARK: - MenuAction
+
+/// `NSMenuItem.representedObject` に保持するメニュー項目のアクション。
+@MainActor
+private final class MenuAction: NSObject {
+  private let action: @MainActor @Sendable () -> Void
+
+  init(_ action: @MainActor @Sendable @escaping () -> Void) {
+    self.action = action
+  }
+
+  func perform() {
+    action()
+  }
+}
@@ -0,0 +1,261 @@
+import Foundation
+import IgniteroIPC
+
+// 起動中の Ignitero Launcher を操作する CLI。
+//
+//   ignitero show                  ランチャーを表示
+//   ignitero refresh               キャッシュを再構築
+//   ignitero reset_cache --yes     キャッシュとアイコンを削除してスキャンし直す（お気に入り・履歴も消えるため --yes が必要）
+//   ignitero pause_indexing        バックグラウンドのインデックス作成を一時停止（実行中のスキャンも止める）
+//   ignitero resume_indexing       一時停止したインデックス作成を再開
+//   ignitero set_activation_policy <regular|accessory>  Dock にアイコンを表示するかを切り替え
+//   ignitero search <query> [--json]  検索結果を表示
+//   ignitero get_default_items [--json]  検索語が空のときの既定の項目を表示
+//   ignitero open <path>           アプリ・ディレクトリ・ファイルを開く
+//   ignitero list_profiles         設定プロファイルの一覧を表示（使用中は * 付き）
+//   ignitero switch_profile <name> 設定プロファイルを切り替え
+//   ignitero restore_settings_backup [n]  設定を n 世代前のバックアップから復元
+//   ignitero get_query_suggestions [prefix]  過去の検索語から候補を表示
+//   ignitero copy_to_clipboard <text>  テキストをクリップボードにコピー（- なら標準入力）
+//   ignitero get_icon <path> [size]  表示中のディスプレイの倍率に合うアイコン PNG のパスを表示
+//   ignitero display_info [--json]  接続中のディスプレイの倍率と大きさを表示
+//   ignitero ensure_icon <path>    アプリのアイコンを変換し、128px の PNG のパスを表示
+//   ignitero get_permission_status  アクセシビリティ権限が許可されているかを表示
+//   ignitero request_accessibility_permission  アクセシビリティ権限の許可を求める
+//   ignitero search_debug <query> [--json]  候補ごとのスコアの内訳を表示（設定で有効化が必要）
+//   ignitero export_cache <path> [json|csv]  キャッシュの全項目をファイルに書き出す
+
+let usage = """
+  Usage: ignitero <command> [arguments]
+
+  Commands:
+    show                     Show the launcher window
+    refresh                  Rebuild the app and directory cache
+    reset_cache --yes        Delete cache.db and the icon cache, then rescan from scratch
+                             (also clears favorites and history, so --yes is required)
+    pause_indexing           Pause background indexing and stop a scan in progress
+    resume_indexing          Resume background indexing
+    set_activation_policy <regular|accessory>
+                             Show the app in the Dock and the app switcher (regular)
+                             or only in the menu bar (accessory)
+    search <query> [--json]  Print search results (name and path, or JSON with --json)
+    get_default_items [--json]
+                             Print the items the launcher shows for an empty query
+                             (favorites, recent projects, suggestions and frequent items)
+    open <path>              Open an app, directory or file the way the launcher does
+    list_profiles            List settings profiles (the active one is marked with *)
+    switch_profile <name>    Switch to a settings profile
+    restore_settings_backup [n]
+                             Restore settings from backup n (1 = most recent, the default)
+    get_query_suggestions [prefix]
+                             Print past queries starting with prefix, most used first
+    copy_to_clipboard <text> Copy text to the clipboard (use - to read it from stdin)
+    get_icon <path> [size]   Print the icon PNG for path at size points (default 32),
+                             sized for the scale of the active display
+    display_info [--json]    Print the scale and size of connected displays
+    ensure_icon <path>       Convert the app icon if needed and print its 128px PNG
+    get_permission_status    Print whether the accessibility permission is granted
+    search_debug <query> [--json]
+                             Print the score breakdown of each candidate
+                             (requires search_debug_enabled in settings)
+    request_accessibility_permission
+                             Ask for the accessibility permission (shows the system prompt)
+    export_cache <path> [json|csv]
+                             Write every cached app, directory and terminal target to path
+                             (the format defaults to the file extension, then JSON)
+
+  """
+
+/// 使い方を表示して終了する。
+func exitWithUsage() -> Never {
+  FileHandle.standardError.write(Data(usage.utf8))
+  exit(64)  // EX_USAGE
+}
+
+/// エラーを表示して終了する。
+func fail(_ message: String) -> Never {
+  FileHandle.standardError.write(Data("ignitero: \(message)\n".utf8))
+  exit(1)
+}
+
+var arguments = Array(CommandLine.arguments.dropFirst())
+let wantsJSON = arguments.contains("--json")
+let confirmed = arguments.contains("--yes")
+arguments.removeAll { $0 == "--json" || $0 == "--yes" }
+
+guard let commandName = arguments.first else { exitWithUsage() }
+if commandName == "-h" || commandName == "--help" || commandName == "help" {
+  print(usage, terminator: "")
+  exit(0)
+}
+guard let command = IPCCommand(rawValue: commandName) else { exitWithUsage() }
+let rest = arguments.dropFirst().joined(separator: " ")
+
+let request: IPCRequest
+switch command {
+case .show, .refresh, .pauseIndexing, .resumeIndexing, .listProfiles, .getDefaultItems,
+  .displayInfo, .getPermissionStatus, .requestAccessibilityPermission:
+  request = IPCRequest(command: command)
+case .resetCache:
+  guard confirmed else {
+    fail("reset_cache also deletes favorites and history; run it with --yes to confirm")
+  }
+  request = IPCRequest(command: .resetCache)
+case .search, .searchDebug:
+  guard !rest.isEmpty else { exitWithUsage() }
+  request = IPCRequest(command: command, argument: rest)
+case .open, .ensureIcon:
+  guard !rest.isEmpty else { exitWithUsage() }
+  // 相対パスは CLI を実行したディレクトリを基準に解決する
+  let cwd = URL(fileURLWithPath: FileManager.default.currentDirectoryPath)
+  let path = URL(fileURLWithPath: (rest as NSString).expandingTildeInPath, relativeTo: cwd)
+    .standardizedFileURL.path
+  request = IPCRequest(command: command, argument: path)
+case .setActivationPolicy:
+  guard rest == "regular" || rest == "accessory" else { exitWithUsage() }
+  request = IPCRequest(command: .setActivationPolicy, argument: rest)
+case .switchProfile:
+  guard !rest.isEmpty else { exitWithUsage() }
+  request = IPCRequest(command: .switchProfile, argument: rest)
+case .restoreSettingsBackup:
+  request = IPCRequest(command: .restoreSettingsBackup, argument: rest.isEmpty ? nil : rest)
+case .getQuerySuggestions:
+  request = IPCRequest(command: .getQuerySuggestions, argument: rest)
+case .copyToClipboard:
+  // - なら標準入力を末尾の改行を除いてコピーする（複数行のテキスト向け）
+  let text =
+    rest == "-"
+    ? String(decoding: FileHandle.standardInput.readDataToEndOfFile(), as: UTF8.self)
+      .trimmingCharacters(in: .newlines)
+    : rest
+  guard !text.isEmpty else { exitWithUsage() }
+  request = IPCRequest(command: .copyToClipboard, argument: text)
+case .getIcon:
+  // 末尾の数値はサイズとして扱う（パスに空白があっても区切らない）
+  var words = Array(arguments.dropFirst())
+  let size = words.count > 1 ? words.last.flatMap { Int($0) } : nil
+  if size != nil { words.removeLast() }
+  let target = words.joined(separator: " ")
+  guard !target.isEmpty else { exitWithUsage() }
+  let cwd = URL(fileURLWithPath: FileManager.default.currentDirectoryPath)
+  let path = URL(fileURLWithPath: (target as NSString).expandingTildeInPath, relativeTo: cwd)
+    .standardizedFileURL.path
+  request = IPCRequest(command: .getIcon, argument: path, size: size)
+case .exportCache:
+  // 末尾の json / csv は形式として扱う
+  var words = Array(arguments.dropFirst())
+  let format = words.count > 1 ? words.last.map { $0.lowercased() } : nil
+  let hasFormat = format == "json" || format == "csv"
+  if hasFormat { words.removeLast() }
+  let target = words.joined(separator: " ")
+  guard !target.isEmpty else { exitWithUsage() }
+  let cwd = URL(fileURLWithPath: FileManager.default.currentDirectoryPath)
+  let path = URL(fileURLWithPath: (target as NSString).expandingTildeInPath, relativeTo: cwd)
+    .standardizedFileURL.path
+  request = IPCRequest(command: .exportCache, argument: path, format: hasFormat ? format : nil)
+}
+
+let response: IPCResponse
+do {
+  response = try IPCClient().send(request)
+} catch {
+  fail(error.localizedDescription)
+}
+guard response.ok else { fail(response.error ?? "Request failed") }
+
+if command == .search || command == .getDefaultItems {
+  let results = response.results ?? []
+  if wantsJSON {
+    let encoder = JSONEncoder()
+    encoder.outputFormatting = [.prettyPrinted, .sortedKeys, .withoutEscapingSlashes]
+    do {
+      print(String(decoding: try encoder.encode(results), as: UTF8.self))
+    } catch {
+      fail(error.localizedDescription)
+    }
+  } else if let sections = response.sections {
+    // 見出しごとにまとめる設定では見出しの下に結果を並べる
+    var remaining = results[...]
+    for section in sections {
+      print("# \(section.title)")
+      for result in remaining.prefix(section.count) {
+        print("\(result.name)\t\(result.path)")
+      }
+      remaining = remaining.dropFirst(section.count)
+    }
+  } else {
+    for result in results {
+      print("\(result.name)\t\(result.path)")
+    }
+  }
+} else if command == .searchDebug {
+  let results = response.debugResults ?? []
+  if wantsJSON {
+    let encoder = JSONEncoder()
+    encoder.outputFormatting = [.prettyPrinted, .sortedKeys, .withoutEscapingSlashes]
+    do {
+      print(String(decoding: try encoder.encode(results), as: UTF8.self))
+    } catch {
+      fail(error.localizedDescription)
+    }
+  } else {
+    func format(_ value: Double) -> String {
+      value.formatted(.number.precision(.fractionLength(3)))
+    }
+    print("final\tfuzzy\tcategory\thistory\talias\tkind\tname")
+    for result in results {
+      let alias = result.aliasScore.map(format) ?? "-"
+      let name = result.favorite ? "★ \(result.name)" : result.name
+      print(
+        [
+          format(result.finalScore), format(result.fuzzyScore),
+          format(result.categoryAdjustment), format(result.historyBoost), alias, result.kind,
+          name,
+        ].joined(separator: "\t"))
+    }
+  }
+} else if command == .listProfiles {
+  for name in response.profiles ?? [] {
+    print("\(name == response.activeProfile ? "*" : " ") \(name)")
+  }
+} else if command == .getQuerySuggestions {
+  for suggestion in response.suggestions ?? [] {
+    print(suggestion)
+  }
+} else if command == .exportCache {
+  print("Exported \(response.exportedCount ?? 0) items to \(request.argument ?? "")")
+} else if command == .getIcon || command == .ensureIcon {
+  if let iconPath = response.iconPath {
+    print(iconPath)
+  }
+} else if command == .getPermissionStatus || command == .requestAccessibilityPermission {
+  let permissions = response.permissions ?? IPCPermissionStatus(accessibility: false)
+  if wantsJSON {
+    let encoder = JSONEncoder()
+    encoder.outputFormatting = [.prettyPrinted, .sortedKeys]
+    do {
+      print(String(decoding: try encoder.encode(permissions), as: UTF8.self))
+    } catch {
+      fail(error.localizedDescription)
+    }
+  } else {
+    print("accessibility\t\(permissions.accessibility ? "granted" : "denied")")
+  }
+} else if command == .displayInfo {
+  let displays = response.displays ?? []
+  if wantsJSON {
+    let encoder = JSONEncoder()
+    encoder.outputFormatting = [.prettyPrinted, .sortedKeys]
+    do {
+      print(String(decoding: try encoder.encode(displays), as: UTF8.self))
+    } catch {
+      fail(error.localizedDescription)
+    }
+  } else {
+    for display in displays {
+      let marker = display.active ? "*" : " "
+      let size = "\(Int(display.width))x\(Int(display.height))"
+      print("\(marker) \(display.name)\t@\(display.scale.formatted())x\t\(size)")
+    }
+  }
+}
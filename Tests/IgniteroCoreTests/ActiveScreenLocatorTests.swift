@@ -0,0 +1,32 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("ActiveScreenLocator")
+struct ActiveScreenLocatorTests {
+
+  private let mainScreen = CGRect(x: 0, y: 0, width: 1440, height: 900)
+  private let externalScreen = CGRect(x: 1440, y: -180, width: 1920, height: 1080)
+
+  @Test("Quartz 座標を Cocoa 座標へ変換する")
+  func convertsQuartzToCocoa() {
+    let rect = ActiveScreenLocator.cocoaRect(
+      fromQuartz: CGRect(x: 100, y: 50, width: 800, height: 600), primaryScreenHeight: 900)
+    #expect(rect == CGRect(x: 100, y: 250, width: 800, height: 600))
+  }
+
+  @Test("ウィンドウと最も広く重なるスクリーンを選ぶ")
+  func picksScreenWithLargestOverlap() {
+    let window = CGRect(x: 1300, y: 100, width: 800, height: 600)
+    let index = ActiveScreenLocator.bestScreenIndex(
+      for: window, screens: [mainScreen, externalScreen])
+    #expect(index == 1)
+  }
+
+  @Test("どのスクリーンとも重ならない場合は nil")
+  func returnsNilWhenOffScreen() {
+    let window = CGRect(x: -2000, y: 0, width: 400, height: 300)
+    #expect(ActiveScreenLocator.bestScreenIndex(for: window, screens: [mainScreen]) == nil)
+  }
+}
@@ -1,5 +1,7 @@
 import AppKit
 import Foundation
+import IgniteroIPC
+import Synchronization
 import Testing
 
 @testable import IgniteroCore
@@ -18,11 +20,38 @@ private final class MockCacheDB: CacheDatabaseProtocol, @unchecked Sendable {
   var loadedApps: [AppItem] = []
   var savedDirectories: [DirectoryItem] = []
   var loadedDirectories: [DirectoryItem] = []
+  var recordedDirectoryOpens: [(path: String, editor: String?)] = []
+  var recentProjectsResult: [RecentProject] = []
+  var favorites: Set<String> = []
+  var deletedAppPaths: [String] = []
+  var deletedDirectoryPaths: [String] = []
+  var selectedPartitions: [String?] = []
+  var removedPartitions: [String] = []
+  var recordedQueries: [(query: String, path: String)] = []
+  var queryHistoryResult: [SelectionHistoryEntry] = []
+  var querySuggestionsResult: [String] = []
+  var suggestionPrefixes: [String] = []
+  var preferredEditorUpdates: [(editor: String?, path: String)] = []
+  var preferredEditorsResult: [String: String] = [:]
+  var updatedIconPaths: [String: String] = [:]
 
   init(isEmpty: Bool = true) {
     self.isEmptyResult = isEmpty
   }
 
+  func deleteApps(paths: [String]) throws {
+    deletedAppPaths += paths
+    loadedApps.removeAll { paths.contains($0.path) }
+  }
+
+  func deleteDirectories(paths: [String]) throws {
+    deletedDirectoryPaths += paths
+  }
+
+  func updateAppIconPaths(_ iconPaths: [String: String]) throws {
+    updatedIconPaths.merge(iconPaths) { _, new in new }
+  }
+
   func isEmpty() throws -> Bool { isEmptyResult }
 
   func saveApps(_ apps: [AppItem]) throws {
@@ -48,6 +77,49 @@ private final class MockCacheDB: CacheDatabaseProtocol, @unchecked Sendable {
   func clearCache() throws {
     clearCacheCalled = true
   }
+
+  func recordDirectoryOpen(path: String, editor: String?) throws {
+    recordedDirectoryOpens.append((path, editor))
+  }
+
+  func recentProjects(limit: Int) async throws -> [RecentProject] {
+    Array(recentProjectsResult.prefix(limit))
+  }
+
+  func toggleFavorite(path: String) throws -> Bool {
+    if favorites.remove(path) != nil { return false }
+    favorites.insert(path)
+    return true
+  }
+
+  func favoritePaths() async throws -> Set<String> { favorites }
+
+  func selectPartition(_ name: String?) throws {
+    selectedPartitions.append(name)
+  }
+
+  func removePartition(_ name: String) throws {
+    removedPartitions.append(name)
+  }
+
+  func setPreferredEditor(_ editor: String?, forPath path: String) throws {
+    preferredEditorUpdates.append((editor, path))
+  }
+
+  func preferredEditors() async throws -> [String: String] { preferredEditorsResult }
+
+  func recordQuery(_ query: String, selectedPath: String) throws {
+    recordedQueries.append((query, selectedPath))
+  }
+
+  func queryHistory(limit: Int) async throws -> [SelectionHistoryEntry] {
+    Array(queryHistoryResult.prefix(limit))
+  }
+
+  func querySuggestions(prefix: String, limit: Int) async throws -> [String] {
+    suggestionPrefixes.append(prefix)
+    return Array(querySuggestionsResult.prefix(limit))
+  }
 }
 
 /// テスト用モック AppScanner
@@ -81,6 +153,14 @@ private struct CoordinatorMockIMEController: IMEControlling {
   func switchToASCII() {
     // テスト用: 何もしない
   }
+
+  func restoreInputSource() {
+    // テスト用: 何もしない
+  }
+
+  func discardSavedInputSource() {
+    // テスト用: 何もしない
+  }
 }
 
 /// テスト用モック LaunchService
@@ -90,13 +170,23 @@ private final class MockLaunchService: Launching, @unchecked Sendable {
   var openInTerminalCalledWith: (path: String, terminal: TerminalType)?
   var executeCommandCalledWith:
     (command: String, workingDirectory: String?, terminal: TerminalType)?
+  var openURLCalledWith: URL?
+  var openFileCalledWith: (path: String, applicationURL: URL?)?
+  var launchedAppPaths: [String] = []
+  var openedDirectoryPaths: [String] = []
+  /// 起動に失敗させるパス
+  var failingPaths: Set<String> = []
 
   func launchApp(at path: String) async throws {
     launchAppCalledWith = path
+    if failingPaths.contains(path) { throw LaunchError.scriptExecutionFailed(path) }
+    launchedAppPaths.append(path)
   }
 
   func openDirectory(_ path: String, editor: EditorType?) async throws {
     openDirectoryCalledWith = (path, editor)
+    if failingPaths.contains(path) { throw FileSystemError.directoryNotFound(path) }
+    openedDirectoryPaths.append(path)
   }
 
   func openInTerminal(_ path: String, terminal: TerminalType) async throws {
@@ -109,6 +199,14 @@ private final class MockLaunchService: Launching, @unchecked Sendable {
     executeCommandCalledWith = (command, workingDirectory, terminal)
   }
 
+  func openURL(_ url: URL) async throws {
+    openURLCalledWith = url
+  }
+
+  func openFile(_ path: String, applicationURL: URL?) async throws {
+    openFileCalledWith = (path, applicationURL)
+  }
+
   func availableEditors() -> [EditorInfo] {
     EditorType.allCases.map { editor in
       EditorInfo(
@@ -132,6 +230,98 @@ private final class MockLaunchService: Launching, @unchecked Sendable {
   }
 }
 
+/// テスト用モック PasteService
+private final class MockPasteService: Pasting, @unchecked Sendable {
+  var pasted: [(text: String, processIdentifier: pid_t?)] = []
+
+  func paste(_ text: String, into processIdentifier: pid_t?) async throws {
+    pasted.append((text, processIdentifier))
+  }
+}
+
+/// テスト用の固定ディスプレイ構成
+private struct StubDisplayInfoProvider: DisplayInfoProviding {
+  var screens: [DisplayInfo] = [
+    DisplayInfo(name: "Built-in Retina Display", scale: 2, width: 1512, height: 982, isActive: true)
+  ]
+
+  func displays() -> [DisplayInfo] { screens }
+}
+
+/// テスト用のアクセシビリティ権限（`requestAccess` で許可したことにできる）
+private final class StubAccessibilityPermission: AccessibilityPermissionProviding,
+  @unchecked Sendable
+{
+  var trusted: Bool
+  var grantsOnRequest = false
+  var requestCount = 0
+  var openSettingsCount = 0
+
+  init(trusted: Bool = true) {
+    self.trusted = trusted
+  }
+
+  func isTrusted() -> Bool { trusted }
+
+  func requestAccess() -> Bool {
+    requestCount += 1
+    let current = trusted
+    if grantsOnRequest {
+      trusted = true
+    }
+    return current
+  }
+
+  func openSystemSettings() {
+    openSettingsCount += 1
+  }
+}
+
+/// テスト用モッククリップボード（システムのクリップボードを書き換えない）
+private final class MockClipboard: ClipboardWriting, @unchecked Sendable {
+  var copied: [String] = []
+
+  func copy(_ text: String) {
+    copied.append(text)
+  }
+}
+
+/// テスト用モック TrashService（実際のゴミ箱には入れない）
+private final class MockTrashService: Trashing, @unchecked Sendable {
+  var trashedPaths: [String] = []
+  var restored: [TrashedItem] = []
+
+  func moveToTrash(path: String) throws -> TrashedItem {
+    trashedPaths.append(path)
+    return TrashedItem(
+      name: (path as NSString).lastPathComponent, originalPath: path,
+      trashedPath: "/Users/dev/.Trash/\((path as NSString).lastPathComponent)")
+  }
+
+  func restore(_ item: TrashedItem) throws {
+    restored.append(item)
+  }
+}
+
+/// テスト用モック UpdateInstaller
+private final class MockUpdateInstaller: UpdateInstalling, @unchecked Sendable {
+  var stagedVersions: [String] = []
+  var installed: (stagedApp: URL, appURL: URL)?
+  var canVerifySignatures = true
+
+  func stage(
+    _ update: UpdateCheckResult, progress: @escaping @Sendable (UpdateInstallState) -> Void
+  ) async throws -> URL {
+    stagedVersions.append(update.latestVersion)
+    progress(.readyToInstall)
+    return URL(fileURLWithPath: "/tmp/staged/IgniteroLauncher.app")
+  }
+
+  func install(stagedApp: URL, replacing appURL: URL) async throws {
+    installed = (stagedApp, appURL)
+  }
+}
+
 /// テスト用モック URLSession（アップデートチェック用）
 private struct MockURLSession: URLSessionProtocol {
   let responseData: Data
@@ -153,6 +343,31 @@ private struct MockURLSession: URLSessionProtocol {
   }
 }
 
+/// テスト用モック LoginItemService（システムのログイン項目に触れない）
+private final class CoordinatorMockLoginItemService: LoginItemControlling, @unchecked Sendable {
+  var enabled = false
+
+  func isEnabled() -> Bool { enabled }
+
+  func setEnabled(_ enabled: Bool) throws {
+    self.enabled = enabled
+  }
+}
+
+/// テスト用モック ProcessManager（実際のプロセスに触れない）
+private final class MockProcessManager: ProcessControlling, @unchecked Sendable {
+  var processes: [RunningProcess] = []
+  var terminateCalledWith: [(pid: Int32, force: Bool)] = []
+  var terminateError: (any Error)?
+
+  func runningProcesses() -> [RunningProcess] { processes }
+
+  func terminate(pid: Int32, force: Bool) throws {
+    terminateCalledWith.append((pid, force))
+    if let terminateError { throw terminateError }
+  }
+}
+
 // MARK: - テスト補助
 
 @MainActor
@@ -162,6 +377,71 @@ private func makeTempSettingsManager() -> SettingsManager {
   return SettingsManager(configDirectory: dir)
 }
 
+private func makeTempIconCacheManager() -> IconCacheManager {
+  IconCacheManager(
+    cacheDirectory: FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-icon-coord-test-\(UUID().uuidString)").path)
+}
+
+/// 固定のパネルを返すシステム設定のプロバイダー（既定は空で、検索結果に混ざらない）
+private struct StubSystemSettingsProvider: SystemSettingsPaneProviding {
+  var stubPanes: [SystemSettingsPane] = []
+
+  func panes() async -> [SystemSettingsPane] {
+    stubPanes
+  }
+}
+
+/// 固定の SSH ホスト・tmux セッションを返すプロバイダー（テストで ~/.ssh/config を読まないため）
+private struct StubTerminalTargetProvider: TerminalTargetProviding {
+  var stubTargets: [TerminalTarget] = []
+
+  func targets() async -> [TerminalTarget] {
+    stubTargets
+  }
+}
+
+/// 実行したシステム操作を記録するサービス
+private final class MockSystemControlService: SystemControlling, @unchecked Sendable {
+  var performed: [SystemControl] = []
+  var available = SystemControl.allCases
+
+  func availableControls() async -> [SystemControl] {
+    available
+  }
+
+  func perform(_ control: SystemControl) async throws {
+    performed.append(control)
+  }
+}
+
+/// Dock のアイコンの表示の切り替えを記録するサービス
+private final class MockActivationPolicy: ActivationPolicyControlling, @unchecked Sendable {
+  var dockIconVisibilityChanges: [Bool] = []
+
+  func setDockIconVisible(_ visible: Bool) {
+    dockIconVisibilityChanges.append(visible)
+  }
+}
+
+/// 実行したウィンドウ操作を記録するサービス
+private final class MockWindowArranger: WindowArranging, @unchecked Sendable {
+  var arranged: [(action: WindowLayoutAction, processIdentifier: pid_t)] = []
+
+  func arrange(_ action: WindowLayoutAction, processIdentifier: pid_t) async throws {
+    arranged.append((action, processIdentifier))
+  }
+}
+
+/// 指定パス以外はすべて存在するとみなすファイルシステム（テスト用アプリの存在確認用）
+private struct ExistingPathsFileSystem: FileSystemProvider {
+  var missingPaths: Set<String> = []
+
+  func contentsOfDirectory(atPath path: String) throws -> [String] { [] }
+  func isDirectory(atPath path: String) -> Bool { false }
+  func fileExists(atPath path: String) -> Bool { !missingPaths.contains(path) }
+}
+
 private func makeTempSelectionHistory() -> SelectionHistory {
   let path = FileManager.default.temporaryDirectory
     .appendingPathComponent("ignitero-history-test-\(UUID().uuidString).json").path
@@ -177,7 +457,20 @@ private func makeCoordinator(
   appScanner: (any AppScannerProtocol)? = nil,
   directoryScanner: (any DirectoryScannerProtocol)? = nil,
   selectionHistory: SelectionHistory? = nil,
-  urlSession: (any URLSessionProtocol)? = nil
+  urlSession: (any URLSessionProtocol)? = nil,
+  processManager: (any ProcessControlling)? = nil,
+  pasteService: (any Pasting)? = nil,
+  clipboard: (any ClipboardWriting)? = nil,
+  trashService: (any Trashing)? = nil,
+  updateInstaller: (any UpdateInstalling)? = nil,
+  fileSystem: (any FileSystemProvider)? = nil,
+  systemSettingsProvider: (any SystemSettingsPaneProviding)? = nil,
+  systemControlService: (any SystemControlling)? = nil,
+  windowArranger: (any WindowArranging)? = nil,
+  activationPolicy: (any ActivationPolicyControlling)? = nil,
+  displayInfoProvider: (any DisplayInfoProviding)? = nil,
+  accessibilityPermission: (any AccessibilityPermissionProviding)? = nil,
+  terminalTargetProvider: (any TerminalTargetProviding)? = nil
 ) -> AppCoordinator {
   AppCoordinator(
     settingsManager: settingsManager ?? makeTempSettingsManager(),
@@ -188,6 +481,23 @@ private func makeCoordinator(
     directoryScanner: directoryScanner ?? MockDirScanner(),
     selectionHistory: selectionHistory ?? makeTempSelectionHistory(),
     urlSession: urlSession ?? MockURLSession(),
+    loginItemService: CoordinatorMockLoginItemService(),
+    processManager: processManager ?? MockProcessManager(),
+    iconCacheManager: makeTempIconCacheManager(),
+    pasteService: pasteService ?? MockPasteService(),
+    clipboard: clipboard ?? MockClipboard(),
+    trashService: trashService ?? MockTrashService(),
+    updateInstaller: updateInstaller ?? MockUpdateInstaller(),
+    fileSystem: fileSystem ?? ExistingPathsFileSystem(),
+    systemSettingsProvider: systemSettingsProvider ?? StubSystemSettingsProvider(),
+    systemControlService: systemControlService ?? MockSystemControlService(),
+    windowArranger: windowArranger ?? MockWindowArranger(),
+    activationPolicy: activationPolicy ?? MockActivationPolicy(),
+    displayInfoProvider: displayInfoProvider ?? StubDisplayInfoProvider(),
+    accessibilityPermission: accessibilityPermission ?? StubAccessibilityPermission(),
+    terminalTargetProvider: terminalTargetProvider ?? StubTerminalTargetProvider(),
+    ipcSocketPath: nil,
+    watchesSettingsFile: false,
     shortcutDebounceInterval: .zero
   )
 }
@@ -297,6 +607,17 @@ struct AppCoordinatorLifecycleTests {
     #expect(coordinator.launcherViewModel.history.count == 1)
   }
 
+  @Test("start() loads system settings panes into view model")
+  @MainActor
+  func startLoadsSystemSettingsPanes() async {
+    let pane = SystemSettingsPane(id: "com.apple.Sound-Settings.extension", name: "サウンド")
+    let coordinator = makeCoordinator(
+      systemSettingsProvider: StubSystemSettingsProvider(stubPanes: [pane]))
+    await coordinator.start()
+
+    #expect(coordinator.launcherViewModel.systemSettingsPanes == [pane])
+  }
+
   @Test("start() loads commands from settings into view model")
   @MainActor
   func startLoadsCommandsIntoViewModel() async {
@@ -498,6 +819,24 @@ struct AppCoordinatorExecuteResultTests {
     #expect(mockLaunch.launchAppCalledWith == "/Applications/Safari.app")
   }
 
+  @Test("起動後に閉じない設定ではランチャーを表示したままにする")
+  @MainActor
+  func executeResultKeepsLauncherWhenHideAfterLaunchIsOff() async throws {
+    let mockLaunch = MockLaunchService()
+    let settings = makeTempSettingsManager()
+    settings.settings.windowBehavior.hideAfterLaunch = false
+    let coordinator = makeCoordinator(settingsManager: settings, launchService: mockLaunch)
+    coordinator.windowManager.showLauncher()
+
+    coordinator.executeResult(
+      SearchResult(
+        appItem: AppItem(name: "Safari", path: "/Applications/Safari.app"), score: 0.0))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockLaunch.launchAppCalledWith == "/Applications/Safari.app")
+    #expect(coordinator.windowManager.isLauncherVisible)
+  }
+
   @Test("Execute command result executes command")
   @MainActor
   func executeCommandResultExecutesCommand() async throws {
@@ -522,6 +861,148 @@ struct AppCoordinatorExecuteResultTests {
     #expect(mockLaunch.executeCommandCalledWith?.terminal == .iterm2)
   }
 
+  @Test("ここでコマンドを実行では、選んだコマンドをディレクトリで実行する")
+  @MainActor
+  func runCommandHereUsesTargetDirectory() async throws {
+    let mockLaunch = MockLaunchService()
+    let history = makeTempSelectionHistory()
+    let coordinator = makeCoordinator(launchService: mockLaunch, selectionHistory: history)
+    let cmd = CustomCommand(alias: "build", command: "make build", workingDirectory: "/project")
+    coordinator.launcherViewModel.commands = [cmd]
+
+    coordinator.launcherViewModel.beginRunCommand(in: "/Users/test/app")
+    coordinator.executeResult(SearchResult(customCommand: cmd, score: 0.0))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockLaunch.executeCommandCalledWith?.command == "make build")
+    #expect(mockLaunch.executeCommandCalledWith?.workingDirectory == "/Users/test/app")
+    #expect(history.allEntries.map(\.selectedPath) == [cmd.historyIdentifier])
+    #expect(coordinator.launcherViewModel.commandTargetDirectory == nil)
+  }
+
+  @Test("ここでコマンドを実行で入力したコマンドは履歴に記録しない")
+  @MainActor
+  func runTypedCommandHereIsNotRecorded() async throws {
+    let mockLaunch = MockLaunchService()
+    let history = makeTempSelectionHistory()
+    let coordinator = makeCoordinator(launchService: mockLaunch, selectionHistory: history)
+
+    coordinator.launcherViewModel.beginRunCommand(in: "/Users/test/app")
+    coordinator.executeResult(SearchResult(shellCommand: "git status", score: 0))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockLaunch.executeCommandCalledWith?.command == "git status")
+    #expect(mockLaunch.executeCommandCalledWith?.workingDirectory == "/Users/test/app")
+    #expect(history.allEntries.isEmpty)
+  }
+
+  @Test("SSH ホストを選ぶと既定のターミナルで ssh を実行し、識別子で履歴に記録する")
+  @MainActor
+  func executeTerminalTargetRunsSSH() async throws {
+    let mockLaunch = MockLaunchService()
+    let history = makeTempSelectionHistory()
+    let settings = makeTempSettingsManager()
+    settings.settings.defaultTerminal = .ghostty
+    let coordinator = makeCoordinator(
+      settingsManager: settings, launchService: mockLaunch, selectionHistory: history)
+    let target = TerminalTarget(kind: .sshHost, name: "prod-web")
+
+    coordinator.executeResult(SearchResult(terminalTarget: target, score: 0))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockLaunch.executeCommandCalledWith?.command == "ssh prod-web")
+    #expect(mockLaunch.executeCommandCalledWith?.workingDirectory == nil)
+    #expect(mockLaunch.executeCommandCalledWith?.terminal == .ghostty)
+    #expect(history.allEntries.map(\.selectedPath) == ["ssh://prod-web"])
+  }
+
+  @Test("まとめて起動すると失敗した項目があっても残りを起動し、失敗を項目ごとに返す")
+  @MainActor
+  func launchItemsContinuesAfterFailure() async {
+    let mockLaunch = MockLaunchService()
+    mockLaunch.failingPaths = ["/Users/test/missing"]
+    let history = makeTempSelectionHistory()
+    let coordinator = makeCoordinator(launchService: mockLaunch, selectionHistory: history)
+
+    let report = await coordinator.launchItems([
+      SearchResult(directoryItem: DirectoryItem(name: "api", path: "/Users/test/api"), score: 0),
+      SearchResult(
+        directoryItem: DirectoryItem(name: "missing", path: "/Users/test/missing"), score: 0),
+      SearchResult(appItem: AppItem(name: "Safari", path: "/Applications/Safari.app"), score: 0),
+    ])
+
+    #expect(mockLaunch.openedDirectoryPaths == ["/Users/test/api"])
+    #expect(mockLaunch.launchedAppPaths == ["/Applications/Safari.app"])
+    #expect(report.launchedPaths == ["/Users/test/api", "/Applications/Safari.app"])
+    #expect(
+      report.failures == [
+        BatchLaunchFailure(
+          name: "missing", path: "/Users/test/missing", error: .notFound("/Users/test/missing"))
+      ])
+    #expect(report.error == .notFound("/Users/test/missing"))
+    #expect(history.allEntries.count == 3)
+  }
+
+  @Test("複数の項目が失敗した場合は件数と名前をまとめてエラーバナーに表示する")
+  @MainActor
+  func executeMarkedResultsReportsAggregatedError() async throws {
+    let mockLaunch = MockLaunchService()
+    mockLaunch.failingPaths = ["/Applications/Alpha.app", "/Applications/Bravo.app"]
+    let coordinator = makeCoordinator(launchService: mockLaunch)
+    let vm = coordinator.launcherViewModel
+    vm.apps = [
+      AppItem(name: "Alpha", path: "/Applications/Alpha.app"),
+      AppItem(name: "Bravo", path: "/Applications/Bravo.app"),
+      AppItem(name: "Charlie", path: "/Applications/Charlie.app"),
+    ]
+    for name in ["alpha", "bravo", "charlie"] {
+      vm.searchQuery = name
+      vm.updateSearch()
+      vm.toggleMark()
+    }
+
+    coordinator.executeMarkedResults()
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockLaunch.launchedAppPaths == ["/Applications/Charlie.app"])
+    #expect(vm.markedResults.isEmpty)
+    #expect(vm.lastError == .launch("3 件中 2 件（Alpha, Bravo）"))
+  }
+
+  @Test("まとめて起動した項目は、閉じる前の検索語で選択履歴に記録する")
+  @MainActor
+  func executeMarkedResultsRecordsQueryBeforeDismiss() async throws {
+    let mockCache = MockCacheDB(isEmpty: false)
+    let history = makeTempSelectionHistory()
+    let coordinator = makeCoordinator(cacheDatabase: mockCache, selectionHistory: history)
+    let vm = coordinator.launcherViewModel
+    vm.apps = [AppItem(name: "Safari", path: "/Applications/Safari.app")]
+    vm.searchQuery = "Safari"
+    vm.updateSearch()
+    vm.toggleMark()
+
+    coordinator.executeMarkedResults()
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(history.allEntries.map(\.keyword) == ["safari"])
+    #expect(mockCache.recordedQueries.map(\.query) == ["safari"])
+  }
+
+  @Test("結果を貼り付けるとランチャーを閉じてパスを貼り付けサービスへ渡す")
+  @MainActor
+  func pasteResultPastesPath() async throws {
+    let mockPaste = MockPasteService()
+    let coordinator = makeCoordinator(pasteService: mockPaste)
+    coordinator.launcherViewModel.searchQuery = "api"
+
+    coordinator.pasteResult(
+      SearchResult(directoryItem: DirectoryItem(name: "api", path: "/Users/test/api"), score: 0))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockPaste.pasted.map(\.text) == ["/Users/test/api"])
+    #expect(coordinator.launcherViewModel.searchQuery.isEmpty)
+  }
+
   @Test("Execute result records selection history")
   @MainActor
   func executeResultRecordsHistory() async {
@@ -570,6 +1051,47 @@ struct AppCoordinatorExecuteResultTests {
     #expect(history.allEntries[0].selectedPath == "/Applications/Xcode.app")
   }
 
+  @Test("Execute result records query history in cache database")
+  @MainActor
+  func executeResultRecordsQueryHistory() async {
+    let mockDB = MockCacheDB()
+    let coordinator = makeCoordinator(
+      cacheDatabase: mockDB,
+      launchService: MockLaunchService()
+    )
+
+    coordinator.launcherViewModel.searchQuery = " Term "
+    coordinator.executeResult(
+      SearchResult(appItem: AppItem(name: "iTerm", path: "/Applications/iTerm.app"), score: 0))
+
+    #expect(mockDB.recordedQueries.count == 1)
+    #expect(mockDB.recordedQueries.first?.query == "term")
+    #expect(mockDB.recordedQueries.first?.path == "/Applications/iTerm.app")
+    // 選択履歴と同じ組み合わせは二重に数えない
+    #expect(coordinator.launcherViewModel.history.count == 1)
+  }
+
+  @Test("Query history in cache database boosts results chosen for longer queries")
+  @MainActor
+  func queryHistoryBoostsPreviouslyChosenResult() async {
+    let mockDB = MockCacheDB(isEmpty: false)
+    mockDB.queryHistoryResult = [
+      SelectionHistoryEntry(keyword: "term", selectedPath: "/Applications/iTerm.app", count: 3)
+    ]
+    let coordinator = makeCoordinator(cacheDatabase: mockDB)
+    await coordinator.start()
+    coordinator.launcherViewModel.apps = [
+      AppItem(name: "Terminal", path: "/System/Applications/Utilities/Terminal.app"),
+      AppItem(name: "iTerm", path: "/Applications/iTerm.app"),
+    ]
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .search, argument: "ter"))
+
+    #expect(coordinator.launcherViewModel.history.count == 1)
+    #expect(response.results?.first?.path == "/Applications/iTerm.app")
+  }
+
   @Test("Execute command result records command history identifier")
   @MainActor
   func executeCommandResultRecordsCommandHistoryIdentifier() async throws {
@@ -650,66 +1172,220 @@ struct AppCoordinatorDismissLauncherTests {
     #expect(coordinator.launcherViewModel.searchQuery == "")
     #expect(coordinator.windowManager.isLauncherVisible == false)
   }
-}
 
-// MARK: - ターミナル起動テスト
+  @Test("ランチャーを閉じると表示前の入力ソースに戻す")
+  @MainActor
+  func dismissLauncherRestoresInputSource() {
+    let ime = MockIMEController()
+    let coordinator = makeCoordinator(imeController: ime)
 
-@Suite("AppCoordinator Open In Terminal")
-struct AppCoordinatorOpenInTerminalTests {
+    coordinator.activate(mode: .launcher)
+    #expect(ime.switchToASCIICallCount == 1)
+    #expect(ime.restoreInputSourceCallCount == 0)
 
-  @Test("openInTerminal uses default terminal from settings")
+    coordinator.dismissLauncher()
+    #expect(ime.restoreInputSourceCallCount == 1)
+
+    // 表示していないときに閉じても戻さない
+    coordinator.dismissLauncher()
+    #expect(ime.restoreInputSourceCallCount == 1)
+  }
+
+  @Test("入力ソースの扱いが「切り替えない」なら表示時に切り替えない")
   @MainActor
-  func openInTerminalUsesDefaultTerminal() async throws {
-    let mockLaunch = MockLaunchService()
+  func imeBehaviorOffDoesNotSwitch() throws {
     let settings = makeTempSettingsManager()
-    settings.settings.defaultTerminal = .ghostty
+    settings.settings.windowBehavior.imeBehavior = .off
+    try settings.save()
+    let ime = MockIMEController()
+    let coordinator = makeCoordinator(settingsManager: settings, imeController: ime)
 
-    let coordinator = makeCoordinator(
-      settingsManager: settings,
-      launchService: mockLaunch
-    )
+    coordinator.activate(mode: .launcher)
+    coordinator.dismissLauncher()
 
-    coordinator.openInTerminal("/Users/dev/project")
+    #expect(ime.switchToASCIICallCount == 0)
+    #expect(ime.restoreInputSourceCallCount == 0)
+    #expect(ime.discardSavedInputSourceCallCount == 1)
+  }
 
-    // 非同期タスクの完了を待つ
-    try await Task.sleep(nanoseconds: 100_000_000)
+  @Test("追加ショートカットの入力ソースの扱いは全体の設定より優先する")
+  @MainActor
+  func hotkeyIMEBehaviorOverridesSetting() {
+    let ime = MockIMEController()
+    let coordinator = makeCoordinator(imeController: ime)
 
-    #expect(mockLaunch.openInTerminalCalledWith?.path == "/Users/dev/project")
-    #expect(mockLaunch.openInTerminalCalledWith?.terminal == .ghostty)
+    // 全体の設定は「閉じたら戻す」だが、ショートカットは「切り替えるだけ」
+    coordinator.activate(mode: .apps, imeBehavior: .forceEnglish)
+    #expect(ime.switchToASCIICallCount == 1)
+    coordinator.dismissLauncher()
+    #expect(ime.restoreInputSourceCallCount == 0)
+    #expect(ime.discardSavedInputSourceCallCount == 1)
+
+    coordinator.activate(mode: .apps, imeBehavior: .off)
+    #expect(ime.switchToASCIICallCount == 1)
   }
 }
 
-// MARK: - 設定連携テスト
+// MARK: - モード別ホットキーテスト
 
-@Suite("AppCoordinator Settings Integration")
-struct AppCoordinatorSettingsIntegrationTests {
+@Suite("AppCoordinator Launcher Modes")
+struct AppCoordinatorLauncherModeTests {
 
-  @Test("Settings changes propagate to view model commands")
+  @Test("絞り込みモードはランチャーを表示し検索対象を設定する")
   @MainActor
-  func settingsChangesPropagate() throws {
-    let settings = makeTempSettingsManager()
-    let coordinator = makeCoordinator(settingsManager: settings)
-
-    // 初期状態では空
-    #expect(coordinator.launcherViewModel.commands.isEmpty)
-
-    // 設定経由でコマンドを追加する
-    settings.settings.customCommands = [
-      CustomCommand(alias: "deploy", command: "npm run deploy")
+  func scopedModeShowsLauncherWithScope() {
+    let coordinator = makeCoordinator()
+    coordinator.launcherViewModel.directories = [
+      DirectoryItem(name: "proj", path: "/Users/dev/proj")
+    ]
+    coordinator.launcherViewModel.apps = [
+      AppItem(name: "Proj App", path: "/Applications/Proj App.app")
     ]
 
-    // 再読み込みを実行する
-    coordinator.reloadDataFromSettings()
+    coordinator.activate(mode: .directories)
+    coordinator.launcherViewModel.searchQuery = "proj"
+    coordinator.launcherViewModel.updateSearch()
 
-    #expect(coordinator.launcherViewModel.commands.count == 1)
-    #expect(coordinator.launcherViewModel.commands[0].alias == "deploy")
+    #expect(coordinator.windowManager.isLauncherVisible == true)
+    #expect(coordinator.launcherViewModel.scopeOverride == .directories)
+    #expect(coordinator.launcherViewModel.searchResults.allSatisfy { $0.kind == .directory })
   }
 
-  @Test("SettingsViewModel shares same SettingsManager")
+  @Test("同じモードを再度起動するとランチャーを閉じる")
   @MainActor
-  func settingsViewModelSharesManager() {
-    let settings = makeTempSettingsManager()
-    let coordinator = makeCoordinator(settingsManager: settings)
+  func sameModeTogglesOff() {
+    let coordinator = makeCoordinator()
+
+    coordinator.activate(mode: .apps)
+    coordinator.activate(mode: .apps)
+
+    #expect(coordinator.windowManager.isLauncherVisible == false)
+    #expect(coordinator.launcherViewModel.scopeOverride == nil)
+  }
+
+  @Test("別モードへの切り替えは表示を維持して検索対象のみ変更する")
+  @MainActor
+  func switchingModeKeepsLauncherVisible() {
+    let coordinator = makeCoordinator()
+
+    coordinator.activate(mode: .apps)
+    coordinator.activate(mode: .commands)
+
+    #expect(coordinator.windowManager.isLauncherVisible == true)
+    #expect(coordinator.launcherViewModel.scopeOverride == .commands)
+  }
+
+  @Test("ランチャーを閉じると検索対象の絞り込みも解除される")
+  @MainActor
+  func dismissClearsScope() {
+    let coordinator = makeCoordinator()
+
+    coordinator.activate(mode: .directories)
+    coordinator.dismissLauncher()
+    coordinator.windowManager.showLauncher()
+
+    #expect(coordinator.launcherViewModel.scopeOverride == nil)
+  }
+}
+
+// MARK: - ターミナル起動テスト
+
+@Suite("AppCoordinator Open In Terminal")
+struct AppCoordinatorOpenInTerminalTests {
+
+  @Test("openInTerminal uses default terminal from settings")
+  @MainActor
+  func openInTerminalUsesDefaultTerminal() async throws {
+    let mockLaunch = MockLaunchService()
+    let settings = makeTempSettingsManager()
+    settings.settings.defaultTerminal = .ghostty
+
+    let coordinator = makeCoordinator(
+      settingsManager: settings,
+      launchService: mockLaunch
+    )
+
+    coordinator.openInTerminal("/Users/dev/project")
+
+    // 非同期タスクの完了を待つ
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockLaunch.openInTerminalCalledWith?.path == "/Users/dev/project")
+    #expect(mockLaunch.openInTerminalCalledWith?.terminal == .ghostty)
+  }
+}
+
+// MARK: - 設定連携テスト
+
+@Suite("AppCoordinator Settings Integration")
+struct AppCoordinatorSettingsIntegrationTests {
+
+  @Test("Settings changes propagate to view model commands")
+  @MainActor
+  func settingsChangesPropagate() throws {
+    let settings = makeTempSettingsManager()
+    let coordinator = makeCoordinator(settingsManager: settings)
+
+    // 初期状態では空
+    #expect(coordinator.launcherViewModel.commands.isEmpty)
+
+    // 設定経由でコマンドを追加する
+    settings.settings.customCommands = [
+      CustomCommand(alias: "deploy", command: "npm run deploy")
+    ]
+
+    // 再読み込みを実行する
+    coordinator.reloadDataFromSettings()
+
+    #expect(coordinator.launcherViewModel.commands.count == 1)
+    #expect(coordinator.launcherViewModel.commands[0].alias == "deploy")
+  }
+
+  @Test("外観の設定変更をビューモデルとウィンドウへ反映する")
+  @MainActor
+  func appearanceSettingsApplyToWindow() throws {
+    let settings = makeTempSettingsManager()
+    let coordinator = makeCoordinator(settingsManager: settings)
+
+    try coordinator.settingsViewModel.setAppearance(
+      AppearanceSettings(windowWidth: 760, density: .comfortable, mode: .dark))
+
+    #expect(coordinator.launcherViewModel.appearance.windowWidth == 760)
+    #expect(coordinator.windowManager.windowWidth == 760)
+    #expect(coordinator.windowManager.resultRowHeight == 60)
+    #expect(coordinator.launcherPanel.appearance?.name == .darkAqua)
+  }
+
+  @Test("フォーカスを戻す設定をウィンドウへ反映する")
+  @MainActor
+  func restoreFocusSettingAppliesToWindow() throws {
+    let coordinator = makeCoordinator()
+    #expect(coordinator.windowManager.restoresFocusOnHide)
+
+    try coordinator.settingsViewModel.setRestoreFocusOnHide(false)
+
+    #expect(!coordinator.settingsManager.settings.restoreFocusOnHide)
+    #expect(!coordinator.windowManager.restoresFocusOnHide)
+  }
+
+  @Test("自動で閉じる条件の設定をウィンドウへ反映する")
+  @MainActor
+  func windowBehaviorSettingAppliesToWindow() throws {
+    let coordinator = makeCoordinator()
+    #expect(coordinator.windowManager.hidesOnFocusLoss)
+
+    try coordinator.settingsViewModel.setWindowBehavior(
+      WindowBehaviorSettings(hideOnFocusLoss: false, focusLossGracePeriodMs: 9000))
+
+    #expect(!coordinator.windowManager.hidesOnFocusLoss)
+    #expect(coordinator.windowManager.focusLossGracePeriod == .milliseconds(5000))
+  }
+
+  @Test("SettingsViewModel shares same SettingsManager")
+  @MainActor
+  func settingsViewModelSharesManager() {
+    let settings = makeTempSettingsManager()
+    let coordinator = makeCoordinator(settingsManager: settings)
 
     #expect(coordinator.settingsViewModel.settingsManager === settings)
     #expect(coordinator.menuBarActions.settingsManager === settings)
@@ -828,11 +1504,16 @@ struct AppCoordinatorMenuBarIntegrationTests {
     let coordinator = makeCoordinator()
     let items = coordinator.menuBarActions.menuItems
 
-    #expect(items.count == 4)
+    #expect(items.count == 8)
     #expect(items[0].id == "show-window")
-    #expect(items[1].id == "rebuild-cache")
-    #expect(items[2].id == "settings")
-    #expect(items[3].id == "quit")
+    #expect(items[1].id == "recent-items")
+    #expect(items[2].id == "cache-status")
+    #expect(items[3].id == "rebuild-cache")
+    #expect(items[4].id == "reset-cache")
+    #expect(items[5].id == "pause-indexing")
+    #expect(items[6].id == "settings")
+    #expect(items[7].id == "quit")
+    #expect(coordinator.menuBarActions.cacheBootstrap === coordinator.cacheBootstrap)
   }
 }
 
@@ -1084,6 +1765,88 @@ struct AppCoordinatorUpdateCheckerTests {
 
     #expect(coordinator.launcherViewModel.shouldShowUpdateBanner == false)
   }
+
+  @Test("自動ダウンロードでは通知と同時に検証まで済ませ、インストール後に再起動する")
+  @MainActor
+  func autoDownloadStagesAndInstallRelaunches() async throws {
+    let releases = #"""
+      [{"tag_name": "v99.0.0", "prerelease": false, "html_url": "https://example.com/v99",
+        "assets": [
+          {"name": "IgniteroLauncher.zip", "browser_download_url": "https://example.com/a.zip"},
+          {"name": "IgniteroLauncher.zip.sha256", "browser_download_url": "https://example.com/a.sha256"},
+          {"name": "IgniteroLauncher.zip.sig", "browser_download_url": "https://example.com/a.sig"}
+        ]}]
+      """#
+    let settings = makeTempSettingsManager()
+    settings.settings.update = UpdateSettings(installMode: .autoDownload)
+    let installer = MockUpdateInstaller()
+    let coordinator = makeCoordinator(
+      settingsManager: settings, urlSession: MockURLSession(responseData: Data(releases.utf8)),
+      updateInstaller: installer)
+    coordinator.installedAppURL = URL(fileURLWithPath: "/Applications/IgniteroLauncher.app")
+    var relaunched: URL?
+    coordinator.relaunchHandler = { relaunched = $0 }
+
+    await coordinator.checkForUpdates()
+
+    #expect(coordinator.launcherViewModel.updateBannerVersion == "99.0.0")
+    #expect(coordinator.launcherViewModel.isUpdateInstallable)
+    #expect(installer.stagedVersions == ["99.0.0"])
+    #expect(coordinator.launcherViewModel.updateInstallState == .readyToInstall)
+
+    await coordinator.installUpdate()
+
+    // 検証済みのアップデートは再ダウンロードしない
+    #expect(installer.stagedVersions == ["99.0.0"])
+    #expect(installer.installed?.appURL == coordinator.installedAppURL)
+    #expect(relaunched == coordinator.installedAppURL)
+  }
+
+  @Test("アーカイブのないリリースはインストールせずにリリースページを開く")
+  @MainActor
+  func installUpdateWithoutArchiveOpensReleasePage() async {
+    let releases =
+      #"[{"tag_name": "v99.0.0", "prerelease": false, "html_url": "https://example.com/v99"}]"#
+    let mockLaunch = MockLaunchService()
+    let installer = MockUpdateInstaller()
+    let coordinator = makeCoordinator(
+      launchService: mockLaunch, urlSession: MockURLSession(responseData: Data(releases.utf8)),
+      updateInstaller: installer)
+
+    await coordinator.checkForUpdates()
+    await coordinator.installUpdate()
+
+    #expect(!coordinator.launcherViewModel.isUpdateInstallable)
+    #expect(installer.stagedVersions.isEmpty)
+    #expect(mockLaunch.openURLCalledWith?.absoluteString == "https://example.com/v99")
+  }
+
+  @Test("署名を検証できないビルドは署名付きのリリースでもインストールせずにリリースページを開く")
+  @MainActor
+  func installUpdateWithoutPublicKeyOpensReleasePage() async {
+    let releases = #"""
+      [{"tag_name": "v99.0.0", "prerelease": false, "html_url": "https://example.com/v99",
+        "assets": [
+          {"name": "IgniteroLauncher.zip", "browser_download_url": "https://example.com/a.zip"},
+          {"name": "IgniteroLauncher.zip.sha256", "browser_download_url": "https://example.com/a.sha256"},
+          {"name": "IgniteroLauncher.zip.sig", "browser_download_url": "https://example.com/a.sig"}
+        ]}]
+      """#
+    let mockLaunch = MockLaunchService()
+    let installer = MockUpdateInstaller()
+    installer.canVerifySignatures = false
+    let coordinator = makeCoordinator(
+      launchService: mockLaunch, urlSession: MockURLSession(responseData: Data(releases.utf8)),
+      updateInstaller: installer)
+    coordinator.installedAppURL = URL(fileURLWithPath: "/Applications/IgniteroLauncher.app")
+
+    await coordinator.checkForUpdates()
+    await coordinator.installUpdate()
+
+    #expect(!coordinator.launcherViewModel.isUpdateInstallable)
+    #expect(installer.stagedVersions.isEmpty)
+    #expect(mockLaunch.openURLCalledWith?.absoluteString == "https://example.com/v99")
+  }
 }
 
 // MARK: - エンドツーエンドフローテスト
@@ -1304,6 +2067,208 @@ struct AppCoordinatorEditorPickerSelectionTests {
     #expect(coordinator.launcherViewModel.searchQuery.isEmpty)
   }
 
+  @Test("既定以外のエディタを選ぶとディレクトリごとに記憶し、次から使う")
+  @MainActor
+  func editorSelectRemembersNonDefaultEditor() async throws {
+    let mockDB = MockCacheDB()
+    let launchService = MockLaunchService()
+    let coordinator = makeCoordinator(cacheDatabase: mockDB, launchService: launchService)
+
+    coordinator.showEditorPicker(for: "/tmp/test")
+    coordinator.editorPickerPanel.onSelect?(.vscode)
+    for _ in 0..<1000 where mockDB.preferredEditorUpdates.isEmpty {
+      await Task.yield()
+    }
+
+    #expect(mockDB.preferredEditorUpdates.first?.editor == "vscode")
+    #expect(mockDB.preferredEditorUpdates.first?.path == "/tmp/test")
+    #expect(coordinator.launcherViewModel.directoryEditors == ["/tmp/test": "vscode"])
+    #expect(coordinator.settingsViewModel.directoryEditors == ["/tmp/test": .vscode])
+
+    launchService.openDirectoryCalledWith = nil
+    coordinator.executeResult(
+      SearchResult(directoryItem: DirectoryItem(name: "test", path: "/tmp/test"), score: 0))
+    for _ in 0..<1000 where launchService.openDirectoryCalledWith == nil {
+      await Task.yield()
+    }
+    #expect(launchService.openDirectoryCalledWith?.editor == .vscode)
+  }
+
+  @Test("既定のエディタを選び直すと記憶を消す")
+  @MainActor
+  func editorSelectDefaultForgetsRememberedEditor() async throws {
+    let mockDB = MockCacheDB()
+    let coordinator = makeCoordinator(cacheDatabase: mockDB, launchService: MockLaunchService())
+    try coordinator.setDirectoryEditor(.vscode, forPath: "/tmp/test")
+
+    coordinator.showEditorPicker(for: "/tmp/test")
+    coordinator.editorPickerPanel.onSelect?(.cursor)
+    for _ in 0..<1000 where mockDB.preferredEditorUpdates.count < 2 {
+      await Task.yield()
+    }
+
+    #expect(mockDB.preferredEditorUpdates.last?.editor == nil)
+    #expect(coordinator.launcherViewModel.directoryEditors.isEmpty)
+  }
+
+  @Test("記憶を無効にすると登録ディレクトリのエディタで開く")
+  @MainActor
+  func disablingEditorMemoryFallsBackToConfiguredEditor() throws {
+    let coordinator = makeCoordinator()
+    try coordinator.setDirectoryEditor(.vscode, forPath: "/dev/proj")
+    #expect(coordinator.directoryEditor(for: "/dev/proj", configured: "zed") == .vscode)
+
+    try coordinator.settingsViewModel.setRemembersDirectoryEditor(false)
+
+    #expect(coordinator.directoryEditor(for: "/dev/proj", configured: "zed") == .zed)
+    #expect(coordinator.launcherViewModel.directoryEditors.isEmpty)
+    // 記憶したエディタは消さずに残す
+    #expect(coordinator.settingsViewModel.directoryEditors == ["/dev/proj": .vscode])
+  }
+
+  @Test("設定が有効なら Xcode のプロジェクトがあるディレクトリは Xcode で開く")
+  @MainActor
+  func xcodeProjectsOpenInXcodeWhenEnabled() throws {
+    let coordinator = makeCoordinator()
+    #expect(
+      coordinator.directoryEditor(for: "/dev/app", configured: "zed", hasXcodeProject: true)
+        == .zed)
+
+    try coordinator.settingsViewModel.setOpensXcodeProjectsInXcode(true)
+
+    #expect(
+      coordinator.directoryEditor(for: "/dev/app", configured: "zed", hasXcodeProject: true)
+        == .xcode)
+    #expect(coordinator.directoryEditor(for: "/dev/web", configured: "zed") == .zed)
+    // エディタピッカーで記憶したエディタは Xcode より優先する
+    try coordinator.setDirectoryEditor(.cursor, forPath: "/dev/app")
+    #expect(
+      coordinator.directoryEditor(for: "/dev/app", configured: "zed", hasXcodeProject: true)
+        == .cursor)
+  }
+
+  @Test("確認が有効なら確認のボタンでだけゴミ箱に入れ、配下の項目もキャッシュから除く")
+  @MainActor
+  func moveToTrashRequiresConfirmation() {
+    let db = MockCacheDB()
+    let trash = MockTrashService()
+    let coordinator = makeCoordinator(cacheDatabase: db, trashService: trash)
+    coordinator.launcherViewModel.directories = [
+      DirectoryItem(name: "old", path: "/dev/old"),
+      DirectoryItem(name: "sub", path: "/dev/old/sub"),
+      DirectoryItem(name: "older", path: "/dev/older"),
+    ]
+    let result = SearchResult(
+      directoryItem: DirectoryItem(name: "old", path: "/dev/old"), score: 0)
+
+    coordinator.moveToTrash(result)
+    #expect(trash.trashedPaths.isEmpty)
+    #expect(coordinator.launcherViewModel.pendingTrash?.path == "/dev/old")
+
+    // 同じ操作を繰り返しても確定しない
+    coordinator.moveToTrash(result)
+    #expect(trash.trashedPaths.isEmpty)
+    #expect(coordinator.launcherViewModel.pendingTrash?.path == "/dev/old")
+
+    coordinator.launcherViewModel.onConfirmTrash?(result)
+    #expect(trash.trashedPaths == ["/dev/old"])
+    #expect(coordinator.launcherViewModel.pendingTrash == nil)
+    #expect(coordinator.launcherViewModel.trashUndo?.originalPath == "/dev/old")
+    #expect(db.deletedDirectoryPaths == ["/dev/old"])
+    #expect(coordinator.launcherViewModel.directories.map(\.path) == ["/dev/older"])
+  }
+
+  @Test("確認しない設定ではすぐにゴミ箱に入れ、元に戻せる")
+  @MainActor
+  func moveToTrashWithoutConfirmationCanBeUndone() async throws {
+    let db = MockCacheDB()
+    let trash = MockTrashService()
+    let coordinator = makeCoordinator(cacheDatabase: db, trashService: trash)
+    try coordinator.settingsViewModel.setConfirmsMoveToTrash(false)
+    coordinator.launcherViewModel.directories = [DirectoryItem(name: "old", path: "/dev/old")]
+
+    coordinator.launcherViewModel.onMoveToTrash?(
+      SearchResult(directoryItem: DirectoryItem(name: "old", path: "/dev/old"), score: 0))
+    #expect(trash.trashedPaths == ["/dev/old"])
+    #expect(db.deletedDirectoryPaths == ["/dev/old"])
+    #expect(coordinator.launcherViewModel.directories.isEmpty)
+
+    await coordinator.undoMoveToTrash()
+    #expect(trash.restored.map(\.originalPath) == ["/dev/old"])
+    #expect(coordinator.launcherViewModel.trashUndo == nil)
+  }
+
+  @Test("アプリはゴミ箱に入れない")
+  @MainActor
+  func moveToTrashIgnoresApps() throws {
+    let trash = MockTrashService()
+    let coordinator = makeCoordinator(trashService: trash)
+    try coordinator.settingsViewModel.setConfirmsMoveToTrash(false)
+
+    coordinator.moveToTrash(
+      SearchResult(appItem: AppItem(name: "Old", path: "/Applications/Old.app"), score: 0),
+      confirmed: true)
+    #expect(trash.trashedPaths.isEmpty)
+    #expect(coordinator.launcherViewModel.pendingTrash == nil)
+  }
+
+  @Test("結果のコピーは形式ごとのテキストをクリップボードに置いてからランチャーを閉じる")
+  @MainActor
+  func copyResultWritesClipboardAndClearsSearch() {
+    let clipboard = MockClipboard()
+    let coordinator = makeCoordinator(clipboard: clipboard)
+    let directory = SearchResult(
+      directoryItem: DirectoryItem(name: "api", path: "/Users/test/api"), score: 0)
+    coordinator.launcherViewModel.searchQuery = "api"
+
+    coordinator.copyResult(directory, as: .fileURL)
+    coordinator.copyResult(directory, as: .name)
+    #expect(clipboard.copied == ["file:///Users/test/api", "api"])
+    #expect(coordinator.launcherViewModel.searchQuery.isEmpty)
+
+    // コピーできない形式では何もしない
+    let emoji = SearchResult(name: "Emoji", kind: .emoji, score: 0)
+    coordinator.copyResult(emoji, as: .path)
+    #expect(clipboard.copied.count == 2)
+  }
+
+  @Test("エディタピッカーはプロジェクトの種類に適したエディタを既定で選ぶ")
+  @MainActor
+  func editorPickerPreselectsPreferredEditorForProjectType() throws {
+    let coordinator = makeCoordinator()
+    let state = coordinator.editorPickerPanel.pickerState
+
+    coordinator.showEditorPicker(for: "/dev/api", currentEditor: .zed, projectType: .python)
+    #expect(state.selectedEditor == .pycharm)
+
+    coordinator.showEditorPicker(for: "/dev/tool", currentEditor: .zed, projectType: .rust)
+    #expect(state.selectedEditor == .zed)
+
+    // 記憶したエディタはプロジェクトの種類より優先する
+    try coordinator.setDirectoryEditor(.cursor, forPath: "/dev/api")
+    coordinator.showEditorPicker(for: "/dev/api", currentEditor: .zed, projectType: .python)
+    #expect(state.selectedEditor == .cursor)
+  }
+
+  @Test("openInXcode は設定したエディタに関わらず Xcode で開く")
+  @MainActor
+  func openInXcodeUsesXcode() async throws {
+    let launchService = MockLaunchService()
+    let coordinator = makeCoordinator(launchService: launchService)
+    let result = SearchResult(
+      directoryItem: DirectoryItem(
+        name: "app", path: "/dev/app", editor: "cursor", xcodeProject: "/dev/app/App.xcodeproj"),
+      score: 1)
+
+    coordinator.openInXcode(result)
+
+    for _ in 0..<1000 where launchService.openDirectoryCalledWith == nil {
+      await Task.yield()
+    }
+    #expect(launchService.openDirectoryCalledWith?.path == "/dev/app")
+    #expect(launchService.openDirectoryCalledWith?.editor == .xcode)
+  }
+
   @Test("dismissPanel で isPickerVisible が解除される")
   @MainActor
   func editorPickerDismissClearsPickerVisible() {
@@ -1367,6 +2332,43 @@ struct AppCoordinatorSettingsChangeTests {
     #expect(mockDB.saveAppsCalled)
   }
 
+  @Test("設定ファイルを外部で編集するとコマンドを反映し、ディレクトリの変更ではキャッシュを再構築する")
+  @MainActor
+  func externalSettingsEditIsApplied() async throws {
+    let settingsManager = makeTempSettingsManager()
+    try settingsManager.save()
+    let mockDB = MockCacheDB(isEmpty: true)
+    let coordinator = makeCoordinator(settingsManager: settingsManager, cacheDatabase: mockDB)
+    await coordinator.start()
+    mockDB.saveAppsCalled = false
+
+    var edited = settingsManager.settings
+    edited.customCommands = [CustomCommand(alias: "serve", command: "npm run dev")]
+    edited.registeredDirectories = [RegisteredDirectory(path: "/tmp/projects")]
+    try JSONEncoder().encode(edited).write(to: settingsManager.fileURL)
+
+    await coordinator.reloadSettingsFromDisk()
+
+    #expect(coordinator.launcherViewModel.commands.map(\.alias) == ["serve"])
+    #expect(mockDB.saveAppsCalled)
+  }
+
+  @Test("読めない JSON に書き換えられても現在の設定を保つ")
+  @MainActor
+  func unreadableSettingsEditIsIgnored() async throws {
+    let settingsManager = makeTempSettingsManager()
+    settingsManager.settings.customCommands = [CustomCommand(alias: "build", command: "make")]
+    try settingsManager.save()
+    let coordinator = makeCoordinator(settingsManager: settingsManager)
+    await coordinator.start()
+
+    try Data("{ \"custom_commands\": [".utf8).write(to: settingsManager.fileURL)
+    await coordinator.reloadSettingsFromDisk()
+
+    #expect(settingsManager.settings.customCommands.map(\.alias) == ["build"])
+    #expect(coordinator.launcherViewModel.commands.map(\.alias) == ["build"])
+  }
+
   @Test("自動更新設定の変更がタイマーへ即時反映される")
   @MainActor
   func cacheUpdateSettingsRestartTimer() async throws {
@@ -1436,3 +2438,905 @@ struct AppCoordinatorSettingsChangeTests {
     #expect(coordinator.launcherViewModel.isScanning == false)
   }
 }
+
+// MARK: - 最近のプロジェクト
+
+@Suite("AppCoordinator Recent Projects")
+struct AppCoordinatorRecentProjectsTests {
+
+  @Test("ディレクトリを開くとエディタ付きでオープン履歴を記録する")
+  @MainActor
+  func executeDirectoryRecordsOpen() async throws {
+    let mockDB = MockCacheDB()
+    let coordinator = makeCoordinator(cacheDatabase: mockDB)
+
+    let result = SearchResult(
+      directoryItem: DirectoryItem(name: "proj", path: "/dev/proj", editor: "zed"),
+      score: 0.0
+    )
+    coordinator.executeResult(result)
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockDB.recordedDirectoryOpens.count == 1)
+    #expect(mockDB.recordedDirectoryOpens.first?.path == "/dev/proj")
+    #expect(mockDB.recordedDirectoryOpens.first?.editor == "zed")
+  }
+
+  @Test("URL の結果は LaunchService 経由で開く")
+  @MainActor
+  func executeURLOpensViaLaunchService() async throws {
+    let mockLaunch = MockLaunchService()
+    let coordinator = makeCoordinator(launchService: mockLaunch)
+
+    coordinator.executeResult(
+      SearchResult(
+        name: "URL を開く", kind: .webSearch, score: -10, path: "https://example.com"))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockLaunch.openURLCalledWith?.absoluteString == "https://example.com")
+  }
+
+  @Test("システム設定のパネルは URL で開き、選択履歴に記録しない")
+  @MainActor
+  func executeSystemSettingsOpensPaneURL() async throws {
+    let mockLaunch = MockLaunchService()
+    let history = makeTempSelectionHistory()
+    let coordinator = makeCoordinator(launchService: mockLaunch, selectionHistory: history)
+    let pane = SystemSettingsPane(id: "com.apple.Keyboard-Settings.extension", name: "キーボード")
+
+    coordinator.executeResult(
+      SearchResult(systemSettingsPane: pane, url: try #require(pane.url), score: 0))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(
+      mockLaunch.openURLCalledWith?.absoluteString
+        == "x-apple.systempreferences:com.apple.Keyboard-Settings.extension")
+    #expect(history.allEntries.isEmpty)
+  }
+
+  @Test("システム操作は確認なしで実行し、ランチャーを閉じる")
+  @MainActor
+  func executeSystemControlPerformsControl() async throws {
+    let controls = MockSystemControlService()
+    let coordinator = makeCoordinator(systemControlService: controls)
+    coordinator.windowManager.showLauncher()
+
+    coordinator.executeResult(SearchResult(systemControl: .toggleDarkMode, score: 0))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(controls.performed == [.toggleDarkMode])
+    #expect(coordinator.windowManager.isLauncherVisible == false)
+  }
+
+  @Test("実行に必要なショートカットがないシステム操作は検索対象にしない")
+  @MainActor
+  func unavailableSystemControlsAreNotSearchable() async {
+    let controls = MockSystemControlService()
+    controls.available = SystemControl.available(shortcutNames: [])
+    let coordinator = makeCoordinator(systemControlService: controls)
+
+    await coordinator.start()
+
+    #expect(!coordinator.launcherViewModel.systemControls.contains(.toggleDoNotDisturb))
+    #expect(coordinator.launcherViewModel.systemControls.contains(.toggleMute))
+  }
+
+  @Test("直前のアプリがない場合はウィンドウを操作せずエラーを表示する")
+  @MainActor
+  func executeWindowLayoutWithoutTargetShowsError() async throws {
+    let arranger = MockWindowArranger()
+    let coordinator = makeCoordinator(windowArranger: arranger)
+
+    coordinator.executeResult(SearchResult(windowLayout: .leftHalf, score: 0))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(arranger.arranged.isEmpty)
+    #expect(coordinator.launcherViewModel.lastError == .notFound("操作するウィンドウ"))
+  }
+
+  @Test("アプリの起動はオープン履歴に記録しない")
+  @MainActor
+  func executeAppDoesNotRecordOpen() async throws {
+    let mockDB = MockCacheDB()
+    let coordinator = makeCoordinator(cacheDatabase: mockDB)
+
+    coordinator.executeResult(
+      SearchResult(appItem: AppItem(name: "Safari", path: "/Applications/Safari.app"), score: 0))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockDB.recordedDirectoryOpens.isEmpty)
+  }
+
+  @Test("設定の件数で最近のプロジェクトを読み込み、0 件設定では読み込まない")
+  @MainActor
+  func refreshUsesConfiguredLimit() async {
+    let mockDB = MockCacheDB()
+    mockDB.recentProjectsResult = [
+      RecentProject(path: "/dev/a"), RecentProject(path: "/dev/b"), RecentProject(path: "/dev/c"),
+    ]
+    let settings = makeTempSettingsManager()
+    settings.settings.recentProjectsLimit = 2
+    let coordinator = makeCoordinator(settingsManager: settings, cacheDatabase: mockDB)
+
+    await coordinator.refreshRecentProjects()
+    #expect(coordinator.launcherViewModel.recentProjects.map(\.path) == ["/dev/a", "/dev/b"])
+
+    settings.settings.recentProjectsLimit = 0
+    await coordinator.refreshRecentProjects()
+    #expect(coordinator.launcherViewModel.recentProjects.isEmpty)
+  }
+
+  @Test("設定画面で件数を変えると最近のプロジェクトを読み直す")
+  @MainActor
+  func limitChangeRefreshesRecentProjects() async throws {
+    let mockDB = MockCacheDB()
+    mockDB.recentProjectsResult = [
+      RecentProject(path: "/dev/a"), RecentProject(path: "/dev/b"), RecentProject(path: "/dev/c"),
+    ]
+    let settings = makeTempSettingsManager()
+    settings.settings.recentProjectsLimit = 3
+    let coordinator = makeCoordinator(settingsManager: settings, cacheDatabase: mockDB)
+    await coordinator.refreshRecentProjects()
+    #expect(coordinator.launcherViewModel.recentProjects.count == 3)
+
+    try coordinator.settingsViewModel.setRecentProjectsLimit(1)
+    try await Task.sleep(nanoseconds: 100_000_000)
+    #expect(coordinator.launcherViewModel.recentProjects.map(\.path) == ["/dev/a"])
+
+    try coordinator.settingsViewModel.setRecentProjectsLimit(0)
+    try await Task.sleep(nanoseconds: 100_000_000)
+    #expect(coordinator.launcherViewModel.recentProjects.isEmpty)
+  }
+}
+
+@Suite("AppCoordinator Processes")
+struct AppCoordinatorProcessTests {
+
+  @Test("プロセスの結果を確定すると SIGTERM で終了させてランチャーを閉じる")
+  @MainActor
+  func executeProcessTerminates() {
+    let mockProcesses = MockProcessManager()
+    let coordinator = makeCoordinator(processManager: mockProcesses)
+    let process = RunningProcess(pid: 4242, name: "node")
+
+    coordinator.executeResult(SearchResult(process: process, score: 0))
+
+    #expect(mockProcesses.terminateCalledWith.map(\.pid) == [4242])
+    #expect(mockProcesses.terminateCalledWith.first?.force == false)
+    #expect(coordinator.launcherViewModel.searchQuery.isEmpty)
+  }
+
+  @Test("強制終了は SIGKILL を指定する")
+  @MainActor
+  func forceTerminate() {
+    let mockProcesses = MockProcessManager()
+    let coordinator = makeCoordinator(processManager: mockProcesses)
+
+    coordinator.terminateProcess(
+      SearchResult(process: RunningProcess(pid: 4242, name: "node"), score: 0), force: true)
+
+    #expect(mockProcesses.terminateCalledWith.first?.force == true)
+  }
+
+  @Test("終了に失敗するとエラーバナーに表示し、次の操作で消す")
+  @MainActor
+  func terminateFailureShowsError() {
+    let mockProcesses = MockProcessManager()
+    mockProcesses.terminateError = ProcessControlError.permissionDenied(pid: 4242)
+    let coordinator = makeCoordinator(processManager: mockProcesses)
+    let result = SearchResult(process: RunningProcess(pid: 4242, name: "node"), score: 0)
+
+    coordinator.terminateProcess(result, force: false)
+    #expect(coordinator.launcherViewModel.lastError == .permissionDenied("PID 4242"))
+
+    mockProcesses.terminateError = nil
+    coordinator.executeResult(result)
+    #expect(coordinator.launcherViewModel.lastError == nil)
+  }
+
+  @Test("自己診断は全項目を判定し、設定画面から実行できる")
+  @MainActor
+  func runDiagnosticsFromSettings() async {
+    let coordinator = makeCoordinator()
+
+    await coordinator.settingsViewModel.runDiagnostics()
+    let report = coordinator.settingsViewModel.diagnosticReport
+    #expect(
+      report?.checks.map(\.id)
+        == ["accessibility", "hotkeys", "cache_db", "settings_file", "icon_cache", "scan"])
+    #expect(report?.checks.first { $0.id == "cache_db" }?.status == .ok)
+    #expect(report?.checks.first { $0.id == "settings_file" }?.status == .ok)
+  }
+
+  @Test("kill プレフィックスでプロセス一覧を検索する")
+  @MainActor
+  func killPrefixSearchesProcesses() {
+    let mockProcesses = MockProcessManager()
+    mockProcesses.processes = [
+      RunningProcess(pid: 10, name: "node", cpuUsage: 12.5, memoryBytes: 200_000_000),
+      RunningProcess(pid: 11, name: "Safari", cpuUsage: 1.0, memoryBytes: 500_000_000),
+    ]
+    let coordinator = makeCoordinator(processManager: mockProcesses)
+
+    coordinator.launcherViewModel.searchQuery = "kill node"
+    coordinator.launcherViewModel.updateSearch()
+
+    #expect(coordinator.launcherViewModel.searchResults.first?.kind == .process)
+    #expect(coordinator.launcherViewModel.searchResults.first?.process?.pid == 10)
+  }
+}
+
+@Suite("AppCoordinator Favorites")
+struct AppCoordinatorFavoritesTests {
+
+  @Test("お気に入りの切り替えを DB とランチャーへ反映する")
+  @MainActor
+  func toggleFavoriteUpdatesViewModel() {
+    let mockDB = MockCacheDB()
+    let coordinator = makeCoordinator(cacheDatabase: mockDB)
+
+    #expect(coordinator.toggleFavorite(path: "/Applications/Safari.app") == true)
+    #expect(mockDB.favorites == ["/Applications/Safari.app"])
+    #expect(coordinator.launcherViewModel.favoritePaths == ["/Applications/Safari.app"])
+
+    #expect(coordinator.toggleFavorite(path: "/Applications/Safari.app") == false)
+    #expect(mockDB.favorites.isEmpty)
+    #expect(coordinator.launcherViewModel.favoritePaths.isEmpty)
+  }
+}
+
+// MARK: - CLI リクエストのテスト
+
+@Suite("AppCoordinator CLI Requests")
+struct AppCoordinatorCLIRequestTests {
+
+  @Test("get_query_suggestions は正規化した検索語で過去の検索語を返す")
+  @MainActor
+  func getQuerySuggestionsReturnsPastQueries() async {
+    let mockDB = MockCacheDB()
+    mockDB.querySuggestionsResult = ["term", "terraform"]
+    let coordinator = makeCoordinator(cacheDatabase: mockDB)
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .getQuerySuggestions, argument: " TER "))
+
+    #expect(response.ok)
+    #expect(response.suggestions == ["term", "terraform"])
+    #expect(mockDB.suggestionPrefixes == ["ter"])
+  }
+
+  @Test("copy_to_clipboard は引数のテキストをクリップボードに置く")
+  @MainActor
+  func copyToClipboardWritesArgument() async {
+    let clipboard = MockClipboard()
+    let coordinator = makeCoordinator(clipboard: clipboard)
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .copyToClipboard, argument: "hello world"))
+    #expect(response.ok)
+    #expect(clipboard.copied == ["hello world"])
+
+    let missing = await coordinator.handleIPCRequest(IPCRequest(command: .copyToClipboard))
+    #expect(!missing.ok)
+    #expect(clipboard.copied.count == 1)
+  }
+
+  @Test("display_info は接続中のディスプレイの倍率と大きさを返す")
+  @MainActor
+  func displayInfoReturnsDisplays() async {
+    var provider = StubDisplayInfoProvider()
+    provider.screens.append(
+      DisplayInfo(name: "DELL U2720Q", scale: 1, width: 2560, height: 1440, isActive: false))
+    let coordinator = makeCoordinator(displayInfoProvider: provider)
+
+    let response = await coordinator.handleIPCRequest(IPCRequest(command: .displayInfo))
+
+    #expect(response.ok)
+    #expect(
+      response.displays == [
+        IPCDisplay(
+          name: "Built-in Retina Display", scale: 2, width: 1512, height: 982, active: true),
+        IPCDisplay(name: "DELL U2720Q", scale: 1, width: 2560, height: 1440, active: false),
+      ])
+  }
+
+  @Test("get_permission_status はアクセシビリティ権限の状態を返す")
+  @MainActor
+  func getPermissionStatusReturnsAccessibility() async {
+    let permission = StubAccessibilityPermission(trusted: false)
+    let coordinator = makeCoordinator(accessibilityPermission: permission)
+
+    let denied = await coordinator.handleIPCRequest(IPCRequest(command: .getPermissionStatus))
+    #expect(denied.ok)
+    #expect(denied.permissions == IPCPermissionStatus(accessibility: false))
+    #expect(!coordinator.settingsViewModel.isAccessibilityTrusted)
+
+    permission.trusted = true
+    let granted = await coordinator.handleIPCRequest(IPCRequest(command: .getPermissionStatus))
+    #expect(granted.permissions == IPCPermissionStatus(accessibility: true))
+    #expect(coordinator.settingsViewModel.isAccessibilityTrusted)
+    #expect(permission.requestCount == 0)
+  }
+
+  @Test("request_accessibility_permission はダイアログを表示し、許可されると設定画面に反映する")
+  @MainActor
+  func requestAccessibilityPermissionPromptsAndUpdatesSettings() async {
+    let permission = StubAccessibilityPermission(trusted: false)
+    permission.grantsOnRequest = true
+    let coordinator = makeCoordinator(accessibilityPermission: permission)
+    let changes = Mutex<[Bool]>([])
+    let observer = NotificationCenter.default.addObserver(
+      forName: AccessibilityPermissionMonitor.didChangeNotification,
+      object: coordinator.accessibilityMonitor, queue: nil
+    ) { notification in
+      if let trusted = notification.userInfo?["trusted"] as? Bool {
+        changes.withLock { $0.append(trusted) }
+      }
+    }
+    defer { NotificationCenter.default.removeObserver(observer) }
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .requestAccessibilityPermission))
+
+    #expect(response.ok)
+    // 返すのはダイアログで許可する前の状態
+    #expect(response.permissions == IPCPermissionStatus(accessibility: false))
+    #expect(permission.requestCount == 1)
+    #expect(coordinator.settingsViewModel.isAccessibilityTrusted)
+    #expect(changes.withLock { $0 } == [true])
+  }
+
+  @Test("設定画面の「システム設定を開く」はアクセシビリティの設定を開く")
+  @MainActor
+  func settingsOpensAccessibilitySettings() {
+    let permission = StubAccessibilityPermission(trusted: false)
+    let coordinator = makeCoordinator(accessibilityPermission: permission)
+
+    coordinator.settingsViewModel.openAccessibilitySettings()
+    coordinator.settingsViewModel.requestAccessibilityPermission()
+
+    #expect(permission.openSettingsCount == 1)
+    #expect(permission.requestCount == 1)
+  }
+
+  @Test("ensure_icon は未変換のアイコンを変換し、キャッシュとビューモデルに記録する")
+  @MainActor
+  func ensureIconConvertsAndRecordsIcon() async throws {
+    let db = MockCacheDB()
+    let coordinator = makeCoordinator(cacheDatabase: db)
+    let appPath = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-ensure-icon-\(UUID().uuidString).app").path
+    try FileManager.default.createDirectory(atPath: appPath, withIntermediateDirectories: true)
+    defer { try? FileManager.default.removeItem(atPath: appPath) }
+    let app = AppItem(name: "Lazy", path: appPath, iconSourcePath: appPath)
+    coordinator.launcherViewModel.apps = [app]
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .ensureIcon, argument: appPath))
+
+    let expected = coordinator.iconCacheManager.cachedIconPath(for: appPath)
+    #expect(response.ok)
+    #expect(response.iconPath == expected)
+    #expect(db.updatedIconPaths == [appPath: expected])
+    let result = SearchResult(appItem: app, score: 0)
+    #expect(coordinator.launcherViewModel.iconPath(for: result) == expected)
+  }
+
+  @Test("get_icon は存在しないパスとサイズの指定が不正な場合に失敗する")
+  @MainActor
+  func getIconRejectsInvalidRequests() async {
+    let coordinator = makeCoordinator()
+
+    let missing = await coordinator.handleIPCRequest(
+      IPCRequest(command: .getIcon, argument: "/nonexistent/Missing.app"))
+    #expect(!missing.ok)
+
+    let invalidSize = await coordinator.handleIPCRequest(
+      IPCRequest(command: .getIcon, argument: NSTemporaryDirectory(), size: 0))
+    #expect(!invalidSize.ok)
+  }
+
+  @Test("search はランチャーと同じインデックスで検索した結果を返す")
+  @MainActor
+  func searchReturnsLauncherResults() async {
+    let coordinator = makeCoordinator()
+    coordinator.launcherViewModel.apps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app"),
+      AppItem(name: "Xcode", path: "/Applications/Xcode.app"),
+    ]
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .search, argument: "saf"))
+
+    #expect(response.ok)
+    #expect(response.results?.first?.path == "/Applications/Safari.app")
+    #expect(response.results?.first?.kind == "app")
+    #expect(response.results?.first?.group == "apps")
+    #expect(response.sections == nil)
+  }
+
+  @Test("get_default_items は検索語が空のときの既定の項目を返す")
+  @MainActor
+  func getDefaultItemsReturnsFavoritesAndHistory() async {
+    let coordinator = makeCoordinator()
+    coordinator.launcherViewModel.apps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app"),
+      AppItem(name: "Xcode", path: "/Applications/Xcode.app"),
+      AppItem(name: "Notes", path: "/Applications/Notes.app"),
+    ]
+    coordinator.launcherViewModel.favoritePaths = ["/Applications/Xcode.app"]
+    coordinator.launcherViewModel.history = [
+      SelectionHistoryEntry(
+        keyword: "saf", selectedPath: "/Applications/Safari.app", count: 3, lastUsed: Date())
+    ]
+
+    let response = await coordinator.handleIPCRequest(IPCRequest(command: .getDefaultItems))
+
+    #expect(response.ok)
+    #expect(
+      response.results?.map(\.path) == ["/Applications/Xcode.app", "/Applications/Safari.app"])
+    #expect(response.results?.first?.kind == "app")
+  }
+
+  @Test("見出しごとにまとめる設定の search は見出しの順に並べ、見出しごとの件数を返す")
+  @MainActor
+  func searchReturnsSectionsWhenGrouped() async {
+    let coordinator = makeCoordinator()
+    coordinator.launcherViewModel.apps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app")
+    ]
+    coordinator.launcherViewModel.directories = [
+      DirectoryItem(name: "safari-ext", path: "/dev/safari-ext")
+    ]
+    coordinator.launcherViewModel.resultSettings = ResultSettings(
+      groupResults: true, groupOrder: [.projects])
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .search, argument: "safari"))
+
+    #expect(response.ok)
+    #expect(response.results?.map(\.group) == ["projects", "apps"])
+    #expect(response.sections?.map(\.group) == ["projects", "apps"])
+    #expect(response.sections?.map(\.count) == [1, 1])
+    #expect(response.sections?.first?.title == SearchResultGroup.projects.title)
+  }
+
+  @Test("search_debug は設定で有効にした場合だけスコアの内訳を返す")
+  @MainActor
+  func searchDebugRequiresSettingAndReturnsBreakdown() async throws {
+    let settings = makeTempSettingsManager()
+    let coordinator = makeCoordinator(settingsManager: settings)
+    coordinator.launcherViewModel.directories = [
+      DirectoryItem(name: "safari-ext", path: "/dev/safari-ext", isGitRepo: true)
+    ]
+    coordinator.launcherViewModel.apps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app")
+    ]
+
+    let disabled = await coordinator.handleIPCRequest(
+      IPCRequest(command: .searchDebug, argument: "safari"))
+    #expect(!disabled.ok)
+    #expect(disabled.debugResults == nil)
+
+    try coordinator.settingsViewModel.setSearchDebugEnabled(true)
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .searchDebug, argument: "safari"))
+
+    #expect(response.ok)
+    let directory = response.debugResults?.first { $0.path == "/dev/safari-ext" }
+    #expect(directory?.kind == "directory")
+    let adjustment = directory?.categoryAdjustment ?? 0
+    #expect(abs(adjustment + SearchService.gitRepoScoreBonus) < 1e-9)
+    #expect(abs((directory?.finalScore ?? 0) - ((directory?.fuzzyScore ?? 0) + adjustment)) < 1e-9)
+    let app = response.debugResults?.first { $0.path == "/Applications/Safari.app" }
+    #expect(app?.categoryAdjustment == 0)
+    #expect(app?.finalScore == app?.fuzzyScore)
+  }
+
+  @Test("export_cache はキャッシュの項目を指定した形式でファイルに書き出す")
+  @MainActor
+  func exportCacheWritesFile() async throws {
+    let mockDB = MockCacheDB()
+    mockDB.loadedApps = [AppItem(name: "Safari", path: "/Applications/Safari.app")]
+    mockDB.loadedDirectories = [DirectoryItem(name: "proj", path: "/dev/proj")]
+    let coordinator = makeCoordinator(cacheDatabase: mockDB)
+    let path = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-export-\(UUID().uuidString).txt").path
+    defer { try? FileManager.default.removeItem(atPath: path) }
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .exportCache, argument: path, format: "csv"))
+
+    #expect(response.ok)
+    #expect(response.exportedCount == 2)
+    let csv = try String(contentsOfFile: path, encoding: .utf8)
+    #expect(csv.contains("app,Safari,/Applications/Safari.app"))
+    #expect(csv.contains("directory,proj,/dev/proj"))
+
+    let unknown = await coordinator.handleIPCRequest(
+      IPCRequest(command: .exportCache, argument: path, format: "xml"))
+    #expect(!unknown.ok)
+  }
+
+  @Test("reset_cache はキャッシュを作り直してスキャンし直す")
+  @MainActor
+  func resetCacheRecreatesStorageAndRescans() async {
+    let mockDB = MockCacheDB(isEmpty: false)
+    let coordinator = makeCoordinator(
+      cacheDatabase: mockDB,
+      appScanner: MockAppScanner(apps: [AppItem(name: "Safari", path: "/Applications/Safari.app")])
+    )
+    coordinator.launcherViewModel.convertedIconPaths = ["/Applications/Old.app": "/icons/old.png"]
+
+    let response = await coordinator.handleIPCRequest(IPCRequest(command: .resetCache))
+
+    #expect(response.ok)
+    // MockCacheDB はプロトコルの既定実装（clearCache）で作り直す
+    #expect(mockDB.clearCacheCalled)
+    #expect(mockDB.saveAppsCalled)
+    #expect(coordinator.launcherViewModel.convertedIconPaths["/Applications/Old.app"] == nil)
+  }
+
+  @Test("set_activation_policy は Dock のアイコンの表示を切り替えて設定に保存する")
+  @MainActor
+  func setActivationPolicyTogglesDockIcon() async {
+    let activationPolicy = MockActivationPolicy()
+    let coordinator = makeCoordinator(activationPolicy: activationPolicy)
+
+    let regular = await coordinator.handleIPCRequest(
+      IPCRequest(command: .setActivationPolicy, argument: "regular"))
+    #expect(regular.ok)
+    #expect(coordinator.settingsManager.settings.windowBehavior.showDockIcon)
+
+    let accessory = await coordinator.handleIPCRequest(
+      IPCRequest(command: .setActivationPolicy, argument: "accessory"))
+    #expect(accessory.ok)
+    #expect(!coordinator.settingsManager.settings.windowBehavior.showDockIcon)
+    #expect(activationPolicy.dockIconVisibilityChanges == [true, false])
+
+    let unknown = await coordinator.handleIPCRequest(
+      IPCRequest(command: .setActivationPolicy, argument: "dock"))
+    #expect(!unknown.ok)
+  }
+
+  @Test("Dock に表示する設定では、アクティブになったときにウィンドウがなければランチャーを表示する")
+  @MainActor
+  func becomingActiveShowsLauncherWithDockIcon() throws {
+    let coordinator = makeCoordinator()
+
+    coordinator.handleDidBecomeActive(hasVisibleWindows: false)
+    #expect(!coordinator.windowManager.isLauncherVisible)
+
+    var behavior = coordinator.settingsManager.settings.windowBehavior
+    behavior.showDockIcon = true
+    try coordinator.settingsViewModel.setWindowBehavior(behavior)
+    coordinator.handleDidBecomeActive(hasVisibleWindows: true)
+    #expect(!coordinator.windowManager.isLauncherVisible)
+
+    coordinator.handleDidBecomeActive(hasVisibleWindows: false)
+    #expect(coordinator.windowManager.isLauncherVisible)
+  }
+
+  @Test("pause_indexing / resume_indexing はインデックス作成の一時停止を切り替える")
+  @MainActor
+  func pauseAndResumeIndexingRequests() async {
+    let coordinator = makeCoordinator()
+
+    let paused = await coordinator.handleIPCRequest(IPCRequest(command: .pauseIndexing))
+    #expect(paused.ok)
+    #expect(coordinator.cacheBootstrap.isIndexingPaused)
+
+    let resumed = await coordinator.handleIPCRequest(IPCRequest(command: .resumeIndexing))
+    #expect(resumed.ok)
+    #expect(!coordinator.cacheBootstrap.isIndexingPaused)
+    coordinator.cacheBootstrap.stopAutoUpdate()
+  }
+
+  @Test("open はディレクトリを既定のエディタで開く")
+  @MainActor
+  func openDirectoryUsesDefaultEditor() async throws {
+    let mockLaunch = MockLaunchService()
+    let coordinator = makeCoordinator(launchService: mockLaunch)
+    let directory = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-cli-\(UUID().uuidString)")
+    try FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
+    defer { try? FileManager.default.removeItem(at: directory) }
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .open, argument: directory.path))
+
+    #expect(response.ok)
+    #expect(mockLaunch.openedDirectoryPaths == [directory.path])
+  }
+
+  @Test("存在しないパスの open は失敗を返し、何も開かない")
+  @MainActor
+  func openMissingPathFails() async {
+    let mockLaunch = MockLaunchService()
+    let coordinator = makeCoordinator(launchService: mockLaunch)
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .open, argument: "/nonexistent/ignitero-cli"))
+
+    #expect(!response.ok)
+    #expect(mockLaunch.openURLCalledWith == nil)
+    #expect(mockLaunch.openFileCalledWith == nil)
+    #expect(mockLaunch.openedDirectoryPaths.isEmpty)
+  }
+
+  @Test("open はファイルを拡張子に割り当てたアプリで開き、割り当てがなければ既定のアプリで開く")
+  @MainActor
+  func openFileUsesMappedApplication() async throws {
+    let directory = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-cli-\(UUID().uuidString)")
+    let application = directory.appendingPathComponent("Typora.app")
+    try FileManager.default.createDirectory(at: application, withIntermediateDirectories: true)
+    defer { try? FileManager.default.removeItem(at: directory) }
+    let settings = makeTempSettingsManager()
+    settings.settings.fileOpeners = [
+      FileOpener(fileExtension: "md", application: application.path)
+    ]
+    let mockLaunch = MockLaunchService()
+    let coordinator = makeCoordinator(settingsManager: settings, launchService: mockLaunch)
+    await coordinator.resolveFileOpenerApplications()
+    let markdown = directory.appendingPathComponent("README.MD")
+    let text = directory.appendingPathComponent("notes.txt")
+    try Data().write(to: markdown)
+    try Data().write(to: text)
+
+    let mapped = await coordinator.handleIPCRequest(
+      IPCRequest(command: .open, argument: markdown.path))
+    #expect(mapped.ok)
+    #expect(mockLaunch.openFileCalledWith?.path == markdown.path)
+    #expect(
+      mockLaunch.openFileCalledWith?.applicationURL?.standardizedFileURL.path
+        == application.standardizedFileURL.path)
+
+    let unmapped = await coordinator.handleIPCRequest(
+      IPCRequest(command: .open, argument: text.path))
+    #expect(unmapped.ok)
+    #expect(mockLaunch.openFileCalledWith?.path == text.path)
+    #expect(mockLaunch.openFileCalledWith?.applicationURL == nil)
+  }
+
+  @Test("list_profiles はプロファイルの一覧と使用中のプロファイルを返す")
+  @MainActor
+  func listProfilesReturnsNamesAndActiveProfile() async throws {
+    let settingsManager = makeTempSettingsManager()
+    try settingsManager.createProfile(named: "work")
+    let coordinator = makeCoordinator(settingsManager: settingsManager)
+
+    let response = await coordinator.handleIPCRequest(IPCRequest(command: .listProfiles))
+
+    #expect(response.ok)
+    #expect(response.profiles == ["default", "work"])
+    #expect(response.activeProfile == "default")
+  }
+
+  @Test("存在しないプロファイルへの switch_profile は失敗を返す")
+  @MainActor
+  func switchToMissingProfileFails() async {
+    let mockCache = MockCacheDB(isEmpty: false)
+    let coordinator = makeCoordinator(cacheDatabase: mockCache)
+
+    let response = await coordinator.handleIPCRequest(
+      IPCRequest(command: .switchProfile, argument: "work"))
+
+    #expect(!response.ok)
+    #expect(mockCache.selectedPartitions.isEmpty)
+  }
+
+  @Test("restore_settings_backup はバックアップから復元してランチャーへ反映する")
+  @MainActor
+  func restoreSettingsBackupAppliesRestoredSettings() async throws {
+    let settingsManager = makeTempSettingsManager()
+    settingsManager.settings.customCommands = [CustomCommand(alias: "build", command: "make")]
+    try settingsManager.save()
+    settingsManager.settings.customCommands = []
+    try settingsManager.save()
+    let coordinator = makeCoordinator(settingsManager: settingsManager)
+
+    let response = await coordinator.handleIPCRequest(IPCRequest(command: .restoreSettingsBackup))
+    let invalid = await coordinator.handleIPCRequest(
+      IPCRequest(command: .restoreSettingsBackup, argument: "latest"))
+
+    #expect(response.ok)
+    #expect(coordinator.launcherViewModel.commands.map(\.alias) == ["build"])
+    #expect(!invalid.ok)
+  }
+}
+
+// MARK: - 設定プロファイルのテスト
+
+@Suite("AppCoordinator Profiles")
+struct AppCoordinatorProfileTests {
+
+  @Test("切り替えるとプロファイルのキャッシュを読み込み、登録ディレクトリを反映する")
+  @MainActor
+  func switchProfileSelectsPartitionAndReloads() async throws {
+    let settingsManager = makeTempSettingsManager()
+    try settingsManager.createProfile(named: "work")
+    try settingsManager.switchProfile(to: "work")
+    try settingsManager.addDirectory(RegisteredDirectory(path: "/tmp/work"))
+    try settingsManager.switchProfile(to: "default")
+    let mockCache = MockCacheDB(isEmpty: false)
+    mockCache.loadedApps = [AppItem(name: "Slack", path: "/Applications/Slack.app")]
+    let coordinator = makeCoordinator(settingsManager: settingsManager, cacheDatabase: mockCache)
+
+    try await coordinator.switchProfile(to: "work")
+
+    #expect(mockCache.selectedPartitions == ["work"])
+    #expect(settingsManager.settings.activeProfile == "work")
+    #expect(settingsManager.settings.registeredDirectories.map(\.path) == ["/tmp/work"])
+    #expect(mockCache.loadAppsCalled)
+    #expect(!mockCache.saveAppsCalled)
+  }
+
+  @Test("設定画面でプロファイルを削除すると、そのプロファイルのキャッシュ DB も削除する")
+  @MainActor
+  func deleteProfileRemovesCachePartition() throws {
+    let settingsManager = makeTempSettingsManager()
+    try settingsManager.createProfile(named: "client/a")
+    let mockCache = MockCacheDB(isEmpty: false)
+    let coordinator = makeCoordinator(settingsManager: settingsManager, cacheDatabase: mockCache)
+
+    try coordinator.settingsViewModel.deleteProfile(named: "client/a")
+
+    #expect(settingsManager.profileNames == ["default"])
+    #expect(mockCache.removedPartitions == ["client_a"])
+  }
+}
+
+// MARK: - URL スキームのテスト
+
+@Suite("AppCoordinator Deep Links")
+struct AppCoordinatorDeepLinkTests {
+
+  @Test("search は検索語を入力した状態でランチャーを表示する")
+  @MainActor
+  func searchLinkPrefillsQuery() async {
+    let coordinator = makeCoordinator()
+    await coordinator.start()
+    coordinator.launcherViewModel.apps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app")
+    ]
+
+    coordinator.handleDeepLink(URL(string: "ignitero://search?q=saf")!)
+
+    #expect(coordinator.windowManager.isLauncherVisible)
+    #expect(coordinator.launcherViewModel.searchQuery == "saf")
+    #expect(coordinator.launcherViewModel.searchResults.first?.name == "Safari")
+  }
+
+  @Test("open は検索対象のアプリだけを起動する")
+  @MainActor
+  func openLinkLaunchesOnlyIndexedItems() async throws {
+    let mockLaunch = MockLaunchService()
+    let coordinator = makeCoordinator(launchService: mockLaunch)
+    await coordinator.start()
+    coordinator.launcherViewModel.apps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app")
+    ]
+
+    coordinator.handleDeepLink(URL(string: "ignitero://open?path=/Applications/Safari.app")!)
+    coordinator.handleDeepLink(URL(string: "ignitero://open?path=/tmp/evil.command")!)
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockLaunch.launchedAppPaths == ["/Applications/Safari.app"])
+    #expect(mockLaunch.openURLCalledWith == nil)
+    #expect(coordinator.launcherViewModel.lastError == .notFound("/tmp/evil.command"))
+  }
+
+  @Test("起動完了前に受け取った URL は起動完了後に処理する")
+  @MainActor
+  func linksBeforeStartAreDeferred() async {
+    let coordinator = makeCoordinator()
+
+    coordinator.handleDeepLink(URL(string: "ignitero://search?q=xcode")!)
+    #expect(!coordinator.windowManager.isLauncherVisible)
+
+    await coordinator.start()
+    #expect(coordinator.launcherViewModel.searchQuery == "xcode")
+  }
+}
+
+// MARK: - メニューバーの最近使った項目テスト
+
+@Suite("AppCoordinator Recent Items")
+struct AppCoordinatorRecentItemsTests {
+
+  @Test("最近使った項目は新しい順に重複なく、インデックスにある項目だけを返す")
+  @MainActor
+  func recentItemsAreDistinctAndIndexed() async throws {
+    let history = makeTempSelectionHistory()
+    let coordinator = makeCoordinator(selectionHistory: history)
+    await coordinator.start()
+    coordinator.launcherViewModel.apps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app"),
+      AppItem(name: "Xcode", path: "/Applications/Xcode.app"),
+    ]
+
+    history.record(keyword: "saf", path: "/Applications/Safari.app")
+    try await Task.sleep(nanoseconds: 10_000_000)
+    history.record(keyword: "x", path: "/Applications/Xcode.app")
+    try await Task.sleep(nanoseconds: 10_000_000)
+    history.record(keyword: "s", path: "/Applications/Safari.app")
+    history.record(keyword: "gone", path: "/Applications/Removed.app")
+
+    let items = coordinator.recentItems(limit: 5)
+    #expect(items.map(\.path) == ["/Applications/Safari.app", "/Applications/Xcode.app"])
+    #expect(coordinator.recentItems(limit: 1).map(\.name) == ["Safari"])
+  }
+}
+
+// MARK: - メニューバーアイコンの状態テスト
+
+@Suite("AppCoordinator Menu Bar Icon State")
+struct AppCoordinatorMenuBarIconStateTests {
+
+  @Test("起動処理中は更新中、完了後は通常になる")
+  @MainActor
+  func refreshingUntilReady() async {
+    let coordinator = makeCoordinator()
+    #expect(coordinator.menuBarIconState == .refreshing)
+
+    await coordinator.start()
+    #expect(coordinator.menuBarIconState == .idle)
+  }
+
+  @Test("アップデートがあればアップデートありになる")
+  @MainActor
+  func updateAvailableAfterNotification() async {
+    let coordinator = makeCoordinator()
+    await coordinator.start()
+
+    coordinator.launcherViewModel.showUpdateBanner(version: "9.9.9")
+    #expect(coordinator.menuBarIconState == .updateAvailable("9.9.9"))
+    #expect(coordinator.menuBarIconState.toolTip(in: .ja).contains("v9.9.9"))
+  }
+}
+
+// MARK: - アンインストール済みアプリの除去テスト
+
+@Suite("AppCoordinator Missing Apps")
+struct AppCoordinatorMissingAppsTests {
+
+  @Test("キャッシュ読込時に存在しないアプリをキャッシュから除く")
+  @MainActor
+  func missingAppsArePrunedOnLoad() async {
+    let mockDB = MockCacheDB(isEmpty: false)
+    mockDB.loadedApps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app"),
+      AppItem(name: "Removed", path: "/Applications/Removed.app"),
+    ]
+    let coordinator = makeCoordinator(
+      cacheDatabase: mockDB,
+      fileSystem: ExistingPathsFileSystem(missingPaths: ["/Applications/Removed.app"]))
+
+    await coordinator.start()
+
+    #expect(coordinator.launcherViewModel.apps.map(\.name) == ["Safari"])
+    #expect(mockDB.deletedAppPaths == ["/Applications/Removed.app"])
+  }
+
+  @Test("起動時に見つからないアプリは起動せずに除き、検索し直す")
+  @MainActor
+  func missingAppIsRemovedOnLaunch() async throws {
+    let mockLaunch = MockLaunchService()
+    let mockDB = MockCacheDB(isEmpty: false)
+    let coordinator = makeCoordinator(
+      cacheDatabase: mockDB, launchService: mockLaunch,
+      fileSystem: ExistingPathsFileSystem(missingPaths: ["/Applications/Removed.app"]))
+    await coordinator.start()
+    let removed = AppItem(name: "Removed", path: "/Applications/Removed.app")
+    coordinator.launcherViewModel.apps = [removed]
+
+    coordinator.executeResult(SearchResult(appItem: removed, score: 0))
+    try await Task.sleep(nanoseconds: 100_000_000)
+
+    #expect(mockLaunch.launchAppCalledWith == nil)
+    #expect(mockDB.deletedAppPaths == ["/Applications/Removed.app"])
+    #expect(coordinator.launcherViewModel.apps.isEmpty)
+    #expect(coordinator.launcherViewModel.lastError == .notFound("/Applications/Removed.app"))
+  }
+}
@@ -0,0 +1,95 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+private func makeTempLogFile(
+  level: LogLevel = .info, maxFileSize: Int = LogFile.defaultMaxFileSize,
+  maxArchivedFiles: Int = LogFile.defaultMaxArchivedFiles
+) -> LogFile {
+  let dir = FileManager.default.temporaryDirectory
+    .appendingPathComponent("ignitero-log-test-\(UUID().uuidString)")
+  return LogFile(
+    directory: dir, level: level, maxFileSize: maxFileSize, maxArchivedFiles: maxArchivedFiles)
+}
+
+@Suite("LogFile")
+struct LogFileTests {
+
+  @Test("設定したレベル未満のログは書き込まない")
+  func filtersByLevel() {
+    let logFile = makeTempLogFile(level: .warning)
+    logFile.write(.info, category: "Test", message: "ignored")
+    logFile.write(.error, category: "Test", message: "scan failed")
+
+    let lines = logFile.recentLines()
+    #expect(lines.count == 1)
+    #expect(lines[0].hasSuffix("[ERROR] [Test] scan failed"))
+  }
+
+  @Test("改行を含む本文は 1 行にまとめる")
+  func flattensMultilineMessages() {
+    let logFile = makeTempLogFile()
+    logFile.write(.info, category: "Test", message: "first\nsecond")
+
+    #expect(logFile.recentLines().map { $0.hasSuffix("first second") } == [true])
+  }
+
+  @Test("上限サイズを超えるとローテーションし、古いファイルは上限数まで残す")
+  func rotatesFiles() throws {
+    let logFile = makeTempLogFile(maxFileSize: 200, maxArchivedFiles: 2)
+    for i in 0..<20 {
+      let padding = String(repeating: "x", count: 40)
+      logFile.write(.info, category: "Test", message: "message \(i) \(padding)")
+    }
+
+    // ローテーション済みのファイルもさかのぼって古い順に返す（書き込みの完了を待ってから読む）
+    let lines = logFile.recentLines(limit: 4)
+    #expect(lines.count == 4)
+    #expect(lines.last?.contains("message 19 ") == true)
+    #expect(lines.first?.contains("message 16 ") == true)
+
+    let files = try FileManager.default.contentsOfDirectory(atPath: logFile.directory.path)
+    #expect(Set(files) == ["ignitero.log", "ignitero.1.log", "ignitero.2.log"])
+  }
+
+  @Test("別スレッドからの書き込みも欠けずに 1 行ずつ残す")
+  func concurrentWritesAreSerialized() {
+    let logFile = makeTempLogFile()
+    DispatchQueue.concurrentPerform(iterations: 50) { i in
+      logFile.write(.info, category: "Test", message: "message \(i)")
+    }
+
+    let lines = logFile.recentLines()
+    #expect(lines.count == 50)
+    #expect(lines.allSatisfy { $0.contains("[INFO] [Test] message ") })
+  }
+}
+
+@Suite("LogMessage")
+struct LogMessageTests {
+
+  @Test("補間した文字列は統合ログでは伏せ、整数・真偽値と public 指定の値は公開する")
+  func redactsPrivateValues() {
+    let path = "/Users/alice/secret"
+    let command = "search"
+    let message: LogMessage =
+      "Opened \(path) via \(command, privacy: .public) (\(3) items, cached: \(true))"
+
+    #expect(message.text == "Opened /Users/alice/secret via search (3 items, cached: true)")
+    #expect(message.redactedText == "Opened <private> via search (3 items, cached: true)")
+  }
+
+  @Test("ログファイルには伏せずに書き込む")
+  func logFileKeepsFullText() {
+    let logFile = makeTempLogFile()
+    let logger = AppLogger(category: "Test", logFile: logFile)
+    let path = "/dev/project"
+
+    logger.info("Scanned \(path)")
+
+    let lines = logFile.recentLines()
+    #expect(lines.count == 1)
+    #expect(lines.first?.hasSuffix("[INFO] [Test] Scanned /dev/project") == true)
+  }
+}
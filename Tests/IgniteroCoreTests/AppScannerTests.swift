@@ -23,7 +23,8 @@ private func createFakeApp(
   displayName: String? = nil,
   bundleName: String? = nil,
   iconFile: String? = nil,
-  iconName: String? = nil
+  iconName: String? = nil,
+  metadata: [String: Any] = [:]
 ) throws -> String {
   let appPath = (directory as NSString).appendingPathComponent(name)
   let contentsPath = (appPath as NSString).appendingPathComponent("Contents")
@@ -35,6 +36,7 @@ private func createFakeApp(
   if let bundleName { plistDict["CFBundleName"] = bundleName }
   if let iconFile { plistDict["CFBundleIconFile"] = iconFile }
   if let iconName { plistDict["CFBundleIconName"] = iconName }
+  plistDict.merge(metadata) { _, new in new }
 
   let plistPath = (contentsPath as NSString).appendingPathComponent("Info.plist")
   let plistData = try PropertyListSerialization.data(
@@ -285,6 +287,59 @@ struct AppScannerPlistExtractionTests {
     #expect(bundleName == "SimpleApp")
   }
 
+  @Test func extractsVersionBundleIdentifierAndCategory() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let appPath = try createFakeApp(
+      at: tmpDir, name: "Meta.app",
+      bundleName: "Meta",
+      metadata: [
+        "CFBundleShortVersionString": "2.4.1",
+        "CFBundleIdentifier": "com.example.Meta",
+        "LSApplicationCategoryType": "public.app-category.developer-tools",
+      ]
+    )
+
+    let app = try #require(AppScanner().extractAppInfo(from: appPath))
+
+    #expect(app.version == "2.4.1")
+    #expect(app.bundleIdentifier == "com.example.Meta")
+    #expect(app.category == "public.app-category.developer-tools")
+    #expect(app.categoryName == "developer-tools")
+  }
+
+  @Test func extractsSpotlightKeywords() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let appPath = try createFakeApp(
+      at: tmpDir, name: "Surf.app",
+      bundleName: "Surf",
+      metadata: [
+        "CFBundleSpotlightKeywords": ["web", "internet", "Surf"],
+        "CFBundleGetInfoString": "Surf 3.2, Copyright © 2024 Example Inc.",
+        "CFBundleURLTypes": [["CFBundleURLSchemes": ["http", "https"]]],
+      ]
+    )
+
+    let app = try #require(AppScanner().extractAppInfo(from: appPath))
+
+    // 名前と同じ語と、開発元の名前を含む CFBundleGetInfoString の語は除く
+    #expect(app.keywords == ["web", "internet", "browser", "ブラウザ"])
+  }
+
+  @Test func extractsChromeWebAppManifestNames() {
+    let keywords = AppScanner.spotlightKeywords(fromInfoPlist: [
+      "CFBundleName": "YT Music",
+      "CrAppModeShortcutName": "YouTube Music",
+      "CrAppModeShortcutURL": "https://www.music.youtube.com/",
+      "CFBundleSpotlightKeywords": "music, player",
+    ])
+
+    #expect(keywords == ["music", "player", "YouTube Music", "music.youtube.com"])
+  }
+
   @Test func returnsNilsForMissingPlist() throws {
     let tmpDir = try makeTempDir()
     defer { cleanup(tmpDir) }
@@ -390,6 +445,50 @@ struct AppScannerPlistExtractionTests {
     // ファイルが実在しないため nil
     #expect(iconPath == nil)
   }
+
+  @Test func detectsAssetCatalog() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let withCatalog = try createFakeApp(at: tmpDir, name: "Modern.app", iconName: "AppIcon")
+    let resources = (withCatalog as NSString).appendingPathComponent("Contents/Resources")
+    try FileManager.default.createDirectory(atPath: resources, withIntermediateDirectories: true)
+    FileManager.default.createFile(
+      atPath: (resources as NSString).appendingPathComponent("Assets.car"), contents: Data())
+    let withoutCatalog = try createFakeApp(at: tmpDir, name: "Legacy.app")
+
+    let scanner = AppScanner()
+    #expect(scanner.hasAssetCatalog(for: withCatalog))
+    #expect(!scanner.hasAssetCatalog(for: withoutCatalog))
+    // .icns がないため従来の解決は nil のまま
+    #expect(scanner.iconFilePath(for: withCatalog) == nil)
+  }
+
+  @Test("Assets.car のみを持つアプリはアプリ自身を変換元にし、表示時にアイコンを生成する")
+  func cachesAssetCatalogIcon() async throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let appPath = try createFakeApp(at: tmpDir, name: "Modern.app", iconName: "AppIcon")
+    let resources = (appPath as NSString).appendingPathComponent("Contents/Resources")
+    try FileManager.default.createDirectory(atPath: resources, withIntermediateDirectories: true)
+    FileManager.default.createFile(
+      atPath: (resources as NSString).appendingPathComponent("Assets.car"), contents: Data())
+
+    let iconDir = (tmpDir as NSString).appendingPathComponent("icons")
+    let manager = IconCacheManager(cacheDirectory: iconDir)
+    let scanner = AppScanner(scanTargets: [], iconCacheManager: manager)
+
+    let result = scanner.resolveIconSources(for: [AppItem(name: "Modern", path: appPath)])
+    #expect(result.first?.iconSourcePath == appPath)
+    #expect(result.first?.iconPath == nil)
+
+    let queue = IconConversionQueue(iconCacheManager: manager)
+    let iconPath = await queue.ensureIcon(
+      appPath: appPath, sourcePath: result.first?.iconSourcePath)
+    #expect(iconPath == manager.cachedIconPath(for: appPath))
+    #expect(FileManager.default.fileExists(atPath: manager.cachedIconPath(for: appPath)))
+  }
 }
 
 // MARK: - ローカライズ名テスト
@@ -540,6 +639,57 @@ struct AppScannerLocalizedNameTests {
 
     #expect(localizedName == nil)
   }
+
+  @Test("旧形式の Japanese.lproj からも読み取る")
+  func readsLegacyLprojName() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let appPath = try createLocalizedApp(
+      at: tmpDir, name: "Legacy.app", bundleName: "Legacy",
+      localizedName: "レガシー", locale: "Japanese")
+
+    #expect(AppScanner().localizedNameFromLproj(for: appPath, locale: "ja") == "レガシー")
+  }
+
+  @Test("InfoPlist.loctable からローカライズ名を読み取る")
+  func readsLocalizedNameFromLoctable() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let appPath = try createFakeApp(at: tmpDir, name: "Preview.app", bundleName: "Preview")
+    let loctable: [String: Any] = [
+      "ja": ["CFBundleDisplayName": "プレビュー", "CFBundleName": "プレビュー"],
+      "en": ["CFBundleName": "Preview"],
+    ]
+    let loctablePath = (appPath as NSString).appendingPathComponent(
+      "Contents/Resources/InfoPlist.loctable")
+    try FileManager.default.createDirectory(
+      atPath: (loctablePath as NSString).deletingLastPathComponent,
+      withIntermediateDirectories: true)
+    try PropertyListSerialization.data(fromPropertyList: loctable, format: .binary, options: 0)
+      .write(to: URL(fileURLWithPath: loctablePath))
+
+    let scanner = AppScanner()
+    #expect(scanner.localizedNameFromLproj(for: appPath, locale: "ja") == "プレビュー")
+    #expect(
+      scanner.localizedNames(for: appPath, locales: ["ja", "en", "fr"]) == ["プレビュー", "Preview"])
+  }
+
+  @Test("日本語と英語の名前をどちらも検索キーに含める")
+  func extractsBothLocalizedAndEnglishNames() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let appPath = try createLocalizedApp(
+      at: tmpDir, name: "Preview.app", bundleName: "Preview", localizedName: "プレビュー")
+
+    let app = try #require(AppScanner().extractAppInfo(from: appPath))
+    let names = Set([app.name] + [app.originalName].compactMap { $0 } + app.alternateNames)
+
+    #expect(names.isSuperset(of: ["プレビュー", "Preview"]))
+    #expect(!app.alternateNames.contains(app.name))
+  }
 }
 
 // MARK: - App 情報抽出テスト
@@ -782,6 +932,74 @@ struct AppScannerFullScanTests {
 
     #expect(results.isEmpty)
   }
+
+  @Test("多数のバンドルを並列に解析しても件数と名前順を保つ")
+  func scansManyBundlesInParallel() async throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    for i in 0..<40 {
+      _ = try createFakeApp(
+        at: tmpDir, name: "Parallel\(i).app", bundleName: String(format: "Parallel %02d", i))
+    }
+    let scanner = AppScanner(scanTargets: [AppScanner.ScanTarget(path: tmpDir, maxDepth: 1)])
+
+    let results = try await scanner.scanApplications(excludedApps: [])
+
+    #expect(results.map(\.name) == (0..<40).map { String(format: "Parallel %02d", $0) })
+    #expect(results.allSatisfy { $0.bundleModificationTime != nil })
+  }
+
+  @Test("更新日時が前回と同じバンドルは Info.plist を読み直さない")
+  func reusesUnchangedBundles() async throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let appPath = try createFakeApp(at: tmpDir, name: "Same.app", bundleName: "Same")
+    let scanner = AppScanner(scanTargets: [AppScanner.ScanTarget(path: tmpDir, maxDepth: 1)])
+    let first = try #require(try await scanner.scanApplications(excludedApps: []).first)
+    // 前回の項目を使ったことが分かるよう、キャッシュ側の名前だけ変える
+    let cached = AppItem(
+      name: "Cached", path: appPath, bundleIdentifier: "com.example.cached",
+      bundleModificationTime: first.bundleModificationTime)
+
+    let rescanned = try await scanner.scanApplications(excludedApps: [], previous: [cached])
+
+    #expect(rescanned.map(\.name) == ["Cached"])
+    #expect(rescanned.first?.bundleIdentifier == "com.example.cached")
+  }
+
+  @Test("読み直さないバンドルも前回の項目の Info.plist の名前で除外する")
+  func excludesUnchangedBundlesByPlistName() async throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let appPath = try createFakeApp(
+      at: tmpDir, name: "Exclude.app", displayName: "Exclude Display", bundleName: "ExcludeBundle")
+    let scanner = AppScanner(scanTargets: [AppScanner.ScanTarget(path: tmpDir, maxDepth: 1)])
+    let cached = try #require(try await scanner.scanApplications(excludedApps: []).first)
+
+    let rescanned = try await scanner.scanApplications(
+      excludedApps: ["Exclude Display"], previous: [cached])
+
+    #expect(rescanned.isEmpty)
+    #expect(cached.path == appPath)
+  }
+
+  @Test("更新日時が変わったバンドルと前回にないバンドルは読み直す")
+  func reparsesChangedBundles() async throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let appPath = try createFakeApp(at: tmpDir, name: "Changed.app", bundleName: "Changed")
+    _ = try createFakeApp(at: tmpDir, name: "New.app", bundleName: "New")
+    let scanner = AppScanner(scanTargets: [AppScanner.ScanTarget(path: tmpDir, maxDepth: 1)])
+    let stale = AppItem(name: "Stale", path: appPath, bundleModificationTime: 0)
+
+    let rescanned = try await scanner.scanApplications(excludedApps: [], previous: [stale])
+
+    #expect(rescanned.map(\.name) == ["Changed", "New"])
+  }
 }
 
 // MARK: - ローカライズ名解決テスト
@@ -866,3 +1084,127 @@ struct AppScannerIsExcludedItemTests {
     #expect(scanFiltered.map(\.path) == postFiltered.map(\.path))
   }
 }
+
+// MARK: - アイコンの変換元
+
+@Suite("AppScanner Icon Sources")
+struct AppScannerIconSourceTests {
+
+  @Test("アイコンを持たないアプリは変換元なしのまま、入力順と件数を維持する")
+  func resolveIconSourcesPreservesOrder() async throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    var apps: [AppItem] = []
+    for i in 0..<40 {
+      let path = try createFakeApp(at: tmpDir, name: "App\(i).app", bundleName: "App\(i)")
+      apps.append(AppItem(name: "App\(i)", path: path))
+    }
+    let scanner = AppScanner(
+      scanTargets: [], iconCacheManager: IconCacheManager(cacheDirectory: tmpDir + "/icons"))
+
+    let result = scanner.resolveIconSources(for: apps)
+
+    #expect(result.map(\.path) == apps.map(\.path))
+    #expect(result.allSatisfy { $0.iconPath == nil && $0.iconSourcePath == nil })
+  }
+
+  @Test("スキャンでは .icns を変換せず、変換済みのアイコンだけを設定する")
+  func scanRecordsIcnsWithoutConverting() async throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let appPath = try createFakeApp(at: tmpDir, name: "Icon.app", iconFile: "AppIcon")
+    let resources = (appPath as NSString).appendingPathComponent("Contents/Resources")
+    try FileManager.default.createDirectory(atPath: resources, withIntermediateDirectories: true)
+    let icnsPath = (resources as NSString).appendingPathComponent("AppIcon.icns")
+    FileManager.default.createFile(atPath: icnsPath, contents: Data())
+    let manager = IconCacheManager(cacheDirectory: tmpDir + "/icons")
+    let scanner = AppScanner(
+      scanTargets: [AppScanner.ScanTarget(path: tmpDir, maxDepth: 1)], iconCacheManager: manager)
+
+    let scanned = try await scanner.scanApplications(excludedApps: [])
+    #expect(scanned.first?.iconSourcePath == icnsPath)
+    #expect(scanned.first?.iconPath == nil)
+    #expect(manager.usage().fileCount == 0)
+
+    // 表示時に変換済みなら、次のスキャンでそのパスを引き継ぐ
+    try manager.ensureCacheDirectory()
+    try Data(count: 10).write(to: URL(fileURLWithPath: manager.cachedIconPath(for: appPath)))
+    let rescanned = try await scanner.scanApplications(excludedApps: [])
+    #expect(rescanned.first?.iconPath == manager.cachedIconPath(for: appPath))
+  }
+}
+
+// MARK: - Launch Services 登録アプリ
+
+/// 固定のパスを返す登録アプリの探索元
+private struct StubRegisteredAppDiscovery: RegisteredAppDiscovering {
+  let paths: [String]
+
+  func registeredAppPaths() async -> [String] {
+    paths
+  }
+}
+
+@Suite("AppScanner Registered Apps")
+struct AppScannerRegisteredAppsTests {
+
+  @Test("lsregister の出力からアプリのパスだけを取り出す")
+  func parsesLsregisterDump() {
+    let dump = """
+      bundle id:                  1234
+      path:                       /Users/dev/Library/Setapp/Foo.app (0x1a2b)
+      name:                       Foo
+      --------------------------------------------------------------------------------
+      path:                       /Users/dev/Library/Setapp/Foo.app (0x1a2c)
+      path:                       /opt/homebrew/Caskroom/bar/Bar.app (0x2000)
+      path:                       /Applications/Xcode.app/Contents/Developer/Simulator.app (0x3)
+      path:                       /Users/dev/Library/Bar.appex (0x4)
+      path:                       /Users/dev/.Trash/Old.app (0x5)
+      path:                       /System/Library/CoreServices/Dock.app (0x6)
+      """
+
+    #expect(
+      LaunchServicesAppDiscovery.appPaths(fromDump: dump) == [
+        "/Users/dev/Library/Setapp/Foo.app",
+        "/opt/homebrew/Caskroom/bar/Bar.app",
+      ])
+  }
+
+  @Test("スキャン済みのパスを除いて launchServices 由来として返す")
+  func returnsUnknownAppsTaggedWithSource() async throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+    let known = try createFakeApp(at: tmpDir, name: "Known.app")
+    let extra = try createFakeApp(at: tmpDir, name: "Extra.app", bundleName: "Extra")
+
+    let scanner = AppScanner(
+      scanTargets: [],
+      iconCacheManager: IconCacheManager(
+        cacheDirectory: (tmpDir as NSString).appendingPathComponent("icons")),
+      registeredAppDiscovery: StubRegisteredAppDiscovery(paths: [known, extra, extra]))
+
+    let apps = await scanner.scanRegisteredApplications(excludingPaths: [known])
+
+    #expect(apps.map(\.path) == [extra])
+    #expect(apps.first?.name == "Extra")
+    #expect(apps.first?.source == .launchServices)
+  }
+
+  @Test("既定のスキャンで見つけたアプリは scan 由来になる")
+  func scannedAppsAreTaggedAsScan() async throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+    _ = try createFakeApp(at: tmpDir, name: "Local.app")
+
+    let scanner = AppScanner(
+      scanTargets: [AppScanner.ScanTarget(path: tmpDir, maxDepth: 1)],
+      iconCacheManager: IconCacheManager(
+        cacheDirectory: (tmpDir as NSString).appendingPathComponent("icons")))
+
+    let apps = try await scanner.scanApplications(excludedApps: [])
+
+    #expect(apps.map(\.source) == [.scan])
+  }
+}
@@ -16,6 +16,8 @@ private final class CacheBootstrapMockDB: CacheDatabaseProtocol, @unchecked Send
   var loadedApps: [AppItem] = []
   var savedDirectories: [DirectoryItem] = []
   var loadedDirectories: [DirectoryItem] = []
+  var savedTerminalTargets: [TerminalTarget]?
+  var recordedScanMetrics: ScanMetrics?
 
   init(isEmpty: Bool = true) {
     self.isEmptyResult = isEmpty
@@ -48,20 +50,97 @@ private final class CacheBootstrapMockDB: CacheDatabaseProtocol, @unchecked Send
   func clearCache() throws {
     clearCacheCalled = true
   }
+
+  func saveTerminalTargets(_ targets: [TerminalTarget]) throws {
+    savedTerminalTargets = targets
+  }
+
+  func recordScanMetrics(_ metrics: ScanMetrics) throws {
+    recordedScanMetrics = metrics
+  }
+}
+
+// MARK: - モック TerminalTargetProvider
+
+private struct CacheBootstrapMockTerminalTargets: TerminalTargetProviding {
+  let result: [TerminalTarget]
+
+  func targets() async -> [TerminalTarget] {
+    result
+  }
+}
+
+private struct CacheBootstrapMockRecentWorkspaces: RecentWorkspaceProviding {
+  let result: [DirectoryItem]
+
+  func recentWorkspaces(for editors: [EditorType]) async -> [DirectoryItem] {
+    result
+  }
+}
+
+/// 最近開いたフォルダを調べている間にインデックス作成を一時停止する（スキャン中の一時停止の再現用）
+private final class CacheBootstrapPausingRecentWorkspaces: RecentWorkspaceProviding,
+  @unchecked Sendable
+{
+  weak var bootstrap: CacheBootstrap?
+
+  func recentWorkspaces(for editors: [EditorType]) async -> [DirectoryItem] {
+    await MainActor.run { bootstrap?.pauseIndexing() }
+    return [DirectoryItem(name: "outside", path: "/opt/outside")]
+  }
 }
 
 // MARK: - モック AppScanner
 
 private struct CacheBootstrapMockAppScanner: AppScannerProtocol {
   let apps: [AppItem]
+  /// Launch Services に登録されたアプリとして返すアプリ
+  let registeredApps: [AppItem]
 
-  init(apps: [AppItem] = []) {
+  init(apps: [AppItem] = [], registeredApps: [AppItem] = []) {
     self.apps = apps
+    self.registeredApps = registeredApps
   }
 
   func scanApplications(excludedApps: [String]) throws -> [AppItem] {
     apps
   }
+
+  func scanRegisteredApplications(excludingPaths knownPaths: Set<String>) async -> [AppItem] {
+    registeredApps.filter { !knownPaths.contains($0.path) }
+  }
+}
+
+/// 前回のスキャン結果として渡されたアプリを記録するスキャナー
+private final class CacheBootstrapRecordingAppScanner: AppScannerProtocol, @unchecked Sendable {
+  var receivedPrevious: [AppItem]?
+
+  func scanApplications(excludedApps: [String]) throws -> [AppItem] {
+    []
+  }
+
+  func scanApplications(excludedApps: [String], previous: [AppItem]) throws -> [AppItem] {
+    receivedPrevious = previous
+    return previous
+  }
+}
+
+/// アプリのスキャン中にインデックス作成を一時停止し、打ち切りが伝わったかを記録するスキャナー
+private final class CacheBootstrapPausingAppScanner: AppScannerProtocol, @unchecked Sendable {
+  weak var bootstrap: CacheBootstrap?
+  var observedCancellation = false
+
+  func scanApplications(excludedApps: [String]) throws -> [AppItem] {
+    []
+  }
+
+  func scanApplications(
+    excludedApps: [String], previous: [AppItem], cancellation: ScanCancellation
+  ) async throws -> [AppItem] {
+    await MainActor.run { bootstrap?.pauseIndexing() }
+    observedCancellation = cancellation.isCancelled
+    return []
+  }
 }
 
 // MARK: - モック DirectoryScanner
@@ -78,6 +157,23 @@ private struct CacheBootstrapMockDirScanner: DirectoryScannerProtocol {
   }
 }
 
+/// 指定パスのディレクトリを読み取れないスキャナー（切断されたボリュームの再現用）
+private struct CacheBootstrapUnavailableDirScanner: DirectoryScannerProtocol {
+  let unavailable: Set<String>
+
+  func scan(directories: [RegisteredDirectory]) throws -> ScanResult {
+    ScanResult(directories: [], apps: [])
+  }
+
+  func scan(directory: RegisteredDirectory) throws -> ScanResult {
+    guard !unavailable.contains(directory.path) else {
+      throw FileSystemError.directoryNotFound(directory.path)
+    }
+    return ScanResult(
+      directories: [DirectoryItem(name: "fresh", path: directory.path + "/fresh")], apps: [])
+  }
+}
+
 // MARK: - テスト
 
 @Suite("CacheBootstrap")
@@ -200,7 +296,7 @@ struct CacheBootstrapTests {
 
     await bootstrap.rebuildCache()
 
-    // saveApps/saveDirectories が DELETE+INSERT で置換するため
+    // saveApps/saveDirectories が差分適用で置換するため
     // 事前の clearCache は行わない（スキャン失敗時の空キャッシュ防止）
     #expect(mockDB.clearCacheCalled == false)
     #expect(mockDB.saveAppsCalled == true)
@@ -241,6 +337,149 @@ struct CacheBootstrapTests {
     #expect(mockDB.savedDirectories[0].name == "project")
   }
 
+  @Test("アプリのスキャンにはキャッシュのアプリを前回の結果として渡す")
+  @MainActor
+  func rebuildCachePassesCachedAppsToScanner() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    mockDB.loadedApps = [
+      AppItem(
+        name: "Safari", path: "/Applications/Safari.app", bundleModificationTime: 1_700_000_000)
+    ]
+    let scanner = CacheBootstrapRecordingAppScanner()
+    let bootstrap = CacheBootstrap(
+      settingsManager: makeSettingsManager(updateOnStartup: false),
+      cacheDatabase: mockDB,
+      appScanner: scanner,
+      directoryScanner: CacheBootstrapMockDirScanner()
+    )
+
+    await bootstrap.rebuildCache()
+
+    #expect(scanner.receivedPrevious == mockDB.loadedApps)
+    #expect(mockDB.savedApps == mockDB.loadedApps)
+  }
+
+  @Test("スキャンの所要時間と更新日時が変わっていないアプリの数を記録する")
+  @MainActor
+  func rebuildCacheRecordsScanMetrics() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    mockDB.loadedApps = [
+      AppItem(
+        name: "Safari", path: "/Applications/Safari.app", bundleModificationTime: 1_700_000_000),
+      AppItem(name: "Notes", path: "/Applications/Notes.app"),
+    ]
+    let bootstrap = CacheBootstrap(
+      settingsManager: makeSettingsManager(updateOnStartup: false),
+      cacheDatabase: mockDB,
+      appScanner: CacheBootstrapRecordingAppScanner(),
+      directoryScanner: CacheBootstrapMockDirScanner(
+        result: ScanResult(directories: [DirectoryItem(name: "dev", path: "/dev")], apps: []))
+    )
+
+    await bootstrap.rebuildCache()
+
+    let metrics = try #require(mockDB.recordedScanMetrics)
+    #expect(metrics == bootstrap.lastScanMetrics)
+    #expect(metrics.appCount == 2)
+    #expect(metrics.unchangedAppCount == 1)
+    #expect(metrics.directoryCount == 1)
+    #expect(metrics.totalMilliseconds >= metrics.appScanMilliseconds)
+  }
+
+  @Test("SSH ホスト・tmux セッションは設定が有効な場合だけ保存する")
+  @MainActor
+  func terminalTargetsAreSavedOnlyWhenEnabled() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    let settings = makeSettingsManager(updateOnStartup: false)
+    let targets = [
+      TerminalTarget(kind: .sshHost, name: "prod-web"),
+      TerminalTarget(kind: .tmuxSession, name: "main"),
+    ]
+
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: mockDB,
+      appScanner: CacheBootstrapMockAppScanner(),
+      directoryScanner: CacheBootstrapMockDirScanner(),
+      terminalTargetProvider: CacheBootstrapMockTerminalTargets(result: targets)
+    )
+
+    await bootstrap.rebuildCache()
+    #expect(mockDB.savedTerminalTargets == targets)
+
+    // 無効にすると保存済みの一覧を消す
+    settings.settings.cacheUpdate.indexTerminalTargets = false
+    await bootstrap.rebuildCache()
+    #expect(mockDB.savedTerminalTargets == [])
+  }
+
+  @Test("エディタで最近開いたフォルダはスキャン結果と重複しないものだけ追加する")
+  @MainActor
+  func recentWorkspacesAreMergedWithoutDuplicates() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    let settings = makeSettingsManager(updateOnStartup: false)
+    let recent = CacheBootstrapMockRecentWorkspaces(result: [
+      DirectoryItem(name: "project", path: "/Users/dev/project", editor: "cursor"),
+      DirectoryItem(name: "outside", path: "/opt/outside", editor: "cursor"),
+    ])
+
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: mockDB,
+      appScanner: CacheBootstrapMockAppScanner(),
+      directoryScanner: CacheBootstrapMockDirScanner(
+        result: ScanResult(
+          directories: [DirectoryItem(name: "project", path: "/Users/dev/project")], apps: [])),
+      recentWorkspaceProvider: recent
+    )
+
+    // 設定が無効なら取り込まない
+    await bootstrap.rebuildCache()
+    #expect(mockDB.savedDirectories.map(\.path) == ["/Users/dev/project"])
+
+    settings.settings.recentWorkspaceEditors = [.cursor]
+    await bootstrap.rebuildCache()
+    #expect(mockDB.savedDirectories.map(\.path) == ["/Users/dev/project", "/opt/outside"])
+    #expect(mockDB.savedDirectories[0].editor == nil)
+  }
+
+  @Test("Launch Services の登録アプリは設定が有効な場合だけ保存する")
+  @MainActor
+  func registeredAppsAreSavedOnlyWhenEnabled() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    let mockAppScanner = CacheBootstrapMockAppScanner(
+      apps: [AppItem(name: "Safari", path: "/Applications/Safari.app")],
+      registeredApps: [
+        AppItem(name: "Foo", path: "/Users/dev/Library/Foo.app", source: .launchServices),
+        AppItem(name: "Hidden", path: "/opt/Hidden.app", source: .launchServices),
+      ])
+    let settings = makeSettingsManager(updateOnStartup: false)
+    settings.settings.excludedApps = ["Hidden"]
+    var completedApps: [AppItem] = []
+
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: mockDB,
+      appScanner: mockAppScanner,
+      directoryScanner: CacheBootstrapMockDirScanner()
+    )
+    bootstrap.onScanCompleted = { completedApps = $0 }
+
+    await bootstrap.rebuildCache()
+    #expect(mockDB.savedApps.map(\.path) == ["/Applications/Safari.app"])
+
+    settings.settings.cacheUpdate.discoverRegisteredApps = true
+    await bootstrap.rebuildCache()
+
+    // 除外リストはスキャン済みアプリと同じく適用し、設定画面用の一覧には含める
+    #expect(
+      Set(mockDB.savedApps.map(\.path)) == [
+        "/Applications/Safari.app", "/Users/dev/Library/Foo.app",
+      ])
+    #expect(mockDB.savedApps.first { $0.name == "Foo" }?.source == .launchServices)
+    #expect(completedApps.map(\.name) == ["Foo", "Hidden", "Safari"])
+  }
+
   // MARK: - isScanning Flag Tests
 
   @Test("isScanning flag toggles correctly during scan")
@@ -292,6 +531,36 @@ struct CacheBootstrapTests {
     #expect(bootstrap.lastScanDate != nil)
   }
 
+  @Test("スキャン後にアンインストールされたアプリのアイコンを削除する")
+  @MainActor
+  func scanCollectsIconGarbage() async throws {
+    let iconDir = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-icon-bootstrap-test-\(UUID().uuidString)")
+    try FileManager.default.createDirectory(at: iconDir, withIntermediateDirectories: true)
+    defer { try? FileManager.default.removeItem(at: iconDir) }
+    let iconCache = IconCacheManager(cacheDirectory: iconDir.path)
+    for appPath in ["/Applications/Safari.app", "/Applications/Uninstalled.app"] {
+      try Data(count: 10).write(to: URL(fileURLWithPath: iconCache.cachedIconPath(for: appPath)))
+    }
+
+    let bootstrap = CacheBootstrap(
+      settingsManager: makeSettingsManager(),
+      cacheDatabase: CacheBootstrapMockDB(isEmpty: true),
+      appScanner: CacheBootstrapMockAppScanner(apps: [
+        AppItem(name: "Safari", path: "/Applications/Safari.app")
+      ]),
+      directoryScanner: CacheBootstrapMockDirScanner(),
+      iconCacheManager: iconCache
+    )
+
+    await bootstrap.performInitialScan()
+
+    #expect(FileManager.default.fileExists(atPath: iconCache.cachedIconPath(for: "/Applications/Safari.app")))
+    #expect(
+      !FileManager.default.fileExists(
+        atPath: iconCache.cachedIconPath(for: "/Applications/Uninstalled.app")))
+  }
+
   // MARK: - Auto Update Tests
 
   @Test("startAutoUpdate creates task when autoUpdateEnabled")
@@ -367,6 +636,87 @@ struct CacheBootstrapTests {
     #expect(bootstrap.autoUpdateTask == nil)
   }
 
+  @Test("pauseIndexing stops auto update and resumeIndexing restarts it")
+  @MainActor
+  func pauseAndResumeIndexing() async throws {
+    let settings = makeSettingsManager(
+      autoUpdateEnabled: true,
+      autoUpdateIntervalHours: 1
+    )
+
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: CacheBootstrapMockDB(isEmpty: false),
+      appScanner: CacheBootstrapMockAppScanner(),
+      directoryScanner: CacheBootstrapMockDirScanner()
+    )
+
+    bootstrap.startAutoUpdate()
+    bootstrap.pauseIndexing()
+    #expect(bootstrap.isIndexingPaused)
+    #expect(bootstrap.autoUpdateTask == nil)
+
+    // 一時停止中は設定変更による再スケジュールでも再開しない
+    bootstrap.startAutoUpdate()
+    #expect(bootstrap.autoUpdateTask == nil)
+
+    bootstrap.resumeIndexing()
+    #expect(!bootstrap.isIndexingPaused)
+    #expect(bootstrap.autoUpdateTask != nil)
+
+    bootstrap.stopAutoUpdate()
+  }
+
+  @Test("実行中のスキャンは一時停止すると保存せずに打ち切り、既存のキャッシュを保持する")
+  @MainActor
+  func pauseInterruptsRunningScan() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    let settings = makeSettingsManager(updateOnStartup: false)
+    settings.settings.recentWorkspaceEditors = [.cursor]
+    let recent = CacheBootstrapPausingRecentWorkspaces()
+
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: mockDB,
+      appScanner: CacheBootstrapMockAppScanner(apps: [
+        AppItem(name: "Safari", path: "/Applications/Safari.app")
+      ]),
+      directoryScanner: CacheBootstrapMockDirScanner(),
+      recentWorkspaceProvider: recent
+    )
+    recent.bootstrap = bootstrap
+
+    await bootstrap.rebuildCache()
+    #expect(bootstrap.isIndexingPaused)
+    #expect(!bootstrap.isScanning)
+    #expect(!mockDB.saveAppsCalled)
+    #expect(!mockDB.saveDirectoriesCalled)
+    #expect(mockDB.recordedScanMetrics == nil)
+
+    // 一時停止中でも手動の再構築は最後まで実行する
+    recent.bootstrap = nil
+    await bootstrap.rebuildCache()
+    #expect(mockDB.savedApps.map(\.path) == ["/Applications/Safari.app"])
+  }
+
+  @Test("一時停止は実行中のアプリのスキャンに打ち切りとして伝わる")
+  @MainActor
+  func pauseCancelsInFlightAppScan() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    let scanner = CacheBootstrapPausingAppScanner()
+    let bootstrap = CacheBootstrap(
+      settingsManager: makeSettingsManager(updateOnStartup: false),
+      cacheDatabase: mockDB,
+      appScanner: scanner,
+      directoryScanner: CacheBootstrapMockDirScanner()
+    )
+    scanner.bootstrap = bootstrap
+
+    await bootstrap.rebuildCache()
+    #expect(scanner.observedCancellation)
+    #expect(!mockDB.saveAppsCalled)
+  }
+
   // MARK: - インターバルクランプテスト
 
   @Test("autoUpdateIntervalNanoseconds は 0 時間を 1 時間にクランプする")
@@ -441,4 +791,202 @@ struct CacheBootstrapTests {
 
     bootstrap.stopAutoUpdate()
   }
+
+  @Test("次の自動更新までの待ち時間は起点からの経過時間を差し引き、過ぎていれば 0")
+  func autoUpdateDelaySubtractsElapsedTime() {
+    let hour = CacheBootstrap.autoUpdateIntervalNanoseconds(hours: 1)
+    let start = Date(timeIntervalSince1970: 1_000_000)
+
+    #expect(
+      CacheBootstrap.autoUpdateDelayNanoseconds(
+        interval: hour, since: start, now: start.addingTimeInterval(15 * 60))
+        == hour / 4 * 3)
+    // スリープなどで間隔を過ぎていればすぐに更新する
+    #expect(
+      CacheBootstrap.autoUpdateDelayNanoseconds(
+        interval: hour, since: start, now: start.addingTimeInterval(5 * 3600)) == 0)
+    // 時計が戻った場合は間隔をそのまま待つ
+    #expect(
+      CacheBootstrap.autoUpdateDelayNanoseconds(
+        interval: hour, since: start, now: start.addingTimeInterval(-60)) == hour)
+  }
+
+  @Test("スリープからの復帰では自動更新中の場合だけ予定を決め直す")
+  @MainActor
+  func handleWakeReschedulesOnlyWhenRunning() {
+    let settings = makeSettingsManager(autoUpdateEnabled: true, autoUpdateIntervalHours: 1)
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: CacheBootstrapMockDB(isEmpty: false),
+      appScanner: CacheBootstrapMockAppScanner(),
+      directoryScanner: CacheBootstrapMockDirScanner()
+    )
+
+    bootstrap.handleWake()
+    #expect(bootstrap.autoUpdateTask == nil)
+
+    bootstrap.startAutoUpdate()
+    let before = bootstrap.autoUpdateTask
+    bootstrap.handleWake()
+    #expect(bootstrap.autoUpdateTask != nil)
+    #expect(bootstrap.autoUpdateTask != before)
+    #expect(before?.isCancelled == true)
+
+    bootstrap.stopAutoUpdate()
+  }
+
+  // MARK: - 単一ディレクトリ再スキャンテスト
+
+  @Test("refreshDirectory は対象ディレクトリ由来の項目だけを置き換える")
+  @MainActor
+  func refreshDirectoryReplacesOnlyOwnedItems() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    mockDB.loadedApps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app"),
+      AppItem(name: "Old", path: "/Users/dev/tools/Old.app"),
+    ]
+    mockDB.loadedDirectories = [
+      DirectoryItem(name: "tools", path: "/Users/dev/tools"),
+      DirectoryItem(name: "removed", path: "/Users/dev/tools/removed"),
+      DirectoryItem(name: "other", path: "/Users/dev/other/app"),
+    ]
+    let mockDirScanner = CacheBootstrapMockDirScanner(
+      result: ScanResult(
+        directories: [
+          DirectoryItem(name: "tools", path: "/Users/dev/tools"),
+          DirectoryItem(name: "added", path: "/Users/dev/tools/added"),
+        ],
+        apps: [AppItem(name: "New", path: "/Users/dev/tools/New.app")]
+      ))
+    let settings = makeSettingsManager()
+    settings.settings.registeredDirectories = [
+      RegisteredDirectory(
+        path: "/Users/dev/tools/", parentOpenMode: .finder, subdirsOpenMode: .editor,
+        scanForApps: true)
+    ]
+
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: mockDB,
+      appScanner: CacheBootstrapMockAppScanner(),
+      directoryScanner: mockDirScanner
+    )
+
+    let refreshed = await bootstrap.refreshDirectory(path: "/Users/dev/tools")
+
+    #expect(refreshed)
+    #expect(
+      Set(mockDB.savedApps.map(\.path)) == ["/Applications/Safari.app", "/Users/dev/tools/New.app"])
+    #expect(
+      Set(mockDB.savedDirectories.map(\.path)) == [
+        "/Users/dev/tools", "/Users/dev/tools/added", "/Users/dev/other/app",
+      ])
+  }
+
+  @Test("読み取れなかったディレクトリはキャッシュの項目を残し、状況を記録する")
+  @MainActor
+  func unavailableDirectoryKeepsCachedItems() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    mockDB.loadedDirectories = [
+      DirectoryItem(name: "photos", path: "/Volumes/nas/photos"),
+      DirectoryItem(name: "stale", path: "/Users/dev/stale"),
+    ]
+    let settings = makeSettingsManager()
+    settings.settings.registeredDirectories = [
+      RegisteredDirectory(path: "/Volumes/nas", parentOpenMode: .none, subdirsOpenMode: .finder),
+      RegisteredDirectory(path: "/Users/dev", parentOpenMode: .none, subdirsOpenMode: .finder),
+    ]
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: mockDB,
+      appScanner: CacheBootstrapMockAppScanner(),
+      directoryScanner: CacheBootstrapUnavailableDirScanner(unavailable: ["/Volumes/nas"])
+    )
+
+    await bootstrap.rebuildCache()
+
+    #expect(
+      Set(mockDB.savedDirectories.map(\.path)) == ["/Users/dev/fresh", "/Volumes/nas/photos"])
+    #expect(bootstrap.scanStatuses.map(\.path) == ["/Volumes/nas", "/Users/dev"])
+    #expect(bootstrap.scanStatuses[1].state == .completed)
+
+    // 単一ディレクトリの再スキャンも失敗時はキャッシュを更新しない
+    mockDB.saveDirectoriesCalled = false
+    #expect(await bootstrap.refreshDirectory(path: "/Volumes/nas") == false)
+    #expect(!mockDB.saveDirectoriesCalled)
+  }
+
+  @Test("refreshDirectory は未登録のパスではキャッシュを更新しない")
+  @MainActor
+  func refreshDirectorySkipsUnregisteredPath() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    let bootstrap = CacheBootstrap(
+      settingsManager: makeSettingsManager(),
+      cacheDatabase: mockDB,
+      appScanner: CacheBootstrapMockAppScanner(),
+      directoryScanner: CacheBootstrapMockDirScanner()
+    )
+
+    let refreshed = await bootstrap.refreshDirectory(path: "/Users/dev/unknown")
+
+    #expect(!refreshed)
+    #expect(!mockDB.saveAppsCalled)
+    #expect(!mockDB.saveDirectoriesCalled)
+  }
+
+  @Test("アプリスキャン無効のディレクトリでは既存アプリを残す")
+  @MainActor
+  func refreshDirectoryKeepsAppsWhenAppScanDisabled() async throws {
+    let mockDB = CacheBootstrapMockDB(isEmpty: false)
+    mockDB.loadedApps = [AppItem(name: "Tool", path: "/Applications/Tool.app")]
+    let settings = makeSettingsManager()
+    settings.settings.registeredDirectories = [
+      RegisteredDirectory(
+        path: "/Applications", parentOpenMode: .none, subdirsOpenMode: .finder,
+        scanForApps: false)
+    ]
+
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: mockDB,
+      appScanner: CacheBootstrapMockAppScanner(),
+      directoryScanner: CacheBootstrapMockDirScanner()
+    )
+
+    await bootstrap.refreshDirectory(path: "/Applications")
+
+    #expect(mockDB.savedApps.map(\.path) == ["/Applications/Tool.app"])
+  }
+
+  @Test("バッテリー残量が少ない間は自動更新を見送り、理由を記録する")
+  @MainActor
+  func autoUpdateDeferredOnLowBattery() {
+    let settings = makeSettingsManager()
+    let load = StubSystemLoad(SystemLoadSnapshot(isOnBattery: true, batteryPercent: 12))
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: CacheBootstrapMockDB(),
+      appScanner: CacheBootstrapMockAppScanner(),
+      directoryScanner: CacheBootstrapMockDirScanner(),
+      systemLoadProvider: load
+    )
+
+    #expect(bootstrap.evaluateAutoUpdateDeferral() == .lowBattery(percent: 12))
+    #expect(bootstrap.lastAutoUpdateDeferral == .lowBattery(percent: 12))
+
+    settings.settings.cacheUpdate.skipOnLowBattery = false
+    #expect(bootstrap.evaluateAutoUpdateDeferral() == nil)
+    #expect(bootstrap.lastAutoUpdateDeferral == nil)
+  }
+}
+
+/// 決まった電源と負荷の状態を返す。
+private struct StubSystemLoad: SystemLoadProviding {
+  let state: SystemLoadSnapshot
+
+  init(_ state: SystemLoadSnapshot) {
+    self.state = state
+  }
+
+  func snapshot() -> SystemLoadSnapshot { state }
 }
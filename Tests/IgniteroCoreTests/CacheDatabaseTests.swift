@@ -43,6 +43,119 @@ import Testing
   #expect(loaded[0].name == "Safari Updated")
 }
 
+@Test func cacheDatabaseDeleteAppsRemovesOnlyGivenPaths() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveApps([
+    AppItem(name: "Safari", path: "/Applications/Safari.app"),
+    AppItem(name: "Removed", path: "/Applications/Removed.app"),
+  ])
+
+  try await db.deleteApps(paths: ["/Applications/Removed.app", "/Applications/Unknown.app"])
+
+  let loaded = try await db.loadApps()
+  #expect(loaded.map(\.path) == ["/Applications/Safari.app"])
+}
+
+@Test func cacheDatabaseDeletesDirectoriesWithDescendants() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveDirectories([
+    DirectoryItem(name: "old", path: "/dev/old"),
+    DirectoryItem(name: "sub", path: "/dev/old/sub"),
+    DirectoryItem(name: "older", path: "/dev/older"),
+  ])
+
+  try await db.deleteDirectories(paths: ["/dev/old"])
+
+  #expect(try await db.loadDirectories().map(\.path) == ["/dev/older"])
+  #expect(try db.searchDirectories(matching: "sub", in: .name, limit: 10).isEmpty)
+}
+
+@Test func cacheDatabaseStoresIconSourceAndConvertedIconPaths() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveApps([
+    AppItem(name: "Lazy", path: "/Applications/Lazy.app", iconSourcePath: "/icons/Lazy.icns")
+  ])
+  #expect(try await db.loadApps().first?.iconSourcePath == "/icons/Lazy.icns")
+
+  try db.updateAppIconPaths(["/Applications/Lazy.app": "/cache/lazy.png"])
+  let loaded = try await db.loadApps().first
+  #expect(loaded?.iconPath == "/cache/lazy.png")
+  #expect(loaded?.iconSourcePath == "/icons/Lazy.icns")
+}
+
+@Test func cacheDatabaseStoresAppMetadata() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveApps([
+    AppItem(
+      name: "Xcode", path: "/Applications/Xcode.app", version: "16.0",
+      bundleIdentifier: "com.apple.dt.Xcode", category: "public.app-category.developer-tools")
+  ])
+  var loaded = try await db.loadApps().first
+  #expect(loaded?.version == "16.0")
+  #expect(loaded?.bundleIdentifier == "com.apple.dt.Xcode")
+  #expect(loaded?.category == "public.app-category.developer-tools")
+
+  // アップデートでバージョンだけが変わった場合も差分として反映する
+  try await db.saveApps([
+    AppItem(
+      name: "Xcode", path: "/Applications/Xcode.app", version: "16.1",
+      bundleIdentifier: "com.apple.dt.Xcode", category: "public.app-category.developer-tools")
+  ])
+  loaded = try await db.loadApps().first
+  #expect(loaded?.version == "16.1")
+}
+
+@Test func cacheDatabaseStoresAlternateNames() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveApps([
+    AppItem(name: "Preview", path: "/System/Applications/Preview.app", alternateNames: ["プレビュー"]),
+    AppItem(name: "Safari", path: "/Applications/Safari.app"),
+  ])
+  let loaded = try await db.loadApps()
+  #expect(loaded.first { $0.name == "Preview" }?.alternateNames == ["プレビュー"])
+  #expect(loaded.first { $0.name == "Safari" }?.alternateNames == [])
+}
+
+@Test func cacheDatabaseStoresKeywords() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  let app = AppItem(
+    name: "Safari", path: "/Applications/Safari.app", keywords: ["browser", "ブラウザ"])
+  try await db.saveApps([app, AppItem(name: "Notes", path: "/System/Applications/Notes.app")])
+  let loaded = try await db.loadApps()
+  #expect(loaded.first { $0.name == "Safari" }?.keywords == ["browser", "ブラウザ"])
+  #expect(loaded.first { $0.name == "Notes" }?.keywords == [])
+}
+
+@Test func cacheDatabaseStoresBundleModificationTime() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  let app = AppItem(
+    name: "Safari", path: "/Applications/Safari.app", bundleModificationTime: 1_700_000_000.123456)
+  try await db.saveApps([app])
+  #expect(try await db.loadApps() == [app])
+
+  // 更新日時だけ変わった場合も更新する
+  let updated = AppItem(
+    name: "Safari", path: "/Applications/Safari.app", bundleModificationTime: 1_700_000_100)
+  #expect(try db.applyAppDiff([updated]).updated == 1)
+  #expect(try await db.loadApps().first?.bundleModificationTime == 1_700_000_100)
+}
+
+@Test func cacheDatabaseRecordsScanMetricsInMetadata() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  #expect(try await db.lastScanMetrics() == nil)
+
+  let metrics = ScanMetrics(
+    totalMilliseconds: 1200, appScanMilliseconds: 300, directoryScanMilliseconds: 850,
+    appCount: 420, unchangedAppCount: 415, directoryCount: 1800,
+    finishedAt: Date(timeIntervalSince1970: 1_700_000_000))
+  try db.recordScanMetrics(metrics)
+
+  #expect(try await db.lastScanMetrics() == metrics)
+  // スキャン結果と一緒に消す
+  try db.clearCache()
+  #expect(try await db.lastScanMetrics() == nil)
+}
+
 @Test func cacheDatabaseAppWithOptionalFields() async throws {
   let db = try CacheDatabase(inMemory: true)
   let app = AppItem(name: "Test", path: "/test.app", iconPath: nil, originalName: nil)
@@ -53,6 +166,24 @@ import Testing
   #expect(loaded[0].originalName == nil)
 }
 
+@Test func cacheDatabaseStoresAppSource() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveApps([
+    AppItem(name: "Safari", path: "/Applications/Safari.app"),
+    AppItem(name: "Foo", path: "/Users/dev/Library/Foo.app", source: .launchServices),
+  ])
+
+  let loaded = try await db.loadApps()
+  #expect(loaded.first { $0.name == "Safari" }?.source == .scan)
+  #expect(loaded.first { $0.name == "Foo" }?.source == .launchServices)
+
+  // 同じパスが既定フォルダのスキャンで見つかった場合は由来も更新する
+  try await db.saveApps([
+    AppItem(name: "Foo", path: "/Users/dev/Library/Foo.app", source: .scan)
+  ])
+  #expect(try await db.loadApps().first?.source == .scan)
+}
+
 @Test func cacheDatabaseSaveAndLoadDirectories() async throws {
   let db = try CacheDatabase(inMemory: true)
   let dirs = [
@@ -112,6 +243,57 @@ import Testing
   #expect(empty == true)
 }
 
+@Test func cacheDatabaseRecordsSchemaVersionAndKeepsItOnClear() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  #expect(try db.storedSchemaVersion() == CacheDatabase.schemaVersion)
+
+  try db.clearCache()
+  #expect(try db.storedSchemaVersion() == CacheDatabase.schemaVersion)
+}
+
+@Test func cacheDatabaseRebuildsNewerSchemaAndKeepsBackup() async throws {
+  let dbPath = FileManager.default.temporaryDirectory
+    .appendingPathComponent("test_schema_\(UUID().uuidString).db").path
+  defer {
+    for suffix in ["", "-wal", "-shm"] {
+      try? FileManager.default.removeItem(atPath: dbPath + suffix)
+      try? FileManager.default.removeItem(atPath: dbPath + suffix + ".broken")
+    }
+  }
+  do {
+    let newer = try DatabaseQueue(path: dbPath)
+    try await newer.write { db in
+      try db.execute(sql: "CREATE TABLE metadata (key TEXT PRIMARY KEY, value TEXT NOT NULL)")
+      try db.execute(sql: "INSERT INTO metadata (key, value) VALUES ('schema_version', '999')")
+    }
+    try newer.close()
+  }
+
+  let db = try CacheDatabase(path: dbPath)
+
+  #expect(try db.storedSchemaVersion() == CacheDatabase.schemaVersion)
+  #expect(try await db.isEmpty())
+  #expect(FileManager.default.fileExists(atPath: dbPath + ".broken"))
+}
+
+@Test func cacheDatabaseRebuildsUnreadableFile() async throws {
+  let dbPath = FileManager.default.temporaryDirectory
+    .appendingPathComponent("test_broken_\(UUID().uuidString).db").path
+  defer {
+    for suffix in ["", "-wal", "-shm"] {
+      try? FileManager.default.removeItem(atPath: dbPath + suffix)
+      try? FileManager.default.removeItem(atPath: dbPath + suffix + ".broken")
+    }
+  }
+  try Data("not a database".utf8).write(to: URL(fileURLWithPath: dbPath))
+
+  let db = try CacheDatabase(path: dbPath)
+  try db.saveApps([AppItem(name: "Safari", path: "/Applications/Safari.app")])
+
+  #expect(try await db.loadApps().count == 1)
+  #expect(FileManager.default.fileExists(atPath: dbPath + ".broken"))
+}
+
 @Test func cacheDatabaseWALModeEnabled() async throws {
   let tempDir = FileManager.default.temporaryDirectory
   let dbPath = tempDir.appendingPathComponent("test_wal_\(UUID().uuidString).db").path
@@ -122,6 +304,117 @@ import Testing
   #expect(journalMode == "wal")
 }
 
+@Test func cacheDatabaseWaitsForOtherWriterInsteadOfFailing() throws {
+  let dbPath = FileManager.default.temporaryDirectory
+    .appendingPathComponent("test_busy_\(UUID().uuidString).db").path
+  defer {
+    for suffix in ["", "-wal", "-shm"] {
+      try? FileManager.default.removeItem(atPath: dbPath + suffix)
+    }
+  }
+  let db = try CacheDatabase(path: dbPath)
+  let other = try DatabaseQueue(path: dbPath)
+  let locked = DispatchSemaphore(value: 0)
+  let released = DispatchSemaphore(value: 0)
+
+  // 別の接続が書き込みロックを 0.3 秒保持する
+  DispatchQueue.global().async {
+    try? other.write { conn in
+      try conn.execute(sql: "INSERT OR REPLACE INTO metadata (key, value) VALUES ('busy', '1')")
+      locked.signal()
+      Thread.sleep(forTimeInterval: 0.3)
+    }
+    released.signal()
+  }
+  locked.wait()
+
+  // busy timeout の間に解放されるため `database is locked` にならない
+  try db.saveApps([AppItem(name: "Safari", path: "/Applications/Safari.app")])
+  released.wait()
+
+  #expect(try db.isEmpty() == false)
+}
+
+@Test func cacheDatabaseReadsWhileSavingFromAnotherTask() async throws {
+  let dbPath = FileManager.default.temporaryDirectory
+    .appendingPathComponent("test_pool_\(UUID().uuidString).db").path
+  defer {
+    for suffix in ["", "-wal", "-shm"] {
+      try? FileManager.default.removeItem(atPath: dbPath + suffix)
+    }
+  }
+  let db = try CacheDatabase(path: dbPath)
+  let batches = (0..<5).map { batch in
+    (0..<500).map { AppItem(name: "App \(batch)-\($0)", path: "/Applications/A\($0).app") }
+  }
+  try db.saveApps(batches[0])
+
+  // 保存（書き込み用の接続）と読み込み（読み込み用の接続）を並行して実行する
+  try await withThrowingTaskGroup(of: Void.self) { group in
+    group.addTask {
+      for batch in batches.dropFirst() {
+        try db.saveApps(batch)
+      }
+    }
+    for _ in 0..<20 {
+      group.addTask {
+        // 読み込みは保存の途中の状態を見ない（常にいずれかの版の 500 件）
+        #expect(try await db.loadApps().count == 500)
+      }
+    }
+    try await group.waitForAll()
+  }
+
+  #expect(try await db.loadApps().allSatisfy { $0.name.hasPrefix("App 4-") })
+}
+
+@Test func cacheDatabaseResetStorageDeletesFileAndRecreatesSchema() async throws {
+  let dbPath = FileManager.default.temporaryDirectory
+    .appendingPathComponent("test_reset_\(UUID().uuidString).db").path
+  defer {
+    for suffix in ["", "-wal", "-shm"] {
+      try? FileManager.default.removeItem(atPath: dbPath + suffix)
+      try? FileManager.default.removeItem(
+        atPath: CacheDatabase.partitionPath(base: dbPath, name: "work") + suffix)
+    }
+  }
+  let db = try CacheDatabase(path: dbPath)
+  try db.saveApps([AppItem(name: "Safari", path: "/Applications/Safari.app")])
+  _ = try db.toggleFavorite(path: "/Applications/Safari.app")
+  try db.selectPartition("work")
+  try db.saveApps([AppItem(name: "Xcode", path: "/Applications/Xcode.app")])
+  try db.selectPartition(nil)
+
+  try db.resetStorage()
+
+  #expect(try db.isEmpty())
+  #expect(try await db.favoritePaths().isEmpty)
+  #expect(try db.storedSchemaVersion() == CacheDatabase.schemaVersion)
+  // ほかのパーティションは残る
+  try db.selectPartition("work")
+  #expect(try await db.loadApps().map(\.name) == ["Xcode"])
+}
+
+@Test func cacheDatabaseResetStorageKeepsWorkingWhenFilesCannotBeRemoved() async throws {
+  let dbPath = FileManager.default.temporaryDirectory
+    .appendingPathComponent("test_reset_failure_\(UUID().uuidString).db").path
+  defer {
+    for suffix in ["", "-wal", "-shm"] {
+      try? FileManager.default.removeItem(atPath: dbPath + suffix)
+    }
+  }
+  let db = try CacheDatabase(
+    path: dbPath, removeItem: { _ in throw CocoaError(.fileWriteNoPermission) })
+  try db.saveApps([AppItem(name: "Safari", path: "/Applications/Safari.app")])
+
+  #expect(throws: CocoaError.self) { try db.resetStorage() }
+
+  // 削除できなかった DB を開き直し、読み書きを続けられる
+  #expect(try await db.loadApps().map(\.name) == ["Safari"])
+  try db.saveApps([AppItem(name: "Xcode", path: "/Applications/Xcode.app")])
+  #expect(try await db.loadApps().map(\.name) == ["Xcode"])
+}
+
 @Test func cacheDatabaseFileBasedInit() throws {
   let tempDir = FileManager.default.temporaryDirectory
   let dbPath = tempDir.appendingPathComponent("test_cache_\(UUID().uuidString).db").path
@@ -130,3 +423,372 @@ import Testing
   let _ = try CacheDatabase(path: dbPath)
   #expect(FileManager.default.fileExists(atPath: dbPath))
 }
+
+@Test func cacheDatabaseApplyAppDiffCountsChanges() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveApps([
+    AppItem(name: "Safari", path: "/Applications/Safari.app"),
+    AppItem(name: "Notes", path: "/Applications/Notes.app"),
+    AppItem(name: "Mail", path: "/Applications/Mail.app"),
+  ])
+
+  // Safari は変更なし、Notes は名前変更、Mail は削除、Maps は新規
+  let summary = try await db.applyAppDiff([
+    AppItem(name: "Safari", path: "/Applications/Safari.app"),
+    AppItem(name: "メモ", path: "/Applications/Notes.app"),
+    AppItem(name: "Maps", path: "/Applications/Maps.app"),
+  ])
+
+  #expect(summary == CacheDiffSummary(inserted: 1, updated: 1, deleted: 1, unchanged: 1))
+  let loaded = try await db.loadApps()
+  #expect(
+    Set(loaded.map(\.path)) == [
+      "/Applications/Safari.app", "/Applications/Notes.app", "/Applications/Maps.app",
+    ])
+  #expect(loaded.first { $0.path == "/Applications/Notes.app" }?.name == "メモ")
+}
+
+@Test func cacheDatabaseApplyAppDiffIsNoOpForSameInput() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  let apps = [
+    AppItem(name: "Safari", path: "/Applications/Safari.app", iconPath: "/icons/safari.png"),
+    AppItem(name: "Finder", path: "/System/Applications/Finder.app"),
+  ]
+  try await db.saveApps(apps)
+
+  let summary = try await db.applyAppDiff(apps)
+  #expect(summary == CacheDiffSummary(unchanged: 2))
+}
+
+@Test func cacheDatabaseApplyAppDiffDeduplicatesPaths() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  // 同一パスが重複した場合は後勝ち
+  let summary = try await db.applyAppDiff([
+    AppItem(name: "Old", path: "/Applications/Dup.app"),
+    AppItem(name: "New", path: "/Applications/Dup.app"),
+  ])
+  #expect(summary.inserted == 1)
+  let loaded = try await db.loadApps()
+  #expect(loaded.count == 1)
+  #expect(loaded[0].name == "New")
+}
+
+@Test func cacheDatabaseApplyDirectoryDiffCountsChanges() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveDirectories([
+    DirectoryItem(name: "a", path: "/dev/a", editor: "cursor"),
+    DirectoryItem(name: "b", path: "/dev/b"),
+  ])
+
+  let summary = try await db.applyDirectoryDiff([
+    DirectoryItem(name: "a", path: "/dev/a", editor: "zed"),
+    DirectoryItem(name: "c", path: "/dev/c"),
+  ])
+
+  #expect(summary == CacheDiffSummary(inserted: 1, updated: 1, deleted: 1, unchanged: 0))
+  let loaded = try await db.loadDirectories()
+  #expect(Set(loaded.map(\.path)) == ["/dev/a", "/dev/c"])
+  #expect(loaded.first { $0.path == "/dev/a" }?.editor == "zed")
+}
+
+@Test func cacheDatabaseRecordsDirectoryOpens() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  let base = Date(timeIntervalSince1970: 1_700_000_000)
+  try await db.recordDirectoryOpen(path: "/dev/a", editor: "cursor", at: base)
+  try await db.recordDirectoryOpen(path: "/dev/b", editor: "zed", at: base.addingTimeInterval(10))
+  try await db.recordDirectoryOpen(path: "/dev/a", editor: "vscode", at: base.addingTimeInterval(20))
+
+  let recent = try await db.recentProjects(limit: 10)
+  #expect(recent.map(\.path) == ["/dev/a", "/dev/b"])
+  // 同一パスは回数を加算し、エディタは最後に開いたものを保持する
+  #expect(recent[0].openCount == 2)
+  #expect(recent[0].editor == "vscode")
+  #expect(recent[1].openCount == 1)
+}
+
+@Test func cacheDatabaseRecentProjectsRespectsLimit() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  let base = Date(timeIntervalSince1970: 1_700_000_000)
+  for index in 0..<5 {
+    try await db.recordDirectoryOpen(
+      path: "/dev/p\(index)", editor: nil, at: base.addingTimeInterval(Double(index)))
+  }
+
+  #expect(try await db.recentProjects(limit: 2).map(\.path) == ["/dev/p4", "/dev/p3"])
+  #expect(try await db.recentProjects(limit: 0).isEmpty)
+}
+
+@Test func cacheDatabaseClearCacheKeepsDirectoryOpens() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.recordDirectoryOpen(path: "/dev/a", editor: "cursor")
+  try await db.clearCache()
+  // オープン履歴はキャッシュ再構築で消えない
+  #expect(try await db.recentProjects(limit: 5).count == 1)
+}
+
+@Test func cacheDatabaseRemembersPreferredEditorForOpenedDirectories() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.recordDirectoryOpen(path: "/dev/a", editor: "zed")
+  try await db.setPreferredEditor("zed", forPath: "/dev/a")
+  // 開いた記録のないディレクトリは対象外
+  try await db.setPreferredEditor("vscode", forPath: "/dev/b")
+  // 開き直しても記憶は保持する
+  try await db.recordDirectoryOpen(path: "/dev/a", editor: "cursor")
+  #expect(try await db.preferredEditors() == ["/dev/a": "zed"])
+
+  try await db.setPreferredEditor(nil, forPath: "/dev/a")
+  #expect(try await db.preferredEditors().isEmpty)
+  #expect(try await db.recentProjects(limit: 5).count == 1)
+}
+
+@Test func cacheDatabaseRecordsQueryHistory() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  let base = Date(timeIntervalSince1970: 1_700_000_000)
+  try await db.recordQuery("term", selectedPath: "/Applications/iTerm.app", at: base)
+  try await db.recordQuery(
+    "saf", selectedPath: "/Applications/Safari.app", at: base.addingTimeInterval(10))
+  try await db.recordQuery(
+    "term", selectedPath: "/Applications/iTerm.app", at: base.addingTimeInterval(20))
+  try await db.recordQuery("", selectedPath: "/Applications/Xcode.app")
+
+  let history = try await db.queryHistory(limit: 10)
+  #expect(history.map(\.keyword) == ["term", "saf"])
+  // 同じ検索語と結果は回数を加算する
+  #expect(history[0].count == 2)
+  #expect(history[0].selectedPath == "/Applications/iTerm.app")
+  #expect(try await db.queryHistory(limit: 0).isEmpty)
+}
+
+@Test func cacheDatabaseSuggestsQueriesByPrefix() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  let base = Date(timeIntervalSince1970: 1_700_000_000)
+  try await db.recordQuery("terraform", selectedPath: "/dev/infra", at: base)
+  try await db.recordQuery("term", selectedPath: "/Applications/iTerm.app", at: base)
+  try await db.recordQuery(
+    "term", selectedPath: "/System/Applications/Utilities/Terminal.app", at: base)
+  try await db.recordQuery("ter", selectedPath: "/Applications/iTerm.app", at: base)
+  try await db.recordQuery("saf", selectedPath: "/Applications/Safari.app", at: base)
+  try await db.recordQuery("50%_off", selectedPath: "/dev/sale", at: base)
+
+  // 選んだ回数の合計が多い順で、入力中の検索語そのものは含めない
+  #expect(try await db.querySuggestions(prefix: "ter", limit: 10) == ["term", "terraform"])
+  #expect(try await db.querySuggestions(prefix: "ter", limit: 1) == ["term"])
+  // `%`・`_` はワイルドカードとして扱わない
+  #expect(try await db.querySuggestions(prefix: "50%", limit: 10) == ["50%_off"])
+  #expect(try await db.querySuggestions(prefix: "5_", limit: 10).isEmpty)
+}
+
+@Test func cacheDatabasePrunesOldQueryHistory() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  let base = Date(timeIntervalSince1970: 1_700_000_000)
+  for index in 0...CacheDatabase.queryHistoryLimit {
+    try await db.recordQuery(
+      "q\(index)", selectedPath: "/dev/p", at: base.addingTimeInterval(Double(index)))
+  }
+
+  let history = try await db.queryHistory(limit: CacheDatabase.queryHistoryLimit + 1)
+  #expect(history.count == CacheDatabase.queryHistoryLimit)
+  // 最も使われていない検索語から削除する
+  #expect(!history.contains { $0.keyword == "q0" })
+}
+
+@Test func cacheDatabaseClearCacheKeepsQueryHistory() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.recordQuery("term", selectedPath: "/Applications/iTerm.app")
+  try await db.clearCache()
+  #expect(try await db.queryHistory(limit: 5).count == 1)
+}
+
+@Test func cacheDatabaseReplacesTerminalTargetsInOrder() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveTerminalTargets([
+    TerminalTarget(kind: .sshHost, name: "old-host")
+  ])
+  let targets = [
+    TerminalTarget(kind: .sshHost, name: "prod-web", detail: "deploy@10.0.0.5"),
+    TerminalTarget(kind: .tmuxSession, name: "main"),
+    TerminalTarget(kind: .sshHost, name: "bastion"),
+  ]
+  try await db.saveTerminalTargets(targets)
+  #expect(try await db.loadTerminalTargets() == targets)
+
+  // スキャン結果のキャッシュとして削除する
+  try await db.clearCache()
+  #expect(try await db.loadTerminalTargets().isEmpty)
+}
+
+@Test func cacheDatabaseStoresGitMetadataForDirectories() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveDirectories([
+    DirectoryItem(name: "repo", path: "/dev/repo", isGitRepo: true, gitBranch: "main"),
+    DirectoryItem(name: "plain", path: "/dev/plain"),
+  ])
+
+  // ブランチの変更は差分として UPDATE される
+  let summary = try await db.applyDirectoryDiff([
+    DirectoryItem(name: "repo", path: "/dev/repo", isGitRepo: true, gitBranch: "develop"),
+    DirectoryItem(name: "plain", path: "/dev/plain"),
+  ])
+  #expect(summary == CacheDiffSummary(updated: 1, unchanged: 1))
+
+  let loaded = try await db.loadDirectories()
+  let repo = try #require(loaded.first { $0.path == "/dev/repo" })
+  #expect(repo.isGitRepo)
+  #expect(repo.gitBranch == "develop")
+  #expect(loaded.first { $0.path == "/dev/plain" }?.isGitRepo == false)
+}
+
+@Test func cacheDatabaseStoresDirectoryPriority() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveDirectories([DirectoryItem(name: "main", path: "/dev/main", priority: 2)])
+
+  // 優先度の変更は差分として UPDATE される
+  let summary = try await db.applyDirectoryDiff([
+    DirectoryItem(name: "main", path: "/dev/main", priority: 4)
+  ])
+  #expect(summary == CacheDiffSummary(updated: 1))
+
+  let loaded = try await db.loadDirectories()
+  #expect(loaded.map(\.priority) == [4])
+}
+
+@Test func cacheDatabaseStoresDirectoryDisplayAndRawNames() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveDirectories([
+    DirectoryItem(name: "work/api", path: "/work/api", originalName: "api")
+  ])
+
+  let loaded = try #require(try await db.loadDirectories().first)
+  #expect(loaded.name == "work/api")
+  #expect(loaded.originalName == "api")
+}
+
+@Test func cacheDatabaseStoresAndUpdatesDirectoryXcodeProject() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveDirectories([
+    DirectoryItem(name: "app", path: "/dev/app", xcodeProject: "/dev/app/App.xcodeproj")
+  ])
+  #expect(try await db.loadDirectories().first?.xcodeProject == "/dev/app/App.xcodeproj")
+
+  let summary = try await db.applyDirectoryDiff([DirectoryItem(name: "app", path: "/dev/app")])
+  #expect(summary == CacheDiffSummary(updated: 1))
+  #expect(try await db.loadDirectories().first?.xcodeProject == nil)
+}
+
+@Test func cacheDatabaseStoresAndUpdatesDirectoryProjectType() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveDirectories([DirectoryItem(name: "api", path: "/dev/api", projectType: .go)])
+  #expect(try await db.loadDirectories().first?.projectType == .go)
+
+  let summary = try await db.applyDirectoryDiff([
+    DirectoryItem(name: "api", path: "/dev/api", projectType: .rust)
+  ])
+  #expect(summary == CacheDiffSummary(updated: 1))
+  #expect(try await db.loadDirectories().first?.projectType == .rust)
+}
+
+@Test func cacheDatabaseStoresAndUpdatesDirectoryFinderTags() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  let tags = [FinderTag(name: "urgent", color: .red), FinderTag(name: "Client")]
+  try await db.saveDirectories([DirectoryItem(name: "api", path: "/dev/api", finderTags: tags)])
+  #expect(try await db.loadDirectories().first?.finderTags == tags)
+
+  let summary = try await db.applyDirectoryDiff([DirectoryItem(name: "api", path: "/dev/api")])
+  #expect(summary == CacheDiffSummary(updated: 1))
+  #expect(try await db.loadDirectories().first?.finderTags == [])
+}
+
+@Test func cacheDatabaseTogglesFavoritesAndKeepsThemOnClear() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  #expect(try await db.toggleFavorite(path: "/Applications/Safari.app") == true)
+  #expect(try await db.toggleFavorite(path: "/dev/a") == true)
+  #expect(try await db.toggleFavorite(path: "/dev/a") == false)
+
+  try await db.clearCache()
+  #expect(try await db.favoritePaths() == ["/Applications/Safari.app"])
+}
+
+@Test func cacheDatabaseFullTextSearchMatchesNamePrefixes() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveDirectories([
+    DirectoryItem(name: "ignitero-launcher", path: "/Users/test/dev/ignitero-launcher"),
+    DirectoryItem(name: "my-project", path: "/Users/test/dev/my-project"),
+    DirectoryItem(name: "notes", path: "/Users/test/ignitero-notes"),
+  ])
+
+  let byName = try db.searchDirectories(matching: "ign laun", in: .name, limit: 10)
+  #expect(byName.map(\.name) == ["ignitero-launcher"])
+
+  // パスの要素にも前方一致する（名前の検索ではパスを照合しない）
+  let byPath = try db.searchDirectories(matching: "dev", in: .path, limit: 10)
+  #expect(Set(byPath.map(\.name)) == ["ignitero-launcher", "my-project"])
+  #expect(try db.searchDirectories(matching: "dev", in: .name, limit: 10).isEmpty)
+  #expect(try db.searchDirectories(matching: "", in: .name, limit: 10).isEmpty)
+}
+
+@Test func cacheDatabaseFullTextSearchFollowsDirectoryDiffs() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveDirectories([
+    DirectoryItem(name: "alpha", path: "/dev/alpha"),
+    DirectoryItem(name: "beta", path: "/dev/beta"),
+  ])
+  try await db.saveDirectories([DirectoryItem(name: "alpha-renamed", path: "/dev/alpha")])
+
+  #expect(try db.searchDirectories(matching: "beta", in: .name, limit: 10).isEmpty)
+  let renamed = try db.searchDirectories(matching: "renamed", in: .name, limit: 10)
+  #expect(renamed.map(\.path) == ["/dev/alpha"])
+
+  try await db.clearCache()
+  #expect(try db.searchDirectories(matching: "alpha", in: .name, limit: 10).isEmpty)
+}
+
+@Test func cacheDatabaseIntegrityCheckPassesForHealthyDatabase() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveDirectories([DirectoryItem(name: "alpha", path: "/dev/alpha")])
+  #expect(try db.integrityProblems().isEmpty)
+}
+
+@Test func cacheDatabasePartitionsKeepProfileCachesSeparate() async throws {
+  let db = try CacheDatabase(inMemory: true)
+  try await db.saveApps([AppItem(name: "Safari", path: "/Applications/Safari.app")])
+
+  try db.selectPartition("work")
+  #expect(try db.isEmpty())
+  try await db.saveApps([AppItem(name: "Slack", path: "/Applications/Slack.app")])
+
+  try db.selectPartition(nil)
+  #expect(try await db.loadApps().map(\.name) == ["Safari"])
+  try db.selectPartition("work")
+  #expect(try await db.loadApps().map(\.name) == ["Slack"])
+}
+
+@Test func cacheDatabaseRemovePartitionDeletesUnusedPartitionFiles() async throws {
+  let dbPath = FileManager.default.temporaryDirectory
+    .appendingPathComponent("test_remove_partition_\(UUID().uuidString).db").path
+  let workPath = CacheDatabase.partitionPath(base: dbPath, name: "work")
+  defer {
+    for suffix in ["", "-wal", "-shm"] {
+      try? FileManager.default.removeItem(atPath: dbPath + suffix)
+      try? FileManager.default.removeItem(atPath: workPath + suffix)
+    }
+  }
+  let db = try CacheDatabase(path: dbPath)
+  try db.selectPartition("work")
+  try db.saveApps([AppItem(name: "Slack", path: "/Applications/Slack.app")])
+
+  // 使用中のパーティションは削除しない
+  try db.removePartition("work")
+  #expect(FileManager.default.fileExists(atPath: workPath))
+
+  try db.selectPartition(nil)
+  try db.removePartition("work")
+  #expect(!FileManager.default.fileExists(atPath: workPath))
+  try db.selectPartition("work")
+  #expect(try db.isEmpty())
+}
+
+@Test func cacheDatabasePartitionPathAddsNameBeforeExtension() {
+  #expect(CacheDatabase.partitionPath(base: "/tmp/cache.db", name: "work") == "/tmp/cache-work.db")
+  #expect(CacheDatabase.partitionPath(base: "/tmp/cache.db", name: "") == "/tmp/cache.db")
+  #expect(CacheDatabase.partitionPath(base: "/tmp/cache", name: "work") == "/tmp/cache-work")
+}
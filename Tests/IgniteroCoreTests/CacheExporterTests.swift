@@ -0,0 +1,52 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("CacheExporter")
+struct CacheExporterTests {
+  private let apps = [
+    AppItem(
+      name: "Safari", path: "/Applications/Safari.app", bundleIdentifier: "com.apple.Safari")
+  ]
+  private let directories = [
+    DirectoryItem(name: "web, \"app\"", path: "/dev/web", isGitRepo: true, gitBranch: "main")
+  ]
+  private let targets = [TerminalTarget(kind: .sshHost, name: "prod", detail: "deploy@prod")]
+
+  @Test("JSON は種別ごとの配列にキャッシュの項目をそのまま書き出す")
+  func jsonContainsEveryKind() throws {
+    let data = try CacheExporter.export(
+      apps: apps, directories: directories, terminalTargets: targets, format: .json)
+    let object = try #require(try JSONSerialization.jsonObject(with: data) as? [String: Any])
+
+    let exportedApps = try #require(object["apps"] as? [[String: Any]])
+    #expect(exportedApps.first?["bundle_id"] as? String == "com.apple.Safari")
+    let exportedDirectories = try #require(object["directories"] as? [[String: Any]])
+    #expect(exportedDirectories.first?["git_branch"] as? String == "main")
+    let exportedTargets = try #require(object["terminal_targets"] as? [[String: Any]])
+    #expect(exportedTargets.first?["identifier"] as? String == "ssh://prod")
+  }
+
+  @Test("CSV は共通の列で 1 行 1 項目を書き出し、カンマや引用符を含む値を囲む")
+  func csvQuotesFields() throws {
+    let data = try CacheExporter.export(
+      apps: apps, directories: directories, terminalTargets: targets, format: .csv)
+    let lines = String(decoding: data, as: UTF8.self)
+      .split(separator: "\r\n", omittingEmptySubsequences: true)
+
+    #expect(lines.count == 4)
+    #expect(lines[0] == CacheExporter.csvColumns.joined(separator: ","))
+    #expect(lines[1].hasPrefix("app,Safari,/Applications/Safari.app,"))
+    #expect(lines[2].hasPrefix("directory,\"web, \"\"app\"\"\",/dev/web,"))
+    #expect(lines[3].hasPrefix("ssh,prod,ssh://prod,"))
+    #expect(lines[3].hasSuffix(",deploy@prod"))
+  }
+
+  @Test("形式を省略した場合は拡張子が .csv なら CSV、それ以外は JSON")
+  func infersFormatFromExtension() {
+    #expect(CacheExportFormat.inferred(fromPath: "/tmp/cache.CSV") == .csv)
+    #expect(CacheExportFormat.inferred(fromPath: "/tmp/cache.json") == .json)
+    #expect(CacheExportFormat.inferred(fromPath: "/tmp/cache") == .json)
+  }
+}
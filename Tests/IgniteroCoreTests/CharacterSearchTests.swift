@@ -0,0 +1,78 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("CharacterSearch")
+struct CharacterSearchTests {
+
+  private let search = CharacterSearch(
+    emoji: EmojiKeywordSearch(keywords: [
+      "🤷": ["お手上げする人", "person shrugging", "shrug"],
+      "✅": ["白抜きのチェック", "check mark button", "white_check_mark"],
+      "👍": ["サムズアップ", "thumbs up", "いいね"],
+    ]),
+    symbols: [
+      SymbolEntry(character: "✓", name: "チェックマーク", keywords: ["check mark", "tick"]),
+      SymbolEntry(character: "⌘", name: "Command キー", keywords: ["command", "cmd"]),
+    ]
+  )
+
+  @Test("ショートコード表記の前後の : を無視して完全一致を先頭にする")
+  func shortcodeExactMatch() {
+    let results = search.search(":shrug:")
+    #expect(results.first?.path == "🤷")
+    #expect(results.first?.kind == .character)
+    #expect(results.first?.score == CharacterSearch.exactScore)
+  }
+
+  @Test("絵文字と記号の両方から名前で検索し、完全一致を前方一致より優先する")
+  func matchesEmojiAndSymbols() {
+    let results = search.search("check mark")
+    #expect(results.map(\.path) == ["✓", "✅"])
+  }
+
+  @Test("_ は空白として扱い、日本語のキーワードでも検索できる")
+  func underscoreAndJapaneseKeywords() {
+    #expect(search.search("white_check").first?.path == "✅")
+    #expect(search.search("いいね").first?.path == "👍")
+  }
+
+  @Test("空の検索語や一致しない検索語は結果なし")
+  func emptyAndUnmatched() {
+    #expect(search.search(":").isEmpty)
+    #expect(search.search("zzzz").isEmpty)
+  }
+
+  @Test("件数の上限を守る")
+  func respectsLimit() {
+    #expect(search.search("c", limit: 1).count == 1)
+  }
+
+  @Test("記号プレフィックスで絵文字・記号のみを検索する")
+  func symbolPrefixInUnifiedSearch() {
+    let service = SearchService(characterSearch: search)
+    let index = SearchIndex(
+      apps: [AppItem(name: "Shrug Tool", path: "/Applications/Shrug Tool.app")],
+      directories: [], commands: [])
+    let results = service.search(query: ":shrug", index: index, history: [])
+    #expect(!results.isEmpty)
+    #expect(results.allSatisfy { $0.kind == .character })
+    #expect(SearchQueryParser.parse(":shrug:").scope == .characters)
+  }
+
+  @Test("組み込みの記号データセットは文字が重複しない")
+  func catalogHasUniqueCharacters() {
+    let characters = SymbolCatalog.entries.map(\.character)
+    #expect(Set(characters).count == characters.count)
+  }
+
+  @Test("組み込みの辞書は英語名・ショートコード・Unicode の文字名でも検索できる")
+  func bundledDatasetMatchesEnglishNames() {
+    let shared = CharacterSearch.shared
+    #expect(shared.search(":thumbsup:").map(\.path).contains("👍"))
+    #expect(shared.search("thumbs up").first?.path == "👍")
+    #expect(shared.search(":white_check_mark:").map(\.path).contains("✅"))
+    #expect(shared.search("left curly bracket").first?.path == "{")
+  }
+}
@@ -0,0 +1,48 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+// MARK: - ClipboardCopyFormat テスト
+
+@Suite("ClipboardCopyFormat")
+struct ClipboardCopyFormatTests {
+
+  @Test("アプリ・ディレクトリはパス・file:// URL・名前をコピーできる")
+  func textForFileItems() {
+    let app = SearchResult(
+      appItem: AppItem(name: "Safari", path: "/Applications/Safari.app"), score: 0)
+    let directory = SearchResult(
+      directoryItem: DirectoryItem(name: "my api", path: "/Users/test/my api"), score: 0)
+
+    #expect(ClipboardCopyFormat.path.text(for: app) == "/Applications/Safari.app")
+    #expect(ClipboardCopyFormat.fileURL.text(for: app) == "file:///Applications/Safari.app")
+    #expect(ClipboardCopyFormat.name.text(for: app) == "Safari")
+    #expect(ClipboardCopyFormat.path.text(for: directory) == "/Users/test/my api")
+    #expect(ClipboardCopyFormat.fileURL.text(for: directory) == "file:///Users/test/my%20api")
+    #expect(ClipboardCopyFormat.name.text(for: directory) == "my api")
+  }
+
+  @Test("ファイルを指さない結果は名前だけをコピーできる")
+  func onlyNameForOtherResults() {
+    let command = SearchResult(
+      customCommand: CustomCommand(alias: "build", command: "make build"), score: 0)
+    let emoji = SearchResult(name: "Emoji", kind: .emoji, score: 0)
+
+    #expect(ClipboardCopyFormat.path.text(for: command) == nil)
+    #expect(ClipboardCopyFormat.fileURL.text(for: command) == nil)
+    #expect(ClipboardCopyFormat.name.text(for: command) == "build")
+    #expect(ClipboardCopyFormat.path.text(for: emoji) == nil)
+    #expect(ClipboardCopyFormat.name.text(for: emoji) == "Emoji")
+  }
+
+  @Test("Web 検索はパスとして URL をコピーし、file:// URL はコピーできない")
+  func webSearchCopiesURLAsPath() {
+    let search = SearchResult(
+      name: "Google で検索", kind: .webSearch, score: 0,
+      path: "https://www.google.com/search?q=swift")
+
+    #expect(ClipboardCopyFormat.path.text(for: search) == "https://www.google.com/search?q=swift")
+    #expect(ClipboardCopyFormat.fileURL.text(for: search) == nil)
+  }
+}
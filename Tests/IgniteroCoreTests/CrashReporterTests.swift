@@ -0,0 +1,77 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+private func makeTempStore() -> CrashReportStore {
+  CrashReportStore(
+    directory: FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-crash-test-\(UUID().uuidString)"))
+}
+
+@Suite("CrashReportStore")
+struct CrashReportStoreTests {
+
+  @Test("未報告の記録は 1 回だけ返し、日時付きのファイルへ移す")
+  func takesPendingReportOnce() throws {
+    let store = makeTempStore()
+    #expect(store.takePendingReport() == nil)
+
+    try store.record("Ignitero Launcher crashed\nSignal: 11\n")
+    let now = Date(timeIntervalSince1970: 1_700_000_000)
+    let report = try #require(store.takePendingReport(now: now))
+
+    #expect(report.text.contains("Signal: 11"))
+    #expect(report.fileURL.lastPathComponent.hasPrefix("crash-"))
+    #expect(FileManager.default.fileExists(atPath: report.fileURL.path))
+    #expect(!FileManager.default.fileExists(atPath: store.pendingURL.path))
+    #expect(store.takePendingReport(now: now) == nil)
+  }
+
+  @Test("報告済みの記録は上限数まで残す")
+  func prunesArchivedReports() throws {
+    let store = makeTempStore()
+    for i in 0..<(CrashReportStore.maxArchivedReports + 2) {
+      try store.record("crash \(i)")
+      _ = store.takePendingReport(now: Date(timeIntervalSince1970: 1_700_000_000 + Double(i)))
+    }
+
+    let files = try FileManager.default.contentsOfDirectory(atPath: store.directory.path)
+    #expect(files.count == CrashReportStore.maxArchivedReports)
+  }
+}
+
+@Suite("CrashReport")
+struct CrashReportTests {
+
+  @Test("例外の記録に名前・理由・バックトレースを含める")
+  func exceptionText() {
+    let text = CrashReport.exceptionText(
+      name: "NSRangeException", reason: "index 3 beyond bounds",
+      callStack: ["0 CoreFoundation", "1 libobjc.A.dylib"])
+
+    let lines = text.split(separator: "\n").map(String.init)
+    #expect(lines[0].hasPrefix("Ignitero Launcher v\(Ignitero.version) crashed"))
+    #expect(lines[1] == "Exception: NSRangeException")
+    #expect(lines[2] == "Reason: index 3 beyond bounds")
+    #expect(lines.suffix(2) == ["0 CoreFoundation", "1 libobjc.A.dylib"])
+  }
+
+  @Test("Issue の URL に原因の行と記録の先頭を入れる")
+  func issueURL() throws {
+    let report = CrashReport(
+      fileURL: URL(fileURLWithPath: "/tmp/crash-20231114-221320.log"),
+      text: "Ignitero Launcher crashed\nSignal: 11\n" + String(repeating: "x", count: 100))
+    let url = try #require(report.issueURL(maxLogLength: 40))
+    let components = try #require(URLComponents(url: url, resolvingAgainstBaseURL: false))
+    let query = Dictionary(
+      uniqueKeysWithValues: (components.queryItems ?? []).map { ($0.name, $0.value ?? "") })
+
+    #expect(components.path == "/owayo/ignitero-launcher/issues/new")
+    #expect(query["title"] == "Crash: Signal: 11")
+    let body = try #require(query["body"])
+    #expect(body.contains("Signal: 11"))
+    #expect(!body.contains(String(repeating: "x", count: 100)))
+    #expect(body.contains("crash-20231114-221320.log"))
+  }
+}
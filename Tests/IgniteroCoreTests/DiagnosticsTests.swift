@@ -0,0 +1,73 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("Diagnostics")
+struct DiagnosticsTests {
+
+  @Test("アクセシビリティ権限がなければ失敗とする")
+  func accessibility() {
+    #expect(Diagnostics.accessibility(isTrusted: true).status == .ok)
+    #expect(Diagnostics.accessibility(isTrusted: false).status == .failed)
+  }
+
+  @Test("ホットキーの登録失敗は表示用が失敗、追加ショートカットは警告とする")
+  func hotkeys() {
+    let binding = HotkeyBinding(shortcut: "cmd+shift+d", mode: .directories)
+    #expect(Diagnostics.hotkeys(isToggleRegistered: true, failedBindings: []).status == .ok)
+    #expect(Diagnostics.hotkeys(isToggleRegistered: false, failedBindings: []).status == .failed)
+
+    let check = Diagnostics.hotkeys(isToggleRegistered: true, failedBindings: [binding])
+    #expect(check.status == .warning)
+    #expect(check.detail.contains("cmd+shift+d"))
+  }
+
+  @Test("キャッシュ DB の問題や検査の失敗は失敗とする")
+  func cacheIntegrity() {
+    struct Failure: Error {}
+    #expect(Diagnostics.cacheIntegrity(.success([])).status == .ok)
+    #expect(Diagnostics.cacheIntegrity(.success(["row 3 missing"])).status == .failed)
+    #expect(Diagnostics.cacheIntegrity(.failure(Failure())).status == .failed)
+  }
+
+  @Test("設定ファイルが未作成なら問題なし、読み込めなければ失敗とする")
+  func settingsFile() {
+    #expect(Diagnostics.settingsFile(.valid).status == .ok)
+    #expect(Diagnostics.settingsFile(.missing).status == .ok)
+    #expect(Diagnostics.settingsFile(.invalid("broken")).status == .failed)
+  }
+
+  @Test("アイコンキャッシュが上限を超えると警告とする")
+  func iconCache() {
+    let usage = IconCacheUsage(fileCount: 10, totalBytes: 2_000)
+    #expect(Diagnostics.iconCache(usage, warningBytes: 5_000).status == .ok)
+    #expect(Diagnostics.iconCache(usage, warningBytes: 1_000).status == .warning)
+  }
+
+  @Test("完了しなかったスキャンを警告として列挙する")
+  func scanHealth() {
+    #expect(Diagnostics.scanHealth([]).status == .ok)
+    let check = Diagnostics.scanHealth([
+      DirectoryScanStatus(path: "/dev/a", state: .completed, duration: 0.1),
+      DirectoryScanStatus(path: "/Volumes/NAS", state: .timedOut, duration: 5),
+    ])
+    #expect(check.status == .warning)
+    #expect(check.detail.contains("/Volumes/NAS"))
+    #expect(!check.detail.contains("/dev/a"))
+  }
+
+  @Test("不具合報告用のテキストに各項目の状態を含める")
+  func reportSummary() {
+    let report = DiagnosticReport(checks: [
+      Diagnostics.accessibility(isTrusted: true),
+      Diagnostics.settingsFile(.invalid("broken")),
+    ])
+    #expect(report.hasProblems)
+    let lines = report.summary.split(separator: "\n")
+    #expect(lines.count == 3)
+    #expect(lines[1].hasPrefix("[OK] accessibility:"))
+    #expect(lines[2].hasPrefix("[FAILED] settings_file:"))
+    #expect(!DiagnosticReport(checks: [Diagnostics.settingsFile(.valid)]).hasProblems)
+  }
+}
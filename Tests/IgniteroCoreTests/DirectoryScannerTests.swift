@@ -1,4 +1,5 @@
 import Foundation
+import Synchronization
 import Testing
 
 @testable import IgniteroCore
@@ -9,6 +10,12 @@ struct MockFileSystemProvider: FileSystemProvider {
   var directoryContents: [String: [String]] = [:]
   var directoryFlags: Set<String> = []
   var existingPaths: Set<String> = []
+  /// シンボリックリンクのパス → リンク先の実体パス
+  var symlinks: [String: String] = [:]
+  /// ファイルパス → 内容
+  var fileContents: [String: String] = [:]
+  /// パス → Finder のタグ
+  var tags: [String: [FinderTag]] = [:]
 
   func contentsOfDirectory(atPath path: String) throws -> [String] {
     guard let contents = directoryContents[path] else {
@@ -24,6 +31,22 @@ struct MockFileSystemProvider: FileSystemProvider {
   func fileExists(atPath path: String) -> Bool {
     existingPaths.contains(path)
   }
+
+  func isSymbolicLink(atPath path: String) -> Bool {
+    symlinks[path] != nil
+  }
+
+  func canonicalPath(atPath path: String) -> String {
+    symlinks[path] ?? path
+  }
+
+  func contentsOfFile(atPath path: String) -> String? {
+    fileContents[path]
+  }
+
+  func finderTags(atPath path: String) -> [FinderTag] {
+    tags[path] ?? []
+  }
 }
 
 // MARK: - ScanResult テスト
@@ -46,6 +69,165 @@ struct ScanResultTests {
   }
 }
 
+// MARK: - 並列スキャンテスト
+
+/// パスごとに待ち時間を指定できるスキャナー（同時実行数も記録する）
+private final class SlowDirectoryScanner: DirectoryScannerProtocol, Sendable {
+  let delays: [String: Duration]
+  let unreadable: Set<String>
+  private let running = Mutex((current: 0, peak: 0))
+
+  init(delays: [String: Duration] = [:], unreadable: Set<String> = []) {
+    self.delays = delays
+    self.unreadable = unreadable
+  }
+
+  var peakConcurrency: Int { running.withLock { $0.peak } }
+
+  func scan(directories: [RegisteredDirectory]) throws -> ScanResult {
+    ScanResult(directories: [], apps: [])
+  }
+
+  func scan(directory: RegisteredDirectory) throws -> ScanResult {
+    running.withLock {
+      $0.current += 1
+      $0.peak = max($0.peak, $0.current)
+    }
+    defer { running.withLock { $0.current -= 1 } }
+    if let delay = delays[directory.path] {
+      let (seconds, attoseconds) = delay.components
+      Thread.sleep(forTimeInterval: Double(seconds) + Double(attoseconds) / 1e18)
+    }
+    guard !unreadable.contains(directory.path) else {
+      throw FileSystemError.directoryNotFound(directory.path)
+    }
+    let name = (directory.path as NSString).lastPathComponent
+    return ScanResult(directories: [DirectoryItem(name: name, path: directory.path)], apps: [])
+  }
+}
+
+/// 打ち切られるまで走査を続けるスキャナー（打ち切りを受け取ったかを記録する）
+private final class HangingDirectoryScanner: DirectoryScannerProtocol, Sendable {
+  private let cancelled = Mutex(false)
+
+  var observedCancellation: Bool { cancelled.withLock { $0 } }
+
+  func scan(directories: [RegisteredDirectory]) throws -> ScanResult {
+    ScanResult(directories: [], apps: [])
+  }
+
+  func scan(
+    directory: RegisteredDirectory, cancellation: ScanCancellation
+  ) throws -> ScanResult {
+    let deadline = Date().addingTimeInterval(5)
+    while !cancellation.isCancelled, Date() < deadline {
+      Thread.sleep(forTimeInterval: 0.01)
+    }
+    cancelled.withLock { $0 = cancellation.isCancelled }
+    return ScanResult(directories: [], apps: [])
+  }
+}
+
+@Suite("DirectoryScanner Concurrent Scan")
+struct DirectoryScannerConcurrentScanTests {
+
+  private func registered(_ path: String) -> RegisteredDirectory {
+    RegisteredDirectory(path: path, parentOpenMode: .finder, subdirsOpenMode: .none)
+  }
+
+  @Test("応答しないディレクトリは制限時間で打ち切り、他のディレクトリの結果を返す")
+  func slowDirectoryTimesOut() async {
+    let scanner = SlowDirectoryScanner(delays: ["/Volumes/nas": .seconds(2)])
+    let start = ContinuousClock.now
+
+    let report = await scanner.scanConcurrently(
+      directories: [registered("/Volumes/nas"), registered("/dev/a")],
+      timeout: .milliseconds(200))
+
+    #expect(ContinuousClock.now - start < .seconds(1.5))
+    #expect(report.statuses.map(\.path) == ["/Volumes/nas", "/dev/a"])
+    #expect(report.statuses[0].state == .timedOut)
+    #expect(report.statuses[1].state == .completed)
+    #expect(report.statuses[1].itemCount == 1)
+    #expect(report.result.directories.map(\.path) == ["/dev/a"])
+    #expect(report.incompletePaths == ["/Volumes/nas"])
+  }
+
+  @Test("制限時間を過ぎたスキャンには打ち切りを伝える")
+  func timedOutScanIsCancelled() async throws {
+    let scanner = HangingDirectoryScanner()
+
+    let report = await scanner.scanConcurrently(
+      directories: [registered("/Volumes/nas")], timeout: .milliseconds(100))
+    #expect(report.statuses.map(\.state) == [.timedOut])
+
+    for _ in 0..<50 where !scanner.observedCancellation {
+      try await Task.sleep(nanoseconds: 20_000_000)
+    }
+    #expect(scanner.observedCancellation)
+  }
+
+  @Test("全体を打ち切ると実行中の走査を止め、残りのディレクトリはスキャンしない")
+  func cancellationStopsRemainingScans() async throws {
+    let scanner = HangingDirectoryScanner()
+    let cancellation = ScanCancellation()
+    Task {
+      try? await Task.sleep(for: .milliseconds(100))
+      cancellation.cancel()
+    }
+
+    let report = await scanner.scanConcurrently(
+      directories: [registered("/Volumes/nas"), registered("/Volumes/usb")],
+      timeout: .seconds(30), maxConcurrentScans: 1, cancellation: cancellation)
+
+    #expect(report.statuses.map(\.path) == ["/Volumes/nas"])
+    #expect(scanner.observedCancellation)
+  }
+
+  @Test("DirectoryScanner は打ち切られると配下の走査をやめる")
+  func cancelledScanStopsTraversal() throws {
+    let fs = MockFileSystemProvider(
+      directoryContents: ["/dev": ["a", "b"], "/dev/a": [], "/dev/b": []],
+      directoryFlags: ["/dev/a", "/dev/b"])
+    let directory = RegisteredDirectory(
+      path: "/dev", parentOpenMode: .finder, subdirsOpenMode: .finder)
+    let cancellation = ScanCancellation()
+    cancellation.cancel()
+
+    let result = try DirectoryScanner(fileSystemProvider: fs).scan(
+      directory: directory, cancellation: cancellation)
+
+    #expect(result.directories.map(\.path) == ["/dev"])
+  }
+
+  @Test("読み取れないディレクトリは unavailable として報告する")
+  func unreadableDirectoryIsUnavailable() async {
+    let fs = MockFileSystemProvider(directoryContents: ["/dev/a": []])
+    let report = await DirectoryScanner(fileSystemProvider: fs).scanConcurrently(
+      directories: [registered("/Volumes/usb"), registered("/dev/a")])
+
+    guard case .unavailable = report.statuses[0].state else {
+      Issue.record("Expected unavailable, got \(report.statuses[0].state)")
+      return
+    }
+    #expect(report.statuses[1].state == .completed)
+    #expect(report.result.directories.map(\.path) == ["/dev/a"])
+  }
+
+  @Test("同時に実行するスキャンを上限以内に抑え、結果は登録順に並べる")
+  func respectsConcurrencyLimit() async {
+    let paths = (1...6).map { "/dev/p\($0)" }
+    let scanner = SlowDirectoryScanner(
+      delays: Dictionary(uniqueKeysWithValues: paths.map { ($0, Duration.milliseconds(50)) }))
+
+    let report = await scanner.scanConcurrently(
+      directories: paths.map(registered), maxConcurrentScans: 2)
+
+    #expect(scanner.peakConcurrency <= 2)
+    #expect(report.result.directories.map(\.path) == paths)
+  }
+}
+
 // MARK: - DirectoryScanner プロトコルテスト
 
 @Suite("DirectoryScannerProtocol")
@@ -119,6 +301,41 @@ struct DirectoryScannerSubdirectoryScanningTests {
     #expect(subdirs.contains { $0.name == "project-b" && $0.path == "\(basePath)/project-b" })
   }
 
+  @Test func subdirectoryNameTemplateIsAppliedWithRawName() throws {
+    var fs = MockFileSystemProvider()
+    let basePath = "/Users/dev/work"
+    fs.directoryContents[basePath] = ["api-server"]
+    fs.directoryFlags = [basePath, "\(basePath)/api-server"]
+    fs.existingPaths = [basePath, "\(basePath)/api-server"]
+
+    let registered = RegisteredDirectory(
+      path: basePath, parentOpenMode: .finder, subdirsOpenMode: .editor, scanForApps: false,
+      subdirsNameTemplate: "{root}/{name}")
+
+    let result = try DirectoryScanner(fileSystemProvider: fs).scan(directories: [registered])
+
+    let subdir = try #require(result.directories.first { $0.path == "\(basePath)/api-server" })
+    #expect(subdir.name == "work/api-server")
+    #expect(subdir.originalName == "api-server")
+    // 親ディレクトリ自身にはテンプレートを適用しない
+    #expect(result.directories.first { $0.path == basePath }?.name == "work")
+  }
+
+  @Test func subdirectoryNameTemplateExpansion() {
+    func displayName(_ template: String?) -> String {
+      RegisteredDirectory(
+        path: "/Users/dev/work/", parentOpenMode: .none, subdirsOpenMode: .finder,
+        scanForApps: false, subdirsNameTemplate: template
+      ).subdirectoryDisplayName(for: "web", parentPath: "/Users/dev/work/clients")
+    }
+
+    #expect(displayName(nil) == "web")
+    #expect(displayName("  ") == "web")
+    #expect(displayName("work/") == "work/web")
+    #expect(displayName("{name} ({parent})") == "web (clients)")
+    #expect(displayName("{root}:{parent}/{name}") == "work:clients/web")
+  }
+
   @Test func scanExcludesRegularFiles() throws {
     var fs = MockFileSystemProvider()
     let basePath = "/Users/dev/projects"
@@ -704,6 +921,273 @@ struct DirectoryScannerParentNameTests {
   }
 }
 
+// MARK: - 除外パターンテスト
+
+@Suite("DirectoryScanner Exclude Patterns")
+struct DirectoryScannerExcludePatternTests {
+
+  @Test func excludedSubdirectoriesAndAppsAreSkipped() throws {
+    var fs = MockFileSystemProvider()
+    let basePath = "/Users/dev/projects"
+    fs.directoryContents[basePath] = ["app", "node_modules", "target", "Tool.app", "Old.app"]
+    fs.directoryFlags = [
+      basePath,
+      "\(basePath)/app",
+      "\(basePath)/node_modules",
+      "\(basePath)/target",
+      "\(basePath)/Tool.app",
+      "\(basePath)/Old.app",
+    ]
+
+    let registered = RegisteredDirectory(
+      path: basePath,
+      parentOpenMode: .none,
+      subdirsOpenMode: .editor,
+      scanForApps: true,
+      excludePatterns: ["node_modules", "/target/", "Old*.app"]
+    )
+
+    let result = try DirectoryScanner(fileSystemProvider: fs).scan(directories: [registered])
+
+    #expect(result.directories.map(\.name) == ["app"])
+    #expect(result.apps.map(\.name) == ["Tool"])
+  }
+}
+
+// MARK: - 走査深さテスト
+
+@Suite("DirectoryScanner Subdirectory Depth")
+struct DirectoryScannerDepthTests {
+  let basePath = "/Users/dev/monorepo"
+
+  private func makeFileSystem() -> MockFileSystemProvider {
+    var fs = MockFileSystemProvider()
+    fs.directoryContents = [
+      basePath: ["packages", "README.md"],
+      "\(basePath)/packages": ["web", "api"],
+      "\(basePath)/packages/web": ["src", "Preview.app"],
+      "\(basePath)/packages/api": [],
+      "\(basePath)/packages/web/src": ["components"],
+    ]
+    fs.directoryFlags = [
+      basePath,
+      "\(basePath)/packages",
+      "\(basePath)/packages/web",
+      "\(basePath)/packages/api",
+      "\(basePath)/packages/web/src",
+      "\(basePath)/packages/web/src/components",
+      "\(basePath)/packages/web/Preview.app",
+    ]
+    return fs
+  }
+
+  private func registered(depth: Int, scanForApps: Bool = false) -> RegisteredDirectory {
+    RegisteredDirectory(
+      path: basePath, parentOpenMode: .none, subdirsOpenMode: .editor,
+      scanForApps: scanForApps, subdirsDepth: depth)
+  }
+
+  @Test func defaultDepthScansDirectChildrenOnly() throws {
+    let scanner = DirectoryScanner(fileSystemProvider: makeFileSystem())
+    let result = try scanner.scan(directories: [registered(depth: 1)])
+    #expect(result.directories.map(\.path) == ["\(basePath)/packages"])
+  }
+
+  @Test func depthThreeIncludesNestedPackages() throws {
+    let scanner = DirectoryScanner(fileSystemProvider: makeFileSystem())
+    let result = try scanner.scan(directories: [registered(depth: 3, scanForApps: true)])
+    #expect(
+      Set(result.directories.map(\.path)) == [
+        "\(basePath)/packages",
+        "\(basePath)/packages/web",
+        "\(basePath)/packages/api",
+        "\(basePath)/packages/web/src",
+      ])
+    // 深さ以内にある .app はアプリとして検出する
+    #expect(result.apps.map(\.name) == ["Preview"])
+  }
+
+  @Test func excludePatternsApplyToNestedPaths() throws {
+    var dir = registered(depth: 3)
+    dir.excludePatterns = ["packages/web"]
+    let scanner = DirectoryScanner(fileSystemProvider: makeFileSystem())
+    let result = try scanner.scan(directories: [dir])
+    #expect(
+      Set(result.directories.map(\.path)) == ["\(basePath)/packages", "\(basePath)/packages/api"])
+  }
+
+  @Test func symlinkedDirectoriesAreListedButNotTraversed() throws {
+    var fs = MockFileSystemProvider()
+    fs.directoryContents = [
+      basePath: ["loop", "real"],
+      "\(basePath)/loop": ["loop", "real"],
+      "\(basePath)/real": ["child"],
+    ]
+    fs.directoryFlags = [
+      basePath, "\(basePath)/loop", "\(basePath)/real", "\(basePath)/real/child",
+    ]
+    // loop は登録ディレクトリ自身を指すシンボリックリンク
+    fs.symlinks = ["\(basePath)/loop": basePath]
+
+    let scanner = DirectoryScanner(fileSystemProvider: fs)
+    let result = try scanner.scan(directories: [registered(depth: 5)])
+    #expect(
+      Set(result.directories.map(\.path)) == [
+        "\(basePath)/loop", "\(basePath)/real", "\(basePath)/real/child",
+      ])
+  }
+
+  @Test func depthIsClampedToSupportedRange() {
+    #expect(registered(depth: 0).effectiveSubdirsDepth == 1)
+    #expect(registered(depth: 100).effectiveSubdirsDepth == RegisteredDirectory.maxSubdirsDepth)
+  }
+
+  @Test func ownershipFollowsDepth() {
+    let dir = registered(depth: 2)
+    #expect(dir.owns(itemPath: basePath))
+    #expect(dir.owns(itemPath: "\(basePath)/packages/web"))
+    #expect(!dir.owns(itemPath: "\(basePath)/packages/web/src"))
+    #expect(!dir.owns(itemPath: "/Users/dev/monorepo-other"))
+  }
+}
+
+// MARK: - Git リポジトリ検出テスト
+
+@Suite("DirectoryScanner Git Detection")
+struct DirectoryScannerGitDetectionTests {
+  let basePath = "/Users/dev/src"
+
+  private func makeFileSystem() -> MockFileSystemProvider {
+    var fs = MockFileSystemProvider()
+    fs.directoryContents = [
+      basePath: ["github.com", "notes"],
+      "\(basePath)/github.com": ["owner"],
+      "\(basePath)/github.com/owner": ["repo", "wt"],
+      "\(basePath)/github.com/owner/repo": ["packages"],
+      "\(basePath)/notes": [],
+    ]
+    fs.directoryFlags = [
+      basePath,
+      "\(basePath)/github.com",
+      "\(basePath)/github.com/owner",
+      "\(basePath)/github.com/owner/repo",
+      "\(basePath)/github.com/owner/repo/.git",
+      "\(basePath)/github.com/owner/repo/packages",
+      "\(basePath)/github.com/owner/wt",
+      "\(basePath)/notes",
+    ]
+    fs.existingPaths = [
+      "\(basePath)/github.com/owner/repo/.git",
+      "\(basePath)/github.com/owner/wt/.git",
+    ]
+    fs.fileContents = [
+      "\(basePath)/github.com/owner/repo/.git/HEAD": "ref: refs/heads/feature/login\n",
+      // ワークツリーの .git はファイルで、実体の gitdir を指す
+      "\(basePath)/github.com/owner/wt/.git": "gitdir: /Users/dev/.worktrees/wt\n",
+      "/Users/dev/.worktrees/wt/HEAD": "0123456789abcdef0123456789abcdef01234567\n",
+    ]
+    return fs
+  }
+
+  @Test func gitReposBeyondDepthAreDetectedWithBranch() throws {
+    let registered = RegisteredDirectory(
+      path: basePath, parentOpenMode: .none, subdirsOpenMode: .editor, scanForApps: false,
+      detectGitRepos: true)
+
+    let result = try DirectoryScanner(fileSystemProvider: makeFileSystem())
+      .scan(directories: [registered])
+
+    let byPath = Dictionary(uniqueKeysWithValues: result.directories.map { ($0.path, $0) })
+    // 深さ 1 の通常ディレクトリはそのまま、深さを超えた位置は Git リポジトリのみ
+    #expect(
+      Set(byPath.keys) == [
+        "\(basePath)/github.com",
+        "\(basePath)/notes",
+        "\(basePath)/github.com/owner/repo",
+        "\(basePath)/github.com/owner/wt",
+      ])
+    #expect(byPath["\(basePath)/github.com"]?.isGitRepo == false)
+    #expect(byPath["\(basePath)/github.com/owner/repo"]?.isGitRepo == true)
+    #expect(byPath["\(basePath)/github.com/owner/repo"]?.gitBranch == "feature/login")
+    #expect(byPath["\(basePath)/github.com/owner/wt"]?.gitBranch == "0123456")
+  }
+
+  @Test func itemsInheritClampedPriority() throws {
+    let registered = RegisteredDirectory(
+      path: basePath, parentOpenMode: .finder, subdirsOpenMode: .editor, scanForApps: false,
+      priority: 9)
+
+    let result = try DirectoryScanner(fileSystemProvider: makeFileSystem())
+      .scan(directories: [registered])
+
+    #expect(!result.directories.isEmpty)
+    #expect(
+      result.directories.allSatisfy { $0.priority == RegisteredDirectory.priorityRange.upperBound })
+  }
+
+  @Test func gitDetectionDisabledKeepsDepthLimit() throws {
+    let registered = RegisteredDirectory(
+      path: basePath, parentOpenMode: .none, subdirsOpenMode: .editor, scanForApps: false)
+
+    let result = try DirectoryScanner(fileSystemProvider: makeFileSystem())
+      .scan(directories: [registered])
+
+    #expect(Set(result.directories.map(\.path)) == ["\(basePath)/github.com", "\(basePath)/notes"])
+    #expect(result.directories.allSatisfy { !$0.isGitRepo })
+  }
+
+  @Test func branchNameParsing() {
+    #expect(DirectoryScanner.branchName(fromHead: "ref: refs/heads/main\n") == "main")
+    #expect(DirectoryScanner.branchName(fromHead: "ref: refs/remotes/origin/x") == nil)
+    #expect(DirectoryScanner.branchName(fromHead: "abcdef1234567890") == "abcdef1")
+    #expect(DirectoryScanner.branchName(fromHead: nil) == nil)
+    #expect(DirectoryScanner.branchName(fromHead: "  ") == nil)
+  }
+}
+
+// MARK: - Xcode のプロジェクト検出テスト
+
+@Suite("DirectoryScanner Xcode Detection")
+struct DirectoryScannerXcodeDetectionTests {
+  let basePath = "/Users/dev/apps"
+
+  @Test func detectsXcodeProjectsOfParentAndSubdirectories() throws {
+    var fs = MockFileSystemProvider()
+    fs.directoryContents = [
+      basePath: ["Root.xcodeproj", "ios", "web"],
+      "\(basePath)/ios": ["Pods", "App.xcodeproj", "App.xcworkspace"],
+      "\(basePath)/web": ["package.json"],
+      "\(basePath)/Root.xcodeproj": ["project.pbxproj"],
+    ]
+    fs.directoryFlags = [
+      basePath, "\(basePath)/ios", "\(basePath)/web", "\(basePath)/Root.xcodeproj",
+    ]
+    let registered = RegisteredDirectory(
+      path: basePath, parentOpenMode: .editor, subdirsOpenMode: .editor, scanForApps: false)
+
+    let result = try DirectoryScanner(fileSystemProvider: fs).scan(directories: [registered])
+
+    let byPath = Dictionary(uniqueKeysWithValues: result.directories.map { ($0.path, $0) })
+    #expect(byPath[basePath]?.xcodeProject == "\(basePath)/Root.xcodeproj")
+    // ワークスペースがあればプロジェクトより優先する
+    #expect(byPath["\(basePath)/ios"]?.xcodeProject == "\(basePath)/ios/App.xcworkspace")
+    #expect(byPath["\(basePath)/web"]?.xcodeProject == nil)
+  }
+
+  @Test func unreadableItemIsKeptWithoutXcodeProject() throws {
+    var fs = MockFileSystemProvider()
+    fs.directoryContents = [basePath: ["locked"]]
+    fs.directoryFlags = [basePath, "\(basePath)/locked"]
+    let registered = RegisteredDirectory(
+      path: basePath, parentOpenMode: .none, subdirsOpenMode: .editor, scanForApps: false)
+
+    let result = try DirectoryScanner(fileSystemProvider: fs).scan(directories: [registered])
+
+    #expect(result.directories.map(\.path) == ["\(basePath)/locked"])
+    #expect(result.directories.first?.xcodeProject == nil)
+  }
+}
+
 // MARK: - キャッシュデータベース連携テスト
 
 @Suite("DirectoryScanner Cache Integration")
@@ -902,3 +1386,69 @@ struct DirectoryScannerRealFileSystemTests {
     #expect(result.apps[0].path == appBundle.path)
   }
 }
+
+@Suite("DirectoryScanner Project Type Detection")
+struct DirectoryScannerProjectTypeDetectionTests {
+  let basePath = "/Users/dev/projects"
+
+  @Test func annotatesProjectTypesOfParentAndSubdirectories() throws {
+    var fs = MockFileSystemProvider()
+    fs.directoryContents = [
+      basePath: ["Package.swift", "engine", "web", "notes"],
+      "\(basePath)/engine": ["Cargo.toml", "package.json"],
+      "\(basePath)/web": ["package.json"],
+      "\(basePath)/notes": ["todo.md"],
+    ]
+    fs.directoryFlags = [
+      basePath, "\(basePath)/engine", "\(basePath)/web", "\(basePath)/notes",
+    ]
+    let registered = RegisteredDirectory(
+      path: basePath, parentOpenMode: .editor, subdirsOpenMode: .editor, scanForApps: false)
+
+    let result = try DirectoryScanner(fileSystemProvider: fs).scan(directories: [registered])
+
+    let byPath = Dictionary(uniqueKeysWithValues: result.directories.map { ($0.path, $0) })
+    #expect(byPath[basePath]?.projectType == .swiftPackage)
+    #expect(byPath["\(basePath)/engine"]?.projectType == .rust)
+    #expect(byPath["\(basePath)/web"]?.projectType == .node)
+    #expect(byPath["\(basePath)/notes"]?.projectType == nil)
+  }
+
+  @Test func usesInjectedRegistry() throws {
+    var fs = MockFileSystemProvider()
+    fs.directoryContents = [basePath: ["Cargo.toml"]]
+    fs.directoryFlags = [basePath]
+    let registered = RegisteredDirectory(
+      path: basePath, parentOpenMode: .editor, subdirsOpenMode: .none, scanForApps: false)
+    let registry = ProjectTypeRegistry(detectors: [])
+
+    let result = try DirectoryScanner(fileSystemProvider: fs, projectTypeRegistry: registry)
+      .scan(directories: [registered])
+
+    #expect(result.directories.first?.projectType == nil)
+  }
+}
+
+@Suite("DirectoryScanner Finder Tags")
+struct DirectoryScannerFinderTagTests {
+  let basePath = "/Users/dev/clients"
+
+  @Test func readsFinderTagsOfParentAndSubdirectories() throws {
+    var fs = MockFileSystemProvider()
+    fs.directoryContents = [basePath: ["acme", "misc"], "\(basePath)/acme": []]
+    fs.directoryFlags = [basePath, "\(basePath)/acme", "\(basePath)/misc"]
+    fs.tags = [
+      basePath: [FinderTag(name: "Work", color: .blue)],
+      "\(basePath)/acme": [FinderTag(name: "urgent", color: .red)],
+    ]
+    let registered = RegisteredDirectory(
+      path: basePath, parentOpenMode: .editor, subdirsOpenMode: .editor, scanForApps: false)
+
+    let result = try DirectoryScanner(fileSystemProvider: fs).scan(directories: [registered])
+
+    let byPath = Dictionary(uniqueKeysWithValues: result.directories.map { ($0.path, $0) })
+    #expect(byPath[basePath]?.finderTags == [FinderTag(name: "Work", color: .blue)])
+    #expect(byPath["\(basePath)/acme"]?.finderTags == [FinderTag(name: "urgent", color: .red)])
+    #expect(byPath["\(basePath)/misc"]?.finderTags == [])
+  }
+}
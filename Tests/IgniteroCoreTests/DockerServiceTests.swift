@@ -0,0 +1,171 @@
+import Foundation
+import Synchronization
+import Testing
+
+@testable import IgniteroCore
+
+/// テスト用の固定コンテナ一覧
+private struct StubDocker: DockerListing {
+  let result: [DockerContainer]
+
+  func cachedContainers() -> [DockerContainer] { result }
+  func refresh() async -> [DockerContainer] { result }
+}
+
+/// 取得し直すまでは空を返すコンテナ一覧（取得し直した回数を記録する）
+private final class DelayedDocker: DockerListing, Sendable {
+  let result: [DockerContainer]
+  private let state = Mutex((fetched: false, refreshCount: 0))
+
+  init(result: [DockerContainer]) {
+    self.result = result
+  }
+
+  var refreshCount: Int { state.withLock { $0.refreshCount } }
+
+  func cachedContainers() -> [DockerContainer] {
+    state.withLock { $0.fetched } ? result : []
+  }
+
+  func refresh() async -> [DockerContainer] {
+    state.withLock {
+      $0.fetched = true
+      $0.refreshCount += 1
+    }
+    return result
+  }
+}
+
+/// Docker の検索を有効にしたプレフィックス設定
+private let dockerPrefixes = SearchPrefixSettings(dockerEnabled: true)
+
+@Suite("DockerService")
+struct DockerServiceTests {
+
+  @Test("docker ps の JSON 行からコンテナと Compose のラベルを読み取る")
+  func parsesPSOutput() {
+    let output = """
+      {"ID":"abc123","Image":"postgres:16","Labels":"com.docker.compose.project=shop,com.docker.compose.project.working_dir=/Users/dev/shop,com.docker.compose.service=db","Names":"shop-db-1","State":"running"}
+      not json
+      {"ID":"def456","Image":"redis","Labels":"","Names":"cache,cache-alias","State":"exited"}
+      """
+
+    let containers = DockerService.containers(fromPS: output)
+
+    #expect(
+      containers == [
+        DockerContainer(
+          id: "abc123", name: "shop-db-1", image: "postgres:16", state: "running",
+          composeProject: "shop", composeWorkingDirectory: "/Users/dev/shop"),
+        DockerContainer(id: "def456", name: "cache", image: "redis", state: "exited"),
+      ])
+  }
+
+  @Test("docker が見つからない場合は空を返す")
+  func returnsEmptyWithoutExecutable() async {
+    let service = DockerService(executablePaths: ["/nonexistent/docker"])
+    #expect(await service.refresh().isEmpty)
+    #expect(service.cachedContainers().isEmpty)
+  }
+
+  @Test("docker ps の出力を非同期に読み取り、取得した一覧を使い回す")
+  func refreshRunsProcessAsynchronously() async throws {
+    let script = FileManager.default.temporaryDirectory
+      .appendingPathComponent("docker-stub-\(UUID().uuidString)")
+    let line = #"{"ID":"abc","Image":"redis","Labels":"","Names":"cache","State":"running"}"#
+    try "#!/bin/sh\necho '\(line)'\n".write(to: script, atomically: true, encoding: .utf8)
+    try FileManager.default.setAttributes([.posixPermissions: 0o755], ofItemAtPath: script.path)
+    defer { try? FileManager.default.removeItem(at: script) }
+    let service = DockerService(executablePaths: [script.path])
+
+    #expect(service.cachedContainers().isEmpty)
+    let containers = await service.refresh()
+
+    #expect(containers == [DockerContainer(id: "abc", name: "cache", image: "redis")])
+    #expect(service.cachedContainers() == containers)
+  }
+
+  @Test("シェルは bash があれば bash、なければ sh で開く")
+  func buildsShellCommand() {
+    let container = DockerContainer(id: "abc", name: "shop-db-1", image: "postgres")
+    #expect(
+      container.shellCommand
+        == "docker exec -it shop-db-1 sh -c "
+        + "'if command -v bash >/dev/null; then exec bash; else exec sh; fi'")
+  }
+}
+
+@Suite("SearchService Docker")
+struct SearchServiceDockerTests {
+
+  private let service = SearchService(
+    docker: StubDocker(result: [
+      DockerContainer(
+        id: "1", name: "shop-db-1", image: "postgres:16", composeProject: "shop",
+        composeWorkingDirectory: "/Users/dev/shop"),
+      DockerContainer(
+        id: "2", name: "shop-web-1", image: "node:22", composeProject: "shop",
+        composeWorkingDirectory: "/Users/dev/shop"),
+      DockerContainer(id: "3", name: "old-redis", image: "redis", state: "exited"),
+    ]))
+
+  @Test("docker プレフィックスで実行中のコンテナのシェルと Compose のフォルダを返す")
+  func listsContainersAndProjects() {
+    let results = service.search(query: "", index: .empty, history: [], defaultScope: .docker)
+
+    #expect(results.map(\.name) == ["shop", "shop-db-1", "shop-web-1"])
+    #expect(results.map(\.kind) == [.directory, .command, .command])
+    #expect(results[0].path == "/Users/dev/shop")
+    #expect(results[1].command?.hasPrefix("docker exec -it shop-db-1 ") == true)
+    #expect(
+      SearchQueryParser.parse("docker web", prefixes: dockerPrefixes)
+        == ParsedSearchQuery(scope: .docker, term: "web"))
+  }
+
+  @Test("Docker の検索は既定では無効で、docker プレフィックスも通常の検索語になる")
+  func dockerSearchIsOptIn() {
+    #expect(!SearchPrefixSettings.default.dockerEnabled)
+    #expect(
+      SearchQueryParser.parse("docker web") == ParsedSearchQuery(scope: .all, term: "docker web"))
+  }
+
+  @Test("イメージ名でもコンテナに一致する")
+  func matchesContainerByImage() {
+    let results = service.search(
+      query: "docker postgres", index: .empty, history: [], prefixes: dockerPrefixes)
+    #expect(results.first?.name == "shop-db-1")
+  }
+
+  @Test("検索は取得済みの一覧だけを使い、取得し直して変わったかを返す")
+  func searchUsesCachedContainers() async {
+    let docker = DelayedDocker(result: [DockerContainer(id: "1", name: "web", image: "nginx")])
+    let service = SearchService(docker: docker)
+
+    #expect(service.search(query: "", index: .empty, history: [], defaultScope: .docker).isEmpty)
+    #expect(docker.refreshCount == 0)
+
+    #expect(await service.refreshDockerContainers())
+    #expect(!(await service.refreshDockerContainers()))
+    #expect(
+      service.search(query: "", index: .empty, history: [], defaultScope: .docker).map(\.name)
+        == ["web"])
+  }
+
+  @Test("ランチャーで docker プレフィックスを入力すると一覧を取得し直して検索し直す")
+  @MainActor
+  func launcherRefreshesDockerContainers() async throws {
+    let docker = DelayedDocker(result: [DockerContainer(id: "1", name: "web", image: "nginx")])
+    let vm = LauncherViewModel(
+      searchService: SearchService(docker: docker), searchSession: SearchSession(debounce: .zero))
+    vm.searchPrefixes = dockerPrefixes
+
+    vm.searchQuery = "docker web"
+    vm.scheduleSearch()
+    for _ in 0..<50 where vm.searchResults.isEmpty {
+      try await Task.sleep(nanoseconds: 20_000_000)
+    }
+
+    #expect(vm.searchResults.map(\.name) == ["web"])
+    #expect(docker.refreshCount >= 1)
+  }
+}
@@ -85,6 +85,17 @@ struct EditorPickerStateShortcutTests {
     #expect(state.selectedEditor == .zed)
   }
 
+  @MainActor
+  @Test func shortcutsSelectJetBrainsIDEs() {
+    let state = EditorPickerState(availableEditors: EditorType.allCases)
+    #expect(state.handleKey("i"))
+    #expect(state.selectedEditor == .intellij)
+    #expect(state.handleKey("p"))
+    #expect(state.selectedEditor == .pycharm)
+    #expect(state.handleKey("s"))
+    #expect(state.selectedEditor == .webstorm)
+  }
+
   @MainActor
   @Test func shortcutForUnavailableEditorIsIgnored() {
     let state = EditorPickerState(availableEditors: [.cursor, .vscode])
@@ -96,7 +107,7 @@ struct EditorPickerStateShortcutTests {
   @MainActor
   @Test func unknownKeyIsIgnored() {
     let state = EditorPickerState(availableEditors: EditorType.allCases)
-    let handled = state.handleKey("x")
+    let handled = state.handleKey("q")
     #expect(handled == false)
     #expect(state.selectedIndex == nil)
   }
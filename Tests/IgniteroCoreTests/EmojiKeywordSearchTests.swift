@@ -85,6 +85,19 @@ struct EmojiKeywordSearchTests {
     // 大文字小文字無視なので同じ結果になるはず
     #expect(lower == upper)
   }
+
+  @Test("英語のキーワードがない単一のコードポイントの文字に Unicode の文字名を加える")
+  func addsUnicodeNamesToSingleScalarEntries() {
+    let keywords = EmojiKeywordSearch.addingUnicodeNames(to: [
+      "{": ["開き波括弧", "中括弧"],
+      "☺\u{FE0F}": ["笑顔", "white smiling face"],
+      "👍🏽": ["サムズアップ: やや濃い肌色"],
+    ])
+
+    #expect(keywords["{"] == ["開き波括弧", "中括弧", "left curly bracket"])
+    #expect(keywords["☺\u{FE0F}"] == ["笑顔", "white smiling face"])
+    #expect(keywords["👍🏽"] == ["サムズアップ: やや濃い肌色"])
+  }
 }
 
 // MARK: - EmojiKeywordSearch エッジケース
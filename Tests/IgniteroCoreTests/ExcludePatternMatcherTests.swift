@@ -0,0 +1,54 @@
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("ExcludePatternMatcher")
+struct ExcludePatternMatcherTests {
+
+  @Test("スラッシュを含まないパターンは任意の階層の名前に一致する")
+  func unanchoredNameMatchesAnyDepth() {
+    let matcher = ExcludePatternMatcher(patterns: ["node_modules"])
+    #expect(matcher.isExcluded(relativePath: "node_modules"))
+    #expect(matcher.isExcluded(relativePath: "packages/web/node_modules"))
+    #expect(!matcher.isExcluded(relativePath: "node_modules_backup"))
+  }
+
+  @Test("スラッシュを含むパターンは登録ディレクトリ起点で一致する")
+  func anchoredPatternMatchesFromRoot() {
+    let matcher = ExcludePatternMatcher(patterns: ["/dist", "build/cache"])
+    #expect(matcher.isExcluded(relativePath: "dist"))
+    #expect(!matcher.isExcluded(relativePath: "web/dist"))
+    #expect(matcher.isExcluded(relativePath: "build/cache"))
+    #expect(!matcher.isExcluded(relativePath: "app/build/cache"))
+  }
+
+  @Test("ワイルドカードと ** に対応する")
+  func wildcards() {
+    let matcher = ExcludePatternMatcher(patterns: ["*.tmp", "cache-?", "vendor/**/fixtures"])
+    #expect(matcher.isExcluded(relativePath: "a/session.tmp"))
+    #expect(matcher.isExcluded(relativePath: "cache-1"))
+    #expect(!matcher.isExcluded(relativePath: "cache-10"))
+    #expect(matcher.isExcluded(relativePath: "vendor/fixtures"))
+    #expect(matcher.isExcluded(relativePath: "vendor/a/b/fixtures"))
+  }
+
+  @Test("除外されたディレクトリの配下も除外される")
+  func descendantsOfExcludedDirectory() {
+    let matcher = ExcludePatternMatcher(patterns: ["target/"])
+    #expect(matcher.isExcluded(relativePath: "target/debug/app"))
+  }
+
+  @Test("! で始まるパターンは除外を取り消す（後勝ち）")
+  func negation() {
+    let matcher = ExcludePatternMatcher(patterns: [".*", "!.github"])
+    #expect(matcher.isExcluded(relativePath: ".venv"))
+    #expect(!matcher.isExcluded(relativePath: ".github"))
+  }
+
+  @Test("空行とコメント行は無視する")
+  func ignoresBlankAndComments() {
+    let matcher = ExcludePatternMatcher(patterns: ["", "  ", "# comment", "/"])
+    #expect(matcher.isEmpty)
+    #expect(!matcher.isExcluded(relativePath: "anything"))
+  }
+}
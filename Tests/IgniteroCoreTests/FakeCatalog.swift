@@ -0,0 +1,45 @@
+import Foundation
+
+@testable import IgniteroCore
+
+/// 性能テスト用の大量の検索対象を生成する。
+///
+/// 実際のカタログに近づけるため、アプリとディレクトリを半数ずつ、よくある単語を組み合わせた
+/// 名前で作る。同じ件数なら毎回同じ内容になる（乱数を使わない）。
+enum FakeCatalog {
+  private static let words = [
+    "code", "studio", "terminal", "project", "notes", "mail", "photo", "music", "docs", "api",
+    "server", "client", "design", "preview", "launcher", "backup", "monitor", "editor", "web",
+    "mobile", "data", "cloud", "script", "viewer",
+  ]
+
+  /// `count` 件（アプリ・ディレクトリの合計）の検索対象を生成する。
+  static func generate(
+    _ count: Int
+  ) -> (apps: [AppItem], directories: [DirectoryItem]) {
+    let appCount = count / 2
+    let apps = (0..<appCount).map { i in
+      AppItem(
+        name: "\(name(i)) \(i)", path: "/Applications/Fake\(i).app",
+        bundleIdentifier: "com.example.fake\(i)")
+    }
+    let directories = (0..<(count - appCount)).map { i in
+      DirectoryItem(
+        name: "\(name(i + 7))-\(i)", path: "/Users/test/dev/\(name(i + 7))-\(i)",
+        isGitRepo: i % 3 == 0)
+    }
+    return (apps, directories)
+  }
+
+  /// 生成した検索対象のインデックスを構築する。
+  static func index(_ count: Int) -> SearchIndex {
+    let catalog = generate(count)
+    return SearchIndex(apps: catalog.apps, directories: catalog.directories, commands: [])
+  }
+
+  private static func name(_ i: Int) -> String {
+    let first = words[i % words.count]
+    let second = words[(i / words.count) % words.count]
+    return "\(first)-\(second)"
+  }
+}
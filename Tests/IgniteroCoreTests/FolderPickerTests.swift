@@ -0,0 +1,20 @@
+import AppKit
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("FolderPicker")
+struct FolderPickerTests {
+
+  @Test("キャンセル済みの Task ではダイアログを表示せず nil を返す")
+  @MainActor
+  func cancelledTaskReturnsNil() async {
+    let task = Task { @MainActor in
+      await FolderPicker.chooseDirectory(message: "テスト")
+    }
+    // Task 本体が MainActor で実行される前にキャンセルする
+    task.cancel()
+    let url = await task.value
+    #expect(url == nil)
+  }
+}
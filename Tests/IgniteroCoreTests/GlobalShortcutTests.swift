@@ -8,6 +8,8 @@ import Testing
 @MainActor
 final class MockIMEController: IMEControlling, @unchecked Sendable {
   private(set) var switchToASCIICallCount = 0
+  private(set) var restoreInputSourceCallCount = 0
+  private(set) var discardSavedInputSourceCallCount = 0
 
   nonisolated func switchToASCII() {
     // In tests we call this from @MainActor context via GlobalShortcutManager,
@@ -16,6 +18,48 @@ final class MockIMEController: IMEControlling, @unchecked Sendable {
       switchToASCIICallCount += 1
     }
   }
+
+  nonisolated func restoreInputSource() {
+    MainActor.assumeIsolated {
+      restoreInputSourceCallCount += 1
+    }
+  }
+
+  nonisolated func discardSavedInputSource() {
+    MainActor.assumeIsolated {
+      discardSavedInputSourceCallCount += 1
+    }
+  }
+}
+
+// MARK: - Mock Modifier Tap Monitor
+
+@MainActor
+final class MockModifierTapMonitor: ModifierTapMonitoring {
+  var canStart = true
+  private(set) var monitoredModifiers: Set<HotkeyModifier> = []
+  private(set) var stopCallCount = 0
+  private var onDoubleTap: (@MainActor (HotkeyModifier) -> Void)?
+
+  func start(
+    modifiers: Set<HotkeyModifier>, onDoubleTap: @escaping @MainActor (HotkeyModifier) -> Void
+  ) -> Bool {
+    guard canStart else { return false }
+    monitoredModifiers = modifiers
+    self.onDoubleTap = onDoubleTap
+    return true
+  }
+
+  func stop() {
+    stopCallCount += 1
+    monitoredModifiers = []
+    onDoubleTap = nil
+  }
+
+  /// 修飾キーを 2 回押したことにする
+  func simulateDoubleTap(_ modifier: HotkeyModifier) {
+    onDoubleTap?(modifier)
+  }
 }
 
 // MARK: - KeyboardShortcuts.Name Tests
@@ -205,3 +249,127 @@ struct GlobalShortcutManagerToggleIMETests {
     #expect(imeController.switchToASCIICallCount == 2)
   }
 }
+
+// MARK: - GlobalShortcutManager Hotkey Binding Tests
+
+@Suite("GlobalShortcutManager Hotkey Bindings")
+struct GlobalShortcutManagerHotkeyBindingTests {
+
+  @Test func carbonShortcutConvertsValidBinding() {
+    let binding = HotkeyBinding(shortcut: "cmd+shift+p", mode: .directories)
+    let carbon = GlobalShortcutManager.carbonShortcut(for: binding)
+    let expected = KeyboardShortcuts.Shortcut(.p, modifiers: [.command, .shift])
+    #expect(carbon?.keyCode == UInt32(expected.carbonKeyCode))
+    #expect(carbon?.modifiers == UInt32(expected.carbonModifiers))
+  }
+
+  @Test func carbonShortcutRejectsInvalidBinding() {
+    let binding = HotkeyBinding(shortcut: "p", mode: .apps)
+    #expect(GlobalShortcutManager.carbonShortcut(for: binding) == nil)
+  }
+
+  @Test func carbonShortcutUsesPhysicalKeyCode() {
+    // 102 は JIS キーボードの英数キー（kVK_JIS_Eisu）
+    let binding = HotkeyBinding(shortcut: "ctrl+keycode:102", mode: .launcher)
+    let carbon = GlobalShortcutManager.carbonShortcut(for: binding)
+    #expect(carbon?.keyCode == 102)
+    let expected = KeyboardShortcuts.Shortcut(.space, modifiers: [.control])
+    #expect(carbon?.modifiers == UInt32(expected.carbonModifiers))
+  }
+
+  @MainActor
+  @Test func doubleTapBindingIsMonitoredAndFiresMode() {
+    let monitor = MockModifierTapMonitor()
+    let manager = GlobalShortcutManager(
+      windowManager: WindowManager(),
+      imeController: MockIMEController(),
+      debounceInterval: .zero,
+      modifierTapMonitor: monitor
+    )
+    var receivedModes: [LauncherMode] = []
+    manager.onModeHotkey = { mode, _ in receivedModes.append(mode) }
+    manager.updateBindings([
+      HotkeyBinding(shortcut: "cmd+shift+p", mode: .directories),
+      HotkeyBinding(shortcut: "double:cmd", mode: .apps),
+    ])
+
+    manager.setup()
+    defer { manager.teardown() }
+    #expect(monitor.monitoredModifiers == [.command])
+
+    monitor.simulateDoubleTap(.command)
+    #expect(receivedModes == [.apps])
+  }
+
+  @MainActor
+  @Test func doubleTapBindingFailsWithoutEventTap() {
+    let monitor = MockModifierTapMonitor()
+    monitor.canStart = false
+    let manager = GlobalShortcutManager(
+      windowManager: WindowManager(),
+      imeController: MockIMEController(),
+      debounceInterval: .zero,
+      modifierTapMonitor: monitor
+    )
+    let binding = HotkeyBinding(shortcut: "double:shift", mode: .launcher)
+    manager.updateBindings([binding])
+
+    manager.setup()
+    defer { manager.teardown() }
+    #expect(manager.failedBindings == [binding])
+  }
+
+  @MainActor
+  @Test func updateBindingsBeforeSetupStoresBindings() {
+    let manager = GlobalShortcutManager(
+      windowManager: WindowManager(),
+      imeController: MockIMEController(),
+      debounceInterval: .zero
+    )
+    let bindings = [HotkeyBinding(shortcut: "cmd+shift+p", mode: .directories)]
+
+    manager.updateBindings(bindings)
+
+    #expect(manager.bindings == bindings)
+  }
+
+  @MainActor
+  @Test func handleShortcutFollowsIMEBehavior() {
+    let windowManager = WindowManager()
+    let imeController = MockIMEController()
+    let manager = GlobalShortcutManager(
+      windowManager: windowManager,
+      imeController: imeController,
+      debounceInterval: .zero
+    )
+    manager.imeBehavior = .off
+
+    manager.handleShortcut()
+    #expect(windowManager.isLauncherVisible == true)
+    #expect(imeController.switchToASCIICallCount == 0)
+    #expect(manager.activeIMEBehavior == .off)
+
+    manager.handleShortcut()
+    // バインディングの指定は全体の設定より優先する
+    manager.handleShortcut(imeBehavior: .forceEnglish)
+    #expect(imeController.switchToASCIICallCount == 1)
+    #expect(manager.activeIMEBehavior == .forceEnglish)
+  }
+
+  @MainActor
+  @Test func unknownBindingIDIsIgnored() {
+    let windowManager = WindowManager()
+    let manager = GlobalShortcutManager(
+      windowManager: windowManager,
+      imeController: MockIMEController(),
+      debounceInterval: .zero
+    )
+    var receivedModes: [LauncherMode] = []
+    manager.onModeHotkey = { mode, _ in receivedModes.append(mode) }
+
+    manager.handleBindingHotKey(id: GlobalShortcutManager.bindingHotKeyIDBase + 42)
+
+    #expect(receivedModes.isEmpty)
+    #expect(windowManager.isLauncherVisible == false)
+  }
+}
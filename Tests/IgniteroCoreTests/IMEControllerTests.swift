@@ -26,6 +26,19 @@ struct IMEControllerTests {
     }
   }
 
+  @Test func restoreInputSourceWithoutSwitchDoesNothing() {
+    let controller = IMEController()
+    controller.restoreInputSource()
+  }
+
+  @Test func switchThenRestoreDoesNotCrash() {
+    let controller = IMEController()
+    controller.switchToASCII()
+    controller.switchToASCII()
+    controller.restoreInputSource()
+    controller.restoreInputSource()
+  }
+
   @Test func conformsToIMEControlling() {
     let controller: any IMEControlling = IMEController()
     controller.switchToASCII()
@@ -0,0 +1,159 @@
+import Foundation
+import IgniteroIPC
+import Testing
+
+@testable import IgniteroCore
+
+// MARK: - メッセージ
+
+@Suite("IPC Codec")
+struct IPCCodecTests {
+
+  @Test func requestRoundTripsAsSingleLine() throws {
+    let request = IPCRequest(command: .search, argument: "safari")
+    let data = try IPCCodec.encode(request)
+
+    #expect(data.last == UInt8(ascii: "\n"))
+    #expect(data.filter { $0 == UInt8(ascii: "\n") }.count == 1)
+    #expect(try IPCCodec.decode(IPCRequest.self, from: data) == request)
+  }
+
+  @Test func responseRoundTrips() throws {
+    let response = IPCResponse(
+      ok: true,
+      results: [
+        IPCSearchItem(name: "Safari", path: "/Applications/Safari.app", kind: "app", score: 1)
+      ]
+    )
+    let decoded = try IPCCodec.decode(IPCResponse.self, from: try IPCCodec.encode(response))
+    #expect(decoded == response)
+  }
+
+  @Test func rejectsTooLongSocketPath() {
+    let path = "/tmp/" + String(repeating: "a", count: 200) + ".sock"
+    #expect(throws: IPCError.socketPathTooLong(path)) {
+      _ = try IPCSocket.address(for: path)
+    }
+  }
+}
+
+// MARK: - サーバー・クライアント
+
+@Suite("IPC Server")
+@MainActor
+struct IPCServerTests {
+
+  /// sun_path の長さ制限に収まるよう短いパスを使う
+  private func makeSocketPath() -> String {
+    "/tmp/ignitero-test-\(UUID().uuidString.prefix(8)).sock"
+  }
+
+  @Test func clientReceivesHandlerResponse() async throws {
+    let path = makeSocketPath()
+    let server = IPCServer(socketPath: path) { request in
+      IPCResponse(
+        ok: true,
+        results: [
+          IPCSearchItem(name: request.argument ?? "", path: "/tmp", kind: "directory", score: 0)
+        ])
+    }
+    try server.start()
+    defer { server.stop() }
+
+    let response = try await Task.detached {
+      try IPCClient(socketPath: path).send(IPCRequest(command: .search, argument: "tmp"))
+    }.value
+
+    #expect(response.ok)
+    #expect(response.results?.first?.name == "tmp")
+  }
+
+  @Test func stopRemovesSocket() throws {
+    let path = makeSocketPath()
+    let server = IPCServer(socketPath: path) { _ in .success }
+    try server.start()
+    #expect(FileManager.default.fileExists(atPath: path))
+
+    server.stop()
+    #expect(!server.isRunning)
+    #expect(!FileManager.default.fileExists(atPath: path))
+  }
+
+  @Test("ソケットは作成時から所有者だけが読み書きできる")
+  func socketIsOwnerOnly() throws {
+    let path = makeSocketPath()
+    let server = IPCServer(socketPath: path) { _ in .success }
+    try server.start()
+    defer { server.stop() }
+
+    let attributes = try FileManager.default.attributesOfItem(atPath: path)
+    #expect((attributes[.posixPermissions] as? NSNumber)?.intValue == 0o600)
+  }
+
+  @Test("待ち受け中のソケットは削除せず、2 つ目のサーバーの開始を拒否する")
+  func refusesToReplaceLiveSocket() throws {
+    let path = makeSocketPath()
+    let first = IPCServer(socketPath: path) { _ in .success }
+    try first.start()
+    defer { first.stop() }
+
+    let second = IPCServer(socketPath: path) { _ in .success }
+    #expect(throws: IPCError.alreadyRunning(path)) {
+      try second.start()
+    }
+    #expect(!second.isRunning)
+    #expect(FileManager.default.fileExists(atPath: path))
+  }
+
+  @Test("前回の異常終了で残ったソケットファイルは置き換える")
+  func replacesStaleSocket() throws {
+    let path = makeSocketPath()
+    // bind したまま閉じ、誰も待ち受けていないソケットファイルを残す
+    var address = try IPCSocket.address(for: path)
+    let fd = socket(AF_UNIX, SOCK_STREAM, 0)
+    let bound = withUnsafePointer(to: &address) {
+      $0.withMemoryRebound(to: sockaddr.self, capacity: 1) {
+        bind(fd, $0, socklen_t(MemoryLayout<sockaddr_un>.size))
+      }
+    }
+    close(fd)
+    #expect(bound == 0)
+    #expect(FileManager.default.fileExists(atPath: path))
+
+    let server = IPCServer(socketPath: path) { _ in .success }
+    try server.start()
+    defer { server.stop() }
+    #expect(server.isRunning)
+  }
+
+  @Test("リクエストを送らない接続は制限時間で打ち切る")
+  func idleConnectionTimesOut() async throws {
+    let path = makeSocketPath()
+    let server = IPCServer(socketPath: path, ioTimeout: .milliseconds(200)) { _ in .success }
+    try server.start()
+    defer { server.stop() }
+
+    let start = ContinuousClock.now
+    let response = try await Task.detached {
+      var address = try IPCSocket.address(for: path)
+      let fd = socket(AF_UNIX, SOCK_STREAM, 0)
+      defer { close(fd) }
+      _ = withUnsafePointer(to: &address) {
+        $0.withMemoryRebound(to: sockaddr.self, capacity: 1) {
+          connect(fd, $0, socklen_t(MemoryLayout<sockaddr_un>.size))
+        }
+      }
+      return try IPCCodec.decode(IPCResponse.self, from: try IPCSocket.readLine(from: fd))
+    }.value
+
+    #expect(!response.ok)
+    #expect(ContinuousClock.now - start < .seconds(2))
+  }
+
+  @Test func clientReportsNotRunningWithoutServer() {
+    let path = makeSocketPath()
+    #expect(throws: IPCError.notRunning(path)) {
+      _ = try IPCClient(socketPath: path).send(IPCRequest(command: .show))
+    }
+  }
+}
@@ -1,3 +1,4 @@
+import AppKit
 import Foundation
 import Testing
 
@@ -122,4 +123,144 @@ struct IconCacheManagerTests {
     let pngMagic: [UInt8] = [0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A]
     #expect(data.prefix(pngMagic.count) == Data(pngMagic))
   }
+
+  @Test func cachedIconPathUsesSizeSuffixExceptLarge() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let manager = IconCacheManager(cacheDirectory: tmpDir)
+    let appPath = "/Applications/Safari.app"
+    let large = manager.cachedIconPath(for: appPath, size: .large)
+    let small = manager.cachedIconPath(for: appPath, size: .small)
+
+    // 128px は従来のファイル名を維持する
+    #expect(large == manager.cachedIconPath(for: appPath))
+    #expect(small == large.replacingOccurrences(of: ".png", with: "_32.png"))
+    #expect(manager.cachedIconPath(for: appPath, size: .medium).hasSuffix("_64.png"))
+  }
+
+  @Test func outputSizesAlwaysIncludeLarge() {
+    let manager = IconCacheManager(cacheDirectory: "/tmp", outputSizes: [.small])
+    #expect(manager.outputSizes == [.small, .large])
+  }
+
+  @Test("表示サイズと倍率から拡大せずに済む最小のサイズを選ぶ")
+  func iconSizeFittingDisplayScale() {
+    #expect(IconSize.fitting(pointSize: 32, scale: 1) == .small)
+    #expect(IconSize.fitting(pointSize: 32, scale: 2) == .medium)
+    #expect(IconSize.fitting(pointSize: 28, scale: 2) == .medium)
+    #expect(IconSize.fitting(pointSize: 48, scale: 2) == .large)
+    // 最大でも足りない場合・倍率が不正な場合
+    #expect(IconSize.fitting(pointSize: 128, scale: 2) == .large)
+    #expect(IconSize.fitting(pointSize: 32, scale: 0) == .small)
+  }
+
+  @Test("倍率に合うサイズの PNG があればそのパスを、なければ 128px のパスを返す")
+  func cachedIconPathForDisplayScale() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let manager = IconCacheManager(cacheDirectory: tmpDir)
+    let appPath = "/Applications/Scaled.app"
+    #expect(manager.cachedIconPath(for: appPath, pointSize: 32, scale: 2) == nil)
+
+    let large = manager.cachedIconPath(for: appPath)
+    try Data(count: 10).write(to: URL(fileURLWithPath: large))
+    #expect(manager.cachedIconPath(for: appPath, pointSize: 32, scale: 2) == large)
+
+    let medium = manager.cachedIconPath(for: appPath, size: .medium)
+    try Data(count: 10).write(to: URL(fileURLWithPath: medium))
+    #expect(manager.cachedIconPath(for: appPath, pointSize: 32, scale: 2) == medium)
+    #expect(IconCacheManager.iconPath(large, pointSize: 32, scale: 2) == medium)
+    #expect(IconCacheManager.iconPath(large, pointSize: 32, scale: 1) == large)
+    #expect(IconCacheManager.iconPath("/tmp/App.icns", pointSize: 32, scale: 2) == "/tmp/App.icns")
+  }
+
+  @Test("キャッシュ済み PNG のファイル数と合計サイズを返す")
+  func usageCountsPNGFiles() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let manager = IconCacheManager(cacheDirectory: tmpDir)
+    try Data(count: 100).write(to: URL(fileURLWithPath: manager.cachedIconPath(for: "/Applications/A.app")))
+    try Data(count: 50).write(to: URL(fileURLWithPath: manager.cachedIconPath(for: "/Applications/B.app")))
+    try Data(count: 10).write(to: URL(fileURLWithPath: tmpDir + "/note.txt"))
+
+    let usage = manager.usage()
+    #expect(usage.fileCount == 2)
+    #expect(usage.totalBytes == 150)
+  }
+
+  @Test("現在のアプリから参照されていないアイコンを全サイズ分削除する")
+  func collectGarbageRemovesUnreferencedIcons() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let manager = IconCacheManager(cacheDirectory: tmpDir)
+    for appPath in ["/Applications/Kept.app", "/Applications/Removed.app"] {
+      for size in IconSize.allCases {
+        try Data(count: 10).write(
+          to: URL(fileURLWithPath: manager.cachedIconPath(for: appPath, size: size)))
+      }
+    }
+
+    let result = manager.collectGarbage(keeping: ["/Applications/Kept.app"])
+    #expect(result == IconCacheCleanupResult(removedUnreferenced: 3, evicted: 0, freedBytes: 30))
+    #expect(manager.usage().fileCount == 3)
+    #expect(FileManager.default.fileExists(atPath: manager.cachedIconPath(for: "/Applications/Kept.app")))
+  }
+
+  @Test("上限サイズを超えると最終アクセスの古いアイコンから削除する")
+  func collectGarbageEvictsLeastRecentlyUsed() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let manager = IconCacheManager(cacheDirectory: tmpDir)
+    let apps = ["/Applications/Old.app", "/Applications/Middle.app", "/Applications/New.app"]
+    for (offset, appPath) in apps.enumerated() {
+      var url = URL(fileURLWithPath: manager.cachedIconPath(for: appPath))
+      try Data(count: 100).write(to: url)
+      var values = URLResourceValues()
+      values.contentAccessDate = Date(timeIntervalSince1970: 1_700_000_000 + Double(offset) * 60)
+      try url.setResourceValues(values)
+    }
+
+    let result = manager.collectGarbage(keeping: apps, maxBytes: 250)
+    #expect(result.removedUnreferenced == 0)
+    #expect(result.evicted == 1)
+    #expect(!FileManager.default.fileExists(atPath: manager.cachedIconPath(for: apps[0])))
+    #expect(FileManager.default.fileExists(atPath: manager.cachedIconPath(for: apps[2])))
+  }
+
+  @Test("removeAll でキャッシュ済みの PNG をすべて削除する")
+  func removeAllDeletesCachedIcons() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let manager = IconCacheManager(cacheDirectory: tmpDir)
+    try Data(count: 10).write(to: URL(fileURLWithPath: manager.cachedIconPath(for: "/Applications/A.app")))
+    try manager.removeAll()
+    #expect(manager.usage() == IconCacheUsage(fileCount: 0, totalBytes: 0))
+  }
+
+  @Test("各出力サイズの PNG を指定ピクセル数で生成する")
+  func cacheIconGeneratesAllSizes() throws {
+    let tmpDir = try makeTempDir()
+    defer { cleanup(tmpDir) }
+
+    let icnsPath = "/System/Applications/Utilities/Terminal.app/Contents/Resources/Terminal.icns"
+    guard FileManager.default.fileExists(atPath: icnsPath) else { return }
+
+    let manager = IconCacheManager(cacheDirectory: tmpDir)
+    let appPath = "/Applications/Sizes.app"
+    let result = try manager.cacheIcon(from: icnsPath, for: appPath)
+    #expect(result == manager.cachedIconPath(for: appPath))
+
+    for size in IconSize.allCases {
+      let path = manager.cachedIconPath(for: appPath, size: size)
+      let rep = try #require(NSBitmapImageRep(data: Data(contentsOf: URL(fileURLWithPath: path))))
+      #expect(rep.pixelsWide == size.rawValue)
+      #expect(rep.pixelsHigh == size.rawValue)
+    }
+  }
 }
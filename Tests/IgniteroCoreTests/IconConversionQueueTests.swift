@@ -0,0 +1,51 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("IconConversionQueue")
+struct IconConversionQueueTests {
+
+  private func makeTempDir() throws -> String {
+    let dir = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-icon-queue-test-\(UUID().uuidString)")
+    try FileManager.default.createDirectory(at: dir, withIntermediateDirectories: true)
+    return dir.path
+  }
+
+  @Test("同時実行数の上限内ですべてのアプリのアイコンを変換する")
+  func prefetchConvertsAllApps() async throws {
+    let tmpDir = try makeTempDir()
+    defer { try? FileManager.default.removeItem(atPath: tmpDir) }
+
+    let manager = IconCacheManager(cacheDirectory: tmpDir, outputSizes: [.large])
+    let queue = IconConversionQueue(iconCacheManager: manager, maxConcurrency: 2)
+    // 変換元がないアプリは Finder と同じ（汎用の）アイコンを変換する
+    let apps = (0..<6).map { AppItem(name: "App\($0)", path: "/Applications/Prefetch\($0).app") }
+
+    let iconPaths = await queue.prefetch(apps)
+
+    #expect(iconPaths.count == apps.count)
+    for app in apps {
+      #expect(iconPaths[app.path] == manager.cachedIconPath(for: app.path))
+      #expect(FileManager.default.fileExists(atPath: manager.cachedIconPath(for: app.path)))
+    }
+  }
+
+  @Test("同じアプリへの同時の要求は同じパスを返す")
+  func concurrentRequestsShareConversion() async throws {
+    let tmpDir = try makeTempDir()
+    defer { try? FileManager.default.removeItem(atPath: tmpDir) }
+
+    let manager = IconCacheManager(cacheDirectory: tmpDir, outputSizes: [.large])
+    let queue = IconConversionQueue(iconCacheManager: manager, maxConcurrency: 1)
+    let appPath = "/Applications/Shared.app"
+
+    async let first = queue.ensureIcon(appPath: appPath, sourcePath: nil)
+    async let second = queue.ensureIcon(appPath: appPath, sourcePath: nil)
+    let results = await [first, second]
+
+    #expect(results == [manager.cachedIconPath(for: appPath), manager.cachedIconPath(for: appPath)])
+    #expect(manager.usage().fileCount == 1)
+  }
+}
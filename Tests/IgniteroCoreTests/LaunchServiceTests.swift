@@ -9,7 +9,7 @@ import Testing
 struct EditorTypeTests {
 
   @Test func allCasesCount() {
-    #expect(EditorType.allCases.count == 5)
+    #expect(EditorType.allCases.count == 9)
   }
 
   @Test func allCasesContainsExpected() {
@@ -19,6 +19,10 @@ struct EditorTypeTests {
     #expect(cases.contains(.vscode))
     #expect(cases.contains(.antigravity))
     #expect(cases.contains(.zed))
+    #expect(cases.contains(.intellij))
+    #expect(cases.contains(.pycharm))
+    #expect(cases.contains(.webstorm))
+    #expect(cases.contains(.xcode))
   }
 
   @Test func rawValues() {
@@ -27,6 +31,9 @@ struct EditorTypeTests {
     #expect(EditorType.vscode.rawValue == "vscode")
     #expect(EditorType.antigravity.rawValue == "antigravity")
     #expect(EditorType.zed.rawValue == "zed")
+    #expect(EditorType.intellij.rawValue == "intellij")
+    #expect(EditorType.pycharm.rawValue == "pycharm")
+    #expect(EditorType.webstorm.rawValue == "webstorm")
   }
 
   @Test func codable() throws {
@@ -146,6 +153,10 @@ struct LaunchServiceAppNameTests {
     #expect(LaunchService.appName(for: .vscode) == "Visual Studio Code.app")
     #expect(LaunchService.appName(for: .antigravity) == "Antigravity.app")
     #expect(LaunchService.appName(for: .zed) == "Zed.app")
+    #expect(LaunchService.appName(for: .intellij) == "IntelliJ IDEA.app")
+    #expect(LaunchService.appName(for: .pycharm) == "PyCharm.app")
+    #expect(LaunchService.appName(for: .webstorm) == "WebStorm.app")
+    #expect(LaunchService.appName(for: .xcode) == "Xcode.app")
   }
 
   @Test func editorDisplayNames() {
@@ -154,6 +165,7 @@ struct LaunchServiceAppNameTests {
     #expect(LaunchService.displayName(for: .vscode) == "Visual Studio Code")
     #expect(LaunchService.displayName(for: .antigravity) == "Antigravity")
     #expect(LaunchService.displayName(for: .zed) == "Zed")
+    #expect(LaunchService.displayName(for: .intellij) == "IntelliJ IDEA")
   }
 
   @Test func terminalAppNames() {
@@ -706,11 +718,20 @@ struct LaunchServiceAppleScriptCoverageTests {
 struct LaunchServiceEditorPathTests {
 
   @Test func editorApplicationPath() {
-    for editor in EditorType.allCases {
+    for editor in EditorType.allCases where editor.jetBrainsConfigPrefixes.isEmpty {
       let path = LaunchService.applicationPath(for: editor)
       #expect(path == "/Applications/\(LaunchService.appName(for: editor))")
     }
   }
+
+  @Test("JetBrains の IDE は Community 版・~/Applications のアプリも候補にする")
+  func jetBrainsApplicationPathCandidates() {
+    let path = LaunchService.applicationPath(for: .intellij)
+    let names = ["IntelliJ IDEA.app"] + LaunchService.communityAppNames(for: .intellij)
+    #expect(names.contains((path as NSString).lastPathComponent))
+    #expect(LaunchService.communityAppNames(for: .pycharm) == ["PyCharm CE.app"])
+    #expect(LaunchService.communityAppNames(for: .cursor).isEmpty)
+  }
 }
 
 // MARK: - ターミナルパステスト
@@ -946,9 +967,17 @@ struct EditorTypeSupportsCodeWorkspaceTests {
     #expect(EditorType.zed.supportsCodeWorkspace == false)
   }
 
-  @Test("Zed 以外のエディタはすべて .code-workspace をサポートする")
+  @Test("JetBrains の IDE は .code-workspace をサポートしない")
+  func jetBrainsIDEsDoNotSupportCodeWorkspace() {
+    for editor in [EditorType.intellij, .pycharm, .webstorm] {
+      #expect(editor.supportsCodeWorkspace == false)
+    }
+  }
+
+  @Test("Zed・JetBrains の IDE・Xcode 以外のエディタはすべて .code-workspace をサポートする")
   func allEditorsExceptZedSupportCodeWorkspace() {
-    for editor in EditorType.allCases where editor != .zed {
+    for editor in EditorType.allCases
+    where editor != .zed && editor != .xcode && editor.jetBrainsConfigPrefixes.isEmpty {
       #expect(
         editor.supportsCodeWorkspace == true,
         "\(editor.rawValue) は supportsCodeWorkspace == true であるべき"
@@ -957,6 +986,41 @@ struct EditorTypeSupportsCodeWorkspaceTests {
   }
 }
 
+// MARK: - ProjectFileDetector テスト
+
+@Suite("ProjectFileDetector")
+struct ProjectFileDetectorTests {
+
+  @Test("拡張子の優先順に検出し、同じ拡張子は名前順で先頭を選ぶ")
+  func detectsByExtensionPriority() {
+    let entries = ["Pods", "B.xcodeproj", "A.xcodeproj", "App.xcworkspace", ".hidden.xcworkspace"]
+    #expect(ProjectFileDetector.xcode.detect(in: entries) == "App.xcworkspace")
+    #expect(ProjectFileDetector.xcode.detect(in: ["B.xcodeproj", "A.xcodeproj"]) == "A.xcodeproj")
+    #expect(ProjectFileDetector.xcode.detect(in: ["Package.swift"]) == nil)
+    #expect(ProjectFileDetector.codeWorkspace.detect(in: ["mono.code-workspace"]) != nil)
+  }
+
+  @Test("エディタごとに開くプロジェクトファイルの検出器を割り当てる")
+  func editorsUseMatchingDetector() {
+    #expect(EditorType.cursor.projectFileDetector == .codeWorkspace)
+    #expect(EditorType.xcode.projectFileDetector == .xcode)
+    #expect(EditorType.zed.projectFileDetector == nil)
+  }
+
+  @Test("ディレクトリを読み、検出したファイルのパスを返す")
+  func detectsInDirectory() throws {
+    let dir = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-detector-\(UUID().uuidString)").path
+    defer { try? FileManager.default.removeItem(atPath: dir) }
+    try FileManager.default.createDirectory(
+      atPath: (dir as NSString).appendingPathComponent("App.xcodeproj"),
+      withIntermediateDirectories: true)
+
+    #expect(ProjectFileDetector.xcode.detect(inDirectory: dir) == "\(dir)/App.xcodeproj")
+    #expect(ProjectFileDetector.xcode.detect(inDirectory: "/nonexistent/dir") == nil)
+  }
+}
+
 // MARK: - CustomCommand テスト
 
 @Suite("CustomCommand")
@@ -1227,3 +1291,41 @@ struct LaunchServiceAppleScriptPropertiesTests {
     }
   }
 }
+
+// MARK: - URL Validation
+
+@Suite("LaunchService URL Validation")
+struct LaunchServiceURLValidationTests {
+
+  private func validated(_ text: String, canOpen: Bool = false) -> String? {
+    LaunchService.validatedURL(from: text, canOpen: { _ in canOpen })?.absoluteString
+  }
+
+  @Test("https と mailto はそのまま受け付ける")
+  func acceptsWellKnownSchemes() {
+    #expect(validated("https://example.com/path?q=1") == "https://example.com/path?q=1")
+    #expect(validated("  mailto:dev@example.com ") == "mailto:dev@example.com")
+  }
+
+  @Test("www とホスト:ポート形式はスキームを補う")
+  func addsSchemeForBareHosts() {
+    #expect(validated("www.example.com") == "https://www.example.com")
+    #expect(validated("localhost:3000/api") == "http://localhost:3000/api")
+  }
+
+  @Test("カスタムスキームは開けるアプリがある場合のみ受け付ける")
+  func customSchemeRequiresHandler() {
+    #expect(validated("slack://open", canOpen: true) == "slack://open")
+    #expect(validated("slack://open", canOpen: false) == nil)
+  }
+
+  @Test("URL でない入力や危険なスキームは受け付けない")
+  func rejectsNonURLs() {
+    #expect(validated("safari") == nil)
+    #expect(validated("g swift") == nil)
+    #expect(validated("https://") == nil)
+    #expect(validated("mailto:") == nil)
+    #expect(validated("file:///etc/hosts", canOpen: true) == nil)
+    #expect(validated("javascript:alert(1)", canOpen: true) == nil)
+  }
+}
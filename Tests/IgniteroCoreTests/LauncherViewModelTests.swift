@@ -73,6 +73,30 @@ struct LauncherViewModelSearchTests {
     #expect(vm.searchResults[0].name == "Safari")
   }
 
+  @MainActor
+  @Test func toggleCategoryFilterAddsAndRemovesFilter() {
+    let vm = LauncherViewModel()
+    vm.apps = [
+      AppItem(
+        name: "Code Editor", path: "/Applications/Code Editor.app",
+        category: "public.app-category.developer-tools"),
+      AppItem(name: "Code Notes", path: "/Applications/Code Notes.app"),
+    ]
+    vm.searchQuery = "code"
+    vm.updateSearch()
+    #expect(vm.searchResults.count == 2)
+
+    vm.toggleCategoryFilter("developer-tools")
+    #expect(vm.searchQuery == "code category:developer-tools")
+    #expect(vm.isFilteringCategory("developer-tools"))
+    #expect(vm.searchResults.map(\.name) == ["Code Editor"])
+
+    vm.toggleCategoryFilter("developer-tools")
+    #expect(vm.searchQuery == "code")
+    #expect(!vm.isFilteringCategory("developer-tools"))
+    #expect(vm.searchResults.count == 2)
+  }
+
   @MainActor
   @Test func searchQueryResetsSelectedIndex() {
     let vm = LauncherViewModel()
@@ -114,6 +138,46 @@ struct LauncherViewModelSearchTests {
   }
 }
 
+// MARK: - LauncherViewModel データソースの更新
+
+@Suite("LauncherViewModel Data Source Updates")
+struct LauncherViewModelDataSourceUpdateTests {
+
+  @MainActor
+  @Test("まとめて更新すると更新後のすべてのデータソースがインデックスに反映される")
+  func batchUpdateRebuildsIndex() {
+    let vm = LauncherViewModel()
+    vm.updateDataSources {
+      vm.apps = [AppItem(name: "Safari", path: "/Applications/Safari.app")]
+      vm.hiddenPaths = ["/Applications/Safari.app"]
+      vm.commands = [CustomCommand(alias: "deploy", command: "npm run deploy")]
+    }
+
+    #expect(vm.searchIndex.apps.isEmpty)
+    #expect(vm.searchIndex.commands.map(\.alias) == ["deploy"])
+  }
+
+  @MainActor
+  @Test("バックグラウンドで構築したインデックスに差し替えて検索できる")
+  func backgroundUpdateSwapsIndex() async {
+    let vm = LauncherViewModel()
+    vm.apps = [AppItem(name: "Finder", path: "/System/Applications/Finder.app")]
+
+    await vm.updateDataSourcesInBackground {
+      vm.apps = [AppItem(name: "Safari", path: "/Applications/Safari.app")]
+      vm.directories = [
+        DirectoryItem(name: "my-project", path: "/Users/test/my-project", editor: "cursor")
+      ]
+    }
+
+    #expect(vm.searchIndex.apps.map(\.name) == ["Safari"])
+    #expect(vm.searchIndex.directories.map(\.name) == ["my-project"])
+    vm.searchQuery = "safari"
+    vm.updateSearch()
+    #expect(vm.searchResults.first?.name == "Safari")
+  }
+}
+
 // MARK: - LauncherViewModel 特殊アクション
 
 @Suite("LauncherViewModel Special Actions")
@@ -130,6 +194,41 @@ struct LauncherViewModelSpecialActionTests {
     #expect(vm.searchResults.first?.path == "https://www.google.com/search?q=hello")
   }
 
+  @MainActor
+  @Test("URL を入力すると URL を開くアクションが先頭に挿入される")
+  func openURLActionInserted() {
+    let vm = LauncherViewModel()
+    vm.searchQuery = "https://github.com/owayo"
+    vm.updateSearch()
+    #expect(vm.searchResults.first?.kind == .webSearch)
+    #expect(vm.searchResults.first?.path == "https://github.com/owayo")
+  }
+
+  @MainActor
+  @Test("クイックリンク名と検索語を入力すると置き換えた URL を開くアクションが先頭に挿入される")
+  func quickLinkActionInserted() {
+    let vm = LauncherViewModel()
+    vm.quickLinks = [
+      QuickLink(name: "jira", url: "https://jira.example.com/browse/{query}", icon: "ticket")
+    ]
+    vm.searchQuery = "jira PROJ-123"
+    vm.updateSearch()
+    #expect(vm.searchResults.first?.kind == .webSearch)
+    #expect(vm.searchResults.first?.name == "jira で「PROJ-123」を開く")
+    #expect(vm.searchResults.first?.path == "https://jira.example.com/browse/PROJ-123")
+    #expect(vm.searchResults.first?.symbolName == "ticket")
+  }
+
+  @MainActor
+  @Test("クイックリンクは名前でも検索できる")
+  func quickLinkSearchableByName() {
+    let vm = LauncherViewModel()
+    vm.quickLinks = [QuickLink(name: "dashboard", url: "https://grafana.example.com")]
+    vm.searchQuery = "dashboard"
+    vm.updateSearch()
+    #expect(vm.searchResults.contains { $0.path == "https://grafana.example.com" })
+  }
+
   @MainActor
   @Test("x プレフィックスで X 検索アクションが先頭に挿入される")
   func xSearchActionInserted() {
@@ -170,6 +269,38 @@ struct LauncherViewModelSpecialActionTests {
     }
   }
 
+  @MainActor
+  @Test("見出しごとにまとめる場合、先頭に挿入したアクションは検索結果のアクションと同じ見出しにする")
+  func insertedActionsShareSectionWithSearchedActions() {
+    let vm = LauncherViewModel()
+    vm.resultSettings = ResultSettings(groupResults: true)
+    vm.updateDataSources {
+      vm.apps = [AppItem(name: "Color Meter", path: "/Applications/Color Meter.app")]
+      vm.systemSettingsPanes = [SystemSettingsPane(id: "com.apple.Colors", name: "Colors")]
+    }
+    vm.searchQuery = "color"
+    vm.updateSearch()
+
+    #expect(vm.searchResults.map(\.kind) == [.colorPicker, .systemSettings, .app])
+    #expect(vm.sectionHeaders == [0: .actions, 2: .apps])
+  }
+
+  @MainActor
+  @Test("見出しごとにまとめる場合、挿入したアクションがなければ設定した見出しの順に並べる")
+  func sectionsFollowConfiguredOrder() {
+    let vm = LauncherViewModel()
+    vm.resultSettings = ResultSettings(groupResults: true)
+    vm.updateDataSources {
+      vm.apps = [AppItem(name: "Notes", path: "/Applications/Notes.app")]
+      vm.systemSettingsPanes = [SystemSettingsPane(id: "com.apple.Notifications", name: "Notes")]
+    }
+    vm.searchQuery = "notes"
+    vm.updateSearch()
+
+    #expect(vm.searchResults.map(\.kind) == [.app, .systemSettings])
+    #expect(vm.sectionHeaders == [0: .apps, 1: .actions])
+  }
+
   @MainActor
   @Test("emoji および emoji プレフィックスで Emoji ピッカーアクションが挿入される")
   func emojiPickerActionInserted() {
@@ -405,6 +536,52 @@ struct LauncherViewModelConfirmSelectionTests {
   }
 }
 
+// MARK: - LauncherViewModel 複数選択
+
+@Suite("LauncherViewModel Marked Results")
+struct LauncherViewModelMarkedResultsTests {
+
+  @MainActor
+  @Test("複数選択は検索し直しても保持され、選択した順に並ぶ")
+  func toggleMarkKeepsMarksAcrossSearches() {
+    let vm = LauncherViewModel()
+    vm.directories = [
+      DirectoryItem(name: "alpha", path: "/Users/test/alpha"),
+      DirectoryItem(name: "beta", path: "/Users/test/beta"),
+    ]
+    vm.searchQuery = "beta"
+    vm.updateSearch()
+    #expect(vm.toggleMark())
+    vm.searchQuery = "alpha"
+    vm.updateSearch()
+    #expect(vm.toggleMark())
+
+    #expect(vm.markedResults.map(\.path) == ["/Users/test/beta", "/Users/test/alpha"])
+    #expect(vm.handleSpecialKey(.enter, modifiers: []) == .executeMarked)
+
+    // 同じ結果をもう一度選ぶと解除される
+    #expect(vm.toggleMark())
+    #expect(vm.markedResults.map(\.path) == ["/Users/test/beta"])
+  }
+
+  @MainActor
+  @Test("Web検索などの一過性アクションは複数選択できず、クリアで複数選択も消える")
+  func toggleMarkIgnoresTransientActionsAndClears() {
+    let vm = LauncherViewModel()
+    vm.apps = [AppItem(name: "Safari", path: "/Applications/Safari.app")]
+    vm.searchQuery = "g safari"
+    vm.updateSearch()
+    #expect(vm.confirmSelection()?.kind == .webSearch)
+    #expect(!vm.toggleMark())
+
+    vm.searchQuery = "safari"
+    vm.updateSearch()
+    vm.toggleMark()
+    vm.clearSearch()
+    #expect(vm.markedResults.isEmpty)
+  }
+}
+
 // MARK: - LauncherViewModel 特殊キー処理
 
 @Suite("LauncherViewModel Special Key Handling")
@@ -496,6 +673,43 @@ struct LauncherViewModelSpecialKeyTests {
     #expect(action == .showTerminalPicker)
   }
 
+  @MainActor
+  @Test func optionRightArrowOnDirectoryReturnsRunCommandHere() {
+    let vm = LauncherViewModel()
+    vm.directories = [
+      DirectoryItem(name: "my-project", path: "/Users/test/my-project")
+    ]
+    vm.searchQuery = "project"
+    vm.updateSearch()
+    #expect(vm.handleSpecialKey(.right, modifiers: .option) == .runCommandHere)
+  }
+
+  @MainActor
+  @Test func runCommandHereSearchesCommandsAndTypedCommand() {
+    let vm = LauncherViewModel()
+    vm.directories = [DirectoryItem(name: "git-tools", path: "/Users/test/git-tools")]
+    vm.commands = [CustomCommand(alias: "git status", command: "git status")]
+    vm.beginRunCommand(in: "/Users/test/my-project")
+
+    vm.searchQuery = "git"
+    vm.updateSearch()
+    #expect(vm.searchResults.allSatisfy { $0.kind == .command })
+    #expect(vm.searchResults.first?.command == "git status")
+    // 入力したコマンドそのものを最後に加える
+    #expect(vm.searchResults.last?.command == "git")
+    #expect(vm.searchResults.last?.path.isEmpty == true)
+
+    // 登録したコマンドと同じ入力は重複させない
+    vm.searchQuery = "git status"
+    vm.updateSearch()
+    #expect(vm.searchResults.filter { $0.command == "git status" }.count == 1)
+
+    #expect(vm.endRunCommand())
+    #expect(vm.commandTargetDirectory == nil)
+    #expect(vm.scopeOverride == nil)
+    #expect(!vm.endRunCommand())
+  }
+
   @MainActor
   @Test func rightArrowOnNonDirectoryReturnsNil() {
     let vm = LauncherViewModel()
@@ -844,6 +1058,62 @@ struct LauncherViewModelConfirmEdgeCaseTests {
   }
 }
 
+@Suite("LauncherViewModel Trash")
+struct LauncherViewModelTrashTests {
+
+  @MainActor
+  @Test func clearSearchCancelsTrashConfirmation() {
+    let vm = LauncherViewModel()
+    vm.requestTrashConfirmation(
+      for: SearchResult(directoryItem: DirectoryItem(name: "a", path: "/dev/a"), score: 0))
+    #expect(vm.pendingTrash?.path == "/dev/a")
+
+    vm.clearSearch()
+    #expect(vm.pendingTrash == nil)
+  }
+
+  @MainActor
+  @Test func expiredUndoDoesNotHideNewerItem() {
+    let vm = LauncherViewModel()
+    let first = TrashedItem(name: "a", originalPath: "/dev/a", trashedPath: "/t/a")
+    let second = TrashedItem(name: "b", originalPath: "/dev/b", trashedPath: "/t/b")
+    vm.showTrashUndo(first)
+    vm.showTrashUndo(second)
+
+    vm.dismissTrashUndo(first)
+    #expect(vm.trashUndo == second)
+    vm.dismissTrashUndo()
+    #expect(vm.trashUndo == nil)
+  }
+}
+
+// MARK: - LauncherViewModel アイコンの変換要求
+
+@Suite("LauncherViewModel Icons")
+struct LauncherViewModelIconTests {
+
+  @MainActor
+  @Test func requestsConversionOnceForUnconvertedApps() {
+    let vm = LauncherViewModel()
+    let unconverted = AppItem(
+      name: "Lazy", path: "/Applications/Lazy.app", iconSourcePath: "/icons/Lazy.icns")
+    let withoutSource = AppItem(name: "Plain", path: "/Applications/Plain.app")
+    vm.apps = [unconverted, withoutSource]
+    var requested: [String] = []
+    vm.onEnsureIcon = { requested.append($0) }
+
+    let result = SearchResult(appItem: unconverted, score: 0)
+    vm.requestIcon(for: result)
+    vm.requestIcon(for: result)
+    vm.requestIcon(for: SearchResult(appItem: withoutSource, score: 0))
+    #expect(requested == ["/Applications/Lazy.app"])
+
+    // 変換後は変換済みのパスで表示する
+    vm.convertedIconPaths["/Applications/Lazy.app"] = "/icons/lazy.png"
+    #expect(vm.iconPath(for: result) == "/icons/lazy.png")
+  }
+}
+
 // MARK: - LauncherViewModel コマンド結果のキー操作
 
 @Suite("LauncherViewModel Command Key Handling")
@@ -969,3 +1239,49 @@ struct LauncherViewModelCalculatorExpressionEdgeCaseTests {
     #expect(vm.calculatorResult == "1")
   }
 }
+
+// MARK: - LauncherViewModel 検索の予約
+
+@Suite("LauncherViewModel Scheduled Search")
+struct LauncherViewModelScheduledSearchTests {
+
+  @MainActor
+  @Test("予約した検索は完了後に結果へ反映される")
+  func scheduledSearchUpdatesResults() async throws {
+    let vm = LauncherViewModel(searchSession: SearchSession(debounce: .milliseconds(10)))
+    vm.apps = [AppItem(name: "Safari", path: "/Applications/Safari.app")]
+    vm.searchQuery = "safari"
+    vm.scheduleSearch()
+
+    for _ in 0..<100 where vm.searchResults.isEmpty {
+      try await Task.sleep(for: .milliseconds(10))
+    }
+    #expect(vm.searchResults.first?.name == "Safari")
+  }
+
+  @MainActor
+  @Test("確定操作は予約中の検索を即座に実行してから行う")
+  func confirmSelectionFlushesPendingSearch() {
+    let vm = LauncherViewModel(searchSession: SearchSession(debounce: .seconds(10)))
+    vm.apps = [AppItem(name: "Safari", path: "/Applications/Safari.app")]
+    vm.searchQuery = "safari"
+    vm.scheduleSearch()
+    #expect(vm.searchResults.isEmpty)
+
+    let selected = vm.confirmSelection()
+    #expect(selected?.name == "Safari")
+  }
+
+  @MainActor
+  @Test("clearSearch は予約中の検索を破棄する")
+  func clearSearchCancelsPendingSearch() async throws {
+    let vm = LauncherViewModel(searchSession: SearchSession(debounce: .milliseconds(10)))
+    vm.apps = [AppItem(name: "Safari", path: "/Applications/Safari.app")]
+    vm.searchQuery = "safari"
+    vm.scheduleSearch()
+    vm.clearSearch()
+
+    try await Task.sleep(for: .milliseconds(100))
+    #expect(vm.searchResults.isEmpty)
+  }
+}
@@ -0,0 +1,56 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("LoginItemService LaunchAgent Fallback")
+struct LoginItemServiceTests {
+
+  private func makeService() -> (LoginItemService, URL) {
+    let dir = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-login-item-\(UUID().uuidString)")
+    let service = LoginItemService(
+      launchAgentsDirectory: dir,
+      executablePath: "/Applications/IgniteroLauncher.app/Contents/MacOS/IgniteroLauncher",
+      preferLaunchAgent: true
+    )
+    return (service, dir)
+  }
+
+  @Test func enableWritesLaunchAgentPlist() throws {
+    let (service, dir) = makeService()
+    defer { try? FileManager.default.removeItem(at: dir) }
+
+    try service.setEnabled(true)
+
+    #expect(service.isEnabled())
+    let data = try Data(contentsOf: service.launchAgentURL)
+    let plist = try #require(
+      try PropertyListSerialization.propertyList(from: data, format: nil) as? [String: Any])
+    #expect(plist["Label"] as? String == service.label)
+    #expect(plist["RunAtLoad"] as? Bool == true)
+    #expect(
+      plist["ProgramArguments"] as? [String] == [
+        "/Applications/IgniteroLauncher.app/Contents/MacOS/IgniteroLauncher"
+      ])
+  }
+
+  @Test func disableRemovesLaunchAgentPlist() throws {
+    let (service, dir) = makeService()
+    defer { try? FileManager.default.removeItem(at: dir) }
+
+    try service.setEnabled(true)
+    try service.setEnabled(false)
+
+    #expect(!service.isEnabled())
+    #expect(!FileManager.default.fileExists(atPath: service.launchAgentURL.path))
+  }
+
+  @Test func disableWithoutPlistDoesNotThrow() throws {
+    let (service, dir) = makeService()
+    defer { try? FileManager.default.removeItem(at: dir) }
+
+    try service.setEnabled(false)
+    #expect(!service.isEnabled())
+  }
+}
@@ -163,6 +163,18 @@ struct MenuBarActionsOpenSettingsTests {
     #expect(actions.isSettingsOpen == true)
   }
 
+  @MainActor
+  @Test func openSettingsInvokesOnOpenSettings() {
+    let actions = MenuBarActions(
+      windowManager: WindowManager(),
+      settingsManager: SettingsManager(configDirectory: makeTempConfigDir())
+    )
+    var openCount = 0
+    actions.onOpenSettings = { openCount += 1 }
+    actions.openSettings()
+    #expect(openCount == 1)
+  }
+
   @MainActor
   @Test func openSettingsIdempotent() {
     let actions = MenuBarActions(
@@ -252,11 +264,78 @@ struct MenuBarActionsMenuItemsTests {
     )
 
     let items = actions.menuItems
-    #expect(items.count == 4)
+    #expect(items.count == 8)
     #expect(items[0].title == "ウィンドウを表示")
-    #expect(items[1].title == "キャッシュを再構築")
-    #expect(items[2].title == "設定")
-    #expect(items[3].title == "終了")
+    #expect(items[1].title == "最近使った項目")
+    #expect(items[2].id == "cache-status")
+    #expect(items[3].title == "キャッシュを再構築")
+    #expect(items[4].title == "キャッシュをリセット...")
+    #expect(items[5].title == "インデックス作成を一時停止")
+    #expect(items[6].title == "設定")
+    #expect(items[7].title == "終了")
+  }
+
+  @MainActor
+  @Test func menuItemsFollowLanguageSetting() throws {
+    let settings = SettingsManager(configDirectory: makeTempConfigDir())
+    settings.settings.language = .en
+    let actions = MenuBarActions(windowManager: WindowManager(), settingsManager: settings)
+
+    let items = actions.menuItems
+    #expect(items.map(\.title) == [
+      "Show Window", "Recent Items", "Cache: Unknown", "Rebuild Cache", "Reset Cache...",
+      "Pause Indexing", "Settings", "Quit",
+    ])
+    #expect(MenuBarIconState.refreshing.toolTip(in: .en) == "Ignitero Launcher - Updating cache...")
+  }
+
+  @MainActor
+  @Test func recentItemsSubmenuListsProvidedItems() {
+    let actions = MenuBarActions(
+      windowManager: WindowManager(),
+      settingsManager: SettingsManager(configDirectory: makeTempConfigDir())
+    )
+    #expect(actions.menuItems[1].isEnabled == false)
+
+    let safari = SearchResult(
+      appItem: AppItem(name: "Safari", path: "/Applications/Safari.app"), score: 0)
+    var requestedLimit: Int?
+    var opened: [String] = []
+    actions.recentItemsProvider = { limit in
+      requestedLimit = limit
+      return [safari]
+    }
+    actions.onOpenRecentItem = { opened.append($0.path) }
+
+    let recent = actions.menuItems[1]
+    #expect(requestedLimit == MenuBarActions.recentItemsLimit)
+    #expect(recent.isEnabled == true)
+    #expect(recent.submenu?.map(\.title) == ["Safari"])
+    recent.submenu?.first?.action()
+    #expect(opened == ["/Applications/Safari.app"])
+  }
+
+  @MainActor
+  @Test func pauseIndexingItemTogglesCacheBootstrap() throws {
+    let settings = SettingsManager(configDirectory: makeTempConfigDir())
+    let actions = MenuBarActions(windowManager: WindowManager(), settingsManager: settings)
+    let bootstrap = CacheBootstrap(
+      settingsManager: settings,
+      cacheDatabase: try CacheDatabase(inMemory: true),
+      appScanner: AppScanner(),
+      directoryScanner: DirectoryScanner()
+    )
+    actions.cacheBootstrap = bootstrap
+    #expect(actions.menuItems[2].title == "キャッシュ: 起動後は未更新")
+
+    actions.menuItems[5].action()
+    #expect(bootstrap.isIndexingPaused)
+    #expect(actions.menuItems[5].isChecked)
+    #expect(actions.menuItems[2].title == "キャッシュ: 起動後は未更新（一時停止中）")
+
+    actions.menuItems[5].action()
+    #expect(!bootstrap.isIndexingPaused)
+    bootstrap.stopAutoUpdate()
   }
 
   @MainActor
@@ -269,7 +348,51 @@ struct MenuBarActionsMenuItemsTests {
     // rebuildCache 完了後はリビルド中でない
     await actions.rebuildCache()
     let items = actions.menuItems
-    #expect(items[1].title == "キャッシュを再構築")
+    #expect(items[3].title == "キャッシュを再構築")
+  }
+
+  @MainActor
+  @Test func resetCacheDelegatesAndMarksRebuilding() async {
+    let actions = MenuBarActions(
+      windowManager: WindowManager(),
+      settingsManager: SettingsManager(configDirectory: makeTempConfigDir())
+    )
+    let observed = ObservedFlag()
+    actions.onResetCache = { @MainActor [actions] in
+      observed.value = actions.isRebuildingCache
+    }
+
+    await actions.resetCache()
+
+    #expect(observed.value)
+    #expect(!actions.isRebuildingCache)
+  }
+
+  @MainActor
+  @Test func resetCacheItemRequiresConfirmation() async {
+    let settings = SettingsManager(configDirectory: makeTempConfigDir())
+    settings.settings.language = .en
+    let actions = MenuBarActions(windowManager: WindowManager(), settingsManager: settings)
+    let resets = ObservedCount()
+    actions.onResetCache = { @MainActor in resets.value += 1 }
+    var confirmedLanguages: [AppLanguage] = []
+    var accepts = false
+    actions.confirmResetCache = { language in
+      confirmedLanguages.append(language)
+      return accepts
+    }
+
+    actions.menuItems[4].action()
+    await Task.yield()
+    #expect(confirmedLanguages == [.en])
+    #expect(resets.value == 0)
+
+    accepts = true
+    actions.menuItems[4].action()
+    for _ in 0..<100 where resets.value == 0 {
+      await Task.yield()
+    }
+    #expect(resets.value == 1)
   }
 }
 
@@ -292,3 +415,7 @@ private final class CallCounter {
 private final class ObservedFlag {
   var value: Bool = false
 }
+
+private final class ObservedCount {
+  var value = 0
+}
@@ -182,3 +182,375 @@ struct EditorTypeDisplayNameTests {
     #expect(EditorType.antigravity.supportsCodeWorkspace == true)
   }
 }
+
+// MARK: - HotkeyBinding
+
+@Suite("HotkeyBinding Model")
+struct HotkeyBindingTests {
+
+  @Test("修飾キーとキーを解析できる")
+  func parseValidShortcut() {
+    let parsed = HotkeyBinding.parse("Cmd+Shift+P")
+    #expect(parsed?.key == "p")
+    #expect(parsed?.modifiers == [.command, .shift])
+  }
+
+  @Test("表記揺れ（alt / ctrl / enter）を吸収する")
+  func parseAliases() {
+    let parsed = HotkeyBinding.parse("alt + ctrl + enter")
+    #expect(parsed?.key == "return")
+    #expect(parsed?.modifiers == [.option, .control])
+  }
+
+  @Test("修飾キーなし・複数キー・未対応キーは無効")
+  func parseInvalidShortcuts() {
+    #expect(HotkeyBinding.parse("p") == nil)
+    #expect(HotkeyBinding.parse("cmd+p+q") == nil)
+    #expect(HotkeyBinding.parse("cmd+home") == nil)
+    #expect(HotkeyBinding.parse("cmd+shift") == nil)
+    #expect(HotkeyBinding.parse("") == nil)
+  }
+
+  @Test("キーコードと修飾キーの 2 回押しを解析できる")
+  func parseKeyCodeAndDoubleTap() {
+    let parsed = HotkeyBinding.parse("cmd+keycode:49")
+    #expect(parsed?.key == "keycode:49")
+    #expect(parsed?.modifiers == [.command])
+    #expect(HotkeyBinding.parse("cmd+keycode:128") == nil)
+    #expect(HotkeyBinding.parse("keycode:49") == nil)
+
+    #expect(HotkeyBinding.parseDoubleTap("Double:Cmd") == .command)
+    #expect(HotkeyBinding.parseDoubleTap("double: alt") == .option)
+    #expect(HotkeyBinding.parseDoubleTap("double:p") == nil)
+    #expect(HotkeyBinding.parse("double:cmd") == nil)
+    #expect(HotkeyBinding.isValidShortcut("double:ctrl"))
+    #expect(!HotkeyBinding.isValidShortcut("double:"))
+  }
+
+  @Test("JSON ラウンドトリップと enabled の既定値")
+  func codableRoundTrip() throws {
+    let binding = HotkeyBinding(shortcut: "cmd+shift+e", mode: .colorPicker)
+    let data = try JSONEncoder().encode(binding)
+    let decoded = try JSONDecoder().decode(HotkeyBinding.self, from: data)
+    #expect(decoded == binding)
+
+    let legacy = #"{"shortcut":"cmd+shift+d","mode":"directories"}"#.data(using: .utf8)!
+    let decodedLegacy = try JSONDecoder().decode(HotkeyBinding.self, from: legacy)
+    #expect(decodedLegacy.enabled == true)
+    #expect(decodedLegacy.mode == .directories)
+    #expect(decodedLegacy.imeBehavior == nil)
+
+    let withIME = #"{"shortcut":"cmd+j","mode":"launcher","ime_behavior":"off"}"#
+    let decodedIME = try JSONDecoder().decode(HotkeyBinding.self, from: Data(withIME.utf8))
+    #expect(decodedIME.imeBehavior == .off)
+  }
+
+  @Test("検索対象を絞り込むモードのみ searchScope を持つ")
+  func modeSearchScope() {
+    #expect(LauncherMode.directories.searchScope == .directories)
+    #expect(LauncherMode.apps.searchScope == .apps)
+    #expect(LauncherMode.commands.searchScope == .commands)
+    #expect(LauncherMode.launcher.searchScope == nil)
+    #expect(LauncherMode.emoji.searchScope == nil)
+  }
+}
+
+@Suite("QuickLink Model")
+struct QuickLinkModelTests {
+
+  private let mdn = QuickLink(
+    name: "mdn", url: "https://developer.mozilla.org/search?q={query}", icon: "book")
+
+  @Test("{query} を URL エンコードした検索語に置き換える")
+  func resolvedURLEncodesQuery() {
+    #expect(
+      mdn.resolvedURL(query: "fetch api")?.absoluteString
+        == "https://developer.mozilla.org/search?q=fetch%20api")
+    #expect(
+      mdn.resolvedURL(query: "a&b=c")?.absoluteString
+        == "https://developer.mozilla.org/search?q=a%26b%3Dc")
+  }
+
+  @Test("名前と検索語に分けて一致するクイックリンクを返す")
+  func matchSplitsKeywordAndArgument() throws {
+    let jira = QuickLink(name: "jira", url: "https://jira.example.com/browse/{query}")
+    let match = try #require(QuickLink.match("JIRA PROJ-123", in: [mdn, jira]))
+    #expect(match.link.id == jira.id)
+    #expect(match.argument == "PROJ-123")
+    #expect(QuickLink.match("jira ", in: [jira]) == nil)
+    #expect(QuickLink.match("jira", in: [jira]) == nil)
+  }
+
+  @Test("プレースホルダーのないクイックリンクは検索語付きで一致しない")
+  func matchRequiresPlaceholder() {
+    let home = QuickLink(name: "home", url: "https://example.com")
+    #expect(!home.acceptsQuery)
+    #expect(QuickLink.match("home foo", in: [home]) == nil)
+  }
+
+  @Test("名前が空・空白を含む場合や URL にスキームがない場合は無効")
+  func validation() {
+    #expect(mdn.isValid)
+    #expect(!QuickLink(name: "", url: "https://example.com").isValid)
+    #expect(!QuickLink(name: "my link", url: "https://example.com").isValid)
+    #expect(!QuickLink(name: "ex", url: "example.com/{query}").isValid)
+  }
+
+  @Test("JSON ラウンドトリップと id の既定値")
+  func codableRoundTrip() throws {
+    let data = try JSONEncoder().encode(mdn)
+    #expect(try JSONDecoder().decode(QuickLink.self, from: data) == mdn)
+
+    let legacy = #"{"name":"gh","url":"https://github.com/{query}"}"#.data(using: .utf8)!
+    let decoded = try JSONDecoder().decode(QuickLink.self, from: legacy)
+    #expect(decoded.name == "gh")
+    #expect(decoded.icon == nil)
+  }
+}
+
+// MARK: - FileOpener Tests
+
+@Suite("FileOpener Model")
+struct FileOpenerModelTests {
+
+  private let markdown = FileOpener(fileExtension: ".MD", application: "Typora")
+  private let gzip = FileOpener(fileExtension: "gz", application: "The Unarchiver")
+  private let tarball = FileOpener(fileExtension: "tar.gz", application: "Archive Utility")
+
+  @Test("拡張子は大文字小文字を区別せず、先頭の . を省略して照合する")
+  func matchesExtensionCaseInsensitively() {
+    #expect(markdown.normalizedExtension == "md")
+    #expect(FileOpener.opener(forPath: "/tmp/README.md", in: [markdown]) == markdown)
+    #expect(FileOpener.opener(forPath: "/tmp/notes.Md", in: [markdown]) == markdown)
+    #expect(FileOpener.opener(forPath: "/tmp/md", in: [markdown]) == nil)
+    #expect(FileOpener.opener(forPath: "/tmp/notes.mdx", in: [markdown]) == nil)
+  }
+
+  @Test("複数に一致する場合は長い拡張子を優先する")
+  func prefersLongestExtension() {
+    let openers = [gzip, tarball]
+    #expect(FileOpener.opener(forPath: "/tmp/src.tar.gz", in: openers) == tarball)
+    #expect(FileOpener.opener(forPath: "/tmp/log.gz", in: openers) == gzip)
+  }
+
+  @Test("拡張子やアプリが空、または拡張子に空白を含む場合は無効")
+  func validation() {
+    #expect(markdown.isValid)
+    #expect(!FileOpener(fileExtension: ".", application: "Typora").isValid)
+    #expect(!FileOpener(fileExtension: "m d", application: "Typora").isValid)
+    #expect(!FileOpener(fileExtension: "md", application: " ").isValid)
+    let invalid = FileOpener(fileExtension: "", application: "Typora")
+    #expect(FileOpener.opener(forPath: "/tmp/a.", in: [invalid]) == nil)
+  }
+
+  @Test("JSON は extension キーで保存し、id を省略できる")
+  func codableRoundTrip() throws {
+    let data = try JSONEncoder().encode(markdown)
+    #expect(try JSONDecoder().decode(FileOpener.self, from: data) == markdown)
+
+    let legacy = #"{"extension":"sketch","application":"Sketch"}"#.data(using: .utf8)!
+    let decoded = try JSONDecoder().decode(FileOpener.self, from: legacy)
+    #expect(decoded.fileExtension == "sketch")
+    #expect(decoded.application == "Sketch")
+  }
+}
+
+// MARK: - LauncherError Tests
+
+@Suite("LauncherError Model")
+struct LauncherErrorTests {
+
+  @Test("起動エラーを分類し、エラーコードを付ける")
+  func classifiesLaunchErrors() {
+    let editor = LauncherError(LaunchError.editorNotFound(.vscode))
+    #expect(editor == .notFound(EditorType.vscode.displayName))
+    #expect(editor.code == "not_found")
+
+    let script = LauncherError(LaunchError.scriptExecutionFailed("syntax error"))
+    #expect(script.code == "launch_failed")
+  }
+
+  @Test("Apple Events が許可されていない場合は権限エラーとして扱う")
+  func automationDeniedIsPermissionError() {
+    let error = LauncherError(
+      LaunchError.scriptExecutionFailed("execution error: Not authorized (-1743)"))
+    #expect(error.code == "permission_denied")
+    #expect(error.recoverySuggestion != nil)
+  }
+
+  @Test("プロセス・ファイルのエラーを権限と未検出に分類する")
+  func classifiesSystemErrors() {
+    #expect(LauncherError(ProcessControlError.permissionDenied(pid: 10)) == .permissionDenied("PID 10"))
+    #expect(LauncherError(ProcessControlError.notFound(pid: 10)) == .notFound("PID 10"))
+    #expect(LauncherError(FileSystemError.directoryNotFound("/dev/x")) == .notFound("/dev/x"))
+    #expect(
+      LauncherError(CocoaError(.fileReadNoSuchFile, userInfo: [NSFilePathErrorKey: "/tmp/a"]))
+        == .notFound("/tmp/a"))
+    #expect(LauncherError(POSIXError(.EACCES)).code == "permission_denied")
+    #expect(LauncherError(PasteError.accessibilityNotTrusted) == .permissionDenied("アクセシビリティ"))
+    #expect(
+      LauncherError(WindowLayoutError.accessibilityNotTrusted) == .permissionDenied("アクセシビリティ"))
+    #expect(LauncherError(WindowLayoutError.noFocusedWindow) == .notFound("操作するウィンドウ"))
+  }
+
+  @Test("表示言語に合わせたメッセージと対処方法を返す")
+  func localizesMessages() {
+    let error = LauncherError.notFound("Zed")
+    #expect(error.message(in: .ja) == "見つかりません: Zed")
+    #expect(error.message(in: .en) == "Not found: Zed")
+    #expect(error.errorDescription == error.message(in: .ja))
+    #expect(error.recoverySuggestion(in: .en).hasPrefix("Check that it is installed"))
+  }
+
+  @Test("LauncherError はそのまま、分類できないエラーは io として扱う")
+  func passesThroughAndFallsBackToIO() {
+    #expect(LauncherError(LauncherError.cache("locked")) == .cache("locked"))
+    #expect(LauncherError(CancellationError()).code == "io_error")
+  }
+}
+
+// MARK: - AppLanguage テスト
+
+@Suite("AppLanguage Model")
+struct AppLanguageTests {
+
+  @Test("auto はシステムの優先言語が日本語なら ja、それ以外は en に解決する")
+  func resolvesAutoFromPreferredLanguages() {
+    #expect(AppLanguage.auto.resolved(preferredLanguages: ["ja-JP", "en-US"]) == .ja)
+    #expect(AppLanguage.auto.resolved(preferredLanguages: ["fr-FR", "ja-JP"]) == .en)
+    #expect(AppLanguage.auto.resolved(preferredLanguages: []) == .en)
+    #expect(AppLanguage.ja.resolved(preferredLanguages: ["en-US"]) == .ja)
+  }
+
+  @Test("言語ごとの文字列を返す")
+  func returnsLocalizedText() {
+    #expect(AppLanguage.ja.text(.settingsWindowTitle) == "設定 - Ignitero")
+    #expect(AppLanguage.en.text(.settingsWindowTitle) == "Settings - Ignitero")
+    #expect(
+      AppLanguage.en.text(.cacheStatus(lastUpdated: nil, paused: true))
+        == "Cache: Not updated since launch (Paused)")
+    #expect(AppLanguage.ja.text(.profile("work")) == "プロファイル: work")
+  }
+}
+
+// MARK: - DeepLink テスト
+
+@Suite("DeepLink Model")
+struct DeepLinkTests {
+
+  @Test("search・open・show を解釈する")
+  func parsesSupportedLinks() {
+    #expect(DeepLink(url: URL(string: "ignitero://search?q=foo%20bar")!) == .search("foo bar"))
+    #expect(
+      DeepLink(url: URL(string: "ignitero://open?path=/Applications/Safari.app")!)
+        == .open("/Applications/Safari.app"))
+    #expect(DeepLink(url: URL(string: "ignitero://show")!) == .show)
+    #expect(DeepLink(url: URL(string: "ignitero://search")!) == .search(""))
+  }
+
+  @Test("open のチルダはホームディレクトリに展開する")
+  func expandsTildeInOpenPath() {
+    let home = FileManager.default.homeDirectoryForCurrentUser.path
+    #expect(DeepLink(url: URL(string: "ignitero://open?path=~/src")!) == .open("\(home)/src"))
+  }
+
+  @Test("未知の操作・他のスキーム・パスのない open は解釈しない")
+  func rejectsUnsupportedLinks() {
+    #expect(DeepLink(url: URL(string: "ignitero://delete?path=/tmp")!) == nil)
+    #expect(DeepLink(url: URL(string: "https://search?q=foo")!) == nil)
+    #expect(DeepLink(url: URL(string: "ignitero://open")!) == nil)
+  }
+}
+
+@Suite("AppBundleQuery")
+struct AppBundleQueryTests {
+
+  @Test("検索語から bundle: と id: の絞り込みを取り出す")
+  func extractsBundleFilters() {
+    #expect(
+      AppBundleQuery.parse("bundle:com.apple code")
+        == AppBundleQuery(bundleIdentifiers: ["com.apple"], term: "code"))
+    #expect(
+      AppBundleQuery.parse("id:com.google.chrome")
+        == AppBundleQuery(bundleIdentifiers: ["com.google.chrome"], term: ""))
+    #expect(AppBundleQuery.parse("bundle: safari") == nil)
+    #expect(AppBundleQuery.parse("safari") == nil)
+  }
+
+  @Test("バンドル ID に大文字小文字を区別せず前方一致する")
+  func matchesBundleIdentifierPrefix() {
+    let xcode = AppItem(
+      name: "Xcode", path: "/Applications/Xcode.app", bundleIdentifier: "com.apple.dt.Xcode")
+    let unknown = AppItem(name: "Unknown", path: "/Applications/Unknown.app")
+
+    #expect(AppBundleQuery(bundleIdentifiers: ["com.apple.dt.xcode"], term: "").matches(xcode))
+    #expect(!AppBundleQuery(bundleIdentifiers: ["com.google"], term: "").matches(xcode))
+    #expect(!AppBundleQuery(bundleIdentifiers: ["com"], term: "").matches(unknown))
+  }
+}
+
+@Suite("AppCategoryQuery")
+struct AppCategoryQueryTests {
+
+  @Test("検索語から category: と cat: の絞り込みを取り出す")
+  func extractsCategoryFilters() {
+    #expect(
+      AppCategoryQuery.parse("category:developer code")
+        == AppCategoryQuery(categories: ["developer"], term: "code"))
+    #expect(
+      AppCategoryQuery.parse("cat:games")
+        == AppCategoryQuery(categories: ["games"], term: ""))
+    #expect(AppCategoryQuery.parse("category: xcode") == nil)
+    #expect(AppCategoryQuery.parse("xcode") == nil)
+  }
+
+  @Test("カテゴリ名全体か - で区切った語に前方一致する")
+  func matchesCategoryWords() {
+    let chess = AppItem(
+      name: "Chess", path: "/System/Applications/Chess.app",
+      category: "public.app-category.board-games")
+    let unknown = AppItem(name: "Unknown", path: "/Applications/Unknown.app")
+
+    #expect(AppCategoryQuery(categories: ["board"], term: "").matches(chess))
+    #expect(AppCategoryQuery(categories: ["game"], term: "").matches(chess))
+    #expect(AppCategoryQuery(categories: ["board-games"], term: "").matches(chess))
+    #expect(!AppCategoryQuery(categories: ["utilities"], term: "").matches(chess))
+    #expect(!AppCategoryQuery(categories: ["games"], term: "").matches(unknown))
+  }
+}
+
+@Suite("FinderTag Model")
+struct FinderTagModelTests {
+
+  @Test("_kMDItemUserTags の名前と色番号を解析する")
+  func parsesUserTags() throws {
+    let data = try PropertyListSerialization.data(
+      fromPropertyList: ["レッド\n6", "urgent", "Work\n4", ""], format: .binary, options: 0)
+
+    #expect(
+      FinderTag.parse(userTags: data) == [
+        FinderTag(name: "レッド", color: .red), FinderTag(name: "urgent"),
+        FinderTag(name: "Work", color: .blue),
+      ])
+    #expect(FinderTag.parse(userTags: Data("broken".utf8)).isEmpty)
+  }
+
+  @Test("名前の前方一致か色の名前で一致する")
+  func matchesByNameOrColor() {
+    let tag = FinderTag(name: "Urgent", color: .red)
+    #expect(tag.matches("urg"))
+    #expect(tag.matches("red"))
+    #expect(tag.matches("レッド"))
+    #expect(!tag.matches("blue"))
+  }
+
+  @Test("検索語から # と tag: の絞り込みを取り出す")
+  func extractsTagFilters() {
+    #expect(
+      FinderTagQuery.parse("#red api tag:urgent")
+        == FinderTagQuery(tags: ["red", "urgent"], term: "api"))
+    #expect(FinderTagQuery.parse("＃work") == FinderTagQuery(tags: ["work"], term: ""))
+    #expect(FinderTagQuery.parse("c# notes") == nil)
+    #expect(FinderTagQuery.parse("# tag:") == nil)
+  }
+}
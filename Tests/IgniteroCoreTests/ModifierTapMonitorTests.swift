@@ -0,0 +1,58 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("ModifierDoubleTapDetector")
+struct ModifierDoubleTapDetectorTests {
+  private let start = ContinuousClock.now
+
+  private func at(_ milliseconds: Int) -> ContinuousClock.Instant {
+    start + .milliseconds(milliseconds)
+  }
+
+  @Test("修飾キーを単独で 2 回続けて押すと成立する")
+  func doubleTapFires() {
+    var detector = ModifierDoubleTapDetector()
+    #expect(detector.modifiersChanged([.command], at: at(0)) == nil)
+    #expect(detector.modifiersChanged([], at: at(80)) == nil)
+    #expect(detector.modifiersChanged([.command], at: at(200)) == .command)
+    // 3 回目は新しい 1 回目として数える
+    #expect(detector.modifiersChanged([], at: at(260)) == nil)
+    #expect(detector.modifiersChanged([.command], at: at(300)) == nil)
+  }
+
+  @Test("間隔が空いた・別の修飾キー・同時押し・他のキーを挟んだ場合は成立しない")
+  func interruptedTapsDoNotFire() {
+    var slow = ModifierDoubleTapDetector()
+    _ = slow.modifiersChanged([.shift], at: at(0))
+    _ = slow.modifiersChanged([], at: at(50))
+    #expect(slow.modifiersChanged([.shift], at: at(600)) == nil)
+
+    var different = ModifierDoubleTapDetector()
+    _ = different.modifiersChanged([.command], at: at(0))
+    _ = different.modifiersChanged([], at: at(50))
+    #expect(different.modifiersChanged([.option], at: at(100)) == nil)
+
+    var chord = ModifierDoubleTapDetector()
+    _ = chord.modifiersChanged([.command], at: at(0))
+    _ = chord.modifiersChanged([.command, .shift], at: at(30))
+    _ = chord.modifiersChanged([], at: at(60))
+    #expect(chord.modifiersChanged([.command], at: at(100)) == nil)
+
+    // ⌘C のように他のキーと組み合わせた場合
+    var combo = ModifierDoubleTapDetector()
+    _ = combo.modifiersChanged([.command], at: at(0))
+    combo.keyPressed()
+    _ = combo.modifiersChanged([], at: at(50))
+    #expect(combo.modifiersChanged([.command], at: at(100)) == nil)
+  }
+
+  @Test("長押ししてから離した場合は 1 回目と数えない")
+  func longPressIsNotATap() {
+    var detector = ModifierDoubleTapDetector()
+    _ = detector.modifiersChanged([.control], at: at(0))
+    _ = detector.modifiersChanged([], at: at(800))
+    #expect(detector.modifiersChanged([.control], at: at(900)) == nil)
+  }
+}
@@ -0,0 +1,32 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+// MARK: - PasteService テスト
+
+@Suite("PasteService")
+struct PasteServiceTests {
+
+  @Test("アプリ・ディレクトリはパス、コマンドはコマンド文字列を貼り付ける")
+  func textForPathsAndCommands() {
+    let app = SearchResult(
+      appItem: AppItem(name: "Safari", path: "/Applications/Safari.app"), score: 0)
+    let directory = SearchResult(
+      directoryItem: DirectoryItem(name: "api", path: "/Users/test/api"), score: 0)
+    let command = SearchResult(
+      customCommand: CustomCommand(alias: "build", command: "make build"), score: 0)
+
+    #expect(PasteService.text(for: app) == "/Applications/Safari.app")
+    #expect(PasteService.text(for: directory) == "/Users/test/api")
+    #expect(PasteService.text(for: command) == "make build")
+  }
+
+  @Test("Emoji ピッカー・カラーピッカーなど貼り付けるテキストがない結果は nil")
+  func textIsNilForPickers() {
+    #expect(PasteService.text(for: SearchResult(name: "Emoji", kind: .emoji, score: 0)) == nil)
+    #expect(
+      PasteService.text(for: SearchResult(name: "カラーピッカー", kind: .colorPicker, score: 0)) == nil)
+    #expect(PasteService.text(for: SearchResult(name: "URL", kind: .webSearch, score: 0)) == nil)
+  }
+}
@@ -0,0 +1,81 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+/// テスト用の固定プロセス一覧
+private struct StubProcesses: ProcessControlling {
+  let processes: [RunningProcess]
+
+  func runningProcesses() -> [RunningProcess] { processes }
+
+  func terminate(pid: Int32, force: Bool) throws {}
+}
+
+@Suite("ProcessManager")
+struct ProcessManagerTests {
+
+  @Test("自身が起動した子プロセスを一覧に含め、SIGTERM で終了させる")
+  func listsAndTerminatesChildProcess() throws {
+    let child = Process()
+    child.executableURL = URL(fileURLWithPath: "/bin/sleep")
+    child.arguments = ["30"]
+    try child.run()
+    defer { if child.isRunning { child.terminate() } }
+
+    let manager = ProcessManager()
+    let listed = manager.runningProcesses().first { $0.pid == child.processIdentifier }
+    #expect(listed?.name == "sleep")
+    #expect(listed?.path == "/bin/sleep")
+    // 初回の取得では CPU 使用率を算出できない
+    #expect(listed?.cpuUsage == nil)
+    #expect(manager.runningProcesses().contains { $0.cpuUsage != nil })
+
+    try manager.terminate(pid: child.processIdentifier, force: false)
+    child.waitUntilExit()
+    #expect(child.terminationReason == .uncaughtSignal)
+    #expect(child.terminationStatus == SIGTERM)
+  }
+
+  @Test("自身と launchd は終了させない")
+  func refusesProtectedProcesses() {
+    let manager = ProcessManager()
+    #expect(throws: ProcessControlError.protectedProcess(pid: 1)) {
+      try manager.terminate(pid: 1, force: true)
+    }
+    let own = ProcessInfo.processInfo.processIdentifier
+    #expect(throws: ProcessControlError.protectedProcess(pid: own)) {
+      try manager.terminate(pid: own, force: false)
+    }
+    #expect(!manager.runningProcesses().contains { $0.pid == own })
+  }
+}
+
+@Suite("SearchService Processes")
+struct SearchServiceProcessTests {
+
+  private let service = SearchService(
+    processes: StubProcesses(processes: [
+      RunningProcess(pid: 10, name: "node", cpuUsage: 0.5, memoryBytes: 300_000_000),
+      RunningProcess(pid: 11, name: "Safari", cpuUsage: 20.0, memoryBytes: 100_000_000),
+      RunningProcess(pid: 12, name: "Xcode", memoryBytes: 900_000_000),
+    ]))
+
+  @Test("kill プレフィックスで名前に一致するプロセスのみを返す")
+  func killPrefixFiltersByName() {
+    let index = SearchIndex(
+      apps: [AppItem(name: "Safari", path: "/Applications/Safari.app")], directories: [],
+      commands: [])
+    let results = service.search(query: "kill safari", index: index, history: [])
+    #expect(results.map(\.kind) == [.process])
+    #expect(results.first?.process?.pid == 11)
+    #expect(SearchQueryParser.parse("kill node") == ParsedSearchQuery(scope: .processes, term: "node"))
+  }
+
+  @Test("検索語が空の場合は CPU 使用率、メモリ使用量の順に並べる")
+  func emptyTermSortsByUsage() {
+    let results = service.search(
+      query: "", index: .empty, history: [], defaultScope: .processes)
+    #expect(results.compactMap(\.process?.pid) == [11, 10, 12])
+  }
+}
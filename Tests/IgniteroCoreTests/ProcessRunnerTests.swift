@@ -0,0 +1,44 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("ProcessRunner")
+struct ProcessRunnerTests {
+
+  @Test("標準出力・標準エラー出力と終了コードを返す")
+  func returnsOutputAndStatus() async throws {
+    let output = try await ProcessRunner.run(
+      "/bin/sh", arguments: ["-c", "echo out; echo ' err ' >&2; exit 3"])
+
+    #expect(output.status == 3)
+    #expect(String(decoding: output.standardOutput, as: UTF8.self) == "out\n")
+    #expect(output.errorMessage == "err")
+  }
+
+  @Test("パイプのバッファを超える出力でも終了まで待てる")
+  func drainsLargeOutput() async throws {
+    let output = try await ProcessRunner.run(
+      "/bin/sh", arguments: ["-c", "head -c 300000 /dev/zero; head -c 300000 /dev/zero >&2"])
+
+    #expect(output.status == 0)
+    #expect(output.standardOutput.count == 300_000)
+    #expect(output.standardError.count == 300_000)
+  }
+
+  @Test("起動できない場合はエラーを投げる")
+  func throwsWhenExecutableIsMissing() async {
+    await #expect(throws: (any Error).self) {
+      try await ProcessRunner.run("/nonexistent/ignitero-command", arguments: [])
+    }
+  }
+
+  @Test("上限を過ぎたコマンドは終了させる")
+  func terminatesAfterTimeout() async throws {
+    let start = Date()
+    let output = try await ProcessRunner.run("/bin/sleep", arguments: ["10"], timeout: 0.2)
+
+    #expect(output.status != 0)
+    #expect(Date().timeIntervalSince(start) < 5)
+  }
+}
@@ -0,0 +1,46 @@
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("ProjectTypeRegistry")
+struct ProjectTypeRegistryTests {
+
+  @Test("目印のファイルからプロジェクトの種類を判定する")
+  func detectsProjectTypeFromMarkerFiles() {
+    let registry = ProjectTypeRegistry.standard
+    #expect(registry.detect(in: ["Cargo.toml", "src"]) == .rust)
+    #expect(registry.detect(in: ["package.json", "node_modules"]) == .node)
+    #expect(registry.detect(in: ["App.xcworkspace"]) == .xcode)
+    #expect(registry.detect(in: ["Package.swift", "Sources"]) == .swiftPackage)
+    #expect(registry.detect(in: ["go.mod"]) == .go)
+    #expect(registry.detect(in: ["pyproject.toml"]) == .python)
+    #expect(registry.detect(in: ["build.gradle.kts"]) == .jvm)
+    #expect(registry.detect(in: ["Gemfile"]) == .ruby)
+    #expect(registry.detect(in: ["README.md", "docs"]) == nil)
+  }
+
+  @Test("複数の目印がある場合は先に登録した検出器を優先する")
+  func earlierDetectorsTakePrecedence() {
+    let registry = ProjectTypeRegistry.standard
+    #expect(registry.detect(in: ["Package.swift", "App.xcodeproj"]) == .xcode)
+    #expect(registry.detect(in: ["package.json", "Cargo.toml"]) == .rust)
+    #expect(registry.detect(in: ["package.json", "Gemfile"]) == .ruby)
+  }
+
+  @Test("独自の検出器を登録できる")
+  func customDetectors() {
+    let registry = ProjectTypeRegistry(detectors: [
+      ProjectTypeDetector(type: .node, fileNames: ["deno.json"])
+    ])
+    #expect(registry.detect(in: ["deno.json"]) == .node)
+    #expect(registry.detect(in: ["Cargo.toml"]) == nil)
+  }
+
+  @Test("プロジェクトの種類に適したエディタ")
+  func preferredEditors() {
+    #expect(ProjectType.swiftPackage.preferredEditor == .xcode)
+    #expect(ProjectType.python.preferredEditor == .pycharm)
+    #expect(ProjectType.jvm.preferredEditor == .intellij)
+    #expect(ProjectType.rust.preferredEditor == nil)
+  }
+}
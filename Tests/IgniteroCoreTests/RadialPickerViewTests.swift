@@ -198,12 +198,12 @@ struct RadialPickerGeometryEdgeCaseTests {
 @Suite("RadialPickerItemFactory Editor Items")
 struct RadialPickerItemFactoryEditorTests {
 
-  @Test func editorItemsProduces5Items() {
+  @Test func editorItemsProducesItemPerEditor() {
     let editors: [EditorInfo] = EditorType.allCases.map { type in
       EditorInfo(id: type, name: type.rawValue, appName: "\(type.rawValue).app", installed: true)
     }
     let items = RadialPickerItemFactory.editorItems(from: editors)
-    #expect(items.count == 5)
+    #expect(items.count == 9)
   }
 
   @Test func editorItemsHaveCorrectShortcutKeys() {
@@ -221,6 +221,10 @@ struct RadialPickerItemFactoryEditorTests {
     #expect(items[2].shortcutKey == "v")
     #expect(items[3].shortcutKey == "a")
     #expect(items[4].shortcutKey == "z")
+    #expect(
+      RadialPickerItemFactory.editorItems(from: [
+        EditorInfo(id: .webstorm, name: "WebStorm", appName: "WebStorm.app", installed: true)
+      ])[0].shortcutKey == "s")
   }
 
   @Test func editorItemsPreserveInstalledState() {
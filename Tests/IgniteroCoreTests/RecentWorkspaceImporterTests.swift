@@ -0,0 +1,201 @@
+import Foundation
+import GRDB
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("RecentWorkspaceImporter")
+struct RecentWorkspaceImporterTests {
+
+  private func makeTempDir() throws -> String {
+    let dir = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-recent-workspaces-\(UUID().uuidString)")
+    try FileManager.default.createDirectory(at: dir, withIntermediateDirectories: true)
+    return dir.resolvingSymlinksInPath().path
+  }
+
+  /// `<Application Support>/<folder>/User/globalStorage` を作成して返す。
+  private func makeGlobalStorage(in support: String, folder: String) throws -> String {
+    let path = (support as NSString).appendingPathComponent("\(folder)/User/globalStorage")
+    try FileManager.default.createDirectory(atPath: path, withIntermediateDirectories: true)
+    return path
+  }
+
+  private func recentlyOpenedJSON(_ entries: [[String: Any]]) throws -> String {
+    let data = try JSONSerialization.data(withJSONObject: ["entries": entries])
+    return String(decoding: data, as: UTF8.self)
+  }
+
+  @Test("フォルダとワークスペースのフォルダを返し、ファイルとリモートは除く")
+  func extractsFolderPaths() {
+    let json: [String: Any] = [
+      "entries": [
+        ["folderUri": "file:///Users/dev/my%20app"],
+        ["workspace": ["id": "1", "configPath": "file:///Users/dev/mono/mono.code-workspace"]],
+        ["fileUri": "file:///Users/dev/notes.md"],
+        ["folderUri": "vscode-remote://ssh-remote%2Bprod/srv/app"],
+      ]
+    ]
+
+    #expect(
+      RecentWorkspaceImporter.folderPaths(fromRecentlyOpened: json) == [
+        "/Users/dev/my app", "/Users/dev/mono",
+      ])
+  }
+
+  @Test("state.vscdb から存在するフォルダだけを取り込み、エディタを設定する")
+  func importsFromStateDatabase() async throws {
+    let support = try makeTempDir()
+    defer { try? FileManager.default.removeItem(atPath: support) }
+    let project = (support as NSString).appendingPathComponent("project")
+    try FileManager.default.createDirectory(
+      atPath: (project as NSString).appendingPathComponent(".git"),
+      withIntermediateDirectories: true)
+    let storage = try makeGlobalStorage(in: support, folder: "Cursor")
+    let queue = try DatabaseQueue(path: (storage as NSString).appendingPathComponent("state.vscdb"))
+    let value = try recentlyOpenedJSON([
+      ["folderUri": URL(fileURLWithPath: project).absoluteString],
+      ["folderUri": "file:///nonexistent/project"],
+    ])
+    try await queue.write { db in
+      try db.execute(sql: "CREATE TABLE ItemTable (key TEXT UNIQUE, value BLOB)")
+      try db.execute(
+        sql: "INSERT INTO ItemTable (key, value) VALUES (?, ?)",
+        arguments: [RecentWorkspaceImporter.recentlyOpenedKey, value])
+    }
+    try queue.close()
+
+    let importer = RecentWorkspaceImporter(applicationSupportDirectory: support)
+    let items = await importer.recentWorkspaces(for: [.cursor])
+
+    #expect(items.map(\.path) == [project])
+    #expect(items.first?.editor == EditorType.cursor.rawValue)
+    #expect(items.first?.isGitRepo == true)
+  }
+
+  @Test("state.vscdb がない場合は storage.json を読み、エディタ間の重複は除く")
+  func fallsBackToStorageJSON() async throws {
+    let support = try makeTempDir()
+    defer { try? FileManager.default.removeItem(atPath: support) }
+    let project = (support as NSString).appendingPathComponent("shared")
+    try FileManager.default.createDirectory(atPath: project, withIntermediateDirectories: true)
+    let entries: [[String: Any]] = [["folderUri": URL(fileURLWithPath: project).absoluteString]]
+    for folder in ["Code", "Windsurf"] {
+      let storage = try makeGlobalStorage(in: support, folder: folder)
+      let data = try JSONSerialization.data(
+        withJSONObject: ["openedPathsList": ["entries": entries]])
+      try data.write(
+        to: URL(fileURLWithPath: (storage as NSString).appendingPathComponent("storage.json")))
+    }
+
+    let importer = RecentWorkspaceImporter(applicationSupportDirectory: support)
+    let items = await importer.recentWorkspaces(for: [.windsurf, .vscode, .zed])
+
+    #expect(items.map(\.path) == [project])
+    #expect(items.first?.editor == EditorType.windsurf.rawValue)
+  }
+
+  @Test("JetBrains の IDE は最新の設定フォルダの recentProjects.xml を読む")
+  func importsFromJetBrainsRecentProjects() async throws {
+    let root = try makeTempDir()
+    defer { try? FileManager.default.removeItem(atPath: root) }
+    let project = (root as NSString).appendingPathComponent("api")
+    try FileManager.default.createDirectory(atPath: project, withIntermediateDirectories: true)
+    for (folder, path) in [("PyCharm2024.3", "/nonexistent/old"), ("PyCharmCE2025.1", project)] {
+      let options = (root as NSString).appendingPathComponent("\(folder)/options")
+      try FileManager.default.createDirectory(atPath: options, withIntermediateDirectories: true)
+      let xml = """
+        <application><component name="RecentProjectsManager"><option name="additionalInfo">
+        <map><entry key="\(path)"><value><RecentProjectMetaInfo /></value></entry></map>
+        </option></component></application>
+        """
+      try xml.write(
+        toFile: (options as NSString).appendingPathComponent("recentProjects.xml"),
+        atomically: true, encoding: .utf8)
+    }
+
+    let importer = RecentWorkspaceImporter(
+      applicationSupportDirectory: root, jetBrainsConfigRoot: root)
+    let items = await importer.recentWorkspaces(for: [.pycharm, .intellij])
+
+    #expect(items.map(\.path) == [project])
+    #expect(items.first?.editor == EditorType.pycharm.rawValue)
+  }
+}
+
+@Suite("JetBrainsRecentProjectsParser")
+struct JetBrainsRecentProjectsParserTests {
+
+  @Test("additionalInfo の項目を開いた日時の新しい順に返し、$USER_HOME$ を展開する")
+  func parsesAdditionalInfoEntries() {
+    let xml = """
+      <application>
+        <component name="RecentProjectsManager">
+          <option name="additionalInfo">
+            <map>
+              <entry key="$USER_HOME$/IdeaProjects/old">
+                <value>
+                  <RecentProjectMetaInfo>
+                    <option name="projectOpenTimestamp" value="1700000000000" />
+                  </RecentProjectMetaInfo>
+                </value>
+              </entry>
+              <entry key="/work/new">
+                <value>
+                  <RecentProjectMetaInfo>
+                    <option name="projectOpenTimestamp" value="1760000000000" />
+                  </RecentProjectMetaInfo>
+                </value>
+              </entry>
+            </map>
+          </option>
+          <option name="lastOpenedProject" value="/work/new" />
+        </component>
+      </application>
+      """
+
+    let paths = JetBrainsRecentProjectsParser.projectPaths(
+      in: Data(xml.utf8), homeDirectory: "/Users/dev")
+
+    #expect(paths == ["/work/new", "/Users/dev/IdeaProjects/old"])
+  }
+
+  @Test("旧形式の recentPaths を記述順に読み、重複は除く")
+  func parsesLegacyRecentPaths() {
+    let xml = """
+      <application>
+        <component name="RecentProjectsManager">
+          <option name="recentPaths">
+            <list>
+              <option value="/work/a" />
+              <option value="/work/b" />
+              <option value="/work/a" />
+            </list>
+          </option>
+        </component>
+      </application>
+      """
+
+    #expect(
+      JetBrainsRecentProjectsParser.projectPaths(in: Data(xml.utf8)) == ["/work/a", "/work/b"])
+    #expect(JetBrainsRecentProjectsParser.projectPaths(in: Data("<broken".utf8)).isEmpty)
+  }
+
+  @Test("接頭辞に一致する設定フォルダのうち最も新しいバージョンを選ぶ")
+  func selectsLatestConfigDirectory() throws {
+    let root = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-jetbrains-\(UUID().uuidString)").path
+    defer { try? FileManager.default.removeItem(atPath: root) }
+    for name in ["IntelliJIdea2024.3", "IdeaIC2025.1", "IntelliJIdea2025.10", "PyCharm2026.1"] {
+      try FileManager.default.createDirectory(
+        atPath: (root as NSString).appendingPathComponent(name), withIntermediateDirectories: true)
+    }
+
+    let latest = JetBrainsRecentProjectsParser.latestConfigDirectory(
+      in: root, prefixes: EditorType.intellij.jetBrainsConfigPrefixes)
+
+    #expect(latest.map { ($0 as NSString).lastPathComponent } == "IntelliJIdea2025.10")
+    #expect(
+      JetBrainsRecentProjectsParser.latestConfigDirectory(in: root, prefixes: ["WebStorm"]) == nil)
+  }
+}
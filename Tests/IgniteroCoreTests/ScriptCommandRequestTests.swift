@@ -0,0 +1,66 @@
+import Foundation
+import IgniteroIPC
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("ScriptCommandRequest")
+struct ScriptCommandRequestTests {
+
+  @Test("search launcher は検索語を search リクエストにし、空や文字列以外は受け付けない")
+  func searchRequest() {
+    #expect(
+      ScriptCommandRequest.search("safari") == IPCRequest(command: .search, argument: "safari"))
+    #expect(ScriptCommandRequest.search("") == nil)
+    #expect(ScriptCommandRequest.search(nil) == nil)
+    #expect(ScriptCommandRequest.search(42) == nil)
+  }
+
+  @Test("open item は ~ を展開し、パスを正規化する")
+  func openRequestExpandsPath() {
+    let home = NSHomeDirectory()
+    #expect(
+      ScriptCommandRequest.open("~/src/../Downloads")
+        == IPCRequest(command: .open, argument: home + "/Downloads"))
+    #expect(
+      ScriptCommandRequest.open("/Applications/Safari.app/")
+        == IPCRequest(command: .open, argument: "/Applications/Safari.app"))
+    #expect(ScriptCommandRequest.open("") == nil)
+  }
+
+  @Test("検索結果を name / path / kind / score のレコードにする")
+  func searchResultRecords() throws {
+    let response = IPCResponse(
+      ok: true,
+      results: [
+        IPCSearchItem(
+          name: "Safari", path: "/Applications/Safari.app", kind: "app", score: 0.9,
+          matchedIndices: [0], group: "apps")
+      ])
+    let records = ScriptCommandRequest.searchResultRecords(from: response)
+
+    #expect(records.count == 1)
+    let record = try #require(records.first)
+    #expect(Set(record.keys) == Set(ScriptCommandRequest.searchResultKeys))
+    #expect(record["name"] as? String == "Safari")
+    #expect(record["path"] as? String == "/Applications/Safari.app")
+    #expect(record["kind"] as? String == "app")
+    #expect(record["score"] as? Double == 0.9)
+    #expect(ScriptCommandRequest.searchResultRecords(from: .success).isEmpty)
+  }
+
+  @Test("レコードのキーは sdef の search result の cocoa key と一致する")
+  func recordKeysMatchScriptingDefinition() throws {
+    let sdef = URL(fileURLWithPath: #filePath)
+      .deletingLastPathComponent().deletingLastPathComponent().deletingLastPathComponent()
+      .appendingPathComponent("Resources/Ignitero.sdef")
+    let text = try String(contentsOf: sdef, encoding: .utf8)
+    let recordType = try #require(
+      text.range(
+        of: #"(?s)<record-type name="search result".*?</record-type>"#,
+        options: .regularExpression))
+    let keys = text[recordType].matches(of: /<cocoa key="([^"]+)"\/>/).map { String($0.1) }
+
+    #expect(keys == ScriptCommandRequest.searchResultKeys)
+  }
+}
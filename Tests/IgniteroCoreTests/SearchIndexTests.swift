@@ -0,0 +1,99 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("SearchIndex")
+struct SearchIndexTests {
+
+  @Test("名前と元の名前を小文字化して保持する")
+  func storesLowercasedNames() throws {
+    let index = SearchIndex(
+      apps: [
+        AppItem(
+          name: "カレンダー", path: "/System/Applications/Calendar.app", originalName: "Calendar")
+      ],
+      directories: [DirectoryItem(name: "MyProject", path: "/Users/test/dev/MyProject")],
+      commands: [CustomCommand(alias: "Deploy", command: "make deploy")],
+      home: "/Users/test"
+    )
+    let app = try #require(index.indexedApps.first)
+    #expect(app.names.first == "カレンダー")
+    #expect(app.names.contains("かれんだー"))
+    #expect(app.names.last == "calendar")
+    let dir = try #require(index.indexedDirectories.first)
+    #expect(dir.names == ["myproject"])
+    #expect(dir.abbreviatedPath == "~/dev/myproject")
+    #expect(index.indexedCommands.first?.names == ["deploy"])
+    #expect(index.count == 3)
+  }
+
+  @Test("表示名テンプレートを適用したディレクトリは元の名前でも一致させる")
+  func directoryOriginalNameIsIndexed() throws {
+    let index = SearchIndex(
+      apps: [],
+      directories: [DirectoryItem(name: "work/api", path: "/work/api", originalName: "api")],
+      commands: [])
+    let dir = try #require(index.indexedDirectories.first)
+    #expect(dir.names == ["work/api", "api"])
+  }
+
+  @Test("変換器のキーをインデックスに追加し、検索で一致させる")
+  func transliteratorKeysAreIndexed() throws {
+    struct StubTransliterator: Transliterator {
+      let id = "stub"
+      func searchKeys(for name: String) -> [String] {
+        name == "微信" ? ["weixin", "wx"] : []
+      }
+    }
+    let apps = [AppItem(name: "微信", path: "/Applications/WeChat.app")]
+    let index = SearchIndex(
+      apps: apps, directories: [], commands: [], transliterators: [StubTransliterator()])
+    let entry = try #require(index.indexedApps.first)
+    #expect(entry.names == ["微信", "weixin", "wx"])
+
+    let results = SearchService().search(query: "weixin", index: index, history: [])
+    #expect(results.first?.path == "/Applications/WeChat.app")
+  }
+
+  @Test("非表示のパスは検索対象と別名から除外する")
+  func hiddenPathsAreExcluded() {
+    let command = CustomCommand(alias: "uninstall", command: "./uninstall.sh")
+    let index = SearchIndex(
+      apps: [
+        AppItem(name: "Safari", path: "/Applications/Safari.app"),
+        AppItem(name: "Safari Uninstaller", path: "/Applications/Safari Uninstaller.app"),
+      ],
+      directories: [],
+      commands: [command],
+      aliases: [ItemAlias(alias: "su", path: "/Applications/Safari Uninstaller.app")],
+      hiddenPaths: ["/Applications/Safari Uninstaller.app", command.historyIdentifier]
+    )
+    #expect(index.apps.map(\.path) == ["/Applications/Safari.app"])
+    #expect(index.commands.isEmpty)
+    #expect(index.aliasTargets.isEmpty)
+
+    let results = SearchService().search(query: "safari", index: index, history: [])
+    #expect(results.map(\.path) == ["/Applications/Safari.app"])
+  }
+
+  @Test("インデックス経由の検索は配列を渡す検索と同じ結果になる")
+  func indexedSearchMatchesArraySearch() {
+    let apps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app"),
+      AppItem(name: "Slack", path: "/Applications/Slack.app"),
+    ]
+    let directories = [
+      DirectoryItem(name: "safari-extension", path: "/Users/test/safari-extension")
+    ]
+    let service = SearchService()
+    let fromArrays = service.search(
+      query: "saf", apps: apps, directories: directories, commands: [], history: [])
+    let fromIndex = service.search(
+      query: "saf",
+      index: SearchIndex(apps: apps, directories: directories, commands: []),
+      history: [])
+    #expect(fromIndex.map(\.path) == fromArrays.map(\.path))
+    #expect(fromIndex.map(\.score) == fromArrays.map(\.score))
+  }
+}
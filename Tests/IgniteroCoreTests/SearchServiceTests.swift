@@ -190,6 +190,34 @@ struct SearchServiceOriginalNameTests {
     #expect(!results.isEmpty)
     #expect(results[0].name == "システム設定")
   }
+
+  @Test("英語環境でも日本語のローカライズ名で検索できる")
+  func matchesAlternateName() {
+    let apps = [
+      AppItem(
+        name: "Preview", path: "/System/Applications/Preview.app", alternateNames: ["プレビュー"])
+    ]
+    let results = SearchService().search(
+      query: "ぷれびゅー", apps: apps, directories: [], commands: [], history: [])
+    #expect(results.first?.path == "/System/Applications/Preview.app")
+  }
+
+  @Test("キーワードでも一致するが、名前で一致したアプリより下に並べる")
+  func matchesKeywordsWithLowerWeight() {
+    let apps = [
+      AppItem(name: "Safari", path: "/Applications/Safari.app", keywords: ["browser", "ブラウザ"]),
+      AppItem(name: "Browserosaurus", path: "/Applications/Browserosaurus.app"),
+      AppItem(name: "Notes", path: "/System/Applications/Notes.app"),
+    ]
+    let results = SearchService().search(
+      query: "browser", apps: apps, directories: [], commands: [], history: [])
+    #expect(results.map(\.name) == ["Browserosaurus", "Safari"])
+    #expect(results[1].score == SearchService.keywordScorePenalty)
+
+    let japanese = SearchService().search(
+      query: "ブラウザ", apps: apps, directories: [], commands: [], history: [])
+    #expect(japanese.map(\.name) == ["Safari"])
+  }
 }
 
 // MARK: - 全角クエリ
@@ -209,6 +237,51 @@ struct SearchServiceFullwidthTests {
   }
 }
 
+// MARK: - かな・ローマ字
+
+@Suite("SearchService Kana")
+struct SearchServiceKanaTests {
+
+  private let apps = [
+    AppItem(name: "メモ", path: "/System/Applications/Notes.app", originalName: "Notes")
+  ]
+
+  @Test("カタカナをひらがなに畳み込む")
+  func foldsKatakanaToHiragana() {
+    #expect(SearchQueryNormalizer.foldKana("メモ帳") == "めも帳")
+    #expect(SearchQueryNormalizer.foldKana("abc") == "abc")
+  }
+
+  @Test("かなをローマ字に変換する")
+  func convertsKanaToRomaji() {
+    #expect(SearchQueryNormalizer.romaji("メモ") == "memo")
+    #expect(SearchQueryNormalizer.romaji("めも") == "memo")
+    #expect(SearchQueryNormalizer.romaji("Safari") == nil)
+  }
+
+  @Test("カタカナ名をローマ字で検索できる")
+  func romajiQueryMatchesKatakanaName() {
+    let results = SearchService().search(
+      query: "memo", apps: apps, directories: [], commands: [], history: [])
+    #expect(results.first?.path == "/System/Applications/Notes.app")
+  }
+
+  @Test("カタカナ名をひらがなでも検索できる")
+  func hiraganaQueryMatchesKatakanaName() {
+    let results = SearchService().search(
+      query: "めも", apps: apps, directories: [], commands: [], history: [])
+    #expect(results.first?.path == "/System/Applications/Notes.app")
+  }
+
+  @Test("カタカナで入力しても一致する")
+  func katakanaQueryMatchesKatakanaName() {
+    let directories = [DirectoryItem(name: "ドキュメント", path: "/Users/test/Documents")]
+    let results = SearchService().search(
+      query: "ドキュメント", apps: [], directories: directories, commands: [], history: [])
+    #expect(results.first?.path == "/Users/test/Documents")
+  }
+}
+
 // MARK: - 履歴ブースト
 
 @Suite("SearchService History")
@@ -721,3 +794,895 @@ struct SearchServiceHistoryBoostEdgeCaseTests {
     #expect(results.count == 20)
   }
 }
+
+// MARK: - 検索プレフィックス
+
+@Suite("SearchQueryParser")
+struct SearchQueryParserTests {
+
+  @Test("英数字プレフィックスは空白付きで検索対象を絞り込む")
+  func letterPrefixRequiresSpace() {
+    #expect(SearchQueryParser.parse("d project") == ParsedSearchQuery(scope: .directories, term: "project"))
+    #expect(SearchQueryParser.parse("a saf") == ParsedSearchQuery(scope: .apps, term: "saf"))
+    // 空白なしは通常検索
+    #expect(SearchQueryParser.parse("dash") == ParsedSearchQuery(scope: .all, term: "dash"))
+    #expect(SearchQueryParser.parse("d") == ParsedSearchQuery(scope: .all, term: "d"))
+  }
+
+  @Test("記号プレフィックスは空白なしでも認識する")
+  func symbolPrefixWithoutSpace() {
+    #expect(SearchQueryParser.parse(">deploy") == ParsedSearchQuery(scope: .commands, term: "deploy"))
+    #expect(SearchQueryParser.parse("> deploy") == ParsedSearchQuery(scope: .commands, term: "deploy"))
+    #expect(SearchQueryParser.parse("~/dev") == ParsedSearchQuery(scope: .paths, term: "/dev"))
+    #expect(SearchQueryParser.parse(">") == ParsedSearchQuery(scope: .commands, term: ""))
+  }
+
+  @Test("全角のプレフィックスも正規化して認識する")
+  func fullwidthPrefix() {
+    #expect(SearchQueryParser.parse("Ｄ Project") == ParsedSearchQuery(scope: .directories, term: "project"))
+  }
+
+  @Test("カスタムプレフィックスと無効化")
+  func customAndDisabledPrefixes() {
+    let custom = SearchPrefixSettings(app: "app:", directory: "", command: ">", path: "~")
+    #expect(SearchQueryParser.parse("app:saf", prefixes: custom) == ParsedSearchQuery(scope: .apps, term: "saf"))
+    // 空文字のプレフィックスは無効
+    #expect(SearchQueryParser.parse("d proj", prefixes: custom).scope == .all)
+
+    let disabled = SearchPrefixSettings(enabled: false)
+    #expect(SearchQueryParser.parse("d proj", prefixes: disabled) == ParsedSearchQuery(scope: .all, term: "d proj"))
+  }
+}
+
+@Suite("SearchService Prefix Filters")
+struct SearchServicePrefixFilterTests {
+  let apps = [AppItem(name: "Proj Viewer", path: "/Applications/Proj Viewer.app")]
+  let directories = [DirectoryItem(name: "proj", path: "/Users/dev/proj")]
+  let commands = [CustomCommand(alias: "proj", command: "make proj")]
+
+  @Test("d プレフィックスはディレクトリのみを返す")
+  func directoryPrefix() {
+    let results = SearchService().search(
+      query: "d proj", apps: apps, directories: directories, commands: commands, history: [])
+    #expect(!results.isEmpty)
+    #expect(results.allSatisfy { $0.kind == .directory })
+  }
+
+  @Test("a プレフィックスはアプリのみを返す")
+  func appPrefix() {
+    let results = SearchService().search(
+      query: "a proj", apps: apps, directories: directories, commands: commands, history: [])
+    #expect(results.map(\.kind) == [.app])
+  }
+
+  @Test("> プレフィックスはコマンドのみを返す")
+  func commandPrefix() {
+    let results = SearchService().search(
+      query: ">proj", apps: apps, directories: directories, commands: commands, history: [])
+    #expect(results.map(\.kind) == [.command])
+  }
+
+  @Test("プレフィックスのみの場合は該当種別の履歴だけを返す")
+  func prefixOnlyFiltersHistory() {
+    let history = [
+      SelectionHistoryEntry(keyword: "proj", selectedPath: "/Users/dev/proj", count: 3),
+      SelectionHistoryEntry(
+        keyword: "proj", selectedPath: commands[0].historyIdentifier, count: 5),
+    ]
+    let results = SearchService().search(
+      query: ">", apps: apps, directories: directories, commands: commands, history: history)
+    #expect(results.map(\.kind) == [.command])
+  }
+
+  @Test("パス検索はホームを ~ 表記に短縮して照合する")
+  func abbreviatedPath() {
+    #expect(SearchService.abbreviatedPath("/Users/me/dev/App", home: "/Users/me") == "~/dev/app")
+    #expect(SearchService.abbreviatedPath("/Users/meow/x", home: "/Users/me") == "/users/meow/x")
+    #expect(SearchService.abbreviatedPath("/Applications/A.app", home: "") == "/applications/a.app")
+  }
+}
+
+@Suite("SearchService Recent Projects")
+struct SearchServiceRecentProjectsTests {
+  let apps = [AppItem(name: "Safari", path: "/Applications/Safari.app")]
+  let directories = [
+    DirectoryItem(name: "alpha", path: "/dev/alpha"),
+    DirectoryItem(name: "beta", path: "/dev/beta", editor: "zed"),
+  ]
+
+  @Test("空クエリでは最近のプロジェクトが新しい順に先頭へ並ぶ")
+  func recentProjectsComeFirst() {
+    let base = Date(timeIntervalSince1970: 1_700_000_000)
+    let recent = [
+      RecentProject(path: "/dev/alpha", editor: "cursor", openCount: 1, lastOpened: base),
+      RecentProject(path: "/dev/beta", editor: nil, openCount: 4, lastOpened: base + 60),
+    ]
+    let history = [
+      SelectionHistoryEntry(keyword: "saf", selectedPath: "/Applications/Safari.app", count: 9),
+      SelectionHistoryEntry(keyword: "al", selectedPath: "/dev/alpha", count: 2),
+    ]
+    let results = SearchService().search(
+      query: "", apps: apps, directories: directories, commands: [], history: history,
+      recentProjects: recent)
+
+    #expect(results.map(\.path) == ["/dev/beta", "/dev/alpha", "/Applications/Safari.app"])
+    // 最後に開いたエディタを優先し、未記録ならディレクトリ既定のエディタを使う
+    #expect(results[0].editor == "zed")
+    #expect(results[1].editor == "cursor")
+  }
+
+  @Test("キャッシュに存在しないディレクトリは表示しない")
+  func unknownProjectsAreSkipped() {
+    let recent = [RecentProject(path: "/dev/removed")]
+    let results = SearchService().search(
+      query: "", apps: apps, directories: directories, commands: [], history: [],
+      recentProjects: recent)
+    #expect(results.isEmpty)
+  }
+
+  @Test("アプリ絞り込み時は最近のプロジェクトを表示しない")
+  func appScopeExcludesProjects() {
+    let recent = [RecentProject(path: "/dev/alpha")]
+    let results = SearchService().search(
+      query: "", apps: apps, directories: directories, commands: [], history: [],
+      defaultScope: .apps, recentProjects: recent)
+    #expect(results.isEmpty)
+  }
+
+  @Test("クエリがある場合は最近のプロジェクトを先頭固定しない")
+  func nonEmptyQueryIgnoresRecentProjects() {
+    let recent = [RecentProject(path: "/dev/beta")]
+    let results = SearchService().search(
+      query: "alpha", apps: apps, directories: directories, commands: [], history: [],
+      recentProjects: recent)
+    #expect(results.first?.path == "/dev/alpha")
+  }
+}
+
+@Suite("SearchService Default Items")
+struct SearchServiceDefaultItemsTests {
+  let apps = [
+    AppItem(name: "Slack", path: "/Applications/Slack.app"),
+    AppItem(name: "Xcode", path: "/Applications/Xcode.app"),
+    AppItem(name: "Music", path: "/Applications/Music.app"),
+  ]
+
+  /// 指定時刻にだけ使った回数を持つ時刻ごとの使用回数
+  private func hourly(_ hour: Int, _ count: Int) -> [Int] {
+    var counts = Array(repeating: 0, count: 24)
+    counts[hour] = count
+    return counts
+  }
+
+  private func date(hour: Int) throws -> Date {
+    try #require(
+      Calendar.current.date(from: DateComponents(year: 2026, month: 1, day: 5, hour: hour)))
+  }
+
+  @Test("今の時間帯によく使う項目を使用回数の多い履歴より先に提案する")
+  func timeOfDaySuggestionsComeBeforeHistory() throws {
+    let history = [
+      SelectionHistoryEntry(
+        keyword: "xc", selectedPath: "/Applications/Xcode.app", count: 20,
+        hourlyCounts: hourly(14, 20)),
+      SelectionHistoryEntry(
+        keyword: "sl", selectedPath: "/Applications/Slack.app", count: 5,
+        hourlyCounts: hourly(9, 5)),
+      SelectionHistoryEntry(
+        keyword: "mu", selectedPath: "/Applications/Music.app", count: 8,
+        hourlyCounts: hourly(9, 1)),
+    ]
+    let index = SearchIndex(apps: apps, directories: [], commands: [])
+    let morning = SearchService().defaultItems(
+      index: index, history: history, now: try date(hour: 10))
+    // 1 回しか使っていない Music は提案せず、使用回数順の履歴に並ぶ
+    #expect(morning.map(\.name) == ["Slack", "Xcode", "Music"])
+
+    let afternoon = SearchService().defaultItems(
+      index: index, history: history, now: try date(hour: 14))
+    #expect(afternoon.map(\.name) == ["Xcode", "Music", "Slack"])
+  }
+
+  @Test("お気に入りは時間帯の提案より先に表示する")
+  func favoritesComeBeforeSuggestions() throws {
+    let history = [
+      SelectionHistoryEntry(
+        keyword: "sl", selectedPath: "/Applications/Slack.app", count: 5,
+        hourlyCounts: hourly(9, 5))
+    ]
+    let index = SearchIndex(
+      apps: apps, directories: [], commands: [], favorites: ["/Applications/Music.app"])
+    let results = SearchService().defaultItems(
+      index: index, history: history, now: try date(hour: 9))
+    #expect(results.map(\.name) == ["Music", "Slack"])
+    #expect(results[0].isFavorite)
+  }
+
+  @Test("絞り込み対象外の種別は提案しない")
+  func suggestionsRespectScope() throws {
+    let history = [
+      SelectionHistoryEntry(
+        keyword: "sl", selectedPath: "/Applications/Slack.app", count: 5,
+        hourlyCounts: hourly(9, 5))
+    ]
+    let index = SearchIndex(apps: apps, directories: [], commands: [])
+    let results = SearchService().defaultItems(
+      index: index, history: history, scope: .directories, now: try date(hour: 9))
+    #expect(results.isEmpty)
+  }
+}
+
+@Suite("SearchService Git Repositories")
+struct SearchServiceGitRepositoryTests {
+
+  @Test("同じ一致度なら Git リポジトリを上位に表示する")
+  func gitReposRankHigher() {
+    let directories = [
+      DirectoryItem(name: "webapp", path: "/archive/webapp"),
+      DirectoryItem(name: "webapp", path: "/src/webapp", isGitRepo: true, gitBranch: "main"),
+    ]
+    let results = SearchService().search(
+      query: "webapp", apps: [], directories: directories, commands: [], history: [])
+    #expect(results.first?.path == "/src/webapp")
+    #expect(results.first?.isGitRepo == true)
+    #expect(results.first?.gitBranch == "main")
+  }
+
+  @Test("優先度の高い登録ディレクトリの項目を上位に表示する")
+  func higherPriorityDirectoriesRankHigher() {
+    let directories = [
+      DirectoryItem(name: "webapp", path: "/archive/webapp", priority: -2),
+      DirectoryItem(name: "webapp", path: "/src/webapp", priority: 3),
+    ]
+    let results = SearchService().search(
+      query: "webapp", apps: [], directories: directories, commands: [], history: [])
+    #expect(results.map(\.path) == ["/src/webapp", "/archive/webapp"])
+  }
+
+  @Test("パス検索でも登録ディレクトリの優先度を反映する")
+  func pathSearchAppliesPriority() {
+    let directories = [
+      DirectoryItem(name: "webapp", path: "/aaaa/webapp"),
+      DirectoryItem(name: "webapp", path: "/bbbb/webapp", priority: 3),
+    ]
+    let results = SearchService().search(
+      query: "~webapp", apps: [], directories: directories, commands: [], history: [])
+    #expect(results.map(\.path) == ["/bbbb/webapp", "/aaaa/webapp"])
+  }
+
+  @Test("優先度は履歴ブーストより弱い")
+  func priorityDoesNotOverrideHistory() {
+    let directories = [
+      DirectoryItem(name: "webapp", path: "/archive/webapp"),
+      DirectoryItem(name: "webapp", path: "/src/webapp", priority: 5),
+    ]
+    let history = [
+      SelectionHistoryEntry(
+        keyword: "webapp", selectedPath: "/archive/webapp", count: 1, lastUsed: Date())
+    ]
+    let results = SearchService().search(
+      query: "webapp", apps: [], directories: directories, commands: [], history: history)
+    #expect(results.first?.path == "/archive/webapp")
+  }
+}
+
+@Suite("SearchService Duplicate Names")
+struct SearchServiceDuplicateNameTests {
+
+  @Test("同じ名前の結果には親ディレクトリを付ける")
+  func sameNamesGetParentDirectory() {
+    let directories = [
+      DirectoryItem(name: "backend", path: "/work/shop/backend"),
+      DirectoryItem(name: "Backend", path: "/oss/api/Backend"),
+      DirectoryItem(name: "backend-tools", path: "/work/backend-tools"),
+    ]
+    let results = SearchService().search(
+      query: "backend", apps: [], directories: directories, commands: [], history: [])
+
+    let byPath = Dictionary(uniqueKeysWithValues: results.map { ($0.path, $0.disambiguation) })
+    #expect(byPath["/work/shop/backend"] == "/work/shop")
+    #expect(byPath["/oss/api/Backend"] == "/oss/api")
+    #expect(byPath["/work/backend-tools"] == .some(nil))
+  }
+
+  @Test("実体が同じパスの結果は 1 件にまとめる")
+  func removesSameCanonicalPath() {
+    let apps = [
+      AppItem(name: "Editor", path: "/Applications/Editor.app"),
+      AppItem(name: "Editor", path: "/Applications/./Editor.app"),
+    ]
+    let results = SearchService().search(
+      query: "editor", apps: apps, directories: [], commands: [], history: [])
+
+    #expect(results.count == 1)
+    #expect(results.first?.disambiguation == nil)
+  }
+
+  @Test("実体のパスはインデックスの構築時に解決したものを使い、前回の解決結果を引き継ぐ")
+  func usesCanonicalPathsResolvedAtIndexing() {
+    let apps = [
+      AppItem(name: "Editor", path: "/Applications/Editor.app"),
+      AppItem(name: "Editor", path: "/Volumes/Tools/Editor.app"),
+    ]
+    let index = SearchIndex(
+      apps: apps, directories: [], commands: [],
+      canonicalPaths: ["/Volumes/Tools/Editor.app": "/Applications/Editor.app"])
+
+    let results = SearchService().search(query: "editor", index: index, history: [])
+
+    #expect(results.map(\.path) == ["/Applications/Editor.app"])
+    #expect(index.canonicalPaths["/Applications/Editor.app"] == "/Applications/Editor.app")
+  }
+}
+
+@Suite("SearchService Aliases")
+struct SearchServiceAliasTests {
+
+  private let apps = [
+    AppItem(name: "Visual Studio Code", path: "/Applications/Visual Studio Code.app"),
+    AppItem(name: "VSCodium", path: "/Applications/VSCodium.app"),
+  ]
+
+  private func index(aliases: [ItemAlias]) -> SearchIndex {
+    SearchIndex(apps: apps, directories: [], commands: [], aliases: aliases)
+  }
+
+  @Test("別名と完全一致した項目を先頭に表示する")
+  func exactAliasRanksFirst() {
+    let aliases = [ItemAlias(alias: "vsc", path: "/Applications/Visual Studio Code.app")]
+    let results = SearchService().search(query: "vsc", index: index(aliases: aliases), history: [])
+    #expect(results.first?.path == "/Applications/Visual Studio Code.app")
+    #expect(results.first?.score == SearchService.aliasExactScore)
+  }
+
+  @Test("インデックスにないパスの別名はディレクトリとして追加する")
+  func aliasToUnindexedDirectory() {
+    let aliases = [ItemAlias(alias: "dl", path: "/Users/test/Downloads")]
+    let results = SearchService().search(query: "dl", index: index(aliases: aliases), history: [])
+    #expect(results.first?.kind == .directory)
+    #expect(results.first?.name == "Downloads")
+  }
+
+  @Test("検索対象外の種別の別名は追加しない")
+  func aliasRespectsScope() {
+    let aliases = [ItemAlias(alias: "dl", path: "/Users/test/Downloads")]
+    let results = SearchService().search(
+      query: "dl", index: index(aliases: aliases), history: [], defaultScope: .apps)
+    #expect(!results.contains { $0.path == "/Users/test/Downloads" })
+  }
+}
+
+@Suite("SearchService Favorites")
+struct SearchServiceFavoriteTests {
+
+  private let apps = [
+    AppItem(name: "Notes", path: "/Applications/Notes.app"),
+    AppItem(name: "Notion", path: "/Applications/Notion.app"),
+  ]
+  private let directories = [DirectoryItem(name: "notebook", path: "/dev/notebook")]
+
+  private func index(favorites: Set<String>) -> SearchIndex {
+    SearchIndex(apps: apps, directories: directories, commands: [], favorites: favorites)
+  }
+
+  @Test("一致したお気に入りは一致度に関係なく先頭に表示する")
+  func favoritesRankFirst() {
+    let results = SearchService().search(
+      query: "no", index: index(favorites: ["/Applications/Notion.app"]), history: [])
+    #expect(results.first?.path == "/Applications/Notion.app")
+    #expect(results.first?.isFavorite == true)
+    #expect(results.dropFirst().allSatisfy { !$0.isFavorite })
+  }
+
+  @Test("空クエリではお気に入りを最近のプロジェクトより先に表示する")
+  func emptyQueryListsFavoritesFirst() {
+    let results = SearchService().search(
+      query: "", index: index(favorites: ["/Applications/Notes.app"]), history: [],
+      recentProjects: [RecentProject(path: "/dev/notebook")])
+    #expect(results.map(\.path) == ["/Applications/Notes.app", "/dev/notebook"])
+  }
+
+  @Test("お気に入りがなければ並び順は変わらない")
+  func noFavoritesKeepsScoreOrder() {
+    let service = SearchService()
+    let withIndex = service.search(query: "not", index: index(favorites: []), history: [])
+    let withArrays = service.search(
+      query: "not", apps: apps, directories: directories, commands: [], history: [])
+    #expect(withIndex.map(\.path) == withArrays.map(\.path))
+  }
+}
+
+// MARK: - 全文検索
+
+/// 照合した列と検索語を記録し、固定の候補を返す全文検索
+private final class StubDirectorySearcher: DirectoryFullTextSearching, @unchecked Sendable {
+  let candidates: [DirectoryItem]
+  private(set) var queries: [(query: String, field: FullTextSearchField)] = []
+
+  init(candidates: [DirectoryItem]) {
+    self.candidates = candidates
+  }
+
+  func searchDirectories(
+    matching query: String, in field: FullTextSearchField, limit: Int
+  ) throws -> [DirectoryItem] {
+    queries.append((query, field))
+    return Array(candidates.prefix(limit))
+  }
+}
+
+@Suite("SearchService Full Text Search")
+struct SearchServiceFullTextSearchTests {
+
+  @Test("全文検索の候補を結果に含め、メモリ上のディレクトリは照合しない")
+  func usesSearcherInsteadOfDirectories() {
+    let searcher = StubDirectorySearcher(candidates: [
+      DirectoryItem(name: "ignitero-launcher", path: "/dev/ignitero-launcher")
+    ])
+    let index = SearchIndex(
+      apps: [], directories: [DirectoryItem(name: "ignite-memory", path: "/dev/ignite-memory")],
+      commands: [], directorySearcher: searcher)
+
+    let results = SearchService().search(query: "ignite", index: index, history: [])
+
+    #expect(results.map(\.path) == ["/dev/ignitero-launcher"])
+    #expect(searcher.queries.map(\.query) == ["ignite"])
+    #expect(searcher.queries.map(\.field) == [.name])
+  }
+
+  @Test("ファジー照合で一致しない候補も残し、非表示のパスは除外する")
+  func keepsPrefixOnlyMatchesAndDropsHidden() {
+    let searcher = StubDirectorySearcher(candidates: [
+      DirectoryItem(name: "alpha beta", path: "/dev/alpha-beta"),
+      DirectoryItem(name: "alpha", path: "/dev/hidden"),
+    ])
+    let index = SearchIndex(
+      apps: [], directories: [], commands: [], hiddenPaths: ["/dev/hidden"],
+      directorySearcher: searcher)
+
+    let results = SearchService().search(query: "bet alp", index: index, history: [])
+
+    #expect(results.map(\.path) == ["/dev/alpha-beta"])
+  }
+
+  @Test("パス検索ではパスの列を照合する")
+  func pathScopeSearchesPathField() {
+    let searcher = StubDirectorySearcher(candidates: [
+      DirectoryItem(name: "project", path: "/Users/test/dev/project")
+    ])
+    let index = SearchIndex(apps: [], directories: [], commands: [], directorySearcher: searcher)
+
+    let results = SearchService().search(query: "~dev", index: index, history: [])
+
+    #expect(results.map(\.path) == ["/Users/test/dev/project"])
+    #expect(searcher.queries.map(\.field) == [.path])
+  }
+
+  @Test("パス検索の候補にも Git リポジトリと優先度の補正を内訳とともに反映する")
+  func pathScopeRecordsBreakdown() throws {
+    let searcher = StubDirectorySearcher(candidates: [
+      DirectoryItem(name: "project", path: "/Users/test/dev/project", isGitRepo: true, priority: 2)
+    ])
+    let index = SearchIndex(apps: [], directories: [], commands: [], directorySearcher: searcher)
+
+    let result = try #require(
+      SearchService().debugSearch(query: "~dev", index: index, history: []).first)
+
+    let expected = -(SearchService.gitRepoScoreBonus + 2 * SearchService.directoryPriorityStep)
+    #expect(abs(result.breakdown.categoryAdjustment - expected) < 1e-9)
+  }
+}
+
+// MARK: - 結果の件数と並べ方
+
+@Suite("SearchService Result Settings")
+struct SearchServiceResultSettingsTests {
+
+  @Test("設定の最大件数まで返す")
+  func respectsMaxResults() {
+    let apps = (0..<30).map { AppItem(name: "App\($0)", path: "/Applications/App\($0).app") }
+    let results = SearchService().search(
+      query: "app", apps: apps, directories: [], commands: [], history: [],
+      resultSettings: ResultSettings(maxResults: 5))
+    #expect(results.count == 5)
+  }
+
+  @Test("種別ごとの上限を超えた結果は後続の種別に譲る")
+  func respectsCategoryLimits() {
+    let apps = ["Notes A", "Notes B", "Notes C"].map {
+      AppItem(name: $0, path: "/Applications/\($0).app")
+    }
+    let directories = [DirectoryItem(name: "notes-x", path: "/dev/notes-x")]
+    let results = SearchService().search(
+      query: "notes", apps: apps, directories: directories, commands: [], history: [],
+      resultSettings: ResultSettings(maxApps: 2))
+    #expect(results.filter { $0.kind == .app }.count == 2)
+    #expect(results.contains { $0.path == "/dev/notes-x" })
+  }
+
+  @Test("同じ一致度の結果を頻度順または名前順に並べる")
+  func tieBreakOrdering() {
+    // 同じ長さ・同じ位置で一致するため一致度が等しい
+    let apps = [
+      AppItem(name: "Bat Tool", path: "/Applications/Bat Tool.app"),
+      AppItem(name: "Axe Tool", path: "/Applications/Axe Tool.app"),
+    ]
+    // 検索語と関係のないキーワードの履歴（履歴ブーストは効かず、頻度だけに効く）
+    let history = [
+      SelectionHistoryEntry(
+        keyword: "zzz", selectedPath: "/Applications/Bat Tool.app", count: 3, lastUsed: Date())
+    ]
+
+    let byFrecency = SearchService().search(
+      query: "tool", apps: apps, directories: [], commands: [], history: history,
+      resultSettings: ResultSettings(tieBreak: .frecency))
+    #expect(byFrecency.map(\.name) == ["Bat Tool", "Axe Tool"])
+
+    let alphabetical = SearchService().search(
+      query: "tool", apps: apps, directories: [], commands: [], history: history,
+      resultSettings: ResultSettings(tieBreak: .alphabetical))
+    #expect(alphabetical.map(\.name) == ["Axe Tool", "Bat Tool"])
+  }
+
+  @Test("見出しごとにまとめる設定では見出しの順に並べ、区分内はスコア順を保つ")
+  func groupedResultsFollowGroupOrder() {
+    let apps = [AppItem(name: "Deploy Tool", path: "/Applications/Deploy Tool.app")]
+    let directories = [
+      DirectoryItem(name: "deploy", path: "/dev/deploy"),
+      DirectoryItem(name: "deploy-scripts", path: "/dev/deploy-scripts"),
+    ]
+    let commands = [CustomCommand(alias: "deploy", command: "npm run deploy")]
+
+    let results = SearchService().search(
+      query: "deploy", apps: apps, directories: directories, commands: commands, history: [],
+      resultSettings: ResultSettings(groupResults: true, groupOrder: [.commands, .projects]))
+    #expect(results.map(\.kind) == [.command, .directory, .directory, .app])
+    #expect(results.filter { $0.kind == .directory }.map(\.path) == [
+      "/dev/deploy", "/dev/deploy-scripts",
+    ])
+
+    let sections = SearchResultSection.sections(of: results, order: [.commands, .projects])
+    #expect(sections.map(\.group) == [.commands, .projects, .apps])
+    #expect(sections.map(\.results.count) == [1, 2, 1])
+  }
+
+  @Test("頻度は最終使用からの経過日数で減衰する")
+  func frecencyDecaysWithAge() {
+    let now = Date(timeIntervalSince1970: 1_700_000_000)
+    let scores = SearchService.frecencyScores(
+      history: [
+        SelectionHistoryEntry(keyword: "a", selectedPath: "/recent", count: 2, lastUsed: now),
+        SelectionHistoryEntry(
+          keyword: "b", selectedPath: "/old", count: 4, lastUsed: now.addingTimeInterval(-14 * 86_400)),
+      ], now: now)
+    #expect(scores["/recent"] == 2)
+    #expect(scores["/old"] == 4.0 / 3.0)
+  }
+}
+
+@Suite("SearchService System Settings")
+struct SearchServiceSystemSettingsTests {
+  let panes = [
+    SystemSettingsPane(
+      id: "com.apple.Displays-Settings.extension", name: "ディスプレイ",
+      keywords: ["displays", "monitor"], symbolName: "display"),
+    SystemSettingsPane(id: "com.apple.wifi-settings-extension", name: "Wi-Fi", symbolName: "wifi"),
+  ]
+
+  @Test("英語のキーワードでもシステム設定のパネルに一致する")
+  func matchesPaneByKeyword() throws {
+    let index = SearchIndex(apps: [], directories: [], commands: [], systemSettingsPanes: panes)
+
+    let results = SearchService().search(query: "monitor", index: index, history: [])
+
+    let result = try #require(results.first)
+    #expect(result.kind == .systemSettings)
+    #expect(result.name == "ディスプレイ")
+    #expect(result.path == "x-apple.systempreferences:com.apple.Displays-Settings.extension")
+    #expect(result.symbolName == "display")
+  }
+
+  @Test("プレフィックスで絞り込んだ検索と非表示のパネルは除く")
+  func excludesPanesFromScopedSearchAndHiddenPaths() {
+    let index = SearchIndex(
+      apps: [], directories: [], commands: [], systemSettingsPanes: panes,
+      hiddenPaths: ["x-apple.systempreferences:com.apple.wifi-settings-extension"])
+    let service = SearchService()
+
+    #expect(service.search(query: "wifi", index: index, history: []).isEmpty)
+    #expect(
+      service.search(query: "display", index: index, history: [], defaultScope: .apps).isEmpty)
+  }
+}
+
+@Suite("SearchService System Controls")
+struct SearchServiceSystemControlTests {
+
+  @Test("英語のキーワードでシステム操作に一致する")
+  func matchesControlByKeyword() throws {
+    let index = SearchIndex(
+      apps: [], directories: [], commands: [], systemControls: SystemControl.allCases)
+
+    let result = try #require(
+      SearchService().search(query: "mute", index: index, history: []).first)
+
+    #expect(result.kind == .systemControl)
+    #expect(result.name == "消音を切り替え")
+    #expect(SystemControl(identifier: result.path) == .toggleMute)
+  }
+
+  @Test("非表示にしたシステム操作は検索対象から除く")
+  func excludesHiddenControls() {
+    let index = SearchIndex(
+      apps: [], directories: [], commands: [], systemControls: SystemControl.allCases,
+      hiddenPaths: [SystemControl.startScreenSaver.identifier])
+
+    let results = SearchService().search(query: "screen saver", index: index, history: [])
+
+    #expect(!results.contains { $0.path == SystemControl.startScreenSaver.identifier })
+  }
+}
+
+@Suite("SearchService Window Layout")
+struct SearchServiceWindowLayoutTests {
+
+  @Test("英語のキーワードでウィンドウ操作に一致する")
+  func matchesActionByKeyword() throws {
+    let index = SearchIndex(
+      apps: [], directories: [], commands: [], windowLayoutActions: WindowLayoutAction.allCases)
+
+    let result = try #require(
+      SearchService().search(query: "left half", index: index, history: []).first)
+
+    #expect(result.kind == .windowLayout)
+    #expect(result.name == "ウィンドウを左半分に配置")
+    #expect(WindowLayoutAction(identifier: result.path) == .leftHalf)
+  }
+
+  @Test("スコープを絞った検索ではウィンドウ操作を出さない")
+  func excludesActionsFromScopedSearch() {
+    let index = SearchIndex(
+      apps: [], directories: [], commands: [], windowLayoutActions: WindowLayoutAction.allCases)
+
+    let results = SearchService().search(
+      query: "maximize", index: index, history: [], defaultScope: .apps)
+
+    #expect(!results.contains { $0.kind == .windowLayout })
+  }
+}
+
+@Suite("SearchService Terminal Targets")
+struct SearchServiceTerminalTargetTests {
+  private let targets = [
+    TerminalTarget(kind: .sshHost, name: "prod-web", detail: "deploy@10.0.0.5"),
+    TerminalTarget(kind: .tmuxSession, name: "main"),
+  ]
+
+  @Test("`ssh <ホスト>` と入力すると SSH 接続のコマンド結果を返す")
+  func matchesHostByInvocation() throws {
+    let index = SearchIndex(apps: [], directories: [], commands: [], terminalTargets: targets)
+
+    let result = try #require(
+      SearchService().search(query: "ssh prod-web", index: index, history: []).first)
+
+    #expect(result.kind == .command)
+    #expect(result.name == "prod-web")
+    #expect(result.command == "ssh prod-web")
+    #expect(result.path == "ssh://prod-web")
+    #expect(index.indexedResult(for: "ssh://prod-web")?.command == "ssh prod-web")
+  }
+
+  @Test("アプリ・ディレクトリに絞った検索では接続先を出さない")
+  func excludesTargetsFromScopedSearch() {
+    let index = SearchIndex(apps: [], directories: [], commands: [], terminalTargets: targets)
+
+    let results = SearchService().search(
+      query: "main", index: index, history: [], defaultScope: .apps)
+
+    #expect(results.isEmpty)
+  }
+}
+
+@Suite("SearchService Bundle IDs")
+struct SearchServiceBundleIDTests {
+  private let apps = [
+    AppItem(
+      name: "Safari", path: "/Applications/Safari.app", version: "18.0",
+      bundleIdentifier: "com.apple.Safari"),
+    AppItem(name: "Xcode", path: "/Applications/Xcode.app", bundleIdentifier: "com.apple.dt.Xcode"),
+    AppItem(
+      name: "Google Chrome", path: "/Applications/Google Chrome.app",
+      bundleIdentifier: "com.google.Chrome"),
+  ]
+
+  @Test("bundle: でバンドル ID に前方一致するアプリだけを返す")
+  func filtersAppsByBundleIdentifier() {
+    let results = SearchService().search(
+      query: "bundle:com.apple", apps: apps, directories: [], commands: [], history: [])
+
+    #expect(Set(results.map(\.path)) == ["/Applications/Safari.app", "/Applications/Xcode.app"])
+    #expect(results.first { $0.name == "Safari" }?.version == "18.0")
+    #expect(results.first { $0.name == "Safari" }?.bundleIdentifier == "com.apple.Safari")
+  }
+
+  @Test("残りの検索語で名前を照合する")
+  func matchesRemainingTermByName() {
+    let results = SearchService().search(
+      query: "xco id:com.apple", apps: apps, directories: [], commands: [], history: [])
+    #expect(results.map(\.path) == ["/Applications/Xcode.app"])
+  }
+}
+
+@Suite("SearchService App Categories")
+struct SearchServiceAppCategoryTests {
+  private let apps = [
+    AppItem(
+      name: "Xcode", path: "/Applications/Xcode.app", bundleIdentifier: "com.apple.dt.Xcode",
+      category: "public.app-category.developer-tools"),
+    AppItem(
+      name: "Chess", path: "/System/Applications/Chess.app", bundleIdentifier: "com.apple.Chess",
+      category: "public.app-category.board-games"),
+    AppItem(
+      name: "Docker", path: "/Applications/Docker.app", bundleIdentifier: "com.docker.docker",
+      category: "public.app-category.developer-tools"),
+    AppItem(name: "Unknown", path: "/Applications/Unknown.app"),
+  ]
+
+  @Test("category: でカテゴリに一致するアプリだけを返す")
+  func filtersAppsByCategory() {
+    let service = SearchService()
+
+    let developer = service.search(
+      query: "category:developer", apps: apps, directories: [], commands: [], history: [])
+    #expect(Set(developer.map(\.name)) == ["Xcode", "Docker"])
+    #expect(developer.first?.categoryName == "developer-tools")
+
+    let games = service.search(
+      query: "cat:games", apps: apps, directories: [], commands: [], history: [])
+    #expect(games.map(\.name) == ["Chess"])
+  }
+
+  @Test("バンドル ID の絞り込みと残りの検索語を組み合わせられる")
+  func combinesWithBundleFilterAndTerm() {
+    let service = SearchService()
+
+    let apple = service.search(
+      query: "category:developer bundle:com.apple", apps: apps, directories: [], commands: [],
+      history: [])
+    #expect(apple.map(\.name) == ["Xcode"])
+
+    let docker = service.search(
+      query: "dock category:developer", apps: apps, directories: [], commands: [], history: [])
+    #expect(docker.map(\.name) == ["Docker"])
+  }
+}
+
+@Suite("SearchService Finder Tags")
+struct SearchServiceFinderTagTests {
+  private let directories = [
+    DirectoryItem(
+      name: "api", path: "/dev/api", finderTags: [FinderTag(name: "urgent", color: .red)]),
+    DirectoryItem(name: "web", path: "/dev/web", finderTags: [FinderTag(name: "Client")]),
+    DirectoryItem(name: "apple", path: "/dev/apple"),
+  ]
+
+  @Test("#色 と tag:名前 でタグの付いたディレクトリだけを返す")
+  func filtersDirectoriesByTag() {
+    let service = SearchService()
+
+    let byColor = service.search(
+      query: "#red", apps: [], directories: directories, commands: [], history: [])
+    #expect(byColor.map(\.path) == ["/dev/api"])
+    #expect(byColor.first?.finderTags == [FinderTag(name: "urgent", color: .red)])
+
+    let byName = service.search(
+      query: "tag:client", apps: [], directories: directories, commands: [], history: [])
+    #expect(byName.map(\.path) == ["/dev/web"])
+  }
+
+  @Test("残りの検索語で名前を照合する")
+  func matchesRemainingTermByName() {
+    let results = SearchService().search(
+      query: "ap #urgent", apps: [], directories: directories, commands: [], history: [])
+    #expect(results.map(\.path) == ["/dev/api"])
+
+    let none = SearchService().search(
+      query: "web #urgent", apps: [], directories: directories, commands: [], history: [])
+    #expect(none.isEmpty)
+  }
+}
+
+// MARK: - スコアの内訳
+
+@Suite("SearchService Score Breakdown")
+struct SearchServiceScoreBreakdownTests {
+
+  @Test("ファジー一致・種別ごとの補正・選択履歴の補正を記録する")
+  func recordsBreakdown() {
+    let directories = [
+      DirectoryItem(name: "webapp", path: "/dev/webapp", isGitRepo: true, priority: 2),
+      DirectoryItem(name: "webapp", path: "/archive/webapp"),
+    ]
+    let history = [
+      SelectionHistoryEntry(
+        keyword: "webapp", selectedPath: "/archive/webapp", count: 10, lastUsed: Date())
+    ]
+    let results = SearchService().debugSearch(
+      query: "webapp", index: SearchIndex(apps: [], directories: directories, commands: []),
+      history: history)
+
+    let boosted = results.first { $0.path == "/archive/webapp" }?.breakdown
+    #expect(boosted?.categoryAdjustment == 0)
+    #expect(abs((boosted?.historyBoost ?? 0) + 1.1) < 1e-9)
+
+    let adjusted = results.first { $0.path == "/dev/webapp" }
+    let expected = -(SearchService.gitRepoScoreBonus + 2 * SearchService.directoryPriorityStep)
+    #expect(abs((adjusted?.breakdown.categoryAdjustment ?? 0) - expected) < 1e-9)
+    #expect(adjusted?.breakdown.historyBoost == 0)
+    #expect(
+      abs(
+        (adjusted?.score ?? 0)
+          - ((adjusted?.breakdown.fuzzy ?? 0) + (adjusted?.breakdown.categoryAdjustment ?? 0)))
+        < 1e-9)
+  }
+
+  @Test("別名に一致した場合は別名のスコアを記録する")
+  func recordsAliasScore() {
+    let apps = [AppItem(name: "Visual Studio Code", path: "/Applications/VSCode.app")]
+    let index = SearchIndex(
+      apps: apps, directories: [], commands: [],
+      aliases: [ItemAlias(alias: "vc", path: "/Applications/VSCode.app")])
+    let results = SearchService().debugSearch(query: "vc", index: index, history: [])
+    #expect(results.first?.breakdown.aliasScore == SearchService.aliasExactScore)
+    #expect(results.first?.score == SearchService.aliasExactScore)
+  }
+}
+
+// MARK: - 一致した文字の位置
+
+@Suite("SearchService Matched Indices")
+struct SearchServiceMatchedIndicesTests {
+
+  @Test("名前のうち検索語に一致した文字の位置を返す")
+  func returnsMatchedIndicesForName() {
+    let apps = [AppItem(name: "Safari", path: "/Applications/Safari.app")]
+    let results = SearchService().search(
+      query: "saf", apps: apps, directories: [], commands: [], history: [])
+    let indices = results.first?.matchedIndices ?? []
+    #expect(Set([0, 1, 2]).isSubset(of: indices))
+    #expect(indices == indices.sorted())
+    #expect(indices.allSatisfy { $0 < "Safari".count })
+  }
+
+  @Test("カタカナの名前もひらがなの検索語で位置を返し、名前以外で一致した場合は空")
+  func foldsKanaAndSkipsNonNameMatches() {
+    let apps = [
+      AppItem(name: "メモ", path: "/System/Applications/Notes.app"),
+      AppItem(
+        name: "Preview", path: "/System/Applications/Preview.app", alternateNames: ["プレビュー"]),
+    ]
+    let memo = SearchService().search(
+      query: "めも", apps: apps, directories: [], commands: [], history: [])
+    #expect(memo.first?.matchedIndices == [0, 1])
+
+    let preview = SearchService().search(
+      query: "ぷれびゅー", apps: apps, directories: [], commands: [], history: [])
+    #expect(preview.first?.path == "/System/Applications/Preview.app")
+    #expect(preview.first?.matchedIndices == [])
+  }
+
+  @Test("絞り込み語を除いた残りの語で照合する")
+  func ignoresFilterWords() {
+    let directories = [
+      DirectoryItem(
+        name: "api", path: "/dev/api", finderTags: [FinderTag(name: "urgent", color: .red)])
+    ]
+    let results = SearchService().search(
+      query: "ap #urgent", apps: [], directories: directories, commands: [], history: [])
+    #expect(results.first?.matchedIndices == [0, 1])
+
+    let tagOnly = SearchService().search(
+      query: "#urgent", apps: [], directories: directories, commands: [], history: [])
+    #expect(tagOnly.first?.matchedIndices == [])
+  }
+}
@@ -0,0 +1,61 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("SearchSession")
+struct SearchSessionTests {
+
+  @MainActor
+  @Test("連続して送信した場合は最新の結果だけが反映される")
+  func onlyLatestSubmissionCompletes() async throws {
+    let session = SearchSession(debounce: .milliseconds(20))
+    var delivered: [String] = []
+
+    for name in ["s", "sa", "saf"] {
+      session.submit {
+        [SearchResult(name: name, kind: .app, score: 0)]
+      } onComplete: { results in
+        delivered.append(contentsOf: results.map(\.name))
+      }
+    }
+
+    for _ in 0..<100 where session.isPending {
+      try await Task.sleep(for: .milliseconds(10))
+    }
+    #expect(delivered == ["saf"])
+    #expect(session.isPending == false)
+  }
+
+  @MainActor
+  @Test("キャンセルした検索の結果は反映されない")
+  func cancelledSubmissionDoesNotComplete() async throws {
+    let session = SearchSession(debounce: .milliseconds(20))
+    var completed = false
+
+    session.submit {
+      [SearchResult(name: "Safari", kind: .app, score: 0)]
+    } onComplete: { _ in
+      completed = true
+    }
+    #expect(session.isPending)
+    session.cancel()
+
+    try await Task.sleep(for: .milliseconds(100))
+    #expect(completed == false)
+    #expect(session.isPending == false)
+  }
+
+  @Test("キャンセル済みのタスクでは cancellableSearch が CancellationError を投げる")
+  func cancellableSearchThrowsWhenCancelled() async {
+    let apps = (0..<100).map { AppItem(name: "App \($0)", path: "/Applications/App\($0).app") }
+    let task = Task {
+      withUnsafeCurrentTask { $0?.cancel() }
+      return try SearchService().cancellableSearch(
+        query: "app", apps: apps, directories: [], commands: [], history: [])
+    }
+    await #expect(throws: CancellationError.self) {
+      try await task.value
+    }
+  }
+}
@@ -335,6 +335,47 @@ struct SelectionHistoryTests {
     #expect(results[0].keyword == "ターミナル")
   }
 
+  // MARK: - 時刻ごとの使用回数
+
+  @Test("記録した時刻ごとの使用回数を保存・読み込みできる")
+  func hourlyCountsRoundTrip() throws {
+    let path = makeTempFilePath()
+    defer { cleanup(path) }
+
+    let nineAM = try #require(
+      Calendar.current.date(from: DateComponents(year: 2026, month: 1, day: 5, hour: 9)))
+    let history = SelectionHistory(filePath: path)
+    history.record(keyword: "sl", path: "/Applications/Slack.app", at: nineAM)
+    history.record(keyword: "sl", path: "/Applications/Slack.app", at: nineAM + 3600)
+    try history.save()
+
+    let loaded = SelectionHistory(filePath: path)
+    try loaded.load()
+    let entry = try #require(loaded.entries(for: "sl").first)
+    #expect(entry.hourlyCounts[9] == 1)
+    #expect(entry.hourlyCounts[10] == 1)
+    #expect(entry.usage(aroundHour: 9) == 2)
+    #expect(entry.usage(aroundHour: 15) == 0)
+  }
+
+  @Test("時刻ごとの使用回数がない旧形式の履歴も読み込める")
+  func legacyEntriesWithoutHourlyCounts() throws {
+    let path = makeTempFilePath()
+    defer { cleanup(path) }
+    let json = #"""
+      [{"keyword":"xc","selectedPath":"/Applications/Xcode.app","count":3,
+      "lastUsed":"2026-01-05T09:00:00Z"}]
+      """#
+    try json.write(toFile: path, atomically: true, encoding: .utf8)
+
+    let history = SelectionHistory(filePath: path)
+    try history.load()
+    let entry = try #require(history.allEntries.first)
+    #expect(entry.count == 3)
+    #expect(entry.hourlyCounts.isEmpty)
+    #expect(entry.usage(aroundHour: 9) == 0)
+  }
+
   // MARK: - 保持スコアによる削除戦略
 
   @Test("頻繁に使用されるエントリは古くても保持される")
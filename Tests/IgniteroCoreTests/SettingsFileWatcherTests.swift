@@ -0,0 +1,49 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("SettingsFileWatcher")
+@MainActor
+struct SettingsFileWatcherTests {
+  private let directory = FileManager.default.temporaryDirectory
+    .appendingPathComponent("ignitero-watcher-test-\(UUID().uuidString)")
+
+  private var fileURL: URL {
+    directory.appendingPathComponent("settings.json")
+  }
+
+  @Test("続けて届いた変更は 1 回にまとめて通知する")
+  func coalescesBurstOfChanges() async throws {
+    var count = 0
+    let watcher = SettingsFileWatcher(fileURL: fileURL, debounceInterval: .milliseconds(20)) {
+      count += 1
+    }
+
+    watcher.fileDidChange()
+    watcher.fileDidChange()
+    watcher.fileDidChange()
+    try await Task.sleep(for: .milliseconds(200))
+
+    #expect(count == 1)
+  }
+
+  @Test("一時ファイルからの置き換えによる保存を検知する")
+  func detectsAtomicReplacement() async throws {
+    defer { try? FileManager.default.removeItem(at: directory) }
+    var count = 0
+    let watcher = SettingsFileWatcher(fileURL: fileURL, debounceInterval: .milliseconds(20)) {
+      count += 1
+    }
+    watcher.start()
+    defer { watcher.stop() }
+    #expect(watcher.isWatching)
+
+    try Data("{}".utf8).write(to: fileURL, options: .atomic)
+    for _ in 0..<100 where count == 0 {
+      try await Task.sleep(for: .milliseconds(20))
+    }
+
+    #expect(count >= 1)
+  }
+}
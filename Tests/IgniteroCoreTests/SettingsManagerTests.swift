@@ -181,6 +181,154 @@ struct SettingsCodableTests {
     #expect(settings.updateCache == nil)
     #expect(settings.defaultEditor == .cursor)
     #expect(settings.defaultTerminal == .ghostty)
+    #expect(settings.windowPlacement == .followMouse)
+    #expect(settings.windowPosition == nil)
+    #expect(settings.cacheUpdate.scanTimeoutSeconds == CacheUpdateSettings.defaultScanTimeoutSeconds)
+    #expect(settings.cacheUpdate.maxConcurrentScans == CacheUpdateSettings.defaultMaxConcurrentScans)
+    #expect(settings.cacheUpdate.iconCacheMaxMegabytes == nil)
+    #expect(settings.cacheUpdate.discoverRegisteredApps == false)
+  }
+
+  @Test func appearanceDefaultsWhenMissing() throws {
+    let settings = try JSONDecoder().decode(Settings.self, from: Data("{}".utf8))
+    #expect(settings.appearance == .default)
+
+    // 一部のキーだけ書かれていても残りは既定値で補完する
+    let partial = try JSONDecoder().decode(
+      AppearanceSettings.self, from: Data(#"{"accent_color":"green","corner_radius":20}"#.utf8))
+    #expect(partial.accentColor == .green)
+    #expect(partial.cornerRadius == 20)
+    #expect(partial.windowWidth == 680)
+    #expect(partial.material == .ultraThin)
+  }
+
+  @Test func appearanceRoundTripAndClamping() throws {
+    let appearance = AppearanceSettings(
+      accentColor: .purple, windowWidth: 4000, density: .comfortable, material: .thick,
+      cornerRadius: -3, mode: .light)
+    let data = try JSONEncoder().encode(appearance)
+    let json = try #require(String(data: data, encoding: .utf8))
+    #expect(json.contains("\"window_width\":4000"))
+    #expect(try JSONDecoder().decode(AppearanceSettings.self, from: data) == appearance)
+
+    #expect(appearance.clampedWindowWidth == AppearanceSettings.windowWidthRange.upperBound)
+    #expect(appearance.clampedCornerRadius == 0)
+  }
+
+  @Test func resultSettingsDecodeAndClamp() throws {
+    let settings = try JSONDecoder().decode(Settings.self, from: Data("{}".utf8))
+    #expect(settings.results == .default)
+    #expect(settings.results.maxResults == 20)
+    #expect(settings.results.tieBreak == .frecency)
+
+    let results = try JSONDecoder().decode(
+      ResultSettings.self,
+      from: Data(#"{"max_results":500,"max_directories":5,"tie_break":"alphabetical"}"#.utf8))
+    #expect(results.clampedMaxResults == ResultSettings.maxResultsRange.upperBound)
+    #expect(results.limit(for: .directory) == 5)
+    #expect(results.limit(for: .app) == nil)
+    #expect(results.tieBreak == .alphabetical)
+    #expect(!results.groupResults)
+    #expect(results.orderedGroups == SearchResultGroup.allCases)
+
+    // 見出しの順に含まれない区分は既定の順で後ろに付ける
+    let grouped = try JSONDecoder().decode(
+      ResultSettings.self,
+      from: Data(#"{"group_results":true,"group_order":["actions","apps","actions"]}"#.utf8))
+    #expect(grouped.groupResults)
+    #expect(grouped.orderedGroups == [.actions, .apps, .projects, .commands])
+  }
+
+  @Test func restoreFocusOnHideDefaultsToTrue() throws {
+    let settings = try JSONDecoder().decode(Settings.self, from: Data("{}".utf8))
+    #expect(settings.restoreFocusOnHide)
+
+    // 移行前の形式（トップレベルの restore_focus_on_hide）も移行して読み込む
+    let disabled = try SettingsManager.decodeSettings(
+      from: Data(#"{"restore_focus_on_hide":false}"#.utf8)
+    ).settings
+    #expect(!disabled.restoreFocusOnHide)
+  }
+
+  @Test func windowBehaviorDefaultsToHiding() throws {
+    let settings = try JSONDecoder().decode(Settings.self, from: Data("{}".utf8))
+    #expect(settings.windowBehavior == .default)
+    #expect(settings.windowBehavior.hideOnFocusLoss)
+    #expect(settings.windowBehavior.hideAfterLaunch)
+    #expect(settings.windowBehavior.clampedFocusLossGracePeriod == .zero)
+
+    let custom = try JSONDecoder().decode(
+      Settings.self,
+      from: Data(
+        #"{"window_behavior":{"focus_loss_grace_period_ms":-100,"hide_after_launch":false}}"#
+          .utf8))
+    #expect(custom.windowBehavior.hideOnFocusLoss)
+    #expect(!custom.windowBehavior.hideAfterLaunch)
+    #expect(custom.windowBehavior.clampedFocusLossGracePeriod == .zero)
+  }
+
+  @Test func showDockIconDefaultsToOff() throws {
+    let settings = try JSONDecoder().decode(Settings.self, from: Data("{}".utf8))
+    #expect(!settings.windowBehavior.showDockIcon)
+
+    let custom = try JSONDecoder().decode(
+      Settings.self, from: Data(#"{"window_behavior":{"show_dock_icon":true}}"#.utf8))
+    #expect(custom.windowBehavior.showDockIcon)
+    #expect(custom.windowBehavior.hideOnFocusLoss)
+  }
+
+  @Test func imeBehaviorDefaultsToRestoreOnHide() throws {
+    let settings = try JSONDecoder().decode(Settings.self, from: Data("{}".utf8))
+    #expect(settings.windowBehavior.imeBehavior == .restoreOnHide)
+
+    let off = try JSONDecoder().decode(
+      Settings.self, from: Data(#"{"window_behavior":{"ime_behavior":"off"}}"#.utf8))
+    #expect(off.windowBehavior.imeBehavior == .off)
+    #expect(!off.windowBehavior.imeBehavior.switchesToASCII)
+    #expect(IMEBehavior.forceEnglish.switchesToASCII)
+    #expect(!IMEBehavior.forceEnglish.restoresOnHide)
+  }
+
+  @Test func updateSettingsDefaultToNotify() throws {
+    let settings = try JSONDecoder().decode(Settings.self, from: Data("{}".utf8))
+    #expect(settings.update.installMode == .notify)
+
+    let auto = try JSONDecoder().decode(
+      Settings.self, from: Data(#"{"update":{"install_mode":"auto_download"}}"#.utf8))
+    #expect(auto.update.installMode == .autoDownload)
+  }
+
+  @Test func iconCacheMaxMegabytesRoundTrip() throws {
+    let cache = CacheUpdateSettings(
+      updateOnStartup: true, autoUpdateEnabled: false, autoUpdateIntervalHours: 6,
+      iconCacheMaxMegabytes: 300)
+    let data = try JSONEncoder().encode(cache)
+    let json = try #require(String(data: data, encoding: .utf8))
+    #expect(json.contains("\"icon_cache_max_megabytes\":300"))
+    let decoded = try JSONDecoder().decode(CacheUpdateSettings.self, from: data)
+    #expect(decoded.iconCacheMaxMegabytes == 300)
+  }
+
+  @Test func discoverRegisteredAppsRoundTrip() throws {
+    let cache = CacheUpdateSettings(
+      updateOnStartup: true, autoUpdateEnabled: false, autoUpdateIntervalHours: 6,
+      discoverRegisteredApps: true)
+    let data = try JSONEncoder().encode(cache)
+    let json = try #require(String(data: data, encoding: .utf8))
+    #expect(json.contains("\"discover_registered_apps\":true"))
+    let decoded = try JSONDecoder().decode(CacheUpdateSettings.self, from: data)
+    #expect(decoded.discoverRegisteredApps)
+  }
+
+  @Test func windowPlacementRoundTrip() throws {
+    let settings = Settings(
+      windowPlacement: .rememberedPosition, windowPosition: WindowPosition(x: 120, top: 840))
+    let data = try JSONEncoder().encode(settings)
+    let json = try #require(String(data: data, encoding: .utf8))
+    #expect(json.contains("\"window_placement\":\"remembered_position\""))
+    let decoded = try JSONDecoder().decode(Settings.self, from: data)
+    #expect(decoded.windowPlacement == .rememberedPosition)
+    #expect(decoded.windowPosition == WindowPosition(x: 120, top: 840))
   }
 
   @Test func allTerminalTypes() throws {
@@ -218,9 +366,24 @@ struct SettingsCodableTests {
       let dir = try JSONDecoder().decode(RegisteredDirectory.self, from: data)
       #expect(dir.parentOpenMode == mode)
       #expect(dir.subdirsOpenMode == mode)
+      // exclude_patterns がない既存設定は空配列で補完される
+      #expect(dir.excludePatterns.isEmpty)
+      #expect(dir.priority == 0)
     }
   }
 
+  @Test func registeredDirectoryExcludePatternsRoundTrip() throws {
+    let dir = RegisteredDirectory(
+      path: "/dev", parentOpenMode: .none, subdirsOpenMode: .editor, scanForApps: false,
+      excludePatterns: ["node_modules", "target/"], priority: -3)
+    let data = try JSONEncoder().encode(dir)
+    let json = try #require(String(data: data, encoding: .utf8))
+    #expect(json.contains("exclude_patterns"))
+    #expect(json.contains("\"priority\":-3"))
+    let decoded = try JSONDecoder().decode(RegisteredDirectory.self, from: data)
+    #expect(decoded == dir)
+  }
+
   @Test func updateCacheEncodeDecode() throws {
     let cache = UpdateCache(
       latestVersion: "27.1.0",
@@ -268,6 +431,24 @@ struct SettingsManagerTests {
     #expect(manager2.settings.excludedApps == ["Xcode.app"])
   }
 
+  @Test func fileStatusReportsMissingValidAndInvalid() throws {
+    let dir = try makeTempDir()
+    defer { cleanup(dir) }
+
+    let manager = SettingsManager(configDirectory: dir)
+    #expect(manager.fileStatus() == .missing)
+
+    try manager.save()
+    #expect(manager.fileStatus() == .valid)
+
+    let filePath = dir.appendingPathComponent("settings.json")
+    try "not valid json {{{".write(to: filePath, atomically: true, encoding: .utf8)
+    guard case .invalid = manager.fileStatus() else {
+      Issue.record("壊れた設定ファイルを invalid と判定すること")
+      return
+    }
+  }
+
   @Test func loadFromNonExistentFileUsesDefaults() throws {
     let dir = try makeTempDir()
     defer { cleanup(dir) }
@@ -539,3 +720,246 @@ struct SettingsManagerTests {
     #expect(manager.settings.excludedApps == ["Feedback Assistant.app"])
   }
 }
+
+// MARK: - プロファイル
+
+@Suite("SettingsManager Profiles")
+@MainActor
+struct SettingsManagerProfileTests {
+  private let configDirectory = FileManager.default.temporaryDirectory
+    .appendingPathComponent("ignitero-profile-test-\(UUID().uuidString)")
+
+  private func makeManager() throws -> SettingsManager {
+    try FileManager.default.createDirectory(
+      at: configDirectory, withIntermediateDirectories: true)
+    return SettingsManager(configDirectory: configDirectory)
+  }
+
+  @Test("初めて作るとそれまでの設定を default として保存し、使用中にする")
+  func firstProfileKeepsCurrentSettingsAsDefault() throws {
+    let manager = try makeManager()
+    try manager.addDirectory(RegisteredDirectory(path: "/tmp/personal"))
+
+    try manager.createProfile(named: " work ")
+
+    #expect(manager.profileNames == ["default", "work"])
+    #expect(manager.settings.activeProfile == "default")
+    #expect(manager.settings.profiles[0].registeredDirectories.map(\.path) == ["/tmp/personal"])
+    #expect(throws: SettingsProfileError.duplicateName("work")) {
+      try manager.createProfile(named: "work")
+    }
+    #expect(throws: SettingsProfileError.emptyName) {
+      try manager.createProfile(named: "  ")
+    }
+  }
+
+  @Test("切り替えると使用中の設定を書き戻し、切り替え先の設定を反映する")
+  func switchingSavesAndRestoresProfileSettings() throws {
+    let manager = try makeManager()
+    try manager.createProfile(named: "work")
+    try manager.switchProfile(to: "work")
+    try manager.addDirectory(RegisteredDirectory(path: "/tmp/work"))
+    try manager.addCommand(CustomCommand(alias: "deploy", command: "make deploy"))
+
+    #expect(try manager.switchProfile(to: "default"))
+    #expect(manager.settings.registeredDirectories.isEmpty)
+    #expect(manager.settings.customCommands.isEmpty)
+
+    #expect(try manager.switchProfile(to: "work"))
+    #expect(!(try manager.switchProfile(to: "work")))
+    #expect(manager.settings.registeredDirectories.map(\.path) == ["/tmp/work"])
+    #expect(manager.settings.customCommands.map(\.alias) == ["deploy"])
+
+    let reloaded = SettingsManager(configDirectory: configDirectory)
+    try reloaded.load()
+    #expect(reloaded.settings.activeProfile == "work")
+    #expect(reloaded.profileNames == ["default", "work"])
+  }
+
+  @Test("使用中・存在しないプロファイルは削除できない")
+  func deleteRejectsActiveAndMissingProfiles() throws {
+    let manager = try makeManager()
+    try manager.createProfile(named: "work")
+
+    #expect(throws: SettingsProfileError.profileInUse("default")) {
+      try manager.deleteProfile(named: "default")
+    }
+    #expect(throws: SettingsProfileError.notFound("home")) {
+      try manager.deleteProfile(named: "home")
+    }
+    try manager.deleteProfile(named: "work")
+    #expect(manager.profileNames == ["default"])
+  }
+
+  @Test("キャッシュのパーティション名は default を既定にし、ファイル名に使えない文字を置き換える")
+  func cachePartitionNameSanitizesProfileName() {
+    #expect(SettingsProfile.cachePartitionName(for: nil) == nil)
+    #expect(SettingsProfile.cachePartitionName(for: "default") == nil)
+    #expect(SettingsProfile.cachePartitionName(for: "client/a b") == "client_a_b")
+  }
+
+  @Test("置き換え後に既存のプロファイルとキャッシュ DB のファイル名が同じになる名前は作れない")
+  func createRejectsNamesSharingCachePartition() throws {
+    let manager = try makeManager()
+    try manager.createProfile(named: "client/a")
+
+    #expect(throws: SettingsProfileError.conflictingName("client a", existing: "client/a")) {
+      try manager.createProfile(named: "client a")
+    }
+    #expect(throws: SettingsProfileError.conflictingName("Client_A", existing: "client/a")) {
+      try manager.createProfile(named: "Client_A")
+    }
+    #expect(manager.profileNames == ["default", "client/a"])
+  }
+
+  @Test("切り替えるとランチャー表示のショートカットを切り替え元のプロファイルへ書き戻す")
+  func switchingSavesLauncherShortcut() throws {
+    let manager = try makeManager()
+    try manager.createProfile(named: "work")
+    let optionSpace = LauncherShortcut(keyCode: 49, modifiers: 2048)
+
+    try manager.switchProfile(to: "work", launcherShortcut: optionSpace)
+    #expect(manager.settings.profiles[0].launcherShortcut == optionSpace)
+    #expect(manager.settings.profiles[1].launcherShortcut == nil)
+
+    // 渡さない場合は保存済みのショートカットを残す
+    try manager.switchProfile(to: "default")
+    try manager.switchProfile(to: "work")
+    #expect(manager.settings.profiles[0].launcherShortcut == optionSpace)
+  }
+}
+
+// MARK: - 外部での編集の読み直し
+
+@Suite("SettingsManager Reload From Disk")
+@MainActor
+struct SettingsManagerReloadTests {
+  private let configDirectory = FileManager.default.temporaryDirectory
+    .appendingPathComponent("ignitero-reload-test-\(UUID().uuidString)")
+
+  @Test("自分で保存した内容は変更として扱わない")
+  func ownSaveIsNotReportedAsChange() throws {
+    let manager = SettingsManager(configDirectory: configDirectory)
+    manager.settings.defaultTerminal = .ghostty
+    try manager.save()
+
+    #expect(try manager.reloadFromDisk() == nil)
+  }
+
+  @Test("外部で編集された内容を反映し、反映前の設定を返す")
+  func externalEditIsApplied() throws {
+    let manager = SettingsManager(configDirectory: configDirectory)
+    try manager.save()
+    let json = #"{ "default_terminal": "warp", "excluded_apps": ["Chess.app"] }"#
+    try Data(json.utf8).write(to: manager.fileURL)
+
+    let previous = try manager.reloadFromDisk()
+
+    #expect(previous?.defaultTerminal == .terminal)
+    #expect(manager.settings.defaultTerminal == .warp)
+    #expect(manager.settings.excludedApps == ["Chess.app"])
+    #expect(try manager.reloadFromDisk() == nil)
+  }
+
+  @Test("読めない JSON やファイルの削除では既定値に戻さない")
+  func unreadableFileKeepsCurrentSettings() throws {
+    let manager = SettingsManager(configDirectory: configDirectory)
+    manager.settings.defaultTerminal = .iterm2
+    try manager.save()
+
+    try Data("{ \"default_terminal\": ".utf8).write(to: manager.fileURL)
+    #expect(throws: DecodingError.self) { try manager.reloadFromDisk() }
+    #expect(manager.settings.defaultTerminal == .iterm2)
+
+    try FileManager.default.removeItem(at: manager.fileURL)
+    #expect(try manager.reloadFromDisk() == nil)
+    #expect(manager.settings.defaultTerminal == .iterm2)
+  }
+}
+
+// MARK: - バックアップ
+
+@Suite("SettingsManager Backups")
+@MainActor
+struct SettingsManagerBackupTests {
+  private let configDirectory = FileManager.default.temporaryDirectory
+    .appendingPathComponent("ignitero-backup-test-\(UUID().uuidString)")
+
+  @Test("内容が変わる保存のたびに直前のファイルを残し、上限を超えた古い世代は消す")
+  func rotatesBackupsUpToLimit() throws {
+    let manager = SettingsManager(configDirectory: configDirectory, backupInterval: 0)
+    try manager.save()
+    try manager.save()
+    #expect(manager.backups().isEmpty)
+
+    for limit in 1...(SettingsManager.backupLimit + 2) {
+      manager.settings.recentProjectsLimit = limit
+      try manager.save()
+    }
+
+    #expect(manager.backups().map(\.generation) == Array(1...SettingsManager.backupLimit))
+    let newest = configDirectory.appendingPathComponent("settings.json.1")
+    let latest = try JSONDecoder().decode(Settings.self, from: Data(contentsOf: newest))
+    #expect(latest.recentProjectsLimit == SettingsManager.backupLimit + 1)
+  }
+
+  @Test("間隔内の連続した保存は世代を進めず、起動後の最初の保存前の内容を残す")
+  func throttlesBackupsWithinInterval() throws {
+    let manager = SettingsManager(configDirectory: configDirectory)
+    manager.settings.recentProjectsLimit = 1
+    try manager.save()
+
+    for limit in 2...(SettingsManager.backupLimit + 2) {
+      manager.settings.recentProjectsLimit = limit
+      try manager.save()
+    }
+
+    #expect(manager.backups().map(\.generation) == [1])
+    let newest = configDirectory.appendingPathComponent("settings.json.1")
+    let backup = try JSONDecoder().decode(Settings.self, from: Data(contentsOf: newest))
+    #expect(backup.recentProjectsLimit == 1)
+
+    // 次の起動（新しいインスタンス）の最初の保存では世代を進める
+    let relaunched = SettingsManager(configDirectory: configDirectory)
+    try relaunched.load()
+    relaunched.settings.recentProjectsLimit = 0
+    try relaunched.save()
+    #expect(relaunched.backups().map(\.generation) == [1, 2])
+  }
+
+  @Test("バックアップから復元すると、復元前の設定が新しいバックアップになる")
+  func restoreKeepsPreviousSettingsAsBackup() throws {
+    let manager = SettingsManager(configDirectory: configDirectory)
+    manager.settings.defaultTerminal = .ghostty
+    try manager.save()
+    manager.settings.defaultTerminal = .warp
+    try manager.save()
+
+    let previous = try manager.restoreBackup()
+
+    #expect(previous.defaultTerminal == .warp)
+    #expect(manager.settings.defaultTerminal == .ghostty)
+    try manager.restoreBackup()
+    #expect(manager.settings.defaultTerminal == .warp)
+    #expect(throws: SettingsBackupError.notFound(generation: 4)) {
+      try manager.restoreBackup(generation: 4)
+    }
+  }
+
+  @Test("シンボリックリンクの設定ファイルはリンク先を書き換え、リンクを残す")
+  func durableWriteKeepsSymlink() throws {
+    let fm = FileManager.default
+    try fm.createDirectory(at: configDirectory, withIntermediateDirectories: true)
+    let target = configDirectory.appendingPathComponent("dotfiles-settings.json")
+    try Data("{}".utf8).write(to: target)
+    let link = configDirectory.appendingPathComponent("settings.json")
+    try fm.createSymbolicLink(at: link, withDestinationURL: target)
+
+    try SettingsManager.writeDurably(Data("{\"a\":1}".utf8), to: link)
+
+    #expect(try fm.destinationOfSymbolicLink(atPath: link.path) == target.path)
+    #expect(try String(contentsOf: target, encoding: .utf8) == "{\"a\":1}")
+    let leftovers = try fm.contentsOfDirectory(atPath: configDirectory.path)
+    #expect(!leftovers.contains { $0.hasSuffix(".tmp") })
+  }
+}
@@ -0,0 +1,90 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("SettingsMigrator")
+struct SettingsMigrationTests {
+
+  private func migratedJSON(_ json: String) throws -> [String: Any] {
+    let data = try #require(SettingsMigrator.migrate(Data(json.utf8)))
+    return try #require(try JSONSerialization.jsonObject(with: data) as? [String: Any])
+  }
+
+  @Test("Tauri 版の設定はウィンドウ位置を削除し、他の項目を保つ")
+  func migratesTauriFormat() throws {
+    let json = try migratedJSON(
+      """
+      {
+        "default_terminal": "ghostty",
+        "excluded_apps": ["Feedback Assistant.app"],
+        "main_window_position": { "x": 300, "y": 150 }
+      }
+      """)
+
+    #expect(json["main_window_position"] == nil)
+    #expect(json["default_terminal"] as? String == "ghostty")
+    #expect(json["schema_version"] as? Int == SettingsMigrator.currentVersion)
+  }
+
+  @Test("バージョンのない Swift 版の設定は restore_focus_on_hide を window_behavior へ移す")
+  func migratesUnversionedSwiftFormat() throws {
+    let data = Data(
+      #"{"restore_focus_on_hide": false, "window_behavior": {"hide_after_launch": false}}"#.utf8)
+
+    let settings = try SettingsManager.decodeSettings(from: data)
+
+    #expect(settings.migrated)
+    #expect(!settings.settings.windowBehavior.restoreFocusOnHide)
+    #expect(!settings.settings.windowBehavior.hideAfterLaunch)
+    #expect(settings.settings.schemaVersion == SettingsMigrator.currentVersion)
+  }
+
+  @Test("バージョン 1 の設定は残りの手順だけを適用する")
+  func migratesFromVersionOne() throws {
+    let json = try migratedJSON(
+      #"{"schema_version": 1, "restore_focus_on_hide": false, "main_window_position": {}}"#)
+
+    // バージョン 1 では Tauri 版の項目の削除は済んでいる扱い
+    #expect(json["main_window_position"] != nil)
+    #expect((json["window_behavior"] as? [String: Any])?["restore_focus_on_hide"] as? Bool == false)
+  }
+
+  @Test("移動先に値がある場合は移動先を優先する")
+  func keepsExistingValueAtDestination() throws {
+    let json = try migratedJSON(
+      #"{"restore_focus_on_hide": false, "window_behavior": {"restore_focus_on_hide": true}}"#)
+
+    #expect(json["restore_focus_on_hide"] == nil)
+    #expect((json["window_behavior"] as? [String: Any])?["restore_focus_on_hide"] as? Bool == true)
+  }
+
+  @Test("現在・新しいバージョンと JSON でないファイルは変換しない")
+  func skipsCurrentNewerAndInvalidFiles() throws {
+    let current = try JSONEncoder().encode(Settings.default)
+    #expect(SettingsMigrator.migrate(current) == nil)
+    let newer = #"{"schema_version": \#(SettingsMigrator.currentVersion + 1)}"#
+    #expect(SettingsMigrator.migrate(Data(newer.utf8)) == nil)
+    #expect(SettingsMigrator.migrate(Data("not json".utf8)) == nil)
+    #expect(SettingsMigrator.migrate(Data("[]".utf8)) == nil)
+  }
+
+  @Test("読み込み時に移行した設定を保存し、移行前のファイルをバックアップに残す")
+  @MainActor
+  func loadPersistsMigratedSettings() throws {
+    let dir = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-migration-test-\(UUID().uuidString)")
+    try FileManager.default.createDirectory(at: dir, withIntermediateDirectories: true)
+    defer { try? FileManager.default.removeItem(at: dir) }
+    let original = Data(#"{"restore_focus_on_hide": false}"#.utf8)
+    try original.write(to: dir.appendingPathComponent("settings.json"))
+
+    let manager = SettingsManager(configDirectory: dir)
+    try manager.load()
+
+    #expect(!manager.settings.restoreFocusOnHide)
+    let saved = try Data(contentsOf: dir.appendingPathComponent("settings.json"))
+    #expect(SettingsMigrator.migrate(saved) == nil)
+    #expect(try Data(contentsOf: dir.appendingPathComponent("settings.json.1")) == original)
+  }
+}
@@ -13,6 +13,21 @@ private func makeTempSettingsManager() throws -> SettingsManager {
   return SettingsManager(configDirectory: dir)
 }
 
+/// テスト用モック LoginItemService
+private final class MockLoginItemService: LoginItemControlling, @unchecked Sendable {
+  var enabled = false
+  var setEnabledCalls: [Bool] = []
+  var shouldThrow = false
+
+  func isEnabled() -> Bool { enabled }
+
+  func setEnabled(_ enabled: Bool) throws {
+    if shouldThrow { throw CocoaError(.featureUnsupported) }
+    setEnabledCalls.append(enabled)
+    self.enabled = enabled
+  }
+}
+
 // MARK: - SettingsTab Tests
 
 @Suite("SettingsTab Enum")
@@ -20,11 +35,12 @@ struct SettingsTabTests {
 
   @Test func allCasesExist() {
     let cases = SettingsTab.allCases
-    #expect(cases.count == 4)
+    #expect(cases.count == 5)
     #expect(cases.contains(.general))
     #expect(cases.contains(.directories))
     #expect(cases.contains(.commands))
     #expect(cases.contains(.excludedApps))
+    #expect(cases.contains(.diagnostics))
   }
 
   @Test func rawValues() {
@@ -32,6 +48,7 @@ struct SettingsTabTests {
     #expect(SettingsTab.directories.rawValue == "directories")
     #expect(SettingsTab.commands.rawValue == "commands")
     #expect(SettingsTab.excludedApps.rawValue == "excludedApps")
+    #expect(SettingsTab.diagnostics.rawValue == "diagnostics")
   }
 }
 
@@ -605,6 +622,71 @@ struct SettingsViewModelExcludedAppsTests {
   }
 }
 
+// MARK: - Launch at Login Tests
+
+@Suite("SettingsViewModel Launch at Login")
+struct SettingsViewModelLaunchAtLoginTests {
+
+  @MainActor
+  @Test("有効化すると登録して launch_at_login を保存する")
+  func enableRegistersAndPersists() throws {
+    let dir = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-settings-vm-\(UUID().uuidString)")
+    let manager = SettingsManager(configDirectory: dir)
+    let service = MockLoginItemService()
+    let vm = SettingsViewModel(settingsManager: manager, loginItemService: service)
+
+    try vm.setLaunchAtLogin(true)
+
+    #expect(service.setEnabledCalls == [true])
+    #expect(vm.launchAtLogin == true)
+    let reloaded = SettingsManager(configDirectory: dir)
+    try reloaded.load()
+    #expect(reloaded.settings.launchAtLogin == true)
+  }
+
+  @MainActor
+  @Test("登録に失敗した場合は設定を変更しない")
+  func failureKeepsSetting() throws {
+    let manager = try makeTempSettingsManager()
+    let service = MockLoginItemService()
+    service.shouldThrow = true
+    let vm = SettingsViewModel(settingsManager: manager, loginItemService: service)
+
+    #expect(throws: (any Error).self) {
+      try vm.setLaunchAtLogin(true)
+    }
+    #expect(vm.launchAtLogin == false)
+  }
+
+  @MainActor
+  @Test("設定が有効で未登録なら起動時に再登録する")
+  func syncReRegistersWhenMissing() throws {
+    let manager = try makeTempSettingsManager()
+    manager.settings.launchAtLogin = true
+    let service = MockLoginItemService()
+    let vm = SettingsViewModel(settingsManager: manager, loginItemService: service)
+
+    try vm.syncLaunchAtLogin()
+
+    #expect(service.setEnabledCalls == [true])
+  }
+
+  @MainActor
+  @Test("既存の登録状態を設定へ取り込む")
+  func syncAdoptsExistingRegistration() throws {
+    let manager = try makeTempSettingsManager()
+    let service = MockLoginItemService()
+    service.enabled = true
+    let vm = SettingsViewModel(settingsManager: manager, loginItemService: service)
+
+    try vm.syncLaunchAtLogin()
+
+    #expect(service.setEnabledCalls.isEmpty)
+    #expect(manager.settings.launchAtLogin == true)
+  }
+}
+
 // MARK: - onSettingsChanged Callback Tests
 
 @Suite("SettingsViewModel onSettingsChanged Callback")
@@ -635,6 +717,19 @@ struct SettingsViewModelOnSettingsChangedTests {
     #expect(callbackCount == 1)
   }
 
+  @MainActor
+  @Test func setLanguageSavesAndCallsOnSettingsChanged() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var callbackCount = 0
+    vm.onSettingsChanged = { _ in callbackCount += 1 }
+
+    try vm.setLanguage(.en)
+    #expect(manager.settings.language == .en)
+    #expect(callbackCount == 1)
+  }
+
   @MainActor
   @Test func setCacheUpdateSettingsCallsOnSettingsChangedFromSuite() throws {
     let manager = try makeTempSettingsManager()
@@ -703,6 +798,263 @@ struct SettingsViewModelOnSettingsChangedTests {
     #expect(changes == [.reloadOnly, .reloadOnly])
   }
 
+  @MainActor
+  @Test("ディレクトリの再スキャン要求は対象パス付きで通知し、設定は変更しない")
+  func refreshDirectoryNotifiesPath() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+    try vm.addDirectory(
+      path: "/Users/dev/proj", parentOpenMode: .editor, subdirsOpenMode: .editor,
+      scanForApps: false)
+    let before = manager.settings.registeredDirectories
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    vm.refreshDirectory(at: 0)
+    vm.refreshDirectory(at: 5)
+
+    #expect(changes == [.directoryRefreshRequested(path: "/Users/dev/proj")])
+    #expect(manager.settings.registeredDirectories == before)
+  }
+
+  @MainActor
+  @Test("最近のプロジェクト件数は 0〜20 に丸めて保存し reloadOnly を通知する")
+  func recentProjectsLimitIsClampedAndNotifiesReloadOnly() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.setRecentProjectsLimit(50)
+    #expect(manager.settings.recentProjectsLimit == 20)
+    try vm.setRecentProjectsLimit(-1)
+    #expect(manager.settings.recentProjectsLimit == 0)
+    #expect(changes == [.reloadOnly, .reloadOnly])
+  }
+
+  @MainActor
+  @Test("最近開いたフォルダの取り込みをエディタごとに切り替えて cacheInvalidated を通知する")
+  func importsRecentWorkspacesPerEditor() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.setImportsRecentWorkspaces(true, for: .cursor)
+    try vm.setImportsRecentWorkspaces(true, for: .vscode)
+    try vm.setImportsRecentWorkspaces(true, for: .cursor)
+    #expect(manager.settings.recentWorkspaceEditors == [.cursor, .vscode])
+    try vm.setImportsRecentWorkspaces(false, for: .cursor)
+    #expect(manager.settings.recentWorkspaceEditors == [.vscode])
+    #expect(changes == [.cacheInvalidated, .cacheInvalidated, .cacheInvalidated])
+  }
+
+  @MainActor
+  @Test("同じ別名を設定すると割り当て先を置き換え、削除できる")
+  func itemAliasSetReplaceAndRemove() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.setItemAlias("VSC", for: "/Applications/Cursor.app")
+    try vm.setItemAlias("vsc", for: "/Applications/Visual Studio Code.app")
+    #expect(manager.settings.itemAliases.count == 1)
+    #expect(manager.settings.itemAliases.first?.alias == "vsc")
+    #expect(manager.settings.itemAliases.first?.path == "/Applications/Visual Studio Code.app")
+
+    try vm.setItemAlias("dl", for: "~/Downloads")
+    #expect(manager.settings.itemAliases.last?.path == NSHomeDirectory() + "/Downloads")
+
+    try vm.setItemAlias("has space", for: "/tmp")
+    #expect(manager.settings.itemAliases.count == 2)
+
+    try vm.removeItemAlias("vsc")
+    #expect(manager.settings.itemAliases.map(\.alias) == ["dl"])
+    #expect(changes == [.reloadOnly, .reloadOnly, .reloadOnly, .reloadOnly])
+  }
+
+  @MainActor
+  @Test("項目の非表示と再表示を保存し、重複して追加しない")
+  func excludeAndRestoreItems() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.excludeItem(path: "/Applications/Helper.app")
+    try vm.excludeItem(path: "/Applications/Helper.app")
+    #expect(vm.excludedItems == ["/Applications/Helper.app"])
+
+    try vm.restoreExcludedItem(path: "/Applications/Helper.app")
+    #expect(vm.excludedItems.isEmpty)
+    #expect(changes == [.reloadOnly, .reloadOnly])
+  }
+
+  @MainActor
+  @Test("クイックリンクを追加・更新・削除し、不正な内容や重複した名前は保存しない")
+  func quickLinkCRUD() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.addQuickLink(name: "mdn", url: "https://developer.mozilla.org/search?q={query}")
+    try vm.addQuickLink(name: "MDN", url: "https://example.com/{query}")
+    try vm.addQuickLink(name: "bad link", url: "https://example.com")
+    try vm.addQuickLink(name: "noscheme", url: "example.com")
+    #expect(vm.quickLinks.map(\.name) == ["mdn"])
+    #expect(vm.quickLinks.first?.icon == nil)
+
+    var link = try #require(vm.quickLinks.first)
+    link.icon = "book"
+    try vm.updateQuickLink(link)
+    #expect(manager.settings.quickLinks.first?.icon == "book")
+
+    try vm.removeQuickLink(id: link.id)
+    #expect(vm.quickLinks.isEmpty)
+    #expect(changes == [.reloadOnly, .reloadOnly, .reloadOnly])
+  }
+
+  @MainActor
+  @Test("拡張子にアプリを割り当て、同じ拡張子は置き換え、不正な内容は保存しない")
+  func fileOpenerSetAndRemove() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.setFileOpener(fileExtension: ".md", application: "Typora")
+    try vm.setFileOpener(fileExtension: "MD", application: " Obsidian ")
+    try vm.setFileOpener(fileExtension: "sketch", application: "")
+    try vm.setFileOpener(fileExtension: "", application: "Sketch")
+    #expect(vm.fileOpeners.map(\.fileExtension) == ["md"])
+    #expect(manager.settings.fileOpeners.first?.application == "Obsidian")
+
+    let opener = try #require(vm.fileOpeners.first)
+    try vm.removeFileOpener(id: opener.id)
+    #expect(vm.fileOpeners.isEmpty)
+    #expect(changes == [.reloadOnly, .reloadOnly, .reloadOnly])
+  }
+
+  @MainActor
+  @Test("ローマ字検索の切り替えを保存して reloadOnly を通知する")
+  func setTransliterationEnabledSavesAndNotifies() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.setTransliterationEnabled(true)
+    #expect(manager.settings.transliterationEnabled == true)
+    #expect(changes == [.reloadOnly])
+  }
+
+  @MainActor
+  @Test("全文検索の切り替えを保存して reloadOnly を通知する")
+  func setFullTextSearchEnabledSavesAndNotifies() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.setFullTextSearchEnabled(true)
+    #expect(manager.settings.fullTextSearchEnabled == true)
+    #expect(changes == [.reloadOnly])
+  }
+
+  @MainActor
+  @Test("検索結果の設定を保存して reloadOnly を通知する")
+  func setResultSettingsSavesAndNotifies() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.setResultSettings(ResultSettings(maxResults: 10, maxApps: 3))
+    #expect(manager.settings.results.maxResults == 10)
+    #expect(manager.settings.results.maxApps == 3)
+    #expect(changes == [.reloadOnly])
+  }
+
+  @MainActor
+  @Test("リリースチャンネルを変更するとキャッシュを無効にして再確認を通知する")
+  func setUpdateSettingsChannelChangeInvalidatesCache() throws {
+    let manager = try makeTempSettingsManager()
+    manager.settings.updateCache = UpdateCache(latestVersion: "2.0.0", checkedAt: Date())
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.setUpdateSettings(UpdateSettings(installMode: .autoDownload))
+    #expect(manager.settings.updateCache?.checkedAt != nil)
+    try vm.setUpdateSettings(UpdateSettings(installMode: .autoDownload, releaseChannel: .beta))
+    #expect(manager.settings.update.releaseChannel == .beta)
+    #expect(manager.settings.updateCache?.checkedAt == nil)
+    #expect(changes == [.reloadOnly, .releaseChannelChanged])
+  }
+
+  @MainActor
+  @Test("外観設定を保存して reloadOnly を通知する")
+  func setAppearanceSavesAndNotifies() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.setAppearance(AppearanceSettings(accentColor: .blue, density: .compact, mode: .dark))
+    #expect(manager.settings.appearance.accentColor == .blue)
+    #expect(manager.settings.appearance.density == .compact)
+    #expect(manager.settings.appearance.mode == .dark)
+    #expect(changes == [.reloadOnly])
+  }
+
+  @MainActor
+  @Test("ログレベルを保存してログファイルへ即座に反映する")
+  func setLogLevelAppliesToLogFile() throws {
+    let manager = try makeTempSettingsManager()
+    let logFile = LogFile(
+      directory: FileManager.default.temporaryDirectory
+        .appendingPathComponent("ignitero-log-test-\(UUID().uuidString)"))
+    let vm = SettingsViewModel(settingsManager: manager, logFile: logFile)
+
+    try vm.setLogLevel(.debug)
+    logFile.write(.debug, category: "Test", message: "verbose")
+
+    #expect(manager.settings.logLevel == .debug)
+    #expect(vm.recentLogs().hasSuffix("[DEBUG] [Test] verbose"))
+  }
+
+  @MainActor
+  @Test("位置の記憶以外に切り替えると保存済みの位置を破棄する")
+  func changingPlacementFromRememberedClearsPosition() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.setWindowPlacement(.rememberedPosition)
+    manager.settings.windowPosition = WindowPosition(x: 100, top: 800)
+    try vm.setWindowPlacement(.activeDisplay)
+
+    #expect(manager.settings.windowPlacement == .activeDisplay)
+    #expect(manager.settings.windowPosition == nil)
+    #expect(changes == [.reloadOnly, .reloadOnly])
+  }
+
   @MainActor
   @Test("キャッシュ更新設定変更は updateScheduleChanged を通知する")
   func cacheUpdateSettingsNotifyUpdateScheduleChanged() throws {
@@ -736,6 +1088,28 @@ struct SettingsViewModelOnSettingsChangedTests {
     #expect(changes == [.reloadOnly, .reloadOnly, .reloadOnly])
   }
 
+  @MainActor
+  @Test("追加ホットキーの追加・更新・削除は hotkeysChanged を通知する")
+  func hotkeyBindingCRUDNotifiesHotkeysChanged() throws {
+    let manager = try makeTempSettingsManager()
+    let vm = SettingsViewModel(settingsManager: manager)
+
+    var changes: [SettingsChange] = []
+    vm.onSettingsChanged = { changes.append($0) }
+
+    try vm.addHotkeyBinding(shortcut: "cmd+shift+p", mode: .directories)
+    var binding = manager.settings.hotkeyBindings[0]
+    binding.mode = .apps
+    try vm.updateHotkeyBinding(at: 0, binding)
+    #expect(manager.settings.hotkeyBindings[0].mode == .apps)
+    try vm.removeHotkeyBinding(at: 0)
+    // 範囲外インデックスは無視され通知も行わない
+    try vm.removeHotkeyBinding(at: 5)
+
+    #expect(manager.settings.hotkeyBindings.isEmpty)
+    #expect(changes == [.hotkeysChanged, .hotkeysChanged, .hotkeysChanged])
+  }
+
   @MainActor
   @Test("ディレクトリの追加・更新・削除は cacheInvalidated を通知する")
   func directoryCRUDNotifiesCacheInvalidated() throws {
@@ -0,0 +1,40 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("SystemControl")
+struct SystemControlTests {
+
+  @Test("識別子から操作を復元できる")
+  func restoresControlFromIdentifier() {
+    for control in SystemControl.allCases {
+      #expect(SystemControl(identifier: control.identifier) == control)
+    }
+    #expect(SystemControl.toggleMute.identifier == "system-control://toggle_mute")
+    #expect(SystemControl(identifier: "system-control://shutdown") == nil)
+    #expect(SystemControl(identifier: "toggle_mute") == nil)
+  }
+
+  @Test("おやすみモード以外は AppleScript で実行する")
+  func usesAppleScriptExceptForDoNotDisturb() {
+    for control in SystemControl.allCases where control != .toggleDoNotDisturb {
+      #expect(control.appleScript != nil)
+    }
+    #expect(SystemControl.toggleDoNotDisturb.appleScript == nil)
+    #expect(
+      SystemControl.toggleDarkMode.appleScript
+        == """
+        tell application "System Events" to tell appearance preferences \
+        to set dark mode to not dark mode
+        """)
+  }
+
+  @Test("おやすみモードはショートカットを作成済みの場合のみ出す")
+  func doNotDisturbRequiresShortcut() {
+    #expect(!SystemControl.available(shortcutNames: []).contains(.toggleDoNotDisturb))
+    #expect(
+      SystemControl.available(shortcutNames: ["Other", SystemControl.doNotDisturbShortcutName])
+        == SystemControl.allCases)
+  }
+}
@@ -0,0 +1,56 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("AutoUpdateDeferral")
+struct AutoUpdateDeferralTests {
+  private let settings = CacheUpdateSettings(
+    updateOnStartup: true, autoUpdateEnabled: true, autoUpdateIntervalHours: 1)
+
+  @Test("バッテリー駆動でしきい値未満の場合だけ見送る")
+  func lowBatteryOnlyOnBatteryPower() {
+    let low = SystemLoadSnapshot(isOnBattery: true, batteryPercent: 29)
+    #expect(AutoUpdateDeferral.evaluate(low, settings: settings) == .lowBattery(percent: 29))
+
+    let charging = SystemLoadSnapshot(isOnBattery: false, batteryPercent: 10)
+    #expect(AutoUpdateDeferral.evaluate(charging, settings: settings) == nil)
+
+    let enough = SystemLoadSnapshot(isOnBattery: true, batteryPercent: 30)
+    #expect(AutoUpdateDeferral.evaluate(enough, settings: settings) == nil)
+
+    var disabled = settings
+    disabled.skipOnLowBattery = false
+    #expect(AutoUpdateDeferral.evaluate(low, settings: disabled) == nil)
+  }
+
+  @Test("負荷が高い・温度で抑制されている場合は見送る")
+  func heavyLoadOrThermalPressure() {
+    let busy = SystemLoadSnapshot(loadPerCore: 0.9)
+    #expect(AutoUpdateDeferral.evaluate(busy, settings: settings) == .heavyLoad(loadPerCore: 0.9))
+
+    let hot = SystemLoadSnapshot(loadPerCore: 0.1, thermalState: .serious)
+    #expect(AutoUpdateDeferral.evaluate(hot, settings: settings) == .heavyLoad(loadPerCore: 0.1))
+
+    let idle = SystemLoadSnapshot(loadPerCore: 0.2, thermalState: .fair)
+    #expect(AutoUpdateDeferral.evaluate(idle, settings: settings) == nil)
+
+    var disabled = settings
+    disabled.skipUnderHeavyLoad = false
+    #expect(AutoUpdateDeferral.evaluate(busy, settings: disabled) == nil)
+  }
+
+  @Test("以前の設定ファイルでは見送りの設定が既定値になる")
+  func decodesDefaults() throws {
+    let json = Data(
+      #"{"update_on_startup":true,"auto_update_enabled":true,"auto_update_interval_hours":2}"#
+        .utf8)
+    let decoded = try JSONDecoder().decode(CacheUpdateSettings.self, from: json)
+
+    #expect(decoded.skipOnLowBattery)
+    #expect(
+      decoded.lowBatteryThresholdPercent
+        == CacheUpdateSettings.defaultLowBatteryThresholdPercent)
+    #expect(decoded.skipUnderHeavyLoad)
+  }
+}
@@ -0,0 +1,79 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("SystemSettingsProvider")
+struct SystemSettingsProviderTests {
+
+  private func makeTempDir() throws -> String {
+    let dir = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-settings-panes-\(UUID().uuidString)")
+    try FileManager.default.createDirectory(at: dir, withIntermediateDirectories: true)
+    return dir.path
+  }
+
+  /// Info.plist だけを持つ擬似バンドルを作成する
+  private func createBundle(at directory: String, name: String, info: [String: Any]) throws {
+    let contents = (directory as NSString).appendingPathComponent("\(name)/Contents")
+    try FileManager.default.createDirectory(atPath: contents, withIntermediateDirectories: true)
+    let data = try PropertyListSerialization.data(fromPropertyList: info, format: .xml, options: 0)
+    try data.write(
+      to: URL(fileURLWithPath: (contents as NSString).appendingPathComponent("Info.plist")))
+  }
+
+  @Test("主なパネルはシステム設定の URL で開く")
+  func curatedPanesHaveSettingsURLs() {
+    let ids = SystemSettingsPane.curated.map(\.id)
+    #expect(Set(ids).count == ids.count)
+    #expect(
+      SystemSettingsPane.curated.allSatisfy {
+        $0.url?.absoluteString == "x-apple.systempreferences:\($0.id)"
+      })
+  }
+
+  @Test("設定拡張と環境設定パネルを探索し、一覧にないものだけ追加する")
+  func addsDiscoveredPanesAfterCuratedOnes() async throws {
+    let extensions = try makeTempDir()
+    let prefPanes = try makeTempDir()
+    defer {
+      try? FileManager.default.removeItem(atPath: extensions)
+      try? FileManager.default.removeItem(atPath: prefPanes)
+    }
+    let settingsExtension = ["EXExtensionPointIdentifier": "com.apple.Settings.extension.ui"]
+    try createBundle(
+      at: extensions, name: "Game Center.appex",
+      info: [
+        "CFBundleIdentifier": "com.apple.Game-Center-Settings.extension",
+        "CFBundleDisplayName": "Game Center",
+        "EXAppExtensionAttributes": settingsExtension,
+      ])
+    try createBundle(
+      at: extensions, name: "Displays.appex",
+      info: [
+        "CFBundleIdentifier": "com.apple.Displays-Settings.extension",
+        "CFBundleDisplayName": "Displays",
+        "EXAppExtensionAttributes": settingsExtension,
+      ])
+    try createBundle(
+      at: extensions, name: "Widget.appex",
+      info: [
+        "CFBundleIdentifier": "com.apple.widget",
+        "CFBundleDisplayName": "Widget",
+        "EXAppExtensionAttributes": ["EXExtensionPointIdentifier": "com.apple.widgetkit"],
+      ])
+    try createBundle(
+      at: prefPanes, name: "Java.prefPane",
+      info: [
+        "CFBundleIdentifier": "com.oracle.java.JavaControlPanel", "NSPrefPaneIconLabel": "Java",
+      ])
+
+    let provider = SystemSettingsProvider(
+      extensionDirectories: [extensions], preferencePaneDirectories: [prefPanes])
+    let panes = await provider.panes()
+
+    let curatedCount = SystemSettingsPane.curated.count
+    #expect(panes.prefix(curatedCount).map(\.id) == SystemSettingsPane.curated.map(\.id))
+    #expect(panes.dropFirst(curatedCount).map(\.name) == ["Game Center", "Java"])
+  }
+}
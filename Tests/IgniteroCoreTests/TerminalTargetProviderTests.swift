@@ -0,0 +1,112 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("SSHConfigParser")
+struct SSHConfigParserTests {
+
+  private func makeTempDir() throws -> String {
+    let dir = FileManager.default.temporaryDirectory
+      .appendingPathComponent("ignitero-ssh-config-\(UUID().uuidString)")
+    try FileManager.default.createDirectory(at: dir, withIntermediateDirectories: true)
+    return dir.path
+  }
+
+  @Test("Host の別名を記述順に返し、ワイルドカードと否定は除く")
+  func parsesHostAliases() {
+    let config = """
+      # 共通設定
+      Host *
+        ServerAliveInterval 60
+
+      Host prod-web prod-web-2 !prod-db
+        HostName 10.0.0.5
+        User deploy
+        HostName 10.0.0.6
+
+      Host=staging  # 行末コメント
+        hostname = staging.example.com
+
+      Match host *.internal
+        User admin
+
+      Host "my server" web-??
+      """
+
+    let hosts = SSHConfigParser.hosts(in: config)
+
+    #expect(
+      hosts == [
+        TerminalTarget(kind: .sshHost, name: "prod-web", detail: "deploy@10.0.0.5"),
+        TerminalTarget(kind: .sshHost, name: "prod-web-2", detail: "deploy@10.0.0.5"),
+        TerminalTarget(kind: .sshHost, name: "staging", detail: "staging.example.com"),
+        TerminalTarget(kind: .sshHost, name: "my server"),
+      ])
+  }
+
+  @Test("Include のファイルをその位置に展開し、ワイルドカードは名前順に読む")
+  func followsIncludes() throws {
+    let dir = try makeTempDir()
+    defer { try? FileManager.default.removeItem(atPath: dir) }
+    let configDir = (dir as NSString).appendingPathComponent("config.d")
+    try FileManager.default.createDirectory(atPath: configDir, withIntermediateDirectories: true)
+    try "Host b-host\n".write(
+      toFile: (configDir as NSString).appendingPathComponent("b.conf"), atomically: true,
+      encoding: .utf8)
+    try "Host a-host\nHost first\n".write(
+      toFile: (configDir as NSString).appendingPathComponent("a.conf"), atomically: true,
+      encoding: .utf8)
+    let configPath = (dir as NSString).appendingPathComponent("config")
+    try "Host first\nInclude config.d/*.conf missing.conf\nHost last\n".write(
+      toFile: configPath, atomically: true, encoding: .utf8)
+
+    let hosts = SSHConfigParser.hosts(atPath: configPath)
+
+    #expect(hosts.map(\.name) == ["first", "a-host", "b-host", "last"])
+  }
+
+  @Test("設定ファイルがない場合は空")
+  func returnsEmptyForMissingFile() {
+    #expect(SSHConfigParser.hosts(atPath: "/nonexistent/ssh/config").isEmpty)
+  }
+}
+
+@Suite("TmuxConfigParser")
+struct TmuxConfigParserTests {
+
+  @Test("new-session の -s で指定したセッション名を返す")
+  func parsesSessionNames() {
+    let config = """
+      set -g mouse on
+      new-session -d -s main
+      new -s"work" -n editor \\; split-window -h
+      # new-session -s commented
+      new -A -s main ; new-session -sscratch
+      bind S new-session -s other
+      """
+
+    #expect(TmuxConfigParser.sessions(in: config).map(\.name) == ["main", "work", "scratch"])
+  }
+}
+
+@Suite("TerminalTarget")
+struct TerminalTargetTests {
+
+  @Test("接続先ごとのコマンドを組み立て、特殊文字を含む名前はクォートする")
+  func buildsCommands() {
+    #expect(TerminalTarget(kind: .sshHost, name: "prod-web").command == "ssh prod-web")
+    #expect(TerminalTarget(kind: .sshHost, name: "my server").command == "ssh 'my server'")
+    #expect(
+      TerminalTarget(kind: .tmuxSession, name: "main").command == "tmux new-session -A -s main")
+    #expect(TerminalTarget(kind: .sshHost, name: "it's").command == #"ssh 'it'\''s'"#)
+  }
+
+  @Test("識別子で接続先を他の種別と区別する")
+  func recognizesIdentifiers() {
+    #expect(TerminalTarget(kind: .sshHost, name: "prod-web").identifier == "ssh://prod-web")
+    #expect(TerminalTarget.isIdentifier("tmux://main"))
+    #expect(!TerminalTarget.isIdentifier("command://1234"))
+    #expect(!TerminalTarget.isIdentifier("/Users/dev/ssh"))
+  }
+}
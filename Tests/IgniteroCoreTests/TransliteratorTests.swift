@@ -0,0 +1,28 @@
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("ICUTransliterator")
+struct ICUTransliteratorTests {
+
+  private let transliterator = ICUTransliterator()
+
+  @Test("漢字はピンインの連結と頭文字をキーにする")
+  func pinyinKeys() {
+    #expect(transliterator.searchKeys(for: "微信") == ["weixin", "wx"])
+  }
+
+  @Test("ハングルは ASCII のローマ字表記をキーにする")
+  func hangulKeys() throws {
+    let key = try #require(transliterator.searchKeys(for: "카카오톡").first)
+    #expect(key.hasPrefix("kakao"))
+    #expect(key.allSatisfy(\.isASCII))
+  }
+
+  @Test("対象外の文字だけの名前はキーを生成しない")
+  func ignoresLatinAndKana() {
+    #expect(transliterator.searchKeys(for: "Safari").isEmpty)
+    #expect(transliterator.searchKeys(for: "メモ").isEmpty)
+  }
+}
@@ -87,6 +87,26 @@ struct GitHubReleaseModelTests {
 
     #expect(release.tagName == "v2.0.0-beta.1")
     #expect(release.prerelease == true)
+    #expect(release.assets.isEmpty)
+  }
+
+  @Test func selectsArchiveAndChecksumAssets() throws {
+    let json = """
+      {
+        "tag_name": "v2.0.0",
+        "prerelease": false,
+        "html_url": "https://github.com/test/releases/tag/v2.0.0",
+        "assets": [
+          {"name": "notes.txt", "browser_download_url": "https://example.com/notes.txt"},
+          {"name": "IgniteroLauncher.zip.sha256", "browser_download_url": "https://example.com/zip.sha256"},
+          {"name": "IgniteroLauncher.zip", "browser_download_url": "https://example.com/app.zip"}
+        ]
+      }
+      """
+    let release = try JSONDecoder().decode(GitHubRelease.self, from: Data(json.utf8))
+
+    #expect(release.archiveAsset?.browserDownloadURL == "https://example.com/app.zip")
+    #expect(release.checksumAsset?.browserDownloadURL == "https://example.com/zip.sha256")
   }
 }
 
@@ -137,6 +157,20 @@ struct UpdateCacheModelTests {
     #expect(decoded.downloadURL == original.downloadURL)
   }
 
+  @Test func assetURLsEncodeAndDecode() throws {
+    let original = UpdateCache(
+      latestVersion: "3.0.0", assetURL: "https://example.com/app.zip",
+      checksumURL: "https://example.com/app.zip.sha256",
+      signatureURL: "https://example.com/app.zip.sig")
+    let data = try JSONEncoder().encode(original)
+    #expect(try JSONDecoder().decode(UpdateCache.self, from: data) == original)
+
+    // アセット URL のない古いキャッシュも読み込める
+    let legacy = try JSONDecoder().decode(
+      UpdateCache.self, from: Data(#"{"latest_version":"1.0.0"}"#.utf8))
+    #expect(legacy.assetURL == nil)
+  }
+
   @Test func equalityIncludesDownloadURL() {
     let a = UpdateCache(latestVersion: "1.0.0", downloadURL: "https://example.com/a")
     let b = UpdateCache(latestVersion: "1.0.0", downloadURL: "https://example.com/b")
@@ -253,6 +287,29 @@ struct VersionComparisonTests {
     // プレリリースのコアは元バージョンなので、次のパッチ版の方が新しい。
     #expect(VersionComparator.isNewer("1.2.1", than: "1.2.0-beta.1") == true)
   }
+
+  @Test func releaseIsNewerThanItsPrerelease() {
+    #expect(VersionComparator.isNewer("1.2.0", than: "1.2.0-beta.1") == true)
+    #expect(VersionComparator.isNewer("1.2.0-rc.1", than: "1.2.0-beta.9") == true)
+  }
+
+  @Test func prereleaseNumericIdentifiersCompareNumerically() {
+    // 文字列比較では "10" < "2" になるが、数値として比較する
+    #expect(VersionComparator.isNewer("0.2.0-beta.10", than: "0.2.0-beta.2") == true)
+    #expect(VersionComparator.isNewer("0.2.0-beta.2", than: "0.2.0-beta.10") == false)
+  }
+
+  @Test func prereleaseIdentifierPrecedenceFollowsSemVer() {
+    // SemVer 2.0 の例: alpha < alpha.1 < alpha.beta < beta < beta.2 < beta.11 < rc.1
+    let ordered = [
+      "1.0.0-alpha", "1.0.0-alpha.1", "1.0.0-alpha.beta", "1.0.0-beta", "1.0.0-beta.2",
+      "1.0.0-beta.11", "1.0.0-rc.1", "1.0.0",
+    ]
+    #expect(ordered.map(SemanticVersion.init) == ordered.map(SemanticVersion.init).sorted())
+    for (older, newer) in zip(ordered, ordered.dropFirst()) {
+      #expect(VersionComparator.isNewer(newer, than: older))
+    }
+  }
 }
 
 // MARK: - UpdateChecker 新バージョン検出テスト
@@ -285,6 +342,59 @@ struct UpdateCheckerNewVersionTests {
     #expect(result?.downloadURL == "https://github.com/test/releases/tag/v99.0.0")
   }
 
+  @Test func resultIncludesInstallableAssetsAndCachesThem() async {
+    let mockSession = MockURLSession()
+    var release = makeRelease(tagName: "v99.0.0")
+    release["assets"] = [
+      ["name": "IgniteroLauncher.zip", "browser_download_url": "https://example.com/app.zip"],
+      [
+        "name": "IgniteroLauncher.zip.sha256",
+        "browser_download_url": "https://example.com/app.zip.sha256",
+      ],
+      [
+        "name": "IgniteroLauncher.zip.sig",
+        "browser_download_url": "https://example.com/app.zip.sig",
+      ],
+    ]
+    mockSession.dataToReturn = makeReleasesJSON([release])
+
+    let settingsManager = SettingsManager(configDirectory: makeTempConfigDir())
+    let checker = UpdateChecker(
+      session: mockSession, settingsManager: settingsManager, owner: "test", repo: "test-repo")
+
+    let result = await checker.checkForUpdate(currentVersion: "1.0.0")
+    #expect(result?.assetURL == "https://example.com/app.zip")
+    #expect(result?.isInstallable == true)
+
+    // キャッシュから復元した結果もインストールできる
+    let cached = await checker.checkForUpdate(currentVersion: "1.0.0")
+    #expect(cached?.checksumURL == "https://example.com/app.zip.sha256")
+    #expect(cached?.signatureURL == "https://example.com/app.zip.sig")
+    #expect(cached?.isInstallable == true)
+  }
+
+  @Test func releaseWithoutSignatureIsNotInstallable() async {
+    let mockSession = MockURLSession()
+    var release = makeRelease(tagName: "v99.0.0")
+    release["assets"] = [
+      ["name": "IgniteroLauncher.zip", "browser_download_url": "https://example.com/app.zip"],
+      [
+        "name": "IgniteroLauncher.zip.sha256",
+        "browser_download_url": "https://example.com/app.zip.sha256",
+      ],
+    ]
+    mockSession.dataToReturn = makeReleasesJSON([release])
+
+    let settingsManager = SettingsManager(configDirectory: makeTempConfigDir())
+    let checker = UpdateChecker(
+      session: mockSession, settingsManager: settingsManager, owner: "test", repo: "test-repo")
+
+    let result = await checker.checkForUpdate(currentVersion: "1.0.0")
+    #expect(result?.assetURL == "https://example.com/app.zip")
+    #expect(result?.signatureURL == nil)
+    #expect(result?.isInstallable == false)
+  }
+
   @Test func returnsNilForOlderVersion() async {
     let mockSession = MockURLSession()
     mockSession.dataToReturn = makeReleasesJSON([
@@ -347,6 +457,27 @@ struct UpdateCheckerPrereleaseTests {
     #expect(result == nil)
   }
 
+  @Test func betaChannelIncludesPrereleases() async {
+    let mockSession = MockURLSession()
+    mockSession.dataToReturn = makeReleasesJSON([
+      makeRelease(tagName: "v99.0.0-beta.2", prerelease: true),
+      makeRelease(tagName: "v99.0.0-beta.10", prerelease: true),
+      makeRelease(tagName: "v98.0.0"),
+    ])
+
+    let settingsManager = SettingsManager(configDirectory: makeTempConfigDir())
+    settingsManager.settings.update.releaseChannel = .beta
+    let checker = UpdateChecker(
+      session: mockSession, settingsManager: settingsManager, owner: "test", repo: "test-repo")
+
+    let result = await checker.checkForUpdate(currentVersion: "1.0.0")
+    #expect(result?.latestVersion == "99.0.0-beta.10")
+
+    // 安定版チャンネルへ戻すと、キャッシュしたプレリリースは通知しない
+    settingsManager.settings.update.releaseChannel = .stable
+    #expect(await checker.checkForUpdate(currentVersion: "1.0.0") == nil)
+  }
+
   @Test func selectsStableOverPrerelease() async {
     let mockSession = MockURLSession()
     mockSession.dataToReturn = makeReleasesJSON([
@@ -737,6 +868,120 @@ struct UpdateCheckerErrorHandlingTests {
     #expect(result != nil)
     #expect(result?.latestVersion == "2.0.0")
   }
+
+  @Test func offlineErrorIsRecordedWithoutBackoff() async {
+    let mockSession = MockURLSession()
+    mockSession.errorToThrow = URLError(.notConnectedToInternet)
+
+    let settingsManager = SettingsManager(configDirectory: makeTempConfigDir())
+    let checker = UpdateChecker(
+      session: mockSession,
+      settingsManager: settingsManager,
+      owner: "test",
+      repo: "test-repo"
+    )
+
+    _ = await checker.checkForUpdate(currentVersion: "1.0.0")
+    let cache = settingsManager.settings.updateCache
+    #expect(cache?.lastCheckError != nil)
+    #expect(cache?.consecutiveFailures == 0)
+    #expect(cache?.lastFailedAt == nil)
+
+    // オンラインに戻ればすぐに再確認する
+    mockSession.errorToThrow = nil
+    mockSession.dataToReturn = makeReleasesJSON([makeRelease(tagName: "v2.0.0")])
+    let result = await checker.checkForUpdate(currentVersion: "1.0.0")
+    #expect(result?.latestVersion == "2.0.0")
+    #expect(settingsManager.settings.updateCache?.lastCheckError == nil)
+  }
+
+  @Test func failureBacksOffUntilRetryDelayPasses() async {
+    let mockSession = MockURLSession()
+    mockSession.errorToThrow = URLError(.timedOut)
+
+    let settingsManager = SettingsManager(configDirectory: makeTempConfigDir())
+    let checker = UpdateChecker(
+      session: mockSession,
+      settingsManager: settingsManager,
+      owner: "test",
+      repo: "test-repo"
+    )
+
+    _ = await checker.checkForUpdate(currentVersion: "1.0.0")
+    #expect(settingsManager.settings.updateCache?.consecutiveFailures == 1)
+
+    // 待ち時間内は API を呼ばない
+    mockSession.requestedURL = nil
+    mockSession.errorToThrow = nil
+    mockSession.dataToReturn = makeReleasesJSON([makeRelease(tagName: "v2.0.0")])
+    #expect(await checker.checkForUpdate(currentVersion: "1.0.0") == nil)
+    #expect(mockSession.requestedURL == nil)
+
+    // 待ち時間が過ぎたら再試行し、成功すれば失敗の記録を消す
+    settingsManager.settings.updateCache?.lastFailedAt = Date().addingTimeInterval(-31 * 60)
+    let result = await checker.checkForUpdate(currentVersion: "1.0.0")
+    #expect(mockSession.requestedURL != nil)
+    #expect(result?.latestVersion == "2.0.0")
+    #expect(settingsManager.settings.updateCache?.consecutiveFailures == 0)
+    #expect(settingsManager.settings.updateCache?.lastFailedAt == nil)
+  }
+
+  @Test func proxyRejectionIsRecordedAsError() async {
+    let mockSession = MockURLSession()
+    mockSession.dataToReturn = makeReleasesJSON([makeRelease(tagName: "v2.0.0")])
+    mockSession.responseToReturn = HTTPURLResponse(
+      url: URL(string: "https://api.github.com")!, statusCode: 407, httpVersion: nil,
+      headerFields: nil)
+
+    let settingsManager = SettingsManager(configDirectory: makeTempConfigDir())
+    let checker = UpdateChecker(
+      session: mockSession,
+      settingsManager: settingsManager,
+      owner: "test",
+      repo: "test-repo"
+    )
+
+    let result = await checker.checkForUpdate(currentVersion: "1.0.0")
+    #expect(result == nil)
+    #expect(
+      settingsManager.settings.updateCache?.lastCheckError
+        == UpdateCheckError.httpStatus(407).localizedDescription)
+    #expect(settingsManager.settings.updateCache?.consecutiveFailures == 1)
+  }
+
+  @Test func retryDelayDoublesUpToCacheExpiry() {
+    #expect(UpdateChecker.retryDelay(afterFailures: 0) == 0)
+    #expect(UpdateChecker.retryDelay(afterFailures: 1) == 30 * 60)
+    #expect(UpdateChecker.retryDelay(afterFailures: 2) == 60 * 60)
+    #expect(UpdateChecker.retryDelay(afterFailures: 4) == 4 * 3600)
+    #expect(UpdateChecker.retryDelay(afterFailures: 10) == 12 * 3600)
+    #expect(UpdateChecker.retryDelay(afterFailures: 1000) == 12 * 3600)
+  }
+}
+
+// MARK: - ProxyConfiguration テスト
+
+@Suite("ProxyConfiguration")
+struct ProxyConfigurationTests {
+
+  @Test func readsHTTPSProxyBeforeHTTPProxy() {
+    let proxy = ProxyConfiguration(environment: [
+      "HTTP_PROXY": "http://plain.example.com:3128",
+      "HTTPS_PROXY": "http://secure.example.com:8443",
+    ])
+    #expect(proxy == ProxyConfiguration(host: "secure.example.com", port: 8443))
+  }
+
+  @Test func acceptsLowercaseAndSchemelessValues() {
+    #expect(
+      ProxyConfiguration(environment: ["http_proxy": "proxy.example.com"])
+        == ProxyConfiguration(host: "proxy.example.com", port: 8080))
+  }
+
+  @Test func returnsNilWithoutProxy() {
+    #expect(ProxyConfiguration(environment: [:]) == nil)
+    #expect(ProxyConfiguration(environment: ["HTTPS_PROXY": " "]) == nil)
+  }
 }
 
 // MARK: - UpdateChecker API URL テスト
@@ -0,0 +1,205 @@
+import CryptoKit
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+// MARK: - URLSession モック
+
+/// URL ごとに返すデータを切り替えるモック
+private struct AssetURLSession: URLSessionProtocol {
+  let responses: [String: Data]
+
+  func data(for request: URLRequest) async throws -> (Data, URLResponse) {
+    let url = request.url!
+    let data = responses[url.absoluteString]
+    let response = HTTPURLResponse(
+      url: url, statusCode: data == nil ? 404 : 200, httpVersion: nil, headerFields: nil)!
+    return (data ?? Data(), response)
+  }
+}
+
+private func makeTempStagingDir() -> URL {
+  FileManager.default.temporaryDirectory
+    .appendingPathComponent("ignitero-update-installer-test-\(UUID().uuidString)")
+}
+
+private func sha256Hex(_ data: Data) -> String {
+  SHA256.hash(data: data).map { String(format: "%02x", $0) }.joined()
+}
+
+private func signature(_ data: Data, with key: Curve25519.Signing.PrivateKey) throws -> Data {
+  Data(try key.signature(for: data).base64EncodedString().utf8)
+}
+
+private let signedUpdate = UpdateCheckResult(
+  latestVersion: "99.0.0", downloadURL: "https://example.com",
+  assetURL: "https://example.com/app.zip",
+  checksumURL: "https://example.com/app.zip.sha256",
+  signatureURL: "https://example.com/app.zip.sig")
+
+// MARK: - チェックサム検証テスト
+
+@Suite("UpdateInstaller Checksum")
+struct UpdateInstallerChecksumTests {
+
+  @Test("shasum 形式のチェックサムと一致すれば成功し、一致しなければ失敗する")
+  func verifiesShasumFormat() throws {
+    let data = Data("archive".utf8)
+    let hash = sha256Hex(data)
+
+    try UpdateInstaller.verifyChecksum(
+      of: data, expected: "\(hash.uppercased())  IgniteroLauncher.zip\n")
+    #expect(throws: UpdateInstallError.checksumMismatch) {
+      try UpdateInstaller.verifyChecksum(of: Data("tampered".utf8), expected: hash)
+    }
+    #expect(throws: UpdateInstallError.checksumMismatch) {
+      try UpdateInstaller.verifyChecksum(of: data, expected: "")
+    }
+  }
+}
+
+// MARK: - 署名検証テスト
+
+@Suite("UpdateInstaller Signature")
+struct UpdateInstallerSignatureTests {
+
+  @Test("リリース用の鍵の署名だけを受け付ける")
+  func verifiesEd25519Signature() throws {
+    let key = Curve25519.Signing.PrivateKey()
+    let data = Data("archive".utf8)
+    let signed = String(decoding: try signature(data, with: key), as: UTF8.self)
+
+    try UpdateInstaller.verifySignature(
+      of: data, signature: signed + "\n", publicKey: key.publicKey)
+    #expect(throws: UpdateInstallError.signatureInvalid) {
+      try UpdateInstaller.verifySignature(
+        of: Data("tampered".utf8), signature: signed, publicKey: key.publicKey)
+    }
+    #expect(throws: UpdateInstallError.signatureInvalid) {
+      try UpdateInstaller.verifySignature(
+        of: data, signature: signed, publicKey: Curve25519.Signing.PrivateKey().publicKey)
+    }
+    #expect(throws: UpdateInstallError.signatureInvalid) {
+      try UpdateInstaller.verifySignature(of: data, signature: "", publicKey: key.publicKey)
+    }
+  }
+
+  @Test("Base64 の公開鍵を読み込み、不正な値は nil にする")
+  func parsesPublicKey() {
+    let key = Curve25519.Signing.PrivateKey().publicKey
+    let base64 = key.rawRepresentation.base64EncodedString()
+    #expect(
+      UpdateInstaller.publicKey(base64: " \(base64)\n")?.rawRepresentation
+        == key.rawRepresentation)
+    #expect(UpdateInstaller.publicKey(base64: "") == nil)
+    #expect(UpdateInstaller.publicKey(base64: "not-a-key") == nil)
+  }
+}
+
+// MARK: - ダウンロード・検証テスト
+
+@Suite("UpdateInstaller Stage")
+struct UpdateInstallerStageTests {
+
+  @Test("アーカイブが添付されていないリリースはダウンロードしない")
+  func stageRequiresArtifacts() async {
+    let installer = UpdateInstaller(
+      session: AssetURLSession(responses: [:]), stagingDirectory: makeTempStagingDir())
+
+    await #expect(throws: UpdateInstallError.artifactNotFound) {
+      _ = try await installer.stage(
+        UpdateCheckResult(latestVersion: "99.0.0", downloadURL: "https://example.com"),
+        progress: { _ in })
+    }
+  }
+
+  @Test("署名のないリリースはダウンロードしない")
+  func stageRequiresSignature() async {
+    let installer = UpdateInstaller(
+      session: AssetURLSession(responses: [:]), stagingDirectory: makeTempStagingDir(),
+      publicKey: Curve25519.Signing.PrivateKey().publicKey)
+    let update = UpdateCheckResult(
+      latestVersion: "99.0.0", downloadURL: "https://example.com",
+      assetURL: "https://example.com/app.zip",
+      checksumURL: "https://example.com/app.zip.sha256")
+
+    await #expect(throws: UpdateInstallError.artifactNotFound) {
+      _ = try await installer.stage(update, progress: { _ in })
+    }
+  }
+
+  @Test("公開鍵を埋め込んでいないビルドはダウンロードしない")
+  func stageRequiresPublicKey() async {
+    let installer = UpdateInstaller(
+      session: AssetURLSession(responses: [:]), stagingDirectory: makeTempStagingDir(),
+      publicKey: nil)
+
+    #expect(!installer.canVerifySignatures)
+    await #expect(throws: UpdateInstallError.publicKeyMissing) {
+      _ = try await installer.stage(signedUpdate, progress: { _ in })
+    }
+  }
+
+  @Test("チェックサムが一致しないアーカイブは展開せずに失敗する")
+  func stageRejectsChecksumMismatch() async throws {
+    let key = Curve25519.Signing.PrivateKey()
+    let archive = Data("archive".utf8)
+    let installer = UpdateInstaller(
+      session: AssetURLSession(responses: [
+        "https://example.com/app.zip": archive,
+        "https://example.com/app.zip.sha256": Data(sha256Hex(Data("other".utf8)).utf8),
+        "https://example.com/app.zip.sig": try signature(archive, with: key),
+      ]),
+      stagingDirectory: makeTempStagingDir(), publicKey: key.publicKey)
+
+    await #expect(throws: UpdateInstallError.checksumMismatch) {
+      _ = try await installer.stage(signedUpdate, progress: { _ in })
+    }
+  }
+
+  @Test("アセットを差し替えたアーカイブはチェックサムが合っていても展開せずに失敗する")
+  func stageRejectsForeignSignature() async throws {
+    let staging = makeTempStagingDir()
+    let archive = Data("replaced archive".utf8)
+    let installer = UpdateInstaller(
+      session: AssetURLSession(responses: [
+        "https://example.com/app.zip": archive,
+        "https://example.com/app.zip.sha256": Data(sha256Hex(archive).utf8),
+        "https://example.com/app.zip.sig": try signature(
+          archive, with: Curve25519.Signing.PrivateKey()),
+      ]),
+      stagingDirectory: staging, publicKey: Curve25519.Signing.PrivateKey().publicKey)
+
+    await #expect(throws: UpdateInstallError.signatureInvalid) {
+      _ = try await installer.stage(signedUpdate, progress: { _ in })
+    }
+    #expect(!FileManager.default.fileExists(atPath: staging.path))
+  }
+
+  @Test("ダウンロードの HTTP エラーをステータスコード付きで返す")
+  func stageReportsDownloadFailure() async {
+    let installer = UpdateInstaller(
+      session: AssetURLSession(responses: [:]), stagingDirectory: makeTempStagingDir(),
+      publicKey: Curve25519.Signing.PrivateKey().publicKey)
+
+    await #expect(throws: UpdateInstallError.downloadFailed(statusCode: 404)) {
+      _ = try await installer.stage(signedUpdate, progress: { _ in })
+    }
+  }
+
+  @Test("バンドル ID が異なるアプリはインストールしない")
+  func validateBundleRejectsOtherApps() throws {
+    let app = makeTempStagingDir().appendingPathComponent("Other.app")
+    let contents = app.appendingPathComponent("Contents")
+    try FileManager.default.createDirectory(at: contents, withIntermediateDirectories: true)
+    let plist: [String: Any] = ["CFBundleIdentifier": "com.example.other"]
+    try PropertyListSerialization.data(fromPropertyList: plist, format: .xml, options: 0)
+      .write(to: contents.appendingPathComponent("Info.plist"))
+
+    #expect(throws: UpdateInstallError.self) {
+      try UpdateInstaller.validateBundle(at: app, bundleIdentifier: "com.owayo.ignitero.launcher")
+    }
+    try UpdateInstaller.validateBundle(at: app, bundleIdentifier: "com.example.other")
+  }
+}
@@ -0,0 +1,59 @@
+import CoreGraphics
+import Foundation
+import Testing
+
+@testable import IgniteroCore
+
+@Suite("WindowLayoutAction")
+struct WindowLayoutActionTests {
+  private let screen = CGRect(x: 0, y: 25, width: 1441, height: 875)
+  private let window = CGRect(x: 300, y: 200, width: 800, height: 600)
+
+  @Test("識別子から操作を復元できる")
+  func restoresActionFromIdentifier() {
+    for action in WindowLayoutAction.allCases {
+      #expect(WindowLayoutAction(identifier: action.identifier) == action)
+    }
+    #expect(WindowLayoutAction.leftHalf.identifier == "window-layout://left_half")
+    #expect(WindowLayoutAction(identifier: "window-layout://top_half") == nil)
+    #expect(WindowLayoutAction(identifier: "system-control://toggle_mute") == nil)
+  }
+
+  @Test("左右半分は画面の幅を分け合い、隙間なく並ぶ")
+  func halvesSplitVisibleFrame() {
+    let left = WindowLayoutAction.leftHalf.frame(for: window, in: screen)
+    let right = WindowLayoutAction.rightHalf.frame(for: window, in: screen)
+
+    #expect(left == CGRect(x: 0, y: 25, width: 720, height: 875))
+    #expect(right == CGRect(x: 720, y: 25, width: 721, height: 875))
+  }
+
+  @Test("最大化は可視領域いっぱいにする")
+  func maximizeFillsVisibleFrame() {
+    #expect(WindowLayoutAction.maximize.frame(for: window, in: screen) == screen)
+  }
+
+  @Test("中央配置は大きさを保ち、画面より大きい場合は縮める")
+  func centerKeepsSizeWithinScreen() {
+    #expect(
+      WindowLayoutAction.center.frame(for: window, in: screen)
+        == CGRect(x: 321, y: 163, width: 800, height: 600))
+
+    let large = CGRect(x: 0, y: 0, width: 2000, height: 1200)
+    #expect(WindowLayoutAction.center.frame(for: large, in: screen) == screen)
+  }
+}
+
+@Suite("WindowLayoutService")
+struct WindowLayoutServiceTests {
+
+  @Test("AppKit の座標をアクセシビリティ API の左上原点の座標に変換する")
+  func convertsToAccessibilityCoordinates() {
+    // メニューバー 25pt・Dock 70pt を除いた 1440x900 の画面
+    let visible = CGRect(x: 0, y: 70, width: 1440, height: 805)
+
+    #expect(
+      WindowLayoutService.accessibilityFrame(fromScreenFrame: visible, primaryScreenHeight: 900)
+        == CGRect(x: 0, y: 25, width: 1440, height: 805))
+  }
+}
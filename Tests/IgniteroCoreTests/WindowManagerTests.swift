@@ -255,6 +255,15 @@ struct WindowManagerResizeTests {
     #expect(manager.currentHeight == expected)
   }
 
+  @MainActor
+  @Test("外観設定の行の高さと幅で高さを計算する")
+  func applyAppearanceChangesRowHeight() {
+    let manager = WindowManager()
+    manager.applyAppearance(width: 800, rowHeight: 44, resultCount: 3)
+    #expect(manager.windowWidth == 800)
+    #expect(manager.currentHeight == WindowManager.minHeight + 3 * 44)
+  }
+
   @MainActor
   @Test func constantValues() {
     #expect(WindowManager.minHeight == 108)
@@ -291,6 +300,33 @@ struct WindowManagerResizeEdgeCaseTests {
 @Suite("WindowManager Callbacks")
 struct WindowManagerCallbackTests {
 
+  @MainActor
+  @Test("プログラムからの配置・リサイズは記憶する位置として保存せず、ユーザーの移動だけを保存する")
+  func onlyUserMovesAreRemembered() async throws {
+    let manager = WindowManager()
+    let panel = NSPanel(
+      contentRect: NSRect(x: 0, y: 0, width: 600, height: 80), styleMask: [.borderless],
+      backing: .buffered, defer: true)
+    manager.launcherPanel = panel
+    manager.placement = .rememberedPosition
+    manager.positionSaveDebounce = .zero
+    var saved: [WindowPosition] = []
+    manager.onPositionChanged = { saved.append($0) }
+    defer { manager.hideLauncher() }
+
+    manager.showLauncher()
+    manager.resizeForResults(count: 5)
+    try await Task.sleep(for: .milliseconds(50))
+    #expect(saved.isEmpty)
+    #expect(manager.savedPosition == nil)
+
+    panel.setFrameOrigin(NSPoint(x: 40, y: 300))
+    try await Task.sleep(for: .milliseconds(50))
+    let expected = WindowPosition(x: 40, top: 300 + panel.frame.height)
+    #expect(manager.savedPosition == expected)
+    #expect(saved == [expected])
+  }
+
   @MainActor
   @Test func onShowLauncherCalledWhenShowing() {
     let manager = WindowManager()
@@ -300,6 +336,19 @@ struct WindowManagerCallbackTests {
     #expect(called)
   }
 
+  @MainActor
+  @Test("表示中のランチャーを閉じたときだけ onHideLauncher が呼ばれる")
+  func onHideLauncherCalledOnlyWhenVisible() {
+    let manager = WindowManager()
+    var count = 0
+    manager.onHideLauncher = { count += 1 }
+    manager.hideLauncher()
+    #expect(count == 0)
+    manager.showLauncher()
+    manager.hideLauncher(restoringFocus: true)
+    #expect(count == 1)
+  }
+
   @MainActor
   @Test func onAutoDismissNotCalledWhenHidden() {
     let manager = WindowManager()
@@ -340,3 +389,115 @@ struct WindowManagerCallbackTests {
     #expect(manager.isLauncherVisible == false)
   }
 }
+
+// MARK: - WindowManager Position Tests
+
+@Suite("WindowManager Position")
+struct WindowManagerPositionTests {
+
+  private let size = CGSize(width: 680, height: 108)
+  private let mainScreen = CGRect(x: 0, y: 0, width: 1440, height: 875)
+  private let externalScreen = CGRect(x: 1440, y: 0, width: 1920, height: 1055)
+
+  @Test("スクリーン内の位置はそのまま復元される")
+  func positionInsideScreenIsRestored() throws {
+    let origin = try #require(
+      WindowManager.validatedOrigin(
+        for: WindowPosition(x: 200, top: 700), size: size, screens: [mainScreen]))
+    #expect(origin == CGPoint(x: 200, y: 592))
+  }
+
+  @Test("外部モニター上の位置も復元される")
+  func positionOnExternalScreenIsRestored() throws {
+    let origin = try #require(
+      WindowManager.validatedOrigin(
+        for: WindowPosition(x: 2000, top: 900), size: size,
+        screens: [mainScreen, externalScreen]))
+    #expect(origin == CGPoint(x: 2000, y: 792))
+  }
+
+  @Test("取り外したモニター上の位置は nil になる")
+  func positionOnDisconnectedScreenIsNil() {
+    let origin = WindowManager.validatedOrigin(
+      for: WindowPosition(x: 2000, top: 900), size: size, screens: [mainScreen])
+    #expect(origin == nil)
+  }
+
+  @Test("スクリーン端からはみ出す位置は可視領域内に補正される")
+  func positionIsClampedIntoScreen() throws {
+    let origin = try #require(
+      WindowManager.validatedOrigin(
+        for: WindowPosition(x: 1000, top: 875), size: size, screens: [mainScreen]))
+    #expect(origin == CGPoint(x: 760, y: 767))
+  }
+
+  @Test("メニューバーアイコンの直下・中央に配置される")
+  func originBelowAnchorIsCentered() {
+    let anchor = CGRect(x: 700, y: 875, width: 24, height: 24)
+    let origin = WindowManager.origin(below: anchor, size: size, screen: mainScreen)
+    #expect(origin == CGPoint(x: 372, y: 763))
+  }
+
+  @Test("画面右端のアイコンでは可視領域内に収める")
+  func originBelowAnchorIsClampedToScreenEdge() {
+    let anchor = CGRect(x: 1400, y: 875, width: 24, height: 24)
+    let origin = WindowManager.origin(below: anchor, size: size, screen: mainScreen)
+    #expect(origin == CGPoint(x: 760, y: 763))
+  }
+
+  @MainActor
+  @Test("アイコンのクリックで表示中のランチャーを閉じる")
+  func toggleBelowAnchorHidesVisibleLauncher() {
+    let manager = WindowManager()
+    let anchor = CGRect(x: 700, y: 875, width: 24, height: 24)
+    manager.toggleLauncher(below: anchor)
+    #expect(manager.isLauncherVisible == true)
+    manager.toggleLauncher(below: anchor)
+    #expect(manager.isLauncherVisible == false)
+  }
+
+  @MainActor
+  @Test("フォーカスを失うと閉じる設定では即座に閉じる")
+  func focusLossHidesLauncher() {
+    let manager = WindowManager()
+    var autoDismissed = false
+    manager.onAutoDismiss = { autoDismissed = true }
+    manager.showLauncher()
+
+    manager.handleFocusLoss()
+    #expect(!manager.isLauncherVisible)
+    #expect(autoDismissed)
+  }
+
+  @MainActor
+  @Test("フォーカスを失っても閉じない設定では表示したまま")
+  func focusLossIsIgnoredWhenDisabled() {
+    let manager = WindowManager()
+    manager.hidesOnFocusLoss = false
+    manager.showLauncher()
+
+    manager.handleFocusLoss()
+    #expect(manager.isLauncherVisible)
+  }
+
+  @MainActor
+  @Test("猶予時間の経過後に閉じる")
+  func focusLossHidesAfterGracePeriod() async throws {
+    let manager = WindowManager()
+    manager.focusLossGracePeriod = .milliseconds(50)
+    manager.showLauncher()
+
+    manager.handleFocusLoss()
+    #expect(manager.isLauncherVisible)
+    try await Task.sleep(for: .milliseconds(200))
+    #expect(!manager.isLauncherVisible)
+  }
+
+  @MainActor
+  @Test("表示位置は既定でカーソルのあるディスプレイ")
+  func placementDefaultsToFollowMouse() {
+    let manager = WindowManager()
+    #expect(manager.placement == .followMouse)
+    #expect(manager.savedPosition == nil)
+  }
+}
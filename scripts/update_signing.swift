@@ -0,0 +1,56 @@
+#!/usr/bin/env swift
+// アプリ内アップデート用のアーカイブに Ed25519 署名を付けるスクリプト。
+//
+// 秘密鍵は Base64 で鍵ファイルに保存し、リポジトリには含めない。公開鍵は
+// Resources/Info.plist の IgniteroUpdatePublicKey に埋め込み、アプリはダウンロードした
+// アーカイブをその公開鍵で検証してから展開する。
+//
+// 使い方:
+//     swift scripts/update_signing.swift generate <鍵ファイル>          # 鍵を作成し、公開鍵を出力
+//     swift scripts/update_signing.swift public-key <鍵ファイル>        # 公開鍵を出力
+//     swift scripts/update_signing.swift sign <鍵ファイル> <アーカイブ>   # 署名を出力
+
+import CryptoKit
+import Foundation
+
+func fail(_ message: String) -> Never {
+  FileHandle.standardError.write(Data("error: \(message)\n".utf8))
+  exit(1)
+}
+
+func loadPrivateKey(_ path: String) -> Curve25519.Signing.PrivateKey {
+  guard let text = try? String(contentsOfFile: path, encoding: .utf8),
+    let data = Data(base64Encoded: text.trimmingCharacters(in: .whitespacesAndNewlines)),
+    let key = try? Curve25519.Signing.PrivateKey(rawRepresentation: data)
+  else { fail("cannot read signing key: \(path)") }
+  return key
+}
+
+let arguments = Array(CommandLine.arguments.dropFirst())
+switch (arguments.first, arguments.count) {
+case ("generate", 2):
+  let path = arguments[1]
+  guard !FileManager.default.fileExists(atPath: path) else {
+    fail("signing key already exists: \(path)")
+  }
+  let key = Curve25519.Signing.PrivateKey()
+  let directory = (path as NSString).deletingLastPathComponent
+  try FileManager.default.createDirectory(
+    atPath: directory.isEmpty ? "." : directory, withIntermediateDirectories: true)
+  guard
+    FileManager.default.createFile(
+      atPath: path, contents: Data(key.rawRepresentation.base64EncodedString().utf8),
+      attributes: [.posixPermissions: 0o600])
+  else { fail("cannot write signing key: \(path)") }
+  print(key.publicKey.rawRepresentation.base64EncodedString())
+case ("public-key", 2):
+  print(loadPrivateKey(arguments[1]).publicKey.rawRepresentation.base64EncodedString())
+case ("sign", 3):
+  let key = loadPrivateKey(arguments[1])
+  guard let archive = FileManager.default.contents(atPath: arguments[2]) else {
+    fail("cannot read archive: \(arguments[2])")
+  }
+  print(try key.signature(for: archive).base64EncodedString())
+default:
+  fail("usage: update_signing.swift generate|public-key <key> | sign <key> <archive>")
+}